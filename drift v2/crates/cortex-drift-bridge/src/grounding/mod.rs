@@ -5,12 +5,14 @@ pub mod classification;
 pub mod contradiction;
 pub mod evidence;
 pub mod loop_runner;
+pub mod prior_source;
 pub mod scheduler;
 pub mod scorer;
 
 pub use classification::{classify_groundability, Groundability};
 pub use evidence::{EvidenceType, GroundingEvidence};
 pub use loop_runner::GroundingLoopRunner;
+pub use prior_source::BridgePriorSource;
 pub use scheduler::{GroundingScheduler, TriggerType};
 pub use scorer::GroundingScorer;
 
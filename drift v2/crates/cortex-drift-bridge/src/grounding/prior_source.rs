@@ -0,0 +1,127 @@
+//! Bridge PriorSource impl: confidence priors from grounding verdicts.
+//!
+//! Memories tagged `pattern:<id>` are read once, with the latest grounding
+//! verdict of each, and become Beta pseudo-counts for `ConfidenceScorer`:
+//! Validated adds 2.0 to alpha and Partial 0.5; Weak adds 0.5 to beta and
+//! Invalidated 2.0. Ungroundable, insufficient-data and failed groundings
+//! say nothing about the pattern.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use drift_core::traits::PriorSource;
+use tracing::warn;
+
+use crate::traits::IBridgeStorage;
+use crate::types::GroundingVerdict;
+
+/// Tag prefix linking a memory to a pattern.
+const PATTERN_TAG_PREFIX: &str = "pattern:";
+
+/// Most memories consulted per pattern.
+const MAX_MEMORIES_PER_PATTERN: usize = 50;
+
+/// Bridge implementation of PriorSource.
+///
+/// Priors are loaded when the source is built, so scoring a batch of
+/// patterns costs one bridge query rather than one per pattern and memory.
+pub struct BridgePriorSource {
+    priors: HashMap<String, (f64, f64)>,
+}
+
+impl BridgePriorSource {
+    pub fn new(bridge_store: Option<Arc<dyn IBridgeStorage>>) -> Self {
+        let priors = match bridge_store {
+            Some(store) => load_priors(store.as_ref()),
+            None => HashMap::new(),
+        };
+        Self { priors }
+    }
+
+    /// Create a no-op source for standalone mode.
+    pub fn no_op() -> Self {
+        Self {
+            priors: HashMap::new(),
+        }
+    }
+}
+
+impl PriorSource for BridgePriorSource {
+    fn prior(&self, pattern_id: &str) -> Option<(f64, f64)> {
+        self.priors.get(pattern_id).copied()
+    }
+}
+
+/// Group the latest verdicts of pattern-tagged memories by pattern id.
+fn load_priors(store: &dyn IBridgeStorage) -> HashMap<String, (f64, f64)> {
+    let rows = match store.latest_verdicts_by_tag_prefix(PATTERN_TAG_PREFIX) {
+        Ok(rows) => rows,
+        Err(e) => {
+            warn!(error = %e, "Failed to query pattern verdicts for priors");
+            return HashMap::new();
+        }
+    };
+
+    // Rows are newest verdict first, so the per-pattern cap keeps the most
+    // recently grounded memories.
+    let mut verdicts: HashMap<String, Vec<GroundingVerdict>> = HashMap::new();
+    for (tags, classification) in rows {
+        let Some(verdict) = parse_verdict(&classification) else {
+            continue;
+        };
+        let tags: Vec<String> = serde_json::from_str(&tags).unwrap_or_default();
+        for tag in tags {
+            let Some(pattern_id) = tag.strip_prefix(PATTERN_TAG_PREFIX) else {
+                continue;
+            };
+            let entry = verdicts.entry(pattern_id.to_string()).or_default();
+            if entry.len() < MAX_MEMORIES_PER_PATTERN {
+                entry.push(verdict);
+            }
+        }
+    }
+
+    verdicts
+        .into_iter()
+        .filter_map(|(pattern_id, v)| Some((pattern_id, prior_from_verdicts(v)?)))
+        .collect()
+}
+
+/// `(alpha, beta)` pseudo-counts contributed by one verdict.
+pub fn verdict_pseudo_counts(verdict: GroundingVerdict) -> (f64, f64) {
+    match verdict {
+        GroundingVerdict::Validated => (2.0, 0.0),
+        GroundingVerdict::Partial => (0.5, 0.0),
+        GroundingVerdict::Weak => (0.0, 0.5),
+        GroundingVerdict::Invalidated => (0.0, 2.0),
+        GroundingVerdict::NotGroundable
+        | GroundingVerdict::InsufficientData
+        | GroundingVerdict::Error => (0.0, 0.0),
+    }
+}
+
+/// Summed pseudo-counts for a pattern's verdicts, or `None` if none of
+/// them carries information.
+pub fn prior_from_verdicts(
+    verdicts: impl IntoIterator<Item = GroundingVerdict>,
+) -> Option<(f64, f64)> {
+    let (alpha, beta) = verdicts
+        .into_iter()
+        .map(verdict_pseudo_counts)
+        .fold((0.0, 0.0), |(a, b), (da, db)| (a + da, b + db));
+    (alpha > 0.0 || beta > 0.0).then_some((alpha, beta))
+}
+
+/// Parse the `classification` column of `bridge_grounding_results`.
+fn parse_verdict(classification: &str) -> Option<GroundingVerdict> {
+    match classification {
+        "Validated" => Some(GroundingVerdict::Validated),
+        "Partial" => Some(GroundingVerdict::Partial),
+        "Weak" => Some(GroundingVerdict::Weak),
+        "Invalidated" => Some(GroundingVerdict::Invalidated),
+        "NotGroundable" => Some(GroundingVerdict::NotGroundable),
+        "InsufficientData" => Some(GroundingVerdict::InsufficientData),
+        "Error" => Some(GroundingVerdict::Error),
+        _ => None,
+    }
+}
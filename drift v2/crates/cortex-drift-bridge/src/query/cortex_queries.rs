@@ -102,6 +102,34 @@ pub fn get_memories_by_tag(
     Ok(results)
 }
 
+/// Latest grounding classification of every memory with a tag starting with
+/// `tag_prefix`, as `(tags, classification)`, newest verdict first.
+///
+/// One query for all memories, instead of a grounding-history lookup per memory.
+pub fn get_latest_verdicts_by_tag_prefix(
+    conn: &Connection,
+    tag_prefix: &str,
+) -> BridgeResult<Vec<(String, String)>> {
+    let pattern = format!("%\"{}%", escape_like(tag_prefix));
+    let mut stmt = conn.prepare(
+        "SELECT m.tags, g.classification
+         FROM bridge_memories m
+         JOIN bridge_grounding_results g ON g.id = (
+             SELECT id FROM bridge_grounding_results
+             WHERE memory_id = m.id ORDER BY created_at DESC, id DESC LIMIT 1
+         )
+         WHERE m.tags LIKE ?1 ESCAPE '\\' ORDER BY g.created_at DESC, g.id DESC",
+    )?;
+    let rows = stmt.query_map(rusqlite::params![pattern], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+    })?;
+    let mut results = Vec::new();
+    for row in rows {
+        results.push(row?);
+    }
+    Ok(results)
+}
+
 /// Count total memories in bridge_memories.
 pub fn count_memories(conn: &Connection) -> BridgeResult<u64> {
     let count: i64 = conn.query_row(
@@ -13,5 +13,5 @@ pub mod weights;
 
 pub use corrections::{CorrectionRootCause, SpecCorrection};
 pub use attribution::DataSourceAttribution;
-pub use weight_provider::BridgeWeightProvider;
+pub use weight_provider::{BoundsViolation, BridgeWeightProvider, DecayState, WeightKey};
 pub use decomposition_provider::BridgeDecompositionPriorProvider;
@@ -18,6 +18,8 @@ use tracing::{info, warn};
 
 use crate::traits::IBridgeStorage;
 
+use super::weights::{clamp_weight, decay_weight, MIN_WEIGHT};
+
 /// Boost factor for weight adjustment formula.
 const BOOST_FACTOR: f64 = 0.5;
 
@@ -27,6 +29,47 @@ const MIN_SAMPLE_SIZE: usize = 15;
 /// Maximum allowed weight for any single section.
 const MAX_WEIGHT: f64 = 5.0;
 
+/// Identifies one adaptive weight: a section within a migration path's table.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WeightKey {
+    /// Cache key of the migration path (`src:tgt:src_fw:tgt_fw`).
+    pub path: String,
+    /// Specification section name, e.g. `public_api`.
+    pub section: String,
+}
+
+impl WeightKey {
+    pub fn new(path: &MigrationPath, section: impl Into<String>) -> Self {
+        Self {
+            path: BridgeWeightProvider::cache_key(path),
+            section: section.into(),
+        }
+    }
+}
+
+/// How far an adaptive weight has decayed back toward its static default.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecayState {
+    /// Static default the weight decays toward.
+    pub static_default: f64,
+    /// Unix timestamp the weight was last computed.
+    pub last_updated: i64,
+    /// Days since `last_updated`.
+    pub elapsed_days: f64,
+    /// Weight after applying the 365-day half-life.
+    pub effective: f64,
+}
+
+/// A cached weight found outside `[MIN_WEIGHT, MAX_WEIGHT]` or non-finite.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundsViolation {
+    pub key: WeightKey,
+    /// Value found in the table.
+    pub observed: f64,
+    /// Value it was clamped to.
+    pub clamped: f64,
+}
+
 /// Bridge implementation of WeightProvider.
 /// Reads Cortex Skill memories and computes adaptive weights.
 pub struct BridgeWeightProvider {
@@ -181,6 +224,94 @@ impl BridgeWeightProvider {
         Ok(memory_id)
     }
 
+    /// Install a weight table for a migration path, replacing any cached one.
+    ///
+    /// Weights outside `[MIN_WEIGHT, MAX_WEIGHT]` are clamped on the way in;
+    /// each one clamped is reported.
+    pub fn cache_weights(
+        &self,
+        path: &MigrationPath,
+        mut table: AdaptiveWeightTable,
+    ) -> Vec<BoundsViolation> {
+        let key = Self::cache_key(path);
+        let violations = enforce_bounds(&key, &mut table);
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.insert(key, table);
+        }
+        violations
+    }
+
+    /// Every cached adaptive weight with its decay state, sorted by key.
+    pub fn snapshot(&self) -> Vec<(WeightKey, f64, DecayState)> {
+        let Ok(cache) = self.cache.lock() else {
+            return Vec::new();
+        };
+
+        let now = Utc::now().timestamp();
+        let mut entries: Vec<(WeightKey, f64, DecayState)> = cache
+            .iter()
+            .flat_map(|(path, table)| {
+                let elapsed_days = if table.last_updated > 0 {
+                    (now - table.last_updated).max(0) as f64 / 86_400.0
+                } else {
+                    0.0
+                };
+                table.weights.iter().map(move |(section, &weight)| {
+                    let static_default = static_default(section);
+                    let decay = DecayState {
+                        static_default,
+                        last_updated: table.last_updated,
+                        elapsed_days,
+                        effective: decay_weight(weight, static_default, elapsed_days),
+                    };
+                    let key = WeightKey { path: path.clone(), section: section.clone() };
+                    (key, weight, decay)
+                })
+            })
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries
+    }
+
+    /// Reset one cached weight to its static default.
+    ///
+    /// Sections without a static default are removed. Returns `false` if the
+    /// key is not cached. Persisted Skill memories are left untouched; call
+    /// `persist_weights` to make the reset durable.
+    pub fn reset_weight(&self, key: &WeightKey) -> bool {
+        let Ok(mut cache) = self.cache.lock() else {
+            return false;
+        };
+        let Some(table) = cache.get_mut(&key.path) else {
+            return false;
+        };
+        if !table.weights.contains_key(&key.section) {
+            return false;
+        }
+
+        match AdaptiveWeightTable::static_defaults().weights.get(&key.section) {
+            Some(&default) => {
+                table.weights.insert(key.section.clone(), default);
+            }
+            None => {
+                table.weights.remove(&key.section);
+            }
+        }
+        table.failure_distribution.remove(&key.section);
+        info!(path = %key.path, section = %key.section, "Reset adaptive weight to static default");
+        true
+    }
+
+    /// Reset every cached table to static defaults.
+    pub fn reset_all(&self) {
+        if let Ok(mut cache) = self.cache.lock() {
+            for table in cache.values_mut() {
+                *table = AdaptiveWeightTable::static_defaults();
+            }
+            info!(tables = cache.len(), "Reset all adaptive weights to static defaults");
+        }
+    }
+
     /// Cache key for a migration path.
     fn cache_key(path: &MigrationPath) -> String {
         format!(
@@ -193,6 +324,44 @@ impl BridgeWeightProvider {
     }
 }
 
+/// Static default weight for a section; unknown sections default to 1.0.
+fn static_default(section: &str) -> f64 {
+    AdaptiveWeightTable::static_defaults()
+        .weights
+        .get(section)
+        .copied()
+        .unwrap_or(1.0)
+}
+
+/// Clamp the weights of `table` that escaped their bounds, reporting each one.
+///
+/// Non-finite weights fall back to the section's static default.
+fn enforce_bounds(path: &str, table: &mut AdaptiveWeightTable) -> Vec<BoundsViolation> {
+    let mut violations = Vec::new();
+    for (section, weight) in table.weights.iter_mut() {
+        let observed = *weight;
+        if observed.is_finite() && (MIN_WEIGHT..=MAX_WEIGHT).contains(&observed) {
+            continue;
+        }
+        let clamped = clamp_weight(observed, static_default(section));
+        warn!(
+            path = %path,
+            section = %section,
+            observed,
+            clamped,
+            "Adaptive weight escaped its bounds — clamped"
+        );
+        *weight = clamped;
+        violations.push(BoundsViolation {
+            key: WeightKey { path: path.to_string(), section: section.clone() },
+            observed,
+            clamped,
+        });
+    }
+    violations.sort_by(|a, b| a.key.cmp(&b.key));
+    violations
+}
+
 impl WeightProvider for BridgeWeightProvider {
     fn get_weights(&self, path: &MigrationPath) -> AdaptiveWeightTable {
         // Check cache first
@@ -239,12 +408,13 @@ impl WeightProvider for BridgeWeightProvider {
                         if let Ok(weights_map) = serde_json::from_value::<HashMap<String, f64>>(
                             weights_obj.clone(),
                         ) {
-                            let table = AdaptiveWeightTable {
+                            let mut table = AdaptiveWeightTable {
                                 weights: weights_map,
                                 failure_distribution: HashMap::new(),
                                 sample_size: 0,
                                 last_updated: chrono::Utc::now().timestamp(),
                             };
+                            enforce_bounds(&key, &mut table);
                             // Cache it
                             if let Ok(mut cache) = self.cache.lock() {
                                 cache.insert(key, table.clone());
@@ -349,6 +349,15 @@ impl IBridgeStorage for BridgeStorageEngine {
         })
     }
 
+    fn latest_verdicts_by_tag_prefix(
+        &self,
+        tag_prefix: &str,
+    ) -> BridgeResult<Vec<(String, String)>> {
+        self.pool.with_reader(|conn| {
+            crate::query::cortex_queries::get_latest_verdicts_by_tag_prefix(conn, tag_prefix)
+        })
+    }
+
     // ── 4 Lifecycle ──
 
     fn initialize(&self) -> BridgeResult<()> {
@@ -185,6 +185,13 @@ pub trait IBridgeStorage: Send + Sync {
     /// Get the previous grounding score for a memory.
     fn get_previous_grounding_score(&self, memory_id: &str) -> BridgeResult<Option<f64>>;
 
+    /// Latest grounding classification of each memory with a tag starting
+    /// with `tag_prefix`, as `(tags JSON, classification)`, newest verdict first.
+    fn latest_verdicts_by_tag_prefix(
+        &self,
+        tag_prefix: &str,
+    ) -> BridgeResult<Vec<(String, String)>>;
+
     // ── 4 Lifecycle ──
 
     /// Initialize storage (create tables, run migrations).
@@ -280,6 +287,12 @@ impl<T: IBridgeStorage + ?Sized> IBridgeStorage for Arc<T> {
     fn get_previous_grounding_score(&self, memory_id: &str) -> BridgeResult<Option<f64>> {
         (**self).get_previous_grounding_score(memory_id)
     }
+    fn latest_verdicts_by_tag_prefix(
+        &self,
+        tag_prefix: &str,
+    ) -> BridgeResult<Vec<(String, String)>> {
+        (**self).latest_verdicts_by_tag_prefix(tag_prefix)
+    }
     fn initialize(&self) -> BridgeResult<()> {
         (**self).initialize()
     }
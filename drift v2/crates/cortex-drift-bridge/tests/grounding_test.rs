@@ -1,4 +1,4 @@
-//! T9-GND-01 through T9-GND-10: Grounding logic tests.
+//! T9-GND-01 through T9-GND-11: Grounding logic tests.
 
 use cortex_drift_bridge::grounding::classification::*;
 use cortex_drift_bridge::grounding::evidence::*;
@@ -468,3 +468,109 @@ fn t9_gnd_loop_snapshot_persisted() {
     assert_eq!(snapshot.total_checked, 5);
     assert!(snapshot.validated > 0 || snapshot.partial > 0);
 }
+
+// ---- T9-GND-11: Test grounding verdicts become confidence priors ----
+
+#[test]
+fn t9_gnd_11_verdict_priors() {
+    use cortex_drift_bridge::grounding::prior_source::prior_from_verdicts;
+    use drift_core::traits::PriorSource;
+
+    assert_eq!(
+        prior_from_verdicts([GroundingVerdict::Validated, GroundingVerdict::Partial]),
+        Some((2.5, 0.0))
+    );
+    assert_eq!(
+        prior_from_verdicts([GroundingVerdict::Invalidated, GroundingVerdict::Weak, GroundingVerdict::Validated]),
+        Some((2.0, 2.5))
+    );
+    assert_eq!(
+        prior_from_verdicts([GroundingVerdict::NotGroundable, GroundingVerdict::InsufficientData]),
+        None
+    );
+    assert_eq!(BridgePriorSource::no_op().prior("any"), None);
+}
+
+#[test]
+fn t9_gnd_11_priors_loaded_from_bridge_db() {
+    use drift_core::traits::PriorSource;
+    use std::sync::Arc;
+
+    let db = setup_bridge_db();
+    let memories = [
+        ("pm1", r#"["pattern:p-1"]"#),
+        ("pm2", r#"["pattern:p-1","pattern:p-2"]"#),
+        ("pm3", r#"["other"]"#),
+    ];
+    for (id, tags) in memories {
+        db.execute(
+            "INSERT INTO bridge_memories (id, memory_type, content, summary, confidence, importance, tags)
+             VALUES (?1, 'PatternRationale', '{}', ?1, 0.7, 'normal', ?2)",
+            rusqlite::params![id, tags],
+        )
+        .unwrap();
+    }
+    // pm1's older Invalidated verdict is superseded by a later Validated one.
+    for (id, verdict) in [
+        ("pm1", GroundingVerdict::Invalidated),
+        ("pm1", GroundingVerdict::Validated),
+        ("pm2", GroundingVerdict::Weak),
+        ("pm3", GroundingVerdict::Validated),
+    ] {
+        db.insert_grounding_result(&GroundingResult {
+            memory_id: id.to_string(),
+            verdict,
+            grounding_score: 0.5,
+            previous_score: None,
+            score_delta: None,
+            confidence_adjustment: ConfidenceAdjustment {
+                mode: AdjustmentMode::NoChange,
+                delta: None,
+                reason: String::new(),
+            },
+            evidence: vec![],
+            generates_contradiction: false,
+            duration_ms: 0,
+        })
+        .unwrap();
+    }
+
+    let store: Arc<dyn IBridgeStorage> = Arc::new(db);
+    let source = BridgePriorSource::new(Some(store));
+    assert_eq!(source.prior("p-1"), Some((2.0, 0.5)));
+    assert_eq!(source.prior("p-2"), Some((0.0, 0.5)));
+    assert_eq!(source.prior("other"), None);
+    assert_eq!(source.prior("p-3"), None);
+}
+
+#[test]
+fn t9_gnd_11_priors_cap_keeps_latest_verdicts() {
+    use drift_core::traits::PriorSource;
+    use std::sync::Arc;
+
+    // 50 new memories grounded long ago, and one old memory grounded last.
+    let db = setup_bridge_db();
+    for i in 0..51 {
+        let (id, memory_at, verdict, verdict_at) = if i == 50 {
+            ("old".to_string(), 1, "Invalidated", 3_000)
+        } else {
+            (format!("new-{i}"), 2_000, "Validated", 100 + i)
+        };
+        db.execute(
+            "INSERT INTO bridge_memories (id, memory_type, content, summary, confidence, importance, tags, created_at)
+             VALUES (?1, 'PatternRationale', '{}', ?1, 0.7, 'normal', '[\"pattern:p-cap\"]', ?2)",
+            rusqlite::params![id, memory_at],
+        )
+        .unwrap();
+        db.execute(
+            "INSERT INTO bridge_grounding_results (memory_id, grounding_score, classification, evidence, created_at)
+             VALUES (?1, 0.5, ?2, '[]', ?3)",
+            rusqlite::params![id, verdict, verdict_at],
+        )
+        .unwrap();
+    }
+
+    let store: Arc<dyn IBridgeStorage> = Arc::new(db);
+    let source = BridgePriorSource::new(Some(store));
+    assert_eq!(source.prior("p-cap"), Some((98.0, 2.0)));
+}
@@ -90,6 +90,7 @@ impl IBridgeStorage for MockBridgeStorage {
     }
     fn search_memories_by_tag(&self, _tag: &str, _limit: usize) -> BridgeResult<Vec<BridgeMemoryRow>> { Ok(vec![]) }
     fn get_previous_grounding_score(&self, _memory_id: &str) -> BridgeResult<Option<f64>> { Ok(None) }
+    fn latest_verdicts_by_tag_prefix(&self, _tag_prefix: &str) -> BridgeResult<Vec<(String, String)>> { Ok(vec![]) }
     fn initialize(&self) -> BridgeResult<()> { Ok(()) }
     fn migrate(&self) -> BridgeResult<()> { Ok(()) }
     fn health_check(&self) -> BridgeResult<BridgeHealthStatus> {
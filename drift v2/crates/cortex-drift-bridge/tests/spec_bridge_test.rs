@@ -1,11 +1,11 @@
-//! T9-BRIDGE-01 through T9-BRIDGE-50: Specification engine bridge tests.
+//! T9-BRIDGE-01 through T9-BRIDGE-51: Specification engine bridge tests.
 
 use cortex_causal::CausalEngine;
 use cortex_drift_bridge::specification::attribution::{AttributionStats, DataSourceAttribution};
 use cortex_drift_bridge::specification::corrections::*;
 use cortex_drift_bridge::specification::events;
 use cortex_drift_bridge::specification::narrative;
-use cortex_drift_bridge::specification::weight_provider::BridgeWeightProvider;
+use cortex_drift_bridge::specification::weight_provider::{BridgeWeightProvider, WeightKey};
 use cortex_drift_bridge::specification::decomposition_provider::BridgeDecompositionPriorProvider;
 use drift_core::traits::decomposition::DecompositionPriorProvider;
 use drift_core::traits::weight_provider::{AdaptiveWeightTable, MigrationPath, WeightProvider};
@@ -519,6 +519,54 @@ fn t9_bridge_50_d4_compliance_cargo_toml() {
     );
 }
 
+// ---- T9-BRIDGE-51: Weight snapshot, bounds enforcement and reset ----
+
+#[test]
+fn t9_bridge_51_weight_snapshot_and_reset() {
+    let provider = BridgeWeightProvider::no_op();
+    let path = MigrationPath::language_only("python", "rust");
+    let default_api = AdaptiveWeightTable::static_defaults().weights["public_api"];
+
+    // A weight pushed past MAX_WEIGHT is clamped to the bound and reported.
+    let mut table = AdaptiveWeightTable::static_defaults();
+    table.weights.insert("public_api".to_string(), 9.0);
+    table.last_updated = chrono::Utc::now().timestamp();
+    let violations = provider.cache_weights(&path, table);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].key, WeightKey::new(&path, "public_api"));
+    assert_eq!(violations[0].observed, 9.0);
+    assert_eq!(violations[0].clamped, 5.0);
+    let recached = provider.cache_weights(&path, provider.get_weights(&path));
+    assert!(recached.is_empty(), "clamping is idempotent");
+
+    let snapshot = provider.snapshot();
+    assert_eq!(snapshot.len(), AdaptiveWeightTable::static_defaults().weights.len());
+    let (_, weight, decay) = snapshot
+        .iter()
+        .find(|(key, _, _)| key.section == "public_api")
+        .expect("public_api should be in the snapshot");
+    assert_eq!(*weight, 5.0);
+    assert_eq!(decay.static_default, default_api);
+    assert!(decay.elapsed_days < 1.0);
+    assert!((decay.effective - 5.0).abs() < 0.01, "fresh weights have not decayed");
+    assert_eq!(provider.get_weights(&path).weights["public_api"], 5.0);
+
+    // Resetting restores the static default.
+    assert!(provider.reset_weight(&WeightKey::new(&path, "public_api")));
+    assert_eq!(provider.get_weights(&path).weights["public_api"], default_api);
+    assert!(!provider.reset_weight(&WeightKey::new(&path, "no_such_section")));
+
+    // reset_all restores every cached table.
+    let mut table = AdaptiveWeightTable::static_defaults();
+    table.weights.insert("security".to_string(), 4.0);
+    provider.cache_weights(&path, table);
+    provider.reset_all();
+    assert_eq!(
+        provider.get_weights(&path).weights,
+        AdaptiveWeightTable::static_defaults().weights
+    );
+}
+
 // ---- SpecSection parsing ----
 
 #[test]
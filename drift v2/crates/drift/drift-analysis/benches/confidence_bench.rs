@@ -35,6 +35,7 @@ fn make_patterns(n: usize) -> Vec<AggregatedPattern> {
                 confidence_values: vec![0.85; locations as usize],
                 is_dirty: false,
                 location_hash: 0,
+                over_limit: false,
             }
         })
         .collect()
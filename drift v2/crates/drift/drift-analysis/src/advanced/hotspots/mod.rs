@@ -0,0 +1,10 @@
+//! Hotspot Analysis — churn-weighted risk ranking.
+//!
+//! Combines caller-supplied git churn with complexity and blast radius.
+//! High-churn, high-complexity functions rank as the riskiest.
+
+pub mod types;
+pub mod ranking;
+
+pub use types::*;
+pub use ranking::rank;
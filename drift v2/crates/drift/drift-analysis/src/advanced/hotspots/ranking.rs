@@ -0,0 +1,77 @@
+//! Hotspot ranking from churn, complexity, and blast radius.
+
+use std::collections::HashMap;
+
+use drift_core::types::collections::FxHashSet;
+use drift_core::types::identifiers::FunctionId;
+use lasso::Key;
+
+use super::types::Hotspot;
+
+/// Weight of the churn × complexity factor in the composite score.
+const HOTSPOT_WEIGHT: f64 = 0.75;
+/// Weight of the blast radius factor in the composite score.
+const BLAST_RADIUS_WEIGHT: f64 = 0.25;
+
+/// Rank functions by churn-weighted risk.
+///
+/// Each input is normalized against its maximum. The churn and complexity
+/// factors are combined with a geometric mean, so a function must be high
+/// on both to be a hotspot; blast radius then amplifies the impact.
+/// Functions present in any input are ranked; missing values count as 0.
+/// Results are sorted by score descending, ties broken by function id.
+pub fn rank(
+    complexity: &HashMap<FunctionId, u32>,
+    blast_radius: &HashMap<FunctionId, u32>,
+    churn: &HashMap<FunctionId, u32>,
+) -> Vec<Hotspot> {
+    let max_complexity = max_value(complexity);
+    let max_blast = max_value(blast_radius);
+    let max_churn = max_value(churn);
+
+    let mut ids: FxHashSet<FunctionId> = FxHashSet::default();
+    ids.extend(complexity.keys().copied());
+    ids.extend(blast_radius.keys().copied());
+    ids.extend(churn.keys().copied());
+
+    let mut hotspots: Vec<Hotspot> = ids
+        .into_iter()
+        .map(|function_id| {
+            let c = complexity.get(&function_id).copied().unwrap_or(0);
+            let b = blast_radius.get(&function_id).copied().unwrap_or(0);
+            let h = churn.get(&function_id).copied().unwrap_or(0);
+
+            let hotspot = (normalize(h, max_churn) * normalize(c, max_complexity)).sqrt();
+            let score = hotspot * HOTSPOT_WEIGHT + normalize(b, max_blast) * BLAST_RADIUS_WEIGHT;
+
+            Hotspot {
+                function_id,
+                churn: h,
+                complexity: c,
+                blast_radius: b,
+                score: score.clamp(0.0, 1.0),
+            }
+        })
+        .collect();
+
+    hotspots.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.function_id.inner().into_usize().cmp(&b.function_id.inner().into_usize()))
+    });
+
+    hotspots
+}
+
+fn max_value(values: &HashMap<FunctionId, u32>) -> u32 {
+    values.values().copied().max().unwrap_or(0)
+}
+
+fn normalize(value: u32, max: u32) -> f64 {
+    if max == 0 {
+        0.0
+    } else {
+        value as f64 / max as f64
+    }
+}
@@ -0,0 +1,19 @@
+//! Hotspot analysis types.
+
+use drift_core::types::identifiers::FunctionId;
+use serde::{Deserialize, Serialize};
+
+/// A ranked hotspot — one function with its raw inputs and composite score.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Hotspot {
+    /// The function being ranked.
+    pub function_id: FunctionId,
+    /// Commit count touching this function (caller-supplied).
+    pub churn: u32,
+    /// Complexity of the function (e.g. cyclomatic).
+    pub complexity: u32,
+    /// Transitive caller count.
+    pub blast_radius: u32,
+    /// Composite hotspot score (0.0-1.0). Higher = riskier.
+    pub score: f64,
+}
@@ -4,6 +4,8 @@
 //! - Simulation Engine: Monte Carlo effort estimation with 13 task categories
 //! - Decision Mining: git2-based institutional decision extraction
 //! - N+1 Query Detection: Advanced ORM-aware loop-query detection
+//! - Hotspot Analysis: churn-weighted risk ranking per function
 
 pub mod simulation;
 pub mod decisions;
+pub mod hotspots;
@@ -9,6 +9,9 @@ pub mod monte_carlo;
 pub mod strategies;
 
 pub use types::*;
-pub use scorers::{ComplexityScorer, RiskScorer, EffortScorer, ConfidenceScorer, Scorer};
+pub use scorers::{
+    all_scorers, scorer_by_name, ComplexityScorer, ConfidenceScorer, EffortScorer, RiskScorer, Scorer,
+    DEFAULT_SCORER_NAMES,
+};
 pub use monte_carlo::MonteCarloSimulator;
 pub use strategies::StrategyRecommender;
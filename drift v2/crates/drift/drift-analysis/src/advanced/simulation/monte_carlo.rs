@@ -1,8 +1,13 @@
 //! Monte Carlo simulation for effort estimation with P10/P50/P90 confidence intervals.
 //!
 //! Uses random sampling with configurable iteration count and seed for reproducibility.
+//! An optional `Scorer` calibrates the base effort before sampling.
 
-use super::types::{ConfidenceInterval, SimulationContext, TaskCategory};
+use super::scorers::Scorer;
+use super::types::{
+    ConfidenceInterval, RiskLevel, SimulationApproach, SimulationContext, SimulationTask,
+    TaskCategory,
+};
 
 /// Monte Carlo simulator for effort estimation.
 pub struct MonteCarloSimulator {
@@ -10,6 +15,8 @@ pub struct MonteCarloSimulator {
     iterations: u32,
     /// Random seed for reproducibility (None = non-deterministic).
     seed: Option<u64>,
+    /// Optional effort calibration (None = built-in formula only).
+    scorer: Option<Box<dyn Scorer>>,
 }
 
 impl MonteCarloSimulator {
@@ -18,6 +25,7 @@ impl MonteCarloSimulator {
         Self {
             iterations: iterations.max(100),
             seed: None,
+            scorer: None,
         }
     }
 
@@ -27,6 +35,19 @@ impl MonteCarloSimulator {
         self
     }
 
+    /// Calibrate the base effort with a scorer, e.g. one fitted to a team's
+    /// historical estimates. A score of 0.5 leaves the base effort unchanged;
+    /// 0.0 halves it and 1.0 raises it by half.
+    pub fn with_scorer(mut self, scorer: Box<dyn Scorer>) -> Self {
+        self.scorer = Some(scorer);
+        self
+    }
+
+    /// Name of the calibration scorer, if one is set.
+    pub fn scorer_name(&self) -> Option<&'static str> {
+        self.scorer.as_ref().map(|s| s.name())
+    }
+
     /// Run Monte Carlo simulation and produce P10/P50/P90 confidence intervals.
     ///
     /// Uses a simple LCG (linear congruential generator) for portability
@@ -36,7 +57,7 @@ impl MonteCarloSimulator {
         category: TaskCategory,
         context: &SimulationContext,
     ) -> ConfidenceInterval {
-        let base_effort = category.base_effort_hours();
+        let base_effort = category.base_effort_hours() * self.effort_scale(category, context);
         let mut samples = Vec::with_capacity(self.iterations as usize);
 
         // Initialize RNG state
@@ -91,6 +112,37 @@ impl MonteCarloSimulator {
         ConfidenceInterval { p10, p50, p90 }
     }
 
+    /// Base-effort multiplier from the calibration scorer, in [0.5, 1.5].
+    fn effort_scale(&self, category: TaskCategory, context: &SimulationContext) -> f64 {
+        let Some(scorer) = &self.scorer else {
+            return 1.0;
+        };
+        let task = SimulationTask {
+            category,
+            description: String::new(),
+            affected_files: Vec::new(),
+            context: context.clone(),
+        };
+        let approach = SimulationApproach {
+            name: "baseline".to_string(),
+            description: String::new(),
+            estimated_effort_hours: category.base_effort_hours(),
+            risk_level: RiskLevel::Low,
+            affected_file_count: 0,
+            complexity_score: 0.0,
+            risk_score: 0.0,
+            effort_score: 0.0,
+            confidence_score: 0.0,
+            composite_score: 0.0,
+            tradeoffs: Vec::new(),
+        };
+        let score = scorer.score(&task, &approach);
+        if score.is_nan() {
+            return 1.0;
+        }
+        0.5 + score.clamp(0.0, 1.0)
+    }
+
     /// Sample from approximate normal distribution using Box-Muller transform.
     fn sample_normal(&self, state: &mut u64, mean: f64, std_dev: f64) -> f64 {
         // Generate two uniform samples
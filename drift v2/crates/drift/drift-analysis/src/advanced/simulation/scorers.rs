@@ -98,14 +98,26 @@ impl Scorer for ConfidenceScorer {
     fn weight(&self) -> f64 { 0.20 }
 }
 
+/// Names of the built-in scoring strategies, in composite order.
+pub const DEFAULT_SCORER_NAMES: &[&str] = &["complexity", "risk", "effort", "confidence"];
+
 /// Get all 4 scorers.
 pub fn all_scorers() -> Vec<Box<dyn Scorer>> {
-    vec![
-        Box::new(ComplexityScorer),
-        Box::new(RiskScorer),
-        Box::new(EffortScorer),
-        Box::new(ConfidenceScorer),
-    ]
+    DEFAULT_SCORER_NAMES
+        .iter()
+        .filter_map(|name| scorer_by_name(name))
+        .collect()
+}
+
+/// Look up a built-in scoring strategy by name (see `DEFAULT_SCORER_NAMES`).
+pub fn scorer_by_name(name: &str) -> Option<Box<dyn Scorer>> {
+    match name {
+        "complexity" => Some(Box::new(ComplexityScorer)),
+        "risk" => Some(Box::new(RiskScorer)),
+        "effort" => Some(Box::new(EffortScorer)),
+        "confidence" => Some(Box::new(ConfidenceScorer)),
+        _ => None,
+    }
 }
 
 #[cfg(test)]
@@ -19,10 +19,34 @@ impl StrategyRecommender {
     }
 
     pub fn with_seed(mut self, seed: u64) -> Self {
-        self.simulator = MonteCarloSimulator::new(1000).with_seed(seed);
+        self.simulator = std::mem::take(&mut self.simulator).with_seed(seed);
         self
     }
 
+    /// Replace the scoring strategies used to rank approaches.
+    pub fn with_scorers(mut self, scorers: Vec<Box<dyn Scorer>>) -> Self {
+        self.scorers = scorers;
+        self
+    }
+
+    /// Add a scorer, replacing any existing scorer with the same name.
+    pub fn with_scorer(mut self, scorer: Box<dyn Scorer>) -> Self {
+        self.scorers.retain(|s| s.name() != scorer.name());
+        self.scorers.push(scorer);
+        self
+    }
+
+    /// Use a custom simulator (e.g. one with a calibration scorer) for effort estimates.
+    pub fn with_simulator(mut self, simulator: MonteCarloSimulator) -> Self {
+        self.simulator = simulator;
+        self
+    }
+
+    /// Names of the scorers in use, in scoring order.
+    pub fn scorer_names(&self) -> Vec<&'static str> {
+        self.scorers.iter().map(|s| s.name()).collect()
+    }
+
     /// Generate approaches for a task and recommend the best one.
     pub fn recommend(&self, task: &SimulationTask) -> SimulationResult {
         let mut approaches = self.generate_approaches(task);
@@ -3,14 +3,17 @@
 use std::time::Instant;
 
 use drift_core::errors::CallGraphError;
-use drift_core::types::collections::FxHashMap;
+use drift_core::types::collections::{FxHashMap, FxHashSet};
+use drift_core::types::identifiers::FunctionId;
+use petgraph::visit::EdgeRef;
 use rayon::prelude::*;
 
-use crate::parsers::types::{CallSite, ParseResult};
+use crate::parsers::interned::{InternedCallSite, SymbolTable};
+use crate::parsers::types::{CallSite, ImportInfo, ParseResult};
 
 use super::di_support;
 use super::resolution::{resolve_call, ResolutionDiagnostics};
-use super::types::{CallEdge, CallGraph, CallGraphStats, FunctionNode};
+use super::types::{CallEdge, CallGraph, CallGraphStats, FunctionNode, Resolution};
 
 /// Builder for constructing a call graph from parse results.
 pub struct CallGraphBuilder {
@@ -42,216 +45,451 @@ impl CallGraphBuilder {
         let mut graph = CallGraph::new();
 
         // Phase 1: Add all function nodes
-        // CG-RES-08: Build qualified names for module-level functions
         let all_nodes: Vec<FunctionNode> = parse_results
             .par_iter()
-            .flat_map_iter(|pr| {
-                let module_name = module_name_from_file(&pr.file);
-                // Top-level functions
-                let top_level = pr.functions.iter().map(move |f| {
-                    let qn = f.qualified_name.clone().or_else(|| {
-                        Some(format!("{}.{}", module_name, f.name))
-                    });
-                    FunctionNode {
-                        file: pr.file.clone(),
-                        name: f.name.clone(),
-                        qualified_name: qn,
-                        language: pr.language.name().to_string(),
-                        line: f.line,
-                        end_line: f.end_line,
-                        is_entry_point: false, // Detected later
-                        is_exported: f.is_exported,
-                        signature_hash: f.signature_hash,
-                        body_hash: f.body_hash,
-                    }
-                });
-                top_level
-            })
+            .flat_map_iter(file_nodes)
             .collect();
+        for node in all_nodes {
+            graph.add_function(node);
+        }
 
-        // Also add class methods as graph nodes
-        let class_method_nodes: Vec<FunctionNode> = parse_results
-            .par_iter()
-            .flat_map_iter(|pr| {
-                pr.classes.iter().flat_map(move |class| {
-                    class.methods.iter().map(move |m| {
-                        let qn = m.qualified_name.clone().or_else(|| {
-                            Some(format!("{}.{}", class.name, m.name))
-                        });
-                        FunctionNode {
-                            file: pr.file.clone(),
-                            name: format!("{}.{}", class.name, m.name),
-                            qualified_name: qn,
-                            language: pr.language.name().to_string(),
-                            line: m.line,
-                            end_line: m.end_line,
-                            is_entry_point: false,
-                            is_exported: m.is_exported || class.is_exported,
-                            signature_hash: m.signature_hash,
-                            body_hash: m.body_hash,
-                        }
-                    })
-                })
-            })
+        // Build lookup indices for resolution
+        let mut inputs: Vec<FileResolution> = parse_results
+            .iter()
+            .map(|pr| FileResolution::new(pr, &graph.symbols))
             .collect();
+        let mut index = ResolutionIndex::default();
+        for input in &inputs {
+            index.add(input);
+        }
 
-        for node in all_nodes {
-            graph.add_function(node);
+        // CG-RES-05: Detect DI frameworks for DI resolution
+        let uses_di = inputs.iter().any(|input| input.uses_di);
+
+        // Phase 2: Resolve call sites into edges
+        for input in &mut inputs {
+            for i in 0..input.calls.len() {
+                resolve_into(&mut graph, &index, input, i, uses_di);
+            }
         }
-        for node in class_method_nodes {
-            graph.add_function(node);
+        graph.resolution_inputs = inputs;
+
+        // Detect entry points
+        super::traversal::mark_entry_points(&mut graph, parse_results);
+
+        let stats = graph_stats(&graph, start);
+        Ok((graph, stats))
+    }
+
+    /// CG-INCR-02: Update `prev_graph` for changed and removed files only.
+    ///
+    /// Nodes and edges of `changed_results` and `removed_files` are dropped
+    /// and the changed files re-extracted. Calls from unchanged files are
+    /// re-resolved only when they look up a name the changed files defined
+    /// before or define now (or when DI detection flips), so the result and
+    /// stats match `build` over the same final file set, with modified files
+    /// keeping their position and added files appended.
+    pub fn rebuild_incremental(
+        &self,
+        prev_graph: CallGraph,
+        changed_results: &[ParseResult],
+        removed_files: &[String],
+    ) -> (CallGraph, CallGraphStats) {
+        let start = Instant::now();
+        let mut graph = prev_graph;
+        let changed: FxHashSet<&str> = changed_results
+            .iter()
+            .map(|pr| pr.file.as_str())
+            .chain(removed_files.iter().map(String::as_str))
+            .collect();
+
+        let previous = std::mem::take(&mut graph.resolution_inputs);
+        let old_uses_di = previous.iter().any(|input| input.uses_di);
+        let mut affected_names: FxHashSet<String> = FxHashSet::default();
+        for input in previous.iter().filter(|input| changed.contains(input.file.as_str())) {
+            affected_names.extend(input.symbols.iter().flat_map(IndexedSymbol::lookup_names));
         }
 
-        // Build lookup indices for resolution
-        let mut name_index: FxHashMap<String, Vec<String>> = FxHashMap::default();
-        let mut qualified_index: FxHashMap<String, String> = FxHashMap::default();
-        let mut export_index: FxHashMap<String, Vec<String>> = FxHashMap::default();
-        let mut language_index: FxHashMap<String, String> = FxHashMap::default();
-
-        for pr in parse_results {
-            let lang = pr.language.name().to_string();
-            for func in &pr.functions {
-                let key = format!("{}::{}", pr.file, func.name);
-                name_index.entry(func.name.clone()).or_default().push(key.clone());
-                language_index.insert(key.clone(), lang.clone());
-                if let Some(ref qn) = func.qualified_name {
-                    qualified_index.insert(qn.clone(), key.clone());
+        // Drop the changed files' nodes (and every edge touching them)
+        let mut removed_keys: FxHashSet<String> = FxHashSet::default();
+        for file in &changed {
+            for &idx in graph.get_file_nodes(file) {
+                let node = &graph.graph[idx];
+                removed_keys.insert(format!("{}::{}", node.file, node.name));
+            }
+            graph.remove_file(file);
+        }
+
+        // Modified files keep their position in build order, added files go last
+        let mut fresh: Vec<Option<FileResolution>> = changed_results
+            .iter()
+            .map(|pr| Some(FileResolution::new(pr, &graph.symbols)))
+            .collect();
+        let mut take_fresh = |file: &str| {
+            fresh.iter_mut().find(|f| f.as_ref().is_some_and(|f| f.file == file)).and_then(Option::take)
+        };
+        let mut inputs: Vec<FileResolution> = Vec::with_capacity(previous.len() + changed_results.len());
+        for input in previous {
+            if !changed.contains(input.file.as_str()) {
+                inputs.push(input);
+            } else if let Some(updated) = take_fresh(&input.file) {
+                inputs.push(updated);
+            }
+        }
+        inputs.extend(fresh.into_iter().flatten());
+
+        // Re-add nodes for the changed files
+        let mut new_nodes = Vec::new();
+        let mut route_handlers: FxHashSet<String> = FxHashSet::default();
+        for pr in changed_results {
+            new_nodes.extend(file_nodes(pr).map(|node| graph.add_function(node)));
+        }
+        for input in inputs.iter().filter(|input| changed.contains(input.file.as_str())) {
+            affected_names.extend(input.symbols.iter().flat_map(IndexedSymbol::lookup_names));
+            route_handlers.extend(input.route_handlers.iter().cloned());
+        }
+        super::traversal::mark_nodes(&mut graph, &new_nodes, &route_handlers);
+
+        let mut index = ResolutionIndex::default();
+        for input in &inputs {
+            index.add(input);
+        }
+        let uses_di = inputs.iter().any(|input| input.uses_di);
+
+        // Re-resolve calls of changed files and calls whose lookups they affect
+        for input in &mut inputs {
+            let is_changed = changed.contains(input.file.as_str());
+            for i in 0..input.calls.len() {
+                let call = &input.calls[i];
+                let symbols = &graph.symbols;
+                let stale = is_changed
+                    || uses_di != old_uses_di
+                    || input.looks_up(&call.call_site, symbols, &affected_names)
+                    || call.outcome.is_some_and(|(key, _)| removed_keys.contains(symbols.function(key)));
+                if !stale {
+                    continue;
                 }
-                // CG-RES-08: Also index module_name.function_name
-                let module_qn = format!("{}.{}", module_name_from_file(&pr.file), func.name);
-                qualified_index.entry(module_qn).or_insert_with(|| key.clone());
-                if func.is_exported {
-                    export_index.entry(func.name.clone()).or_default().push(key);
+                // Edges into removed nodes are already gone (the key may now name a re-added node)
+                if let Some((callee_key, _)) = input.calls[i].outcome.take() {
+                    if !removed_keys.contains(graph.symbols.function(callee_key)) {
+                        remove_call_edge(&mut graph, &input.calls[i], callee_key);
+                    }
                 }
+                resolve_into(&mut graph, &index, input, i, uses_di);
             }
-            // Index class methods (key includes class name to avoid collisions)
-            for class in &pr.classes {
-                for method in &class.methods {
-                    let key = format!("{}::{}.{}", pr.file, class.name, method.name);
-                    name_index.entry(method.name.clone()).or_default().push(key.clone());
-                    language_index.insert(key.clone(), lang.clone());
-                    // Qualified: ClassName.methodName
-                    let class_qn = format!("{}.{}", class.name, method.name);
-                    qualified_index.insert(class_qn, key.clone());
-                    if let Some(ref qn) = method.qualified_name {
-                        qualified_index.insert(qn.clone(), key.clone());
-                    }
-                    if method.is_exported || class.is_exported {
-                        export_index.entry(method.name.clone()).or_default().push(key);
-                    }
+        }
+        graph.resolution_inputs = inputs;
+
+        let stats = graph_stats(&graph, start);
+        (graph, stats)
+    }
+}
+
+impl Default for CallGraphBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What a file contributed to a build: its symbols, imports and call
+/// outcomes, kept on the graph so `rebuild_incremental` can re-index and
+/// re-resolve without the file's `ParseResult`. Calls are the bulk of it,
+/// so they are kept interned in the graph's `SymbolTable`.
+#[derive(Debug, Clone)]
+pub(crate) struct FileResolution {
+    pub(crate) file: String,
+    language: &'static str,
+    imports: Vec<ImportInfo>,
+    symbols: Vec<IndexedSymbol>,
+    calls: Vec<FileCall>,
+    route_handlers: Vec<String>,
+    uses_di: bool,
+}
+
+/// A function or method as entered into the lookup indices.
+#[derive(Debug, Clone)]
+struct IndexedSymbol {
+    name: String,
+    key: String,
+    /// Qualified names, and whether each overwrites an existing entry.
+    qualified: Vec<(String, bool)>,
+    is_exported: bool,
+}
+
+/// A call site attributed to its caller, with the edge it resolved to.
+/// Keys are interned `file::name` node keys.
+#[derive(Debug, Clone)]
+struct FileCall {
+    caller_key: FunctionId,
+    call_site: InternedCallSite,
+    outcome: Option<(FunctionId, Resolution)>,
+}
+
+impl FileResolution {
+    fn new(pr: &ParseResult, table: &SymbolTable) -> Self {
+        let mut symbols = Vec::new();
+        // CG-RES-08: Also index module_name.function_name
+        let module_name = module_name_from_file(&pr.file);
+        for func in &pr.functions {
+            let mut qualified = Vec::new();
+            if let Some(ref qn) = func.qualified_name {
+                qualified.push((qn.clone(), true));
+            }
+            qualified.push((format!("{}.{}", module_name, func.name), false));
+            symbols.push(IndexedSymbol {
+                name: func.name.clone(),
+                key: format!("{}::{}", pr.file, func.name),
+                qualified,
+                is_exported: func.is_exported,
+            });
+        }
+        // Index class methods (key includes class name to avoid collisions)
+        for class in &pr.classes {
+            for method in &class.methods {
+                // Qualified: ClassName.methodName
+                let mut qualified = vec![(format!("{}.{}", class.name, method.name), true)];
+                if let Some(ref qn) = method.qualified_name {
+                    qualified.push((qn.clone(), true));
                 }
+                symbols.push(IndexedSymbol {
+                    name: method.name.clone(),
+                    key: format!("{}::{}.{}", pr.file, class.name, method.name),
+                    qualified,
+                    is_exported: method.is_exported || class.is_exported,
+                });
             }
         }
 
-        // CG-RES-05: Detect DI frameworks for DI resolution
-        let detected_frameworks = di_support::detect_di_frameworks(parse_results);
+        // Collect all (caller_key, call_site) pairs
+        let file = table.file_id(&pr.file);
+        let calls = pr.functions.iter().flat_map(|func| {
+            let caller_key = table.function_id(&format!("{}::{}", pr.file, func.name));
+            pr.call_sites
+                .iter()
+                .filter(move |cs| cs.line >= func.line && cs.line <= func.end_line)
+                .map(move |cs| FileCall {
+                    caller_key,
+                    call_site: table.intern_call_site_in(file, cs),
+                    outcome: None,
+                })
+        });
 
-        // Phase 2: Resolve call sites into edges
-        // Collect all (caller_key, call_site, file) tuples
-        let call_entries: Vec<(String, &CallSite, &ParseResult)> = parse_results
+        Self {
+            file: pr.file.clone(),
+            language: pr.language.name(),
+            imports: pr.imports.clone(),
+            symbols,
+            calls: calls.collect(),
+            route_handlers: super::traversal::route_handler_keys(pr),
+            uses_di: !di_support::detect_di_frameworks(std::slice::from_ref(pr)).is_empty(),
+        }
+    }
+
+    /// Calls that did not resolve, as (caller key, call site).
+    pub(crate) fn unresolved_calls<'a>(
+        &'a self,
+        symbols: &'a SymbolTable,
+    ) -> impl Iterator<Item = (&'a str, CallSite)> + 'a {
+        self.calls
             .iter()
-            .flat_map(|pr| {
-                pr.functions.iter().flat_map(move |func| {
-                    let caller_key = format!("{}::{}", pr.file, func.name);
-                    pr.call_sites
-                        .iter()
-                        .filter(move |cs| {
-                            cs.line >= func.line && cs.line <= func.end_line
-                        })
-                        .map(move |cs| (caller_key.clone(), cs, pr))
-                })
+            .filter(|call| call.outcome.is_none())
+            .map(|call| (symbols.function(call.caller_key), call.call_site.resolve(symbols)))
+    }
+
+    /// Whether resolving `call_site` looks up any of `names`: its callee, or
+    /// the original name behind an aliased import.
+    fn looks_up(&self, call_site: &InternedCallSite, symbols: &SymbolTable, names: &FxHashSet<String>) -> bool {
+        let callee = symbols.function(call_site.callee);
+        names.contains(callee)
+            || self.imports.iter().flat_map(|i| &i.specifiers).any(|spec| {
+                spec.alias.as_deref() == Some(callee) && names.contains(&spec.name)
             })
-            .collect();
+    }
+}
 
-        let mut resolution_counts: FxHashMap<String, usize> = FxHashMap::default();
-        let mut diagnostics = ResolutionDiagnostics::new();
-        let mut resolved = 0usize;
-
-        for (caller_key, call_site, pr) in &call_entries {
-            let caller_language = pr.language.name();
-            if let Some(caller_idx) = graph.get_node(caller_key) {
-                // Try standard resolution chain first
-                let resolution_result = resolve_call(
-                    call_site,
-                    &pr.file,
-                    caller_language,
-                    &pr.imports,
-                    &name_index,
-                    &qualified_index,
-                    &export_index,
-                    &language_index,
-                );
-
-                // CG-RES-05: If standard resolution fails, try DI resolution
-                let resolution_result = resolution_result.or_else(|| {
-                    if !detected_frameworks.is_empty() {
-                        // Check if the callee name matches a DI-injected type
-                        di_support::resolve_di_injection(
-                            &call_site.callee_name,
-                            &name_index,
-                        )
-                    } else {
-                        None
-                    }
-                });
+impl IndexedSymbol {
+    /// Names a call must use to resolve to this symbol: the plain name and the
+    /// last segment of each qualified name.
+    fn lookup_names(&self) -> impl Iterator<Item = String> + '_ {
+        std::iter::once(self.name.clone()).chain(
+            self.qualified.iter().map(|(qn, _)| qn.rsplit('.').next().unwrap_or(qn).to_string()),
+        )
+    }
+}
 
-                // CG-RES-12: Record diagnostics
-                diagnostics.record(
-                    resolution_result.as_ref().map(|(_, r)| r),
-                    caller_language,
-                );
-
-                if let Some((callee_key, resolution)) = resolution_result {
-                    if let Some(callee_idx) = graph.get_node(&callee_key) {
-                        let edge = CallEdge {
-                            resolution,
-                            confidence: resolution.default_confidence(),
-                            call_site_line: call_site.line,
-                        };
-                        graph.add_edge(caller_idx, callee_idx, edge);
-                        *resolution_counts.entry(resolution.name().to_string()).or_default() += 1;
-                        resolved += 1;
-                    }
+/// Lookup indices for call resolution, filled file by file in build order.
+#[derive(Default)]
+struct ResolutionIndex {
+    name_index: FxHashMap<String, Vec<String>>,
+    qualified_index: FxHashMap<String, String>,
+    export_index: FxHashMap<String, Vec<String>>,
+    language_index: FxHashMap<String, String>,
+}
+
+impl ResolutionIndex {
+    fn add(&mut self, input: &FileResolution) {
+        for symbol in &input.symbols {
+            self.name_index.entry(symbol.name.clone()).or_default().push(symbol.key.clone());
+            self.language_index.insert(symbol.key.clone(), input.language.to_string());
+            for (qn, overwrite) in &symbol.qualified {
+                if *overwrite {
+                    self.qualified_index.insert(qn.clone(), symbol.key.clone());
+                } else {
+                    self.qualified_index.entry(qn.clone()).or_insert_with(|| symbol.key.clone());
                 }
             }
+            if symbol.is_exported {
+                self.export_index.entry(symbol.name.clone()).or_default().push(symbol.key.clone());
+            }
         }
+    }
+}
+
+/// Resolve `input.calls[i]`, recording the outcome and adding its edge.
+fn resolve_into(graph: &mut CallGraph, index: &ResolutionIndex, input: &mut FileResolution, i: usize, uses_di: bool) {
+    let call = &input.calls[i];
+    let Some(caller_idx) = graph.get_node(graph.symbols.function(call.caller_key)) else {
+        return;
+    };
+    let call_site = call.call_site.resolve(&graph.symbols);
+    // Try standard resolution chain first
+    let outcome = resolve_call(
+        &call_site,
+        &input.file,
+        input.language,
+        &input.imports,
+        &index.name_index,
+        &index.qualified_index,
+        &index.export_index,
+        &index.language_index,
+    )
+    // CG-RES-05: If standard resolution fails, try DI resolution
+    .or_else(|| {
+        // Check if the callee name matches a DI-injected type
+        uses_di.then(|| di_support::resolve_di_injection(&call_site.callee_name, &index.name_index)).flatten()
+    });
 
-        // CG-RES-12: Emit warnings for low resolution rates
-        for warning in diagnostics.low_resolution_warnings() {
-            tracing::warn!("{}", warning);
+    if let Some((ref callee_key, resolution)) = outcome {
+        if let Some(callee_idx) = graph.get_node(callee_key) {
+            let edge = CallEdge {
+                resolution,
+                confidence: resolution.default_confidence(),
+                call_site_line: call_site.line,
+            };
+            graph.add_edge(caller_idx, callee_idx, edge);
         }
+    }
+    input.calls[i].outcome = outcome.map(|(key, resolution)| (graph.symbols.function_id(&key), resolution));
+}
 
-        // Detect entry points
-        super::traversal::mark_entry_points(&mut graph, parse_results);
+/// Remove the edge a call previously resolved to, if both ends still exist.
+fn remove_call_edge(graph: &mut CallGraph, call: &FileCall, callee_key: FunctionId) {
+    let caller = graph.get_node(graph.symbols.function(call.caller_key));
+    let callee = graph.get_node(graph.symbols.function(callee_key));
+    let (Some(caller), Some(callee)) = (caller, callee) else {
+        return;
+    };
+    let edge = graph.graph
+        .edges_connecting(caller, callee)
+        .find(|e| e.weight().call_site_line == call.call_site.line)
+        .map(|e| e.id());
+    if let Some(edge) = edge {
+        graph.graph.remove_edge(edge);
+    }
+}
 
-        let total_calls = call_entries.len();
-        let stats = CallGraphStats {
-            total_functions: graph.function_count(),
-            total_edges: graph.edge_count(),
-            entry_points: graph.graph.node_indices()
-                .filter(|&idx| graph.graph[idx].is_entry_point)
-                .count(),
-            resolution_counts,
-            resolution_rate: if total_calls > 0 {
-                resolved as f64 / total_calls as f64
-            } else {
-                0.0
-            },
-            build_duration: start.elapsed(),
-            cycles_detected: 0,
-            diagnostics,
-        };
+/// Stats over the graph and the call outcomes recorded on it.
+fn graph_stats(graph: &CallGraph, start: Instant) -> CallGraphStats {
+    let mut resolution_counts: FxHashMap<String, usize> = FxHashMap::default();
+    let mut diagnostics = ResolutionDiagnostics::new();
+    let mut resolved = 0usize;
+    let mut total_calls = 0usize;
 
-        Ok((graph, stats))
+    for input in &graph.resolution_inputs {
+        for call in &input.calls {
+            total_calls += 1;
+            if graph.get_node(graph.symbols.function(call.caller_key)).is_none() {
+                continue;
+            }
+            // CG-RES-12: Record diagnostics
+            diagnostics.record(call.outcome.as_ref().map(|(_, r)| r), input.language);
+            if let Some((callee_key, resolution)) = call.outcome {
+                if graph.get_node(graph.symbols.function(callee_key)).is_some() {
+                    *resolution_counts.entry(resolution.name().to_string()).or_default() += 1;
+                    resolved += 1;
+                }
+            }
+        }
     }
-}
 
-impl Default for CallGraphBuilder {
-    fn default() -> Self {
-        Self::new()
+    // CG-RES-12: Emit warnings for low resolution rates
+    for warning in diagnostics.low_resolution_warnings() {
+        tracing::warn!("{}", warning);
+    }
+
+    CallGraphStats {
+        total_functions: graph.function_count(),
+        total_edges: graph.edge_count(),
+        entry_points: graph.graph.node_indices()
+            .filter(|&idx| graph.graph[idx].is_entry_point)
+            .count(),
+        resolution_counts,
+        resolution_rate: if total_calls > 0 {
+            resolved as f64 / total_calls as f64
+        } else {
+            0.0
+        },
+        build_duration: start.elapsed(),
+        cycles_detected: 0,
+        diagnostics,
     }
 }
 
+/// Function and class method nodes for one file.
+/// CG-RES-08: Build qualified names for module-level functions
+fn file_nodes(pr: &ParseResult) -> impl Iterator<Item = FunctionNode> + '_ {
+    let module_name = module_name_from_file(&pr.file);
+    // Top-level functions
+    let top_level = pr.functions.iter().map(move |f| {
+        let qn = f.qualified_name.clone().or_else(|| {
+            Some(format!("{}.{}", module_name, f.name))
+        });
+        FunctionNode {
+            file: pr.file.clone(),
+            name: f.name.clone(),
+            qualified_name: qn,
+            language: pr.language.name().to_string(),
+            line: f.line,
+            end_line: f.end_line,
+            is_entry_point: false, // Detected later
+            is_exported: f.is_exported,
+            signature_hash: f.signature_hash,
+            body_hash: f.body_hash,
+        }
+    });
+    // Also add class methods as graph nodes
+    let methods = pr.classes.iter().flat_map(move |class| {
+        class.methods.iter().map(move |m| {
+            let qn = m.qualified_name.clone().or_else(|| {
+                Some(format!("{}.{}", class.name, m.name))
+            });
+            FunctionNode {
+                file: pr.file.clone(),
+                name: format!("{}.{}", class.name, m.name),
+                qualified_name: qn,
+                language: pr.language.name().to_string(),
+                line: m.line,
+                end_line: m.end_line,
+                is_entry_point: false,
+                is_exported: m.is_exported || class.is_exported,
+                signature_hash: m.signature_hash,
+                body_hash: m.body_hash,
+            }
+        })
+    });
+    top_level.chain(methods)
+}
+
 /// Extract a module name from a file path.
 /// e.g., "src/utils/format.ts" → "format"
 /// e.g., "controllers/user.controller.ts" → "user.controller"
@@ -0,0 +1,102 @@
+//! GraphML export for Gephi, yEd and other graph viewers.
+//!
+//! Functions become nodes carrying `name`, `file`, `is_exported` and
+//! `is_entry_point`; call edges carry `status="resolved"` with their
+//! resolution strategy and confidence. Calls that did not resolve are kept
+//! as `status="unresolved"` edges into phantom nodes (`phantom=true`), one
+//! per distinct callee, so dangling calls stay visible instead of being
+//! dropped.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::types::CallGraph;
+
+const GRAPHML_KEYS: &str = r#"  <key id="name" for="node" attr.name="name" attr.type="string"/>
+  <key id="file" for="node" attr.name="file" attr.type="string"/>
+  <key id="is_exported" for="node" attr.name="is_exported" attr.type="boolean"/>
+  <key id="is_entry_point" for="node" attr.name="is_entry_point" attr.type="boolean"/>
+  <key id="phantom" for="node" attr.name="phantom" attr.type="boolean"><default>false</default></key>
+  <key id="status" for="edge" attr.name="status" attr.type="string"/>
+  <key id="resolution" for="edge" attr.name="resolution" attr.type="string"/>
+  <key id="confidence" for="edge" attr.name="confidence" attr.type="double"/>
+"#;
+
+/// Render `graph` as a GraphML document.
+pub fn to_graphml(graph: &CallGraph) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+    xml.push_str(GRAPHML_KEYS);
+    xml.push_str("  <graph id=\"calls\" edgedefault=\"directed\">\n");
+
+    for idx in graph.graph.node_indices() {
+        let node = &graph.graph[idx];
+        let _ = writeln!(xml, "    <node id=\"n{}\">", idx.index());
+        let _ = writeln!(xml, "      <data key=\"name\">{}</data>", escape(&node.name));
+        let _ = writeln!(xml, "      <data key=\"file\">{}</data>", escape(&node.file));
+        let _ = writeln!(xml, "      <data key=\"is_exported\">{}</data>", node.is_exported);
+        let _ = writeln!(xml, "      <data key=\"is_entry_point\">{}</data>", node.is_entry_point);
+        xml.push_str("    </node>\n");
+    }
+
+    // Unresolved calls, grouped by target so each phantom appears once
+    let mut phantoms: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for input in &graph.resolution_inputs {
+        for (caller_key, call_site) in input.unresolved_calls(&graph.symbols) {
+            let Some(caller) = graph.get_node(caller_key) else {
+                continue;
+            };
+            let target = match call_site.receiver {
+                Some(ref receiver) => format!("{}.{}", receiver, call_site.callee_name),
+                None => call_site.callee_name.clone(),
+            };
+            phantoms.entry(target).or_default().push(caller.index());
+        }
+    }
+    for (i, target) in phantoms.keys().enumerate() {
+        let _ = writeln!(xml, "    <node id=\"p{i}\">");
+        let _ = writeln!(xml, "      <data key=\"name\">{}</data>", escape(target));
+        xml.push_str("      <data key=\"phantom\">true</data>\n");
+        xml.push_str("    </node>\n");
+    }
+
+    let mut edge_id = 0usize;
+    for edge in graph.graph.edge_indices() {
+        let Some((caller, callee)) = graph.graph.edge_endpoints(edge) else {
+            continue;
+        };
+        let call = &graph.graph[edge];
+        let _ = writeln!(
+            xml,
+            "    <edge id=\"e{edge_id}\" source=\"n{}\" target=\"n{}\">",
+            caller.index(),
+            callee.index()
+        );
+        xml.push_str("      <data key=\"status\">resolved</data>\n");
+        let _ = writeln!(xml, "      <data key=\"resolution\">{}</data>", call.resolution.name());
+        let _ = writeln!(xml, "      <data key=\"confidence\">{}</data>", call.confidence);
+        xml.push_str("    </edge>\n");
+        edge_id += 1;
+    }
+    for (i, callers) in phantoms.values().enumerate() {
+        for caller in callers {
+            let _ = writeln!(xml, "    <edge id=\"e{edge_id}\" source=\"n{caller}\" target=\"p{i}\">");
+            xml.push_str("      <data key=\"status\">unresolved</data>\n");
+            xml.push_str("    </edge>\n");
+            edge_id += 1;
+        }
+    }
+
+    xml.push_str("  </graph>\n");
+    xml.push_str("</graphml>\n");
+    xml
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -42,7 +42,7 @@ impl IncrementalCallGraph {
     /// - `added`: newly added files (parse results)
     /// - `modified`: modified files (parse results)
     /// - `removed`: paths of removed files
-    /// - `all_results`: all current parse results (for re-resolution of affected edges)
+    /// - `all_results`: all current parse results (used when a full rebuild is cheaper)
     pub fn update(
         &mut self,
         added: &[ParseResult],
@@ -50,45 +50,27 @@ impl IncrementalCallGraph {
         removed: &[String],
         all_results: &[ParseResult],
     ) -> Result<CallGraphStats, drift_core::errors::CallGraphError> {
-        // Collect set of changed files for targeted re-resolution
-        let mut changed_files: Vec<String> = Vec::new();
-
-        // Remove nodes/edges for deleted files
-        for path in removed {
-            self.graph.remove_file(path);
-            changed_files.push(path.clone());
-        }
-
-        // Remove nodes/edges for modified files (will be re-added)
-        for pr in modified {
-            self.graph.remove_file(&pr.file);
-            changed_files.push(pr.file.clone());
-        }
-
-        // Track added files
-        for pr in added {
-            changed_files.push(pr.file.clone());
-        }
+        let changed_files = added.len() + modified.len() + removed.len();
 
         // If many files changed (>30% of total), full rebuild is more efficient
         let change_ratio = if all_results.is_empty() {
             1.0
         } else {
-            changed_files.len() as f64 / all_results.len() as f64
+            changed_files as f64 / all_results.len() as f64
         };
 
-        if change_ratio > 0.30 || changed_files.len() > 100 {
+        if change_ratio > 0.30 || changed_files > 100 {
             // Full rebuild — too many changes for incremental to be efficient
             let (graph, stats) = self.builder.build(all_results)?;
             self.graph = graph;
             return Ok(stats);
         }
 
-        // Targeted rebuild: only rebuild from all_results but this is still
-        // correct because builder.build handles the full resolution chain.
-        // The optimization is that we've already removed stale nodes/edges above,
-        // so the graph starts from a cleaner state.
-        let (graph, stats) = self.builder.build(all_results)?;
+        // Targeted rebuild: re-extract the changed files and re-resolve only
+        // the calls they can affect.
+        let changed: Vec<ParseResult> = added.iter().chain(modified).cloned().collect();
+        let previous = std::mem::take(&mut self.graph);
+        let (graph, stats) = self.builder.rebuild_incremental(previous, &changed, removed);
         self.graph = graph;
         Ok(stats)
     }
@@ -9,9 +9,11 @@ pub mod traversal;
 pub mod cte_fallback;
 pub mod incremental;
 pub mod di_support;
+pub mod export;
 
 pub use types::{CallGraph, FunctionNode, CallEdge, Resolution, CallGraphStats};
 pub use builder::CallGraphBuilder;
 pub use resolution::{ResolutionDiagnostics, is_fuzzy_blocked, resolve_call, resolve_constructor};
 pub use traversal::{bfs_forward, bfs_inverse, detect_entry_points};
 pub use incremental::IncrementalCallGraph;
+pub use export::to_graphml;
@@ -78,31 +78,38 @@ pub fn detect_entry_points(graph: &CallGraph) -> Vec<NodeIndex> {
 
 /// Mark entry points directly on the graph (mutable).
 pub fn mark_entry_points(graph: &mut CallGraph, parse_results: &[ParseResult]) {
-    // Build a set of route handler function names from decorators
-    let mut route_handlers: FxHashSet<String> = FxHashSet::default();
-    for pr in parse_results {
-        // CG-EP-01: Check function-level decorators
-        for func in &pr.functions {
-            if has_entry_point_decorator(&func.decorators) {
-                route_handlers.insert(format!("{}::{}", pr.file, func.name));
-            }
+    let route_handlers: FxHashSet<String> = parse_results.iter().flat_map(route_handler_keys).collect();
+    let indices: Vec<NodeIndex> = graph.graph.node_indices().collect();
+    mark_nodes(graph, &indices, &route_handlers);
+}
+
+/// Keys of the route handler functions declared in one file.
+pub(super) fn route_handler_keys(pr: &ParseResult) -> Vec<String> {
+    let mut route_handlers = Vec::new();
+    // CG-EP-01: Check function-level decorators
+    for func in &pr.functions {
+        if has_entry_point_decorator(&func.decorators) {
+            route_handlers.push(format!("{}::{}", pr.file, func.name));
         }
-        // CG-EP-01: Check class-level decorators (controllers) and their methods
-        for class in &pr.classes {
-            let is_controller = class.decorators.iter().any(|d| {
-                let dl = d.name.to_lowercase();
-                dl.contains("controller") || dl.contains("api") || dl.contains("resolver")
-            });
-            for method in &class.methods {
-                if has_entry_point_decorator(&method.decorators) || is_controller {
-                    route_handlers.insert(format!("{}::{}", pr.file, method.name));
-                }
+    }
+    // CG-EP-01: Check class-level decorators (controllers) and their methods
+    for class in &pr.classes {
+        let is_controller = class.decorators.iter().any(|d| {
+            let dl = d.name.to_lowercase();
+            dl.contains("controller") || dl.contains("api") || dl.contains("resolver")
+        });
+        for method in &class.methods {
+            if has_entry_point_decorator(&method.decorators) || is_controller {
+                route_handlers.push(format!("{}::{}", pr.file, method.name));
             }
         }
     }
+    route_handlers
+}
 
-    let indices: Vec<NodeIndex> = graph.graph.node_indices().collect();
-    for idx in indices {
+/// Mark the given nodes that are entry points by heuristic or route handler key.
+pub(super) fn mark_nodes(graph: &mut CallGraph, indices: &[NodeIndex], route_handlers: &FxHashSet<String>) {
+    for &idx in indices {
         let node = &graph.graph[idx];
         let key = format!("{}::{}", node.file, node.name);
         let is_entry = is_entry_point(node) || route_handlers.contains(&key);
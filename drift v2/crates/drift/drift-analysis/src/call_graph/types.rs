@@ -8,6 +8,10 @@ use petgraph::stable_graph::StableGraph;
 use petgraph::Directed;
 use serde::{Deserialize, Serialize};
 
+use crate::parsers::interned::SymbolTable;
+
+use super::builder::FileResolution;
+
 /// The call graph: a directed graph of function calls.
 pub struct CallGraph {
     /// The underlying petgraph StableGraph.
@@ -16,6 +20,10 @@ pub struct CallGraph {
     pub node_index: FxHashMap<String, NodeIndex>,
     /// Map from file → list of NodeIndex for file-level operations.
     pub file_nodes: FxHashMap<String, Vec<NodeIndex>>,
+    /// Per-file resolution inputs in build order, for `CallGraphBuilder::rebuild_incremental`.
+    pub(crate) resolution_inputs: Vec<FileResolution>,
+    /// Paths, names and keys interned by `resolution_inputs`.
+    pub(crate) symbols: SymbolTable,
 }
 
 impl CallGraph {
@@ -25,6 +33,8 @@ impl CallGraph {
             graph: StableGraph::new(),
             node_index: FxHashMap::default(),
             file_nodes: FxHashMap::default(),
+            resolution_inputs: Vec::new(),
+            symbols: SymbolTable::new(),
         }
     }
 
@@ -77,6 +87,7 @@ impl CallGraph {
                 self.graph.remove_node(*idx);
             }
         }
+        self.resolution_inputs.retain(|input| input.file != file);
     }
 }
 
@@ -0,0 +1,276 @@
+//! Inconsistent API error response shapes.
+//!
+//! Clients of a service written against `{ error: msg }` break on the one
+//! handler that answers `{ message }`, a bare string, or an error body with
+//! status 200. Error responses in request handlers (a response call carrying
+//! a 4xx/5xx status, sent from a `catch`/`except` block, or with an `error`
+//! key) are reduced to a shape: the sorted top-level keys of an object body,
+//! or `string`, plus `with status 200` when no error status is set. Shapes
+//! are treated as a multi-value convention; when one clearly dominates, the
+//! responses deviating from it are reported as advisories naming the
+//! majority shape and the deviating handlers.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::{closing_paren, split_top_level};
+use crate::detectors::handlers::{enclosing_function, in_request_handler};
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::patterns::learning::dirichlet::DirichletMultinomial;
+use crate::scanner::test_files::is_test_file;
+
+/// Response calls whose arguments carry the response body.
+pub const DEFAULT_RESPONSE_CALLS: &[&str] = &[
+    ".json(", ".send(", ".JSON(", ".body(", "jsonify(", "JsonResponse(", "JSONResponse(",
+    "HttpResponse(", "Response(",
+];
+
+/// Fewest error responses that establish a convention by default.
+pub const DEFAULT_MIN_RESPONSES: usize = 4;
+
+/// Status constant fragments that mark an error status.
+const ERROR_STATUS_NAMES: &[&str] = &[
+    "BAD_REQUEST", "UNAUTHORIZED", "FORBIDDEN", "NOT_FOUND", "CONFLICT", "UNPROCESSABLE",
+    "INTERNAL_SERVER_ERROR", "SERVICE_UNAVAILABLE", "StatusBadRequest", "StatusUnauthorized",
+    "StatusForbidden", "StatusNotFound", "StatusConflict", "StatusInternalServerError",
+];
+
+/// Posterior gap below which two shapes are both "the convention".
+const CONTESTED_THRESHOLD: f64 = 0.2;
+
+/// An error response found in a handler.
+struct ErrorResponse {
+    line: u32,
+    column: u32,
+    handler: String,
+    shape: String,
+}
+
+/// Flags error responses whose shape deviates from the one most handlers use.
+#[derive(Debug, Clone)]
+pub struct ErrorResponseShapeCheck {
+    response_calls: Vec<String>,
+    min_responses: usize,
+}
+
+impl ErrorResponseShapeCheck {
+    /// Create a check over custom response calls (fragments such as `.json(`).
+    pub fn new(response_calls: &[&str]) -> Self {
+        Self {
+            response_calls: response_calls.iter().map(|s| s.to_string()).collect(),
+            min_responses: DEFAULT_MIN_RESPONSES,
+        }
+    }
+
+    /// Add framework-specific response calls (e.g. `.reply(`).
+    pub fn with_response_calls(mut self, calls: &[&str]) -> Self {
+        self.response_calls.extend(calls.iter().map(|s| s.to_string()));
+        self
+    }
+
+    /// Require at least `min` error responses before a shape counts as the convention.
+    pub fn with_min_responses(mut self, min: usize) -> Self {
+        self.min_responses = min;
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        if is_test_file(ctx.file) {
+            return Vec::new();
+        }
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let responses = self.error_responses(ctx, text);
+        if responses.len() < self.min_responses.max(2) {
+            return Vec::new();
+        }
+
+        let mut labels: Vec<String> = Vec::new();
+        for response in &responses {
+            if !labels.contains(&response.shape) {
+                labels.push(response.shape.clone());
+            }
+        }
+        if labels.len() < 2 {
+            return Vec::new();
+        }
+        let mut shapes = DirichletMultinomial::new(labels.clone());
+        for response in &responses {
+            shapes.observe(labels.iter().position(|l| *l == response.shape).unwrap_or(0));
+        }
+        let Some((index, majority, _)) = shapes.dominant() else {
+            return Vec::new();
+        };
+        let majority_count = shapes.counts()[index] as usize;
+        if shapes.is_contested(CONTESTED_THRESHOLD) || majority_count * 2 <= responses.len() {
+            return Vec::new();
+        }
+
+        let deviating: Vec<&ErrorResponse> = responses.iter().filter(|r| r.shape != majority).collect();
+        let mut handlers: Vec<&str> = Vec::new();
+        for response in &deviating {
+            if !handlers.contains(&response.handler.as_str()) {
+                handlers.push(&response.handler);
+            }
+        }
+        deviating
+            .iter()
+            .map(|response| PatternMatch {
+                file: ctx.file.to_string(),
+                line: response.line,
+                column: response.column,
+                pattern_id: "API-ERROR-SHAPE-004".to_string(),
+                confidence: 0.50,
+                cwe_ids: SmallVec::new(),
+                owasp: None,
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Api,
+                matched_text: format!(
+                    "Error response in {} is `{}`; {} of {} error responses use `{}` (deviating: {})",
+                    response.handler,
+                    response.shape,
+                    majority_count,
+                    responses.len(),
+                    majority,
+                    handlers.join(", ")
+                ),
+            })
+            .collect()
+    }
+
+    /// Error responses inside request handlers, in source order.
+    fn error_responses(&self, ctx: &DetectionContext, text: &str) -> Vec<ErrorResponse> {
+        let mut calls: Vec<(usize, &str)> = Vec::new();
+        for call in &self.response_calls {
+            let bare = !call.starts_with('.');
+            for (start, _) in text.match_indices(call.as_str()) {
+                let preceded_by_name = text[..start]
+                    .chars()
+                    .next_back()
+                    .is_some_and(|c| c.is_alphanumeric() || c == '_');
+                if !(bare && preceded_by_name) {
+                    calls.push((start, call.as_str()));
+                }
+            }
+        }
+        calls.sort_by_key(|(start, _)| *start);
+
+        let lines: Vec<&str> = text.lines().collect();
+        let mut responses = Vec::new();
+        let mut covered_until = 0;
+        for (start, call) in calls {
+            if start < covered_until {
+                continue;
+            }
+            let open = start + call.len();
+            let Some(close) = closing_paren(&text[open..]).map(|i| open + i) else {
+                continue;
+            };
+            covered_until = close;
+            let line = text[..start].matches('\n').count() as u32;
+            if !in_request_handler(ctx, line) {
+                continue;
+            }
+            let args = split_top_level(&text[open..close], ',');
+            let Some(body) = args.iter().find_map(|a| body_shape(a)) else {
+                continue;
+            };
+
+            // The whole statement, so `res.status(404).json(...)` and
+            // Python's `return jsonify(...), 404` both see their status.
+            let line_start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+            let line_end = text[close..].find('\n').map_or(text.len(), |i| close + i);
+            let statement = &text[line_start..line_end];
+            let error_status = has_error_status(statement);
+            let in_catch = in_catch_block(&lines, line as usize);
+            let error_key = body
+                .trim_matches(['{', '}'])
+                .split(',')
+                .any(|key| matches!(key.trim(), "error" | "errors" | "err"));
+            if !(error_status || in_catch || error_key) {
+                continue;
+            }
+
+            let handler = match enclosing_function(ctx, line) {
+                Some(f) if f.name != "<anonymous>" => format!("`{}`", f.name),
+                Some(f) => format!("handler at line {}", f.line + 1),
+                None => format!("handler at line {}", line + 1),
+            };
+            let shape = if error_status { body } else { format!("{body} with status 200") };
+            responses.push(ErrorResponse {
+                line,
+                column: (start - line_start) as u32,
+                handler,
+                shape,
+            });
+        }
+        responses
+    }
+}
+
+impl Default for ErrorResponseShapeCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_RESPONSE_CALLS)
+    }
+}
+
+/// Shape of a body argument: `{ a, b }` for an object literal (keys sorted),
+/// `string` for a string literal. Keyword arguments (`content=`) and type
+/// prefixes (`gin.H{`) are skipped; other expressions have no known shape.
+fn body_shape(arg: &str) -> Option<String> {
+    let arg = arg.trim();
+    let arg = match arg.split_once('=') {
+        Some((name, value)) if !name.is_empty() && name.trim().chars().all(|c| c.is_alphanumeric() || c == '_') => value.trim(),
+        _ => arg,
+    };
+    if arg.starts_with(['"', '\'', '`']) {
+        return Some("string".to_string());
+    }
+    let brace = arg.find('{')?;
+    if !arg[..brace].chars().all(|c| c.is_alphanumeric() || matches!(c, '.' | '_' | '[' | ']')) || !arg.ends_with('}') {
+        return None;
+    }
+    let mut keys: Vec<String> = split_top_level(&arg[brace + 1..arg.len() - 1], ',')
+        .iter()
+        .filter_map(|entry| {
+            let entry = entry.trim();
+            if entry.is_empty() || entry.starts_with("...") || entry.starts_with("**") {
+                return None;
+            }
+            let key = split_top_level(entry, ':').first().copied().unwrap_or(entry);
+            let key = key.trim().trim_matches(['"', '\'']);
+            key.chars().all(|c| c.is_alphanumeric() || c == '_').then(|| key.to_string())
+        })
+        .collect();
+    keys.sort();
+    keys.dedup();
+    Some(if keys.is_empty() { "{}".to_string() } else { format!("{{ {} }}", keys.join(", ")) })
+}
+
+/// Whether a statement sets a 4xx/5xx status.
+fn has_error_status(statement: &str) -> bool {
+    let numeric = statement
+        .split(|c: char| !c.is_ascii_digit())
+        .any(|n| n.len() == 3 && (n.starts_with('4') || n.starts_with('5')));
+    numeric || ERROR_STATUS_NAMES.iter().any(|name| statement.contains(name))
+}
+
+/// Whether `line` sits in a `catch`, `except` or `rescue` block: the nearest
+/// less-indented line above it opens one.
+fn in_catch_block(lines: &[&str], line: usize) -> bool {
+    let indent = |l: &str| l.len() - l.trim_start().len();
+    let Some(current) = lines.get(line) else {
+        return false;
+    };
+    let own = indent(current);
+    lines[..line]
+        .iter()
+        .rev()
+        .filter(|l| !l.trim().is_empty())
+        .find(|l| indent(l) < own)
+        .is_some_and(|opener| {
+            let opener = opener.trim_start().trim_start_matches('}').trim_start();
+            ["catch", "except", "rescue"].iter().any(|k| opener.starts_with(k))
+        })
+}
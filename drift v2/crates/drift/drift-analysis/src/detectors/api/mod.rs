@@ -1,5 +1,7 @@
 //! API detector — endpoint patterns, REST conventions, versioning.
 
+pub mod error_shapes;
+
 use smallvec::SmallVec;
 
 use crate::detectors::traits::{Detector, DetectorCategory, DetectorVariant};
@@ -103,6 +105,8 @@ impl Detector for ApiDetector {
             }
         }
 
+        matches.extend(error_shapes::ErrorResponseShapeCheck::default().detect(ctx));
+
         matches
     }
 }
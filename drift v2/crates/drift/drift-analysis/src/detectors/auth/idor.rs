@@ -0,0 +1,178 @@
+//! Insecure direct object references (CWE-639) in request handlers.
+//!
+//! `Doc.findById(req.params.id)` returns whatever record the caller names.
+//! A fetch is flagged when it sits in a request handler, is keyed by an id
+//! taken from the request, and nothing in the handler ties the record to the
+//! current user: no ownership/authorization call, and no user-scoped receiver
+//! or query. Ownership is inferred from names only, so matches are advisory.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::{call_arguments, call_path};
+use crate::detectors::handlers::{enclosing_function, in_request_handler};
+use crate::detectors::sinks::SinkSet;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::{CallSite, FunctionInfo};
+
+/// Fetch-by-id sinks checked by default (Mongoose, Sequelize, Prisma,
+/// TypeORM, Django, Flask-SQLAlchemy, ActiveRecord, Spring Data), bare or
+/// qualified (see [`SinkSet`]).
+pub const DEFAULT_FETCH_SINKS: &[&str] = &[
+    "findById", "findByPk", "findOne", "findUnique", "findFirst", "find_by", "find_by_id",
+    "findOneBy", "getById", "get_by_id", "get_object_or_404", "get_or_404",
+];
+
+/// Generic fetch verbs, sinks only when called on a model class or a
+/// repository (`Document.find(id)`, `User.objects.get(pk=id)`,
+/// `userRepo.load(id)`); `cache.get(id)` and `map.get(id)` are not fetches.
+const REPOSITORY_VERBS: &[&str] = &["find", "get", "load"];
+
+/// Receiver name endings of ORM repositories, managers and sessions.
+const REPOSITORY_RECEIVERS: &[&str] = &["repo", "repository", "dao", "objects", "query", "manager", "session"];
+
+/// Request accessors that carry caller-controlled identifiers.
+const REQUEST_ID_SOURCES: &[&str] = &[
+    "req.params", "req.query", "req.body", "request.params", "request.query", "request.args",
+    "request.GET", "request.POST", "params[:", "params.id", "params[\"", "params['", "ctx.params",
+    "c.Param(", "kwargs[",
+];
+
+/// Expressions that reference the authenticated user.
+const CURRENT_USER_REFS: &[&str] = &[
+    "req.user", "request.user", "current_user", "currentUser", "ctx.state.user", "session.user",
+    "User.Identity", "getCurrentUser", "principal", "auth.user", "auth()",
+];
+
+/// Callee fragments that indicate an ownership or authorization check.
+const OWNERSHIP_CHECKS: &[&str] = &[
+    "owner", "authoriz", "authoris", "permission", "policy", "checkaccess", "ensureaccess",
+    "canaccess", "verifyaccess", "assertaccess", "can_access",
+];
+
+/// Flags fetch-by-request-id calls in handlers that lack an ownership check.
+#[derive(Debug, Clone)]
+pub struct IdorCheck {
+    fetch_sinks: SinkSet,
+}
+
+impl IdorCheck {
+    /// Create a check over a custom fetch-by-id sink set.
+    pub fn new(fetch_sinks: &[&str]) -> Self {
+        Self {
+            fetch_sinks: SinkSet::new(fetch_sinks),
+        }
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let mut matches = Vec::new();
+
+        for call in ctx.call_sites {
+            if !in_request_handler(ctx, call.line) {
+                continue;
+            }
+            let path = call_path(ctx.source, call).unwrap_or_default();
+            if !self.is_fetch(call, &path) {
+                continue;
+            }
+            if REQUEST_ID_SOURCES.iter().any(|s| path.contains(s)) || mentions_current_user(&path) {
+                // `req.params.get(...)` reads the request; `current_user.docs.find(...)` is scoped.
+                continue;
+            }
+            let args = call_arguments(ctx.source, call).unwrap_or_default();
+            let handler = enclosing_function(ctx, call.line);
+            let Some(id_arg) = args.iter().find(|a| is_request_id(a, handler)) else {
+                continue;
+            };
+            if args.iter().any(|a| mentions_current_user(a)) || has_ownership_check(ctx, call, handler) {
+                continue;
+            }
+
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: call.line,
+                column: call.column,
+                pattern_id: "AUTH-IDOR-004".to_string(),
+                confidence: 0.50,
+                cwe_ids: SmallVec::from_buf([639, 0]),
+                owasp: Some("A01:2021".to_string()),
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Auth,
+                matched_text: format!(
+                    "{}({id_arg}) fetches by a request-supplied id{} with no ownership check",
+                    path.trim(),
+                    handler.map(|f| format!(" in `{}`", f.name)).unwrap_or_default()
+                ),
+            });
+        }
+
+        matches
+    }
+
+    fn is_fetch(&self, call: &CallSite, path: &str) -> bool {
+        self.fetch_sinks.matching(call, path).is_some()
+            || (REPOSITORY_VERBS.contains(&call.callee_name.as_str()) && is_repository(receiver(path, call)))
+    }
+}
+
+impl Default for IdorCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_FETCH_SINKS)
+    }
+}
+
+/// Whether an argument is an id taken from the request: a request accessor,
+/// or a handler parameter named like an id (path parameters in FastAPI,
+/// Spring `@PathVariable`, ASP.NET route binding).
+fn is_request_id(arg: &str, handler: Option<&FunctionInfo>) -> bool {
+    if REQUEST_ID_SOURCES.iter().any(|s| arg.contains(s)) {
+        return true;
+    }
+    handler.is_some_and(|f| {
+        f.parameters.iter().any(|p| p.name == arg && looks_like_id(&p.name))
+    })
+}
+
+/// The last segment of the receiver in a call path: `objects` in
+/// `User.objects.get`, `userRepo` in `this.userRepo.load`.
+fn receiver<'a>(path: &'a str, call: &CallSite) -> &'a str {
+    let path = path.trim();
+    let receiver = path.strip_suffix(call.callee_name.as_str()).unwrap_or("");
+    let receiver = receiver.trim_end_matches(['.', ':', '?']);
+    receiver
+        .rsplit(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .next()
+        .unwrap_or("")
+}
+
+/// A model class (`Document`, `User`) or a repository-like object.
+fn is_repository(receiver: &str) -> bool {
+    if receiver.starts_with(|c: char| c.is_ascii_uppercase()) {
+        return true;
+    }
+    let lower = receiver.to_lowercase();
+    REPOSITORY_RECEIVERS.iter().any(|r| lower.ends_with(r))
+}
+
+fn looks_like_id(name: &str) -> bool {
+    let lower = name.to_lowercase();
+    lower == "id" || lower == "pk" || lower.ends_with("_id") || name.ends_with("Id")
+}
+
+fn mentions_current_user(text: &str) -> bool {
+    CURRENT_USER_REFS.iter().any(|r| text.contains(r))
+}
+
+/// Whether the handler calls anything that looks like an ownership check.
+/// Without an enclosing function the whole file is the scope.
+fn has_ownership_check(ctx: &DetectionContext, fetch: &CallSite, handler: Option<&FunctionInfo>) -> bool {
+    ctx.call_sites.iter().any(|c| {
+        let in_scope = handler.map_or(true, |f| f.line <= c.line && c.line <= f.end_line);
+        if !in_scope || std::ptr::eq(c, fetch) {
+            return false;
+        }
+        let lower = c.callee_name.to_lowercase();
+        matches!(lower.as_str(), "can" | "authorize" | "authorize!")
+            || OWNERSHIP_CHECKS.iter().any(|k| lower.contains(k))
+    })
+}
@@ -1,5 +1,8 @@
 //! Auth detector — authentication, authorization, JWT, session, and token patterns.
 
+pub mod idor;
+pub mod privileged_routes;
+
 use smallvec::SmallVec;
 
 use crate::detectors::traits::{Detector, DetectorCategory, DetectorVariant};
@@ -101,6 +104,9 @@ impl Detector for AuthDetector {
             }
         }
 
+        matches.extend(idor::IdorCheck::default().detect(ctx));
+        matches.extend(privileged_routes::PrivilegedRouteCheck::default().detect(ctx));
+
         matches
     }
 }
@@ -0,0 +1,130 @@
+//! Privileged routes registered without authentication (CWE-306).
+//!
+//! `router.get("/admin/users", listUsers)` with no auth middleware is the
+//! classic unprotected admin panel. A route registration is checked when its
+//! path string contains a privileged segment (`/admin`, `/internal`,
+//! `/debug`, `/actuator`, ...). It is flagged unless its registration,
+//! decorators, handler or a preceding `.use(...)` carries one of the server
+//! auth markers used by the client-only access-control check (middleware,
+//! guards, decorators, role checks). Markers are matched by name, so
+//! findings are advisory, but reported with high confidence.
+
+use smallvec::SmallVec;
+
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::scanner::test_files::is_test_file;
+use crate::structural::contracts::client_auth::{route_has_auth, DEFAULT_AUTH_MARKERS, DEFAULT_ROLE_MARKERS};
+
+/// Path prefixes that mark privileged scope by default.
+pub const DEFAULT_PRIVILEGED_PATHS: &[&str] = &[
+    "/admin", "/internal", "/_internal", "/debug", "/__debug__", "/actuator", "/management",
+    "/superadmin", "/staff",
+];
+
+/// Lowercased call and decorator fragments that register a route.
+const ROUTE_REGISTRATIONS: &[&str] = &[
+    ".get(", ".post(", ".put(", ".patch(", ".delete(", ".all(", ".use(", ".route(", ".group(",
+    ".handle(", "handlefunc(", "mapping(", "@get(", "@post(", "@put(", "@patch(", "@delete(",
+    "@controller(", "[route(", "[http", "route::",
+];
+
+/// Flags routes under privileged paths that lack an authentication guard.
+#[derive(Debug, Clone)]
+pub struct PrivilegedRouteCheck {
+    paths: Vec<Vec<String>>,
+    auth_markers: Vec<String>,
+}
+
+impl PrivilegedRouteCheck {
+    /// Create a check over custom privileged paths and auth markers.
+    pub fn new(paths: &[&str], auth_markers: &[&str]) -> Self {
+        Self {
+            paths: paths.iter().map(|p| segments(p)).filter(|s| !s.is_empty()).collect(),
+            auth_markers: auth_markers.iter().map(|s| s.to_lowercase()).collect(),
+        }
+    }
+
+    /// Add framework- or project-specific privileged paths (e.g. `/ops`).
+    pub fn with_paths(mut self, paths: &[&str]) -> Self {
+        self.paths.extend(paths.iter().map(|p| segments(p)).filter(|s| !s.is_empty()));
+        self
+    }
+
+    /// Add project-specific auth middleware or guards (e.g. `withSession`).
+    pub fn with_auth_markers(mut self, markers: &[&str]) -> Self {
+        self.auth_markers.extend(markers.iter().map(|s| s.to_lowercase()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        if is_test_file(ctx.file) {
+            return Vec::new();
+        }
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let mut matches: Vec<PatternMatch> = Vec::new();
+
+        for lit in &ctx.parse_result.string_literals {
+            if !lit.value.starts_with('/') || !self.is_privileged(&lit.value) {
+                continue;
+            }
+            let Some(line_text) = lines.get(lit.line as usize) else {
+                continue;
+            };
+            let lower = line_text.to_lowercase();
+            if !ROUTE_REGISTRATIONS.iter().any(|r| lower.contains(r)) {
+                continue;
+            }
+            if matches.iter().any(|m| m.line == lit.line) {
+                continue;
+            }
+            if route_has_auth(text, lit.line + 1, self.auth_markers.iter()) {
+                continue;
+            }
+
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: lit.line,
+                column: lit.column,
+                pattern_id: "AUTH-PRIVILEGED-006".to_string(),
+                confidence: 0.70,
+                cwe_ids: SmallVec::from_buf([306, 0]),
+                owasp: Some("A01:2021".to_string()),
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Auth,
+                matched_text: format!(
+                    "Privileged route {} is registered without authentication middleware or a guard",
+                    lit.value
+                ),
+            });
+        }
+
+        matches
+    }
+
+    /// Whether any configured path occurs as a run of whole segments in `route`.
+    fn is_privileged(&self, route: &str) -> bool {
+        let route = segments(route);
+        self.paths
+            .iter()
+            .any(|path| route.windows(path.len()).any(|w| w == path.as_slice()))
+    }
+}
+
+impl Default for PrivilegedRouteCheck {
+    fn default() -> Self {
+        let markers: Vec<&str> = DEFAULT_AUTH_MARKERS.iter().chain(DEFAULT_ROLE_MARKERS).copied().collect();
+        Self::new(DEFAULT_PRIVILEGED_PATHS, &markers)
+    }
+}
+
+/// Lowercased path segments, with trailing `*` wildcards removed.
+fn segments(path: &str) -> Vec<String> {
+    path.split('/')
+        .map(|s| s.trim_end_matches('*').to_lowercase())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
@@ -0,0 +1,213 @@
+//! Source-text helpers for inspecting call-site arguments.
+//!
+//! `CallSite` only records the argument count. Detectors that need to look at
+//! argument shapes recover the argument text from the source, starting at the
+//! call's recorded position and splitting on top-level commas.
+
+use crate::parsers::types::CallSite;
+
+/// Return the trimmed text of each top-level argument of a call.
+///
+/// Returns `None` if the argument list cannot be located or is unterminated.
+pub fn call_arguments(source: &[u8], call: &CallSite) -> Option<Vec<String>> {
+    let text = std::str::from_utf8(source).ok()?;
+    let start = byte_offset(text, call.line, call.column)?;
+    let rest = &text[start..];
+    let callee_at = rest.find(call.callee_name.as_str())?;
+    let open = callee_at + rest[callee_at..].find('(')?;
+    split_arguments(&rest[open + 1..])
+}
+
+/// Return the qualified callee text of a call, e.g. `Digest::MD5.hexdigest`.
+///
+/// Runs from the call's recorded position to the end of the callee name, so
+/// it recovers receivers the parser does not record.
+pub fn call_path(source: &[u8], call: &CallSite) -> Option<String> {
+    let text = std::str::from_utf8(source).ok()?;
+    let start = byte_offset(text, call.line, call.column)?;
+    let rest = &text[start..];
+    let callee_at = rest.find(call.callee_name.as_str())?;
+    Some(rest[..callee_at + call.callee_name.len()].to_string())
+}
+
+/// Return the source text following a call's closing `)`, e.g. `.name` in
+/// `users.find(match).name`.
+///
+/// Returns `None` if the argument list cannot be located or is unterminated.
+pub fn text_after_call<'a>(source: &'a [u8], call: &CallSite) -> Option<&'a str> {
+    let text = std::str::from_utf8(source).ok()?;
+    let start = byte_offset(text, call.line, call.column)?;
+    let rest = &text[start..];
+    let callee_at = rest.find(call.callee_name.as_str())?;
+    let open = callee_at + rest[callee_at..].find('(')?;
+    let close = closing_paren(&rest[open + 1..])?;
+    Some(&rest[open + 1 + close + 1..])
+}
+
+/// Whether an argument is a string built with top-level `+` concatenation,
+/// e.g. `"user " + name`. Quoted `+` characters are ignored.
+pub fn is_string_concatenation(arg: &str) -> bool {
+    let mut has_string = false;
+    let mut has_plus = false;
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for c in arg.chars() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' | '`' => {
+                quote = Some(c);
+                has_string = true;
+            }
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            '+' if depth == 0 => has_plus = true,
+            _ => {}
+        }
+    }
+
+    has_string && has_plus
+}
+
+/// Split on `separator` outside brackets and string literals.
+pub fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    split_outside(text, separator, false)
+}
+
+/// Split a type list such as `Base<K, V>, Api` on top-level commas, treating
+/// `<` and `>` as brackets too.
+pub fn split_type_list(text: &str) -> Vec<&str> {
+    split_outside(text, ',', true)
+}
+
+fn split_outside(text: &str, separator: char, angle_brackets: bool) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' | '`' => quote = Some(c),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            '<' if angle_brackets => depth += 1,
+            '>' if angle_brackets => depth = depth.saturating_sub(1),
+            c if c == separator && depth == 0 => {
+                parts.push(&text[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// Convert a 0-based (line, byte column) position into a byte offset.
+fn byte_offset(text: &str, line: u32, column: u32) -> Option<usize> {
+    let mut offset = 0usize;
+    for (i, l) in text.split_inclusive('\n').enumerate() {
+        if i == line as usize {
+            let col = column as usize;
+            return (col <= l.len()).then_some(offset + col);
+        }
+        offset += l.len();
+    }
+    None
+}
+
+/// Split the text following an opening `(` into top-level arguments.
+fn split_arguments(after_paren: &str) -> Option<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for c in after_paren.chars() {
+        if let Some(q) = quote {
+            current.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' | '`' => {
+                quote = Some(c);
+                current.push(c);
+            }
+            '(' | '[' | '{' => {
+                depth += 1;
+                current.push(c);
+            }
+            ')' if depth == 0 => {
+                let last = current.trim();
+                if !last.is_empty() {
+                    args.push(last.to_string());
+                }
+                return Some(args);
+            }
+            ')' | ']' | '}' => {
+                depth -= 1;
+                current.push(c);
+            }
+            ',' if depth == 0 => {
+                args.push(current.trim().to_string());
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+
+    None
+}
+
+/// Byte index of the `)` closing an argument list, given the text after `(`.
+pub fn closing_paren(after_paren: &str) -> Option<usize> {
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for (i, c) in after_paren.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' | '`' => quote = Some(c),
+            '(' | '[' | '{' => depth += 1,
+            ')' if depth == 0 => return Some(i),
+            ')' | ']' | '}' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    None
+}
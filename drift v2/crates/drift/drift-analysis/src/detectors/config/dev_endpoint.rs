@@ -0,0 +1,183 @@
+//! Development-only endpoints in shipped code.
+//!
+//! `apiUrl: "http://localhost:3000"` or an ngrok tunnel left in a service
+//! config works on the author's machine and fails (or leaks traffic) in
+//! production. String literals used as values — assignments, properties,
+//! arguments, returns — are flagged when their host is loopback, a `.local`
+//! name, a tunnel (ngrok) or a `test.`/`dev.` subdomain. Comparisons such as
+//! `host === "localhost"` are not values and are skipped, as are test files
+//! and dev-tooling configs. A literal used as the fallback of an environment
+//! lookup (`process.env.API_URL || "http://localhost:3000"`) is still
+//! reported, at lower confidence.
+
+use smallvec::SmallVec;
+
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::{StringContext, StringLiteralInfo};
+
+/// Hosts that only resolve on the developer's machine.
+const LOOPBACK_HOSTS: &[&str] = &["localhost", "0.0.0.0", "[::1]", "::1"];
+
+/// Host suffixes for local names and developer tunnels.
+const DEV_SUFFIXES: &[&str] = &[".localhost", ".local", ".ngrok.io", ".ngrok-free.app", ".ngrok.app", ".loca.lt"];
+
+/// Host prefixes for test and development subdomains.
+const DEV_PREFIXES: &[&str] = &["test.", "dev."];
+
+/// Path fragments of test files and dev-tooling configs, where local
+/// endpoints are intended.
+const EXCLUDED_PATHS: &[&str] = &[
+    "/test/", "/tests/", "/__tests__/", "/spec/", "/e2e/", "/fixtures/", "/__mocks__/", ".test.",
+    ".spec.", "_test.", "_spec.", "/test_", "conftest.py", "test.java", "tests.cs", "test.kt",
+    ".dev.", ".local.", "/development.", "vite.config", "webpack.config", "jest.config",
+    "playwright.config", "cypress.config", "karma.conf", ".storybook/",
+];
+
+/// Environment lookups whose fallback value is a development default.
+const ENV_LOOKUPS: &[&str] = &["process.env", "import.meta.env", "os.environ", "getenv", "ENV[", "env("];
+
+/// Flags development-only hosts in non-test source.
+#[derive(Debug, Clone, Default)]
+pub struct DevEndpointCheck {
+    allowed_hosts: Vec<String>,
+}
+
+impl DevEndpointCheck {
+    /// Create a check that also ignores the given hosts (and their subdomains).
+    pub fn new(allowed_hosts: &[&str]) -> Self {
+        Self {
+            allowed_hosts: allowed_hosts.iter().map(|h| h.to_lowercase()).collect(),
+        }
+    }
+
+    /// Add intentional local references (e.g. a sidecar on `localhost`) to the allowlist.
+    pub fn with_hosts(mut self, hosts: &[&str]) -> Self {
+        self.allowed_hosts.extend(hosts.iter().map(|h| h.to_lowercase()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        if is_excluded_path(ctx.file) {
+            return Vec::new();
+        }
+        let lines: Vec<&str> = std::str::from_utf8(ctx.source).map_or(Vec::new(), |t| t.lines().collect());
+        let mut matches = Vec::new();
+
+        for lit in &ctx.parse_result.string_literals {
+            if !is_value(lit) {
+                continue;
+            }
+            let Some((host, kind)) = endpoint_host(&lit.value) else {
+                continue;
+            };
+            if !is_dev_host(&host, kind) || self.is_allowed(&host) {
+                continue;
+            }
+            let line = lines.get(lit.line as usize).copied().unwrap_or("");
+            let (confidence, usage) = if ENV_LOOKUPS.iter().any(|e| line.contains(e)) {
+                (0.40, "default for an environment lookup")
+            } else if kind == HostKind::Url {
+                (0.65, "URL")
+            } else {
+                (0.50, "address")
+            };
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: lit.line,
+                column: lit.column,
+                pattern_id: "CFG-DEV-ENDPOINT-005".to_string(),
+                confidence,
+                cwe_ids: SmallVec::new(),
+                owasp: Some("A05:2021".to_string()),
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Config,
+                matched_text: format!("Development-only {usage} {host} in shipped code — read it from configuration"),
+            });
+        }
+
+        matches
+    }
+
+    fn is_allowed(&self, host: &str) -> bool {
+        self.allowed_hosts
+            .iter()
+            .any(|a| host == a || host.ends_with(&format!(".{a}")))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HostKind {
+    /// `scheme://host...`
+    Url,
+    /// `host:port`, or a bare loopback host.
+    Address,
+}
+
+/// Literals in value positions; comparisons and lookups land in `Unknown`.
+fn is_value(lit: &StringLiteralInfo) -> bool {
+    matches!(
+        lit.context,
+        StringContext::VariableAssignment
+            | StringContext::ObjectProperty
+            | StringContext::FunctionArgument
+            | StringContext::ReturnValue
+            | StringContext::ArrayElement
+    )
+}
+
+/// Lowercased host of a URL (`http://localhost:3000/api`), a `host:port`
+/// address, or a bare loopback host name.
+fn endpoint_host(value: &str) -> Option<(String, HostKind)> {
+    let value = value.trim();
+    if value.contains(char::is_whitespace) {
+        return None;
+    }
+    let (rest, kind) = match value.split_once("://") {
+        Some((scheme, rest)) if !scheme.is_empty() && scheme.chars().all(|c| c.is_ascii_alphanumeric() || c == '+') => {
+            (rest, HostKind::Url)
+        }
+        Some(_) => return None,
+        None => (value, HostKind::Address),
+    };
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or("");
+    let authority = authority.rsplit('@').next().unwrap_or(authority);
+    let (host, has_port) = if authority.starts_with('[') {
+        let end = authority.find(']')?;
+        (&authority[..=end], authority.len() > end + 1)
+    } else if authority == "::1" {
+        (authority, false)
+    } else {
+        match authority.split_once(':') {
+            Some((host, port)) => (host, port.chars().all(|c| c.is_ascii_digit()) && !port.is_empty()),
+            None => (authority, false),
+        }
+    };
+    let is_literal_host = !host.is_empty()
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '[' | ']' | ':'));
+    if !is_literal_host {
+        return None;
+    }
+    let host = host.to_lowercase();
+    // Without a scheme, only `host:port` or a bare loopback name is an endpoint;
+    // `0.0.0.0` alone is a bind address.
+    if kind == HostKind::Address && !has_port && !matches!(host.as_str(), "localhost" | "127.0.0.1") {
+        return None;
+    }
+    Some((host, kind))
+}
+
+/// Subdomain prefixes only count in URLs: `test.ts:12` is a file position.
+fn is_dev_host(host: &str, kind: HostKind) -> bool {
+    LOOPBACK_HOSTS.contains(&host)
+        || host.starts_with("127.")
+        || DEV_SUFFIXES.iter().any(|s| host.ends_with(s))
+        || kind == HostKind::Url && DEV_PREFIXES.iter().any(|p| host.starts_with(p) && host.len() > p.len())
+}
+
+fn is_excluded_path(file: &str) -> bool {
+    let path = format!("/{}", file.replace('\\', "/").to_lowercase());
+    EXCLUDED_PATHS.iter().any(|p| path.contains(p))
+}
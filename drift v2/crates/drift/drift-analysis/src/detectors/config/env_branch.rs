@@ -0,0 +1,185 @@
+//! Environment-dependent branches that change security behavior.
+//!
+//! `if (process.env.NODE_ENV !== 'production') { disableAuth() }` means the
+//! code under test is not the code in production. A branch is reported when
+//! its condition reads a known environment accessor and its body calls
+//! something security-relevant (auth/CSRF/TLS bypass, verbose errors).
+//! Branch bodies are recovered from the source text, so this is advisory.
+
+use smallvec::SmallVec;
+
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::CallSite;
+use crate::scanner::language_detect::Language;
+
+/// Substrings of a condition that read the runtime environment.
+const ENV_ACCESSORS: &[&str] = &[
+    "process.env", "NODE_ENV", "import.meta.env", "__DEV__", "settings.DEBUG", "app.debug",
+    "os.environ", "os.getenv", "getenv(", "ENV[", "Rails.env", "APP_ENV", "debug_assertions",
+    "isDevelopment", "IsDevelopment", "isProduction", "IsProduction",
+];
+
+/// Callee fragments that weaken security when combined with a toggle verb.
+const SECURITY_SUBJECTS: &[&str] = &[
+    "auth", "csrf", "ssl", "tls", "verif", "cors", "security", "login", "permission", "cert",
+];
+const TOGGLE_VERBS: &[&str] = &["disable", "skip", "bypass", "allow", "permit", "insecure", "noop"];
+
+/// Callees that expose internals (stack traces, debug output) in responses.
+const VERBOSE_ERROR_CALLEES: &[&str] = &[
+    "printstacktrace", "setdebug", "enabledebug", "showerrors", "dumpexceptions",
+    "usedeveloperexceptionpage", "errorhandler",
+];
+
+/// Flags environment checks whose branch body alters security behavior.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EnvBranchCheck;
+
+impl EnvBranchCheck {
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let line_starts: Vec<usize> = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        let indentation_blocks = matches!(ctx.language, Language::Python | Language::Ruby);
+
+        let mut matches = Vec::new();
+        for (idx, line) in text.lines().enumerate() {
+            let Some(condition) = env_condition(line) else {
+                continue;
+            };
+            let start = line_starts[idx];
+            let body = if indentation_blocks {
+                indented_body(text, &line_starts, idx)
+            } else {
+                braced_body(text, start + line.find(condition).unwrap_or(0))
+            };
+            let Some((body_start, body_end)) = body else {
+                continue;
+            };
+
+            let risky: Vec<&CallSite> = ctx
+                .call_sites
+                .iter()
+                .filter(|c| {
+                    let offset = line_starts
+                        .get(c.line as usize)
+                        .map(|s| s + c.column as usize);
+                    offset.is_some_and(|o| body_start <= o && o < body_end)
+                        && is_security_relevant(&c.callee_name)
+                })
+                .collect();
+            let Some(first) = risky.first() else {
+                continue;
+            };
+
+            let callees: Vec<&str> = risky.iter().map(|c| c.callee_name.as_str()).collect();
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: idx as u32,
+                column: (line.len() - line.trim_start().len()) as u32,
+                pattern_id: "CFG-ENV-BRANCH-004".to_string(),
+                confidence: 0.55,
+                cwe_ids: SmallVec::from_buf([489, 0]),
+                owasp: Some("A05:2021".to_string()),
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Config,
+                matched_text: format!(
+                    "Environment-dependent branch `{}` changes security behavior: {}() (line {})",
+                    condition.trim(),
+                    callees.join("(), "),
+                    first.line
+                ),
+            });
+        }
+        matches
+    }
+}
+
+/// If the line opens an environment-keyed branch, return its condition text.
+fn env_condition(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start().trim_start_matches('}').trim_start();
+    let trimmed = trimmed.strip_prefix("else").map(str::trim_start).unwrap_or(trimmed);
+
+    if trimmed.starts_with("#[cfg(debug_assertions)]") {
+        return Some("#[cfg(debug_assertions)]");
+    }
+    let condition = ["if ", "if(", "elif ", "elsif ", "unless "]
+        .iter()
+        .find_map(|kw| trimmed.strip_prefix(kw).map(|_| trimmed))?;
+
+    let reads_env = ENV_ACCESSORS.iter().any(|a| condition.contains(a))
+        || contains_word(condition, "DEBUG");
+    reads_env.then_some(condition)
+}
+
+fn contains_word(text: &str, word: &str) -> bool {
+    text.match_indices(word).any(|(i, _)| {
+        let before = text[..i].chars().next_back();
+        let after = text[i + word.len()..].chars().next();
+        let is_ident = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+        !is_ident(before) && !is_ident(after)
+    })
+}
+
+/// Byte range of the `{ ... }` block following `from`, skipping quoted text.
+fn braced_body(text: &str, from: usize) -> Option<(usize, usize)> {
+    let bytes = text.as_bytes();
+    let open = from + text[from..].find('{')?;
+    let mut depth = 0usize;
+    let mut quote: Option<u8> = None;
+    let mut i = open;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if let Some(q) = quote {
+            if b == b'\\' {
+                i += 1;
+            } else if b == q {
+                quote = None;
+            }
+        } else {
+            match b {
+                b'"' | b'\'' | b'`' => quote = Some(b),
+                b'{' => depth += 1,
+                b'}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some((open, i));
+                    }
+                }
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Byte range of the lines indented deeper than line `idx`.
+fn indented_body(text: &str, line_starts: &[usize], idx: usize) -> Option<(usize, usize)> {
+    let lines: Vec<&str> = text.lines().collect();
+    let indent = |l: &str| l.len() - l.trim_start().len();
+    let base = indent(lines[idx]);
+
+    let mut last = idx;
+    for (i, line) in lines.iter().enumerate().skip(idx + 1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if indent(line) <= base {
+            break;
+        }
+        last = i;
+    }
+    (last > idx).then(|| (line_starts[idx + 1], line_starts[last] + lines[last].len()))
+}
+
+fn is_security_relevant(callee: &str) -> bool {
+    let lower = callee.to_lowercase();
+    VERBOSE_ERROR_CALLEES.contains(&lower.as_str())
+        || (TOGGLE_VERBS.iter().any(|v| lower.contains(v))
+            && SECURITY_SUBJECTS.iter().any(|s| lower.contains(s)))
+}
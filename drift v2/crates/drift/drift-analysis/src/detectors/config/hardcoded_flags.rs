@@ -0,0 +1,146 @@
+//! Hard-coded feature flags and kill switches left in a risky state (CWE-489).
+//!
+//! `const SKIP_AUTH = true` or Django's `DEBUG = True` is fine on a laptop and
+//! a hole once shipped. Named boolean constants are checked by name: bypasses
+//! (`SKIP_*`, `BYPASS_*`, `DISABLE_*`) and toggles (`DEBUG`, `ENABLE_*`,
+//! `DEV_MODE`) are risky when `true`; safeguards (`VERIFY_*`, `REQUIRE_*`,
+//! and `ENABLE_*` of a security control such as `ENABLE_CSRF`) when `false`.
+//! Only SCREAMING_CASE constants count, and test files are skipped. A flag
+//! is an advisory config smell unless its name is security-relevant (auth,
+//! TLS, CSRF, admin, ...), in which case it is escalated.
+
+use smallvec::SmallVec;
+
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::scanner::language_detect::Language;
+use crate::scanner::test_files::is_test_file;
+use crate::structural::constants::extractor::extract_constants;
+
+/// Name fragments of security-relevant flags checked by default.
+pub const DEFAULT_SECURITY_FLAG_NAMES: &[&str] = &[
+    "AUTH", "LOGIN", "PASSWORD", "ADMIN", "PERMISSION", "ACL", "CSRF", "CORS", "SSL", "TLS",
+    "CERT", "SIGNATURE", "SECURITY", "SANDBOX", "RATE_LIMIT", "ENCRYPT", "2FA", "MFA",
+];
+
+/// Name fragments that turn something off; risky when `true`.
+const BYPASS_NAMES: &[&str] = &["SKIP", "BYPASS", "DISABLE", "INSECURE", "UNSAFE", "IGNORE"];
+
+/// Name fragments of toggles that are risky when left `true`.
+const TOGGLE_NAMES: &[&str] = &[
+    "DEBUG", "ENABLE", "ALLOW", "FEATURE", "FF_", "KILL_SWITCH", "DEV_MODE", "TEST_MODE",
+    "MAINTENANCE_MODE", "MOCK", "FORCE",
+];
+
+/// Name fragments of safeguards that are risky when left `false`.
+const SAFEGUARD_NAMES: &[&str] = &["VERIFY", "REQUIRE", "ENFORCE", "VALIDATE", "CHECK"];
+
+/// Flags boolean constants that look like feature flags or debug toggles set riskily.
+#[derive(Debug, Clone)]
+pub struct HardcodedFlagCheck {
+    security_names: Vec<String>,
+}
+
+impl HardcodedFlagCheck {
+    /// Create a check over a custom set of security-relevant name fragments.
+    pub fn new(security_names: &[&str]) -> Self {
+        Self {
+            security_names: security_names.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Add project-specific security name fragments (e.g. `PAYWALL`) to the current set.
+    pub fn with_security_names(mut self, names: &[&str]) -> Self {
+        self.security_names.extend(names.iter().map(|s| s.to_string()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let Some(language) = constants_language(ctx.language) else {
+            return Vec::new();
+        };
+        if is_test_file(ctx.file) {
+            return Vec::new();
+        }
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let mut matches = Vec::new();
+
+        for constant in extract_constants(text, ctx.file, language) {
+            let name = constant.name.as_str();
+            if !is_screaming_case(name) {
+                continue;
+            }
+            let value = constant.value.split_whitespace().next().unwrap_or("").trim_end_matches([';', ',']);
+            let enabled = match value.to_lowercase().as_str() {
+                "true" => true,
+                "false" => false,
+                _ => continue,
+            };
+            let security = self.security_names.iter().any(|s| name.contains(s.as_str()));
+            let risky = if BYPASS_NAMES.iter().any(|s| name.contains(s)) {
+                enabled
+            } else if SAFEGUARD_NAMES.iter().any(|s| name.contains(s)) || (security && name.contains("ENABLE")) {
+                !enabled
+            } else {
+                enabled && TOGGLE_NAMES.iter().any(|s| name.contains(s))
+            };
+            if !risky {
+                continue;
+            }
+
+            let (confidence, owasp, note) = if security {
+                (0.75, Some("A05:2021".to_string()), "security-relevant flag")
+            } else {
+                (0.40, None, "flag")
+            };
+            // Constant lines are 1-based; pattern matches are 0-based.
+            let line = constant.line.saturating_sub(1);
+            let column = lines.get(line as usize).and_then(|l| l.find(name)).unwrap_or(0) as u32;
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line,
+                column,
+                pattern_id: "CFG-FLAG-004".to_string(),
+                confidence,
+                cwe_ids: SmallVec::from_buf([489, 0]),
+                owasp,
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Config,
+                matched_text: format!("Hard-coded {note} {name} = {value} — read it from configuration"),
+            });
+        }
+
+        matches
+    }
+}
+
+impl Default for HardcodedFlagCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_SECURITY_FLAG_NAMES)
+    }
+}
+
+/// Language key used by the constant extractor, for the languages it supports.
+fn constants_language(language: Language) -> Option<&'static str> {
+    Some(match language {
+        Language::TypeScript => "typescript",
+        Language::JavaScript => "javascript",
+        Language::Python => "python",
+        Language::Java => "java",
+        Language::Kotlin => "kotlin",
+        Language::Rust => "rust",
+        Language::Go => "go",
+        Language::CSharp => "csharp",
+        Language::Ruby => "ruby",
+        Language::Php => "php",
+        _ => return None,
+    })
+}
+
+fn is_screaming_case(name: &str) -> bool {
+    name.chars().any(|c| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
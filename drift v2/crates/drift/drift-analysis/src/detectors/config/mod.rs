@@ -1,5 +1,9 @@
 //! Config detector — environment variables, feature flags, configuration files.
 
+pub mod dev_endpoint;
+pub mod env_branch;
+pub mod hardcoded_flags;
+
 use smallvec::SmallVec;
 
 use crate::detectors::traits::{Detector, DetectorCategory, DetectorVariant};
@@ -108,6 +112,10 @@ impl Detector for ConfigDetector {
             }
         }
 
+        matches.extend(env_branch::EnvBranchCheck.detect(ctx));
+        matches.extend(dev_endpoint::DevEndpointCheck::default().detect(ctx));
+        matches.extend(hardcoded_flags::HardcodedFlagCheck::default().detect(ctx));
+
         matches
     }
 }
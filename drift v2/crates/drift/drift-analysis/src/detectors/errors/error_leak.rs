@@ -0,0 +1,293 @@
+//! Error details exposed to clients (CWE-209).
+//!
+//! `res.send(err.stack)`, `return str(e), 500` or
+//! `ResponseEntity.status(500).body(e.getMessage())` hand stack traces, SQL
+//! errors and file paths to the caller. A response sink is flagged when it
+//! receives a stack trace, or the error bound by the enclosing catch clause
+//! (or an error-middleware parameter). Logging the error and returning a
+//! generic message is not flagged, nor are status/code fields or output
+//! guarded by a development-mode check. Error bindings are read from the
+//! source text, so matches are advisory.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::call_arguments;
+use crate::detectors::handlers::in_request_handler;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::{CallSite, ErrorHandlingInfo, ErrorHandlingKind};
+use crate::scanner::language_detect::Language;
+
+/// Calls that write a client response, checked by default: Express/Koa
+/// (`send`, `json`, `end`, `write`, `render`), Flask/Django (`jsonify`,
+/// `make_response`, `abort`, `HttpResponse`, `JsonResponse`), Spring (`body`),
+/// ASP.NET (`BadRequest`, `Problem`, `StatusCode`, `Content`), Go (`http.Error`,
+/// gin `JSON`), and HTTP exceptions frameworks render verbatim.
+pub const DEFAULT_RESPONSE_SINKS: &[&str] = &[
+    "send", "json", "jsonp", "end", "write", "render", "jsonify", "make_response", "abort",
+    "Response", "HttpResponse", "JsonResponse", "HttpResponseServerError", "HttpResponseBadRequest",
+    "body", "BadRequest", "Problem", "StatusCode", "Content", "InternalServerError", "Error",
+    "JSON", "HttpException", "HTTPException", "ResponseStatusException",
+    "InternalServerErrorException", "BadRequestException", "createError",
+];
+
+/// Exceptions that only reach the client when thrown from a request handler.
+const THROWN_SINKS: &[&str] = &[
+    "Error", "HttpException", "HTTPException", "ResponseStatusException",
+    "InternalServerErrorException", "BadRequestException", "createError",
+];
+
+/// Receivers that are response objects; other receivers need a request handler.
+const RESPONSE_RECEIVERS: &[&str] = &["res", "response", "resp", "reply", "ctx", "c", "w", "http", "ResponseEntity"];
+
+/// Expressions that produce a stack trace.
+const STACK_MARKERS: &[&str] = &[
+    ".stack", "format_exc", "print_exc", "format_exception", "getStackTrace", "printStackTrace",
+    "StackTrace", "__traceback__", "backtrace",
+];
+
+/// Error fields that are safe to return.
+const SAFE_FIELDS: &[&str] = &[".code", ".status", ".statusCode", ".status_code", ".name", ".httpStatus"];
+
+/// Development-mode guards under which detailed errors are intended.
+const DEV_GUARDS: &[&str] = &["NODE_ENV", "isDev", "is_dev", "DEBUG", ".debug", "development", "Development"];
+
+/// Handler parameters that hold the error in error middleware.
+const ERROR_PARAMS: &[&str] = &["err", "error"];
+
+/// Flags error details and stack traces written to client responses.
+#[derive(Debug, Clone)]
+pub struct ErrorLeakCheck {
+    sinks: Vec<String>,
+}
+
+impl ErrorLeakCheck {
+    /// Create a check over a custom response sink set.
+    pub fn new(sinks: &[&str]) -> Self {
+        Self {
+            sinks: sinks.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Add framework-specific response writers (e.g. `sendError`) to the current set.
+    pub fn with_sinks(mut self, sinks: &[&str]) -> Self {
+        self.sinks.extend(sinks.iter().map(|s| s.to_string()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let scopes = error_scopes(ctx, &lines);
+        let mut matches = Vec::new();
+        let mut flagged_lines = Vec::new();
+
+        for call in ctx.call_sites {
+            if !self.sinks.contains(&call.callee_name) {
+                continue;
+            }
+            let line_text = lines.get(call.line as usize).copied().unwrap_or("");
+            if !reaches_client(ctx, call, line_text) || DEV_GUARDS.iter().any(|g| line_text.contains(g)) {
+                continue;
+            }
+            let args = call_arguments(ctx.source, call).unwrap_or_default();
+            let vars = bound_errors(&scopes, call.line);
+            let Some((confidence, what)) = args.iter().find_map(|a| leaked(a, &vars)) else {
+                continue;
+            };
+            flagged_lines.push(call.line);
+            matches.push(leak_match(ctx, call.line, call.column, confidence, format!("{}() sends {what} to the client", call.callee_name)));
+        }
+
+        // Python views also respond by returning a value (`return str(e), 500`).
+        if ctx.language == Language::Python {
+            for scope in &scopes {
+                let end = scope.end.min(lines.len().saturating_sub(1));
+                for (idx, line) in lines.iter().enumerate().take(end + 1).skip(scope.start) {
+                    let Some(value) = line.trim_start().strip_prefix("return ") else {
+                        continue;
+                    };
+                    if flagged_lines.contains(&(idx as u32)) || !in_request_handler(ctx, idx as u32) {
+                        continue;
+                    }
+                    if DEV_GUARDS.iter().any(|g| line.contains(g)) {
+                        continue;
+                    }
+                    if let Some((confidence, what)) = leaked(value, &[scope.name.as_str()]) {
+                        let column = (line.len() - line.trim_start().len()) as u32;
+                        matches.push(leak_match(ctx, idx as u32, column, confidence, format!("view returns {what} to the client")));
+                    }
+                }
+            }
+        }
+
+        matches
+    }
+}
+
+impl Default for ErrorLeakCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_RESPONSE_SINKS)
+    }
+}
+
+/// Whether a sink call writes to the client: a call on a response object,
+/// or an exception or bare response constructor (`jsonify`, `BadRequest`)
+/// inside a request handler.
+fn reaches_client(ctx: &DetectionContext, call: &CallSite, line_text: &str) -> bool {
+    let receiver = call.receiver.as_deref().unwrap_or("");
+    if call.callee_name == "Error" && receiver == "http" {
+        return true;
+    }
+    if THROWN_SINKS.contains(&call.callee_name.as_str()) {
+        let thrown = line_text.contains("throw") || line_text.contains("raise");
+        return in_request_handler(ctx, call.line) && (thrown || call.callee_name != "Error");
+    }
+    let receiver_root = receiver.split(['.', '(']).next().unwrap_or("");
+    RESPONSE_RECEIVERS.contains(&receiver_root) || in_request_handler(ctx, call.line)
+}
+
+/// An error variable and the lines (0-based, inclusive) where it is bound.
+struct ErrorScope {
+    name: String,
+    start: usize,
+    end: usize,
+}
+
+/// Error bindings from catch clauses and error-middleware parameters.
+fn error_scopes(ctx: &DetectionContext, lines: &[&str]) -> Vec<ErrorScope> {
+    let mut scopes = Vec::new();
+    for eh in &ctx.parse_result.error_handling {
+        scopes.extend(catch_clauses(ctx.language, eh, lines));
+    }
+    for func in ctx.functions {
+        let is_middleware = func.parameters.iter().any(|p| p.name == "next" || p.name == "res");
+        if !is_middleware {
+            continue;
+        }
+        if let Some(param) = func.parameters.iter().find(|p| ERROR_PARAMS.contains(&p.name.as_str())) {
+            scopes.push(ErrorScope {
+                name: param.name.clone(),
+                start: func.line as usize,
+                end: func.end_line as usize,
+            });
+        }
+    }
+    scopes
+}
+
+/// The catch clauses of a try statement (or promise `.catch`), each bound
+/// from its clause line to the next clause or the end of the statement.
+fn catch_clauses(language: Language, eh: &ErrorHandlingInfo, lines: &[&str]) -> Vec<ErrorScope> {
+    if !matches!(
+        eh.kind,
+        ErrorHandlingKind::TryCatch
+            | ErrorHandlingKind::TryExcept
+            | ErrorHandlingKind::AsyncAwaitTry
+            | ErrorHandlingKind::PromiseCatch
+            | ErrorHandlingKind::Rescue
+    ) {
+        return Vec::new();
+    }
+    let end = (eh.end_line as usize).min(lines.len().saturating_sub(1));
+    let mut clauses: Vec<(usize, String)> = Vec::new();
+    for (idx, line) in lines.iter().enumerate().take(end + 1).skip(eh.line as usize) {
+        if let Some(name) = catch_binding(language, line) {
+            clauses.push((idx, name));
+        }
+    }
+    let mut scopes = Vec::new();
+    for (i, (start, name)) in clauses.iter().enumerate() {
+        let until = clauses.get(i + 1).map_or(end, |(next, _)| next.saturating_sub(1));
+        scopes.push(ErrorScope { name: name.clone(), start: *start, end: until });
+    }
+    scopes
+}
+
+/// The variable a catch clause on `line` binds the error to, if any.
+fn catch_binding(language: Language, line: &str) -> Option<String> {
+    let ident = |s: &str| -> Option<String> {
+        let s = s.trim().trim_start_matches('$');
+        (!s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_')).then(|| s.to_string())
+    };
+    match language {
+        Language::Python => {
+            let clause = line.trim_start().strip_prefix("except")?;
+            let (_, name) = clause.rsplit_once(" as ")?;
+            ident(name.trim_end().trim_end_matches(':'))
+        }
+        Language::Ruby => {
+            let clause = line.trim_start().strip_prefix("rescue")?;
+            let (_, name) = clause.rsplit_once("=>")?;
+            ident(name)
+        }
+        _ => {
+            let at = line.find("catch")?;
+            let rest = line[at + "catch".len()..].trim_start();
+            // `catch (err)`, `catch (SQLException e)`, `.catch(err => ...)`,
+            // `.catch((err) => ...)`, `.catch(function (err) {`.
+            let rest = rest.strip_prefix('(')?.trim_start();
+            let rest = rest.strip_prefix("function").unwrap_or(rest).trim_start();
+            let rest = rest.trim_start_matches('(');
+            let binding = rest
+                .split([')', '='])
+                .next()?
+                .split(':')
+                .next()?
+                .split_whitespace()
+                .last()?;
+            ident(binding)
+        }
+    }
+}
+
+/// Error variables bound at `line`.
+fn bound_errors(scopes: &[ErrorScope], line: u32) -> Vec<&str> {
+    let line = line as usize;
+    scopes
+        .iter()
+        .filter(|s| s.start <= line && line <= s.end)
+        .map(|s| s.name.as_str())
+        .collect()
+}
+
+/// Whether a response value carries error details, as (confidence, description).
+fn leaked(value: &str, vars: &[&str]) -> Option<(f32, String)> {
+    if STACK_MARKERS.iter().any(|m| value.contains(m)) {
+        return Some((0.70, "a stack trace".to_string()));
+    }
+    vars.iter()
+        .find(|var| exposes(value, var))
+        .map(|var| (0.55, format!("error `{var}`")))
+}
+
+/// Whether `value` uses `var` other than through a safe field like `.code`.
+fn exposes(value: &str, var: &str) -> bool {
+    value.match_indices(var).any(|(i, _)| {
+        let is_ident = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+        let after = &value[i + var.len()..];
+        let standalone = !is_ident(value[..i].chars().next_back())
+            && !value[..i].ends_with('.')
+            && !is_ident(after.chars().next());
+        standalone && !SAFE_FIELDS.iter().any(|f| {
+            after.starts_with(f) && !is_ident(after[f.len()..].chars().next())
+        })
+    })
+}
+
+fn leak_match(ctx: &DetectionContext, line: u32, column: u32, confidence: f32, matched_text: String) -> PatternMatch {
+    PatternMatch {
+        file: ctx.file.to_string(),
+        line,
+        column,
+        pattern_id: "ERR-INFO-LEAK-001".to_string(),
+        confidence,
+        cwe_ids: SmallVec::from_buf([209, 0]),
+        owasp: Some("A04:2021".to_string()),
+        detection_method: DetectionMethod::AstVisitor,
+        category: PatternCategory::Errors,
+        matched_text,
+    }
+}
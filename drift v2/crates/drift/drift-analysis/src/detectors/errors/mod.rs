@@ -1,5 +1,9 @@
 //! Errors detector — error handling patterns, try/catch, Result types.
 
+pub mod error_leak;
+pub mod null_deref;
+pub mod resource_leak;
+
 use smallvec::SmallVec;
 
 use crate::detectors::traits::{Detector, DetectorCategory, DetectorVariant};
@@ -55,7 +59,7 @@ impl Detector for ErrorsDetector {
                 ErrorHandlingKind::TryCatch => "ERR-TRY-CATCH-001",
                 ErrorHandlingKind::TryExcept => "ERR-TRY-EXCEPT-001",
                 ErrorHandlingKind::ResultMatch => "ERR-RESULT-MATCH-001",
-                ErrorHandlingKind::QuestionMark => "ERR-QUESTION-MARK-001",
+                ErrorHandlingKind::QuestionMark | ErrorHandlingKind::TryOperator => "ERR-QUESTION-MARK-001",
                 ErrorHandlingKind::Unwrap => "ERR-UNWRAP-001",
                 ErrorHandlingKind::PromiseCatch => "ERR-PROMISE-CATCH-001",
                 _ => continue,
@@ -75,6 +79,10 @@ impl Detector for ErrorsDetector {
             });
         }
 
+        matches.extend(null_deref::NullDerefCheck.detect(ctx));
+        matches.extend(error_leak::ErrorLeakCheck::default().detect(ctx));
+        matches.extend(resource_leak::ResourceLeakCheck::default().detect(ctx));
+
         matches
     }
 }
@@ -0,0 +1,263 @@
+//! Dereference without a null/None check (CWE-476).
+//!
+//! Without type information only the clear cases are flagged: the result of
+//! a lookup that returns null/None on a miss dereferenced in the same
+//! expression (Python `d.get(k).attr` or `re.match(...).group()`, JS/TS
+//! `items.find(...).name`), and a parameter declared nullable (TS `x?: T` or
+//! `T | null`, Python `= None`/`Optional[T]`, Java `@Nullable`) whose first
+//! use in the body is a dereference.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::{call_arguments, call_path, text_after_call};
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::{CallSite, FunctionInfo};
+use crate::scanner::language_detect::Language;
+
+/// Receivers whose `get(url)` is an HTTP request rather than a lookup.
+const HTTP_RECEIVERS: &[&str] = &["requests", "httpx", "session", "client", "http", "api", "app", "router"];
+
+/// Python regex calls that return `None` when nothing matches.
+const REGEX_CALLS: &[&str] = &["match", "search", "fullmatch"];
+
+/// Flags dereferences of values that may be null/None.
+#[derive(Debug, Clone, Default)]
+pub struct NullDerefCheck;
+
+impl NullDerefCheck {
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let mut matches = Vec::new();
+
+        for call in ctx.call_sites {
+            if let Some((confidence, what)) = lookup_result(ctx, call) {
+                let Some(after) = text_after_call(ctx.source, call) else {
+                    continue;
+                };
+                if let Some(member) = dereference(after) {
+                    let path = call_path(ctx.source, call).unwrap_or_default();
+                    matches.push(null_match(
+                        ctx,
+                        call.line,
+                        call.column,
+                        confidence,
+                        format!("{}(...){member} dereferences {what} without a check", path.trim()),
+                    ));
+                }
+            }
+        }
+
+        if matches!(ctx.language, Language::TypeScript | Language::JavaScript | Language::Python | Language::Java) {
+            if let Ok(text) = std::str::from_utf8(ctx.source) {
+                let lines: Vec<&str> = text.lines().collect();
+                for func in ctx.functions {
+                    matches.extend(nullable_parameter_derefs(ctx, &lines, func));
+                }
+            }
+        }
+
+        matches
+    }
+}
+
+/// Whether a call returns null/None on a miss, as (confidence, description).
+fn lookup_result(ctx: &DetectionContext, call: &CallSite) -> Option<(f32, &'static str)> {
+    let receiver = call.receiver.as_deref().unwrap_or("");
+    let receiver_root = receiver.rsplit('.').next().unwrap_or("").to_lowercase();
+    match (ctx.language, call.callee_name.as_str()) {
+        (Language::Python, "get") if !receiver.is_empty() => {
+            // A second argument is the default returned on a miss.
+            if call_arguments(ctx.source, call).map_or(true, |args| args.len() != 1) {
+                return None;
+            }
+            let http = HTTP_RECEIVERS.iter().any(|h| receiver_root == *h || receiver_root.ends_with(&format!("_{h}")));
+            (!http).then_some((0.75, "a `.get()` result that is None when the key is missing"))
+        }
+        (Language::Python, name) if REGEX_CALLS.contains(&name) && receiver == "re" => {
+            Some((0.70, "a regex match that is None when nothing matches"))
+        }
+        (Language::TypeScript | Language::JavaScript, "find") if !receiver.is_empty() => {
+            Some((0.70, "a `.find()` result that is undefined when nothing matches"))
+        }
+        _ => None,
+    }
+}
+
+/// The member access directly applied to an expression (`.attr`, `[k]`),
+/// if any. Optional chaining and non-null assertions are explicit handling.
+fn dereference(after: &str) -> Option<&str> {
+    let member = after.strip_prefix('.').map(|rest| {
+        let len = rest.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(rest.len());
+        &after[..len + 1]
+    });
+    match member {
+        Some(m) if m.len() > 1 => Some(m),
+        _ if after.starts_with('[') => Some("[...]"),
+        _ => None,
+    }
+}
+
+/// Nullable parameters of `func` whose first use is a dereference.
+fn nullable_parameter_derefs(ctx: &DetectionContext, lines: &[&str], func: &FunctionInfo) -> Vec<PatternMatch> {
+    let end = (func.end_line as usize).min(lines.len().saturating_sub(1));
+    // Skip decorator and annotation lines above the declaration.
+    let Some(start) = (func.line as usize..=end).find(|&i| !lines[i].trim_start().starts_with('@')) else {
+        return Vec::new();
+    };
+    let Some((params, body_start)) = signature(lines, start, end) else {
+        return Vec::new();
+    };
+
+    let mut matches = Vec::new();
+    for segment in split_params(&params) {
+        let Some(name) = nullable_param(ctx.language, segment) else {
+            continue;
+        };
+        let first_use = lines[body_start.0..=end].iter().enumerate().find_map(|(i, line)| {
+            let line_idx = body_start.0 + i;
+            let from = if line_idx == body_start.0 { body_start.1.min(line.len()) } else { 0 };
+            word_positions(line, name).find(|pos| *pos >= from).map(|pos| (line_idx, pos))
+        });
+        let Some((line_idx, pos)) = first_use else {
+            continue;
+        };
+        let line = lines[line_idx];
+        let Some(member) = dereference(&line[pos + name.len()..]) else {
+            continue;
+        };
+        if guarded_on_line(line, name) {
+            continue;
+        }
+        matches.push(null_match(
+            ctx,
+            line_idx as u32,
+            pos as u32,
+            0.60,
+            format!("{name}{member} dereferences nullable parameter `{name}` of `{}` without a check", func.name),
+        ));
+    }
+    matches
+}
+
+/// The parameter list text of the function starting at `start`, and the
+/// (line, column) where its body begins.
+fn signature(lines: &[&str], start: usize, end: usize) -> Option<(String, (usize, usize))> {
+    let mut text = String::new();
+    let mut depth = 0i32;
+    let mut opened = false;
+    for (idx, line) in lines.iter().enumerate().take(end + 1).skip(start) {
+        for (col, c) in line.char_indices() {
+            match c {
+                '(' => {
+                    if opened {
+                        text.push(c);
+                    }
+                    depth += 1;
+                    opened = true;
+                }
+                ')' if opened => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some((text, (idx, col + 1)));
+                    }
+                    text.push(c);
+                }
+                _ if opened => text.push(c),
+                _ => {}
+            }
+        }
+        if opened {
+            text.push(' ');
+        }
+    }
+    None
+}
+
+/// Split a parameter list on top-level commas.
+fn split_params(params: &str) -> Vec<&str> {
+    let mut segments = Vec::new();
+    let mut depth = 0i32;
+    let mut from = 0;
+    for (i, c) in params.char_indices() {
+        match c {
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' | ']' | '}' | '>' => depth -= 1,
+            ',' if depth == 0 => {
+                segments.push(params[from..i].trim());
+                from = i + 1;
+            }
+            _ => {}
+        }
+    }
+    segments.push(params[from..].trim());
+    segments.into_iter().filter(|s| !s.is_empty()).collect()
+}
+
+/// The parameter's name, if its declaration says it may be null/None.
+fn nullable_param(language: Language, segment: &str) -> Option<&str> {
+    let ident = |s: &str| -> bool { !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '$') };
+    match language {
+        Language::TypeScript | Language::JavaScript => {
+            let (head, ty) = segment.split_once(':').unwrap_or((segment, ""));
+            let head = head.trim();
+            let ty = ty.split('=').next().unwrap_or("");
+            let optional = head.ends_with('?');
+            let nullable_type = ty.split('|').any(|t| matches!(t.trim(), "null" | "undefined"));
+            let name = head.trim_end_matches('?').trim();
+            (ident(name) && (optional || nullable_type)).then_some(name)
+        }
+        Language::Python => {
+            let name_end = segment.find([':', '=']).unwrap_or(segment.len());
+            let name = segment[..name_end].trim();
+            let default_none = segment.split_once('=').is_some_and(|(_, d)| d.trim() == "None");
+            let optional_type = segment.split_once(':').is_some_and(|(_, t)| {
+                let t = t.split('=').next().unwrap_or("");
+                t.contains("Optional[") || t.split('|').any(|p| p.trim() == "None")
+            });
+            (ident(name) && name != "self" && (default_none || optional_type)).then_some(name)
+        }
+        Language::Java => {
+            let nullable = segment.contains("@Nullable") || segment.contains("@CheckForNull");
+            let name = segment.split_whitespace().last().unwrap_or("");
+            (nullable && ident(name)).then_some(name)
+        }
+        _ => None,
+    }
+}
+
+/// Whether the line also tests `name` (`x.a if x else b`, `x ? x.a : b`).
+fn guarded_on_line(line: &str, name: &str) -> bool {
+    [
+        format!("if {name}"), format!("if ({name}"), format!("if (!{name}"), format!("{name} is not None"),
+        format!("{name} &&"), format!("{name} ?"), format!("{name} !="), format!("{name} =="),
+        format!("{name} and "), format!("{name} or "), format!("{name} ||"), format!("{name} ??"),
+    ]
+    .iter()
+    .any(|g| line.contains(g.as_str()))
+}
+
+/// Byte positions of `word` in `line` as a whole identifier.
+fn word_positions<'a>(line: &'a str, word: &'a str) -> impl Iterator<Item = usize> + 'a {
+    line.match_indices(word).filter_map(move |(i, _)| {
+        let is_ident = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '$');
+        let standalone = !is_ident(line[..i].chars().next_back())
+            && !line[..i].ends_with('.')
+            && !is_ident(line[i + word.len()..].chars().next());
+        standalone.then_some(i)
+    })
+}
+
+fn null_match(ctx: &DetectionContext, line: u32, column: u32, confidence: f32, matched_text: String) -> PatternMatch {
+    PatternMatch {
+        file: ctx.file.to_string(),
+        line,
+        column,
+        pattern_id: "ERR-NULL-DEREF-001".to_string(),
+        confidence,
+        cwe_ids: SmallVec::from_buf([476, 0]),
+        owasp: None,
+        detection_method: DetectionMethod::AstVisitor,
+        category: PatternCategory::Errors,
+        matched_text,
+    }
+}
@@ -0,0 +1,244 @@
+//! Opened resources that are never released (CWE-772).
+//!
+//! A file, socket or connection bound to a variable is flagged when the rest
+//! of its function neither releases it nor hands it on. Released means the
+//! language's cleanup idiom or an explicit close: Go `defer f.Close()`,
+//! Python `with open(...)`, Java try-with-resources, C# `using`, or any
+//! `close`/`destroy`/`dispose`/`release`/`pipe` call on the variable.
+//! Returning the variable transfers ownership to the caller and is not
+//! flagged, nor is a resource opened directly as another call's argument
+//! (`new BufferedReader(new FileReader(f))` wraps it). Exits on error paths
+//! are not followed, so a resource closed on one branch only is not flagged.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::call_path;
+use crate::detectors::handlers::enclosing_function;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::ErrorHandlingKind;
+use crate::scanner::language_detect::Language;
+
+/// Calls that open a resource, by qualified callee: Go (`os.Open`,
+/// `net.Dial`, `sql.Open`), Python (`open`, `socket.socket`), Node (`fs`
+/// streams and handles, `net` sockets), JDBC `getConnection`.
+pub const DEFAULT_OPEN_CALLS: &[&str] = &[
+    "os.Open", "os.OpenFile", "os.Create", "net.Dial", "net.DialTimeout", "net.Listen", "sql.Open",
+    "zip.OpenReader",
+    "open", "io.open", "socket.socket", "socket.create_connection", "tempfile.NamedTemporaryFile",
+    "fs.createReadStream", "fs.createWriteStream", "fs.openSync", "fs.promises.open",
+    "net.createConnection", "net.connect",
+    "DriverManager.getConnection",
+];
+
+/// Resource types constructed with `new` in Java and C#.
+pub const DEFAULT_RESOURCE_TYPES: &[&str] = &[
+    "FileInputStream", "FileOutputStream", "FileReader", "FileWriter", "RandomAccessFile",
+    "Socket", "ServerSocket", "ZipFile", "FileStream", "StreamReader", "StreamWriter", "SqlConnection",
+];
+
+/// Words marking a release method or call (`Close`, `closeSync`, `destroy`).
+const RELEASE_WORDS: &[&str] = &["close", "destroy", "dispose", "release", "pipe", "shutdown"];
+
+/// Flags opened resources without a matching close in the same function.
+#[derive(Debug, Clone)]
+pub struct ResourceLeakCheck {
+    open_calls: Vec<String>,
+    resource_types: Vec<String>,
+}
+
+impl ResourceLeakCheck {
+    /// Create a check over custom open calls and constructed resource types.
+    pub fn new(open_calls: &[&str], resource_types: &[&str]) -> Self {
+        Self {
+            open_calls: open_calls.iter().map(|s| s.to_string()).collect(),
+            resource_types: resource_types.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Add library-specific open calls (e.g. `pgx.Connect`).
+    pub fn with_open_calls(mut self, calls: &[&str]) -> Self {
+        self.open_calls.extend(calls.iter().map(|s| s.to_string()));
+        self
+    }
+
+    /// Add resource types constructed with `new` (e.g. `JarFile`).
+    pub fn with_resource_types(mut self, types: &[&str]) -> Self {
+        self.resource_types.extend(types.iter().map(|s| s.to_string()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let mut opened: Vec<(u32, usize, String)> = Vec::new();
+
+        for call in ctx.call_sites {
+            let Some(path) = call_path(ctx.source, call) else {
+                continue;
+            };
+            let path = path.trim().trim_start_matches("await ").trim();
+            if self.open_calls.iter().any(|c| is_open_call(path, c)) {
+                opened.push((call.line, call.column as usize, path.to_string()));
+            }
+        }
+        if matches!(ctx.language, Language::Java | Language::CSharp) {
+            for (idx, line) in lines.iter().enumerate() {
+                for ty in &self.resource_types {
+                    if let Some(column) = line.find(&format!("new {ty}(")) {
+                        opened.push((idx as u32, column, format!("new {ty}")));
+                    }
+                }
+            }
+        }
+
+        let mut matches = Vec::new();
+        for (line, column, what) in opened {
+            let Some(text) = lines.get(line as usize) else {
+                continue;
+            };
+            let Some(prefix) = text.get(..column) else {
+                continue;
+            };
+            if managed_by_idiom(ctx, &lines, line, prefix) {
+                continue;
+            }
+            let Some(var) = assigned_variable(prefix) else {
+                continue;
+            };
+            let end = enclosing_function(ctx, line).map_or(lines.len(), |f| (f.end_line as usize + 1).min(lines.len()));
+            let rest = lines.get(line as usize + 1..end).unwrap_or_default();
+            if rest.iter().any(|l| releases(l, var)) {
+                continue;
+            }
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line,
+                column: column as u32,
+                pattern_id: "ERR-RESOURCE-LEAK-001".to_string(),
+                confidence: if ctx.language == Language::Python { 0.60 } else { 0.70 },
+                cwe_ids: SmallVec::from_buf([772, 0]),
+                owasp: None,
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Errors,
+                matched_text: format!("`{var}` opened by {what}(...) is never closed in this function"),
+            });
+        }
+
+        matches
+    }
+}
+
+impl Default for ResourceLeakCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_OPEN_CALLS, DEFAULT_RESOURCE_TYPES)
+    }
+}
+
+/// Whether a call path names an open call: `open` only unqualified,
+/// `os.Open` also as `pkg.os.Open`.
+fn is_open_call(path: &str, call: &str) -> bool {
+    path == call || (call.contains('.') && path.ends_with(&format!(".{call}")))
+}
+
+/// Whether the resource opened after `prefix` on `line` is released by the
+/// language's scoped cleanup: a Python `with`, Java try-with-resources, a C#
+/// `using`, or a wrapping call.
+fn managed_by_idiom(ctx: &DetectionContext, lines: &[&str], line: u32, prefix: &str) -> bool {
+    let in_with = ctx
+        .parse_result
+        .error_handling
+        .iter()
+        .any(|eh| eh.kind == ErrorHandlingKind::WithStatement && eh.line == line);
+    // Resources of a multi-line header follow the `try (` line.
+    let previous = line.checked_sub(1).and_then(|l| lines.get(l as usize)).map_or("", |l| l.trim());
+    let in_try_header = prefix.contains("try (")
+        || prefix.contains("try(")
+        || (previous.starts_with("try") && previous.contains('(') && !previous.contains('{'));
+    let wrapped = prefix.trim_end().ends_with(['(', ',']);
+    in_with || in_try_header || prefix.trim_start().starts_with("using") || wrapped
+}
+
+/// Local variable assigned by `prefix` (`f, err := `, `FileReader r = `,
+/// `const s: Stream = await `). Fields (`self.f = `) are released elsewhere
+/// and yield `None`.
+fn assigned_variable(prefix: &str) -> Option<&str> {
+    let prefix = prefix.trim_end();
+    let prefix = prefix.strip_suffix("await").unwrap_or(prefix).trim_end();
+    let lhs = prefix.strip_suffix('=')?;
+    let lhs = lhs.strip_suffix(':').unwrap_or(lhs);
+    if lhs.ends_with(['=', '!', '<', '>']) {
+        return None;
+    }
+    let first = lhs.split(',').next()?;
+    let first = first.split(':').next()?.trim();
+    let var = first.rsplit(char::is_whitespace).next()?;
+    let is_local = !var.is_empty() && var != "_" && var.chars().all(|c| c.is_alphanumeric() || c == '_');
+    is_local.then_some(var)
+}
+
+/// Whether a line releases `var` or returns it to the caller. A release is
+/// a release method called on the variable (`defer f.Close()`,
+/// `sock.destroy()`) or a release call taking it as an argument
+/// (`fs.closeSync(fd)`, `IOUtils.closeQuietly(in)`, but not `close(f.path)`);
+/// a release of anything else on the same line does not count.
+fn releases(line: &str, var: &str) -> bool {
+    let returned = line.trim_start().strip_prefix("return ").is_some_and(|value| {
+        let value = value.trim_start();
+        value.starts_with(var) && !value[var.len()..].starts_with(|c: char| c.is_alphanumeric() || c == '_' || c == '.')
+    });
+    returned
+        || occurrences(line, var)
+            .any(|i| {
+                let after = &line[i + var.len()..];
+                released_as_receiver(after) || (!after.starts_with('.') && released_as_argument(&line[..i]))
+            })
+}
+
+/// Whether the text after a variable calls a release method on it.
+fn released_as_receiver(after: &str) -> bool {
+    let Some(method) = after.strip_prefix("?.").or_else(|| after.strip_prefix('.')) else {
+        return false;
+    };
+    let name = method.split(|c: char| !(c.is_alphanumeric() || c == '_')).next().unwrap_or("");
+    name == "end" || is_release(name)
+}
+
+/// Whether the text before a variable opens an argument list of a release
+/// call, possibly around nested calls (`closeQuietly(wrap(f))`).
+fn released_as_argument(before: &str) -> bool {
+    let mut depth = 0usize;
+    for (i, c) in before.char_indices().rev() {
+        match c {
+            ')' | ']' => depth += 1,
+            '(' | '[' if depth > 0 => depth -= 1,
+            '(' => {
+                let callee = before[..i].trim_end();
+                let name = callee.rsplit(|c: char| !(c.is_alphanumeric() || c == '_')).next().unwrap_or("");
+                if is_release(name) {
+                    return true;
+                }
+            }
+            ';' | '{' | '}' => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+fn is_release(name: &str) -> bool {
+    let lower = name.to_lowercase();
+    RELEASE_WORDS.iter().any(|w| lower.contains(w))
+}
+
+/// Byte offsets where `line` names the identifier `var`.
+fn occurrences<'a>(line: &'a str, var: &'a str) -> impl Iterator<Item = usize> + 'a {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    line.match_indices(var).map(|(i, _)| i).filter(move |&i| {
+        let before = line[..i].chars().next_back().map_or(true, |c| !is_ident(c));
+        let after = line[i + var.len()..].chars().next().map_or(true, |c| !is_ident(c));
+        before && after
+    })
+}
@@ -0,0 +1,219 @@
+//! Source-shape heuristics for locating HTTP request handlers.
+//!
+//! Detectors run before the call graph exists, so entry points are recovered
+//! from route decorators, request-typed parameters, handler naming and
+//! controller classes rather than from framework registration. The request
+//! sources and one-hop assignment tracking below are shared by the checks
+//! that follow request input into a sink.
+
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::FunctionInfo;
+
+/// Whether `line` falls inside a function that handles HTTP requests.
+pub fn in_request_handler(ctx: &DetectionContext, line: u32) -> bool {
+    let in_controller = ctx.classes.iter().any(|c| {
+        c.range.start.line <= line && line <= c.range.end.line && is_controller_class(&c.name)
+    });
+    in_controller
+        || ctx.functions.iter().any(|f| {
+            f.line <= line && line <= f.end_line && is_request_handler(f)
+        })
+}
+
+/// Smallest function whose line range contains `line`.
+pub fn enclosing_function<'a>(ctx: &DetectionContext<'a>, line: u32) -> Option<&'a FunctionInfo> {
+    ctx.functions
+        .iter()
+        .filter(|f| f.line <= line && line <= f.end_line)
+        .min_by_key(|f| f.end_line - f.line)
+}
+
+/// Source of the function enclosing `line`, or the whole file outside functions.
+pub fn enclosing_scope(ctx: &DetectionContext, lines: &[&str], line: u32) -> String {
+    match enclosing_function(ctx, line) {
+        Some(f) => {
+            let end = (f.end_line as usize + 1).min(lines.len());
+            lines.get(f.line as usize..end).unwrap_or_default().join("\n")
+        }
+        None => lines.join("\n"),
+    }
+}
+
+/// Innermost-function lookup for checks that place every call of a file.
+///
+/// Functions are sorted by start line once, each linked to the function
+/// enclosing it; a lookup binary-searches the last function starting at or
+/// before the line and walks outward until one contains it.
+pub struct FunctionIndex {
+    /// Positions into the indexed slice, by start line then widest first.
+    order: Vec<usize>,
+    starts: Vec<u32>,
+    ends: Vec<u32>,
+    /// Index into `order` of the enclosing function, if any.
+    parents: Vec<Option<usize>>,
+}
+
+impl FunctionIndex {
+    pub fn new(functions: &[FunctionInfo]) -> Self {
+        let mut order: Vec<usize> = (0..functions.len()).collect();
+        order.sort_by_key(|&i| (functions[i].line, std::cmp::Reverse(functions[i].end_line)));
+        let starts: Vec<u32> = order.iter().map(|&i| functions[i].line).collect();
+        let ends: Vec<u32> = order.iter().map(|&i| functions[i].end_line).collect();
+
+        let mut parents = Vec::with_capacity(order.len());
+        let mut open: Vec<usize> = Vec::new();
+        for (k, &start) in starts.iter().enumerate() {
+            while open.last().is_some_and(|&p| ends[p] < start) {
+                open.pop();
+            }
+            parents.push(open.last().copied());
+            open.push(k);
+        }
+        Self { order, starts, ends, parents }
+    }
+
+    /// Position in the indexed slice of the smallest function containing `line`.
+    pub fn enclosing(&self, line: u32) -> Option<usize> {
+        let mut k = self.starts.partition_point(|&start| start <= line).checked_sub(1)?;
+        while self.ends[k] < line {
+            k = self.parents[k]?;
+        }
+        Some(self.order[k])
+    }
+}
+
+/// Whether a class name marks a controller whose methods handle requests.
+pub fn is_controller_class(name: &str) -> bool {
+    ["Controller", "View", "ViewSet", "Resource", "Handler"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+}
+
+/// Whether a function looks like a request handler by decorator, parameters or name.
+pub fn is_request_handler(func: &FunctionInfo) -> bool {
+    let route_decorator = func.decorators.iter().any(|d| {
+        // Decorator text may keep the `@` and arguments: `@app.route('/x')`.
+        let name = d.name.trim_start_matches('@').split('(').next().unwrap_or("").to_lowercase();
+        let name = name.rsplit('.').next().unwrap_or(&name);
+        matches!(
+            name,
+            "get" | "post" | "put" | "patch" | "delete" | "route" | "api_view"
+                | "getmapping" | "postmapping" | "putmapping" | "deletemapping"
+                | "requestmapping" | "httpget" | "httppost"
+        )
+    });
+    let request_param = func.parameters.iter().any(|p| {
+        matches!(p.name.as_str(), "req" | "request" | "res" | "response")
+            || p.type_annotation.as_deref().is_some_and(|t| {
+                t.contains("Request") || t.contains("ResponseWriter") || t.contains("HttpContext")
+            })
+    });
+    let name = func.name.to_lowercase();
+    route_decorator || request_param || name.starts_with("handle") || name.ends_with("handler")
+}
+
+/// Request accessors that carry caller-controlled data.
+pub const REQUEST_SOURCES: &[&str] = &[
+    // Node (Express, Koa, Lambda)
+    "req.body", "req.query", "req.params", "ctx.query", "ctx.params", "ctx.request.body",
+    "event.body",
+    // Python (Flask, Django, FastAPI)
+    "request.args", "request.form", "request.values", "request.json", "request.data",
+    "request.body", "request.query", "request.GET", "request.POST",
+    // Java / Servlet
+    "getParameter(", "getHeader(",
+    // C#
+    "Request.Query", "Request.Form",
+    // Go
+    "URL.Query()", "FormValue(",
+    // PHP
+    "$_GET", "$_POST", "$_REQUEST", "$_COOKIE",
+    // Rails
+    "params[",
+];
+
+/// Whether `expr` reads one of the [`REQUEST_SOURCES`].
+pub fn is_request_input(expr: &str) -> bool {
+    REQUEST_SOURCES.iter().any(|s| expr.contains(s))
+}
+
+/// How directly an expression derives from the request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestTaint {
+    /// The expression reads the request itself.
+    Direct,
+    /// A variable assigned from request input earlier in the function.
+    Assigned,
+    /// A parameter of a request handler.
+    HandlerParameter,
+}
+
+impl RequestTaint {
+    /// How the tainted `name` reads in a finding message.
+    pub fn describe(self, name: &str) -> String {
+        match self {
+            Self::Direct => "request input".to_string(),
+            Self::Assigned => format!("`{name}`, assigned from request input"),
+            Self::HandlerParameter => format!("handler parameter `{name}`"),
+        }
+    }
+}
+
+/// How directly `expr` derives from the request, given the enclosing
+/// `handler` and its source `before` the use (one assignment hop).
+pub fn request_taint(
+    ctx: &DetectionContext,
+    handler: Option<&FunctionInfo>,
+    before: &str,
+    expr: &str,
+    line: u32,
+) -> Option<RequestTaint> {
+    if is_request_input(expr) {
+        return Some(RequestTaint::Direct);
+    }
+    let ident = expr.trim_start_matches('$');
+    if assigned_from_request(before, ident) {
+        return Some(RequestTaint::Assigned);
+    }
+    let is_param = handler.is_some_and(|f| f.parameters.iter().any(|p| p.name.trim_start_matches('$') == ident));
+    (is_param && in_request_handler(ctx, line)).then_some(RequestTaint::HandlerParameter)
+}
+
+/// Whether `name` is assigned from request input in `text`.
+pub fn assigned_from_request(text: &str, name: &str) -> bool {
+    assignments(text).any(|(target, value)| target == name && is_request_input(value))
+}
+
+/// Source text of the enclosing function (or whole file) up to the line
+/// before `line`.
+pub fn text_before(lines: &[&str], handler: Option<&FunctionInfo>, line: u32) -> String {
+    let start = handler.map_or(0, |f| f.line as usize).min(line as usize);
+    lines.get(start..line as usize).unwrap_or_default().join("\n")
+}
+
+/// `(target, value)` for each simple `name = value` line, skipping
+/// comparisons, arrows and declaration keywords. A leading `$` (PHP) is
+/// dropped from the target.
+pub fn assignments(text: &str) -> impl Iterator<Item = (&str, &str)> {
+    text.lines().filter_map(|line| {
+        let (lhs, rhs) = line.split_once('=')?;
+        if rhs.starts_with(['=', '>']) || lhs.ends_with(['!', '<', '>', '=']) {
+            return None;
+        }
+        let target = lhs
+            .split_whitespace()
+            .last()?
+            .trim_start_matches('$')
+            .trim_end_matches(':');
+        let is_ident = !target.is_empty() && target.chars().all(|c| c.is_alphanumeric() || c == '_');
+        is_ident.then_some((target, rhs.trim()))
+    })
+}
+
+/// Whether `word` occurs in `text` as a whole identifier.
+pub fn contains_word(text: &str, word: &str) -> bool {
+    text.match_indices(word).any(|(i, _)| {
+        let is_ident = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+        !is_ident(text[..i].chars().next_back()) && !is_ident(text[i + word.len()..].chars().next())
+    })
+}
@@ -0,0 +1,78 @@
+//! Eager string concatenation in logger arguments (SLF4J/Logback style).
+//!
+//! `log.info("user " + name + " did X")` builds the message even when the
+//! level is disabled. The parameterized form `log.info("user {} did X", name)`
+//! defers formatting until the message is actually emitted.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::{call_arguments, is_string_concatenation};
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::scanner::language_detect::Language;
+
+/// Logger methods checked by default.
+pub const DEFAULT_LOGGER_METHODS: &[&str] = &["trace", "debug", "info", "warn", "error"];
+
+/// Flags logger calls whose message argument is a concatenated string.
+#[derive(Debug, Clone)]
+pub struct LogConcatenationCheck {
+    logger_methods: Vec<String>,
+}
+
+impl LogConcatenationCheck {
+    /// Create a check over a custom logger method set.
+    pub fn new(logger_methods: &[&str]) -> Self {
+        Self {
+            logger_methods: logger_methods.iter().map(|m| m.to_string()).collect(),
+        }
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        if !matches!(ctx.language, Language::Java | Language::Kotlin | Language::Scala) {
+            return Vec::new();
+        }
+
+        let mut matches = Vec::new();
+        for call in ctx.call_sites {
+            let Some(receiver) = call.receiver.as_deref() else {
+                continue;
+            };
+            if !is_logger_receiver(receiver) || !self.logger_methods.contains(&call.callee_name) {
+                continue;
+            }
+            let Some(args) = call_arguments(ctx.source, call) else {
+                continue;
+            };
+            if args.first().is_some_and(|first| is_string_concatenation(first)) {
+                matches.push(PatternMatch {
+                    file: ctx.file.to_string(),
+                    line: call.line,
+                    column: call.column,
+                    pattern_id: "LOG-CONCAT-004".to_string(),
+                    confidence: 0.80,
+                    cwe_ids: SmallVec::new(),
+                    owasp: None,
+                    detection_method: DetectionMethod::AstVisitor,
+                    category: PatternCategory::Logging,
+                    matched_text: format!(
+                        "{}.{}() with concatenated message — use a parameterized message",
+                        receiver, call.callee_name
+                    ),
+                });
+            }
+        }
+        matches
+    }
+}
+
+impl Default for LogConcatenationCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_LOGGER_METHODS)
+    }
+}
+
+fn is_logger_receiver(receiver: &str) -> bool {
+    let last = receiver.rsplit('.').next().unwrap_or(receiver).to_lowercase();
+    last == "log" || last == "_log" || last.ends_with("logger")
+}
@@ -1,12 +1,31 @@
 //! Logging detector — console.log, logger frameworks, log levels, structured logging.
 
+pub mod concatenation;
+
+use drift_core::config::DetectorOptions;
 use smallvec::SmallVec;
 
 use crate::detectors::traits::{Detector, DetectorCategory, DetectorVariant};
 use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
 use crate::engine::visitor::DetectionContext;
 
-pub struct LoggingDetector;
+#[derive(Debug, Clone, Default)]
+pub struct LoggingDetector {
+    concatenation: concatenation::LogConcatenationCheck,
+}
+
+impl LoggingDetector {
+    /// Create the detector with the logger methods from `options`.
+    pub fn new(options: &DetectorOptions) -> Self {
+        let concatenation = if options.logger_methods.is_empty() {
+            concatenation::LogConcatenationCheck::default()
+        } else {
+            let methods: Vec<&str> = options.logger_methods.iter().map(String::as_str).collect();
+            concatenation::LogConcatenationCheck::new(&methods)
+        };
+        Self { concatenation }
+    }
+}
 
 impl Detector for LoggingDetector {
     fn id(&self) -> &str { "logging-base" }
@@ -126,6 +145,9 @@ impl Detector for LoggingDetector {
             }
         }
 
+        // Detect eagerly concatenated logger messages (Java/Kotlin)
+        matches.extend(self.concatenation.detect(ctx));
+
         matches
     }
 }
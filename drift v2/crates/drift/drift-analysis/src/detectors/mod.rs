@@ -6,6 +6,9 @@
 
 pub mod traits;
 pub mod registry;
+pub mod call_args;
+pub mod handlers;
+pub mod sinks;
 pub mod api;
 pub mod auth;
 pub mod components;
@@ -23,5 +26,8 @@ pub mod testing;
 pub mod types;
 pub mod accessibility;
 
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_support;
+
 pub use traits::{Detector, DetectorCategory, DetectorVariant};
 pub use registry::DetectorRegistry;
@@ -0,0 +1,111 @@
+//! Request body parsers without a size limit (CWE-770).
+//!
+//! `app.use(express.json())` or `multer({ dest: "uploads/" })` accepts
+//! whatever the client sends, so a few large payloads exhaust memory or disk.
+//! Parser setup calls are flagged when no argument configures a size limit
+//! (`limit`, `limits.fileSize`, `maxFileSize`). Parsers that buffer without
+//! any default cap (multer, busboy) are reported with higher confidence than
+//! those with a small built-in default (body-parser's 100kb). Options passed
+//! by reference cannot be inspected and are not flagged.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::{call_arguments, call_path};
+use crate::detectors::performance::has_opaque_options;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::CallSite;
+
+/// Parser setup calls checked by default, as the qualified callee text
+/// (Express, body-parser, koa-body, koa-bodyparser, multer, formidable, busboy).
+pub const DEFAULT_BODY_PARSERS: &[&str] = &[
+    "express.json", "express.urlencoded", "express.raw", "express.text", "bodyParser",
+    "bodyParser.json", "bodyParser.urlencoded", "bodyParser.raw", "bodyParser.text", "koaBody",
+    "bodyparser", "multer", "formidable", "formidable.IncomingForm", "IncomingForm", "busboy",
+    "Busboy",
+];
+
+/// Parsers with no size cap unless one is configured.
+const UNCAPPED_PARSERS: &[&str] = &["multer", "busboy", "Busboy"];
+
+/// Option fragments (lowercased) that configure a size limit.
+const LIMIT_KEYWORDS: &[&str] = &["limit", "maxfilesize", "maxfieldssize", "maxtotalfilesize", "filesize"];
+
+/// Flags body and multipart parsers configured without a size limit.
+#[derive(Debug, Clone)]
+pub struct BodyLimitCheck {
+    parsers: Vec<String>,
+}
+
+impl BodyLimitCheck {
+    /// Create a check over a custom set of qualified parser setup calls.
+    pub fn new(parsers: &[&str]) -> Self {
+        Self {
+            parsers: parsers.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Add framework-specific parser setup calls to the current set.
+    pub fn with_parsers(mut self, parsers: &[&str]) -> Self {
+        self.parsers.extend(parsers.iter().map(|s| s.to_string()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let mut matches = Vec::new();
+
+        for call in ctx.call_sites {
+            let Some(parser) = self.parser_target(ctx.source, call) else {
+                continue;
+            };
+            let args = call_arguments(ctx.source, call).unwrap_or_default();
+            if configures_limit(&args) || has_opaque_options(&args) {
+                continue;
+            }
+            let (confidence, default) = if UNCAPPED_PARSERS.contains(&parser) {
+                (0.65, "accepts bodies of any size")
+            } else {
+                (0.50, "relies on the default size limit")
+            };
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: call.line,
+                column: call.column,
+                pattern_id: "PERF-BODY-LIMIT-007".to_string(),
+                confidence,
+                cwe_ids: SmallVec::from_buf([770, 0]),
+                owasp: None,
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Performance,
+                matched_text: format!("{parser}() {default} — configure an explicit limit"),
+            });
+        }
+
+        matches
+    }
+
+    /// The configured parser this call site resolves to, if any.
+    fn parser_target(&self, source: &[u8], call: &CallSite) -> Option<&str> {
+        let path = call_path(source, call)?;
+        let chain = path.trim().trim_start_matches("new ").trim();
+        self.parsers
+            .iter()
+            .find(|spec| {
+                chain == spec.as_str() || (spec.contains('.') && chain.ends_with(&format!(".{spec}")))
+            })
+            .map(String::as_str)
+    }
+}
+
+impl Default for BodyLimitCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_BODY_PARSERS)
+    }
+}
+
+fn configures_limit(args: &[String]) -> bool {
+    args.iter().any(|arg| {
+        let lower = arg.to_lowercase();
+        LIMIT_KEYWORDS.iter().any(|k| lower.contains(k))
+    })
+}
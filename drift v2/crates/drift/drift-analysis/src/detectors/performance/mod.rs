@@ -1,5 +1,13 @@
 //! Performance detector — N+1 query patterns, unnecessary allocations, hot paths.
 
+pub mod body_limits;
+pub mod pagination;
+pub mod sync_crypto;
+pub mod sync_io;
+pub mod timeouts;
+pub mod transactions;
+
+use drift_core::config::DetectorOptions;
 use smallvec::SmallVec;
 
 use crate::detectors::traits::{Detector, DetectorCategory, DetectorVariant};
@@ -7,7 +15,26 @@ use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
 use crate::engine::visitor::DetectionContext;
 use crate::scanner::language_detect::Language;
 
-pub struct PerformanceDetector;
+#[derive(Debug, Clone, Default)]
+pub struct PerformanceDetector {
+    pagination: pagination::UnboundedQueryCheck,
+    timeouts: timeouts::TimeoutCheck,
+    body_limits: body_limits::BodyLimitCheck,
+}
+
+impl PerformanceDetector {
+    /// Create the detector with the extra sinks, calls and parsers from `options`.
+    pub fn new(options: &DetectorOptions) -> Self {
+        let sinks: Vec<&str> = options.fetch_all_sinks.iter().map(String::as_str).collect();
+        let calls: Vec<&str> = options.outbound_calls.iter().map(String::as_str).collect();
+        let parsers: Vec<&str> = options.body_parsers.iter().map(String::as_str).collect();
+        Self {
+            pagination: pagination::UnboundedQueryCheck::default().with_sinks(&sinks),
+            timeouts: timeouts::TimeoutCheck::default().with_calls(&calls),
+            body_limits: body_limits::BodyLimitCheck::default().with_parsers(&parsers),
+        }
+    }
+}
 
 impl Detector for PerformanceDetector {
     fn id(&self) -> &str { "performance-base" }
@@ -139,6 +166,23 @@ impl Detector for PerformanceDetector {
             }
         }
 
+        matches.extend(self.pagination.detect(ctx));
+        matches.extend(self.timeouts.detect(ctx));
+        matches.extend(sync_crypto::SyncCryptoCheck::default().detect(ctx));
+        matches.extend(self.body_limits.detect(ctx));
+        matches.extend(transactions::TransactionScopeCheck::default().detect(ctx));
+        matches.extend(sync_io::SyncIoCheck::default().detect(ctx));
+
         matches
     }
 }
+
+/// Whether any of `options` is passed by reference (a variable, spread or
+/// `**kwargs`), so its contents cannot be inspected.
+pub(crate) fn has_opaque_options(options: &[String]) -> bool {
+    options.iter().any(|arg| {
+        arg.starts_with("**")
+            || arg.starts_with("...")
+            || arg.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+    })
+}
@@ -0,0 +1,160 @@
+//! Unbounded queries in request handlers — missing pagination.
+//!
+//! `User.findAll()` or `SELECT * FROM users` inside a route handler loads the
+//! whole table on every request. Calls are flagged when they hit a fetch-all
+//! sink with no limit/pagination argument; SQL string literals are flagged
+//! when a `SELECT` has neither a `WHERE` nor a `LIMIT`-style clause.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::call_arguments;
+use crate::detectors::handlers::in_request_handler;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::CallSite;
+
+/// Fetch-all sinks checked by default (Sequelize, Prisma, TypeORM, Django,
+/// SQLAlchemy, Spring Data, ActiveRecord).
+pub const DEFAULT_FETCH_ALL_SINKS: &[&str] = &[
+    "findAll", "find_all", "findMany", "fetchAll", "fetch_all", "getAll", "get_all", "all",
+    "list",
+];
+
+/// Argument or chain keywords that indicate the query is bounded.
+const PAGINATION_KEYWORDS: &[&str] = &[
+    "limit", "take", "top", "first", "page", "per_page", "perpage", "offset", "skip", "cursor",
+    "paginate", "slice", "pageable",
+];
+
+/// Receivers whose `all`/`list` methods are not data access.
+const NON_DATA_RECEIVERS: &[&str] = &["Promise", "Array", "Object", "asyncio", "os", "fs"];
+
+/// Flags unbounded fetch-all queries inside request handlers.
+#[derive(Debug, Clone)]
+pub struct UnboundedQueryCheck {
+    fetch_all_sinks: Vec<String>,
+}
+
+impl UnboundedQueryCheck {
+    /// Create a check over a custom fetch-all sink set.
+    pub fn new(fetch_all_sinks: &[&str]) -> Self {
+        Self {
+            fetch_all_sinks: fetch_all_sinks.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Add framework-specific fetch-all sinks to the current set.
+    pub fn with_sinks(mut self, sinks: &[&str]) -> Self {
+        self.fetch_all_sinks.extend(sinks.iter().map(|s| s.to_string()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let mut matches = Vec::new();
+
+        for call in ctx.call_sites {
+            if !self.is_fetch_all(call) || !in_request_handler(ctx, call.line) {
+                continue;
+            }
+            if is_bounded_call(ctx.source, call) {
+                continue;
+            }
+            let receiver = call.receiver.as_deref().unwrap_or("");
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: call.line,
+                column: call.column,
+                pattern_id: "PERF-UNBOUNDED-004".to_string(),
+                confidence: 0.70,
+                cwe_ids: SmallVec::new(),
+                owasp: None,
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Performance,
+                matched_text: format!(
+                    "{receiver}.{}() without a limit in a request handler — paginate the query",
+                    call.callee_name
+                ),
+            });
+        }
+
+        for lit in &ctx.parse_result.string_literals {
+            if !is_unbounded_select(&lit.value) || !in_request_handler(ctx, lit.line) {
+                continue;
+            }
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: lit.line,
+                column: lit.column,
+                pattern_id: "PERF-UNBOUNDED-004".to_string(),
+                confidence: 0.65,
+                cwe_ids: SmallVec::new(),
+                owasp: None,
+                detection_method: DetectionMethod::StringRegex,
+                category: PatternCategory::Performance,
+                matched_text: "SELECT without LIMIT in a request handler — paginate the query"
+                    .to_string(),
+            });
+        }
+
+        matches
+    }
+
+    fn is_fetch_all(&self, call: &CallSite) -> bool {
+        if !self.fetch_all_sinks.contains(&call.callee_name) {
+            return false;
+        }
+        // `all`/`list` are only data access when called on a model or query.
+        match call.receiver.as_deref() {
+            Some(receiver) => !NON_DATA_RECEIVERS.contains(&receiver),
+            None => !matches!(call.callee_name.as_str(), "all" | "list"),
+        }
+    }
+}
+
+impl Default for UnboundedQueryCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_FETCH_ALL_SINKS)
+    }
+}
+
+/// Whether the call passes a limit/pagination argument or is chained onto one,
+/// e.g. `findAll({ limit })`, `query.limit(10).all()`, `objects.all()[:20]`.
+fn is_bounded_call(source: &[u8], call: &CallSite) -> bool {
+    if call.receiver.as_deref().is_some_and(mentions_pagination) {
+        return true;
+    }
+    if call_arguments(source, call).is_some_and(|args| args.iter().any(|a| mentions_pagination(a))) {
+        return true;
+    }
+
+    // Pagination applied after the call on the same line.
+    let Ok(text) = std::str::from_utf8(source) else {
+        return false;
+    };
+    let Some(line) = text.lines().nth(call.line as usize) else {
+        return false;
+    };
+    let rest = line.get(call.column as usize..).unwrap_or("");
+    let after = rest
+        .find(call.callee_name.as_str())
+        .map(|at| &rest[at + call.callee_name.len()..])
+        .unwrap_or("");
+    after.contains("[:") || mentions_pagination(after)
+}
+
+fn mentions_pagination(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    PAGINATION_KEYWORDS.iter().any(|k| lower.contains(k))
+}
+
+/// A `SELECT` with no filter and no row bound.
+fn is_unbounded_select(sql: &str) -> bool {
+    let upper = sql.trim_start().to_uppercase();
+    if !upper.starts_with("SELECT ") || !upper.contains(" FROM ") {
+        return false;
+    }
+    let bounded = [" WHERE ", " LIMIT ", " TOP ", " FETCH ", " OFFSET ", "ROWNUM"];
+    let aggregate = ["COUNT(", "SUM(", "AVG(", "MIN(", "MAX("];
+    let padded = format!("{upper} ");
+    !bounded.iter().any(|k| padded.contains(k)) && !aggregate.iter().any(|k| upper.contains(k))
+}
@@ -0,0 +1,156 @@
+//! Heavy synchronous cryptography on request threads (CWE-400).
+//!
+//! `bcrypt.hashSync(pw, 12)` or `crypto.pbkdf2Sync(...)` in a Node handler
+//! blocks the event loop for tens of milliseconds per request, so a burst of
+//! logins stalls every other client. Key-stretching and key-generation calls
+//! are flagged in request handlers when they run synchronously: a `*Sync`
+//! variant, or any heavy call inside an `async` handler that is neither
+//! awaited nor handed to a worker/executor. The cost parameter (rounds,
+//! iterations, key size) is reported when it is a literal; literals below the
+//! usual work factors are not flagged.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::{call_arguments, call_path};
+use crate::detectors::handlers::{enclosing_function, in_request_handler};
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::CallSite;
+
+/// Heavy crypto calls checked by default, as the qualified callee text
+/// (bcrypt/bcryptjs, Node crypto, Python bcrypt/hashlib/cryptography/
+/// PyCryptodome, passlib).
+pub const DEFAULT_HEAVY_CRYPTO_CALLS: &[&str] = &[
+    "bcrypt.hashSync", "bcrypt.compareSync", "bcrypt.genSaltSync", "crypto.pbkdf2Sync",
+    "crypto.scryptSync", "crypto.generateKeyPairSync", "pbkdf2Sync", "scryptSync",
+    "generateKeyPairSync", "bcrypt.hashpw", "bcrypt.checkpw", "bcrypt.gensalt",
+    "hashlib.pbkdf2_hmac", "hashlib.scrypt", "rsa.generate_private_key", "RSA.generate",
+    "pbkdf2_sha256.hash",
+];
+
+/// Call text that hands work to another thread or process.
+const OFFLOAD_MARKERS: &[&str] = &[
+    "run_in_executor", "to_thread", "run_sync", "sync_to_async", "Piscina", "workerpool",
+    "new Worker", "threadpool", "spawn_blocking",
+];
+
+/// Flags heavy synchronous crypto called on a request handler's own thread.
+#[derive(Debug, Clone)]
+pub struct SyncCryptoCheck {
+    heavy_calls: Vec<String>,
+}
+
+impl SyncCryptoCheck {
+    /// Create a check over a custom set of qualified heavy crypto calls.
+    pub fn new(heavy_calls: &[&str]) -> Self {
+        Self {
+            heavy_calls: heavy_calls.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Add project-specific heavy calls (e.g. an in-house KDF wrapper) to the current set.
+    pub fn with_calls(mut self, calls: &[&str]) -> Self {
+        self.heavy_calls.extend(calls.iter().map(|s| s.to_string()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let mut matches = Vec::new();
+
+        for call in ctx.call_sites {
+            let Some(target) = self.heavy_target(ctx.source, call) else {
+                continue;
+            };
+            if call.is_await || !in_request_handler(ctx, call.line) {
+                continue;
+            }
+            let blocking = target.ends_with("Sync")
+                || enclosing_function(ctx, call.line).is_some_and(|f| f.is_async);
+            let line = text.lines().nth(call.line as usize).unwrap_or("");
+            if !blocking || OFFLOAD_MARKERS.iter().any(|m| line.contains(m)) {
+                continue;
+            }
+
+            let args = call_arguments(ctx.source, call).unwrap_or_default();
+            let cost = cost_parameter(target, &args);
+            if cost.is_some_and(|(kind, value)| value < minimum_heavy_cost(kind)) {
+                continue;
+            }
+            let cost_text = cost
+                .map(|(kind, value)| format!(" ({kind} {value})"))
+                .unwrap_or_default();
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: call.line,
+                column: call.column,
+                pattern_id: "PERF-CRYPTO-006".to_string(),
+                confidence: 0.65,
+                cwe_ids: SmallVec::from_buf([400, 0]),
+                owasp: None,
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Performance,
+                matched_text: format!(
+                    "{target}(){cost_text} blocks the request thread — use the async API or offload to a worker"
+                ),
+            });
+        }
+
+        matches
+    }
+
+    /// The configured heavy call this call site resolves to, if any.
+    fn heavy_target(&self, source: &[u8], call: &CallSite) -> Option<&str> {
+        let path = call_path(source, call)?;
+        let chain = path.trim().trim_start_matches("await ").trim();
+        self.heavy_calls
+            .iter()
+            .find(|spec| {
+                chain == spec.as_str() || chain.ends_with(&format!(".{spec}"))
+            })
+            .map(String::as_str)
+    }
+}
+
+impl Default for SyncCryptoCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_HEAVY_CRYPTO_CALLS)
+    }
+}
+
+/// The literal work factor passed to a heavy call, as `(kind, value)`.
+fn cost_parameter(target: &str, args: &[String]) -> Option<(&'static str, u64)> {
+    let name = target.rsplit('.').next().unwrap_or(target);
+    let positional = |i: usize| args.get(i).and_then(|a| a.parse::<u64>().ok());
+    match name {
+        "hashSync" => positional(1).map(|v| ("cost", v)),
+        "genSaltSync" | "gensalt" => positional(0).map(|v| ("cost", v)),
+        "pbkdf2Sync" => positional(2).map(|v| ("iterations", v)),
+        "pbkdf2_hmac" => positional(3).map(|v| ("iterations", v)),
+        "generate" => positional(0).map(|v| ("key size", v)),
+        "generateKeyPairSync" => keyword_value(args, "modulusLength").map(|v| ("key size", v)),
+        "generate_private_key" => keyword_value(args, "key_size").map(|v| ("key size", v)),
+        _ => None,
+    }
+}
+
+/// Value of `name: 123` / `name=123` anywhere in the arguments.
+fn keyword_value(args: &[String], name: &str) -> Option<u64> {
+    args.iter().find_map(|arg| {
+        let at = arg.find(name)?;
+        let rest = arg[at + name.len()..].trim_start().strip_prefix([':', '='])?;
+        let digits: String = rest.trim_start().chars().take_while(|c| c.is_ascii_digit()).collect();
+        digits.parse().ok()
+    })
+}
+
+/// Work factors below which a call is cheap enough to run inline.
+fn minimum_heavy_cost(kind: &str) -> u64 {
+    match kind {
+        "cost" => 10,
+        "iterations" => 10_000,
+        _ => 2048,
+    }
+}
@@ -0,0 +1,131 @@
+//! Synchronous file and process I/O on the event loop (CWE-400).
+//!
+//! `fs.readFileSync(path)` inside an Express handler blocks every other
+//! request until the disk answers; the same call while loading configuration
+//! at startup costs nothing that matters. Node's `*Sync` file, process and
+//! compression calls are therefore weighed by where they run: in module scope
+//! or a startup/init function they are not reported, in a request handler
+//! they are reported with high confidence, and in other functions (which may
+//! or may not sit on a request path) with low confidence.
+
+use smallvec::SmallVec;
+
+use crate::detectors::handlers::{enclosing_function, in_request_handler};
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::FunctionInfo;
+
+/// Blocking calls checked by default, by callee name (Node `fs`,
+/// `child_process` and `zlib`). Heavy crypto is covered by `sync_crypto`.
+pub const DEFAULT_SYNC_IO_CALLS: &[&str] = &[
+    "readFileSync", "writeFileSync", "appendFileSync", "readdirSync", "statSync", "lstatSync",
+    "existsSync", "mkdirSync", "unlinkSync", "rmSync", "rmdirSync", "copyFileSync", "renameSync",
+    "accessSync", "openSync", "readSync", "writeSync", "realpathSync", "execSync",
+    "execFileSync", "spawnSync", "gzipSync", "gunzipSync", "deflateSync", "inflateSync",
+    "brotliCompressSync", "brotliDecompressSync",
+];
+
+/// Lowercased function-name fragments that mark startup and initialization code.
+const STARTUP_NAMES: &[&str] = &[
+    "init", "setup", "bootstrap", "startup", "loadconfig", "load_config", "readconfig",
+    "read_config", "configure", "constructor",
+];
+
+/// Where a blocking call runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Placement {
+    ModuleScope,
+    Startup,
+    RequestHandler,
+    Other,
+}
+
+/// Flags synchronous I/O by placement: suppressed at startup, escalated in handlers.
+#[derive(Debug, Clone)]
+pub struct SyncIoCheck {
+    calls: Vec<String>,
+}
+
+impl SyncIoCheck {
+    /// Create a check over a custom set of blocking callee names.
+    pub fn new(calls: &[&str]) -> Self {
+        Self {
+            calls: calls.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Add project-specific blocking calls (e.g. `readJsonSync`) to the current set.
+    pub fn with_calls(mut self, calls: &[&str]) -> Self {
+        self.calls.extend(calls.iter().map(|s| s.to_string()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let mut matches = Vec::new();
+
+        for call in ctx.call_sites {
+            if !self.calls.contains(&call.callee_name) {
+                continue;
+            }
+            let func = enclosing_function(ctx, call.line);
+            let (confidence, place) = match placement(ctx, func, call.line) {
+                Placement::ModuleScope | Placement::Startup => continue,
+                Placement::RequestHandler => (0.75, "a request handler"),
+                Placement::Other if func.is_some_and(|f| f.is_async) => (0.50, "an async function"),
+                Placement::Other => (0.35, "a function that may run per request"),
+            };
+            let name = func
+                .filter(|f| f.name != "<anonymous>")
+                .map(|f| format!(" `{}`", f.name))
+                .unwrap_or_default();
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: call.line,
+                column: call.column,
+                pattern_id: "PERF-SYNC-IO-009".to_string(),
+                confidence,
+                cwe_ids: SmallVec::from_buf([400, 0]),
+                owasp: None,
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Performance,
+                matched_text: format!(
+                    "{}() blocks the event loop in {place}{name} — use the async API",
+                    call.callee_name
+                ),
+            });
+        }
+
+        matches
+    }
+}
+
+impl Default for SyncIoCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_SYNC_IO_CALLS)
+    }
+}
+
+/// Classify the call's position. A request handler anywhere in the nesting
+/// wins over startup naming, so `init` code registering a handler whose body
+/// reads a file is still reported.
+fn placement(ctx: &DetectionContext, func: Option<&FunctionInfo>, line: u32) -> Placement {
+    if func.is_none() {
+        return Placement::ModuleScope;
+    }
+    if in_request_handler(ctx, line) {
+        return Placement::RequestHandler;
+    }
+    let is_startup = ctx
+        .functions
+        .iter()
+        .filter(|f| f.line <= line && line <= f.end_line)
+        .any(|f| {
+            let name = f.name.to_lowercase();
+            name == "main" || STARTUP_NAMES.iter().any(|s| name.contains(s))
+        });
+    if is_startup {
+        Placement::Startup
+    } else {
+        Placement::Other
+    }
+}
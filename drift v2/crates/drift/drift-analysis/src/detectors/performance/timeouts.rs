@@ -0,0 +1,170 @@
+//! Outbound calls without a timeout (CWE-1088).
+//!
+//! `requests.get(url)` or `fetch(url)` waits forever on a hung peer, tying up
+//! a worker per stuck request. Calls to known HTTP, RPC and database clients
+//! are flagged when no argument configures a timeout, deadline or abort
+//! signal. Go `http.Client{}` literals are flagged when they set no
+//! `Timeout`; Go's package-level `http.Get` always uses the default client,
+//! which has none.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::{call_arguments, call_path};
+use crate::detectors::performance::has_opaque_options;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::CallSite;
+use crate::scanner::language_detect::Language;
+
+/// Outbound calls checked by default, as the qualified callee text
+/// (requests, httpx, urllib, fetch, axios, got, Node http, Go net/http,
+/// gRPC, Spring, java.net.http, common database drivers).
+pub const DEFAULT_OUTBOUND_CALLS: &[&str] = &[
+    "requests.get", "requests.post", "requests.put", "requests.patch", "requests.delete",
+    "requests.head", "requests.request", "httpx.get", "httpx.post", "httpx.put", "httpx.delete",
+    "urlopen", "fetch", "axios", "axios.get", "axios.post", "axios.put", "axios.patch",
+    "axios.delete", "axios.request", "got", "http.request", "https.request", "http.get",
+    "https.get", "http.Get", "http.Post", "http.Head", "http.PostForm", "net.Dial", "grpc.Dial",
+    "RestTemplate", "HttpClient.newHttpClient", "psycopg2.connect", "pymysql.connect",
+    "mysql.createConnection", "MongoClient",
+];
+
+/// Calls that cannot take a timeout at all: the fix is a different client.
+const NO_TIMEOUT_PARAMETER: &[&str] = &[
+    "http.Get", "http.Post", "http.Head", "http.PostForm", "net.Dial", "HttpClient.newHttpClient",
+];
+
+/// Argument fragments that configure a timeout.
+const TIMEOUT_KEYWORDS: &[&str] = &["timeout", "signal", "deadline"];
+
+/// Flags outbound calls made without a configured timeout.
+#[derive(Debug, Clone)]
+pub struct TimeoutCheck {
+    outbound_calls: Vec<String>,
+}
+
+impl TimeoutCheck {
+    /// Create a check over a custom set of qualified outbound calls.
+    pub fn new(outbound_calls: &[&str]) -> Self {
+        Self {
+            outbound_calls: outbound_calls.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Add framework-specific outbound calls to the current set.
+    pub fn with_calls(mut self, calls: &[&str]) -> Self {
+        self.outbound_calls.extend(calls.iter().map(|s| s.to_string()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let mut matches = Vec::new();
+
+        for call in ctx.call_sites {
+            let Some(target) = self.outbound_target(ctx.source, call) else {
+                continue;
+            };
+            let args = call_arguments(ctx.source, call).unwrap_or_default();
+            let unconfigurable = NO_TIMEOUT_PARAMETER.contains(&target);
+            if !unconfigurable && (configures_timeout(&args) || has_opaque_options(option_args(target, &args))) {
+                continue;
+            }
+            let advice = if unconfigurable {
+                "use a client with a timeout"
+            } else {
+                "pass a timeout"
+            };
+            matches.push(timeout_match(
+                ctx,
+                call.line,
+                call.column,
+                format!("{target}() without a timeout — {advice}"),
+            ));
+        }
+
+        if ctx.language == Language::Go {
+            matches.extend(go_clients_without_timeout(ctx));
+        }
+
+        matches
+    }
+
+    /// The configured outbound call this call site resolves to, if any.
+    fn outbound_target(&self, source: &[u8], call: &CallSite) -> Option<&str> {
+        let path = call_path(source, call)?;
+        let chain = path
+            .trim()
+            .trim_start_matches("await ")
+            .trim_start_matches("new ")
+            .trim();
+        self.outbound_calls
+            .iter()
+            .find(|spec| {
+                // Bare names (`fetch`) must be unqualified: `repo.fetch()` is not HTTP.
+                chain == spec.as_str()
+                    || (spec.contains('.') && chain.ends_with(&format!(".{spec}")))
+            })
+            .map(String::as_str)
+    }
+}
+
+impl Default for TimeoutCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_OUTBOUND_CALLS)
+    }
+}
+
+fn configures_timeout(args: &[String]) -> bool {
+    args.iter().any(|arg| {
+        let lower = arg.to_lowercase();
+        TIMEOUT_KEYWORDS.iter().any(|k| lower.contains(k))
+    })
+}
+
+/// The arguments that may carry options. Functions take the target first;
+/// constructors (`RestTemplate(factory)`) take only options.
+fn option_args<'a>(target: &str, args: &'a [String]) -> &'a [String] {
+    let is_constructor = target.starts_with(|c: char| c.is_ascii_uppercase());
+    args.get(usize::from(!is_constructor)..).unwrap_or_default()
+}
+
+/// `http.Client{...}` composite literals that set no `Timeout`.
+fn go_clients_without_timeout(ctx: &DetectionContext) -> Vec<PatternMatch> {
+    let Ok(text) = std::str::from_utf8(ctx.source) else {
+        return Vec::new();
+    };
+    let mut matches = Vec::new();
+    for (start, _) in text.match_indices("http.Client{") {
+        let body_start = start + "http.Client{".len();
+        let Some(len) = text[body_start..].find('}') else {
+            continue;
+        };
+        if text[body_start..body_start + len].contains("Timeout") {
+            continue;
+        }
+        let line = text[..start].matches('\n').count();
+        let column = start - text[..start].rfind('\n').map_or(0, |i| i + 1);
+        matches.push(timeout_match(
+            ctx,
+            line as u32,
+            column as u32,
+            "http.Client{} without a Timeout — set Timeout on the client".to_string(),
+        ));
+    }
+    matches
+}
+
+fn timeout_match(ctx: &DetectionContext, line: u32, column: u32, matched_text: String) -> PatternMatch {
+    PatternMatch {
+        file: ctx.file.to_string(),
+        line,
+        column,
+        pattern_id: "PERF-TIMEOUT-005".to_string(),
+        confidence: 0.70,
+        cwe_ids: SmallVec::from_buf([1088, 0]),
+        owasp: None,
+        detection_method: DetectionMethod::AstVisitor,
+        category: PatternCategory::Performance,
+        matched_text,
+    }
+}
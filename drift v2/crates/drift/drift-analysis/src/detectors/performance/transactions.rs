@@ -0,0 +1,225 @@
+//! Database transactions held open across external calls.
+//!
+//! `beginTransaction(); await httpCall(); commit();` keeps row locks and a
+//! pooled connection for as long as the remote service takes to answer, so
+//! one slow dependency stalls every writer behind those locks. Within each
+//! function, call sites are ordered by position: an external call (HTTP, RPC,
+//! messaging, email) made after a transaction begins and before its commit
+//! or rollback is flagged. Transactions scoped by a decorator
+//! (`@Transactional`, `@transaction.atomic`) or a Python
+//! `with transaction.atomic():` block close at the end of that scope.
+//! Callback-style transactions (`db.transaction(async trx => ...)`) are not
+//! followed.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::{call_arguments, call_path};
+use crate::detectors::handlers::FunctionIndex;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::{CallSite, FunctionInfo};
+
+/// External calls checked by default, as the qualified callee text (HTTP and
+/// RPC clients, message brokers, mail senders).
+pub const DEFAULT_EXTERNAL_CALLS: &[&str] = &[
+    "fetch", "axios", "axios.get", "axios.post", "axios.put", "axios.patch", "axios.delete",
+    "axios.request", "got", "http.request", "https.request", "http.get", "https.get",
+    "requests.get", "requests.post", "requests.put", "requests.patch", "requests.delete",
+    "requests.request", "httpx.get", "httpx.post", "urlopen", "http.Get", "http.Post",
+    "restTemplate.getForObject", "restTemplate.postForObject", "restTemplate.exchange",
+    "webClient.get", "webClient.post", "producer.send", "channel.publish", "sns.publish",
+    "sqs.sendMessage", "transporter.sendMail", "send_mail", "smtp.sendmail",
+];
+
+/// Callee fragments (lowercased) of project clients and wrappers around
+/// external calls (`httpCall`, `this.http.get`, `notifyWebhook`).
+const EXTERNAL_NAME_FRAGMENTS: &[&str] = &["http", "webhook", "rpc", "remote", "sendmail", "send_mail", "sendemail", "send_email"];
+
+/// Calls that open a transaction. `begin`/`Begin` only count with a receiver.
+const BEGIN_CALLS: &[&str] = &[
+    "beginTransaction", "begin_transaction", "startTransaction", "start_transaction",
+    "BeginTransaction", "BeginTx", "begin", "Begin",
+];
+
+/// Calls that end a transaction.
+const END_CALLS: &[&str] = &[
+    "commit", "Commit", "commitTransaction", "commit_transaction", "rollback", "Rollback",
+    "rollbackTransaction", "abortTransaction",
+];
+
+/// Decorators that run the whole function in a transaction.
+const TRANSACTIONAL_DECORATORS: &[&str] = &["transactional", "transaction.atomic", "atomic"];
+
+/// Flags external calls made while a database transaction is open.
+#[derive(Debug, Clone)]
+pub struct TransactionScopeCheck {
+    external_calls: Vec<String>,
+}
+
+impl TransactionScopeCheck {
+    /// Create a check over a custom set of qualified external calls.
+    pub fn new(external_calls: &[&str]) -> Self {
+        Self {
+            external_calls: external_calls.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Add project clients (e.g. `billing.charge`) to the current set.
+    pub fn with_calls(mut self, calls: &[&str]) -> Self {
+        self.external_calls.extend(calls.iter().map(|s| s.to_string()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let lines: Vec<&str> = std::str::from_utf8(ctx.source).map_or(Vec::new(), |t| t.lines().collect());
+        let mut matches = Vec::new();
+
+        let index = FunctionIndex::new(ctx.functions);
+        let mut calls_by_function: Vec<Vec<&CallSite>> = vec![Vec::new(); ctx.functions.len()];
+        for call in ctx.call_sites {
+            if let Some(i) = index.enclosing(call.line) {
+                calls_by_function[i].push(call);
+            }
+        }
+
+        for (func, mut calls) in ctx.functions.iter().zip(calls_by_function) {
+            calls.sort_by_key(|c| (c.line, c.column));
+
+            let decorated = is_transactional(func);
+            // (line the transaction opened, last line of its scope if block-scoped)
+            let mut open: Option<(u32, Option<u32>)> = decorated.then_some((func.line, None));
+            let mut held: Vec<(&CallSite, String, u32)> = Vec::new();
+
+            for call in calls {
+                if let Some((_, Some(end))) = open {
+                    if call.line > end {
+                        matches.extend(held.drain(..).map(|h| held_match(ctx, func, h, 0.55)));
+                        open = decorated.then_some((func.line, None));
+                    }
+                }
+                match self.classify(ctx.source, &lines, call) {
+                    // Nested begins (savepoints) stay inside the outer transaction.
+                    Some(Event::Begin(scope_end)) if open.is_none() => {
+                        open = Some((call.line, scope_end));
+                    }
+                    Some(Event::End) => {
+                        let confidence = if decorated { 0.55 } else { 0.60 };
+                        matches.extend(held.drain(..).map(|h| held_match(ctx, func, h, confidence)));
+                        open = None;
+                    }
+                    Some(Event::External(target)) => {
+                        if let Some((begin, _)) = open {
+                            held.push((call, target, begin));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            // Block and decorator scopes end with the function.
+            if decorated || matches!(open, Some((_, Some(_)))) {
+                matches.extend(held.drain(..).map(|h| held_match(ctx, func, h, 0.55)));
+            }
+        }
+
+        matches
+    }
+
+    fn classify(&self, source: &[u8], lines: &[&str], call: &CallSite) -> Option<Event> {
+        let path = call_path(source, call).unwrap_or_default();
+        let chain = path.trim().trim_start_matches("await ").trim_start_matches("new ").trim();
+        let name = call.callee_name.as_str();
+        let chain = if chain.is_empty() { name } else { chain };
+        let qualified = chain.contains('.') || chain.contains("->");
+
+        if name == "atomic" {
+            let line = lines.get(call.line as usize).copied().unwrap_or("");
+            return line.trim_start().starts_with("with ").then(|| Event::Begin(Some(block_end(lines, call.line))));
+        }
+        if BEGIN_CALLS.contains(&name) && (qualified || !name.eq_ignore_ascii_case("begin")) {
+            return Some(Event::Begin(None));
+        }
+        if END_CALLS.contains(&name) {
+            return Some(Event::End);
+        }
+        if matches!(name, "query" | "execute" | "exec" | "raw") {
+            let args = call_arguments(source, call).unwrap_or_default();
+            let sql = args.first().map(|a| a.trim_matches(['"', '\'', '`']).to_uppercase()).unwrap_or_default();
+            if sql.starts_with("BEGIN") || sql.starts_with("START TRANSACTION") {
+                return Some(Event::Begin(None));
+            }
+            if sql.starts_with("COMMIT") || sql.starts_with("ROLLBACK") {
+                return Some(Event::End);
+            }
+        }
+
+        let spec = self.external_calls.iter().find(|spec| {
+            chain == spec.as_str() || (spec.contains('.') && chain.ends_with(&format!(".{spec}")))
+        });
+        if let Some(spec) = spec {
+            return Some(Event::External(spec.clone()));
+        }
+        let lower = chain.to_lowercase();
+        EXTERNAL_NAME_FRAGMENTS
+            .iter()
+            .any(|f| lower.contains(f))
+            .then(|| Event::External(chain.to_string()))
+    }
+}
+
+impl Default for TransactionScopeCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_EXTERNAL_CALLS)
+    }
+}
+
+enum Event {
+    /// A transaction opens; `Some(line)` when it is scoped to a block ending there.
+    Begin(Option<u32>),
+    End,
+    External(String),
+}
+
+fn is_transactional(func: &FunctionInfo) -> bool {
+    func.decorators.iter().any(|d| {
+        let name = d.name.trim_start_matches('@').split('(').next().unwrap_or("").to_lowercase();
+        TRANSACTIONAL_DECORATORS.contains(&name.as_str())
+    })
+}
+
+/// Last line of the indented block opened at `line` (Python `with`).
+fn block_end(lines: &[&str], line: u32) -> u32 {
+    let indent = |l: &str| l.len() - l.trim_start().len();
+    let opener = indent(lines.get(line as usize).copied().unwrap_or(""));
+    let mut end = line;
+    for (idx, text) in lines.iter().enumerate().skip(line as usize + 1) {
+        if text.trim().is_empty() {
+            continue;
+        }
+        if indent(text) <= opener {
+            break;
+        }
+        end = idx as u32;
+    }
+    end
+}
+
+fn held_match(ctx: &DetectionContext, func: &FunctionInfo, held: (&CallSite, String, u32), confidence: f32) -> PatternMatch {
+    let (call, target, begin) = held;
+    PatternMatch {
+        file: ctx.file.to_string(),
+        line: call.line,
+        column: call.column,
+        pattern_id: "PERF-TXN-IO-008".to_string(),
+        confidence,
+        cwe_ids: SmallVec::new(),
+        owasp: None,
+        detection_method: DetectionMethod::AstVisitor,
+        category: PatternCategory::Performance,
+        matched_text: format!(
+            "`{}` calls {target}() inside the transaction opened on line {} — move external calls outside the transaction",
+            func.name,
+            begin + 1,
+        ),
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::collections::HashSet;
 
+use drift_core::config::DetectorOptions;
 
 use super::traits::{Detector, DetectorCategory};
 use crate::engine::types::PatternMatch;
@@ -12,6 +13,8 @@ pub struct DetectorRegistry {
     detectors: Vec<Box<dyn Detector>>,
     disabled: HashSet<String>,
     critical_only: bool,
+    /// Categories that skip files classified as test code.
+    test_excluded: HashSet<DetectorCategory>,
 }
 
 impl DetectorRegistry {
@@ -21,6 +24,7 @@ impl DetectorRegistry {
             detectors: Vec::new(),
             disabled: HashSet::new(),
             critical_only: false,
+            test_excluded: HashSet::new(),
         }
     }
 
@@ -53,11 +57,41 @@ impl DetectorRegistry {
         }
     }
 
+    /// Skip a category's detectors on test files (e.g. security, so fixture
+    /// credentials are not reported) while other categories still run there.
+    pub fn exclude_tests(&mut self, category: DetectorCategory) {
+        self.test_excluded.insert(category);
+    }
+
+    /// Run a category's detectors on test files again.
+    pub fn include_tests(&mut self, category: DetectorCategory) {
+        self.test_excluded.remove(&category);
+    }
+
+    /// Exclude test files from each category named in `categories`
+    /// (`AnalysisConfig::exclude_tests_from`). Unknown names are ignored with
+    /// a warning.
+    pub fn exclude_tests_from(&mut self, categories: &[String]) {
+        for name in categories {
+            match DetectorCategory::all().iter().find(|c| c.name() == name.trim()) {
+                Some(category) => self.exclude_tests(*category),
+                None => tracing::warn!(category = %name, "unknown detector category in exclude_tests_from"),
+            }
+        }
+    }
+
     /// Run all enabled detectors on the given context.
     pub fn run_all(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        self.run_all_for(ctx, false)
+    }
+
+    /// Run all enabled detectors on a file the scanner classified
+    /// (`ScanEntry::is_test_file`), skipping test-excluded categories on
+    /// test files.
+    pub fn run_all_for(&self, ctx: &DetectionContext, is_test_file: bool) -> Vec<PatternMatch> {
         let mut matches = Vec::new();
         for detector in &self.detectors {
-            if self.should_run(detector.as_ref()) {
+            if self.should_run(detector.as_ref()) && !(is_test_file && self.test_excluded.contains(&detector.category())) {
                 let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
                     detector.detect(ctx)
                 }));
@@ -133,14 +167,20 @@ impl Default for DetectorRegistry {
 
 /// Create a registry with all 16 categories populated (at least 1 detector each).
 pub fn create_default_registry() -> DetectorRegistry {
+    create_registry(&DetectorOptions::default())
+}
+
+/// Create the default registry with detectors configured from `options`
+/// (`AnalysisConfig::detectors`).
+pub fn create_registry(options: &DetectorOptions) -> DetectorRegistry {
     let mut registry = DetectorRegistry::new();
 
     // Priority 5 categories with full implementations
-    registry.register(Box::new(super::security::SecurityDetector));
+    registry.register(Box::new(super::security::SecurityDetector::new(options)));
     registry.register(Box::new(super::data_access::DataAccessDetector));
     registry.register(Box::new(super::errors::ErrorsDetector));
     registry.register(Box::new(super::testing::TestingDetector));
-    registry.register(Box::new(super::structural::StructuralDetector));
+    registry.register(Box::new(super::structural::StructuralDetector::new(options)));
 
     // Remaining 11 categories with skeleton detectors
     registry.register(Box::new(super::api::ApiDetector));
@@ -149,8 +189,8 @@ pub fn create_default_registry() -> DetectorRegistry {
     registry.register(Box::new(super::config::ConfigDetector));
     registry.register(Box::new(super::contracts::ContractsDetector));
     registry.register(Box::new(super::documentation::DocumentationDetector));
-    registry.register(Box::new(super::logging::LoggingDetector));
-    registry.register(Box::new(super::performance::PerformanceDetector));
+    registry.register(Box::new(super::logging::LoggingDetector::new(options)));
+    registry.register(Box::new(super::performance::PerformanceDetector::new(options)));
     registry.register(Box::new(super::styling::StylingDetector));
     registry.register(Box::new(super::types::TypesDetector));
     registry.register(Box::new(super::accessibility::AccessibilityDetector));
@@ -0,0 +1,136 @@
+//! Hardcoded cleartext HTTP endpoints (CWE-319).
+//!
+//! `fetch("http://api.example.com/users")` sends credentials and payloads
+//! unencrypted. String literals that are `http://` URLs are flagged unless
+//! the host is loopback, a local-only name, an XML/schema namespace URI, or
+//! allowlisted. Literals passed straight to a request call are reported with
+//! higher confidence than endpoints held in variables or config objects.
+//! Comments and docstrings never yield literals starting with the URL, so
+//! documentation mentions are not reported.
+
+use smallvec::SmallVec;
+
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::{StringContext, StringLiteralInfo};
+
+/// Request calls whose URL argument is a request target.
+const REQUEST_CALLEES: &[&str] = &[
+    "fetch", "get", "post", "put", "patch", "delete", "head", "request", "axios", "urlopen",
+    "Request", "open", "ajax", "getJSON", "NewRequest", "Get", "Post", "URL", "URI", "create",
+    "send", "connect",
+];
+
+/// Hosts that only resolve locally.
+const LOCAL_HOSTS: &[&str] = &["localhost", "0.0.0.0", "[::1]", "host.docker.internal"];
+
+/// TLDs reserved for local or test use.
+const LOCAL_SUFFIXES: &[&str] = &[".localhost", ".local", ".test", ".internal", ".invalid"];
+
+/// Hosts whose `http://` URIs are identifiers (XML namespaces, schemas), not endpoints.
+const NAMESPACE_HOSTS: &[&str] = &[
+    "www.w3.org", "schemas.xmlsoap.org", "schemas.microsoft.com", "schemas.android.com",
+    "xmlns.com", "purl.org", "json-schema.org", "ns.adobe.com", "www.apache.org",
+    "java.sun.com", "xmlpull.org",
+];
+
+/// Flags hardcoded `http://` endpoints outside loopback and allowlisted hosts.
+#[derive(Debug, Clone, Default)]
+pub struct CleartextUrlCheck {
+    allowed_hosts: Vec<String>,
+}
+
+impl CleartextUrlCheck {
+    /// Create a check that also ignores the given hosts (and their subdomains).
+    pub fn new(allowed_hosts: &[&str]) -> Self {
+        Self {
+            allowed_hosts: allowed_hosts.iter().map(|h| h.to_lowercase()).collect(),
+        }
+    }
+
+    /// Add hosts (e.g. an internal mesh domain) to the allowlist.
+    pub fn with_hosts(mut self, hosts: &[&str]) -> Self {
+        self.allowed_hosts.extend(hosts.iter().map(|h| h.to_lowercase()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let mut matches = Vec::new();
+
+        for lit in &ctx.parse_result.string_literals {
+            let Some(host) = cleartext_host(&lit.value) else {
+                continue;
+            };
+            if is_local(&host) || self.is_allowed(&host) {
+                continue;
+            }
+
+            let (confidence, usage) = if is_request_target(ctx, lit) {
+                (0.85, "request to")
+            } else {
+                match lit.context {
+                    StringContext::VariableAssignment
+                    | StringContext::ObjectProperty
+                    | StringContext::FunctionArgument => (0.65, "endpoint"),
+                    _ => (0.45, "URL"),
+                }
+            };
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: lit.line,
+                column: lit.column,
+                pattern_id: "SEC-HTTP-001".to_string(),
+                confidence,
+                cwe_ids: SmallVec::from_buf([319, 0]),
+                owasp: Some("A02:2021".to_string()),
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Security,
+                matched_text: format!("Cleartext http:// {usage} {host} — use https://"),
+            });
+        }
+
+        matches
+    }
+
+    fn is_allowed(&self, host: &str) -> bool {
+        NAMESPACE_HOSTS.contains(&host)
+            || self
+                .allowed_hosts
+                .iter()
+                .any(|a| host == a || host.ends_with(&format!(".{a}")))
+    }
+}
+
+/// Lowercased host of an `http://` URL literal, without port or credentials.
+fn cleartext_host(value: &str) -> Option<String> {
+    let rest = value.trim().strip_prefix("http://")?;
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or("");
+    let authority = authority.rsplit('@').next().unwrap_or(authority);
+    let host = if authority.starts_with('[') {
+        authority.find(']').map_or(authority, |end| &authority[..=end])
+    } else {
+        authority.split(':').next().unwrap_or(authority)
+    };
+    // Templates such as `http://${host}` or `http://%s` have no fixed host.
+    let is_literal_host = !host.is_empty()
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '[' | ']' | ':'));
+    is_literal_host.then(|| host.to_lowercase())
+}
+
+fn is_local(host: &str) -> bool {
+    LOCAL_HOSTS.contains(&host)
+        || host.starts_with("127.")
+        || LOCAL_SUFFIXES.iter().any(|s| host.ends_with(s))
+}
+
+/// Whether the literal is an argument of a request call on the same line.
+fn is_request_target(ctx: &DetectionContext, lit: &StringLiteralInfo) -> bool {
+    lit.context == StringContext::FunctionArgument
+        && ctx.call_sites.iter().any(|call| {
+            call.line == lit.line
+                && call.column <= lit.column
+                && REQUEST_CALLEES.contains(&call.callee_name.as_str())
+        })
+}
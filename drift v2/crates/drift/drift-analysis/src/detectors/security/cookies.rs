@@ -0,0 +1,215 @@
+//! Cookies set without `HttpOnly`, `Secure` or `SameSite` (CWE-1004, CWE-614, CWE-1275).
+//!
+//! `res.cookie("sid", token)` sends a cookie scripts can read, over plain
+//! HTTP, on cross-site requests. Cookie-setting calls are flagged when their
+//! options (or, for Java `new Cookie` and Spring `ResponseCookie`, the
+//! builder calls that follow) leave any of the three attributes unset or set
+//! to false. Java servlet cookies are not required to set `SameSite`, which
+//! the classic API cannot express. Options passed by reference cannot be
+//! inspected and are not flagged. Cookies whose name marks a session or
+//! credential are reported with higher confidence.
+
+use crate::detectors::call_args::{call_arguments, call_path, text_after_call};
+use crate::detectors::handlers::enclosing_function;
+use crate::detectors::sinks::SinkSet;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::CallSite;
+
+/// Cookie-setting calls checked by default, as a bare callee name or a
+/// qualified callee path (Express, Fastify, Koa, Flask/Django, PHP, ASP.NET,
+/// Go net/http, Java servlets, Spring `ResponseCookie`).
+pub const DEFAULT_COOKIE_SETTERS: &[&str] = &[
+    "res.cookie", "response.cookie", "reply.setCookie", "cookies.set", "set_cookie", "setcookie",
+    "Cookies.Append", "http.SetCookie", "Cookie", "ResponseCookie.from",
+];
+
+/// Name fragments (lowercased) of session and credential cookies.
+const SESSION_NAMES: &[&str] = &["session", "sess", "sid", "auth", "token", "jwt", "remember", "login"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Attribute {
+    HttpOnly,
+    Secure,
+    SameSite,
+}
+
+impl Attribute {
+    fn label(self) -> &'static str {
+        match self {
+            Self::HttpOnly => "HttpOnly",
+            Self::Secure => "Secure",
+            Self::SameSite => "SameSite",
+        }
+    }
+
+    fn cwe(self) -> u32 {
+        match self {
+            Self::HttpOnly => 1004,
+            Self::Secure => 614,
+            Self::SameSite => 1275,
+        }
+    }
+
+    /// Lowercased option/setter spellings across frameworks.
+    fn keys(self) -> &'static [&'static str] {
+        match self {
+            Self::HttpOnly => &["httponly", "http_only"],
+            Self::Secure => &["secure"],
+            Self::SameSite => &["samesite", "same_site"],
+        }
+    }
+}
+
+/// Flags cookies set without `HttpOnly`, `Secure` or `SameSite`.
+#[derive(Debug, Clone)]
+pub struct CookieCheck {
+    setters: SinkSet,
+}
+
+impl CookieCheck {
+    /// Create a check over a custom set of cookie-setting calls.
+    pub fn new(setters: &[&str]) -> Self {
+        Self {
+            setters: SinkSet::new(setters),
+        }
+    }
+
+    /// Add framework-specific cookie setters (e.g. `h.state`) to the current set.
+    pub fn with_setters(mut self, setters: &[&str]) -> Self {
+        self.setters.extend(setters);
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let mut matches = Vec::new();
+
+        for call in ctx.call_sites {
+            let Some(setter) = self.setter_target(ctx.source, call) else {
+                continue;
+            };
+            let args = call_arguments(ctx.source, call).unwrap_or_default();
+            let (options, required): (String, &[Attribute]) = match setter {
+                "Cookie" => (following_text(ctx, &lines, call), &[Attribute::HttpOnly, Attribute::Secure]),
+                "ResponseCookie.from" => (builder_chain(ctx.source, call), &[Attribute::HttpOnly, Attribute::Secure, Attribute::SameSite]),
+                "setcookie" => match php_options(&args) {
+                    Some(options) => (options, &[Attribute::HttpOnly, Attribute::Secure, Attribute::SameSite]),
+                    None => continue,
+                },
+                _ => {
+                    let from = if setter == "http.SetCookie" { 1 } else { 2 };
+                    let rest = args.get(from..).unwrap_or_default();
+                    if rest.iter().any(|a| is_opaque(a)) {
+                        continue;
+                    }
+                    (rest.join(", "), &[Attribute::HttpOnly, Attribute::Secure, Attribute::SameSite])
+                }
+            };
+            let options = options.to_lowercase();
+            let missing: Vec<Attribute> = required.iter().copied().filter(|a| !enables(&options, *a)).collect();
+            if missing.is_empty() {
+                continue;
+            }
+
+            let name_arg = if setter == "http.SetCookie" { None } else { args.first() };
+            let name = name_arg.map(|n| n.trim_matches(['"', '\'', '`']).to_string()).unwrap_or_default();
+            let session = {
+                let lower = name.to_lowercase();
+                SESSION_NAMES.iter().any(|s| lower.contains(s))
+            };
+            let (confidence, kind) = if session { (0.70, "Session cookie") } else { (0.45, "Cookie") };
+            let label = if name.is_empty() || name.contains(char::is_whitespace) { String::new() } else { format!(" `{name}`") };
+            let missing_labels: Vec<&str> = missing.iter().map(|a| a.label()).collect();
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: call.line,
+                column: call.column,
+                pattern_id: "SEC-COOKIE-001".to_string(),
+                confidence,
+                cwe_ids: missing.iter().map(|a| a.cwe()).collect(),
+                owasp: Some("A05:2021".to_string()),
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Security,
+                matched_text: format!("{kind}{label} set without {}", missing_labels.join(", ")),
+            });
+        }
+
+        matches
+    }
+
+    /// The configured setter this call site resolves to, if any.
+    fn setter_target(&self, source: &[u8], call: &CallSite) -> Option<&str> {
+        let path = call_path(source, call).unwrap_or_default();
+        self.setters.matching(call, &path)
+    }
+}
+
+impl Default for CookieCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_COOKIE_SETTERS)
+    }
+}
+
+/// Whether the lowercased option text turns `attribute` on: the key (or its
+/// `set` setter) appears and is not set to false/none. `insecure` and
+/// `secureProxy` do not count as `secure`.
+fn enables(options: &str, attribute: Attribute) -> bool {
+    let is_ident = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    attribute.keys().iter().any(|key| {
+        options.match_indices(key).any(|(i, _)| {
+            let rest = &options[i + key.len()..];
+            if (is_ident(options[..i].chars().next_back()) && !options[..i].ends_with("set"))
+                || is_ident(rest.chars().next())
+            {
+                return false;
+            }
+            let value = rest.trim_start_matches(|c: char| c.is_whitespace() || matches!(c, ':' | '=' | '(' | '"' | '\''));
+            !["false", "none", "nil", "0"].iter().any(|off| value.starts_with(off))
+        })
+    })
+}
+
+/// Options passed as a variable or spread cannot be inspected.
+fn is_opaque(arg: &str) -> bool {
+    let is_reference = arg.starts_with(|c: char| c.is_alphabetic() || c == '_' || c == '$')
+        && arg.chars().all(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '$'))
+        && !matches!(arg, "true" | "false" | "True" | "False" | "None" | "null" | "nil");
+    arg.starts_with("...") || arg.starts_with("**") || is_reference
+}
+
+/// PHP `setcookie`: the options array (PHP 7.3+) or `secure`/`httponly`
+/// positional flags rendered as option text. `None` when options are opaque.
+fn php_options(args: &[String]) -> Option<String> {
+    match args.get(2) {
+        Some(options) if options.contains("=>") => Some(options.clone()),
+        Some(options) if options.starts_with('$') && args.len() == 3 => None,
+        _ => {
+            let flag = |i: usize| args.get(i).is_some_and(|a| a.eq_ignore_ascii_case("true"));
+            let mut text = Vec::new();
+            if flag(5) {
+                text.push("secure");
+            }
+            if flag(6) {
+                text.push("httponly");
+            }
+            Some(text.join(", "))
+        }
+    }
+}
+
+/// Java `new Cookie(...)`: the rest of the enclosing function, where
+/// `setSecure(true)` and `setHttpOnly(true)` would be called.
+fn following_text(ctx: &DetectionContext, lines: &[&str], call: &CallSite) -> String {
+    let end = enclosing_function(ctx, call.line).map_or(lines.len(), |f| f.end_line as usize + 1);
+    lines.get(call.line as usize..end.min(lines.len())).unwrap_or_default().join("\n")
+}
+
+/// Spring `ResponseCookie.from(...)`: the builder chain up to the statement end.
+fn builder_chain(source: &[u8], call: &CallSite) -> String {
+    let after = text_after_call(source, call).unwrap_or("");
+    after.split(';').next().unwrap_or("").to_string()
+}
@@ -0,0 +1,213 @@
+//! Insecure deserialization (CWE-502).
+//!
+//! Native object deserializers rebuild arbitrary types from their input, so
+//! a crafted payload can run code while it is being read. Flagged calls:
+//! `pickle.load(s)`, `yaml.load` without a safe loader and
+//! `yaml.unsafe_load` (Python), `ObjectInputStream.readObject` (Java,
+//! Kotlin, Scala), `unserialize` without `allowed_classes => false` (PHP),
+//! `Marshal.load` (Ruby) and `BinaryFormatter`-family `Deserialize` (C#).
+//! `readObject` and `Deserialize` only count when the receiver is created
+//! or declared as one of those types in the same function.
+//!
+//! A safe variant used nearby (`yaml.safe_load`, an `ObjectInputFilter`,
+//! a `SerializationBinder`, ...) lowers confidence rather than clearing the
+//! call: it shows awareness, not that this input is trusted.
+
+use smallvec::SmallVec;
+
+use crate::detectors::handlers::enclosing_scope;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::scanner::language_detect::Language;
+
+/// Confidence removed when the enclosing function also uses a safe variant.
+const MITIGATED_PENALTY: f32 = 0.35;
+
+/// A deserialization API that can instantiate arbitrary types.
+struct Sink {
+    /// Call text; must stand alone as an identifier path.
+    call: &'static str,
+    languages: &'static [Language],
+    /// Receiver types that make a method call (`.readObject`) a sink.
+    /// Empty for free functions.
+    receivers: &'static [&'static str],
+    /// Arguments on the call's line that make it safe.
+    safe_args: &'static [&'static str],
+    /// Safe variants that lower confidence when used in the same function.
+    mitigations: &'static [&'static str],
+    confidence: f32,
+}
+
+const SINKS: &[Sink] = &[
+    Sink {
+        call: "pickle.loads",
+        languages: &[Language::Python],
+        receivers: &[],
+        safe_args: &[],
+        mitigations: &["hmac.compare_digest"],
+        confidence: 0.85,
+    },
+    Sink {
+        call: "pickle.load",
+        languages: &[Language::Python],
+        receivers: &[],
+        safe_args: &[],
+        mitigations: &["hmac.compare_digest"],
+        confidence: 0.85,
+    },
+    Sink {
+        call: "yaml.load",
+        languages: &[Language::Python],
+        receivers: &[],
+        safe_args: &["SafeLoader", "CSafeLoader", "BaseLoader"],
+        mitigations: &["yaml.safe_load"],
+        confidence: 0.80,
+    },
+    Sink {
+        call: "yaml.unsafe_load",
+        languages: &[Language::Python],
+        receivers: &[],
+        safe_args: &[],
+        mitigations: &[],
+        confidence: 0.90,
+    },
+    Sink {
+        call: ".readObject",
+        languages: &[Language::Java, Language::Kotlin, Language::Scala],
+        receivers: &["ObjectInputStream"],
+        safe_args: &[],
+        mitigations: &["ObjectInputFilter", "ValidatingObjectInputStream", "resolveClass"],
+        confidence: 0.85,
+    },
+    Sink {
+        call: "unserialize",
+        languages: &[Language::Php],
+        receivers: &[],
+        safe_args: &["'allowed_classes' => false", "\"allowed_classes\" => false"],
+        mitigations: &["allowed_classes"],
+        confidence: 0.85,
+    },
+    Sink {
+        call: "Marshal.load",
+        languages: &[Language::Ruby],
+        receivers: &[],
+        safe_args: &[],
+        mitigations: &[],
+        confidence: 0.85,
+    },
+    Sink {
+        call: "Marshal.restore",
+        languages: &[Language::Ruby],
+        receivers: &[],
+        safe_args: &[],
+        mitigations: &[],
+        confidence: 0.85,
+    },
+    Sink {
+        call: ".Deserialize",
+        languages: &[Language::CSharp],
+        receivers: &[
+            "BinaryFormatter", "SoapFormatter", "NetDataContractSerializer", "LosFormatter",
+            "ObjectStateFormatter",
+        ],
+        safe_args: &[],
+        mitigations: &["SerializationBinder"],
+        confidence: 0.90,
+    },
+];
+
+/// Flags native object deserializers that can instantiate attacker-chosen types.
+#[derive(Debug, Clone, Default)]
+pub struct DeserializationCheck;
+
+impl DeserializationCheck {
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let mut matches = Vec::new();
+
+        for (idx, line) in lines.iter().enumerate() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("//") || trimmed.starts_with('#') || trimmed.starts_with('*') {
+                continue;
+            }
+            let line_no = idx as u32;
+
+            for sink in SINKS.iter().filter(|s| s.languages.contains(&ctx.language)) {
+                let Some(column) = find_call(line, sink.call) else {
+                    continue;
+                };
+                if sink.safe_args.iter().any(|a| line[column..].contains(a)) {
+                    continue;
+                }
+                let scope = enclosing_scope(ctx, &lines, line_no);
+                let receiver_type = if sink.receivers.is_empty() {
+                    None
+                } else {
+                    let Some(ty) = receiver_type(&line[..column], &scope, sink.receivers) else {
+                        continue;
+                    };
+                    Some(ty)
+                };
+                let mitigation = sink.mitigations.iter().find(|m| scope.contains(*m));
+
+                let api = match receiver_type {
+                    Some(ty) => format!("{ty}{}()", sink.call),
+                    None => format!("{}()", sink.call),
+                };
+                let mut matched_text = format!("{api} can instantiate arbitrary types from untrusted input");
+                let mut confidence = sink.confidence;
+                if let Some(mitigation) = mitigation {
+                    confidence -= MITIGATED_PENALTY;
+                    matched_text.push_str(&format!(" (`{mitigation}` is used nearby)"));
+                }
+                matches.push(PatternMatch {
+                    file: ctx.file.to_string(),
+                    line: line_no,
+                    column: column as u32,
+                    pattern_id: "SEC-DESER-001".to_string(),
+                    confidence,
+                    cwe_ids: SmallVec::from_buf([502, 0]),
+                    owasp: Some("A08:2021".to_string()),
+                    detection_method: DetectionMethod::AstVisitor,
+                    category: PatternCategory::Security,
+                    matched_text,
+                });
+            }
+        }
+
+        matches
+    }
+}
+
+/// Column of `call` in `line` where it is not part of a longer identifier
+/// (`pickle.load` inside `pickle.loads`, `my_unserialize`).
+fn find_call(line: &str, call: &str) -> Option<usize> {
+    let is_ident = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    line.match_indices(call).map(|(i, _)| i).find(|&i| {
+        (call.starts_with('.') || !is_ident(line[..i].chars().next_back()))
+            && !is_ident(line[i + call.len()..].chars().next())
+    })
+}
+
+/// The receiver type of a method call whose line reads `prefix` up to the
+/// method: named in the receiver expression itself
+/// (`new ObjectInputStream(in).readObject`), or declared for the receiver
+/// variable somewhere in `scope`.
+fn receiver_type(prefix: &str, scope: &str, types: &[&'static str]) -> Option<&'static str> {
+    if let Some(ty) = types.iter().find(|t| prefix.contains(*t)) {
+        return Some(ty);
+    }
+    let start = prefix.trim_end_matches(|c: char| c.is_alphanumeric() || c == '_').len();
+    let receiver = &prefix[start..];
+    if receiver.is_empty() {
+        return None;
+    }
+    types.iter().copied().find(|ty| {
+        scope
+            .lines()
+            .any(|l| l.contains(ty) && find_call(l, receiver).is_some())
+    })
+}
@@ -0,0 +1,285 @@
+//! Disabled security response headers (CWE-693).
+//!
+//! `helmet({ contentSecurityPolicy: false })`, `res.removeHeader("X-Frame-Options")`,
+//! `SECURE_HSTS_SECONDS = 0` or Spring's `headers().frameOptions().disable()`
+//! switch off browser protections against clickjacking, MIME sniffing and
+//! downgrade attacks. Explicit opt-outs are flagged with the header they
+//! affect, as is advertising the stack through `X-Powered-By`. An Express app
+//! that never configures a header middleware is reported at low confidence.
+//! Findings are hardening advice rather than vulnerabilities.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::call_arguments;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::scanner::language_detect::Language;
+
+/// Header middleware whose options are inspected by default: helmet (Express,
+/// Fastify, Koa), Flask-Talisman, lusca and the Ruby `secure_headers` gem.
+pub const DEFAULT_HEADER_MIDDLEWARE: &[&str] = &["helmet", "Talisman", "lusca", "SecureHeaders"];
+
+const CSP: &str = "Content-Security-Policy";
+const XFO: &str = "X-Frame-Options";
+const HSTS: &str = "Strict-Transport-Security";
+const NOSNIFF: &str = "X-Content-Type-Options";
+const REFERRER: &str = "Referrer-Policy";
+
+/// Middleware options that turn a header off when set to false/None, and the header.
+const OPTION_HEADERS: &[(&str, &str)] = &[
+    ("contentSecurityPolicy", CSP), ("content_security_policy", CSP), ("csp", CSP),
+    ("frameguard", XFO), ("xFrameOptions", XFO), ("frame_options", XFO), ("xframe", XFO),
+    ("hsts", HSTS), ("strictTransportSecurity", HSTS), ("strict_transport_security", HSTS),
+    ("noSniff", NOSNIFF), ("xContentTypeOptions", NOSNIFF), ("content_type_options", NOSNIFF),
+    ("referrerPolicy", REFERRER), ("referrer_policy", REFERRER),
+    ("crossOriginOpenerPolicy", "Cross-Origin-Opener-Policy"),
+    ("crossOriginEmbedderPolicy", "Cross-Origin-Embedder-Policy"),
+    ("crossOriginResourcePolicy", "Cross-Origin-Resource-Policy"),
+];
+
+/// Values that disable an option.
+const DISABLED_VALUES: &[&str] = &["false", "False", "None", "null", "nil"];
+
+/// Headers whose removal weakens a response.
+const SECURITY_HEADERS: &[&str] = &[
+    CSP, XFO, HSTS, NOSNIFF, REFERRER, "Permissions-Policy", "Cross-Origin-Opener-Policy",
+    "Cross-Origin-Embedder-Policy", "Cross-Origin-Resource-Policy",
+];
+
+/// Calls that remove a response header (Node, PHP, Go, .NET, Java).
+const REMOVE_CALLEES: &[&str] = &["removeHeader", "header_remove", "Del", "Remove", "remove", "delete"];
+
+/// Calls that set a response header.
+const SET_CALLEES: &[&str] = &[
+    "setHeader", "set", "header", "append", "writeHead", "Set", "Add", "add_header", "addHeader",
+    "AddHeader", "Append",
+];
+
+/// Header values that turn the protection off.
+const WEAK_VALUES: &[(&str, &str)] = &[(HSTS, "max-age=0"), (XFO, "allowall")];
+
+/// Django settings that disable a header, with the disabling value.
+const DJANGO_SETTINGS: &[(&str, &str, &str)] = &[
+    ("SECURE_HSTS_SECONDS", "0", HSTS),
+    ("SECURE_CONTENT_TYPE_NOSNIFF", "False", NOSNIFF),
+    ("SECURE_REFERRER_POLICY", "None", REFERRER),
+];
+
+/// Django view decorators that drop a header.
+const DJANGO_EXEMPTS: &[(&str, &str)] = &[("@xframe_options_exempt", XFO), ("@csp_exempt", CSP)];
+
+/// Spring Security header configurers; the last entry turns off every header.
+const SPRING_CONFIGURERS: &[(&str, &str)] = &[
+    ("frameOptions", XFO), ("httpStrictTransportSecurity", HSTS), ("contentTypeOptions", NOSNIFF),
+    ("contentSecurityPolicy", CSP), ("referrerPolicy", REFERRER), ("headers", "all security headers"),
+];
+
+/// Flags explicitly disabled or removed security headers.
+#[derive(Debug, Clone)]
+pub struct SecurityHeadersCheck {
+    middleware: Vec<String>,
+}
+
+impl SecurityHeadersCheck {
+    /// Create a check over a custom header middleware set.
+    pub fn new(middleware: &[&str]) -> Self {
+        Self {
+            middleware: middleware.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Add framework-specific header middleware (e.g. `secureHeaders`) to the current set.
+    pub fn with_middleware(mut self, middleware: &[&str]) -> Self {
+        self.middleware.extend(middleware.iter().map(|s| s.to_string()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let mut matches = Vec::new();
+
+        for call in ctx.call_sites {
+            let callee = call.callee_name.as_str();
+            let args = || call_arguments(ctx.source, call).unwrap_or_default();
+
+            if self.middleware.iter().any(|m| m == callee) {
+                let options = args().join(",");
+                for header in disabled_options(&options) {
+                    matches.push(header_match(ctx, call.line, call.column, 0.70, format!(
+                        "{callee}() disables {header}",
+                    )));
+                }
+            } else if REMOVE_CALLEES.contains(&callee) {
+                let args = args();
+                let Some(header) = args.first().and_then(|a| security_header(a)) else {
+                    continue;
+                };
+                matches.push(header_match(ctx, call.line, call.column, 0.65, format!(
+                    "{callee}() removes the {header} header",
+                )));
+            } else if SET_CALLEES.contains(&callee) {
+                let args = args();
+                let name = args.first().and_then(|a| unquote(a)).unwrap_or_default();
+                // `app.set("x-powered-by", false)` turns the header off.
+                if args.get(1).is_some_and(|a| DISABLED_VALUES.contains(&a.as_str())) {
+                    continue;
+                }
+                let value = args.get(1).and_then(|a| unquote(a)).unwrap_or_default().to_lowercase();
+                if name.eq_ignore_ascii_case("X-Powered-By") {
+                    matches.push(header_match(ctx, call.line, call.column, 0.50,
+                        "X-Powered-By is set, advertising the server stack".to_string()));
+                } else if let Some((header, _)) = WEAK_VALUES
+                    .iter()
+                    .find(|(h, v)| name.eq_ignore_ascii_case(h) && value.contains(v))
+                {
+                    matches.push(header_match(ctx, call.line, call.column, 0.65, format!(
+                        "{header} is set to `{value}`, which disables it",
+                    )));
+                }
+            } else if callee == "enable" {
+                let args = args();
+                let name = args.first().and_then(|a| unquote(a)).unwrap_or_default();
+                if !name.eq_ignore_ascii_case("x-powered-by") {
+                    continue;
+                }
+                matches.push(header_match(ctx, call.line, call.column, 0.50,
+                    "X-Powered-By is enabled, advertising the server stack".to_string()));
+            }
+        }
+
+        for (idx, line) in lines.iter().enumerate() {
+            let trimmed = line.trim_start();
+            let column = (line.len() - trimmed.len()) as u32;
+            let finding = match ctx.language {
+                Language::Python => django_disabled(trimmed),
+                Language::Java | Language::Kotlin => spring_disabled(trimmed),
+                _ => None,
+            };
+            if let Some(message) = finding {
+                matches.push(header_match(ctx, idx as u32, column, 0.65, message));
+            }
+        }
+
+        if let Some(m) = self.unconfigured_express(ctx, text) {
+            matches.push(m);
+        }
+
+        matches
+    }
+
+    /// An Express app that serves requests without any header middleware or
+    /// explicitly set security header.
+    fn unconfigured_express(&self, ctx: &DetectionContext, text: &str) -> Option<PatternMatch> {
+        if !matches!(ctx.language, Language::JavaScript | Language::TypeScript) {
+            return None;
+        }
+        let app = ctx
+            .call_sites
+            .iter()
+            .find(|c| c.callee_name == "express" && c.receiver.is_none())?;
+        let listens = ctx.call_sites.iter().any(|c| c.callee_name == "listen");
+        let lower = text.to_lowercase();
+        let configured = self.middleware.iter().any(|m| text.contains(m.as_str()))
+            || SECURITY_HEADERS.iter().any(|h| lower.contains(&h.to_lowercase()));
+        (listens && !configured).then(|| header_match(ctx, app.line, app.column, 0.35,
+            "Express app sets no security headers (no helmet or equivalent)".to_string()))
+    }
+}
+
+impl Default for SecurityHeadersCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_HEADER_MIDDLEWARE)
+    }
+}
+
+/// Headers whose middleware options are set to a disabling value, e.g.
+/// `{ contentSecurityPolicy: false }` or `force_https=False, frame_options=None`.
+fn disabled_options(options: &str) -> Vec<&'static str> {
+    let mut headers = Vec::new();
+    for (option, header) in OPTION_HEADERS {
+        let disabled = options.match_indices(option).any(|(i, _)| {
+            let before = options[..i].chars().next_back();
+            if before.is_some_and(|c| c.is_alphanumeric() || c == '_') {
+                return false;
+            }
+            let rest = options[i + option.len()..].trim_start_matches(['"', '\'']).trim_start();
+            let Some(value) = rest.strip_prefix([':', '=']) else {
+                return false;
+            };
+            let value = value.trim_start();
+            DISABLED_VALUES.iter().any(|d| {
+                value.starts_with(d)
+                    && !value[d.len()..].starts_with(|c: char| c.is_alphanumeric() || c == '_')
+            })
+        });
+        if disabled && !headers.contains(header) {
+            headers.push(*header);
+        }
+    }
+    headers
+}
+
+/// The security header named by a string literal argument.
+fn security_header(arg: &str) -> Option<&'static str> {
+    let name = unquote(arg)?;
+    SECURITY_HEADERS.iter().copied().find(|h| h.eq_ignore_ascii_case(name))
+}
+
+fn unquote(arg: &str) -> Option<&str> {
+    let arg = arg.trim();
+    let quote = arg.chars().next().filter(|c| matches!(c, '"' | '\'' | '`'))?;
+    arg[1..].strip_suffix(quote)
+}
+
+/// A Django setting or view decorator that disables a header.
+fn django_disabled(line: &str) -> Option<String> {
+    if let Some((decorator, header)) = DJANGO_EXEMPTS.iter().find(|(d, _)| line.starts_with(d)) {
+        return Some(format!("{decorator} removes {header} from the view's responses"));
+    }
+    let (name, value) = line.split_once('=')?;
+    let name = name.trim();
+    let value = value.split('#').next().unwrap_or("").trim();
+    DJANGO_SETTINGS
+        .iter()
+        .find(|(setting, disabled, _)| name == *setting && value == *disabled)
+        .map(|(setting, _, header)| format!("{setting} = {value} disables {header}"))
+}
+
+/// A Spring Security configurer chain ending in `disable()`.
+fn spring_disabled(line: &str) -> Option<String> {
+    let at = line.find(".disable()").or_else(|| line.find("::disable"))?;
+    let configurer = last_call(&line[..at])?;
+    SPRING_CONFIGURERS
+        .iter()
+        .find(|(c, _)| *c == configurer)
+        .map(|(configurer, header)| format!("{configurer}().disable() turns off {header}"))
+}
+
+/// The name of the last method called in `chain`: `frameOptions` in
+/// `headers(h -> h.frameOptions(f -> f` or `http.headers().frameOptions()`.
+fn last_call(chain: &str) -> Option<&str> {
+    let open = chain.rfind('(')?;
+    let before = &chain[..open];
+    let start = before
+        .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .map_or(0, |i| i + 1);
+    Some(&before[start..])
+}
+
+fn header_match(ctx: &DetectionContext, line: u32, column: u32, confidence: f32, matched_text: String) -> PatternMatch {
+    PatternMatch {
+        file: ctx.file.to_string(),
+        line,
+        column,
+        pattern_id: "SEC-HEADERS-001".to_string(),
+        confidence,
+        cwe_ids: SmallVec::from_buf([693, 0]),
+        owasp: Some("A05:2021".to_string()),
+        detection_method: DetectionMethod::AstVisitor,
+        category: PatternCategory::Security,
+        matched_text,
+    }
+}
@@ -0,0 +1,146 @@
+//! High-entropy string literals (CWE-798).
+//!
+//! `SEC-SECRET-001` only catches literals that mention `password`, `token`
+//! and the like, so a random key held in `const salt = "..."` slips through.
+//! This check looks at the literal itself: longer than 20 characters, drawn
+//! only from the base64 or hex alphabet with a mix of letters and digits,
+//! and with Shannon entropy above the threshold. Anything with whitespace
+//! (prose), URLs and module or file paths is skipped, and so are hex digests
+//! (MD5, SHA-1 and git object ids, SHA-256, SHA-512) and UUIDs: they are as
+//! random as keys but are identifiers, not secrets. Confidence grows with the
+//! entropy above the bar.
+
+use smallvec::SmallVec;
+
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::structural::constants::entropy::shannon_entropy;
+
+/// Default entropy bar for base64 literals, in bits per character.
+pub const DEFAULT_ENTROPY_THRESHOLD: f64 = 4.0;
+
+/// Literals of this many characters or fewer are never reported.
+const MIN_LENGTH: usize = 20;
+
+/// Lengths of pure-hex digests and dashless UUIDs, which are not secrets.
+const DIGEST_LENGTHS: &[usize] = &[32, 40, 64, 128];
+
+/// Alphabet a candidate literal is drawn from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Charset {
+    Base64,
+    Hex,
+}
+
+impl Charset {
+    /// Maximum entropy of the alphabet, in bits per character.
+    fn bits(self) -> f64 {
+        match self {
+            Charset::Base64 => 6.0,
+            Charset::Hex => 4.0,
+        }
+    }
+}
+
+/// Flags long base64/hex literals whose entropy marks them as random keys.
+#[derive(Debug, Clone)]
+pub struct HighEntropyCheck {
+    threshold: f64,
+}
+
+impl HighEntropyCheck {
+    /// Create a check with a custom base64 entropy bar. Hex literals are
+    /// held to the same bar scaled to their 4-bit alphabet.
+    pub fn new(threshold: f64) -> Self {
+        Self { threshold }
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let mut matches = Vec::new();
+
+        for lit in &ctx.parse_result.string_literals {
+            let Some(charset) = charset(&lit.value) else {
+                continue;
+            };
+            let entropy = shannon_entropy(&lit.value);
+            let bar = self.threshold * charset.bits() / Charset::Base64.bits();
+            if entropy <= bar {
+                continue;
+            }
+
+            // 0.5 at the bar, +0.2 per base64-equivalent bit above it
+            let excess = (entropy - bar) * Charset::Base64.bits() / charset.bits();
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: lit.line,
+                column: lit.column,
+                pattern_id: "SEC-SECRET-ENTROPY".to_string(),
+                confidence: (0.5 + 0.2 * excess).min(0.9) as f32,
+                cwe_ids: SmallVec::from_buf([798, 0]),
+                owasp: Some("A07:2021".to_string()),
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Security,
+                matched_text: format!(
+                    "{}-character {} string with {entropy:.1} bits/char entropy — potential hardcoded secret",
+                    lit.value.chars().count(),
+                    if charset == Charset::Hex { "hex" } else { "base64" },
+                ),
+            });
+        }
+
+        matches
+    }
+}
+
+impl Default for HighEntropyCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_ENTROPY_THRESHOLD)
+    }
+}
+
+/// The alphabet of a candidate secret, or `None` for short literals, prose,
+/// URLs, paths, digests and identifiers.
+fn charset(value: &str) -> Option<Charset> {
+    if value.chars().count() <= MIN_LENGTH || value.contains("://") || is_path(value) || is_uuid(value) {
+        return None;
+    }
+    let has_digit = value.bytes().any(|b| b.is_ascii_digit());
+    if value.bytes().all(|b| b.is_ascii_hexdigit()) {
+        if DIGEST_LENGTHS.contains(&value.len()) {
+            return None;
+        }
+        let has_letter = value.bytes().any(|b| b.is_ascii_alphabetic());
+        return (has_digit && has_letter).then_some(Charset::Hex);
+    }
+    let in_alphabet = value
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/' | b'=' | b'-' | b'_'));
+    let mixed = has_digit
+        && value.bytes().any(|b| b.is_ascii_uppercase())
+        && value.bytes().any(|b| b.is_ascii_lowercase());
+    (in_alphabet && mixed).then_some(Charset::Base64)
+}
+
+/// `8-4-4-4-12` hex groups.
+fn is_uuid(value: &str) -> bool {
+    let groups: Vec<&str> = value.split('-').collect();
+    groups.iter().map(|g| g.len()).eq([8, 4, 4, 4, 12])
+        && groups.iter().all(|g| g.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+/// Relative, absolute and scoped module paths, and `a/b/c` paths whose
+/// segments are mostly plain words.
+fn is_path(value: &str) -> bool {
+    if value.starts_with(['/', '.', '~', '@']) {
+        return true;
+    }
+    let segments: Vec<&str> = value.split('/').filter(|s| !s.is_empty()).collect();
+    if segments.len() < 2 {
+        return false;
+    }
+    let words = segments
+        .iter()
+        .filter(|s| s.bytes().all(|b| b.is_ascii_alphabetic() || matches!(b, b'-' | b'_' | b'.')))
+        .count();
+    words * 2 >= segments.len()
+}
@@ -0,0 +1,210 @@
+//! External resources loaded without an integrity check (CWE-353).
+//!
+//! A `<script src="https://cdn...">` without `integrity=` runs whatever the
+//! CDN serves, and `curl https://... | sh` or a downloaded binary executed
+//! without a checksum runs whatever the network returns. Three shapes are
+//! flagged: script and stylesheet tags in HTML strings, templates and JSX
+//! that load from another origin without Subresource Integrity, piping a
+//! download into a shell, and functions that download a file and execute it
+//! without verifying a hash or signature.
+
+use smallvec::SmallVec;
+
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+
+/// Downloads: fetch-to-file helpers and HTTP clients.
+const DOWNLOAD_CALLEES: &[&str] = &[
+    "urlretrieve", "urlopen", "download", "download_file", "DownloadFile", "DownloadFileAsync",
+    "fetch", "copyURLToFile", "file_get_contents",
+];
+
+/// Calls that make a file executable or run it as a process.
+const EXEC_CALLEES: &[&str] = &[
+    "system", "popen", "Popen", "check_call", "check_output", "execSync", "execFile",
+    "execFileSync", "spawn", "spawnSync", "Command", "chmod", "chmodSync", "shell_exec",
+];
+
+/// Fragments (lowercased) of a checksum or signature verification.
+const VERIFY_MARKERS: &[&str] = &[
+    "sha256", "sha512", "sha384", "hashlib", "createhash", "checksum", "digest", "verify", "gpg",
+    "signature", "cosign", "hash_file", "messagedigest",
+];
+
+/// Shells a download may be piped into.
+const SHELLS: &[&str] = &["sh", "bash", "zsh", "ash", "dash", "python", "python3", "node", "perl", "ruby"];
+
+/// Flags external scripts, piped installers and executed downloads without integrity checks.
+#[derive(Debug, Clone, Default)]
+pub struct IntegrityCheck {
+    trusted_hosts: Vec<String>,
+}
+
+impl IntegrityCheck {
+    /// Create a check that treats the given hosts (and their subdomains) as
+    /// first-party, so tags loading from them need no `integrity`.
+    pub fn new(trusted_hosts: &[&str]) -> Self {
+        Self {
+            trusted_hosts: trusted_hosts.iter().map(|h| h.to_lowercase()).collect(),
+        }
+    }
+
+    /// Add first-party asset hosts (e.g. `static.example.com`) to the current set.
+    pub fn with_hosts(mut self, hosts: &[&str]) -> Self {
+        self.trusted_hosts.extend(hosts.iter().map(|h| h.to_lowercase()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let mut matches = Vec::new();
+
+        for (idx, line) in lines.iter().enumerate() {
+            for (column, tag, url) in external_tags(line) {
+                if self.is_trusted(&url) {
+                    continue;
+                }
+                let text = format!("<{tag}> loads {url} without an integrity attribute — add Subresource Integrity");
+                matches.push(integrity_match(ctx, idx as u32, column as u32, 0.60, text));
+            }
+            if let Some(column) = piped_to_shell(line) {
+                let text = "Download piped into a shell without checksum verification".to_string();
+                matches.push(integrity_match(ctx, idx as u32, column as u32, 0.70, text));
+            }
+        }
+
+        // Functions that download and execute without verifying.
+        for func in ctx.functions {
+            let in_body = |line: u32| line >= func.line && line <= func.end_line;
+            let Some(download) = ctx
+                .call_sites
+                .iter()
+                .find(|c| in_body(c.line) && DOWNLOAD_CALLEES.contains(&c.callee_name.as_str()))
+            else {
+                continue;
+            };
+            let Some(exec) = ctx.call_sites.iter().find(|c| {
+                in_body(c.line) && c.line >= download.line && EXEC_CALLEES.contains(&c.callee_name.as_str())
+            }) else {
+                continue;
+            };
+            let body = lines
+                .get(func.line as usize..(func.end_line as usize + 1).min(lines.len()))
+                .unwrap_or_default();
+            let downloads_file = body.iter().any(|l| {
+                let lower = l.to_lowercase();
+                lower.contains("https://") || lower.contains("http://") || lower.contains("url")
+            });
+            let verified = body.iter().any(|l| {
+                let lower = l.to_lowercase();
+                VERIFY_MARKERS.iter().any(|m| lower.contains(m))
+            });
+            if downloads_file && !verified {
+                let text = format!(
+                    "`{}` runs a file downloaded by {}() without verifying its checksum",
+                    func.name, download.callee_name,
+                );
+                matches.push(integrity_match(ctx, exec.line, exec.column, 0.55, text));
+            }
+        }
+
+        matches
+    }
+
+    fn is_trusted(&self, url: &str) -> bool {
+        let host = url_host(url);
+        self.trusted_hosts
+            .iter()
+            .any(|h| host == *h || host.ends_with(&format!(".{h}")))
+    }
+}
+
+/// `(column, tag, url)` for each `<script src>` or stylesheet/preload
+/// `<link href>` on the line that loads from an absolute URL without `integrity`.
+fn external_tags(line: &str) -> Vec<(usize, &'static str, String)> {
+    let lower = line.to_lowercase();
+    let mut found = Vec::new();
+    for (tag, attr) in [("script", "src"), ("link", "href")] {
+        let open = format!("<{tag}");
+        for (start, _) in lower.match_indices(&open) {
+            let after = start + open.len();
+            if !lower[after..].starts_with(|c: char| c.is_whitespace()) {
+                continue;
+            }
+            let end = lower[after..].find('>').map_or(lower.len(), |i| after + i);
+            let attrs = &lower[after..end];
+            if attrs.contains("integrity") {
+                continue;
+            }
+            if tag == "link" && !(attrs.contains("stylesheet") || attrs.contains("preload")) {
+                continue;
+            }
+            let Some(url) = attribute_value(&line[after..end], attr) else {
+                continue;
+            };
+            let absolute = url.starts_with("https://") || url.starts_with("http://") || url.starts_with("//");
+            if absolute && !url.contains("${") && !url.contains("{{") {
+                found.push((start, tag, url.to_string()));
+            }
+        }
+    }
+    found
+}
+
+/// The quoted value of `name=` among tag attributes.
+fn attribute_value<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let lower = attrs.to_lowercase();
+    let mut search = 0;
+    while let Some(rel) = lower[search..].find(name) {
+        let at = search + rel;
+        search = at + name.len();
+        let standalone = at == 0 || lower[..at].ends_with(|c: char| c.is_whitespace());
+        let rest = attrs[at + name.len()..].trim_start();
+        let Some(rest) = rest.strip_prefix('=') else {
+            continue;
+        };
+        if !standalone {
+            continue;
+        }
+        let rest = rest.trim_start().trim_start_matches(['{', '\\']);
+        let quote = rest.chars().next().filter(|c| matches!(c, '"' | '\'' | '`'))?;
+        let value = &rest[1..];
+        return value.find([quote, '\\']).map(|end| &value[..end]);
+    }
+    None
+}
+
+/// Column of a `curl ... | sh` / `wget -O- ... | bash` pipeline on the line.
+fn piped_to_shell(line: &str) -> Option<usize> {
+    let lower = line.to_lowercase();
+    let start = ["curl ", "wget "].iter().filter_map(|d| lower.find(d)).min()?;
+    let pipeline = &lower[start..];
+    let to_shell = pipeline.split('|').skip(1).any(|stage| {
+        let mut words = stage.split_whitespace().skip_while(|w| *w == "sudo" || w.starts_with('-'));
+        words.next().is_some_and(|w| SHELLS.contains(&w.trim_matches(['"', '\'', '`', ')', ';', ','])))
+    });
+    to_shell.then_some(start)
+}
+
+fn url_host(url: &str) -> String {
+    let rest = url.split_once("//").map_or(url, |(_, r)| r);
+    rest.split(['/', '?', '#', ':']).next().unwrap_or("").to_lowercase()
+}
+
+fn integrity_match(ctx: &DetectionContext, line: u32, column: u32, confidence: f32, matched_text: String) -> PatternMatch {
+    PatternMatch {
+        file: ctx.file.to_string(),
+        line,
+        column,
+        pattern_id: "SEC-INTEGRITY-001".to_string(),
+        confidence,
+        cwe_ids: SmallVec::from_buf([353, 0]),
+        owasp: Some("A08:2021".to_string()),
+        detection_method: DetectionMethod::AstVisitor,
+        category: PatternCategory::Security,
+        matched_text,
+    }
+}
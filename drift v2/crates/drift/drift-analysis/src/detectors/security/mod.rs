@@ -1,12 +1,49 @@
 //! Security detector — injection, XSS, CSRF, auth bypass, secrets.
 
+pub mod cleartext;
+pub mod cookies;
+pub mod deserialization;
+pub mod headers;
+pub mod high_entropy;
+pub mod integrity;
+pub mod password_hash;
+pub mod path_traversal;
+pub mod prototype_pollution;
+pub mod reflection;
+pub mod template_injection;
+pub mod upload;
+pub mod webhooks;
+pub mod xxe;
+
+use drift_core::config::DetectorOptions;
 use smallvec::SmallVec;
 
 use crate::detectors::traits::{Detector, DetectorCategory, DetectorVariant};
 use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
 use crate::engine::visitor::DetectionContext;
 
-pub struct SecurityDetector;
+#[derive(Debug, Clone, Default)]
+pub struct SecurityDetector {
+    high_entropy: high_entropy::HighEntropyCheck,
+    cookies: cookies::CookieCheck,
+    cleartext: cleartext::CleartextUrlCheck,
+}
+
+impl SecurityDetector {
+    /// Create the detector with the thresholds, extra calls and allowed hosts
+    /// from `options`.
+    pub fn new(options: &DetectorOptions) -> Self {
+        let setters: Vec<&str> = options.cookie_setters.iter().map(String::as_str).collect();
+        let hosts: Vec<&str> = options.cleartext_allowed_hosts.iter().map(String::as_str).collect();
+        Self {
+            high_entropy: options
+                .entropy_threshold
+                .map_or_else(high_entropy::HighEntropyCheck::default, high_entropy::HighEntropyCheck::new),
+            cookies: cookies::CookieCheck::default().with_setters(&setters),
+            cleartext: cleartext::CleartextUrlCheck::new(&hosts),
+        }
+    }
+}
 
 impl Detector for SecurityDetector {
     fn id(&self) -> &str { "security-base" }
@@ -114,6 +151,21 @@ impl Detector for SecurityDetector {
             }
         }
 
+        matches.extend(password_hash::PasswordHashCheck.detect(ctx));
+        matches.extend(upload::UploadCheck::default().detect(ctx));
+        matches.extend(self.cleartext.detect(ctx));
+        matches.extend(reflection::ReflectionCheck::default().detect(ctx));
+        matches.extend(prototype_pollution::PrototypePollutionCheck::default().detect(ctx));
+        matches.extend(headers::SecurityHeadersCheck::default().detect(ctx));
+        matches.extend(integrity::IntegrityCheck::default().detect(ctx));
+        matches.extend(template_injection::TemplateInjectionCheck::default().detect(ctx));
+        matches.extend(self.cookies.detect(ctx));
+        matches.extend(webhooks::WebhookSignatureCheck::default().detect(ctx));
+        matches.extend(xxe::XxeCheck::default().detect(ctx));
+        matches.extend(deserialization::DeserializationCheck.detect(ctx));
+        matches.extend(path_traversal::PathTraversalCheck::default().detect(ctx));
+        matches.extend(self.high_entropy.detect(ctx));
+
         matches
     }
 }
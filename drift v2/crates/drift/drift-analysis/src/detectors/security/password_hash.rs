@@ -0,0 +1,139 @@
+//! Fast hashes used for password storage.
+//!
+//! MD5 and plain SHA digests are fine for checksums but far too cheap for
+//! passwords. A fast-hash call is reported only when it is tied to a password:
+//! it sits inside a function named like `hashPassword`, or its result is
+//! assigned to a field or variable named like `password_hash`.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::{call_arguments, call_path};
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::CallSite;
+
+/// Normalized names of fast, unsalted digest algorithms.
+const FAST_HASHES: &[&str] = &["md5", "sha1", "sha224", "sha256", "sha384", "sha512"];
+
+/// Factories that take the algorithm name as their first argument:
+/// `crypto.createHash('md5')`, `MessageDigest.getInstance("SHA-256")`,
+/// `hashlib.new('sha1')`, PHP `hash('sha256', ...)`.
+const ALGORITHM_FACTORIES: &[&str] = &["createHash", "getInstance", "new", "hash"];
+
+/// Flags fast-hash calls whose output is stored as a password.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PasswordHashCheck;
+
+impl PasswordHashCheck {
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let mut matches = Vec::new();
+
+        for call in ctx.call_sites {
+            let Some(algorithm) = fast_hash_algorithm(ctx.source, call) else {
+                continue;
+            };
+            let scope = enclosing_function_name(ctx, call.line);
+            let target = assignment_target(ctx.source, call);
+            let password_use = scope
+                .filter(|name| is_password_name(name))
+                .or(target.as_deref().filter(|name| is_password_name(name)));
+            let Some(password_use) = password_use else {
+                continue;
+            };
+
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: call.line,
+                column: call.column,
+                pattern_id: "SEC-PWHASH-001".to_string(),
+                confidence: 0.85,
+                cwe_ids: SmallVec::from_buf([916, 0]),
+                owasp: Some("A02:2021".to_string()),
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Security,
+                matched_text: format!(
+                    "{algorithm} used for password hashing in {password_use} — use bcrypt, scrypt, argon2 or PBKDF2"
+                ),
+            });
+        }
+
+        matches
+    }
+}
+
+/// Identify the fast hash algorithm a call computes, if any.
+fn fast_hash_algorithm(source: &[u8], call: &CallSite) -> Option<&'static str> {
+    if ALGORITHM_FACTORIES.contains(&call.callee_name.as_str()) {
+        let args = call_arguments(source, call)?;
+        let first = args.first()?;
+        let quoted = first
+            .strip_prefix(['"', '\'', '`'])
+            .and_then(|s| s.strip_suffix(['"', '\'', '`']))?;
+        return fast_hash_named(quoted);
+    }
+
+    if let Some(algorithm) = fast_hash_named(&call.callee_name) {
+        return Some(algorithm);
+    }
+
+    // Algorithm as the receiver: `md5.Sum(...)`, `SHA256.Create()`,
+    // `Digest::MD5.hexdigest(...)`.
+    let path = call_path(source, call)?;
+    let receiver = path
+        .strip_suffix(call.callee_name.as_str())?
+        .trim_end_matches(['.', ':']);
+    let last = receiver.rsplit(['.', ':']).next()?;
+    fast_hash_named(last)
+}
+
+fn fast_hash_named(name: &str) -> Option<&'static str> {
+    let normalized: String = name
+        .chars()
+        .filter(|c| *c != '-' && *c != '_')
+        .collect::<String>()
+        .to_lowercase();
+    FAST_HASHES.iter().copied().find(|h| *h == normalized)
+}
+
+/// Name of the innermost function whose line range contains `line`.
+fn enclosing_function_name<'a>(ctx: &'a DetectionContext, line: u32) -> Option<&'a str> {
+    ctx.functions
+        .iter()
+        .filter(|f| f.line <= line && line <= f.end_line)
+        .min_by_key(|f| f.end_line - f.line)
+        .map(|f| f.name.as_str())
+}
+
+/// The variable or field a call's result is assigned to on its own line,
+/// e.g. `password_hash` in `self.password_hash = hashlib.sha256(pw)`.
+fn assignment_target(source: &[u8], call: &CallSite) -> Option<String> {
+    let text = std::str::from_utf8(source).ok()?;
+    let line = text.lines().nth(call.line as usize)?;
+    let before = line.get(..call.column as usize)?.trim_end();
+
+    let lhs = if let Some(lhs) = before.strip_suffix(":=") {
+        lhs
+    } else if let Some(lhs) = before.strip_suffix('=') {
+        if lhs.ends_with(['=', '!', '<', '>']) {
+            return None;
+        }
+        lhs
+    } else {
+        // Object literal key: `{ passwordHash: createHash(...) }`
+        before.strip_suffix(':')?
+    };
+
+    let name = lhs
+        .trim_end()
+        .rsplit(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .next()?
+        .trim_start_matches('$');
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+fn is_password_name(name: &str) -> bool {
+    let lower = name.to_lowercase();
+    ["password", "passwd", "passphrase", "pwd"]
+        .iter()
+        .any(|p| lower.contains(p))
+}
@@ -0,0 +1,169 @@
+//! Path traversal (CWE-22).
+//!
+//! `fs.readFile(req.query.file)` or `open(request.args['name'])` lets the
+//! caller read or write any file the process can reach with `../` segments.
+//! A file API call is flagged when an argument comes from the request
+//! (directly, through one assignment, or as a handler parameter) or is a
+//! literal that already climbs out with `../`. Arguments passed through a
+//! basename-style sanitizer, and names checked against a base directory
+//! before the call, are not flagged. This is a lexical first pass; flows
+//! across functions are left to `graph::taint`.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::{call_arguments, call_path};
+use crate::detectors::handlers::{
+    assigned_from_request, contains_word, enclosing_function, in_request_handler, is_request_input, text_before,
+    RequestTaint,
+};
+use crate::detectors::sinks::SinkSet;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::FunctionInfo;
+
+/// File APIs checked by default, bare or qualified (see [`SinkSet`]).
+pub const DEFAULT_PATH_SINKS: &[&str] = &[
+    // Node
+    "readFile", "readFileSync", "createReadStream", "writeFile", "writeFileSync",
+    "createWriteStream", "appendFile", "appendFileSync", "unlink", "unlinkSync", "sendFile",
+    "path.join", "path.resolve",
+    // Python
+    "open", "send_file", "os.path.join", "os.remove",
+    // Java / Kotlin
+    "Paths.get", "Path.of", "Files.readAllBytes", "Files.newInputStream",
+    // C#
+    "ReadAllText", "ReadAllBytes", "Path.Combine", "File.Open",
+    // Go
+    "os.Open", "os.ReadFile", "ioutil.ReadFile", "filepath.Join", "http.ServeFile",
+    // Ruby
+    "File.read", "File.open",
+    // PHP
+    "file_get_contents", "file_put_contents", "fopen", "readfile",
+    // Rust
+    "fs::read", "fs::read_to_string", "fs::write", "File::open",
+];
+
+/// Calls that reduce a client path to its final component.
+const SANITIZERS: &[&str] = &[
+    "basename(", "secure_filename(", "Path.GetFileName(", "filepath.Base(",
+    "FilenameUtils.getName(", ".file_name()", "getFileName()",
+];
+
+/// Fragments of a check that the resolved path stays under a base directory.
+const CONTAINMENT_MARKERS: &[&str] = &[
+    "startswith(", "starts_with(", "hasprefix(", "is_relative_to(", "normalize(", "realpath(",
+    "getcanonicalpath(",
+];
+
+/// Flags file API calls whose path argument is request-derived or climbs with `../`.
+#[derive(Debug, Clone)]
+pub struct PathTraversalCheck {
+    sinks: SinkSet,
+}
+
+impl PathTraversalCheck {
+    /// Create a check over a custom file API sink set.
+    pub fn new(sinks: &[&str]) -> Self {
+        Self {
+            sinks: SinkSet::new(sinks),
+        }
+    }
+
+    /// Add project-specific file helpers (e.g. `readAsset`) to the current set.
+    pub fn with_sinks(mut self, sinks: &[&str]) -> Self {
+        self.sinks.extend(sinks);
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let mut matches = Vec::new();
+
+        for call in ctx.call_sites {
+            let path = call_path(ctx.source, call).unwrap_or_default();
+            let path = path.trim();
+            if self.sinks.matching(call, path).is_none() {
+                continue;
+            }
+            let handler = enclosing_function(ctx, call.line);
+            let before = text_before(&lines, handler, call.line);
+            let args = call_arguments(ctx.source, call).unwrap_or_default();
+            let found = args
+                .iter()
+                .filter(|arg| !SANITIZERS.iter().any(|s| arg.contains(s)))
+                .filter_map(|arg| taint(ctx, handler, &before, arg, call.line))
+                .max_by(|a, b| a.0.total_cmp(&b.0));
+            let Some((confidence, source)) = found else {
+                continue;
+            };
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: call.line,
+                column: call.column,
+                pattern_id: "SEC-PATH-001".to_string(),
+                confidence,
+                cwe_ids: SmallVec::from_buf([22, 0]),
+                owasp: Some("A01:2021".to_string()),
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Security,
+                matched_text: format!("{path}() opens a path built from {source}"),
+            });
+        }
+
+        matches
+    }
+}
+
+impl Default for PathTraversalCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_PATH_SINKS)
+    }
+}
+
+/// How `arg` reaches the file API, as (confidence, description), or `None`
+/// when it is neither request-derived nor a climbing literal.
+fn taint(
+    ctx: &DetectionContext,
+    handler: Option<&FunctionInfo>,
+    before: &str,
+    arg: &str,
+    line: u32,
+) -> Option<(f32, String)> {
+    if is_request_input(arg) {
+        return Some((0.85, RequestTaint::Direct.describe(arg)));
+    }
+    let names: Vec<&str> = arg
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .map(|n| n.trim_start_matches('$'))
+        .filter(|n| !n.is_empty())
+        .collect();
+    for name in &names {
+        if assigned_from_request(before, name) && !containment_checked(before, name) {
+            return Some((0.70, RequestTaint::Assigned.describe(name)));
+        }
+    }
+    let param = handler.and_then(|f| {
+        f.parameters
+            .iter()
+            .map(|p| p.name.trim_start_matches('$'))
+            .find(|p| names.contains(p) && !matches!(*p, "req" | "request" | "res" | "response"))
+    });
+    if let Some(param) = param {
+        if in_request_handler(ctx, line) && !containment_checked(before, param) {
+            return Some((0.55, RequestTaint::HandlerParameter.describe(param)));
+        }
+    }
+    (arg.contains("../") || arg.contains("..\\\\"))
+        .then(|| (0.50, "a literal with `../` segments".to_string()))
+}
+
+/// Whether a line before the call checks `name` against a base directory.
+fn containment_checked(before: &str, name: &str) -> bool {
+    before.lines().any(|line| {
+        let lower = line.to_lowercase();
+        contains_word(line, name) && CONTAINMENT_MARKERS.iter().any(|m| lower.contains(m))
+    })
+}
@@ -0,0 +1,279 @@
+//! Prototype pollution in JavaScript/TypeScript (CWE-1321).
+//!
+//! `obj[req.query.key] = value` with `key = "__proto__"` writes to
+//! `Object.prototype` and changes every object in the process. Three shapes
+//! are flagged: computed-key assignments whose key comes from the request,
+//! deep-merge/assign calls (`_.merge`, `Object.assign`, `_.set`) fed request
+//! data, and hand-written recursive merges that copy every key. A function
+//! that rejects `__proto__`/`constructor`/`prototype` keys, checks own
+//! properties or builds prototype-less objects is treated as guarded.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::{call_arguments, call_path};
+use crate::detectors::handlers::{
+    enclosing_function, is_request_input, request_taint, text_before, RequestTaint,
+};
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::FunctionInfo;
+use crate::scanner::language_detect::Language;
+
+/// Deep merge and path-set calls checked by default (lodash, jQuery,
+/// `deepmerge`, `Object.assign`).
+pub const DEFAULT_MERGE_SINKS: &[&str] = &[
+    "assign", "merge", "mergeWith", "defaultsDeep", "extend", "deepmerge", "set", "setWith",
+    "zipObjectDeep",
+];
+
+/// Receivers under which the merge sinks are the library functions.
+const MERGE_RECEIVERS: &[&str] = &["", "Object", "_", "lodash", "$", "jQuery", "deepmerge"];
+
+/// Sinks whose tainted argument is a property path rather than a source object.
+const PATH_SINKS: &[&str] = &["set", "setWith", "zipObjectDeep"];
+
+/// Parsed JSON keeps a `__proto__` key as an own property, so merging it
+/// pollutes whatever text it was parsed from.
+const PARSED_SOURCES: &[&str] = &["JSON.parse("];
+
+/// Fragments of a key-sanitization guard.
+const GUARD_MARKERS: &[&str] = &[
+    "__proto__", "'constructor'", "\"constructor\"", "'prototype'", "\"prototype\"",
+    "hasOwnProperty", "Object.hasOwn(", "Object.create(null)", "isSafeKey", "isPrototypePolluted",
+    "Object.freeze(Object.prototype)",
+];
+
+/// Flags writes that let request data reach `Object.prototype`.
+#[derive(Debug, Clone)]
+pub struct PrototypePollutionCheck {
+    merge_sinks: Vec<String>,
+}
+
+impl PrototypePollutionCheck {
+    /// Create a check over a custom merge sink set.
+    pub fn new(merge_sinks: &[&str]) -> Self {
+        Self {
+            merge_sinks: merge_sinks.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Add project-specific merge helpers (e.g. `deepExtend`) to the current set.
+    pub fn with_merge_sinks(mut self, merge_sinks: &[&str]) -> Self {
+        self.merge_sinks.extend(merge_sinks.iter().map(|s| s.to_string()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        if !matches!(ctx.language, Language::JavaScript | Language::TypeScript) {
+            return Vec::new();
+        }
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let mut matches = Vec::new();
+
+        // Deep merge / assign / set calls fed request data.
+        for call in ctx.call_sites {
+            let receiver = call.receiver.as_deref().unwrap_or("");
+            if !self.merge_sinks.contains(&call.callee_name) || !MERGE_RECEIVERS.contains(&receiver) {
+                continue;
+            }
+            if call.callee_name == "assign" && receiver != "Object" {
+                continue;
+            }
+            let args = call_arguments(ctx.source, call).unwrap_or_default();
+            let handler = enclosing_function(ctx, call.line);
+            if guarded(&lines, handler) {
+                continue;
+            }
+            let path_sink = PATH_SINKS.contains(&call.callee_name.as_str());
+            let tainted = args
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| if path_sink { *i == 1 } else { *i >= 1 })
+                .find_map(|(_, arg)| taint(ctx, &lines, handler, arg, call.line));
+            if let Some((confidence, source)) = tainted {
+                let path = call_path(ctx.source, call).unwrap_or_default();
+                let what = if path_sink { "a property path" } else { "properties" };
+                matches.push(pollution_match(ctx, call.line, call.column, confidence, format!(
+                    "{}() copies {what} from {source} without rejecting `__proto__`", path.trim(),
+                )));
+            }
+        }
+
+        // Computed-key assignments: `obj[key] = value`.
+        for (idx, line) in lines.iter().enumerate() {
+            let line_no = idx as u32;
+            for (column, key) in computed_key_assignments(line) {
+                let handler = enclosing_function(ctx, line_no);
+                let Some((confidence, source)) = taint(ctx, &lines, handler, key, line_no) else {
+                    continue;
+                };
+                if guarded(&lines, handler) {
+                    continue;
+                }
+                matches.push(pollution_match(ctx, line_no, column as u32, confidence, format!(
+                    "assignment to `[{key}]` uses a key from {source} without rejecting `__proto__`",
+                )));
+            }
+        }
+
+        // Recursive merges that copy every key of a source object.
+        for func in ctx.functions {
+            if let Some(line) = unguarded_recursive_merge(ctx, &lines, func) {
+                matches.push(pollution_match(ctx, line, 0, 0.60, format!(
+                    "recursive merge `{}` copies every key without rejecting `__proto__`", func.name,
+                )));
+            }
+        }
+
+        matches
+    }
+}
+
+impl Default for PrototypePollutionCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_MERGE_SINKS)
+    }
+}
+
+/// How directly `expr` derives from the request, as (confidence, description).
+fn taint(
+    ctx: &DetectionContext,
+    lines: &[&str],
+    handler: Option<&FunctionInfo>,
+    expr: &str,
+    line: u32,
+) -> Option<(f32, String)> {
+    let expr = expr.trim();
+    if expr.starts_with(['"', '\'', '`']) || expr.parse::<f64>().is_ok() {
+        return None;
+    }
+    if PARSED_SOURCES.iter().any(|s| expr.contains(s)) {
+        return Some((0.80, "parsed JSON".to_string()));
+    }
+    // The key's own line counts: `for (const k in req.body) target[k] = ...`.
+    let before = text_before(lines, handler, line + 1);
+    let iterated = before.lines().any(|l| {
+        loop_binding(l).is_some_and(|(var, over)| var == expr && is_request_input(over))
+    });
+    if iterated {
+        return Some((0.65, format!("`{expr}`, iterated from request input")));
+    }
+    let taint = request_taint(ctx, handler, &before, expr, line)?;
+    let confidence = match taint {
+        RequestTaint::Direct => 0.80,
+        RequestTaint::Assigned => 0.65,
+        RequestTaint::HandlerParameter => 0.50,
+    };
+    Some((confidence, taint.describe(expr)))
+}
+
+/// Whether the enclosing function (or the file) contains a key guard.
+fn guarded(lines: &[&str], handler: Option<&FunctionInfo>) -> bool {
+    let (start, end) = handler.map_or((0, lines.len()), |f| (f.line as usize, f.end_line as usize + 1));
+    lines
+        .get(start..end.min(lines.len()))
+        .unwrap_or_default()
+        .iter()
+        .any(|l| GUARD_MARKERS.iter().any(|m| l.contains(m)))
+}
+
+/// `(column, key)` for each `target[key] = value` (or `target[a][b] = value`)
+/// on the line; comparisons and literal keys are skipped by the caller's taint check.
+fn computed_key_assignments(line: &str) -> Vec<(usize, &str)> {
+    let mut found = Vec::new();
+    let code = line.split("//").next().unwrap_or(line);
+    let mut search = 0;
+    while let Some(rel) = code[search..].find('[') {
+        let open = search + rel;
+        search = open + 1;
+        let preceded_by_ident = code[..open]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '$' || c == ']' || c == ')');
+        if !preceded_by_ident {
+            continue;
+        }
+        let Some(close) = matching_bracket(&code[open + 1..]) else {
+            continue;
+        };
+        let key = &code[open + 1..open + 1 + close];
+        // Skip to the end of any further `[..]` chain and require a plain `=`.
+        let mut rest = &code[open + 1 + close + 1..];
+        while rest.starts_with('[') {
+            match matching_bracket(&rest[1..]) {
+                Some(c) => rest = &rest[c + 2..],
+                None => break,
+            }
+        }
+        let rest = rest.trim_start();
+        let assigns = rest.starts_with('=') && !rest.starts_with("==") && !rest.starts_with("=>");
+        if assigns && !key.trim().is_empty() {
+            let target_start = code[..open]
+                .rfind(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$' || c == '.' || c == ']' || c == '['))
+                .map_or(0, |i| i + 1);
+            found.push((target_start, key.trim()));
+        }
+    }
+    found
+}
+
+/// Offset of the `]` closing a bracket whose `[` precedes `text`.
+fn matching_bracket(text: &str) -> Option<usize> {
+    let mut depth = 0i32;
+    for (i, c) in text.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' if depth == 0 => return Some(i),
+            ']' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The first line of a recursive function that copies every key of an
+/// object into a target without a key guard.
+fn unguarded_recursive_merge(ctx: &DetectionContext, lines: &[&str], func: &FunctionInfo) -> Option<u32> {
+    if func.name.is_empty() || func.name.starts_with('<') {
+        return None;
+    }
+    let body = lines.get(func.line as usize..(func.end_line as usize + 1).min(lines.len()))?;
+    let iterates_keys = body.iter().any(|l| {
+        (l.contains("for (") || l.contains("for(")) && (l.contains(" in ") || l.contains("Object.keys("))
+            || l.contains("Object.keys(") && l.contains("forEach")
+            || l.contains("Object.entries(")
+    });
+    let copies_keys = body.iter().any(|l| !computed_key_assignments(l).is_empty());
+    let recursive = ctx.call_sites.iter().any(|c| {
+        c.callee_name == func.name && c.line > func.line && c.line <= func.end_line
+    });
+    let guarded = body.iter().any(|l| GUARD_MARKERS.iter().any(|m| l.contains(m)));
+    (iterates_keys && copies_keys && recursive && !guarded).then_some(func.line)
+}
+
+/// `(var, iterable)` for `for (const var in|of iterable)`.
+fn loop_binding(line: &str) -> Option<(&str, &str)> {
+    let rest = &line[line.find("for")? + 3..];
+    let rest = rest.trim_start().strip_prefix('(')?;
+    let (binding, over) = rest.split_once(" in ").or_else(|| rest.split_once(" of "))?;
+    let var = binding.split_whitespace().last()?;
+    Some((var, over.trim_end().trim_end_matches('{').trim_end().trim_end_matches(')')))
+}
+
+fn pollution_match(ctx: &DetectionContext, line: u32, column: u32, confidence: f32, matched_text: String) -> PatternMatch {
+    PatternMatch {
+        file: ctx.file.to_string(),
+        line,
+        column,
+        pattern_id: "SEC-PROTO-POLLUTION-001".to_string(),
+        confidence,
+        cwe_ids: SmallVec::from_buf([1321, 0]),
+        owasp: Some("A08:2021".to_string()),
+        detection_method: DetectionMethod::AstVisitor,
+        category: PatternCategory::Security,
+        matched_text,
+    }
+}
@@ -0,0 +1,183 @@
+//! Unsafe reflection (CWE-470).
+//!
+//! `Class.forName(request.getParameter("type"))` lets the caller choose which
+//! class is loaded and instantiated; `getattr(obj, name)()`, `__import__`,
+//! `require(name)`, Ruby `constantize` and PHP variable functions (`$fn()`)
+//! do the same for functions and modules. A dynamic load is flagged when the
+//! name comes from the request, unless the handler first checks it against
+//! an allow-list. Confidence follows how directly the name is tainted: read
+//! from the request in the call itself, assigned from it one hop earlier, or
+//! passed in as a handler parameter.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::{call_arguments, call_path};
+use crate::detectors::handlers::{
+    assignments, contains_word, enclosing_function, request_taint, text_before, RequestTaint,
+};
+use crate::detectors::sinks::SinkSet;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::scanner::language_detect::Language;
+
+/// Dynamic loading calls checked by default: Java `Class.forName` and
+/// `ClassLoader.loadClass`, C# `Type.GetType`/`Activator.CreateInstance`,
+/// Python `__import__`/`importlib.import_module`/`getattr`, Node `require`,
+/// PHP `call_user_func`, Ruby `constantize`/`const_get`.
+pub const DEFAULT_REFLECTION_SINKS: &[&str] = &[
+    "forName", "loadClass", "GetType", "CreateInstance", "__import__", "import_module", "getattr",
+    "require", "call_user_func", "call_user_func_array", "constantize", "safe_constantize",
+    "const_get",
+];
+
+/// Sinks whose loaded name is the receiver rather than an argument.
+const RECEIVER_SINKS: &[&str] = &["constantize", "safe_constantize"];
+
+/// Fragments of a check that restricts a name to known values.
+const ALLOWLIST_MARKERS: &[&str] = &[
+    "allow", "whitelist", "permitted", ".contains(", ".includes(", ".has(", "in_array(",
+    "containskey(", "include?(", " in ",
+];
+
+/// Flags dynamic class, module and function loading from request input.
+#[derive(Debug, Clone)]
+pub struct ReflectionCheck {
+    sinks: SinkSet,
+}
+
+impl ReflectionCheck {
+    /// Create a check over a custom reflection sink set.
+    pub fn new(sinks: &[&str]) -> Self {
+        Self {
+            sinks: SinkSet::new(sinks),
+        }
+    }
+
+    /// Add framework-specific loaders (e.g. `loadPlugin`) to the current set.
+    pub fn with_sinks(mut self, sinks: &[&str]) -> Self {
+        self.sinks.extend(sinks);
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let mut matches = Vec::new();
+
+        for call in ctx.call_sites {
+            let variable_call = ctx.language == Language::Php && call.callee_name.starts_with('$');
+            if !variable_call && !self.sinks.contains(&call.callee_name) {
+                continue;
+            }
+            let path = call_path(ctx.source, call).unwrap_or_default();
+            let name = if variable_call {
+                call.callee_name.trim_start_matches('$').to_string()
+            } else if RECEIVER_SINKS.contains(&call.callee_name.as_str()) {
+                path.trim()
+                    .strip_suffix(call.callee_name.as_str())
+                    .unwrap_or("")
+                    .trim_end_matches('.')
+                    .to_string()
+            } else {
+                let args = call_arguments(ctx.source, call).unwrap_or_default();
+                let index = usize::from(call.callee_name == "getattr");
+                match args.into_iter().nth(index) {
+                    Some(arg) => arg,
+                    None => continue,
+                }
+            };
+            if let Some(m) = reflection_match(ctx, &lines, call.line, call.column, &name, path.trim()) {
+                matches.push(m);
+            }
+        }
+
+        if ctx.language == Language::Php {
+            for (line, column, name) in php_variable_functions(&lines) {
+                if let Some(m) = reflection_match(ctx, &lines, line, column, name, &format!("${name}")) {
+                    matches.push(m);
+                }
+            }
+        }
+
+        matches
+    }
+}
+
+impl Default for ReflectionCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_REFLECTION_SINKS)
+    }
+}
+
+/// Build a match for a load of `name` at `line`, or `None` when the name is
+/// not request-derived or is allow-list checked first.
+fn reflection_match(
+    ctx: &DetectionContext,
+    lines: &[&str],
+    line: u32,
+    column: u32,
+    name: &str,
+    target: &str,
+) -> Option<PatternMatch> {
+    let name = name.trim();
+    if name.is_empty() || name.starts_with(['"', '\'']) {
+        return None;
+    }
+    let handler = enclosing_function(ctx, line);
+    let before = text_before(lines, handler, line);
+    let taint = request_taint(ctx, handler, &before, name, line)?;
+    if allowlist_checked(&before, name) {
+        return None;
+    }
+    Some(PatternMatch {
+        file: ctx.file.to_string(),
+        line,
+        column,
+        pattern_id: "SEC-REFLECT-001".to_string(),
+        confidence: match taint {
+            RequestTaint::Direct => 0.85,
+            RequestTaint::Assigned => 0.70,
+            RequestTaint::HandlerParameter => 0.55,
+        },
+        cwe_ids: SmallVec::from_buf([470, 0]),
+        owasp: Some("A03:2021".to_string()),
+        detection_method: DetectionMethod::AstVisitor,
+        category: PatternCategory::Security,
+        matched_text: format!(
+            "{target}() loads code named by {} without an allow-list check",
+            taint.describe(name),
+        ),
+    })
+}
+
+/// Whether a line before the load checks `name` against known values.
+fn allowlist_checked(before: &str, name: &str) -> bool {
+    let ident = name.trim_start_matches('$');
+    before.lines().any(|line| {
+        let lower = line.to_lowercase();
+        let assigns_name = assignments(line).any(|(target, _)| target == ident);
+        !assigns_name && contains_word(line, ident) && ALLOWLIST_MARKERS.iter().any(|m| lower.contains(m))
+    })
+}
+
+/// PHP variable function calls (`$fn(...)`), which the parser does not
+/// record as call sites. `$obj->$m()` is recorded and handled there.
+fn php_variable_functions<'a>(lines: &[&'a str]) -> Vec<(u32, u32, &'a str)> {
+    let mut calls = Vec::new();
+    for (idx, line) in lines.iter().enumerate() {
+        for (pos, _) in line.match_indices('$') {
+            let prefix = &line[..pos];
+            if prefix.ends_with("->") || prefix.ends_with("::") || prefix.ends_with("new ") {
+                continue;
+            }
+            let rest = &line[pos + 1..];
+            let len = rest.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(rest.len());
+            if len > 0 && rest[len..].starts_with('(') {
+                calls.push((idx as u32, pos as u32, &rest[..len]));
+            }
+        }
+    }
+    calls
+}
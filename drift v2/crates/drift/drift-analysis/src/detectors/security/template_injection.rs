@@ -0,0 +1,134 @@
+//! Server-side template injection (CWE-1336).
+//!
+//! `render_template_string(request.args["tpl"])` compiles the caller's text
+//! as a Jinja template, so `{{ ''.__class__.__mro__ }}` walks into Python
+//! internals; Handlebars, EJS, Pug, Nunjucks, Thymeleaf, Velocity, ERB and
+//! `new Function(...)` behave the same. A compile or render call is flagged
+//! when its *template* argument comes from the request. Passing request data
+//! into a fixed template (`render_template_string("<h1>{{ n }}</h1>", n=name)`)
+//! is not a template injection and is skipped. Confidence follows how
+//! directly the template is tainted, as for unsafe reflection.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::{call_arguments, call_path};
+use crate::detectors::handlers::{enclosing_function, request_taint, text_before, RequestTaint};
+use crate::detectors::sinks::SinkSet;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::{CallSite, FunctionInfo};
+
+/// Template compile/render calls checked by default, as a bare callee name or
+/// a qualified callee path (Flask/Jinja, Django, Mako, Handlebars, EJS, Pug,
+/// Nunjucks, lodash, Mustache, doT, Thymeleaf, Velocity, ERB, Liquid, Twig, JS
+/// `Function`).
+pub const DEFAULT_TEMPLATE_SINKS: &[&str] = &[
+    "render_template_string", "from_string", "jinja2.Template", "Template", "Handlebars.compile",
+    "handlebars.compile", "Handlebars.precompile", "ejs.render", "ejs.compile", "pug.render",
+    "pug.compile", "nunjucks.renderString", "renderString", "_.template", "lodash.template",
+    "Mustache.render", "doT.template", "templateEngine.process", "Velocity.evaluate",
+    "velocityEngine.evaluate", "ERB.new", "Liquid::Template.parse", "createTemplate", "Function",
+];
+
+/// Sinks whose template is not the first argument: `(sink, argument index)`.
+const TEMPLATE_ARGUMENT: &[(&str, usize)] = &[("Velocity.evaluate", 3), ("velocityEngine.evaluate", 3)];
+
+/// Flags templates compiled or rendered from request input.
+#[derive(Debug, Clone)]
+pub struct TemplateInjectionCheck {
+    sinks: SinkSet,
+}
+
+impl TemplateInjectionCheck {
+    /// Create a check over a custom set of template compile/render calls.
+    pub fn new(sinks: &[&str]) -> Self {
+        Self {
+            sinks: SinkSet::new(sinks),
+        }
+    }
+
+    /// Add engine-specific entry points (e.g. `Eta.renderString`) to the current set.
+    pub fn with_sinks(mut self, sinks: &[&str]) -> Self {
+        self.sinks.extend(sinks);
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let mut matches = Vec::new();
+
+        for call in ctx.call_sites {
+            let Some(sink) = self.sink_target(ctx.source, call) else {
+                continue;
+            };
+            let args = call_arguments(ctx.source, call).unwrap_or_default();
+            // `new Function(a, b, body)`: any argument is compiled code.
+            let templates: Vec<&String> = if call.callee_name == "Function" {
+                args.iter().collect()
+            } else {
+                let index = TEMPLATE_ARGUMENT.iter().find(|(s, _)| *s == sink).map_or(0, |(_, i)| *i);
+                args.get(index).into_iter().collect()
+            };
+
+            let handler = enclosing_function(ctx, call.line);
+            let before = text_before(&lines, handler, call.line);
+            let Some((confidence, source)) = templates
+                .iter()
+                .filter_map(|t| taint(ctx, handler, &before, t.trim(), call.line))
+                .max_by(|a, b| a.0.total_cmp(&b.0))
+            else {
+                continue;
+            };
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: call.line,
+                column: call.column,
+                pattern_id: "SEC-SSTI-001".to_string(),
+                confidence,
+                cwe_ids: SmallVec::from_buf([1336, 0]),
+                owasp: Some("A03:2021".to_string()),
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Security,
+                matched_text: format!("{sink}() compiles a template from {source} — render a fixed template and pass input as data"),
+            });
+        }
+
+        matches
+    }
+
+    /// The configured sink this call site resolves to, if any.
+    fn sink_target(&self, source: &[u8], call: &CallSite) -> Option<&str> {
+        let path = call_path(source, call).unwrap_or_default();
+        self.sinks.matching(call, &path)
+    }
+}
+
+impl Default for TemplateInjectionCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_TEMPLATE_SINKS)
+    }
+}
+
+/// How directly the template expression derives from the request, as
+/// (confidence, description). Literal templates are never tainted.
+fn taint(
+    ctx: &DetectionContext,
+    handler: Option<&FunctionInfo>,
+    before: &str,
+    template: &str,
+    line: u32,
+) -> Option<(f32, String)> {
+    if template.is_empty() || (template.starts_with(['"', '\'', '`']) && !template.contains("${")) {
+        return None;
+    }
+    let taint = request_taint(ctx, handler, before, template, line)?;
+    let confidence = match taint {
+        RequestTaint::Direct => 0.90,
+        RequestTaint::Assigned => 0.75,
+        RequestTaint::HandlerParameter => 0.60,
+    };
+    Some((confidence, taint.describe(template)))
+}
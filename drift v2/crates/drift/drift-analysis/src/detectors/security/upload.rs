@@ -0,0 +1,217 @@
+//! Unrestricted file upload (CWE-434).
+//!
+//! An uploaded file written to disk under a name or directory the client
+//! chose (`f.save(os.path.join(UPLOADS, f.filename))`) lets an attacker plant
+//! executable content or overwrite files. Save calls on upload objects are
+//! flagged when the destination derives from the request, or when nothing in
+//! the handler checks the file's extension or content type. Confidence is
+//! highest when both are true and drops when validation is present.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::{call_arguments, call_path};
+use crate::detectors::handlers::{assignments, contains_word, enclosing_function, is_request_input};
+use crate::detectors::sinks::SinkSet;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::{CallSite, FunctionInfo};
+
+/// Upload sinks checked by default: Flask/Werkzeug `save`, Spring
+/// `transferTo`, express-fileupload `mv`, ASP.NET `SaveAs`/`CopyTo`, PHP
+/// `move_uploaded_file`, Laravel `storeAs`/`move`, multer storage.
+pub const DEFAULT_UPLOAD_SINKS: &[&str] = &[
+    "save", "transferTo", "mv", "SaveAs", "CopyTo", "CopyToAsync", "move_uploaded_file",
+    "storeAs", "move", "multer", "diskStorage",
+];
+
+/// Validation fragments checked by default: extension allow-lists, MIME
+/// checks, upload filters and filename sanitizers.
+pub const DEFAULT_VALIDATORS: &[&str] = &[
+    "allowed_file", "allowed_extensions", "allowedextensions", "extname", "splitext",
+    "getextension", "mimetype", "content_type", "contenttype", "getcontenttype", "filefilter",
+    "magic.from", "filetype", "file-type", "accept",
+];
+
+/// Sanitizers that strip directory components from a client filename.
+const PATH_SANITIZERS: &[&str] = &[
+    "secure_filename(", "basename(", "Path.GetFileName(", "FilenameUtils.getName(", "uuid",
+];
+
+/// Client-supplied upload names, read alongside the shared request sources.
+const CLIENT_FILENAMES: &[&str] = &[
+    "originalname", ".filename", "getOriginalFilename", "FileName", "getClientOriginalName",
+    "['name']", "[\"name\"]", "file.name",
+];
+
+/// Expressions that yield an uploaded file object.
+const UPLOAD_SOURCES: &[&str] = &[
+    "request.files", "request.FILES", "req.files", "req.file", "$_FILES", "Request.Files",
+    "FormFile(", "->file(",
+];
+
+/// Parameter types that carry an uploaded file.
+const UPLOAD_TYPES: &[&str] = &["MultipartFile", "IFormFile", "UploadFile", "FileStorage", "UploadedFile"];
+
+/// Flags upload saves with a request-derived destination or no validation.
+#[derive(Debug, Clone)]
+pub struct UploadCheck {
+    sinks: SinkSet,
+    validators: Vec<String>,
+}
+
+impl UploadCheck {
+    /// Create a check over custom upload sink and validator sets.
+    pub fn new(sinks: &[&str], validators: &[&str]) -> Self {
+        Self {
+            sinks: SinkSet::new(sinks),
+            validators: validators.iter().map(|s| s.to_lowercase()).collect(),
+        }
+    }
+
+    /// Add framework-specific upload sinks to the current set.
+    pub fn with_sinks(mut self, sinks: &[&str]) -> Self {
+        self.sinks.extend(sinks);
+        self
+    }
+
+    /// Add project-specific validators (e.g. `validateUpload`) to the current set.
+    pub fn with_validators(mut self, validators: &[&str]) -> Self {
+        self.validators.extend(validators.iter().map(|s| s.to_lowercase()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let mut matches = Vec::new();
+
+        for call in ctx.call_sites {
+            if !self.sinks.contains(&call.callee_name) {
+                continue;
+            }
+            let handler = enclosing_function(ctx, call.line);
+            let scope = scope_text(&lines, handler, call.line);
+            let path = call_path(ctx.source, call).unwrap_or_default();
+            if !is_upload_sink(call, &path, &scope.before, handler) {
+                continue;
+            }
+
+            let args = call_arguments(ctx.source, call).unwrap_or_default();
+            let tainted = tainted_names(&scope.before);
+            let user_path = args.iter().any(|arg| {
+                !PATH_SANITIZERS.iter().any(|s| arg.contains(s))
+                    && (from_client(arg) || tainted.iter().any(|name| contains_word(arg, name)))
+            });
+            let validated = {
+                let lower = scope.all.to_lowercase();
+                self.validators.iter().any(|v| lower.contains(v.as_str()))
+            };
+            if !user_path && validated {
+                continue;
+            }
+
+            let (confidence, reason) = match (user_path, validated) {
+                (true, false) => (0.80, "to a request-derived path without type validation"),
+                (true, true) => (0.60, "to a request-derived path"),
+                _ => (0.50, "without extension or content-type validation"),
+            };
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: call.line,
+                column: call.column,
+                pattern_id: "SEC-UPLOAD-001".to_string(),
+                confidence,
+                cwe_ids: SmallVec::from_buf([434, 0]),
+                owasp: Some("A04:2021".to_string()),
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Security,
+                matched_text: format!("Uploaded file saved {reason}: {}()", path.trim()),
+            });
+        }
+
+        matches
+    }
+}
+
+impl Default for UploadCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_UPLOAD_SINKS, DEFAULT_VALIDATORS)
+    }
+}
+
+/// Source text of the enclosing function (or whole file): all of it, and the
+/// part up to and including the call's line.
+struct Scope {
+    all: String,
+    before: String,
+}
+
+fn scope_text(lines: &[&str], handler: Option<&FunctionInfo>, line: u32) -> Scope {
+    let (start, end) = handler
+        .map(|f| (f.line as usize, f.end_line as usize))
+        .unwrap_or((0, lines.len().saturating_sub(1)));
+    let end = end.min(lines.len().saturating_sub(1));
+    let call_line = (line as usize).clamp(start, end);
+    Scope {
+        all: lines.get(start..=end).unwrap_or_default().join("\n"),
+        before: lines.get(start..=call_line).unwrap_or_default().join("\n"),
+    }
+}
+
+/// Whether a sink call writes an uploaded file, rather than e.g. `user.save()`.
+fn is_upload_sink(call: &CallSite, path: &str, before: &str, handler: Option<&FunctionInfo>) -> bool {
+    match call.callee_name.as_str() {
+        "move_uploaded_file" | "transferTo" | "multer" | "diskStorage" => return true,
+        _ => {}
+    }
+    let receiver = path
+        .trim()
+        .strip_suffix(call.callee_name.as_str())
+        .unwrap_or("")
+        .trim_end_matches(['.', ':', '>', '-']);
+    if receiver.is_empty() {
+        return false;
+    }
+    if UPLOAD_SOURCES.iter().any(|s| receiver.contains(s)) {
+        return true;
+    }
+
+    let root = receiver
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .find(|s| !s.is_empty())
+        .unwrap_or("");
+    let upload_param = handler.is_some_and(|f| {
+        f.parameters.iter().any(|p| {
+            p.name == root
+                && p.type_annotation
+                    .as_deref()
+                    .is_some_and(|t| UPLOAD_TYPES.iter().any(|u| t.contains(u)))
+        })
+    });
+    upload_param || assigned_from(before, root, UPLOAD_SOURCES)
+}
+
+/// Whether `name` is assigned from one of `sources` in `text`.
+fn assigned_from(text: &str, name: &str, sources: &[&str]) -> bool {
+    !name.is_empty() && assignments(text).any(|(target, value)| {
+        target == name && sources.iter().any(|s| value.contains(s))
+    })
+}
+
+/// Names assigned from request-controlled data in `text` (one hop).
+fn tainted_names(text: &str) -> Vec<&str> {
+    assignments(text)
+        .filter(|(_, value)| {
+            from_client(value)
+                && !PATH_SANITIZERS.iter().any(|s| value.contains(s))
+        })
+        .map(|(target, _)| target)
+        .collect()
+}
+
+/// Whether `expr` reads request data or a client-supplied upload name.
+fn from_client(expr: &str) -> bool {
+    is_request_input(expr) || CLIENT_FILENAMES.iter().any(|s| expr.contains(s))
+}
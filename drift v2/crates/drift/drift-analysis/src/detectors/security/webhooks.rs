@@ -0,0 +1,139 @@
+//! Webhook handlers that trust unsigned payloads (CWE-345).
+//!
+//! Anyone who learns a `/webhook` URL can post a forged "payment succeeded"
+//! or "push" event to it unless the handler checks the provider's signature
+//! (`X-Hub-Signature-256`, `Stripe-Signature`, Slack/Twilio/Shopify HMACs).
+//! A request handler counts as a webhook when its name, route decorator or
+//! registration line mentions `webhook`. It is flagged when it reads the
+//! request payload and no signature-verification call precedes that read.
+//! Verification in route middleware on the registration line
+//! (`app.post("/webhook", verifyStripe, ...)`) counts. Confidence is highest
+//! when the signature header is never read at all.
+
+use smallvec::SmallVec;
+
+use crate::detectors::call_args::call_path;
+use crate::detectors::handlers::is_request_handler;
+use crate::detectors::sinks::SinkSet;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::{CallSite, FunctionInfo};
+
+/// Signature-verification calls checked by default, as a bare callee name or
+/// a qualified callee path (HMAC constant-time compares, Stripe, Svix,
+/// Octokit, Twilio, Slack, ActiveSupport, Go `hmac.Equal`).
+pub const DEFAULT_SIGNATURE_VERIFIERS: &[&str] = &[
+    "verifySignature", "verify_signature", "verifyWebhook", "verify_webhook", "verifyHeader",
+    "compare_digest", "timingSafeEqual", "hash_equals", "secure_compare", "MessageDigest.isEqual",
+    "hmac.Equal", "ConstantTimeCompare", "constructEvent", "construct_event", "ConstructEvent",
+    "wh.verify", "webhook.verify", "webhooks.verify", "validateRequest", "validate_request",
+    "isValidSignature", "is_valid_signature",
+];
+
+/// Expressions that read the request payload.
+const PAYLOAD_SOURCES: &[&str] = &[
+    "req.body", "req.rawBody", "request.body", "request.data", "request.get_data", "request.json",
+    "request.get_json", "request.POST", "request.raw_post", "ctx.request.body", "r.Body",
+    "php://input", "getContent()", "getInputStream", "$request->all", "Request.Body",
+];
+
+/// Lowercased fragments of signature headers and HMAC handling.
+const SIGNATURE_MARKERS: &[&str] = &["signature", "hmac", "x-hub-", "x-gitlab-token"];
+
+/// Flags webhook handlers that act on the payload without verifying its signature.
+#[derive(Debug, Clone)]
+pub struct WebhookSignatureCheck {
+    verifiers: SinkSet,
+}
+
+impl WebhookSignatureCheck {
+    /// Create a check over a custom set of signature-verification calls.
+    pub fn new(verifiers: &[&str]) -> Self {
+        Self {
+            verifiers: SinkSet::new(verifiers),
+        }
+    }
+
+    /// Add provider- or project-specific verifiers (e.g. `checkPaddleSignature`).
+    pub fn with_verifiers(mut self, verifiers: &[&str]) -> Self {
+        self.verifiers.extend(verifiers);
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let mut matches = Vec::new();
+
+        for func in ctx.functions {
+            let registration = lines.get(func.line as usize).copied().unwrap_or("");
+            if !is_request_handler(func) || !is_webhook(func, registration) {
+                continue;
+            }
+            if registration.to_lowercase().contains("verify") || self.verifiers.iter().any(|v| registration.contains(v)) {
+                continue;
+            }
+
+            // Payload reads in the body; the signature line may bind parameters.
+            let end = (func.end_line as usize).min(lines.len().saturating_sub(1));
+            let Some((read_line, read_text)) = (func.line as usize + 1..=end)
+                .filter_map(|i| PAYLOAD_SOURCES.iter().find(|s| lines[i].contains(*s)).map(|s| (i as u32, *s)))
+                .next()
+            else {
+                continue;
+            };
+            let verified_at = ctx
+                .call_sites
+                .iter()
+                .filter(|c| func.line <= c.line && c.line <= func.end_line && self.is_verifier(ctx.source, c))
+                .map(|c| c.line)
+                .min();
+            if verified_at.is_some_and(|line| line <= read_line) {
+                continue;
+            }
+
+            let body = lines.get(func.line as usize..=end).unwrap_or_default().join("\n").to_lowercase();
+            let reads_signature = SIGNATURE_MARKERS.iter().any(|m| body.contains(m));
+            let (confidence, reason) = match (verified_at, reads_signature) {
+                (Some(_), _) => (0.50, "verifies the signature only after reading the payload"),
+                (None, true) => (0.60, "reads a signature header but never verifies it"),
+                (None, false) => (0.70, "never checks a signature"),
+            };
+            let name = if func.name == "<anonymous>" { "Webhook handler".to_string() } else { format!("Webhook handler `{}`", func.name) };
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: read_line,
+                column: lines[read_line as usize].find(read_text).unwrap_or(0) as u32,
+                pattern_id: "SEC-WEBHOOK-001".to_string(),
+                confidence,
+                cwe_ids: SmallVec::from_buf([345, 0]),
+                owasp: Some("A08:2021".to_string()),
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Security,
+                matched_text: format!("{name} uses {read_text} but {reason}"),
+            });
+        }
+
+        matches
+    }
+
+    fn is_verifier(&self, source: &[u8], call: &CallSite) -> bool {
+        let path = call_path(source, call).unwrap_or_default();
+        self.verifiers.matching(call, &path).is_some()
+    }
+}
+
+impl Default for WebhookSignatureCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_SIGNATURE_VERIFIERS)
+    }
+}
+
+/// Whether the handler's name, decorators or registration line mark it as a webhook.
+fn is_webhook(func: &FunctionInfo, registration: &str) -> bool {
+    func.name.to_lowercase().contains("webhook")
+        || registration.to_lowercase().contains("webhook")
+        || func.decorators.iter().any(|d| d.raw_text.to_lowercase().contains("webhook"))
+}
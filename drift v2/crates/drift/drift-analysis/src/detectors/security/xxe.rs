@@ -0,0 +1,151 @@
+//! XML external entity processing (CWE-611).
+//!
+//! An XML parser that resolves external entities lets a crafted document
+//! read local files (`<!ENTITY x SYSTEM "file:///etc/passwd">`) or make the
+//! server issue requests. Two shapes are flagged:
+//!
+//! - Java parser factories (`DocumentBuilderFactory`, `SAXParserFactory`,
+//!   `XMLInputFactory`, ...) created in a function that never applies the
+//!   hardening for that factory (`disallow-doctype-decl`, `SUPPORT_DTD`,
+//!   `ACCESS_EXTERNAL_DTD`, ...). Their defaults resolve entities.
+//! - Options that explicitly enable resolution elsewhere: lxml
+//!   `resolve_entities=True`, .NET `DtdProcessing.Parse`, PHP `LIBXML_NOENT`,
+//!   libxmljs `noent: true`.
+
+use smallvec::SmallVec;
+
+use crate::detectors::handlers::enclosing_scope;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+
+/// Java parser factories, each with the settings that disable external
+/// entities for it. Any one of them marks the factory as hardened.
+const JAVA_FACTORIES: &[(&str, &[&str])] = &[
+    ("DocumentBuilderFactory.newInstance(", DOCTYPE_HARDENING),
+    ("SAXParserFactory.newInstance(", DOCTYPE_HARDENING),
+    ("new SAXReader(", DOCTYPE_HARDENING),
+    ("new SAXBuilder(", DOCTYPE_HARDENING),
+    ("XMLInputFactory.newInstance(", STAX_HARDENING),
+    ("XMLInputFactory.newFactory(", STAX_HARDENING),
+    ("TransformerFactory.newInstance(", JAXP_HARDENING),
+    ("SchemaFactory.newInstance(", JAXP_HARDENING),
+];
+
+const DOCTYPE_HARDENING: &[&str] = &[
+    "disallow-doctype-decl", "external-general-entities", "ACCESS_EXTERNAL_DTD",
+    "setExpandEntityReferences(false)",
+];
+const STAX_HARDENING: &[&str] = &["SUPPORT_DTD", "IS_SUPPORTING_EXTERNAL_ENTITIES", "supportDTD"];
+const JAXP_HARDENING: &[&str] = &["ACCESS_EXTERNAL_DTD", "ACCESS_EXTERNAL_SCHEMA", "ACCESS_EXTERNAL_STYLESHEET"];
+
+/// Parser options that turn external entity resolution on.
+pub const DEFAULT_UNSAFE_OPTIONS: &[&str] = &[
+    // Python lxml / xml.sax
+    "resolve_entities=True", "feature_external_ges, True",
+    // .NET XmlReaderSettings / XmlTextReader
+    "DtdProcessing.Parse", "ProhibitDtd = false",
+    // PHP libxml
+    "LIBXML_NOENT", "libxml_disable_entity_loader(false)",
+    // Node libxmljs
+    "noent: true",
+];
+
+/// Flags XML parsers configured (or left by default) to resolve external entities.
+#[derive(Debug, Clone)]
+pub struct XxeCheck {
+    unsafe_options: Vec<String>,
+}
+
+impl XxeCheck {
+    /// Create a check over a custom set of unsafe parser options.
+    pub fn new(unsafe_options: &[&str]) -> Self {
+        Self {
+            unsafe_options: unsafe_options.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Add library-specific unsafe options (e.g. `expand_entities=True`).
+    pub fn with_unsafe_options(mut self, options: &[&str]) -> Self {
+        self.unsafe_options.extend(options.iter().map(|s| s.to_string()));
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let Ok(text) = std::str::from_utf8(ctx.source) else {
+            return Vec::new();
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        let mut matches = Vec::new();
+
+        for (idx, line) in lines.iter().enumerate() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("//") || trimmed.starts_with('#') || trimmed.starts_with('*') {
+                continue;
+            }
+            let line_no = idx as u32;
+
+            for (factory, hardening) in JAVA_FACTORIES {
+                let Some(column) = line.find(factory) else {
+                    continue;
+                };
+                if hardening.iter().any(|h| enclosing_scope(ctx, &lines, line_no).contains(h)) {
+                    continue;
+                }
+                let name = factory.trim_start_matches("new ").trim_end_matches('(');
+                matches.push(xxe_match(
+                    ctx,
+                    line_no,
+                    column,
+                    "SEC-XXE-001",
+                    0.80,
+                    format!("{name} resolves external entities by default and is not hardened (e.g. {})", hardening[0]),
+                ));
+            }
+
+            if let Some((column, option)) = self
+                .unsafe_options
+                .iter()
+                .find_map(|o| line.find(o.as_str()).map(|c| (c, o)))
+            {
+                matches.push(xxe_match(
+                    ctx,
+                    line_no,
+                    column,
+                    "SEC-XXE-002",
+                    0.90,
+                    format!("`{option}` enables external entity resolution in the XML parser"),
+                ));
+            }
+        }
+
+        matches
+    }
+}
+
+impl Default for XxeCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_UNSAFE_OPTIONS)
+    }
+}
+
+fn xxe_match(
+    ctx: &DetectionContext,
+    line: u32,
+    column: usize,
+    pattern_id: &str,
+    confidence: f32,
+    matched_text: String,
+) -> PatternMatch {
+    PatternMatch {
+        file: ctx.file.to_string(),
+        line,
+        column: column as u32,
+        pattern_id: pattern_id.to_string(),
+        confidence,
+        cwe_ids: SmallVec::from_buf([611, 0]),
+        owasp: Some("A05:2021".to_string()),
+        detection_method: DetectionMethod::AstVisitor,
+        category: PatternCategory::Security,
+        matched_text,
+    }
+}
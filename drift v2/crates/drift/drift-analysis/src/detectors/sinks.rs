@@ -0,0 +1,46 @@
+//! Configurable sink lists for the lexical checks.
+//!
+//! A check starts from its default sink calls and lets a project add its own
+//! wrappers (`readAsset`, `loadPlugin`) on top. Bare names match the callee;
+//! qualified names (`os.path.join`, `fs::read`) must end the call path,
+//! because the bare method (`join`, `read`) is too common on its own.
+
+use crate::parsers::types::CallSite;
+
+/// Sink call names, bare or qualified.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SinkSet(Vec<String>);
+
+impl SinkSet {
+    pub fn new(sinks: &[&str]) -> Self {
+        Self(sinks.iter().map(|s| s.to_string()).collect())
+    }
+
+    /// Add sinks to the current set.
+    pub fn extend(&mut self, sinks: &[&str]) {
+        self.0.extend(sinks.iter().map(|s| s.to_string()));
+    }
+
+    /// Every sink, bare or qualified.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+
+    /// Whether `callee` is one of the sinks by bare name.
+    pub fn contains(&self, callee: &str) -> bool {
+        self.0.iter().any(|s| s == callee)
+    }
+
+    /// The sink a call with call path `path` resolves to, if any.
+    pub fn matching(&self, call: &CallSite, path: &str) -> Option<&str> {
+        let path = path.trim();
+        self.0.iter().map(String::as_str).find(|sink| {
+            if sink.contains(['.', ':']) {
+                path.strip_suffix(sink)
+                    .is_some_and(|prefix| !prefix.ends_with(|c: char| c.is_alphanumeric() || c == '_'))
+            } else {
+                call.callee_name == *sink
+            }
+        })
+    }
+}
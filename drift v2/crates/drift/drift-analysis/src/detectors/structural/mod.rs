@@ -1,12 +1,29 @@
 //! Structural detector — naming conventions, file organization, module patterns.
 
+pub mod parameters;
+
+use drift_core::config::DetectorOptions;
 use smallvec::SmallVec;
 
 use crate::detectors::traits::{Detector, DetectorCategory, DetectorVariant};
 use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
 use crate::engine::visitor::DetectionContext;
 
-pub struct StructuralDetector;
+#[derive(Debug, Clone, Default)]
+pub struct StructuralDetector {
+    parameters: parameters::LongParameterListCheck,
+}
+
+impl StructuralDetector {
+    /// Create the detector with the thresholds from `options`.
+    pub fn new(options: &DetectorOptions) -> Self {
+        let parameters = options
+            .max_parameters
+            .map_or_else(parameters::LongParameterListCheck::default, parameters::LongParameterListCheck::new)
+            .with_constructors_excluded(options.exclude_constructors.unwrap_or(false));
+        Self { parameters }
+    }
+}
 
 impl Detector for StructuralDetector {
     fn id(&self) -> &str { "structural-base" }
@@ -68,6 +85,8 @@ impl Detector for StructuralDetector {
             });
         }
 
+        matches.extend(self.parameters.detect(ctx));
+
         matches
     }
 }
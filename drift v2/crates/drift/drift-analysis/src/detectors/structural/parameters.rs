@@ -0,0 +1,101 @@
+//! Long parameter lists (CWE-1064).
+//!
+//! A function taking eight positional arguments is hard to call correctly
+//! and usually wants a parameter object. Functions and methods with more
+//! parameters than the threshold are reported with their count. Receivers
+//! (`self`, `cls`, `this`) are not counted. Constructors, where dependency
+//! injection legitimately lists every collaborator, can be excluded.
+
+use smallvec::SmallVec;
+
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::types::FunctionInfo;
+
+/// Parameter count above which a function is reported by default.
+pub const DEFAULT_MAX_PARAMETERS: usize = 6;
+
+/// Constructor names across languages; a method named after its class also counts.
+const CONSTRUCTOR_NAMES: &[&str] = &["constructor", "__init__", "__construct", "initialize", "init"];
+
+/// Receiver parameters that are not part of the call signature.
+const RECEIVERS: &[&str] = &["self", "&self", "&mut self", "mut self", "cls", "this"];
+
+/// Flags functions whose parameter count exceeds a threshold.
+#[derive(Debug, Clone)]
+pub struct LongParameterListCheck {
+    max_parameters: usize,
+    exclude_constructors: bool,
+}
+
+impl LongParameterListCheck {
+    /// Create a check reporting functions with more than `max_parameters` parameters.
+    pub fn new(max_parameters: usize) -> Self {
+        Self {
+            max_parameters,
+            exclude_constructors: false,
+        }
+    }
+
+    /// Skip constructors (e.g. DI-injected Angular, Nest or Spring services).
+    pub fn with_constructors_excluded(mut self, exclude: bool) -> Self {
+        self.exclude_constructors = exclude;
+        self
+    }
+
+    pub fn detect(&self, ctx: &DetectionContext) -> Vec<PatternMatch> {
+        let mut matches = Vec::new();
+
+        let methods = ctx
+            .classes
+            .iter()
+            .flat_map(|c| c.methods.iter().map(move |m| (m, Some(c.name.as_str()))));
+        let mut seen: Vec<(u32, u32)> = Vec::new();
+        for (func, class) in ctx.functions.iter().map(|f| (f, None)).chain(methods) {
+            if seen.contains(&(func.line, func.column)) {
+                continue;
+            }
+            seen.push((func.line, func.column));
+
+            let count = parameter_count(func);
+            if count <= self.max_parameters || (self.exclude_constructors && is_constructor(func, class)) {
+                continue;
+            }
+            let name = if func.name == "<anonymous>" { "Anonymous function".to_string() } else { format!("`{}`", func.name) };
+            matches.push(PatternMatch {
+                file: ctx.file.to_string(),
+                line: func.line,
+                column: func.column,
+                pattern_id: "STRUCT-LONG-PARAMS".to_string(),
+                confidence: 0.80,
+                cwe_ids: SmallVec::from_buf([1064, 0]),
+                owasp: None,
+                detection_method: DetectionMethod::AstVisitor,
+                category: PatternCategory::Structural,
+                matched_text: format!(
+                    "{name} takes {count} parameters (max {}) — consider a parameter object",
+                    self.max_parameters
+                ),
+            });
+        }
+
+        matches
+    }
+}
+
+impl Default for LongParameterListCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_PARAMETERS)
+    }
+}
+
+fn parameter_count(func: &FunctionInfo) -> usize {
+    func.parameters
+        .iter()
+        .filter(|p| !RECEIVERS.contains(&p.name.trim()))
+        .count()
+}
+
+fn is_constructor(func: &FunctionInfo, class: Option<&str>) -> bool {
+    CONSTRUCTOR_NAMES.contains(&func.name.as_str()) || class.is_some_and(|c| c == func.name)
+}
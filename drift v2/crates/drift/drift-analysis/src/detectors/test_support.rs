@@ -0,0 +1,38 @@
+//! Test support for detector authors.
+//!
+//! Parses a source snippet, builds the `DetectionContext` and runs a single
+//! detector, so detector tests stay one-liners:
+//!
+//! ```ignore
+//! let matches = run_detector(&SecurityDetector::default(), "eval(input);", Language::JavaScript);
+//! ```
+//!
+//! Compiled for unit tests and behind the `test-util` feature for integration
+//! tests and downstream crates.
+
+use std::path::PathBuf;
+
+use crate::detectors::traits::Detector;
+use crate::engine::types::PatternMatch;
+use crate::engine::visitor::DetectionContext;
+use crate::parsers::manager::ParserManager;
+use crate::parsers::types::ParseResult;
+use crate::scanner::language_detect::Language;
+
+/// Parse a snippet as the given language.
+///
+/// The snippet is parsed as `snippet.<ext>` using the language's primary
+/// extension. Panics if the snippet cannot be parsed.
+pub fn parse_snippet(source: &str, language: Language) -> ParseResult {
+    let path = PathBuf::from(format!("snippet.{}", language.extensions()[0]));
+    ParserManager::new()
+        .parse_with_language(source.as_bytes(), &path, language)
+        .unwrap_or_else(|e| panic!("failed to parse {} snippet: {e}", language.name()))
+}
+
+/// Parse a snippet and run one detector over it.
+pub fn run_detector(detector: &dyn Detector, source: &str, language: Language) -> Vec<PatternMatch> {
+    let parse_result = parse_snippet(source, language);
+    let ctx = DetectionContext::from_parse_result(&parse_result, source.as_bytes());
+    detector.detect(&ctx)
+}
@@ -44,7 +44,7 @@ impl DegradationDetector {
         if let (Some(curr_files), Some(prev_files)) = (current.total_files, previous.total_files) {
             if curr_files > 0 && prev_files > 0 {
                 let ratio = curr_files as f64 / prev_files as f64;
-                if ratio < 0.5 || ratio > 2.0 {
+                if !(0.5..=2.0).contains(&ratio) {
                     return Vec::new();
                 }
             }
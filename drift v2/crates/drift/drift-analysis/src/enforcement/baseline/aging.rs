@@ -0,0 +1,47 @@
+//! Baseline aging — how long accepted violations have persisted.
+
+use std::collections::HashSet;
+
+use crate::enforcement::rules::Violation;
+
+use super::types::{violation_key, AgedFinding, Baseline};
+
+const SECS_PER_DAY: u64 = 86_400;
+
+/// Report every baselined violation still present in `current`, oldest
+/// first, flagging those older than `baseline.remediation_after_days`.
+/// Baselined entries no longer reported have been fixed and are omitted.
+pub fn age_report(baseline: &Baseline, current: &[Violation], now: u64) -> Vec<AgedFinding> {
+    let present: HashSet<String> = current
+        .iter()
+        .filter(|v| !v.suppressed)
+        .flat_map(|v| v.fingerprint.clone().into_iter().chain([violation_key(v)]))
+        .collect();
+
+    let mut report: Vec<AgedFinding> = baseline
+        .entries
+        .iter()
+        .filter(|e| {
+            e.fingerprint.as_ref().is_some_and(|f| present.contains(f)) || present.contains(&e.key())
+        })
+        .map(|e| {
+            let age_days = now.saturating_sub(e.first_seen) / SECS_PER_DAY;
+            AgedFinding {
+                file: e.file.clone(),
+                line: e.line,
+                rule_id: e.rule_id.clone(),
+                first_seen: e.first_seen,
+                age_days,
+                requires_remediation: age_days > u64::from(baseline.remediation_after_days),
+            }
+        })
+        .collect();
+
+    report.sort_by(|a, b| {
+        a.first_seen
+            .cmp(&b.first_seen)
+            .then_with(|| a.file.cmp(&b.file))
+            .then_with(|| a.line.cmp(&b.line))
+    });
+    report
+}
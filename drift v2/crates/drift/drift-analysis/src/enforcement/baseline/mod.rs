@@ -0,0 +1,7 @@
+//! Baseline — accepted findings with first-seen timestamps, and aging reports.
+
+pub mod types;
+pub mod aging;
+
+pub use types::*;
+pub use aging::age_report;
@@ -0,0 +1,132 @@
+//! Baseline types — the persisted set of accepted violations.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::enforcement::rules::Violation;
+
+/// Default age after which a baselined finding must be remediated.
+pub const DEFAULT_REMEDIATION_DAYS: u32 = 90;
+
+/// A baseline file: violations accepted so CI can pass, with the time each
+/// was first baselined.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub entries: Vec<BaselineEntry>,
+    /// Age (days) after which a baselined finding is flagged for remediation.
+    #[serde(default = "default_remediation_days")]
+    pub remediation_after_days: u32,
+}
+
+/// One accepted violation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub file: String,
+    pub line: u32,
+    pub rule_id: String,
+    /// When the violation was first baselined, seconds since the Unix epoch.
+    pub first_seen: u64,
+    /// Content fingerprint, so the entry still matches after the line moves.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+}
+
+/// A baselined violation still present, with how long it has persisted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgedFinding {
+    pub file: String,
+    pub line: u32,
+    pub rule_id: String,
+    pub first_seen: u64,
+    pub age_days: u64,
+    /// Older than `Baseline::remediation_after_days`.
+    pub requires_remediation: bool,
+}
+
+fn default_remediation_days() -> u32 {
+    DEFAULT_REMEDIATION_DAYS
+}
+
+impl Default for Baseline {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            remediation_after_days: DEFAULT_REMEDIATION_DAYS,
+        }
+    }
+}
+
+impl BaselineEntry {
+    /// Key in the `"file:line:rule_id"` format used by `GateInput::baseline_violations`.
+    pub fn key(&self) -> String {
+        format!("{}:{}:{}", self.file, self.line, self.rule_id)
+    }
+}
+
+impl Baseline {
+    /// Baseline every unsuppressed violation, first seen at `now`.
+    pub fn from_violations(violations: &[Violation], now: u64) -> Self {
+        let mut baseline = Self::default();
+        baseline.update(violations, now);
+        baseline
+    }
+
+    /// Re-baseline against `violations`: existing entries keep their
+    /// `first_seen` (matched by fingerprint, so moved lines keep their age),
+    /// new ones start at `now`, resolved ones are dropped.
+    pub fn update(&mut self, violations: &[Violation], now: u64) {
+        let previous: HashMap<String, u64> = self
+            .entries
+            .iter()
+            .flat_map(|e| {
+                let by_fingerprint = e.fingerprint.clone().map(|f| (f, e.first_seen));
+                by_fingerprint.into_iter().chain([(e.key(), e.first_seen)])
+            })
+            .collect();
+        let mut seen = HashSet::new();
+        self.entries = violations
+            .iter()
+            .filter(|v| !v.suppressed)
+            .filter_map(|v| {
+                let key = violation_key(v);
+                let first_seen = v
+                    .fingerprint
+                    .as_ref()
+                    .and_then(|f| previous.get(f))
+                    .or_else(|| previous.get(&key))
+                    .copied()
+                    .unwrap_or(now);
+                seen.insert(key).then(|| BaselineEntry {
+                    file: v.file.clone(),
+                    line: v.line,
+                    rule_id: v.rule_id.clone(),
+                    first_seen,
+                    fingerprint: v.fingerprint.clone(),
+                })
+            })
+            .collect();
+    }
+
+    /// Keys for `GateInput::baseline_violations`: each entry's location key,
+    /// plus its fingerprint when known.
+    pub fn keys(&self) -> HashSet<String> {
+        self.entries
+            .iter()
+            .flat_map(|e| e.fingerprint.clone().into_iter().chain([e.key()]))
+            .collect()
+    }
+
+    /// Parse a baseline file.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+}
+
+pub(crate) fn violation_key(v: &Violation) -> String {
+    format!("{}:{}:{}", v.file, v.line, v.rule_id)
+}
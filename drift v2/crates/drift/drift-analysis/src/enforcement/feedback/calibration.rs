@@ -0,0 +1,145 @@
+//! Confidence calibration per detector — a reliability diagram from feedback.
+//!
+//! A detector is calibrated when its findings reported at 0.8 confidence
+//! turn out to be real about 80% of the time. Outcomes recorded with
+//! [`FeedbackTracker::record_with_confidence`] are grouped into ten
+//! confidence buckets; each bucket compares the mean assigned confidence
+//! with the observed true-positive rate.
+
+use serde::{Deserialize, Serialize};
+
+use super::tracker::FeedbackTracker;
+
+/// Number of equal-width confidence buckets over `[0, 1]`.
+pub const CALIBRATION_BUCKETS: usize = 10;
+
+/// Expected calibration error tolerated as calibrated. Above it, the sign
+/// of the confidence gap says which way the detector is off.
+pub const CALIBRATION_TOLERANCE: f64 = 0.10;
+
+/// Whether a detector's confidence should be recalibrated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CalibrationVerdict {
+    Calibrated,
+    /// Findings are wrong more often than their confidence says.
+    Overconfident,
+    /// Findings are right more often than their confidence says.
+    Underconfident,
+    /// Fewer outcomes than the tracker's `min_findings`.
+    InsufficientData,
+}
+
+/// One point of the reliability diagram.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalibrationBucket {
+    /// Inclusive lower bound of the bucket.
+    pub lower: f64,
+    /// Exclusive upper bound (inclusive for the last bucket).
+    pub upper: f64,
+    pub count: u64,
+    pub mean_confidence: f64,
+    pub true_positive_rate: f64,
+}
+
+/// Calibration of a single detector.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetectorCalibration {
+    pub detector_id: String,
+    pub samples: u64,
+    /// Non-empty buckets, lowest confidence first.
+    pub buckets: Vec<CalibrationBucket>,
+    /// Sample-weighted mean of `|mean_confidence - true_positive_rate|`.
+    pub expected_calibration_error: f64,
+    /// Sample-weighted mean of `mean_confidence - true_positive_rate`;
+    /// positive when overconfident.
+    pub confidence_gap: f64,
+    pub verdict: CalibrationVerdict,
+}
+
+/// Calibration of every detector with recorded outcomes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CalibrationReport {
+    /// Sorted by detector id.
+    pub detectors: Vec<DetectorCalibration>,
+}
+
+impl CalibrationReport {
+    /// Calibration of one detector.
+    pub fn detector(&self, detector_id: &str) -> Option<&DetectorCalibration> {
+        self.detectors.iter().find(|d| d.detector_id == detector_id)
+    }
+
+    /// Detectors that should be recalibrated (over- or underconfident).
+    pub fn miscalibrated(&self) -> Vec<&DetectorCalibration> {
+        self.detectors
+            .iter()
+            .filter(|d| matches!(d.verdict, CalibrationVerdict::Overconfident | CalibrationVerdict::Underconfident))
+            .collect()
+    }
+}
+
+/// Build the calibration report from the outcomes in `tracker`.
+pub fn calibration_report(tracker: &FeedbackTracker) -> CalibrationReport {
+    let mut detectors: Vec<DetectorCalibration> = tracker
+        .calibration_samples()
+        .iter()
+        .filter(|(_, samples)| !samples.is_empty())
+        .map(|(detector_id, samples)| calibrate(detector_id, samples, tracker.min_findings))
+        .collect();
+    detectors.sort_by(|a, b| a.detector_id.cmp(&b.detector_id));
+    CalibrationReport { detectors }
+}
+
+fn calibrate(detector_id: &str, samples: &[(f64, bool)], min_findings: u64) -> DetectorCalibration {
+    // (count, confidence sum, true positives) per bucket
+    let mut sums = [(0u64, 0.0f64, 0u64); CALIBRATION_BUCKETS];
+    for &(confidence, true_positive) in samples {
+        let index = ((confidence * CALIBRATION_BUCKETS as f64) as usize).min(CALIBRATION_BUCKETS - 1);
+        let bucket = &mut sums[index];
+        bucket.0 += 1;
+        bucket.1 += confidence;
+        bucket.2 += u64::from(true_positive);
+    }
+
+    let total = samples.len() as f64;
+    let mut ece = 0.0;
+    let mut gap = 0.0;
+    let mut buckets = Vec::new();
+    for (index, &(count, confidence_sum, true_positives)) in sums.iter().enumerate() {
+        if count == 0 {
+            continue;
+        }
+        let mean_confidence = confidence_sum / count as f64;
+        let true_positive_rate = true_positives as f64 / count as f64;
+        let weight = count as f64 / total;
+        ece += weight * (mean_confidence - true_positive_rate).abs();
+        gap += weight * (mean_confidence - true_positive_rate);
+        buckets.push(CalibrationBucket {
+            lower: index as f64 / CALIBRATION_BUCKETS as f64,
+            upper: (index + 1) as f64 / CALIBRATION_BUCKETS as f64,
+            count,
+            mean_confidence,
+            true_positive_rate,
+        });
+    }
+
+    let verdict = if (samples.len() as u64) < min_findings {
+        CalibrationVerdict::InsufficientData
+    } else if ece <= CALIBRATION_TOLERANCE {
+        CalibrationVerdict::Calibrated
+    } else if gap >= 0.0 {
+        CalibrationVerdict::Overconfident
+    } else {
+        CalibrationVerdict::Underconfident
+    };
+
+    DetectorCalibration {
+        detector_id: detector_id.to_string(),
+        samples: samples.len() as u64,
+        buckets,
+        expected_calibration_error: ece,
+        confidence_gap: gap,
+        verdict,
+    }
+}
@@ -4,8 +4,10 @@ pub mod types;
 pub mod tracker;
 pub mod confidence_feedback;
 pub mod stats_provider;
+pub mod calibration;
 
 pub use types::*;
-pub use tracker::FeedbackTracker;
+pub use tracker::{FeedbackSink, FeedbackTracker};
 pub use confidence_feedback::ConfidenceFeedback;
 pub use stats_provider::FeedbackStatsProvider;
+pub use calibration::{calibration_report, CalibrationReport};
@@ -4,6 +4,14 @@ use std::collections::HashMap;
 
 use super::types::*;
 
+/// Persistence for feedback records, so FP history survives restarts.
+///
+/// Implemented by drift-napi over the `feedback` table in drift.db. Errors
+/// are the sink's to log: feedback tracking never fails on storage.
+pub trait FeedbackSink: Send + Sync {
+    fn persist(&self, record: &FeedbackRecord);
+}
+
 /// Feedback tracker: tracks FP rates per detector and auto-disables noisy ones.
 pub struct FeedbackTracker {
     /// FP rate threshold for alert (10%).
@@ -18,6 +26,10 @@ pub struct FeedbackTracker {
     metrics: HashMap<String, FeedbackMetrics>,
     /// Abuse detection: per-author dismiss counts.
     dismiss_counts: HashMap<String, Vec<u64>>,
+    /// Per-detector (assigned confidence, was a true positive) outcomes.
+    calibration: HashMap<String, Vec<(f64, bool)>>,
+    /// Write-through storage for recorded feedback.
+    sink: Option<Box<dyn FeedbackSink>>,
 }
 
 impl FeedbackTracker {
@@ -29,11 +41,23 @@ impl FeedbackTracker {
             min_findings: 10,
             metrics: HashMap::new(),
             dismiss_counts: HashMap::new(),
+            calibration: HashMap::new(),
+            sink: None,
         }
     }
 
-    /// Record a feedback action.
+    /// Persist every recorded action through `sink` as well as tracking it.
+    pub fn with_sink(mut self, sink: Box<dyn FeedbackSink>) -> Self {
+        self.sink = Some(sink);
+        self
+    }
+
+    /// Record a feedback action, writing it through to the sink if any.
     pub fn record(&mut self, record: &FeedbackRecord) {
+        if let Some(sink) = &self.sink {
+            sink.persist(record);
+        }
+
         let metrics = self
             .metrics
             .entry(record.detector_id.clone())
@@ -72,6 +96,30 @@ impl FeedbackTracker {
         }
     }
 
+    /// Record a feedback action on a finding the detector reported with
+    /// `confidence`, keeping the outcome for calibration.
+    ///
+    /// Fixes and escalations count as true positives, false-positive
+    /// dismissals as false positives. Other actions say nothing about
+    /// whether the finding was right and are not kept.
+    pub fn record_with_confidence(&mut self, record: &FeedbackRecord, confidence: f64) {
+        self.record(record);
+        let true_positive = match record.action {
+            FeedbackAction::Fix | FeedbackAction::Escalate => true,
+            FeedbackAction::Dismiss if record.dismissal_reason.is_some_and(|r| r.counts_as_false_positive()) => false,
+            _ => return,
+        };
+        self.calibration
+            .entry(record.detector_id.clone())
+            .or_default()
+            .push((confidence.clamp(0.0, 1.0), true_positive));
+    }
+
+    /// Confidence outcomes per detector, from `record_with_confidence`.
+    pub fn calibration_samples(&self) -> &HashMap<String, Vec<(f64, bool)>> {
+        &self.calibration
+    }
+
     /// Get metrics for a specific detector.
     pub fn get_metrics(&self, detector_id: &str) -> Option<&FeedbackMetrics> {
         self.metrics.get(detector_id)
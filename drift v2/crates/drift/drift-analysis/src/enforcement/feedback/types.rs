@@ -12,6 +12,18 @@ pub enum FeedbackAction {
     Escalate,
 }
 
+impl FeedbackAction {
+    /// Name stored in the `feedback` table's `action` column.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Fix => "fix",
+            Self::Dismiss => "dismiss",
+            Self::Suppress => "suppress",
+            Self::Escalate => "escalate",
+        }
+    }
+}
+
 /// Dismissal reasons.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -23,6 +35,27 @@ pub enum DismissalReason {
 }
 
 impl DismissalReason {
+    /// Name stored in the `feedback` table's `dismissal_reason` column.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::FalsePositive => "false_positive",
+            Self::WontFix => "wont_fix",
+            Self::NotApplicable => "not_applicable",
+            Self::Duplicate => "duplicate",
+        }
+    }
+
+    /// Inverse of [`as_str`](Self::as_str); `None` for free-text reasons.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "false_positive" => Some(Self::FalsePositive),
+            "wont_fix" => Some(Self::WontFix),
+            "not_applicable" => Some(Self::NotApplicable),
+            "duplicate" => Some(Self::Duplicate),
+            _ => None,
+        }
+    }
+
     pub fn counts_as_false_positive(&self) -> bool {
         matches!(self, Self::FalsePositive | Self::NotApplicable)
     }
@@ -0,0 +1,82 @@
+//! Finding fingerprints — the one identity shared by baselines, SARIF
+//! `partialFingerprints` and cross-run deduplication.
+//!
+//! A fingerprint hashes the file, the rule and the *content* of the flagged
+//! line rather than its number, so a finding keeps its fingerprint when
+//! unrelated edits shift it up or down the file. The line is normalized first:
+//!
+//! 1. a trailing `//` or `#` comment after code is dropped (markers inside
+//!    string literals are kept; a line that is only a comment is kept whole),
+//! 2. leading and trailing whitespace is stripped,
+//! 3. internal whitespace runs collapse to a single space.
+//!
+//! Reformatting indentation or editing a trailing comment therefore does not
+//! change the fingerprint; editing the code on the line does. Identical lines
+//! in one file share a fingerprint unless the caller passes distinguishing
+//! `context` (such as the enclosing symbol).
+
+use xxhash_rust::xxh3::xxh3_64;
+
+use super::rules::Violation;
+
+/// Stable fingerprint of a finding: 16 lowercase hex digits.
+///
+/// `line` is the source text of the flagged line; it is normalized with
+/// [`normalize_line`] here, so callers may pass it raw or pre-normalized.
+/// `context` is extra identity for the finding and may be empty.
+pub fn finding_fingerprint(file: &str, rule_id: &str, line: &str, context: &str) -> String {
+    let file = file.replace('\\', "/");
+    let normalized = normalize_line(line);
+    let mut buf = Vec::with_capacity(file.len() + rule_id.len() + normalized.len() + context.len() + 3);
+    for part in [file.as_str(), rule_id, normalized.as_str(), context] {
+        buf.extend_from_slice(part.as_bytes());
+        buf.push(0);
+    }
+    format!("{:016x}", xxh3_64(&buf))
+}
+
+/// Fingerprint of a violation: the one computed from its source line when
+/// the rules engine had the source, else a location-based fallback.
+pub fn violation_fingerprint(violation: &Violation) -> String {
+    match &violation.fingerprint {
+        Some(fingerprint) => fingerprint.clone(),
+        None => finding_fingerprint(&violation.file, &violation.rule_id, "", &format!("line:{}", violation.line)),
+    }
+}
+
+/// Normalize a source line for fingerprinting (see the module docs).
+pub fn normalize_line(line: &str) -> String {
+    let code = strip_trailing_comment(line);
+    code.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// `line` without a `//` or `#` comment that follows code.
+fn strip_trailing_comment(line: &str) -> &str {
+    let bytes = line.as_bytes();
+    let mut quote: Option<u8> = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        match quote {
+            Some(q) => {
+                if b == b'\\' {
+                    i += 1;
+                } else if b == q {
+                    quote = None;
+                }
+            }
+            None => {
+                let is_marker = b == b'#' || (b == b'/' && bytes.get(i + 1) == Some(&b'/'));
+                let after_code = i > 0 && bytes[i - 1].is_ascii_whitespace() && !line[..i].trim().is_empty();
+                if is_marker && after_code {
+                    return &line[..i];
+                }
+                if matches!(b, b'"' | b'\'' | b'`') {
+                    quote = Some(b);
+                }
+            }
+        }
+        i += 1;
+    }
+    line
+}
@@ -40,6 +40,8 @@ impl QualityGate for ConstraintVerificationGate {
                 passing += 1;
             } else {
                 for cv in &constraint.violations {
+                    let rule_id = format!("constraint/{}", constraint.id);
+                    let fingerprint = input.fingerprint(&cv.file, cv.line.unwrap_or(0), &rule_id, &cv.message);
                     violations.push(Violation {
                         id: format!("constraint-{}-{}", constraint.id, cv.file),
                         file: cv.file.clone(),
@@ -49,7 +51,7 @@ impl QualityGate for ConstraintVerificationGate {
                         end_column: None,
                         severity: Severity::Error,
                         pattern_id: constraint.id.clone(),
-                        rule_id: format!("constraint/{}", constraint.id),
+                        rule_id,
                         message: format!(
                             "Constraint '{}' violated: {}",
                             constraint.description, cv.message
@@ -59,6 +61,7 @@ impl QualityGate for ConstraintVerificationGate {
                         owasp_category: None,
                         suppressed: false,
                         is_new: false,
+                        fingerprint: Some(fingerprint),
                     });
                 }
             }
@@ -70,6 +73,14 @@ impl QualityGate for ConstraintVerificationGate {
             100.0
         };
 
+        let explanation = GateExplanation::new(
+            "failing constraints",
+            (total_constraints - passing) as f64,
+            0.0,
+        )
+        .with_metric("constraints", total_constraints as f64)
+        .with_metric("passing constraints", passing as f64);
+
         if violations.is_empty() {
             GateResult::pass(
                 GateId::ConstraintVerification,
@@ -79,7 +90,9 @@ impl QualityGate for ConstraintVerificationGate {
                     total_constraints
                 ),
             )
+            .with_explanation(explanation)
         } else {
+            let explanation = explanation.with_contributing(&violations);
             GateResult::fail(
                 GateId::ConstraintVerification,
                 score,
@@ -91,6 +104,7 @@ impl QualityGate for ConstraintVerificationGate {
                 ),
                 violations,
             )
+            .with_explanation(explanation)
         }
     }
 }
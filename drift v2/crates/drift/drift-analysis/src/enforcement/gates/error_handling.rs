@@ -36,6 +36,8 @@ impl QualityGate for ErrorHandlingGate {
                 _ => Severity::Info,
             };
 
+            let rule_id = format!("error-handling/{}", gap.gap_type);
+            let fingerprint = input.fingerprint(&gap.file, gap.line, &rule_id, &gap.message);
             violations.push(Violation {
                 id: format!("error-handling-{}-{}", gap.file, gap.line),
                 file: gap.file.clone(),
@@ -45,13 +47,14 @@ impl QualityGate for ErrorHandlingGate {
                 end_column: None,
                 severity,
                 pattern_id: "error-handling".to_string(),
-                rule_id: format!("error-handling/{}", gap.gap_type),
+                rule_id,
                 message: gap.message.clone(),
                 quick_fix: None,
                 cwe_id: None,
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                fingerprint: Some(fingerprint),
             });
         }
 
@@ -66,14 +69,27 @@ impl QualityGate for ErrorHandlingGate {
             100.0
         };
 
+        let mut explanation =
+            GateExplanation::new("critical error handling gaps", error_count as f64, 0.0);
+        for gap_type in ["empty_catch", "swallowed", "generic_catch", "unhandled"] {
+            let count = input.error_gaps.iter().filter(|g| g.gap_type == gap_type).count();
+            if count > 0 {
+                explanation = explanation.with_metric(gap_type_label(gap_type), count as f64);
+            }
+        }
+
         if error_count > 0 {
+            let explanation = explanation
+                .with_contributing(violations.iter().filter(|v| v.severity == Severity::Error));
             GateResult::fail(
                 GateId::ErrorHandling,
                 score,
                 format!("{error_count} critical error handling gaps"),
                 violations,
             )
+            .with_explanation(explanation)
         } else if !violations.is_empty() {
+            let explanation = explanation.with_contributing(&violations);
             let warnings: Vec<String> = violations
                 .iter()
                 .take(5)
@@ -85,12 +101,25 @@ impl QualityGate for ErrorHandlingGate {
                 format!("{} error handling warnings", violations.len()),
                 warnings,
             )
+            .with_explanation(explanation)
         } else {
             GateResult::pass(
                 GateId::ErrorHandling,
                 100.0,
                 "No error handling gaps detected".to_string(),
             )
+            .with_explanation(explanation)
         }
     }
 }
+
+/// Human-readable label for an error gap type.
+fn gap_type_label(gap_type: &str) -> &str {
+    match gap_type {
+        "empty_catch" => "empty catch blocks",
+        "swallowed" => "swallowed errors",
+        "generic_catch" => "generic catch blocks",
+        "unhandled" => "unhandled errors",
+        other => other,
+    }
+}
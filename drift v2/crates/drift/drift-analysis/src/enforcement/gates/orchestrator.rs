@@ -3,6 +3,8 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::time::Duration;
 
+use crate::enforcement::fingerprint::violation_fingerprint;
+
 use super::types::*;
 use super::progressive::{ProgressiveConfig, ProgressiveEnforcement};
 use super::constraint_verification::ConstraintVerificationGate;
@@ -67,6 +69,8 @@ impl GateOrchestrator {
     /// Detects circular dependencies and returns an error.
     pub fn execute(&self, input: &GateInput) -> Result<Vec<GateResult>, String> {
         let order = self.topological_sort()?;
+        let (input, advisories) = input.split_advisories();
+        let input = &input;
         let mut results: HashMap<GateId, GateResult> = HashMap::new();
         let mut output = Vec::new();
 
@@ -139,13 +143,26 @@ impl GateOrchestrator {
                             "{}:{}:{}",
                             violation.file, violation.line, violation.rule_id
                         );
-                        violation.is_new = !input.baseline_violations.contains(&key);
+                        violation.is_new = !input.baseline_violations.contains(&key)
+                            && !input.baseline_violations.contains(&violation_fingerprint(violation));
                     }
                 }
 
                 result
             };
 
+            let result = result.with_advisories(
+                advisories
+                    .iter()
+                    .filter(|a| a.gate_id == *gate_id)
+                    .cloned()
+                    .collect(),
+            );
+            let result = match input.sample_fraction {
+                Some(fraction) if fraction < 1.0 => result.with_sample(fraction),
+                _ => result,
+            };
+
             results.insert(*gate_id, result.clone());
             output.push(result);
         }
@@ -173,11 +190,13 @@ impl GateOrchestrator {
             }
         }
 
-        // Kahn's algorithm
-        let mut queue: VecDeque<GateId> = in_degree
+        // Kahn's algorithm, seeded in registration order so independent
+        // gates always run (and report) in the same order.
+        let mut queue: VecDeque<GateId> = self
+            .gates
             .iter()
-            .filter(|(_, &deg)| deg == 0)
-            .map(|(&id, _)| id)
+            .map(|g| g.id())
+            .filter(|id| in_degree.get(id) == Some(&0))
             .collect();
 
         let mut sorted = Vec::new();
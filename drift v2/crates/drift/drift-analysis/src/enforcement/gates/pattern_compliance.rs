@@ -37,6 +37,8 @@ impl QualityGate for PatternComplianceGate {
                     Severity::Info
                 };
 
+                let rule_id = format!("pattern-compliance/{}", pattern.pattern_id);
+                let fingerprint = input.fingerprint(&outlier.file, outlier.line, &rule_id, &outlier.message);
                 violations.push(Violation {
                     id: format!(
                         "pattern-compliance-{}-{}",
@@ -49,7 +51,7 @@ impl QualityGate for PatternComplianceGate {
                     end_column: None,
                     severity,
                     pattern_id: pattern.pattern_id.clone(),
-                    rule_id: format!("pattern-compliance/{}", pattern.pattern_id),
+                    rule_id,
                     message: format!(
                         "Deviates from approved pattern '{}' (confidence: {:.0}%)",
                         pattern.pattern_id,
@@ -60,6 +62,7 @@ impl QualityGate for PatternComplianceGate {
                     owasp_category: None,
                     suppressed: false,
                     is_new: false,
+                    fingerprint: Some(fingerprint),
                 });
             }
         }
@@ -71,9 +74,24 @@ impl QualityGate for PatternComplianceGate {
         };
 
         let score = compliance_rate * 100.0;
-        let has_errors = violations.iter().any(|v| v.severity == Severity::Error);
+        let error_count = violations
+            .iter()
+            .filter(|v| v.severity == Severity::Error)
+            .count();
+        let has_errors = error_count > 0;
+
+        let explanation = GateExplanation::new(
+            "deviations from high-confidence patterns",
+            error_count as f64,
+            0.0,
+        )
+        .with_metric("% compliance", score)
+        .with_metric("conforming locations", total_locations as f64)
+        .with_metric("outliers", total_outliers as f64);
 
         if has_errors {
+            let explanation = explanation
+                .with_contributing(violations.iter().filter(|v| v.severity == Severity::Error));
             GateResult::fail(
                 GateId::PatternCompliance,
                 score,
@@ -84,7 +102,9 @@ impl QualityGate for PatternComplianceGate {
                 ),
                 violations,
             )
+            .with_explanation(explanation)
         } else if !violations.is_empty() {
+            let explanation = explanation.with_contributing(&violations);
             let warnings: Vec<String> = violations
                 .iter()
                 .take(5)
@@ -100,12 +120,14 @@ impl QualityGate for PatternComplianceGate {
                 ),
                 warnings,
             )
+            .with_explanation(explanation)
         } else {
             GateResult::pass(
                 GateId::PatternCompliance,
                 score,
                 format!("Pattern compliance: {:.1}%", score),
             )
+            .with_explanation(explanation)
         }
     }
 }
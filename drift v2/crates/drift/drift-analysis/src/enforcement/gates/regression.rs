@@ -33,16 +33,25 @@ impl QualityGate for RegressionGate {
         let delta = current - previous;
 
         // Check for new Error-severity violations from predecessor gates
-        let new_error_count: usize = input
+        let new_errors: Vec<_> = input
             .predecessor_results
             .values()
             .flat_map(|r| r.violations.iter())
             .filter(|v| {
                 v.is_new && v.severity == crate::enforcement::rules::Severity::Error
             })
-            .count();
+            .collect();
+        let new_error_count = new_errors.len();
 
         if new_error_count > 0 {
+            let explanation = GateExplanation::new(
+                "new error-severity violations",
+                new_error_count as f64,
+                0.0,
+            )
+            .with_metric("previous health score", previous)
+            .with_metric("current health score", current)
+            .with_contributing(new_errors);
             let details = serde_json::json!({
                 "previous_score": previous,
                 "current_score": current,
@@ -60,10 +69,13 @@ impl QualityGate for RegressionGate {
                 Vec::new(),
             );
             result.details = details;
-            return result;
+            return result.with_explanation(explanation);
         }
 
         let score = current;
+        let explanation = GateExplanation::new("health score delta", delta, -5.0)
+            .with_metric("previous health score", previous)
+            .with_metric("current health score", current);
 
         if delta <= -15.0 {
             // Critical regression
@@ -82,7 +94,7 @@ impl QualityGate for RegressionGate {
                 Vec::new(),
             );
             result.details = details;
-            result
+            result.with_explanation(GateExplanation { threshold: -15.0, ..explanation })
         } else if delta <= -5.0 {
             // Warning regression
             let details = serde_json::json!({
@@ -100,7 +112,7 @@ impl QualityGate for RegressionGate {
                 vec![format!("Health score dropped by {:.1} points", delta.abs())],
             );
             result.details = details;
-            result
+            result.with_explanation(explanation)
         } else {
             let details = serde_json::json!({
                 "previous_score": previous,
@@ -115,7 +127,7 @@ impl QualityGate for RegressionGate {
                 ),
             );
             result.details = details;
-            result
+            result.with_explanation(explanation)
         }
     }
 }
@@ -40,6 +40,15 @@ impl QualityGate for SecurityBoundariesGate {
                 _ => Severity::Info,
             };
 
+            let rule_id = format!(
+                "security/{}",
+                finding
+                    .cwe_ids
+                    .first()
+                    .map(|c| format!("CWE-{c}"))
+                    .unwrap_or_else(|| "generic".to_string())
+            );
+            let fingerprint = input.fingerprint(&finding.file, finding.line, &rule_id, &finding.description);
             violations.push(Violation {
                 id: format!("security-{}-{}", finding.file, finding.line),
                 file: finding.file.clone(),
@@ -49,20 +58,14 @@ impl QualityGate for SecurityBoundariesGate {
                 end_column: None,
                 severity,
                 pattern_id: "security-boundary".to_string(),
-                rule_id: format!(
-                    "security/{}",
-                    finding
-                        .cwe_ids
-                        .first()
-                        .map(|c| format!("CWE-{c}"))
-                        .unwrap_or_else(|| "generic".to_string())
-                ),
+                rule_id,
                 message: finding.description.clone(),
                 quick_fix: None,
                 cwe_id: finding.cwe_ids.first().copied(),
                 owasp_category: finding.owasp_categories.first().cloned(),
                 suppressed: false,
                 is_new: false,
+                fingerprint: Some(fingerprint),
             });
         }
 
@@ -77,7 +80,22 @@ impl QualityGate for SecurityBoundariesGate {
             (safe as f64 / input.security_findings.len() as f64) * 100.0
         };
 
+        let mut explanation =
+            GateExplanation::new("critical/high security findings", error_count as f64, 0.0);
+        for severity in ["critical", "high", "medium", "low"] {
+            let count = input
+                .security_findings
+                .iter()
+                .filter(|f| f.severity == severity)
+                .count();
+            if count > 0 {
+                explanation = explanation.with_metric(format!("{severity} findings"), count as f64);
+            }
+        }
+
         if error_count > 0 {
+            let explanation = explanation
+                .with_contributing(violations.iter().filter(|v| v.severity == Severity::Error));
             GateResult::fail(
                 GateId::SecurityBoundaries,
                 score,
@@ -87,7 +105,9 @@ impl QualityGate for SecurityBoundariesGate {
                 ),
                 violations,
             )
+            .with_explanation(explanation)
         } else if !violations.is_empty() {
+            let explanation = explanation.with_contributing(&violations);
             let warnings: Vec<String> = violations
                 .iter()
                 .take(5)
@@ -99,12 +119,14 @@ impl QualityGate for SecurityBoundariesGate {
                 format!("{} security findings (non-critical)", violations.len()),
                 warnings,
             )
+            .with_explanation(explanation)
         } else {
             GateResult::pass(
                 GateId::SecurityBoundaries,
                 100.0,
                 "No security boundary violations".to_string(),
             )
+            .with_explanation(explanation)
         }
     }
 }
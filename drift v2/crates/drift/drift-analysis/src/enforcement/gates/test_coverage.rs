@@ -51,16 +51,23 @@ impl QualityGate for TestCoverageGate {
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                // File-level: the file and rule are the whole identity.
+                fingerprint: Some(input.fingerprint(file, 0, "test-coverage/uncovered-file", "")),
             });
         }
 
+        let explanation = GateExplanation::new("% test coverage", score, threshold)
+            .with_metric("uncovered files", coverage_input.uncovered_files.len() as f64);
+
         if score >= threshold {
             GateResult::pass(
                 GateId::TestCoverage,
                 score,
                 format!("Test coverage: {score:.1}% (threshold: {threshold:.1}%)"),
             )
+            .with_explanation(explanation)
         } else {
+            let explanation = explanation.with_contributing(&violations);
             GateResult::fail(
                 GateId::TestCoverage,
                 score,
@@ -69,6 +76,7 @@ impl QualityGate for TestCoverageGate {
                 ),
                 violations,
             )
+            .with_explanation(explanation)
         }
     }
 }
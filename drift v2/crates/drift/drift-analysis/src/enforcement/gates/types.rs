@@ -3,6 +3,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fmt;
+use super::super::fingerprint::finding_fingerprint;
 
 /// The 6 quality gate identifiers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -70,6 +71,12 @@ pub struct GateResult {
     pub execution_time_ms: u64,
     pub details: serde_json::Value,
     pub error: Option<String>,
+    /// Why the gate reached its status. `None` for skipped/errored gates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<GateExplanation>,
+    /// Findings below the input's `min_confidence`: listed, but not scored.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub advisories: Vec<Advisory>,
 }
 
 impl GateResult {
@@ -86,6 +93,8 @@ impl GateResult {
             execution_time_ms: 0,
             details: serde_json::Value::Null,
             error: None,
+            explanation: None,
+            advisories: Vec::new(),
         }
     }
 
@@ -107,6 +116,8 @@ impl GateResult {
             execution_time_ms: 0,
             details: serde_json::Value::Null,
             error: None,
+            explanation: None,
+            advisories: Vec::new(),
         }
     }
 
@@ -123,6 +134,8 @@ impl GateResult {
             execution_time_ms: 0,
             details: serde_json::Value::Null,
             error: None,
+            explanation: None,
+            advisories: Vec::new(),
         }
     }
 
@@ -139,6 +152,8 @@ impl GateResult {
             execution_time_ms: 0,
             details: serde_json::Value::Null,
             error: None,
+            explanation: None,
+            advisories: Vec::new(),
         }
     }
 
@@ -155,8 +170,176 @@ impl GateResult {
             execution_time_ms: 0,
             details: serde_json::Value::Null,
             error: Some(error),
+            explanation: None,
+            advisories: Vec::new(),
         }
     }
+
+    /// Attach an explanation of how the gate reached its status.
+    pub fn with_explanation(mut self, explanation: GateExplanation) -> Self {
+        self.explanation = Some(explanation);
+        self
+    }
+
+    /// Attach below-threshold findings to list alongside the result.
+    pub fn with_advisories(mut self, advisories: Vec<Advisory>) -> Self {
+        self.advisories = advisories;
+        self
+    }
+
+    /// Mark the result as computed from a `fraction` sample of the files.
+    ///
+    /// The status is kept but flagged as non-authoritative, and
+    /// `details.sample` records the violation count extrapolated to the full
+    /// tree.
+    pub fn with_sample(mut self, fraction: f64) -> Self {
+        let observed = self.violations.len();
+        let estimated = if fraction > 0.0 {
+            (observed as f64 / fraction).round() as usize
+        } else {
+            observed
+        };
+        let sample = serde_json::json!({
+            "fraction": fraction,
+            "authoritative": false,
+            "observed_violations": observed,
+            "estimated_violations": estimated,
+        });
+        match &mut self.details {
+            serde_json::Value::Object(map) => {
+                map.insert("sample".to_string(), sample);
+            }
+            serde_json::Value::Null => self.details = serde_json::json!({ "sample": sample }),
+            _ => {}
+        }
+        self.warnings.push(format!(
+            "Sampled scan ({:.0}% of files): result is not authoritative",
+            fraction * 100.0
+        ));
+        self
+    }
+}
+
+/// A finding whose confidence fell below `GateInput::min_confidence`.
+///
+/// Advisories are excluded from gate scoring but kept in the output so noisy
+/// patterns can be tuned out without losing visibility.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Advisory {
+    /// The gate that would have scored the finding.
+    pub gate_id: GateId,
+    pub pattern_id: String,
+    pub category: String,
+    pub confidence: f64,
+    pub file: String,
+    pub line: u32,
+    pub column: Option<u32>,
+    pub message: String,
+}
+
+/// Maximum contributing findings kept on a `GateExplanation`.
+pub const MAX_CONTRIBUTING_FINDINGS: usize = 10;
+
+/// Breakdown of a gate outcome: the deciding metric, the threshold it was
+/// compared against, every metric the gate computed, and the findings that
+/// pushed it over.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GateExplanation {
+    /// Label of the deciding metric, e.g. "critical error handling gaps".
+    pub metric: String,
+    /// Value of the deciding metric.
+    pub value: f64,
+    /// Configured threshold the deciding metric was compared against.
+    pub threshold: f64,
+    /// All metric values computed by the gate, in display order.
+    pub metrics: Vec<GateMetric>,
+    /// Findings that pushed the gate over its threshold, most severe first.
+    pub contributing: Vec<ContributingFinding>,
+}
+
+/// A single named metric value computed by a gate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GateMetric {
+    pub name: String,
+    pub value: f64,
+}
+
+/// A violation that contributed to a gate outcome.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContributingFinding {
+    pub violation_id: String,
+    pub file: String,
+    pub line: u32,
+    pub severity: super::super::rules::Severity,
+    pub rule_id: String,
+    pub message: String,
+}
+
+impl GateExplanation {
+    pub fn new(metric: impl Into<String>, value: f64, threshold: f64) -> Self {
+        Self {
+            metric: metric.into(),
+            value,
+            threshold,
+            metrics: Vec::new(),
+            contributing: Vec::new(),
+        }
+    }
+
+    /// Record an additional metric value.
+    pub fn with_metric(mut self, name: impl Into<String>, value: f64) -> Self {
+        self.metrics.push(GateMetric { name: name.into(), value });
+        self
+    }
+
+    /// Record the violations that drove the outcome. Sorted by severity,
+    /// then location, and capped at `MAX_CONTRIBUTING_FINDINGS`.
+    pub fn with_contributing<'a>(
+        mut self,
+        violations: impl IntoIterator<Item = &'a super::super::rules::Violation>,
+    ) -> Self {
+        let mut findings: Vec<ContributingFinding> = violations
+            .into_iter()
+            .filter(|v| !v.suppressed)
+            .map(|v| ContributingFinding {
+                violation_id: v.id.clone(),
+                file: v.file.clone(),
+                line: v.line,
+                severity: v.severity,
+                rule_id: v.rule_id.clone(),
+                message: v.message.clone(),
+            })
+            .collect();
+        findings.sort_by(|a, b| {
+            a.severity
+                .cmp(&b.severity)
+                .then_with(|| a.file.cmp(&b.file))
+                .then_with(|| a.line.cmp(&b.line))
+        });
+        findings.truncate(MAX_CONTRIBUTING_FINDINGS);
+        self.contributing = findings;
+        self
+    }
+
+    /// One-line rendering, e.g. `3 critical error handling gaps (threshold: 0)`.
+    pub fn headline(&self) -> String {
+        let separator = if self.metric.starts_with('%') { "" } else { " " };
+        format!(
+            "{}{separator}{} (threshold: {})",
+            format_metric_value(self.value),
+            self.metric,
+            format_metric_value(self.threshold)
+        )
+    }
+}
+
+/// Render whole numbers without a fractional part.
+pub fn format_metric_value(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        format!("{value:.1}")
+    }
 }
 
 /// Input provided to each gate by the orchestrator.
@@ -172,10 +355,96 @@ pub struct GateInput {
     pub previous_health_score: Option<f64>,
     pub current_health_score: Option<f64>,
     pub predecessor_results: HashMap<GateId, GateResult>,
-    /// Baseline violation keys (format: "file:line:rule_id") for is_new detection.
+    /// Baseline violation keys (format: "file:line:rule_id", or a finding
+    /// fingerprint) for is_new detection.
     pub baseline_violations: HashSet<String>,
     /// Optional feedback stats provider for FP-rate-aware gate evaluation.
     pub feedback_stats: Option<std::sync::Arc<dyn super::super::feedback::stats_provider::FeedbackStatsProvider>>,
+    /// Patterns and security findings below this confidence are not scored;
+    /// they are reported as advisories of their gate instead. Constraints,
+    /// error gaps and coverage carry no confidence and are always scored.
+    /// `None` scores everything.
+    pub min_confidence: Option<f64>,
+    /// Set when the scan covered only this fraction of files; every result
+    /// is then marked non-authoritative with extrapolated counts.
+    pub sample_fraction: Option<f64>,
+    /// Source lines per file, for content-based violation fingerprints.
+    pub source_lines: HashMap<String, Vec<String>>,
+}
+
+impl GateInput {
+    /// Fingerprint of a gate violation at `file:line`. Hashes the flagged
+    /// line's text when its source is available, else `context` (the
+    /// finding's own text), so it never depends on the line number.
+    pub fn fingerprint(&self, file: &str, line: u32, rule_id: &str, context: &str) -> String {
+        let text = line
+            .checked_sub(1)
+            .and_then(|idx| self.source_lines.get(file)?.get(idx as usize));
+        match text {
+            Some(text) => finding_fingerprint(file, rule_id, text, ""),
+            None => finding_fingerprint(file, rule_id, "", context),
+        }
+    }
+
+    /// Split off patterns and security findings below `min_confidence`.
+    ///
+    /// Returns the input with only scoreable findings, plus one advisory per
+    /// outlier of each filtered pattern and per filtered security finding.
+    pub fn split_advisories(&self) -> (GateInput, Vec<Advisory>) {
+        let mut input = self.clone();
+        let Some(threshold) = self.min_confidence else {
+            return (input, Vec::new());
+        };
+
+        let (kept, filtered): (Vec<_>, Vec<_>) =
+            input.patterns.drain(..).partition(|p| p.confidence >= threshold);
+        input.patterns = kept;
+        let (kept, filtered_security): (Vec<_>, Vec<_>) = input
+            .security_findings
+            .drain(..)
+            .partition(|f| !matches!(f.confidence, Some(c) if c < threshold));
+        input.security_findings = kept;
+
+        let mut advisories: Vec<Advisory> = filtered
+            .iter()
+            .flat_map(|pattern| {
+                pattern.outliers.iter().map(move |outlier| Advisory {
+                    gate_id: GateId::PatternCompliance,
+                    pattern_id: pattern.pattern_id.clone(),
+                    category: pattern.category.clone(),
+                    confidence: pattern.confidence,
+                    file: outlier.file.clone(),
+                    line: outlier.line,
+                    column: outlier.column,
+                    message: format!(
+                        "Deviates from pattern '{}' (confidence {:.0}% is below the {:.0}% threshold)",
+                        pattern.pattern_id,
+                        pattern.confidence * 100.0,
+                        threshold * 100.0
+                    ),
+                })
+            })
+            .collect();
+        advisories.extend(filtered_security.into_iter().map(|finding| {
+            let confidence = finding.confidence.unwrap_or_default();
+            Advisory {
+                gate_id: GateId::SecurityBoundaries,
+                pattern_id: "security-boundary".to_string(),
+                category: "security".to_string(),
+                confidence,
+                file: finding.file,
+                line: finding.line,
+                column: None,
+                message: format!(
+                    "{} (confidence {:.0}% is below the {:.0}% threshold)",
+                    finding.description,
+                    confidence * 100.0,
+                    threshold * 100.0
+                ),
+            }
+        }));
+        (input, advisories)
+    }
 }
 
 impl std::fmt::Debug for GateInput {
@@ -193,6 +462,9 @@ impl std::fmt::Debug for GateInput {
             .field("predecessor_results", &self.predecessor_results)
             .field("baseline_violations", &self.baseline_violations)
             .field("feedback_stats", &self.feedback_stats.as_ref().map(|_| "<FeedbackStatsProvider>"))
+            .field("min_confidence", &self.min_confidence)
+            .field("sample_fraction", &self.sample_fraction)
+            .field("source_lines", &self.source_lines.len())
             .finish()
     }
 }
@@ -222,6 +494,8 @@ pub struct SecurityFindingInput {
     pub severity: String,
     pub cwe_ids: Vec<u32>,
     pub owasp_categories: Vec<String>,
+    /// Detection confidence (0.0-1.0). `None` is always scored.
+    pub confidence: Option<f64>,
 }
 
 /// Test coverage data for the test coverage gate.
@@ -341,6 +615,7 @@ impl GateInputBuilder {
                 severity: severity.to_string(),
                 cwe_ids,
                 owasp_categories,
+                confidence: Some(f64::from(flow.confidence)),
             });
         }
         self
@@ -377,10 +652,9 @@ impl GateInputBuilder {
                 .clone()
                 .unwrap_or_else(|| {
                     format!(
-                        "{} error handling gap in {}:{}",
+                        "{} error handling gap in {}",
                         gap.gap_type.name(),
-                        gap.file,
-                        gap.line
+                        gap.function
                     )
                 });
 
@@ -445,12 +719,31 @@ impl GateInputBuilder {
     }
 
     /// Set baseline violation keys for is_new detection.
-    /// Keys should be formatted as "file:line:rule_id".
+    /// Keys should be formatted as "file:line:rule_id" or be finding fingerprints.
     pub fn baseline_violations(mut self, baseline: HashSet<String>) -> Self {
         self.input.baseline_violations = baseline;
         self
     }
 
+    /// Score only patterns and security findings at or above `threshold`;
+    /// report the rest as advisories.
+    pub fn min_confidence(mut self, threshold: f64) -> Self {
+        self.input.min_confidence = Some(threshold);
+        self
+    }
+
+    /// Mark the input as coming from a sampled scan (see `ScanStats::sample_fraction`).
+    pub fn sample_fraction(mut self, fraction: f64) -> Self {
+        self.input.sample_fraction = Some(fraction);
+        self
+    }
+
+    /// Source lines per file, for fingerprinting violations by content.
+    pub fn source_lines(mut self, source_lines: HashMap<String, Vec<String>>) -> Self {
+        self.input.source_lines = source_lines;
+        self
+    }
+
     /// Build the final `GateInput`.
     pub fn build(self) -> GateInput {
         self.input
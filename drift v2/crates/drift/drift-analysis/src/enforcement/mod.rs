@@ -7,6 +7,8 @@
 //! - `policy` — 4 aggregation modes for gate results
 //! - `audit` — 5-factor health scoring, degradation detection
 //! - `feedback` — Tricorder-style FP tracking, auto-disable
+//! - `baseline` — Accepted findings, first-seen tracking, aging reports
+//! - `fingerprint` — Stable finding identity shared by baselines, SARIF, dedup
 
 pub mod rules;
 pub mod gates;
@@ -14,3 +16,5 @@ pub mod reporters;
 pub mod policy;
 pub mod audit;
 pub mod feedback;
+pub mod baseline;
+pub mod fingerprint;
@@ -82,6 +82,13 @@ impl Reporter for ConsoleReporter {
                 result.score
             ));
 
+            // Explain failing and warned gates
+            if matches!(result.status, GateStatus::Failed | GateStatus::Warned) {
+                if let Some(ref explanation) = result.explanation {
+                    output.push_str(&format!("  ↳ {}\n", explanation.headline()));
+                }
+            }
+
             // Show violations
             for violation in &result.violations {
                 let prefix = self.severity_prefix(&violation.severity);
@@ -111,6 +118,17 @@ impl Reporter for ConsoleReporter {
                 output.push_str(&format!("  ⚠ {warning}\n"));
             }
 
+            // Show advisories (below the confidence threshold, not scored)
+            for advisory in &result.advisories {
+                output.push_str(&format!(
+                    "  advisory: {}:{}:{}: {}\n",
+                    advisory.file,
+                    advisory.line,
+                    advisory.column.unwrap_or(0),
+                    advisory.message,
+                ));
+            }
+
             output.push('\n');
         }
 
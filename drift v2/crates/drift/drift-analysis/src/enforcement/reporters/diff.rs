@@ -0,0 +1,135 @@
+//! Diff reports — only what changed between two runs.
+//!
+//! For PR comments: findings present now but not before are *new*, findings
+//! present before but gone now are *fixed*. Findings are matched by
+//! [`violation_fingerprint`], so a finding that merely moved lines is
+//! unchanged. Unlike the baseline, which decides CI pass/fail, a diff is a
+//! human-readable change summary and never alters gate status.
+
+use std::collections::HashMap;
+
+use crate::enforcement::fingerprint::violation_fingerprint;
+use crate::enforcement::gates::{GateId, GateResult};
+use crate::enforcement::rules::Violation;
+
+use super::create_reporter;
+
+/// New and fixed findings between two sets of gate results.
+#[derive(Debug, Clone, Default)]
+pub struct ReportDiff {
+    /// Current findings absent from the previous results, with their gate.
+    pub new: Vec<(GateId, Violation)>,
+    /// Previous findings absent from the current results, with their gate.
+    pub fixed: Vec<(GateId, Violation)>,
+    /// Findings present in both.
+    pub unchanged: usize,
+}
+
+impl ReportDiff {
+    /// One-line summary, e.g. `2 new, 1 fixed, 14 unchanged findings`.
+    pub fn summary(&self) -> String {
+        format!(
+            "{} new, {} fixed, {} unchanged finding{}",
+            self.new.len(),
+            self.fixed.len(),
+            self.unchanged,
+            if self.unchanged == 1 { "" } else { "s" }
+        )
+    }
+
+    /// Gate results holding only the delta, for rendering with any reporter.
+    ///
+    /// Each gate with a change keeps its current status and score. New
+    /// findings are marked `is_new`; fixed findings follow them with their
+    /// message prefixed `Fixed:`. Gates without changes are left out.
+    pub fn to_gate_results(&self, previous: &[GateResult], current: &[GateResult]) -> Vec<GateResult> {
+        let mut gates: Vec<&GateResult> = current.iter().collect();
+        gates.extend(previous.iter().filter(|p| !current.iter().any(|c| c.gate_id == p.gate_id)));
+
+        gates
+            .into_iter()
+            .filter_map(|gate| {
+                let new = self.new.iter().filter(|(id, _)| *id == gate.gate_id);
+                let fixed = self.fixed.iter().filter(|(id, _)| *id == gate.gate_id);
+                let violations: Vec<Violation> = new
+                    .map(|(_, v)| Violation { is_new: true, ..v.clone() })
+                    .chain(fixed.map(|(_, v)| Violation {
+                        is_new: false,
+                        message: format!("Fixed: {}", v.message),
+                        ..v.clone()
+                    }))
+                    .collect();
+                if violations.is_empty() {
+                    return None;
+                }
+                let new_count = violations.iter().filter(|v| v.is_new).count();
+                Some(GateResult {
+                    summary: format!("{} new, {} fixed", new_count, violations.len() - new_count),
+                    violations,
+                    warnings: Vec::new(),
+                    explanation: None,
+                    advisories: Vec::new(),
+                    ..gate.clone()
+                })
+            })
+            .collect()
+    }
+}
+
+/// Compare two sets of gate results by finding fingerprint. Suppressed
+/// findings count as absent, so suppressing a finding reports it as fixed.
+pub fn diff_results(previous: &[GateResult], current: &[GateResult]) -> ReportDiff {
+    let mut before = fingerprint_counts(previous);
+    let mut after = fingerprint_counts(current);
+    let mut diff = ReportDiff::default();
+
+    for (gate, violation) in findings(current) {
+        match before.get_mut(&violation_fingerprint(violation)) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                diff.unchanged += 1;
+            }
+            _ => diff.new.push((gate, violation.clone())),
+        }
+    }
+
+    for (gate, violation) in findings(previous) {
+        match after.get_mut(&violation_fingerprint(violation)) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => diff.fixed.push((gate, violation.clone())),
+        }
+    }
+
+    diff
+}
+
+/// Render only the delta between `previous` and `current` in `format`.
+///
+/// The `console` format starts with the [`ReportDiff::summary`] line; in
+/// structured formats each gate's summary carries its own counts instead,
+/// so the output stays valid for its consumer.
+pub fn diff_report(previous: &[GateResult], current: &[GateResult], format: &str) -> Result<String, String> {
+    let reporter = create_reporter(format).ok_or_else(|| format!("unknown report format '{format}'"))?;
+    let diff = diff_results(previous, current);
+    let output = reporter.generate(&diff.to_gate_results(previous, current))?;
+    if format == "console" {
+        Ok(format!("{}\n\n{}", diff.summary(), output))
+    } else {
+        Ok(output)
+    }
+}
+
+fn findings(results: &[GateResult]) -> impl Iterator<Item = (GateId, &Violation)> {
+    results
+        .iter()
+        .flat_map(|g| g.violations.iter().filter(|v| !v.suppressed).map(move |v| (g.gate_id, v)))
+}
+
+/// Occurrences per fingerprint, so repeated identical findings pair up one to one.
+fn fingerprint_counts(results: &[GateResult]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for (_, violation) in findings(results) {
+        *counts.entry(violation_fingerprint(violation)).or_insert(0) += 1;
+    }
+    counts
+}
@@ -4,9 +4,8 @@
 //! See: https://docs.gitlab.com/ee/ci/testing/code_quality.html
 
 use serde_json::{json, Value};
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
 
+use crate::enforcement::fingerprint::violation_fingerprint;
 use crate::enforcement::gates::GateResult;
 use crate::enforcement::rules::{Severity, Violation};
 use super::Reporter;
@@ -30,15 +29,6 @@ impl GitLabCodeQualityReporter {
             Severity::Hint => "info",
         }
     }
-
-    /// Generate a stable fingerprint for deduplication across runs.
-    fn fingerprint(violation: &Violation) -> String {
-        let mut hasher = DefaultHasher::new();
-        violation.rule_id.hash(&mut hasher);
-        violation.file.hash(&mut hasher);
-        violation.line.hash(&mut hasher);
-        format!("{:016x}", hasher.finish())
-    }
 }
 
 impl Default for GitLabCodeQualityReporter {
@@ -72,7 +62,7 @@ impl Reporter for GitLabCodeQualityReporter {
                     "description": description,
                     "categories": Self::categories_for_violation(violation),
                     "severity": Self::severity_to_gitlab(&violation.severity),
-                    "fingerprint": Self::fingerprint(violation),
+                    "fingerprint": violation_fingerprint(violation),
                     "location": {
                         "path": violation.file,
                         "lines": {
@@ -3,7 +3,7 @@
 //! Produces a single HTML file with no external dependencies that renders
 //! a violation list with severity, location, and quick fix suggestions.
 
-use crate::enforcement::gates::{GateResult, GateStatus};
+use crate::enforcement::gates::{format_metric_value, GateResult, GateStatus};
 use crate::enforcement::rules::Severity;
 use super::Reporter;
 
@@ -135,6 +135,24 @@ impl Reporter for HtmlReporter {
                 Self::escape_html(&result.summary)
             ));
 
+            if let Some(ref explanation) = result.explanation {
+                html.push_str(&format!(
+                    "<p class=\"gate-explanation\">{}</p>\n",
+                    Self::escape_html(&explanation.headline())
+                ));
+                if !explanation.metrics.is_empty() {
+                    html.push_str("<ul class=\"gate-metrics\">\n");
+                    for metric in &explanation.metrics {
+                        html.push_str(&format!(
+                            "<li>{}: {}</li>\n",
+                            Self::escape_html(&metric.name),
+                            format_metric_value(metric.value)
+                        ));
+                    }
+                    html.push_str("</ul>\n");
+                }
+            }
+
             let active_violations: Vec<_> = result
                 .violations
                 .iter()
@@ -249,6 +267,8 @@ h2 { font-size: 18px; margin-bottom: 8px; }
 .status-errored { border-left: 4px solid #fd7e14; }
 .score { float: right; font-size: 14px; color: #666; font-weight: 400; }
 .gate-summary { color: #555; margin-bottom: 12px; font-size: 14px; }
+.gate-explanation { font-size: 14px; font-weight: 600; margin-bottom: 4px; }
+.gate-metrics { font-size: 13px; color: #555; margin: 0 0 12px 20px; }
 .no-violations { color: #28a745; font-style: italic; }
 .violations { width: 100%; border-collapse: collapse; font-size: 13px; }
 .violations th { text-align: left; padding: 8px; background: #f8f9fa; border-bottom: 2px solid #dee2e6; }
@@ -40,6 +40,7 @@ impl Reporter for JsonReporter {
                         "is_new": v.is_new,
                     })).collect::<Vec<_>>(),
                     "warnings": r.warnings,
+                    "advisories": r.advisories,
                     "execution_time_ms": r.execution_time_ms,
                     "details": r.details,
                     "error": r.error,
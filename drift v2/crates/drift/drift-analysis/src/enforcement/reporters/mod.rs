@@ -1,7 +1,10 @@
 //! Reporters — output formats for gate results.
 //!
-//! 8 reporter formats: SARIF 2.1.0, JSON, console, GitHub Code Quality,
-//! GitLab Code Quality, JUnit XML, HTML, SonarQube Generic Issue Format.
+//! 10 reporter formats: SARIF 2.1.0, JSON, console, GitHub Code Quality,
+//! GitLab Code Quality, JUnit XML, HTML, SonarQube Generic Issue Format,
+//! a flat vulnerability report, and NDJSON (one violation per line). Further formats can be registered at
+//! runtime through [`ReporterRegistry`] or [`register_reporter`].
+//! [`diff_report`] renders only the change between two runs.
 
 pub mod sarif;
 pub mod json;
@@ -11,31 +14,44 @@ pub mod gitlab;
 pub mod junit;
 pub mod html;
 pub mod sonarqube;
+pub mod vulnreport;
+pub mod ndjson;
+pub mod registry;
+pub mod diff;
+
+use std::io::Write;
 
 use crate::enforcement::gates::GateResult;
 
+pub use diff::{diff_report, diff_results, ReportDiff};
+pub use registry::{register_reporter, ReporterRegistry};
+
 /// Trait for report generation.
 pub trait Reporter: Send + Sync {
     fn name(&self) -> &'static str;
     fn generate(&self, results: &[GateResult]) -> Result<String, String>;
+
+    /// Write the report into `writer`. Reporters that can emit output
+    /// incrementally override this; the default writes `generate`'s output.
+    fn generate_streaming(&self, results: &[GateResult], writer: &mut dyn Write) -> Result<(), String> {
+        let output = self.generate(results)?;
+        writer.write_all(output.as_bytes()).map_err(|e| e.to_string())
+    }
 }
 
-/// Create a reporter by format name.
+/// Create a reporter by format name: a built-in format or one added with
+/// [`register_reporter`].
 pub fn create_reporter(format: &str) -> Option<Box<dyn Reporter>> {
-    match format {
-        "sarif" => Some(Box::new(sarif::SarifReporter::new())),
-        "json" => Some(Box::new(json::JsonReporter)),
-        "console" => Some(Box::new(console::ConsoleReporter::default())),
-        "github" => Some(Box::new(github::GitHubCodeQualityReporter::new())),
-        "gitlab" => Some(Box::new(gitlab::GitLabCodeQualityReporter::new())),
-        "junit" => Some(Box::new(junit::JUnitReporter::new())),
-        "html" => Some(Box::new(html::HtmlReporter::new())),
-        "sonarqube" => Some(Box::new(sonarqube::SonarQubeReporter::new())),
-        _ => None,
-    }
+    registry::create_global(format)
 }
 
-/// List all available reporter format names.
+/// List the built-in reporter format names.
 pub fn available_formats() -> &'static [&'static str] {
-    &["sarif", "json", "console", "github", "gitlab", "junit", "html", "sonarqube"]
+    registry::BUILTIN_FORMATS
+}
+
+/// List every format `create_reporter` accepts: the built-ins, then those
+/// added with [`register_reporter`].
+pub fn registered_formats() -> Vec<String> {
+    registry::global_formats()
 }
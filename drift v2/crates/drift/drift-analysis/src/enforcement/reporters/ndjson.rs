@@ -0,0 +1,80 @@
+//! NDJSON reporter — one JSON object per violation, one per line.
+//!
+//! Log pipelines (Splunk, Loki, `jq -c`) ingest findings line by line; the
+//! nested `json` document has to be read whole first. Each line is a flat
+//! [`NdjsonRecord`] carrying its gate id, so lines can be split, filtered or
+//! concatenated across runs and still parse on their own. A run without
+//! violations produces empty output.
+
+use std::io::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::enforcement::gates::{GateId, GateResult};
+use crate::enforcement::rules::{Severity, Violation};
+use super::Reporter;
+
+/// One violation as written on a single NDJSON line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NdjsonRecord {
+    pub gate_id: GateId,
+    pub id: String,
+    pub rule_id: String,
+    pub pattern_id: String,
+    pub file: String,
+    pub line: u32,
+    pub column: Option<u32>,
+    pub severity: Severity,
+    pub message: String,
+    pub cwe_id: Option<u32>,
+    pub owasp_category: Option<String>,
+    pub suppressed: bool,
+    pub is_new: bool,
+}
+
+impl NdjsonRecord {
+    pub fn new(gate_id: GateId, violation: &Violation) -> Self {
+        Self {
+            gate_id,
+            id: violation.id.clone(),
+            rule_id: violation.rule_id.clone(),
+            pattern_id: violation.pattern_id.clone(),
+            file: violation.file.clone(),
+            line: violation.line,
+            column: violation.column,
+            severity: violation.severity,
+            message: violation.message.clone(),
+            cwe_id: violation.cwe_id,
+            owasp_category: violation.owasp_category.clone(),
+            suppressed: violation.suppressed,
+            is_new: violation.is_new,
+        }
+    }
+}
+
+/// NDJSON (JSON Lines) reporter for streaming ingestion.
+pub struct NdjsonReporter;
+
+impl Reporter for NdjsonReporter {
+    fn name(&self) -> &'static str {
+        "ndjson"
+    }
+
+    fn generate(&self, results: &[GateResult]) -> Result<String, String> {
+        let mut output = Vec::new();
+        self.generate_streaming(results, &mut output)?;
+        String::from_utf8(output).map_err(|e| e.to_string())
+    }
+
+    /// Serialize each violation straight into `writer`, one line at a time.
+    fn generate_streaming(&self, results: &[GateResult], writer: &mut dyn Write) -> Result<(), String> {
+        for gate_result in results {
+            for violation in &gate_result.violations {
+                let record = NdjsonRecord::new(gate_result.gate_id, violation);
+                serde_json::to_writer(&mut *writer, &record).map_err(|e| e.to_string())?;
+                writer.write_all(b"\n").map_err(|e| e.to_string())?;
+            }
+        }
+        writer.flush().map_err(|e| e.to_string())
+    }
+}
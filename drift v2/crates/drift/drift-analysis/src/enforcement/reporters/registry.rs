@@ -0,0 +1,141 @@
+//! Reporter registry — the built-in formats plus reporters added at runtime.
+//!
+//! Embedders that need their own output (a dashboard feed, an internal
+//! ticket format) register a `Reporter` under a format name instead of
+//! patching `create_reporter`. A [`ReporterRegistry`] can be built and passed
+//! around explicitly; [`register_reporter`] adds to the process-wide registry
+//! behind `create_reporter` and `registered_formats`.
+
+use std::io::Write;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use crate::enforcement::gates::GateResult;
+
+use super::{console, github, gitlab, html, json, junit, ndjson, sarif, sonarqube, vulnreport, Reporter};
+
+/// Built-in format names, in registration order.
+pub const BUILTIN_FORMATS: &[&str] =
+    &["sarif", "json", "console", "github", "gitlab", "junit", "html", "sonarqube", "vulnreport", "ndjson"];
+
+/// Reporters by format name, in registration order.
+pub struct ReporterRegistry {
+    reporters: Vec<(String, Arc<dyn Reporter>)>,
+}
+
+impl ReporterRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self {
+            reporters: Vec::new(),
+        }
+    }
+
+    /// Create a registry with every built-in format registered.
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        for format in BUILTIN_FORMATS {
+            if let Some(reporter) = builtin(format) {
+                registry.register(*format, reporter);
+            }
+        }
+        registry
+    }
+
+    /// Register `reporter` under `format`. A reporter already registered
+    /// under that name, built-in or not, is replaced in place.
+    pub fn register(&mut self, format: impl Into<String>, reporter: Box<dyn Reporter>) {
+        let format = format.into();
+        let reporter: Arc<dyn Reporter> = Arc::from(reporter);
+        match self.reporters.iter_mut().find(|(name, _)| *name == format) {
+            Some(entry) => entry.1 = reporter,
+            None => self.reporters.push((format, reporter)),
+        }
+    }
+
+    /// The reporter registered under `format`.
+    pub fn get(&self, format: &str) -> Option<&dyn Reporter> {
+        self.reporters
+            .iter()
+            .find(|(name, _)| *name == format)
+            .map(|(_, reporter)| reporter.as_ref())
+    }
+
+    /// An owned handle to the reporter registered under `format`.
+    pub fn create(&self, format: &str) -> Option<Box<dyn Reporter>> {
+        self.reporters
+            .iter()
+            .find(|(name, _)| *name == format)
+            .map(|(_, reporter)| Box::new(Shared(Arc::clone(reporter))) as Box<dyn Reporter>)
+    }
+
+    /// Registered format names, in registration order.
+    pub fn formats(&self) -> Vec<&str> {
+        self.reporters.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// Render `results` with the reporter registered under `format`.
+    pub fn generate(&self, format: &str, results: &[GateResult]) -> Result<String, String> {
+        self.get(format)
+            .ok_or_else(|| format!("unknown report format '{format}'"))?
+            .generate(results)
+    }
+}
+
+impl Default for ReporterRegistry {
+    fn default() -> Self {
+        Self::with_builtins()
+    }
+}
+
+fn global() -> &'static RwLock<ReporterRegistry> {
+    static GLOBAL: OnceLock<RwLock<ReporterRegistry>> = OnceLock::new();
+    GLOBAL.get_or_init(|| RwLock::new(ReporterRegistry::with_builtins()))
+}
+
+/// Register `reporter` under `format` for `create_reporter` and
+/// `registered_formats`.
+pub fn register_reporter(format: impl Into<String>, reporter: Box<dyn Reporter>) {
+    global().write().unwrap_or_else(|e| e.into_inner()).register(format, reporter);
+}
+
+pub(super) fn create_global(format: &str) -> Option<Box<dyn Reporter>> {
+    global().read().unwrap_or_else(|e| e.into_inner()).create(format)
+}
+
+pub(super) fn global_formats() -> Vec<String> {
+    let registry = global().read().unwrap_or_else(|e| e.into_inner());
+    registry.formats().into_iter().map(str::to_string).collect()
+}
+
+fn builtin(format: &str) -> Option<Box<dyn Reporter>> {
+    match format {
+        "sarif" => Some(Box::new(sarif::SarifReporter::new())),
+        "json" => Some(Box::new(json::JsonReporter)),
+        "console" => Some(Box::new(console::ConsoleReporter::default())),
+        "github" => Some(Box::new(github::GitHubCodeQualityReporter::new())),
+        "gitlab" => Some(Box::new(gitlab::GitLabCodeQualityReporter::new())),
+        "junit" => Some(Box::new(junit::JUnitReporter::new())),
+        "html" => Some(Box::new(html::HtmlReporter::new())),
+        "sonarqube" => Some(Box::new(sonarqube::SonarQubeReporter::new())),
+        "vulnreport" => Some(Box::new(vulnreport::VulnReportReporter::new())),
+        "ndjson" => Some(Box::new(ndjson::NdjsonReporter)),
+        _ => None,
+    }
+}
+
+/// A registered reporter handed out by `create`.
+struct Shared(Arc<dyn Reporter>);
+
+impl Reporter for Shared {
+    fn name(&self) -> &'static str {
+        self.0.name()
+    }
+
+    fn generate(&self, results: &[GateResult]) -> Result<String, String> {
+        self.0.generate(results)
+    }
+
+    fn generate_streaming(&self, results: &[GateResult], writer: &mut dyn Write) -> Result<(), String> {
+        self.0.generate_streaming(results, writer)
+    }
+}
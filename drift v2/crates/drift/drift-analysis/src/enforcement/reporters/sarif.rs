@@ -1,11 +1,16 @@
 //! SARIF 2.1.0 reporter with CWE + OWASP taxonomies for GitHub Code Scanning.
 
+use std::io::Write;
+
 use serde_json::{json, Value};
 
+use crate::enforcement::fingerprint::violation_fingerprint;
 use crate::enforcement::gates::GateResult;
-use crate::enforcement::rules::Severity;
+use crate::enforcement::rules::{Severity, Violation};
 use super::Reporter;
 
+const SARIF_SCHEMA: &str = "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/main/sarif-2.1/schema/sarif-schema-2.1.0.json";
+
 /// SARIF 2.1.0 reporter.
 pub struct SarifReporter {
     pub tool_name: String,
@@ -33,59 +38,80 @@ impl SarifReporter {
         let mut results = Vec::new();
 
         for gate_result in gate_results {
-            for violation in &gate_result.violations {
-                if violation.suppressed {
-                    continue;
-                }
+            for violation in gate_result.violations.iter().filter(|v| !v.suppressed) {
+                results.push(self.build_result(violation));
+            }
+        }
 
-                let mut result = json!({
-                    "ruleId": violation.rule_id,
-                    "level": Self::severity_to_sarif_level(&violation.severity),
-                    "message": {
-                        "text": violation.message
-                    },
-                    "locations": [{
-                        "physicalLocation": {
-                            "artifactLocation": {
-                                "uri": violation.file,
-                                "uriBaseId": "%SRCROOT%"
-                            },
-                            "region": self.build_region(violation)
-                        }
-                    }]
-                });
+        results
+    }
 
-                // Add properties (is_new, CWE, OWASP)
-                let mut properties = serde_json::Map::new();
-                properties.insert("isNew".to_string(), json!(violation.is_new));
-                if let Some(cwe_id) = violation.cwe_id {
-                    properties.insert("cweId".to_string(), json!(format!("CWE-{cwe_id}")));
-                }
-                if let Some(ref owasp) = violation.owasp_category {
-                    properties.insert("owaspCategory".to_string(), json!(owasp));
+    fn build_result(&self, violation: &Violation) -> Value {
+        let mut result = json!({
+            "ruleId": violation.rule_id,
+            "level": Self::severity_to_sarif_level(&violation.severity),
+            "message": {
+                "text": violation.message
+            },
+            "locations": [{
+                "physicalLocation": {
+                    "artifactLocation": {
+                        "uri": violation.file,
+                        "uriBaseId": "%SRCROOT%"
+                    },
+                    "region": self.build_region(violation)
                 }
-                result["properties"] = Value::Object(properties);
+            }],
+            "partialFingerprints": {
+                "driftFindingHash/v1": violation_fingerprint(violation)
+            }
+        });
 
-                // Add quick fix if available
-                if let Some(ref fix) = violation.quick_fix {
-                    result["fixes"] = json!([{
-                        "description": {
-                            "text": fix.description
-                        }
-                    }]);
-                }
+        // Add properties (is_new, CWE, OWASP)
+        let mut properties = serde_json::Map::new();
+        properties.insert("isNew".to_string(), json!(violation.is_new));
+        if let Some(cwe_id) = violation.cwe_id {
+            properties.insert("cweId".to_string(), json!(format!("CWE-{cwe_id}")));
+        }
+        if let Some(ref owasp) = violation.owasp_category {
+            properties.insert("owaspCategory".to_string(), json!(owasp));
+        }
+        result["properties"] = Value::Object(properties);
 
-                results.push(result);
+        // Add quick fix if available
+        if let Some(ref fix) = violation.quick_fix {
+            let mut sarif_fix = json!({
+                "description": {
+                    "text": fix.description
+                }
+            });
+            // A concrete edit becomes an artifactChange (SARIF columns are 1-based)
+            if let (Some(range), Some(replacement)) = (&fix.range, &fix.replacement) {
+                sarif_fix["artifactChanges"] = json!([{
+                    "artifactLocation": {
+                        "uri": violation.file,
+                        "uriBaseId": "%SRCROOT%"
+                    },
+                    "replacements": [{
+                        "deletedRegion": {
+                            "startLine": range.start_line,
+                            "startColumn": range.start_column + 1,
+                            "endLine": range.end_line,
+                            "endColumn": range.end_column + 1
+                        },
+                        "insertedContent": {
+                            "text": replacement
+                        }
+                    }]
+                }]);
             }
+            result["fixes"] = json!([sarif_fix]);
         }
 
-        results
+        result
     }
 
-    fn build_region(
-        &self,
-        violation: &crate::enforcement::rules::Violation,
-    ) -> Value {
+    fn build_region(&self, violation: &Violation) -> Value {
         let mut region = json!({
             "startLine": violation.line.max(1)
         });
@@ -159,6 +185,17 @@ impl SarifReporter {
         rules
     }
 
+    fn build_tool(&self, gate_results: &[GateResult]) -> Value {
+        json!({
+            "driver": {
+                "name": self.tool_name,
+                "version": self.tool_version,
+                "informationUri": "https://github.com/drift-lang/drift",
+                "rules": self.build_rules(gate_results)
+            }
+        })
+    }
+
     fn build_taxonomies(&self, gate_results: &[GateResult]) -> Vec<Value> {
         let mut taxonomies = Vec::new();
 
@@ -232,27 +269,44 @@ impl Reporter for SarifReporter {
     }
 
     fn generate(&self, results: &[GateResult]) -> Result<String, String> {
-        let sarif_results = self.build_results(results);
-        let rules = self.build_rules(results);
-        let taxonomies = self.build_taxonomies(results);
-
         let sarif = json!({
-            "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/main/sarif-2.1/schema/sarif-schema-2.1.0.json",
+            "$schema": SARIF_SCHEMA,
             "version": "2.1.0",
             "runs": [{
-                "tool": {
-                    "driver": {
-                        "name": self.tool_name,
-                        "version": self.tool_version,
-                        "informationUri": "https://github.com/drift-lang/drift",
-                        "rules": rules
-                    }
-                },
-                "results": sarif_results,
-                "taxonomies": taxonomies
+                "tool": self.build_tool(results),
+                "results": self.build_results(results),
+                "taxonomies": self.build_taxonomies(results)
             }]
         });
 
         serde_json::to_string_pretty(&sarif).map_err(|e| e.to_string())
     }
+
+    /// Stream the document: the header, tool (with rules) and taxonomies are
+    /// written first, then each result is serialized straight into `writer`,
+    /// so memory does not grow with the number of results.
+    fn generate_streaming(&self, results: &[GateResult], writer: &mut dyn Write) -> Result<(), String> {
+        let io = |e: std::io::Error| e.to_string();
+        let ser = |e: serde_json::Error| e.to_string();
+
+        write!(writer, "{{\"$schema\":{},\"version\":\"2.1.0\",\"runs\":[{{\"tool\":", json!(SARIF_SCHEMA)).map_err(io)?;
+        serde_json::to_writer(&mut *writer, &self.build_tool(results)).map_err(ser)?;
+        writer.write_all(b",\"taxonomies\":").map_err(io)?;
+        serde_json::to_writer(&mut *writer, &self.build_taxonomies(results)).map_err(ser)?;
+        writer.write_all(b",\"results\":[").map_err(io)?;
+
+        let mut first = true;
+        for gate_result in results {
+            for violation in gate_result.violations.iter().filter(|v| !v.suppressed) {
+                if !first {
+                    writer.write_all(b",").map_err(io)?;
+                }
+                first = false;
+                serde_json::to_writer(&mut *writer, &self.build_result(violation)).map_err(ser)?;
+            }
+        }
+
+        writer.write_all(b"]}]}").map_err(io)?;
+        writer.flush().map_err(io)
+    }
 }
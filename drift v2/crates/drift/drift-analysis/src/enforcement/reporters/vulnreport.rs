@@ -0,0 +1,179 @@
+//! Vulnerability report reporter — flat, vulnerability-centric JSON.
+//!
+//! Unlike SARIF, which nests results under runs and rules, this emits one
+//! self-contained record per finding, in the shape vulnerability aggregators
+//! ingest: id, CWE, OWASP, a qualitative severity band with its CVSS range,
+//! location and description. Field names follow the OSV convention
+//! (`id`, `summary`, `details`, `database_specific`) where one exists.
+
+use serde_json::{json, Value};
+
+use crate::enforcement::gates::GateResult;
+use crate::enforcement::rules::{Severity, Violation};
+use crate::structural::owasp_cwe::registry::lookup_cwe;
+use super::Reporter;
+
+/// Schema version of the emitted document. Bump on breaking shape changes.
+pub const VULN_REPORT_SCHEMA_VERSION: &str = "1.0.0";
+
+/// CWEs whose exploitation typically yields code execution, data takeover or
+/// authentication bypass. Findings carrying one are raised a band.
+const HIGH_IMPACT_CWES: &[u32] = &[
+    22, 77, 78, 89, 94, 287, 306, 502, 611, 798, 862, 863, 918,
+];
+
+/// Qualitative severity band, as used by CVSS v3 ratings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SeverityBand {
+    None,
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl SeverityBand {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "NONE",
+            Self::Low => "LOW",
+            Self::Medium => "MEDIUM",
+            Self::High => "HIGH",
+            Self::Critical => "CRITICAL",
+        }
+    }
+
+    /// CVSS v3 score range covered by the band.
+    pub fn cvss_range(&self) -> (f32, f32) {
+        match self {
+            Self::None => (0.0, 0.0),
+            Self::Low => (0.1, 3.9),
+            Self::Medium => (4.0, 6.9),
+            Self::High => (7.0, 8.9),
+            Self::Critical => (9.0, 10.0),
+        }
+    }
+
+    /// Map a violation's severity and CWE to a band. The rule severity sets
+    /// the base band; a high-impact CWE raises it by one.
+    pub fn for_violation(severity: Severity, cwe_id: Option<u32>) -> Self {
+        let base = match severity {
+            Severity::Error => Self::High,
+            Severity::Warning => Self::Medium,
+            Severity::Info => Self::Low,
+            Severity::Hint => Self::None,
+        };
+        match cwe_id {
+            Some(id) if HIGH_IMPACT_CWES.contains(&id) => base.raised(),
+            _ => base,
+        }
+    }
+
+    fn raised(self) -> Self {
+        match self {
+            Self::None => Self::Low,
+            Self::Low => Self::Medium,
+            Self::Medium => Self::High,
+            Self::High | Self::Critical => Self::Critical,
+        }
+    }
+}
+
+/// Flat vulnerability report reporter.
+pub struct VulnReportReporter;
+
+impl VulnReportReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn vulnerability(gate: &GateResult, violation: &Violation) -> Value {
+        let band = SeverityBand::for_violation(violation.severity, violation.cwe_id);
+        let (cvss_min, cvss_max) = band.cvss_range();
+        let cwe = violation.cwe_id.map(|id| {
+            let entry = lookup_cwe(id);
+            json!({ "id": format!("CWE-{id}"), "name": entry.name, "url": entry.url })
+        });
+
+        json!({
+            "id": violation.id,
+            "rule_id": violation.rule_id,
+            "summary": violation.message,
+            "details": Self::details(violation),
+            "severity": {
+                "band": band.as_str(),
+                "cvss_range": [cvss_min, cvss_max],
+                "source": format!("{}", violation.severity),
+            },
+            "cwe": cwe,
+            "owasp": violation.owasp_category,
+            "location": {
+                "file": violation.file,
+                "start_line": violation.line,
+                "start_column": violation.column,
+                "end_line": violation.end_line.unwrap_or(violation.line),
+                "end_column": violation.end_column,
+            },
+            "database_specific": {
+                "gate": gate.gate_id.as_str(),
+                "pattern_id": violation.pattern_id,
+                "is_new": violation.is_new,
+            },
+        })
+    }
+
+    /// Longer description: the message plus the CWE weakness it instantiates
+    /// and the suggested fix, when known.
+    fn details(violation: &Violation) -> String {
+        let mut details = violation.message.clone();
+        if let Some(id) = violation.cwe_id {
+            let entry = lookup_cwe(id);
+            details.push_str(&format!("\n\nCWE-{id} ({}): {}", entry.name, entry.description));
+        }
+        if let Some(fix) = &violation.quick_fix {
+            details.push_str(&format!("\n\nSuggested fix: {}", fix.description));
+        }
+        details
+    }
+}
+
+impl Default for VulnReportReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Reporter for VulnReportReporter {
+    fn name(&self) -> &'static str {
+        "vulnreport"
+    }
+
+    fn generate(&self, results: &[GateResult]) -> Result<String, String> {
+        let mut vulnerabilities: Vec<Value> = Vec::new();
+        let mut counts = [0usize; 5];
+
+        for gate in results {
+            for violation in gate.violations.iter().filter(|v| !v.suppressed) {
+                let band = SeverityBand::for_violation(violation.severity, violation.cwe_id);
+                counts[band as usize] += 1;
+                vulnerabilities.push(Self::vulnerability(gate, violation));
+            }
+        }
+
+        let output = json!({
+            "schema_version": VULN_REPORT_SCHEMA_VERSION,
+            "tool": { "name": "drift", "version": env!("CARGO_PKG_VERSION") },
+            "summary": {
+                "total": vulnerabilities.len(),
+                "critical": counts[SeverityBand::Critical as usize],
+                "high": counts[SeverityBand::High as usize],
+                "medium": counts[SeverityBand::Medium as usize],
+                "low": counts[SeverityBand::Low as usize],
+                "none": counts[SeverityBand::None as usize],
+            },
+            "vulnerabilities": vulnerabilities,
+        });
+
+        serde_json::to_string_pretty(&output).map_err(|e| e.to_string())
+    }
+}
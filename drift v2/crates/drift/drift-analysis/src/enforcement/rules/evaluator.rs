@@ -2,6 +2,8 @@
 
 use std::collections::HashMap;
 
+use crate::enforcement::fingerprint::finding_fingerprint;
+
 use super::quick_fixes::QuickFixGenerator;
 use super::suppression::SuppressionChecker;
 use super::types::*;
@@ -40,7 +42,13 @@ impl RulesEvaluator {
                 let rule_id = format!("{}/{}", pattern.category, pattern.pattern_id);
                 let id = format!("{}-{}-{}", rule_id, outlier.file, outlier.line);
 
-                let quick_fix = self.fix_generator.suggest(pattern, outlier);
+                let source_line = outlier
+                    .line
+                    .checked_sub(1)
+                    .and_then(|idx| input.source_lines.get(&outlier.file)?.get(idx as usize));
+
+                let quick_fix =
+                    self.fix_generator.suggest_for_line(pattern, outlier, source_line.map(String::as_str));
 
                 let suppressed = self.suppression_checker.is_suppressed(
                     &outlier.file,
@@ -60,10 +68,15 @@ impl RulesEvaluator {
                     severity
                 };
 
-                // Determine is_new from baseline
+                let fingerprint = source_line.map(|text| finding_fingerprint(&outlier.file, &rule_id, text, ""));
+
+                // Determine is_new from baseline (by location or fingerprint)
                 let violation_key = format!("{}:{}:{}", outlier.file, outlier.line, rule_id);
                 let is_new = !input.baseline_violation_ids.is_empty()
-                    && !input.baseline_violation_ids.contains(&violation_key);
+                    && !input.baseline_violation_ids.contains(&violation_key)
+                    && !fingerprint
+                        .as_ref()
+                        .is_some_and(|f| input.baseline_violation_ids.contains(f));
 
                 violations.push(Violation {
                     id,
@@ -81,6 +94,7 @@ impl RulesEvaluator {
                     owasp_category: pattern.owasp_categories.first().cloned(),
                     suppressed,
                     is_new,
+                    fingerprint,
                 });
             }
         }
@@ -96,7 +110,7 @@ impl RulesEvaluator {
         if !pattern.cwe_ids.is_empty() {
             return match pattern.cwe_ids[0] {
                 // CWE-89 SQL injection, CWE-79 XSS, CWE-78 OS command injection
-                89 | 79 | 78 | 22 | 94 | 502 | 611 | 918 | 327 | 798 => Severity::Error,
+                89 | 79 | 78 | 22 | 94 | 502 | 611 | 918 | 327 | 798 | 1336 => Severity::Error,
                 _ => Severity::Warning,
             };
         }
@@ -3,6 +3,7 @@
 pub mod types;
 pub mod evaluator;
 pub mod quick_fixes;
+pub mod parameterize;
 pub mod suppression;
 
 pub use types::*;
@@ -0,0 +1,430 @@
+//! Rewrites an interpolated SQL literal into a parameterized query.
+//!
+//! Only the literal-template case is handled: a JS/TS template literal
+//! (`` `... ${id}` ``), a Python f-string, a Go `fmt.Sprintf` call or a Rust
+//! `format!` call, written on one line, whose text reads as SQL. Each
+//! interpolation becomes a placeholder in the usual driver style — `?` for
+//! JS/TS, `%s` for Python (DB-API), `$1`, `$2`, ... for Go and Rust — and
+//! quotes around it (`'${name}'`) are dropped, since the driver quotes bound
+//! values itself. Concatenated queries and other languages are left to the
+//! generic template.
+
+use crate::scanner::language_detect::Language;
+
+/// Text that marks a literal as SQL (matched case-insensitively).
+const SQL_MARKERS: &[&str] = &["SELECT ", "INSERT INTO ", "UPDATE ", "DELETE FROM ", " WHERE ", " VALUES"];
+
+/// An interpolated SQL literal rewritten to use bind parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParameterizedQuery {
+    /// Byte column where the rewritten expression starts.
+    pub start_column: u32,
+    /// Byte column just past the rewritten expression.
+    pub end_column: u32,
+    /// The SQL as a plain string literal, with placeholders.
+    pub sql: String,
+    /// The interpolated expressions, in placeholder order.
+    pub params: Vec<String>,
+    /// Whether the literal is a call argument, so the parameters can be
+    /// passed right after it.
+    pub in_call: bool,
+}
+
+/// Text or an interpolated expression inside a literal.
+enum Piece {
+    Text(String),
+    Param(String),
+}
+
+/// An interpolating literal found on a line.
+struct Template {
+    /// Byte span of the whole expression (`format!(...)` included).
+    start: usize,
+    end: usize,
+    pieces: Vec<Piece>,
+    /// Opening delimiter of the literal, string prefix included.
+    open: String,
+    /// Closing delimiter of the literal.
+    close: char,
+}
+
+/// The first interpolated SQL literal on `line`, rewritten for `language`.
+pub fn parameterize(line: &str, language: Language) -> Option<ParameterizedQuery> {
+    let templates = match language {
+        Language::TypeScript | Language::JavaScript => template_literals(line),
+        Language::Python => f_strings(line),
+        Language::Go => calls(line, "fmt.Sprintf(", sprintf_pieces),
+        Language::Rust => calls(line, "format!(", format_pieces),
+        _ => return None,
+    };
+    let template = templates.into_iter().find(|t| {
+        let has_param = t.pieces.iter().any(|p| matches!(p, Piece::Param(_)));
+        has_param && is_sql(&t.pieces)
+    })?;
+
+    let (body, params) = render(&template.pieces, language);
+    let (open, close) = if template.close == '`' && language != Language::Go {
+        // Template literal → plain string, unless the body needs backticks
+        match ['\'', '"'].into_iter().find(|q| !body.contains(*q)) {
+            Some(q) => (q.to_string(), q),
+            None => ("`".to_string(), '`'),
+        }
+    } else {
+        (template.open.clone(), template.close)
+    };
+    let before = line[..template.start].trim_end();
+    Some(ParameterizedQuery {
+        start_column: template.start as u32,
+        end_column: template.end as u32,
+        sql: format!("{open}{body}{close}"),
+        params,
+        in_call: before.ends_with('(') || before.ends_with(','),
+    })
+}
+
+/// Bind placeholder for the `n`th (1-based) parameter.
+fn placeholder(language: Language, n: usize) -> String {
+    match language {
+        Language::Python => "%s".to_string(),
+        Language::Go | Language::Rust => format!("${n}"),
+        _ => "?".to_string(),
+    }
+}
+
+fn is_sql(pieces: &[Piece]) -> bool {
+    let text: String = pieces
+        .iter()
+        .map(|p| match p {
+            Piece::Text(t) => t.to_ascii_uppercase(),
+            Piece::Param(_) => " ".to_string(),
+        })
+        .collect();
+    SQL_MARKERS.iter().any(|m| text.contains(m))
+}
+
+/// SQL body with placeholders, and the parameters in order.
+fn render(pieces: &[Piece], language: Language) -> (String, Vec<String>) {
+    let mut body = String::new();
+    let mut params = Vec::new();
+    let mut drop_quote = false;
+    for (i, piece) in pieces.iter().enumerate() {
+        match piece {
+            Piece::Text(text) => {
+                let text = if drop_quote { &text[1..] } else { text.as_str() };
+                drop_quote = false;
+                if language == Language::Python {
+                    // `%` is the DB-API placeholder marker
+                    body.push_str(&text.replace('%', "%%"));
+                } else {
+                    body.push_str(text);
+                }
+            }
+            Piece::Param(expr) => {
+                params.push(expr.trim().to_string());
+                let quoted_after = matches!(pieces.get(i + 1), Some(Piece::Text(t)) if t.starts_with('\''));
+                if body.ends_with('\'') && quoted_after {
+                    body.pop();
+                    drop_quote = true;
+                }
+                body.push_str(&placeholder(language, params.len()));
+            }
+        }
+    }
+    (body, params)
+}
+
+/// JS/TS template literals.
+fn template_literals(line: &str) -> Vec<Template> {
+    let mut templates = Vec::new();
+    let mut pos = 0;
+    while let Some(offset) = line[pos..].find('`') {
+        let start = pos + offset;
+        let mut pieces = Vec::new();
+        let mut text = String::new();
+        let mut i = start + 1;
+        let end = loop {
+            let Some(c) = line[i..].chars().next() else {
+                return templates;
+            };
+            match c {
+                '\\' => {
+                    let escaped = line[i + 1..].chars().next().map_or(0, char::len_utf8);
+                    text.push_str(&line[i..i + 1 + escaped]);
+                    i += 1 + escaped;
+                }
+                '`' => break i + 1,
+                '$' if line[i + 1..].starts_with('{') => {
+                    let Some(close) = closing(line, i + 1) else {
+                        return templates;
+                    };
+                    pieces.push(Piece::Text(std::mem::take(&mut text)));
+                    pieces.push(Piece::Param(line[i + 2..close].to_string()));
+                    i = close + 1;
+                }
+                _ => {
+                    text.push(c);
+                    i += c.len_utf8();
+                }
+            }
+        };
+        pieces.push(Piece::Text(text));
+        templates.push(Template { start, end, pieces, open: "`".to_string(), close: '`' });
+        pos = end;
+    }
+    templates
+}
+
+/// Python f-strings on a single line (triple-quoted strings are not handled).
+fn f_strings(line: &str) -> Vec<Template> {
+    let mut templates = Vec::new();
+    let mut pos = 0;
+    while let Some(offset) = line[pos..].find(['"', '\'']) {
+        let quote_at = pos + offset;
+        let quote = line[quote_at..].chars().next().unwrap_or('"');
+        let start = line[..quote_at].trim_end_matches(|c: char| c.is_ascii_alphabetic()).len();
+        let prefix = &line[start..quote_at];
+        let is_f = prefix.len() <= 2
+            && prefix.contains(['f', 'F'])
+            && prefix.chars().all(|c| "fFrRbB".contains(c));
+        let raw = prefix.contains(['r', 'R']);
+
+        let mut pieces = Vec::new();
+        let mut text = String::new();
+        let mut i = quote_at + 1;
+        let end = loop {
+            let Some(c) = line[i..].chars().next() else {
+                return templates;
+            };
+            let next = line[i + c.len_utf8()..].chars().next();
+            match c {
+                '\\' if !raw => {
+                    let escaped = next.map_or(0, char::len_utf8);
+                    text.push_str(&line[i..i + 1 + escaped]);
+                    i += 1 + escaped;
+                }
+                c if c == quote => break i + 1,
+                '{' | '}' if is_f && next == Some(c) => {
+                    text.push(c);
+                    i += 2;
+                }
+                '{' if is_f => {
+                    let Some(close) = closing(line, i) else {
+                        return templates;
+                    };
+                    pieces.push(Piece::Text(std::mem::take(&mut text)));
+                    pieces.push(Piece::Param(f_string_expr(&line[i + 1..close]).to_string()));
+                    i = close + 1;
+                }
+                _ => {
+                    text.push(c);
+                    i += c.len_utf8();
+                }
+            }
+        };
+        if is_f {
+            pieces.push(Piece::Text(text));
+            let open = format!("{}{quote}", prefix.replace(['f', 'F'], ""));
+            templates.push(Template { start, end, pieces, open, close: quote });
+        }
+        pos = end;
+    }
+    templates
+}
+
+/// The expression of an f-string field, without `=`, `!r` or `:spec`.
+fn f_string_expr(field: &str) -> &str {
+    let mut depth = 0;
+    let mut end = field.len();
+    for (i, c) in field.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ':' if depth == 0 => {
+                end = i;
+                break;
+            }
+            '!' if depth == 0 && !field[i + 1..].starts_with('=') => {
+                end = i;
+                break;
+            }
+            _ => {}
+        }
+    }
+    let expr = field[..end].trim_end();
+    expr.strip_suffix('=').filter(|e| !e.ends_with(['=', '!', '<', '>'])).unwrap_or(expr)
+}
+
+/// Calls to a formatting function whose first argument is a string literal.
+fn calls(
+    line: &str,
+    callee: &str,
+    pieces: fn(&str, &[&str]) -> Option<Vec<Piece>>,
+) -> Vec<Template> {
+    let mut templates = Vec::new();
+    for (at, _) in line.match_indices(callee) {
+        let open_paren = at + callee.len() - 1;
+        let Some(close_paren) = closing(line, open_paren) else {
+            continue;
+        };
+        let args = split_args(&line[open_paren + 1..close_paren]);
+        let Some((format, rest)) = args.split_first() else {
+            continue;
+        };
+        let format = format.trim();
+        let quote = format.chars().next().unwrap_or(' ');
+        if !matches!(quote, '"' | '`') || format.len() < 2 || !format.ends_with(quote) {
+            continue;
+        }
+        let Some(pieces) = pieces(&format[1..format.len() - 1], rest) else {
+            continue;
+        };
+        // `&format!(...)` is replaced as a whole so the literal keeps its type
+        let start = if line[..at].ends_with('&') { at - 1 } else { at };
+        templates.push(Template {
+            start,
+            end: close_paren + 1,
+            pieces,
+            open: quote.to_string(),
+            close: quote,
+        });
+    }
+    templates
+}
+
+/// Go `fmt.Sprintf` verbs matched to their arguments.
+fn sprintf_pieces(format: &str, args: &[&str]) -> Option<Vec<Piece>> {
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    let mut args = args.iter();
+    let mut chars = format.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        if c != '%' {
+            text.push(c);
+            continue;
+        }
+        if chars.next_if(|&(_, c)| c == '%').is_some() {
+            text.push('%');
+            continue;
+        }
+        while chars.next_if(|&(_, c)| "+-# 0.".contains(c) || c.is_ascii_digit()).is_some() {}
+        // Explicit argument indexes (`%[1]d`) are not handled
+        chars.next_if(|&(_, c)| c.is_ascii_alphabetic())?;
+        pieces.push(Piece::Text(std::mem::take(&mut text)));
+        pieces.push(Piece::Param(args.next()?.trim().to_string()));
+    }
+    pieces.push(Piece::Text(text));
+    args.next().is_none().then_some(pieces)
+}
+
+/// Rust `format!` fields matched to their arguments: `{}`, `{0}`, `{name}`
+/// and inline `{ident}`, with any `:spec`.
+fn format_pieces(format: &str, args: &[&str]) -> Option<Vec<Piece>> {
+    let named = |name: &str| {
+        args.iter().find_map(|a| {
+            let (key, value) = a.split_once('=')?;
+            (key.trim() == name && !value.starts_with('=')).then(|| value.trim().to_string())
+        })
+    };
+    let positional: Vec<&str> = args.iter().copied().filter(|a| !a.contains('=') || a.contains("==")).collect();
+    let mut next = 0;
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    let mut i = 0;
+    while let Some(c) = format[i..].chars().next() {
+        let after = format[i + c.len_utf8()..].chars().next();
+        match c {
+            '{' | '}' if after == Some(c) => {
+                text.push(c);
+                i += 2;
+            }
+            '{' => {
+                let close = i + format[i..].find('}')?;
+                let name = format[i + 1..close].split(':').next()?.trim();
+                let param = if name.is_empty() {
+                    next += 1;
+                    positional.get(next - 1)?.trim().to_string()
+                } else if let Ok(index) = name.parse::<usize>() {
+                    positional.get(index)?.trim().to_string()
+                } else {
+                    named(name).unwrap_or_else(|| name.to_string())
+                };
+                pieces.push(Piece::Text(std::mem::take(&mut text)));
+                pieces.push(Piece::Param(param));
+                i = close + 1;
+            }
+            _ => {
+                text.push(c);
+                i += c.len_utf8();
+            }
+        }
+    }
+    pieces.push(Piece::Text(text));
+    Some(pieces)
+}
+
+/// Byte index of the bracket closing the one at `open`, skipping string
+/// literals.
+fn closing(line: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in line[open..].char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' | '`' => quote = Some(c),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Top-level comma-separated arguments, skipping string literals.
+fn split_args(args: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '`' => quote = Some(c),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&args[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if !args[start..].trim().is_empty() {
+        parts.push(&args[start..]);
+    }
+    parts
+}
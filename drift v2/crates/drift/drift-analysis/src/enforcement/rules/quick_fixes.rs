@@ -1,5 +1,10 @@
 //! Quick-fix generator — 7 fix strategies for violations.
 
+use std::path::Path;
+
+use crate::scanner::language_detect::Language;
+
+use super::parameterize::{parameterize, ParameterizedQuery};
 use super::types::*;
 
 /// Generates quick-fix suggestions for violations.
@@ -29,6 +34,42 @@ impl QuickFixGenerator {
             strategy,
             description,
             replacement,
+            range: None,
+        })
+    }
+
+    /// Like [`suggest`](Self::suggest), but with the text of the outlier's
+    /// line. An SQL injection built from an interpolated literal on that
+    /// line gets a concrete rewrite to a parameterized query, with the range
+    /// it replaces, instead of the generic template.
+    pub fn suggest_for_line(
+        &self,
+        pattern: &PatternInfo,
+        outlier: &OutlierLocation,
+        line: Option<&str>,
+    ) -> Option<QuickFix> {
+        let fix = self.suggest(pattern, outlier)?;
+        if fix.strategy != QuickFixStrategy::UseParameterizedQuery || !is_sql_injection(pattern) {
+            return Some(fix);
+        }
+        let extension = Path::new(&outlier.file).extension().and_then(|e| e.to_str());
+        let rewrite = line
+            .zip(Language::from_extension(extension))
+            .and_then(|(line, language)| Some((parameterize(line, language)?, language)));
+        let Some((query, language)) = rewrite else {
+            return Some(fix);
+        };
+
+        Some(QuickFix {
+            strategy: fix.strategy,
+            description: parameterized_description(&query, language),
+            replacement: Some(parameterized_replacement(&query, language)),
+            range: Some(FixRange {
+                start_line: outlier.line,
+                start_column: query.start_column,
+                end_line: outlier.line,
+                end_column: query.end_column,
+            }),
         })
     }
 
@@ -172,6 +213,44 @@ impl QuickFixGenerator {
     }
 }
 
+fn is_sql_injection(pattern: &PatternInfo) -> bool {
+    pattern.cwe_ids.contains(&89) || pattern.pattern_id.to_ascii_lowercase().contains("sql")
+}
+
+/// The query's parameters in the form its driver takes them.
+fn bind_arguments(query: &ParameterizedQuery, language: Language) -> String {
+    let params = query.params.join(", ");
+    match language {
+        Language::Python if query.params.len() == 1 => format!("({params},)"),
+        Language::Python => format!("({params})"),
+        Language::Go => params,
+        Language::Rust => query.params.iter().map(|p| format!(".bind({p})")).collect(),
+        _ => format!("[{params}]"),
+    }
+}
+
+/// The literal with placeholders, followed by its parameters when it is
+/// passed straight to a call. sqlx-style Rust binds through methods on the
+/// query, so only the literal is replaced there.
+fn parameterized_replacement(query: &ParameterizedQuery, language: Language) -> String {
+    if query.in_call && language != Language::Rust {
+        format!("{}, {}", query.sql, bind_arguments(query, language))
+    } else {
+        query.sql.clone()
+    }
+}
+
+fn parameterized_description(query: &ParameterizedQuery, language: Language) -> String {
+    let binds = bind_arguments(query, language);
+    if language == Language::Rust {
+        format!("Use a parameterized query: replace the interpolated SQL with placeholders and chain {binds} onto the query")
+    } else if query.in_call {
+        format!("Use a parameterized query: pass {binds} as bound parameters instead of interpolating them into the SQL")
+    } else {
+        format!("Use a parameterized query: replace the interpolated SQL with placeholders and pass {binds} as bound parameters where it is executed")
+    }
+}
+
 impl Default for QuickFixGenerator {
     fn default() -> Self {
         Self::new()
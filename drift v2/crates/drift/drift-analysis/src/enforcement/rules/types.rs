@@ -43,6 +43,21 @@ pub struct QuickFix {
     pub description: String,
     /// The replacement text, if applicable.
     pub replacement: Option<String>,
+    /// Span `replacement` is meant to replace, when the fix was derived
+    /// from the source itself rather than a generic template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<FixRange>,
+}
+
+/// Span of source text a quick fix edits. Lines are 1-based like
+/// `Violation::line`; columns are 0-based byte offsets into the line, end
+/// exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixRange {
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
 }
 
 /// The 8 quick-fix strategies.
@@ -97,6 +112,10 @@ pub struct Violation {
     pub suppressed: bool,
     /// Whether this violation was introduced by the current change.
     pub is_new: bool,
+    /// Content-based fingerprint when the source line was available
+    /// (see `enforcement::fingerprint::violation_fingerprint`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
 }
 
 /// Input data for the rules evaluator.
@@ -106,7 +125,8 @@ pub struct RulesInput {
     pub patterns: Vec<PatternInfo>,
     /// Source file contents for suppression checking.
     pub source_lines: std::collections::HashMap<String, Vec<String>>,
-    /// Baseline violation keys (format: "file:line:rule_id") for is_new detection.
+    /// Baseline violation keys (format: "file:line:rule_id", or a finding
+    /// fingerprint) for is_new detection.
     pub baseline_violation_ids: std::collections::HashSet<String>,
 }
 
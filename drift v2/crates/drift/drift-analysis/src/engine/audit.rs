@@ -0,0 +1,371 @@
+//! Determinism audit — run the full pipeline twice and diff the outputs.
+//!
+//! Baselines, fingerprints and snapshot tests all assume that the same tree
+//! produces the same findings in the same order. [`verify_determinism`] runs
+//! scan → parse → detection → pattern intelligence → rules → gates →
+//! reporters twice over one root and reports every difference between the
+//! two passes: findings present in only one pass, findings or scores
+//! emitted in a different order, changed confidence scores, and reporter
+//! output that is not byte-identical. Hash-map iteration order and clock
+//! readings leaking into output show up here.
+//!
+//! Inputs that legitimately vary are held fixed: both passes share one
+//! `now`, and gate execution times are zeroed before the reporters run.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use drift_core::config::DriftConfig;
+use drift_core::errors::{GateError, PipelineError};
+use drift_core::events::handler::DriftEventHandler;
+use drift_core::types::collections::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::detectors::registry::create_registry;
+use crate::enforcement::gates::{GateInputBuilder, GateOrchestrator};
+use crate::enforcement::reporters;
+use crate::enforcement::rules::{OutlierLocation, PatternInfo, PatternLocation, RulesEvaluator, RulesInput};
+use crate::parsers::ParserManager;
+use crate::patterns::aggregation::types::AggregatedPattern;
+use crate::patterns::pipeline::{PatternIntelligencePipeline, PipelineResult};
+use crate::scanner::Scanner;
+
+use super::pipeline::AnalysisPipeline;
+use super::resolution::ResolutionIndex;
+use super::types::PatternMatch;
+use super::visitor::{DetectionContext, DetectionEngine, VisitorRegistry};
+
+/// What differed between the two passes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DifferenceKind {
+    /// A finding was produced by one pass only.
+    Findings,
+    /// The same findings or scores were emitted in a different order.
+    Ordering,
+    /// A pattern's confidence score changed, or was scored in one pass only.
+    Scores,
+    /// The named reporter format rendered different output.
+    Report(String),
+}
+
+/// One difference between the two passes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Difference {
+    pub kind: DifferenceKind,
+    pub detail: String,
+}
+
+/// Outcome of a determinism audit.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeterminismReport {
+    pub files: usize,
+    /// Findings (pattern matches and rule violations) in the first pass.
+    pub findings: usize,
+    /// Rule violations (pattern outliers) in the first pass.
+    pub violations: usize,
+    /// Reporter formats compared.
+    pub reports: Vec<String>,
+    pub differences: Vec<Difference>,
+}
+
+impl DeterminismReport {
+    /// Whether the two passes agreed on everything compared.
+    pub fn is_deterministic(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+/// Everything one pass produced, rendered to comparable strings.
+struct Snapshot {
+    files: usize,
+    violations: usize,
+    findings: Vec<String>,
+    /// `(pattern_id, score)` in emission order.
+    scores: Vec<(String, String)>,
+    /// `(format, output)` for every available reporter.
+    reports: Vec<(String, String)>,
+}
+
+struct Silent;
+impl DriftEventHandler for Silent {}
+
+/// Run the full pipeline over `root` twice and report any differences.
+pub fn verify_determinism(root: &Path, config: &DriftConfig) -> Result<DeterminismReport, PipelineError> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let first = run_pass(root, config, now)?;
+    let second = run_pass(root, config, now)?;
+
+    let mut differences = Vec::new();
+    compare_findings(&first.findings, &second.findings, &mut differences);
+    compare_scores(&first.scores, &second.scores, &mut differences);
+    compare_reports(&first.reports, &second.reports, &mut differences);
+    if first.files != second.files {
+        differences.push(Difference {
+            kind: DifferenceKind::Findings,
+            detail: format!("pass 1 analyzed {} files, pass 2 analyzed {}", first.files, second.files),
+        });
+    }
+
+    Ok(DeterminismReport {
+        files: first.files,
+        findings: first.findings.len(),
+        violations: first.violations,
+        reports: first.reports.into_iter().map(|(format, _)| format).collect(),
+        differences,
+    })
+}
+
+fn run_pass(root: &Path, config: &DriftConfig, now: u64) -> Result<Snapshot, PipelineError> {
+    let diff = Scanner::new(config.scan.clone()).scan(root, &FxHashMap::default(), &Silent)?;
+
+    let parser = ParserManager::new()
+        .with_max_depth(config.scan.effective_max_ast_depth())
+        .with_visibility_policies(&config.scan.visibility);
+    let mut detectors = create_registry(&config.analysis.detectors);
+    detectors.exclude_tests_from(&config.analysis.exclude_tests_from);
+    let mut pipeline = AnalysisPipeline::with_engine(DetectionEngine::new(VisitorRegistry::new()));
+    let mut resolution_index = ResolutionIndex::new();
+    let mut matches: Vec<PatternMatch> = Vec::new();
+    let mut source_lines: HashMap<String, Vec<String>> = HashMap::new();
+    let mut files = Vec::new();
+
+    for path in &diff.added {
+        let full_path = if path.is_absolute() { path.clone() } else { root.join(path) };
+        let Ok(source) = std::fs::read(&full_path) else {
+            continue;
+        };
+        let Ok((parse_result, tree)) = parser.parse_returning_tree(&source, &full_path) else {
+            continue;
+        };
+        let result = pipeline.analyze_file(&parse_result, &source, &tree, &mut resolution_index);
+        matches.extend(result.matches);
+        let ctx = DetectionContext::from_parse_result(&parse_result, &source);
+        let is_test_file = diff.entries.get(path).is_some_and(|e| e.is_test_file);
+        matches.extend(detectors.run_all_for(&ctx, is_test_file));
+
+        let text = String::from_utf8_lossy(&source);
+        source_lines.insert(parse_result.file.clone(), text.lines().map(String::from).collect());
+        files.push(parse_result.file);
+    }
+
+    let intelligence = PatternIntelligencePipeline::new().run(&matches, files.len() as u64, now, None);
+    let scores: Vec<(String, String)> = intelligence
+        .scores
+        .iter()
+        .map(|(id, score)| (id.clone(), serde_json::to_string(score).unwrap_or_default()))
+        .collect();
+    let posterior: HashMap<&str, f64> = intelligence
+        .scores
+        .iter()
+        .map(|(id, score)| (id.as_str(), score.posterior_mean))
+        .collect();
+    let mut outliers = outlier_locations(&intelligence);
+
+    // Grouped by pattern id in sorted order, so the audit itself adds no
+    // ordering noise.
+    let mut grouped: BTreeMap<&str, PatternInfo> = BTreeMap::new();
+    for m in &matches {
+        let info = grouped.entry(m.pattern_id.as_str()).or_insert_with(|| PatternInfo {
+            pattern_id: m.pattern_id.clone(),
+            category: m.category.name().to_string(),
+            confidence: posterior.get(m.pattern_id.as_str()).copied().unwrap_or(m.confidence as f64),
+            locations: Vec::new(),
+            outliers: outliers.remove(m.pattern_id.as_str()).unwrap_or_default(),
+            cwe_ids: m.cwe_ids.to_vec(),
+            owasp_categories: m.owasp.iter().cloned().collect(),
+        });
+        info.locations.push(PatternLocation {
+            file: m.file.clone(),
+            line: m.line,
+            column: Some(m.column),
+        });
+    }
+    let patterns: Vec<PatternInfo> = grouped.into_values().collect();
+
+    let violations = RulesEvaluator::new().evaluate(&RulesInput {
+        patterns: patterns.clone(),
+        source_lines: source_lines.clone(),
+        baseline_violation_ids: Default::default(),
+    });
+
+    let gate_input = GateInputBuilder::new()
+        .files(files.clone())
+        .patterns(patterns)
+        .source_lines(source_lines)
+        .build();
+    let mut gate_results = GateOrchestrator::new()
+        .execute(&gate_input)
+        .map_err(|e| PipelineError::Gate(GateError::EvaluationFailed(e)))?;
+    for result in &mut gate_results {
+        result.execution_time_ms = 0;
+    }
+
+    let reports = reporters::registered_formats()
+        .into_iter()
+        .filter_map(|format| {
+            let output = reporters::create_reporter(&format)?.generate(&gate_results).ok()?;
+            Some((format, output))
+        })
+        .collect();
+
+    let findings = matches
+        .iter()
+        .map(|m| format!("match {}", serde_json::to_string(m).unwrap_or_default()))
+        .chain(violations.iter().map(|v| format!("violation {}", serde_json::to_string(v).unwrap_or_default())))
+        .collect();
+
+    Ok(Snapshot {
+        files: files.len(),
+        violations: violations.len(),
+        findings,
+        scores,
+        reports,
+    })
+}
+
+/// Confirmed outliers per pattern, located at the aggregated location they
+/// were detected on.
+fn outlier_locations(intelligence: &PipelineResult) -> HashMap<String, Vec<OutlierLocation>> {
+    let patterns: HashMap<&str, &AggregatedPattern> = intelligence
+        .aggregation
+        .patterns
+        .iter()
+        .map(|p| (p.pattern_id.as_str(), p))
+        .collect();
+    let mut located = HashMap::new();
+    for (pattern_id, results) in &intelligence.outliers {
+        let Some(pattern) = patterns.get(pattern_id.as_str()) else {
+            continue;
+        };
+        // Outlier indices point into `confidence_values`, which is sorted
+        // by confidence; order the locations the same way.
+        let mut by_confidence: Vec<_> = pattern.locations.iter().collect();
+        by_confidence.sort_by(|a, b| a.confidence.partial_cmp(&b.confidence).unwrap_or(Ordering::Equal));
+        let locations: Vec<OutlierLocation> = results
+            .iter()
+            .filter(|o| o.is_outlier)
+            .filter_map(|o| {
+                let location = by_confidence.get(o.index)?;
+                Some(OutlierLocation {
+                    file: location.file.clone(),
+                    line: location.line,
+                    column: Some(location.column),
+                    end_line: None,
+                    end_column: None,
+                    deviation_score: o.deviation_score.value(),
+                    message: format!("Confidence {:.2} deviates from pattern {pattern_id}", o.value),
+                })
+            })
+            .collect();
+        if !locations.is_empty() {
+            located.insert(pattern_id.clone(), locations);
+        }
+    }
+    located
+}
+
+fn compare_findings(first: &[String], second: &[String], differences: &mut Vec<Difference>) {
+    let mut counts: BTreeMap<&str, i64> = BTreeMap::new();
+    for finding in first {
+        *counts.entry(finding).or_default() += 1;
+    }
+    for finding in second {
+        *counts.entry(finding).or_default() -= 1;
+    }
+    let mut same_set = true;
+    for (finding, count) in counts.into_iter().filter(|(_, c)| *c != 0) {
+        same_set = false;
+        let pass = if count > 0 { 1 } else { 2 };
+        differences.push(Difference {
+            kind: DifferenceKind::Findings,
+            detail: format!("only in pass {pass}: {finding}"),
+        });
+    }
+    if same_set {
+        if let Some(i) = first_divergence(first, second) {
+            differences.push(Difference {
+                kind: DifferenceKind::Ordering,
+                detail: format!("findings diverge at position {i}: {} vs {}", first[i], second[i]),
+            });
+        }
+    }
+}
+
+fn compare_scores(first: &[(String, String)], second: &[(String, String)], differences: &mut Vec<Difference>) {
+    let first_map: BTreeMap<&str, &str> = first.iter().map(|(id, s)| (id.as_str(), s.as_str())).collect();
+    let second_map: BTreeMap<&str, &str> = second.iter().map(|(id, s)| (id.as_str(), s.as_str())).collect();
+    let mut same_scores = true;
+    for (id, score) in &first_map {
+        match second_map.get(id) {
+            Some(other) if other == score => {}
+            Some(other) => {
+                same_scores = false;
+                differences.push(Difference {
+                    kind: DifferenceKind::Scores,
+                    detail: format!("{id}: {score} vs {other}"),
+                });
+            }
+            None => {
+                same_scores = false;
+                differences.push(Difference {
+                    kind: DifferenceKind::Scores,
+                    detail: format!("{id} scored in pass 1 only"),
+                });
+            }
+        }
+    }
+    for id in second_map.keys().filter(|id| !first_map.contains_key(*id)) {
+        same_scores = false;
+        differences.push(Difference {
+            kind: DifferenceKind::Scores,
+            detail: format!("{id} scored in pass 2 only"),
+        });
+    }
+    if same_scores {
+        if let Some(i) = first_divergence(first, second) {
+            differences.push(Difference {
+                kind: DifferenceKind::Ordering,
+                detail: format!("scores diverge at position {i}: {} vs {}", first[i].0, second[i].0),
+            });
+        }
+    }
+}
+
+fn compare_reports(first: &[(String, String)], second: &[(String, String)], differences: &mut Vec<Difference>) {
+    for (format, output) in first {
+        let Some((_, other)) = second.iter().find(|(f, _)| f == format) else {
+            differences.push(Difference {
+                kind: DifferenceKind::Report(format.clone()),
+                detail: "rendered in pass 1 only".to_string(),
+            });
+            continue;
+        };
+        if output == other {
+            continue;
+        }
+        let first_lines: Vec<&str> = output.lines().collect();
+        let second_lines: Vec<&str> = other.lines().collect();
+        let i = first_divergence(&first_lines, &second_lines).unwrap_or(0);
+        differences.push(Difference {
+            kind: DifferenceKind::Report(format.clone()),
+            detail: format!(
+                "line {}: {:?} vs {:?}",
+                i + 1,
+                first_lines.get(i).copied().unwrap_or(""),
+                second_lines.get(i).copied().unwrap_or(""),
+            ),
+        });
+    }
+}
+
+/// Index of the first position where the sequences differ, if they do.
+fn first_divergence<T: PartialEq>(first: &[T], second: &[T]) -> Option<usize> {
+    first
+        .iter()
+        .zip(second)
+        .position(|(a, b)| a != b)
+        .or_else(|| (first.len() != second.len()).then(|| first.len().min(second.len())))
+}
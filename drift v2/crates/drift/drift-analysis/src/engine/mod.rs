@@ -11,7 +11,11 @@ pub mod regex_engine;
 pub mod resolution;
 pub mod incremental;
 pub mod toml_patterns;
+pub mod ts_query;
 pub mod gast;
+pub mod audit;
+pub mod orchestrator;
+pub mod patch;
 
 pub use types::{AnalysisResult, PatternMatch, PatternCategory, DetectionMethod, AnalysisPhase};
 pub use visitor::{DetectorHandler, FileDetectorHandler, LearningDetectorHandler, DetectionContext, DetectionEngine, VisitorRegistry};
@@ -19,3 +23,7 @@ pub use pipeline::AnalysisPipeline;
 pub use resolution::ResolutionIndex;
 pub use incremental::IncrementalAnalyzer;
 pub use toml_patterns::{TomlPatternLoader, CompiledQuery};
+pub use ts_query::UserQuery;
+pub use audit::{verify_determinism, DeterminismReport};
+pub use orchestrator::{findings_for_file, FileFindings};
+pub use patch::{analyze_patch, PatchAnalysis};
@@ -0,0 +1,156 @@
+//! Per-file view over one analysis run.
+//!
+//! Each subsystem reports into its own collection (pattern matches per file,
+//! taint flows, crypto and secret findings, constraint verifications).
+//! [`findings_for_file`] gathers everything that lands in one file into a
+//! single line-sorted list, so an editor or file view does not have to
+//! filter each collection itself.
+
+use serde::{Deserialize, Serialize};
+
+use crate::graph::taint::TaintFlow;
+use crate::structural::constants::Secret;
+use crate::structural::constraints::{ConstraintViolation, VerificationResult};
+use crate::structural::crypto::CryptoFinding;
+
+use super::types::{AnalysisResult, PatternMatch};
+
+/// Everything one analysis run produced, across subsystems.
+#[derive(Debug, Clone, Default)]
+pub struct RunResults {
+    /// Per-file engine results; their pattern matches are collected.
+    pub files: Vec<AnalysisResult>,
+    pub taint_flows: Vec<TaintFlow>,
+    pub crypto: Vec<CryptoFinding>,
+    pub secrets: Vec<Secret>,
+    pub constraints: Vec<VerificationResult>,
+    pub complexity: Vec<ComplexityWarning>,
+}
+
+/// A function whose complexity metric exceeds its threshold.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComplexityWarning {
+    pub file: String,
+    pub function: String,
+    /// First line of the function (0-based, as in `FunctionInfo`).
+    pub line: u32,
+    /// Metric name, e.g. `cyclomatic` or `source_lines`.
+    pub metric: String,
+    pub value: f64,
+    pub threshold: f64,
+}
+
+/// One finding in a file, tagged with the subsystem that produced it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+pub enum Finding {
+    Pattern(PatternMatch),
+    /// A taint flow whose source or sink is in the file.
+    Taint(TaintFlow),
+    Crypto(CryptoFinding),
+    Secret(Secret),
+    Constraint {
+        constraint_id: String,
+        violation: ConstraintViolation,
+    },
+    Complexity(ComplexityWarning),
+}
+
+/// A finding with its position in the file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileFinding {
+    /// 1-based line; 0 for file-level findings. Subsystems that report
+    /// 0-based parser lines are shifted to match crypto and secret findings.
+    pub line: u32,
+    /// 1-based column, when the subsystem reports one.
+    pub column: Option<u32>,
+    #[serde(flatten)]
+    pub finding: Finding,
+}
+
+/// All findings for one file, sorted by line then column.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FileFindings {
+    pub file: String,
+    pub findings: Vec<FileFinding>,
+}
+
+impl FileFindings {
+    pub fn is_empty(&self) -> bool {
+        self.findings.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.findings.len()
+    }
+}
+
+/// Collect every finding in `analysis_results` that lands in `file`.
+///
+/// A taint flow is placed at its sink when the sink is in `file`, else at
+/// its source. Findings on the same position keep subsystem order.
+pub fn findings_for_file(analysis_results: &RunResults, file: &str) -> FileFindings {
+    let mut findings = Vec::new();
+
+    for m in analysis_results.files.iter().flat_map(|r| &r.matches).filter(|m| m.file == file) {
+        findings.push(FileFinding {
+            line: m.line + 1,
+            column: Some(m.column + 1),
+            finding: Finding::Pattern(m.clone()),
+        });
+    }
+    for flow in &analysis_results.taint_flows {
+        let (line, column) = if flow.sink.file == file {
+            (flow.sink.line, flow.sink.column)
+        } else if flow.source.file == file {
+            (flow.source.line, flow.source.column)
+        } else {
+            continue;
+        };
+        findings.push(FileFinding {
+            line: line + 1,
+            column: Some(column + 1),
+            finding: Finding::Taint(flow.clone()),
+        });
+    }
+    for c in analysis_results.crypto.iter().filter(|c| c.file == file) {
+        findings.push(FileFinding {
+            line: c.line,
+            column: None,
+            finding: Finding::Crypto(c.clone()),
+        });
+    }
+    for s in analysis_results.secrets.iter().filter(|s| s.file == file) {
+        findings.push(FileFinding {
+            line: s.line,
+            column: None,
+            finding: Finding::Secret(s.clone()),
+        });
+    }
+    for result in &analysis_results.constraints {
+        for v in result.violations.iter().filter(|v| v.file == file) {
+            findings.push(FileFinding {
+                line: v.line.map_or(0, |l| l + 1),
+                column: None,
+                finding: Finding::Constraint {
+                    constraint_id: result.constraint_id.clone(),
+                    violation: v.clone(),
+                },
+            });
+        }
+    }
+    for w in analysis_results.complexity.iter().filter(|w| w.file == file) {
+        findings.push(FileFinding {
+            line: w.line + 1,
+            column: None,
+            finding: Finding::Complexity(w.clone()),
+        });
+    }
+
+    // Stable: equal positions keep the subsystem order above.
+    findings.sort_by_key(|f| (f.line, f.column.unwrap_or(0)));
+    FileFindings {
+        file: file.to_string(),
+        findings,
+    }
+}
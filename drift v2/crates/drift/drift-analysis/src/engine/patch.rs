@@ -0,0 +1,221 @@
+//! Patch analysis — findings for the lines a diff changes (pre-commit mode).
+//!
+//! A pre-commit hook cannot afford a scan. [`analyze_patch`] reads the
+//! unified diff of the staged changes, re-parses only the files it touches
+//! and runs the detectors on their new content, keeping only findings on
+//! added lines. Functions touched by the diff are reported by name: those
+//! covering an added line in the new parse, and those covering a removed
+//! line in the base parse.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::detectors::registry::create_default_registry;
+use crate::parsers::diff::ParseDiff;
+use crate::parsers::manager::ParserManager;
+use crate::parsers::types::{FunctionInfo, ParseResult};
+
+use super::types::PatternMatch;
+use super::visitor::DetectionContext;
+
+/// Changed lines of one file in a unified diff.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilePatch {
+    /// Path before the change; `None` for a new file.
+    pub old_path: Option<String>,
+    /// Path after the change; `None` for a deleted file.
+    pub new_path: Option<String>,
+    /// Added lines, 0-based in the new file.
+    pub added_lines: Vec<u32>,
+    /// Removed lines, 0-based in the old file.
+    pub removed_lines: Vec<u32>,
+}
+
+/// One re-analyzed file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatchedFile {
+    pub path: String,
+    /// Added lines, 0-based.
+    pub added_lines: Vec<u32>,
+    /// Functions touched by the diff (methods as `Class.method`), sorted.
+    pub touched_functions: Vec<String>,
+    /// Symbol changes against the base parse, when the file had one.
+    pub symbol_changes: Option<ParseDiff>,
+}
+
+/// Result of [`analyze_patch`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PatchAnalysis {
+    pub files: Vec<PatchedFile>,
+    /// Findings on added lines, by file then line.
+    pub findings: Vec<PatternMatch>,
+    /// Files in the diff that were not analyzed, with the reason.
+    pub skipped: Vec<(String, String)>,
+}
+
+/// Analyze a unified diff (`git diff --cached`).
+///
+/// `base_parse_results` are the parses of the files before the change (from
+/// the last analysis); `new_content` returns a changed file's content after
+/// the change, e.g. from the index with `git show :<path>`. Files are
+/// matched by the paths in the diff, without their `a/`/`b/` prefixes.
+pub fn analyze_patch(
+    unified_diff: &str,
+    base_parse_results: &[ParseResult],
+    new_content: impl Fn(&str) -> Option<Vec<u8>>,
+) -> PatchAnalysis {
+    let parser = ParserManager::new();
+    let registry = create_default_registry();
+    let mut analysis = PatchAnalysis::default();
+
+    for patch in parse_unified_diff(unified_diff) {
+        let base = patch
+            .old_path
+            .as_deref()
+            .and_then(|old| base_parse_results.iter().find(|pr| pr.file == old));
+        let mut touched: BTreeSet<String> = BTreeSet::new();
+        if let Some(base) = base {
+            touched.extend(functions_covering(base, &patch.removed_lines));
+        }
+
+        let Some(path) = patch.new_path.clone() else {
+            let old = patch.old_path.clone().unwrap_or_default();
+            analysis.skipped.push((old, "file deleted".to_string()));
+            continue;
+        };
+        let Some(source) = new_content(&path) else {
+            analysis.skipped.push((path, "new content unavailable".to_string()));
+            continue;
+        };
+        let parsed = match parser.parse(&source, Path::new(&path)) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                analysis.skipped.push((path, e.to_string()));
+                continue;
+            }
+        };
+        touched.extend(functions_covering(&parsed, &patch.added_lines));
+
+        if !patch.added_lines.is_empty() {
+            let ctx = DetectionContext::from_parse_result(&parsed, &source);
+            analysis.findings.extend(
+                registry
+                    .run_all(&ctx)
+                    .into_iter()
+                    .filter(|m| patch.added_lines.binary_search(&m.line).is_ok()),
+            );
+        }
+        analysis.files.push(PatchedFile {
+            path,
+            added_lines: patch.added_lines,
+            touched_functions: touched.into_iter().collect(),
+            symbol_changes: base.map(|base| parsed.diff(base)),
+        });
+    }
+
+    analysis
+        .findings
+        .sort_by(|a, b| (&a.file, a.line, a.column).cmp(&(&b.file, b.line, b.column)));
+    analysis
+}
+
+/// Parse a unified diff into per-file changed lines.
+pub fn parse_unified_diff(diff: &str) -> Vec<FilePatch> {
+    let mut patches: Vec<FilePatch> = Vec::new();
+    // Remaining old/new lines of the current hunk, and the next line numbers.
+    let (mut old_left, mut new_left) = (0u32, 0u32);
+    let (mut old_line, mut new_line) = (0u32, 0u32);
+
+    for line in diff.lines() {
+        if old_left > 0 || new_left > 0 {
+            let Some(current) = patches.last_mut() else {
+                break;
+            };
+            match line.as_bytes().first() {
+                Some(b'+') => {
+                    current.added_lines.push(new_line);
+                    new_line += 1;
+                    new_left = new_left.saturating_sub(1);
+                }
+                Some(b'-') => {
+                    current.removed_lines.push(old_line);
+                    old_line += 1;
+                    old_left = old_left.saturating_sub(1);
+                }
+                Some(b'\\') => {}
+                _ => {
+                    old_line += 1;
+                    new_line += 1;
+                    old_left = old_left.saturating_sub(1);
+                    new_left = new_left.saturating_sub(1);
+                }
+            }
+            continue;
+        }
+
+        if let Some(old) = line.strip_prefix("--- ") {
+            patches.push(FilePatch {
+                old_path: diff_path(old, "a/"),
+                ..Default::default()
+            });
+        } else if let Some(new) = line.strip_prefix("+++ ") {
+            if let Some(current) = patches.last_mut() {
+                current.new_path = diff_path(new, "b/");
+            }
+        } else if let Some(header) = line.strip_prefix("@@ ") {
+            let Some((old, new)) = hunk_ranges(header) else {
+                continue;
+            };
+            // Hunk starts are 1-based; a zero start means an empty side.
+            (old_line, old_left) = (old.0.saturating_sub(1), old.1);
+            (new_line, new_left) = (new.0.saturating_sub(1), new.1);
+        }
+    }
+
+    patches
+}
+
+/// `(start, count)` of the old and new side of `-a,b +c,d @@`.
+fn hunk_ranges(header: &str) -> Option<((u32, u32), (u32, u32))> {
+    let mut parts = header.split_whitespace();
+    let old = range(parts.next()?.strip_prefix('-')?)?;
+    let new = range(parts.next()?.strip_prefix('+')?)?;
+    Some((old, new))
+}
+
+fn range(text: &str) -> Option<(u32, u32)> {
+    match text.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((text.parse().ok()?, 1)),
+    }
+}
+
+/// Path of a `---`/`+++` header, without its prefix or a trailing timestamp.
+fn diff_path(text: &str, prefix: &str) -> Option<String> {
+    let path = text.split('\t').next().unwrap_or(text).trim();
+    if path == "/dev/null" {
+        return None;
+    }
+    Some(path.strip_prefix(prefix).unwrap_or(path).to_string())
+}
+
+/// Names of the functions and methods whose range contains one of `lines`.
+fn functions_covering(pr: &ParseResult, lines: &[u32]) -> Vec<String> {
+    let covers = |f: &FunctionInfo| lines.iter().any(|&l| f.line <= l && l <= f.end_line);
+    // Parsers may list methods among the functions as well.
+    let method_lines: Vec<u32> = pr.classes.iter().flat_map(|c| c.methods.iter().map(|m| m.line)).collect();
+    let methods = pr.classes.iter().flat_map(|c| {
+        c.methods
+            .iter()
+            .filter(|m| covers(m))
+            .map(move |m| format!("{}.{}", c.name, m.name))
+    });
+    pr.functions
+        .iter()
+        .filter(|f| covers(f) && !method_lines.contains(&f.line))
+        .map(|f| f.name.clone())
+        .chain(methods)
+        .collect()
+}
@@ -0,0 +1,290 @@
+//! User-defined tree-sitter queries — custom AST patterns without writing Rust.
+//!
+//! Queries are loaded from `.drift/queries/*.scm`. Each file starts with a
+//! comment header carrying rule metadata, followed by the S-expression query:
+//!
+//! ```text
+//! ; id: no-eval
+//! ; language: javascript
+//! ; severity: error
+//! ; category: security
+//! ; message: eval() executes arbitrary code
+//! (call_expression function: (identifier) @fn (#eq? @fn "eval"))
+//! ```
+//!
+//! Required header keys: `id`, `language`. Optional: `severity` (default
+//! `warning`), `category` (default `structural`), `confidence` (default 0.70),
+//! `message`, `cwe` (comma-separated), `owasp`, and `capture` (the capture
+//! reported as the match location; defaults to the first capture).
+
+use std::path::Path;
+
+use smallvec::SmallVec;
+use tree_sitter::{Query, QueryCursor, StreamingIterator, Tree};
+
+use drift_core::errors::DetectionError;
+
+use super::types::{DetectionMethod, PatternCategory, PatternMatch};
+use crate::enforcement::rules::Severity;
+use crate::scanner::language_detect::Language;
+
+/// A compiled user-defined tree-sitter query.
+#[derive(Debug)]
+pub struct UserQuery {
+    pub id: String,
+    pub language: Language,
+    pub severity: Severity,
+    pub category: PatternCategory,
+    pub confidence: f32,
+    pub message: Option<String>,
+    pub cwe_ids: SmallVec<[u32; 2]>,
+    pub owasp: Option<String>,
+    query: Query,
+    report_capture: Option<u32>,
+}
+
+impl UserQuery {
+    /// Compile a query S-expression against a language with explicit metadata.
+    pub fn compile(
+        id: &str,
+        language: Language,
+        severity: Severity,
+        source: &str,
+    ) -> Result<Self, DetectionError> {
+        let query = Query::new(&language.ts_language(), source).map_err(|e| {
+            DetectionError::QueryCompilationFailed(format!(
+                "query '{id}' (line {}, column {}): {}",
+                e.row + 1,
+                e.column + 1,
+                e.message
+            ))
+        })?;
+
+        Ok(Self {
+            id: id.to_string(),
+            language,
+            severity,
+            category: PatternCategory::Structural,
+            confidence: default_confidence(),
+            message: None,
+            cwe_ids: SmallVec::new(),
+            owasp: None,
+            query,
+            report_capture: None,
+        })
+    }
+
+    /// Parse a `.scm` file body: metadata header followed by the query.
+    pub fn load_from_str(content: &str) -> Result<Self, DetectionError> {
+        let header = QueryHeader::parse(content)?;
+        let id = header.id.ok_or_else(|| {
+            DetectionError::InvalidPattern("query header is missing 'id'".to_string())
+        })?;
+        let language = header.language.ok_or_else(|| {
+            DetectionError::InvalidPattern(format!("query '{id}' header is missing 'language'"))
+        })?;
+
+        let mut query = Self::compile(&id, language, header.severity.unwrap_or(Severity::Warning), content)?;
+        if let Some(category) = header.category {
+            query.category = category;
+        }
+        if let Some(confidence) = header.confidence {
+            query.confidence = confidence;
+        }
+        query.message = header.message;
+        query.cwe_ids = header.cwe_ids;
+        query.owasp = header.owasp;
+
+        if let Some(name) = header.capture {
+            let index = query.query.capture_index_for_name(&name).ok_or_else(|| {
+                DetectionError::InvalidPattern(format!(
+                    "query '{id}' reports unknown capture '@{name}'"
+                ))
+            })?;
+            query.report_capture = Some(index);
+        }
+
+        Ok(query)
+    }
+
+    /// Load a single query from a `.scm` file.
+    pub fn load_from_file(path: &Path) -> Result<Self, DetectionError> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            DetectionError::InvalidPattern(format!("failed to read {}: {e}", path.display()))
+        })?;
+        Self::load_from_str(&content)
+    }
+
+    /// Load every `*.scm` query in a directory (e.g. `.drift/queries/`).
+    ///
+    /// Invalid queries are skipped with a warning so one bad file does not
+    /// disable the rest. A missing directory yields no queries.
+    pub fn load_dir(dir: &Path) -> Vec<Self> {
+        let mut queries = Vec::new();
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return queries;
+        };
+
+        let mut paths: Vec<_> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "scm"))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            match Self::load_from_file(&path) {
+                Ok(query) => queries.push(query),
+                Err(e) => {
+                    eprintln!("[drift] warning: failed to load query '{}': {e}", path.display());
+                }
+            }
+        }
+        queries
+    }
+
+    /// Run the query over a parsed tree, emitting one match per query match.
+    ///
+    /// The caller is responsible for passing a tree of the same language.
+    pub fn run(&self, tree: &Tree, source: &[u8], file: &str) -> Vec<PatternMatch> {
+        let mut results = Vec::new();
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&self.query, tree.root_node(), source);
+
+        while let Some(m) = matches.next() {
+            let capture = match self.report_capture {
+                Some(index) => m.captures.iter().find(|c| c.index == index),
+                None => m.captures.first(),
+            };
+            let Some(capture) = capture else {
+                continue;
+            };
+
+            let node = capture.node;
+            let text = node.utf8_text(source).unwrap_or("");
+            let matched_text = match &self.message {
+                Some(message) => format!("{message}: {text}"),
+                None => text.to_string(),
+            };
+
+            results.push(PatternMatch {
+                file: file.to_string(),
+                line: node.start_position().row as u32,
+                column: node.start_position().column as u32,
+                pattern_id: self.id.clone(),
+                confidence: self.confidence,
+                cwe_ids: self.cwe_ids.clone(),
+                owasp: self.owasp.clone(),
+                detection_method: DetectionMethod::UserQuery,
+                category: self.category,
+                matched_text,
+            });
+        }
+
+        results
+    }
+}
+
+fn default_confidence() -> f32 {
+    0.70
+}
+
+/// Metadata parsed from the leading `; key: value` comment lines.
+#[derive(Default)]
+struct QueryHeader {
+    id: Option<String>,
+    language: Option<Language>,
+    severity: Option<Severity>,
+    category: Option<PatternCategory>,
+    confidence: Option<f32>,
+    message: Option<String>,
+    cwe_ids: SmallVec<[u32; 2]>,
+    owasp: Option<String>,
+    capture: Option<String>,
+}
+
+impl QueryHeader {
+    fn parse(content: &str) -> Result<Self, DetectionError> {
+        let mut header = Self::default();
+
+        for line in content.lines().map(str::trim) {
+            if line.is_empty() {
+                continue;
+            }
+            let Some(comment) = line.strip_prefix(';') else {
+                break;
+            };
+            let Some((key, value)) = comment.trim_start_matches(';').split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+
+            match key.trim() {
+                "id" => header.id = Some(value.to_string()),
+                "language" => {
+                    header.language = Some(parse_language(value).ok_or_else(|| {
+                        DetectionError::InvalidPattern(format!("unknown query language '{value}'"))
+                    })?);
+                }
+                "severity" => {
+                    header.severity = Some(parse_severity(value).ok_or_else(|| {
+                        DetectionError::InvalidPattern(format!("unknown query severity '{value}'"))
+                    })?);
+                }
+                "category" => {
+                    header.category = Some(PatternCategory::parse_str(value).ok_or_else(|| {
+                        DetectionError::InvalidPattern(format!("unknown query category '{value}'"))
+                    })?);
+                }
+                "confidence" => {
+                    header.confidence = Some(value.parse::<f32>().map_err(|_| {
+                        DetectionError::InvalidPattern(format!("invalid query confidence '{value}'"))
+                    })?);
+                }
+                "message" => header.message = Some(value.to_string()),
+                "cwe" => {
+                    for id in value.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+                        header.cwe_ids.push(id.trim_start_matches("CWE-").parse().map_err(|_| {
+                            DetectionError::InvalidPattern(format!("invalid CWE id '{id}'"))
+                        })?);
+                    }
+                }
+                "owasp" => header.owasp = Some(value.to_string()),
+                "capture" => header.capture = Some(value.trim_start_matches('@').to_string()),
+                _ => {}
+            }
+        }
+
+        Ok(header)
+    }
+}
+
+/// Resolve a language from its display name or a file extension.
+fn parse_language(s: &str) -> Option<Language> {
+    let lower = s.to_lowercase();
+    if let Some(lang) = Language::from_extension(Some(&lower)) {
+        return Some(lang);
+    }
+    match lower.as_str() {
+        "typescript" => Some(Language::TypeScript),
+        "javascript" => Some(Language::JavaScript),
+        "python" => Some(Language::Python),
+        "java" => Some(Language::Java),
+        "csharp" | "c#" => Some(Language::CSharp),
+        "golang" => Some(Language::Go),
+        "rust" => Some(Language::Rust),
+        "ruby" => Some(Language::Ruby),
+        "kotlin" => Some(Language::Kotlin),
+        _ => None,
+    }
+}
+
+fn parse_severity(s: &str) -> Option<Severity> {
+    match s.to_lowercase().as_str() {
+        "error" => Some(Severity::Error),
+        "warning" | "warn" => Some(Severity::Warning),
+        "info" => Some(Severity::Info),
+        "hint" => Some(Severity::Hint),
+        _ => None,
+    }
+}
@@ -46,6 +46,8 @@ pub enum DetectionMethod {
     LearningDeviation,
     /// Semantic analysis.
     Semantic,
+    /// User-defined tree-sitter query (`.drift/queries/*.scm`).
+    UserQuery,
 }
 
 /// The 16 pattern categories.
@@ -0,0 +1,117 @@
+//! Tarjan SCC over the call graph, with per-edge break suggestions.
+
+use drift_core::types::collections::FxHashMap;
+use petgraph::graph::NodeIndex;
+use petgraph::stable_graph::EdgeIndex;
+use petgraph::visit::EdgeRef;
+
+use crate::call_graph::types::CallGraph;
+
+use super::types::{FunctionCycle, FunctionCycleBreak};
+
+/// Find every non-trivial function cycle in the call graph, largest first.
+///
+/// Each edge inside a cycle is scored by how many cycle paths it carries
+/// (fan-in of the caller × fan-out of the callee, both within the cycle).
+/// Edges whose removal alone breaks the cycle rank first, then by score.
+pub fn function_cycles(graph: &CallGraph) -> Vec<FunctionCycle> {
+    let mut cycles: Vec<FunctionCycle> = petgraph::algo::tarjan_scc(&graph.graph)
+        .into_iter()
+        .filter(|scc| scc.len() > 1)
+        .map(|scc| {
+            let mut members: Vec<String> = scc.iter().map(|&n| node_key(graph, n)).collect();
+            members.sort();
+            FunctionCycle {
+                members,
+                break_suggestions: suggest_breaks(graph, &scc),
+            }
+        })
+        .collect();
+    cycles.sort_by(|a, b| b.members.len().cmp(&a.members.len()).then_with(|| a.members.cmp(&b.members)));
+    cycles
+}
+
+fn suggest_breaks(graph: &CallGraph, scc: &[NodeIndex]) -> Vec<FunctionCycleBreak> {
+    let component = Component::new(graph, scc);
+    let mut suggestions: Vec<FunctionCycleBreak> = component
+        .edges
+        .iter()
+        .enumerate()
+        .map(|(position, &(caller, callee, edge))| FunctionCycleBreak {
+            caller: node_key(graph, scc[caller]),
+            callee: node_key(graph, scc[callee]),
+            call_site_line: graph.graph[edge].call_site_line,
+            score: (component.in_degree[caller] * component.outgoing[callee].len()) as f64,
+            breaks_cycle: component.acyclic_without(position),
+        })
+        .collect();
+    suggestions.sort_by(|a, b| {
+        b.breaks_cycle
+            .cmp(&a.breaks_cycle)
+            .then_with(|| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal))
+            .then_with(|| (&a.caller, &a.callee).cmp(&(&b.caller, &b.callee)))
+    });
+    suggestions
+}
+
+/// A strongly connected component as adjacency over member positions,
+/// built once so each candidate edge is tested in O(V + E).
+struct Component {
+    /// Internal calls (self-calls ignored): caller, callee, call graph edge.
+    edges: Vec<(usize, usize, EdgeIndex)>,
+    /// Positions in `edges` leaving each member.
+    outgoing: Vec<Vec<usize>>,
+    /// Internal calls into each member.
+    in_degree: Vec<usize>,
+}
+
+impl Component {
+    fn new(graph: &CallGraph, scc: &[NodeIndex]) -> Self {
+        let positions: FxHashMap<NodeIndex, usize> = scc.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+        let mut edges = Vec::new();
+        let mut outgoing = vec![Vec::new(); scc.len()];
+        let mut in_degree = vec![0; scc.len()];
+        for (from, &node) in scc.iter().enumerate() {
+            for edge in graph.graph.edges(node) {
+                let Some(&to) = positions.get(&edge.target()) else {
+                    continue;
+                };
+                if to == from {
+                    continue;
+                }
+                outgoing[from].push(edges.len());
+                in_degree[to] += 1;
+                edges.push((from, to, edge.id()));
+            }
+        }
+        Self { edges, outgoing, in_degree }
+    }
+
+    /// Whether the members form a DAG once the edge at `removed` is taken
+    /// out. Kahn's algorithm.
+    fn acyclic_without(&self, removed: usize) -> bool {
+        let mut in_degree = self.in_degree.clone();
+        in_degree[self.edges[removed].1] -= 1;
+        let mut ready: Vec<usize> = (0..in_degree.len()).filter(|&n| in_degree[n] == 0).collect();
+        let mut visited = 0;
+        while let Some(node) = ready.pop() {
+            visited += 1;
+            for &edge in self.outgoing[node].iter().filter(|&&e| e != removed) {
+                let to = self.edges[edge].1;
+                in_degree[to] -= 1;
+                if in_degree[to] == 0 {
+                    ready.push(to);
+                }
+            }
+        }
+        visited == in_degree.len()
+    }
+}
+
+fn node_key(graph: &CallGraph, node: NodeIndex) -> String {
+    graph
+        .graph
+        .node_weight(node)
+        .map(|n| format!("{}::{}", n.file, n.name))
+        .unwrap_or_default()
+}
@@ -0,0 +1,8 @@
+//! Function-level cycles — strongly connected components of the call graph
+//! with suggestions for the call edge to break.
+
+pub mod types;
+pub mod detection;
+
+pub use types::*;
+pub use detection::function_cycles;
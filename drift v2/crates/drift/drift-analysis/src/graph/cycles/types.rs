@@ -0,0 +1,29 @@
+//! Function cycle types.
+
+use serde::{Deserialize, Serialize};
+
+/// Functions that (transitively) call each other: a call graph SCC with
+/// more than one member. Self-recursion alone is not a cycle here.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionCycle {
+    /// Members as `file::name` keys (see `CallGraph::get_node`), sorted.
+    pub members: Vec<String>,
+    /// Call edges within the cycle, best candidate to remove first.
+    pub break_suggestions: Vec<FunctionCycleBreak>,
+}
+
+/// A call edge whose removal would untangle (part of) a function cycle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionCycleBreak {
+    /// Calling function (`file::name`).
+    pub caller: String,
+    /// Called function (`file::name`).
+    pub callee: String,
+    /// Line of the call in the caller.
+    pub call_site_line: u32,
+    /// Cycle paths routed through the edge: the caller's fan-in times the
+    /// callee's fan-out within the cycle. Higher removes more coupling.
+    pub score: f64,
+    /// Whether removing this edge alone makes the members acyclic.
+    pub breaks_cycle: bool,
+}
@@ -20,9 +20,10 @@ pub fn detect_handlers(parse_results: &[ParseResult]) -> Vec<ErrorHandler> {
                 ErrorHandlingKind::Defer => HandlerType::DeferRecover,
                 ErrorHandlingKind::DeferRecover => HandlerType::DeferRecover,
                 ErrorHandlingKind::WithStatement => HandlerType::TryCatch,
-                // Throw/QuestionMark/Unwrap are not handlers
+                // Throw/QuestionMark/TryOperator/Unwrap are not handlers
                 ErrorHandlingKind::Throw
                 | ErrorHandlingKind::QuestionMark
+                | ErrorHandlingKind::TryOperator
                 | ErrorHandlingKind::Unwrap
                 | ErrorHandlingKind::TryFinally => continue,
             };
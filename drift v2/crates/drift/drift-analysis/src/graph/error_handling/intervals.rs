@@ -0,0 +1,160 @@
+//! Allen interval relations between error-handling regions.
+//!
+//! Relates the source ranges of `ErrorHandlingInfo` entries in the same
+//! function, so a `try` nested inside another `try`'s handler, or a handler
+//! region that straddles a `with`/`defer` resource scope, can be told apart
+//! from regions that merely follow each other. Ranges are compared as
+//! half-open `[start, end)` spans of `(line, column)` positions, matching
+//! tree-sitter's node ranges.
+
+use std::cmp::Ordering;
+
+use drift_core::types::collections::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::parsers::types::{ParseResult, Position, Range};
+
+/// Index of a region in `ParseResult::error_handling`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct RegionId(pub usize);
+
+/// Allen's thirteen interval relations, read as "first `relation` second".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntervalRelation {
+    /// First ends before second starts.
+    Before,
+    /// First ends exactly where second starts.
+    Meets,
+    /// First starts first and ends inside second.
+    Overlaps,
+    /// Same start, first ends first.
+    Starts,
+    /// First lies strictly inside second.
+    During,
+    /// Same end, first starts later.
+    Finishes,
+    /// Identical ranges.
+    Equals,
+    /// Inverse of `Before`.
+    After,
+    /// Inverse of `Meets`.
+    MetBy,
+    /// Inverse of `Overlaps`.
+    OverlappedBy,
+    /// Inverse of `Starts`.
+    StartedBy,
+    /// Second lies strictly inside first.
+    Contains,
+    /// Inverse of `Finishes`.
+    FinishedBy,
+}
+
+impl IntervalRelation {
+    /// The relation with the operands swapped.
+    pub fn inverse(self) -> Self {
+        match self {
+            Self::Before => Self::After,
+            Self::Meets => Self::MetBy,
+            Self::Overlaps => Self::OverlappedBy,
+            Self::Starts => Self::StartedBy,
+            Self::During => Self::Contains,
+            Self::Finishes => Self::FinishedBy,
+            Self::Equals => Self::Equals,
+            Self::After => Self::Before,
+            Self::MetBy => Self::Meets,
+            Self::OverlappedBy => Self::Overlaps,
+            Self::StartedBy => Self::Starts,
+            Self::Contains => Self::During,
+            Self::FinishedBy => Self::Finishes,
+        }
+    }
+
+    /// Whether the ranges share any position (everything but
+    /// `Before`/`After`/`Meets`/`MetBy`).
+    pub fn intersects(self) -> bool {
+        !matches!(self, Self::Before | Self::After | Self::Meets | Self::MetBy)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Before => "before",
+            Self::Meets => "meets",
+            Self::Overlaps => "overlaps",
+            Self::Starts => "starts",
+            Self::During => "during",
+            Self::Finishes => "finishes",
+            Self::Equals => "equals",
+            Self::After => "after",
+            Self::MetBy => "met_by",
+            Self::OverlappedBy => "overlapped_by",
+            Self::StartedBy => "started_by",
+            Self::Contains => "contains",
+            Self::FinishedBy => "finished_by",
+        }
+    }
+}
+
+/// The Allen relation of range `a` to range `b`.
+pub fn relate(a: &Range, b: &Range) -> IntervalRelation {
+    let (a_start, a_end) = (key(&a.start), key(&a.end));
+    let (b_start, b_end) = (key(&b.start), key(&b.end));
+
+    if a_start == b_start && a_end == b_end {
+        return IntervalRelation::Equals;
+    }
+    if a_end < b_start {
+        return IntervalRelation::Before;
+    }
+    if b_end < a_start {
+        return IntervalRelation::After;
+    }
+    if a_end == b_start {
+        return IntervalRelation::Meets;
+    }
+    if b_end == a_start {
+        return IntervalRelation::MetBy;
+    }
+    match (a_start.cmp(&b_start), a_end.cmp(&b_end)) {
+        (Ordering::Equal, Ordering::Less) => IntervalRelation::Starts,
+        (Ordering::Equal, _) => IntervalRelation::StartedBy,
+        (Ordering::Greater, Ordering::Equal) => IntervalRelation::Finishes,
+        (_, Ordering::Equal) => IntervalRelation::FinishedBy,
+        (Ordering::Greater, Ordering::Less) => IntervalRelation::During,
+        (Ordering::Less, Ordering::Greater) => IntervalRelation::Contains,
+        (Ordering::Less, _) => IntervalRelation::Overlaps,
+        (Ordering::Greater, _) => IntervalRelation::OverlappedBy,
+    }
+}
+
+/// Relations between every pair of error-handling regions that share an
+/// enclosing function (or are both at module level).
+///
+/// Each pair is reported once as `(a, b, relation)` with `a < b`, so
+/// `relation` reads "region `a` is `relation` region `b`"; use
+/// [`IntervalRelation::inverse`] for the other direction.
+pub fn region_relationships(parse_result: &ParseResult) -> Vec<(RegionId, RegionId, IntervalRelation)> {
+    let mut by_function: FxHashMap<Option<&str>, Vec<usize>> = FxHashMap::default();
+    for (i, region) in parse_result.error_handling.iter().enumerate() {
+        by_function.entry(region.function_scope.as_deref()).or_default().push(i);
+    }
+
+    let mut relations = Vec::new();
+    for indices in by_function.values() {
+        for (n, &a) in indices.iter().enumerate() {
+            for &b in &indices[n + 1..] {
+                let relation = relate(
+                    &parse_result.error_handling[a].range,
+                    &parse_result.error_handling[b].range,
+                );
+                relations.push((RegionId(a), RegionId(b), relation));
+            }
+        }
+    }
+    relations.sort_by_key(|(a, b, _)| (*a, *b));
+    relations
+}
+
+fn key(position: &Position) -> (u32, u32) {
+    (position.line, position.column)
+}
@@ -10,6 +10,7 @@ pub mod propagation;
 pub mod gap_analysis;
 pub mod frameworks;
 pub mod cwe_mapping;
+pub mod intervals;
 
 pub use types::*;
 pub use profiler::profile_error_types;
@@ -18,3 +19,4 @@ pub use propagation::trace_propagation;
 pub use gap_analysis::analyze_gaps;
 pub use frameworks::detect_framework_handlers;
 pub use cwe_mapping::map_to_cwe;
+pub use intervals::{region_relationships, IntervalRelation, RegionId};
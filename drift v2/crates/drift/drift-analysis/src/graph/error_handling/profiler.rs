@@ -24,7 +24,7 @@ pub fn profile_error_types(parse_results: &[ParseResult]) -> Vec<ErrorType> {
                 ErrorHandlingKind::ResultMatch => {
                     ("Result".to_string(), true, None)
                 }
-                ErrorHandlingKind::QuestionMark => {
+                ErrorHandlingKind::QuestionMark | ErrorHandlingKind::TryOperator => {
                     ("Result".to_string(), true, None)
                 }
                 ErrorHandlingKind::Throw => {
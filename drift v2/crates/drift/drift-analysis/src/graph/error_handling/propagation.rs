@@ -55,7 +55,10 @@ fn find_error_sources(parse_results: &[ParseResult]) -> Vec<(String, String, u32
         for eh in &pr.error_handling {
             use crate::parsers::types::ErrorHandlingKind;
             match eh.kind {
-                ErrorHandlingKind::Throw | ErrorHandlingKind::QuestionMark | ErrorHandlingKind::Unwrap => {
+                ErrorHandlingKind::Throw
+                | ErrorHandlingKind::QuestionMark
+                | ErrorHandlingKind::TryOperator
+                | ErrorHandlingKind::Unwrap => {
                     let func_name = eh
                         .function_scope
                         .clone()
@@ -3,10 +3,11 @@
 //! - <10K nodes → petgraph in-memory BFS
 //! - ≥10K nodes → SQLite recursive CTE
 
+use std::collections::hash_map::Entry;
 use std::collections::VecDeque;
 
 use drift_core::errors::CallGraphError;
-use drift_core::types::collections::FxHashSet;
+use drift_core::types::collections::{FxHashMap, FxHashSet};
 use petgraph::graph::NodeIndex;
 use petgraph::Direction;
 use rusqlite::Connection;
@@ -62,6 +63,78 @@ pub fn reachability_inverse(
     }
 }
 
+/// Shortest call path from `from` to `to`, both ends included.
+///
+/// Returns `None` when `to` is not reachable. Each node is visited once, so
+/// recursive and mutually recursive functions cannot loop.
+pub fn shortest_path(graph: &CallGraph, from: NodeIndex, to: NodeIndex) -> Option<Vec<NodeIndex>> {
+    bfs_path(graph, &[from], |node| node == to)
+}
+
+/// [`shortest_path`] by function name (`handleRequest`) or node key
+/// (`src/api.ts::handleRequest`). When a name matches several functions,
+/// the shortest path between any of them is returned.
+pub fn shortest_path_by_name(graph: &CallGraph, from: &str, to: &str) -> Option<Vec<NodeIndex>> {
+    let sources = nodes_named(graph, from);
+    let targets: FxHashSet<NodeIndex> = nodes_named(graph, to).into_iter().collect();
+    if targets.is_empty() {
+        return None;
+    }
+    bfs_path(graph, &sources, |node| targets.contains(&node))
+}
+
+fn nodes_named(graph: &CallGraph, name: &str) -> Vec<NodeIndex> {
+    if let Some(idx) = graph.get_node(name) {
+        return vec![idx];
+    }
+    let mut nodes: Vec<NodeIndex> = graph
+        .graph
+        .node_indices()
+        .filter(|&idx| graph.graph[idx].name == name)
+        .collect();
+    nodes.sort();
+    nodes
+}
+
+/// Multi-source BFS that stops at the first node accepted by `is_target`
+/// and walks the parent links back to a source.
+fn bfs_path(
+    graph: &CallGraph,
+    sources: &[NodeIndex],
+    is_target: impl Fn(NodeIndex) -> bool,
+) -> Option<Vec<NodeIndex>> {
+    let mut parent: FxHashMap<NodeIndex, Option<NodeIndex>> = FxHashMap::default();
+    let mut queue = VecDeque::new();
+
+    for &source in sources {
+        if graph.graph.contains_node(source) && parent.insert(source, None).is_none() {
+            queue.push_back(source);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_target(node) {
+            let mut path = vec![node];
+            let mut current = node;
+            while let Some(Some(prev)) = parent.get(&current) {
+                path.push(*prev);
+                current = *prev;
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        for neighbor in graph.graph.neighbors_directed(node, Direction::Outgoing) {
+            if let Entry::Vacant(e) = parent.entry(neighbor) {
+                e.insert(Some(node));
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    None
+}
+
 /// Forward reachability via SQLite CTE (for large graphs).
 pub fn reachability_forward_cte(
     conn: &Connection,
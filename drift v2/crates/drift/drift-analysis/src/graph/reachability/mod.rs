@@ -1,5 +1,7 @@
 //! Reachability analysis — forward/inverse BFS with auto-select engine.
 //!
+//! `shortest_path` returns the call path itself, for "can handler X reach sink Y?" triage.
+//!
 //! Auto-selects petgraph (in-memory) for <10K nodes, SQLite CTE for ≥10K nodes.
 //! Includes sensitivity classification, LRU caching, cross-service reachability,
 //! and field-level data flow tracking.
@@ -12,6 +14,6 @@ pub mod cross_service;
 pub mod field_flow;
 
 pub use types::*;
-pub use bfs::{reachability_forward, reachability_inverse, auto_select_engine};
+pub use bfs::{reachability_forward, reachability_inverse, auto_select_engine, shortest_path, shortest_path_by_name};
 pub use sensitivity::classify_sensitivity;
 pub use cache::ReachabilityCache;
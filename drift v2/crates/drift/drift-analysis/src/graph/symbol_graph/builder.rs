@@ -0,0 +1,210 @@
+//! Build the symbol graph from parse results by lexical name matching.
+//!
+//! No resolver is involved: a referenced name is matched against the types
+//! declared across all parse results. The confidence of each edge says how
+//! sure the match is:
+//!
+//! - 0.95 — the name is declared in the referencing file,
+//! - 0.85 — the referencing file imports the name,
+//! - 0.60 — exactly one other file declares the name,
+//! - 0.40 — several files declare it (an edge goes to each),
+//! - 0.50 — a supertype declared nowhere (an external node).
+
+use petgraph::graph::NodeIndex;
+
+use crate::detectors::call_args::split_type_list;
+use crate::parsers::types::{FunctionInfo, ParseResult};
+
+use super::types::{ReferenceKind, SymbolGraph, SymbolKind, SymbolNode, SymbolReference};
+
+/// Build a symbol graph over `parse_results`.
+pub fn build_symbol_graph(parse_results: &[ParseResult]) -> SymbolGraph {
+    let mut graph = SymbolGraph::new();
+
+    // Pass 1: declare every type so references can match across files.
+    for pr in parse_results {
+        for class in &pr.classes {
+            graph.add_symbol(SymbolNode {
+                name: class.name.clone(),
+                file: pr.file.clone(),
+                line: class.range.start.line,
+                kind: SymbolKind::Type(class.class_kind),
+            });
+        }
+    }
+
+    // Pass 2: references.
+    for pr in parse_results {
+        let imported: Vec<&str> = pr
+            .imports
+            .iter()
+            .flat_map(|i| i.specifiers.iter().map(|s| s.name.as_str()))
+            .collect();
+        let resolver = Resolver { file: &pr.file, imported: &imported };
+
+        for class in &pr.classes {
+            let Some(from) = graph.get_node(&format!("{}::{}", pr.file, class.name)) else {
+                continue;
+            };
+            let line = class.range.start.line;
+            let supertypes = class
+                .extends
+                .iter()
+                .flat_map(|e| extended_names(e))
+                .map(|t| (t, ReferenceKind::Extends))
+                .chain(class.implements.iter().map(|t| (base_name(t), ReferenceKind::Implements)));
+            for (name, kind) in supertypes {
+                if name.is_empty() {
+                    continue;
+                }
+                let targets = resolver.resolve(&graph, name);
+                if targets.is_empty() {
+                    let external = graph.add_symbol(SymbolNode {
+                        name: name.to_string(),
+                        file: String::new(),
+                        line: 0,
+                        kind: SymbolKind::External,
+                    });
+                    graph.add_reference(from, external, SymbolReference { kind, confidence: 0.50, line });
+                }
+                for (to, confidence) in targets {
+                    if to != from {
+                        graph.add_reference(from, to, SymbolReference { kind, confidence, line });
+                    }
+                }
+            }
+
+            let properties = class.properties.iter().filter_map(|p| p.type_annotation.as_deref());
+            for annotation in properties.chain(class.alias_target.as_deref()) {
+                add_annotation_refs(&mut graph, &resolver, from, annotation, line);
+            }
+            for method in &class.methods {
+                let name = format!("{}.{}", class.name, method.name);
+                add_function(&mut graph, &resolver, pr, method, name);
+            }
+        }
+
+        // Parsers may list methods among the functions as well.
+        let method_lines: Vec<u32> = pr.classes.iter().flat_map(|c| c.methods.iter().map(|m| m.line)).collect();
+        for func in pr.functions.iter().filter(|f| !method_lines.contains(&f.line)) {
+            add_function(&mut graph, &resolver, pr, func, func.name.clone());
+        }
+
+        let mut file_node = None;
+        for import in &pr.imports {
+            for specifier in &import.specifiers {
+                let targets = resolver.resolve(&graph, &specifier.name);
+                if targets.is_empty() {
+                    continue;
+                }
+                let from = *file_node.get_or_insert_with(|| {
+                    graph.add_symbol(SymbolNode {
+                        name: "<file>".to_string(),
+                        file: pr.file.clone(),
+                        line: 0,
+                        kind: SymbolKind::File,
+                    })
+                });
+                for (to, confidence) in targets {
+                    let reference = SymbolReference { kind: ReferenceKind::Import, confidence, line: import.line };
+                    graph.add_reference(from, to, reference);
+                }
+            }
+        }
+    }
+
+    graph
+}
+
+/// Matches names referenced from one file against declared types.
+struct Resolver<'a> {
+    file: &'a str,
+    imported: &'a [&'a str],
+}
+
+impl Resolver<'_> {
+    /// Declared types named `name`, with the match confidence.
+    fn resolve(&self, graph: &SymbolGraph, name: &str) -> Vec<(NodeIndex, f32)> {
+        let declared: Vec<NodeIndex> = graph
+            .types_by_name
+            .get(name)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|&idx| graph.graph[idx].kind != SymbolKind::External)
+            .collect();
+        if let Some(&local) = declared.iter().find(|&&idx| graph.graph[idx].file == self.file) {
+            return vec![(local, 0.95)];
+        }
+        let confidence = if self.imported.contains(&name) {
+            0.85
+        } else if declared.len() == 1 {
+            0.60
+        } else {
+            0.40
+        };
+        declared.into_iter().map(|idx| (idx, confidence)).collect()
+    }
+}
+
+/// Add a function node with edges to the types its signature names.
+fn add_function(graph: &mut SymbolGraph, resolver: &Resolver, pr: &ParseResult, func: &FunctionInfo, name: String) {
+    let annotations: Vec<&str> = func
+        .parameters
+        .iter()
+        .filter_map(|p| p.type_annotation.as_deref())
+        .chain(func.return_type.as_deref())
+        .collect();
+    if annotations.is_empty() {
+        return;
+    }
+    let from = graph.add_symbol(SymbolNode {
+        name,
+        file: pr.file.clone(),
+        line: func.line,
+        kind: SymbolKind::Function,
+    });
+    for annotation in annotations {
+        add_annotation_refs(graph, resolver, from, annotation, func.line);
+    }
+}
+
+/// Edges from `from` to each declared type named in a type annotation
+/// (`Promise<User[]>` names `Promise` and `User`).
+fn add_annotation_refs(graph: &mut SymbolGraph, resolver: &Resolver, from: NodeIndex, annotation: &str, line: u32) {
+    let mut names: Vec<&str> = annotation
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|s| !s.is_empty())
+        .collect();
+    names.dedup();
+    for name in names {
+        for (to, confidence) in resolver.resolve(graph, name) {
+            if to != from {
+                let reference = SymbolReference { kind: ReferenceKind::TypeAnnotation, confidence, line };
+                graph.add_reference(from, to, reference);
+            }
+        }
+    }
+}
+
+/// Supertype names in an `extends` clause as parsers record it: a single
+/// name, a list (`A, B`), Python's `(Base, metaclass=Meta)` or TypeScript's
+/// whole heritage `extends Base implements Api` (implements are listed
+/// separately).
+fn extended_names(extends: &str) -> Vec<&str> {
+    let extends = extends.split("implements").next().unwrap_or(extends).trim();
+    let extends = extends.strip_prefix("extends").unwrap_or(extends);
+    let extends = extends.trim().trim_start_matches('(').trim_end_matches(')');
+    split_type_list(extends)
+        .into_iter()
+        .filter(|t| !t.contains('='))
+        .map(base_name)
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// `Base` of `models.Base<T>`, `crate::Base` or `\App\Base`.
+fn base_name(type_name: &str) -> &str {
+    let name = type_name.split(['<', '[', '(']).next().unwrap_or(type_name).trim();
+    name.rsplit(['.', ':', '\\']).next().unwrap_or(name).trim()
+}
@@ -0,0 +1,8 @@
+//! Symbol reference graph — inheritance, type usage and import edges between
+//! types, functions and files, matched lexically by name.
+
+pub mod types;
+pub mod builder;
+
+pub use types::*;
+pub use builder::build_symbol_graph;
@@ -0,0 +1,179 @@
+//! Symbol reference graph types.
+
+use drift_core::types::collections::{FxHashMap, FxHashSet};
+use petgraph::graph::NodeIndex;
+use petgraph::stable_graph::StableGraph;
+use petgraph::visit::EdgeRef;
+use petgraph::{Directed, Direction};
+use serde::{Deserialize, Serialize};
+
+use crate::parsers::types::ClassKind;
+
+/// What a symbol node stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SymbolKind {
+    /// A declared class, interface, struct, trait, enum, ...
+    Type(ClassKind),
+    /// A function or method (methods are named `Class.method`).
+    Function,
+    /// A source file, the origin of import edges.
+    File,
+    /// A supertype named in `extends`/`implements` but declared in no
+    /// analyzed file (a library base class or interface).
+    External,
+}
+
+/// A node of the symbol graph.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymbolNode {
+    pub name: String,
+    /// Declaring file; empty for external types.
+    pub file: String,
+    pub line: u32,
+    pub kind: SymbolKind,
+}
+
+impl SymbolNode {
+    /// Unique key: `file::name`, or `::name` for external types.
+    pub fn key(&self) -> String {
+        format!("{}::{}", self.file, self.name)
+    }
+}
+
+/// How one symbol refers to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReferenceKind {
+    /// Class or interface extends the target.
+    Extends,
+    /// Class implements the target interface or trait.
+    Implements,
+    /// Parameter, return, property or aliased type names the target.
+    TypeAnnotation,
+    /// The file imports the target by name.
+    Import,
+}
+
+impl ReferenceKind {
+    /// Name of the reference kind.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Extends => "extends",
+            Self::Implements => "implements",
+            Self::TypeAnnotation => "type_annotation",
+            Self::Import => "import",
+        }
+    }
+}
+
+/// A reference edge, from the referencing symbol to the referenced one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymbolReference {
+    pub kind: ReferenceKind,
+    /// Confidence of the lexical name match (see `build_symbol_graph`).
+    pub confidence: f32,
+    /// Line of the reference in the referencing file.
+    pub line: u32,
+}
+
+/// Types, functions and files linked by inheritance, type usage and imports.
+///
+/// Complements the call graph: it answers "what uses type `Foo`" and "what
+/// implements interface `Bar`" rather than "what calls `f`".
+#[derive(Debug, Clone, Default)]
+pub struct SymbolGraph {
+    pub graph: StableGraph<SymbolNode, SymbolReference, Directed>,
+    /// Map from `file::name` → NodeIndex.
+    pub node_index: FxHashMap<String, NodeIndex>,
+    /// Map from type name → declaring nodes (several when a name is declared
+    /// in more than one file).
+    pub types_by_name: FxHashMap<String, Vec<NodeIndex>>,
+}
+
+impl SymbolGraph {
+    /// Create an empty symbol graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of symbols (nodes).
+    pub fn symbol_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    /// Number of references (edges).
+    pub fn reference_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    /// Look up a node by its key (`file::name`).
+    pub fn get_node(&self, key: &str) -> Option<NodeIndex> {
+        self.node_index.get(key).copied()
+    }
+
+    /// Add a node, returning the existing one when its key is taken.
+    pub fn add_symbol(&mut self, node: SymbolNode) -> NodeIndex {
+        let key = node.key();
+        if let Some(&existing) = self.node_index.get(&key) {
+            return existing;
+        }
+        let is_type = matches!(node.kind, SymbolKind::Type(_) | SymbolKind::External);
+        let name = node.name.clone();
+        let idx = self.graph.add_node(node);
+        self.node_index.insert(key, idx);
+        if is_type {
+            self.types_by_name.entry(name).or_default().push(idx);
+        }
+        idx
+    }
+
+    /// Add a reference edge.
+    pub fn add_reference(&mut self, from: NodeIndex, to: NodeIndex, reference: SymbolReference) {
+        self.graph.add_edge(from, to, reference);
+    }
+
+    /// Types that extend or implement a type named `name`, directly or
+    /// through intermediate types, sorted by file and line.
+    pub fn subtypes(&self, name: &str) -> Vec<&SymbolNode> {
+        let mut seen: FxHashSet<NodeIndex> = FxHashSet::default();
+        let mut queue: Vec<NodeIndex> = self.types_by_name.get(name).cloned().unwrap_or_default();
+        while let Some(target) = queue.pop() {
+            for edge in self.graph.edges_directed(target, Direction::Incoming) {
+                let inherits = matches!(edge.weight().kind, ReferenceKind::Extends | ReferenceKind::Implements);
+                if inherits && seen.insert(edge.source()) {
+                    queue.push(edge.source());
+                }
+            }
+        }
+        self.sorted(seen.into_iter().collect())
+    }
+
+    /// Concrete types implementing interface or trait `name`: its subtypes
+    /// that are not themselves interfaces or traits.
+    pub fn implementors(&self, name: &str) -> Vec<&SymbolNode> {
+        self.subtypes(name)
+            .into_iter()
+            .filter(|n| !matches!(n.kind, SymbolKind::Type(ClassKind::Interface | ClassKind::Trait)))
+            .collect()
+    }
+
+    /// Every reference to a type named `name`, with the referencing symbol,
+    /// sorted by file and line.
+    pub fn type_users(&self, name: &str) -> Vec<(&SymbolNode, &SymbolReference)> {
+        let mut users: Vec<(&SymbolNode, &SymbolReference)> = self
+            .types_by_name
+            .get(name)
+            .into_iter()
+            .flatten()
+            .flat_map(|&target| self.graph.edges_directed(target, Direction::Incoming))
+            .map(|edge| (&self.graph[edge.source()], edge.weight()))
+            .collect();
+        users.sort_by(|a, b| (&a.0.file, a.1.line, &a.0.name).cmp(&(&b.0.file, b.1.line, &b.0.name)));
+        users
+    }
+
+    fn sorted(&self, nodes: Vec<NodeIndex>) -> Vec<&SymbolNode> {
+        let mut nodes: Vec<&SymbolNode> = nodes.into_iter().map(|idx| &self.graph[idx]).collect();
+        nodes.sort_by(|a, b| (&a.file, a.line, &a.name).cmp(&(&b.file, b.line, &b.name)));
+        nodes
+    }
+}
@@ -0,0 +1,96 @@
+//! Sanitizer coverage across a set of taint flows.
+//!
+//! Each flow says on its own whether a sanitizer was applied on its path.
+//! [`coverage_report`] aggregates them for security review: sanitized vs
+//! unsanitized counts per sink type, and for every CWE the unsanitized flow
+//! with the highest confidence, ordered so the list reads as a remediation
+//! queue.
+
+use serde::{Deserialize, Serialize};
+
+use super::types::{SinkType, TaintFlow};
+
+/// Aggregate sanitizer coverage of a set of flows.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TaintCoverage {
+    pub total_flows: usize,
+    pub sanitized: usize,
+    pub unsanitized: usize,
+    /// One entry per sink type that any flow reaches, ordered by name.
+    pub by_sink: Vec<SinkCoverage>,
+    /// One entry per CWE with unsanitized flows, highest confidence first.
+    pub top_unsanitized: Vec<CweRemediation>,
+}
+
+/// Flows reaching one sink type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SinkCoverage {
+    pub sink_type: SinkType,
+    pub sanitized: usize,
+    pub unsanitized: usize,
+}
+
+/// The unsanitized flow to fix first for one CWE.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CweRemediation {
+    pub cwe_id: u32,
+    /// Unsanitized flows with this CWE, `flow` included.
+    pub unsanitized: usize,
+    pub flow: TaintFlow,
+}
+
+/// Summarize which flows reach their sinks sanitized. A flow without a
+/// `cwe_id` is filed under its sink type's CWE.
+pub fn coverage_report(flows: &[TaintFlow]) -> TaintCoverage {
+    let mut report = TaintCoverage {
+        total_flows: flows.len(),
+        ..TaintCoverage::default()
+    };
+
+    for flow in flows {
+        let sink_type = flow.sink.sink_type;
+        let index = match report.by_sink.iter().position(|s| s.sink_type == sink_type) {
+            Some(index) => index,
+            None => {
+                report.by_sink.push(SinkCoverage { sink_type, sanitized: 0, unsanitized: 0 });
+                report.by_sink.len() - 1
+            }
+        };
+        let entry = &mut report.by_sink[index];
+        if flow.is_sanitized {
+            entry.sanitized += 1;
+            report.sanitized += 1;
+            continue;
+        }
+        entry.unsanitized += 1;
+        report.unsanitized += 1;
+
+        let Some(cwe_id) = flow.cwe_id.or_else(|| sink_type.cwe_id()) else {
+            continue;
+        };
+        match report.top_unsanitized.iter_mut().find(|r| r.cwe_id == cwe_id) {
+            Some(top) => {
+                top.unsanitized += 1;
+                // Ties keep the earlier flow
+                if flow.confidence > top.flow.confidence {
+                    top.flow = flow.clone();
+                }
+            }
+            None => report.top_unsanitized.push(CweRemediation {
+                cwe_id,
+                unsanitized: 1,
+                flow: flow.clone(),
+            }),
+        }
+    }
+
+    report.by_sink.sort_by_key(|s| (s.sink_type.name(), s.sink_type.cwe_id()));
+    report.top_unsanitized.sort_by(|a, b| {
+        b.flow
+            .confidence
+            .total_cmp(&a.flow.confidence)
+            .then(b.unsanitized.cmp(&a.unsanitized))
+            .then(a.cwe_id.cmp(&b.cwe_id))
+    });
+    report
+}
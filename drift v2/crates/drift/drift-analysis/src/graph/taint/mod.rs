@@ -12,6 +12,8 @@ pub mod intraprocedural;
 pub mod interprocedural;
 pub mod propagation;
 pub mod sarif;
+pub mod render;
+pub mod coverage;
 pub mod framework_specs;
 
 pub use types::*;
@@ -19,3 +21,5 @@ pub use registry::TaintRegistry;
 pub use intraprocedural::analyze_intraprocedural;
 pub use interprocedural::analyze_interprocedural;
 pub use sarif::generate_sarif;
+pub use render::{render_path, TaintPathView};
+pub use coverage::{coverage_report, TaintCoverage};
@@ -164,6 +164,11 @@ impl TaintRegistry {
             ("res.write", SinkType::HtmlOutput, &[SanitizerType::HtmlEscape]),
             ("document.write", SinkType::HtmlOutput, &[SanitizerType::HtmlEscape]),
             ("innerHTML", SinkType::HtmlOutput, &[SanitizerType::HtmlEscape]),
+            ("render_template_string", SinkType::TemplateRender, &[SanitizerType::InputValidation]),
+            ("Handlebars.compile", SinkType::TemplateRender, &[SanitizerType::InputValidation]),
+            ("ejs.render", SinkType::TemplateRender, &[SanitizerType::InputValidation]),
+            ("nunjucks.renderString", SinkType::TemplateRender, &[SanitizerType::InputValidation]),
+            ("Velocity.evaluate", SinkType::TemplateRender, &[SanitizerType::InputValidation]),
             ("render", SinkType::TemplateRender, &[SanitizerType::HtmlEscape]),
             ("res.redirect", SinkType::HttpRedirect, &[SanitizerType::UrlEncode]),
             ("redirect", SinkType::HttpRedirect, &[SanitizerType::UrlEncode]),
@@ -0,0 +1,139 @@
+//! Report-ready view of a taint path.
+//!
+//! Flows carry their source, their hops and their sink separately, and
+//! intraprocedural flows repeat the source and sink as the first and last
+//! hop. [`render_path`] flattens a flow into one ordered list of steps with a
+//! label each, dropping those repeats, so SARIF related locations, terminal
+//! output and HTML all show the same path.
+
+use serde::{Deserialize, Serialize};
+
+use super::types::TaintFlow;
+
+/// Role of a step in the path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepKind {
+    Source,
+    Propagation,
+    Sink,
+}
+
+/// One location on a taint path.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaintStep {
+    pub kind: StepKind,
+    pub file: String,
+    /// 1-based line (flow positions are 0-based parser lines).
+    pub line: u32,
+    /// 1-based column.
+    pub column: u32,
+    /// Enclosing function, when the flow records it.
+    pub function: Option<String>,
+    pub label: String,
+}
+
+/// A taint flow as an ordered list of steps plus a one-line summary.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaintPathView {
+    pub steps: Vec<TaintStep>,
+    pub summary: String,
+}
+
+impl TaintPathView {
+    pub fn source(&self) -> &TaintStep {
+        &self.steps[0]
+    }
+
+    pub fn sink(&self) -> &TaintStep {
+        &self.steps[self.steps.len() - 1]
+    }
+}
+
+/// Render `flow` as source → intermediate steps → sink.
+pub fn render_path(flow: &TaintFlow) -> TaintPathView {
+    let source = &flow.source;
+    let sink = &flow.sink;
+    let at = |file: &str, line: u32, column: u32| (file.to_string(), line, column);
+    let source_at = at(&source.file, source.line, source.column);
+    let sink_at = at(&sink.file, sink.line, sink.column);
+
+    let mut steps = vec![TaintStep {
+        kind: StepKind::Source,
+        file: source.file.clone(),
+        line: source.line + 1,
+        column: source.column + 1,
+        function: None,
+        label: format!("{} enters from `{}`", source_label(source.source_type.name()), source.expression),
+    }];
+    for hop in &flow.path {
+        let hop_at = at(&hop.file, hop.line, hop.column);
+        if hop_at == source_at || hop_at == sink_at {
+            continue;
+        }
+        let label = if hop.description.is_empty() {
+            format!("passes through `{}`", hop.function)
+        } else {
+            hop.description.clone()
+        };
+        steps.push(TaintStep {
+            kind: StepKind::Propagation,
+            file: hop.file.clone(),
+            line: hop.line + 1,
+            column: hop.column + 1,
+            function: (!hop.function.is_empty()).then(|| hop.function.clone()),
+            label,
+        });
+    }
+    steps.push(TaintStep {
+        kind: StepKind::Sink,
+        file: sink.file.clone(),
+        line: sink.line + 1,
+        column: sink.column + 1,
+        function: None,
+        label: format!("reaches {} sink `{}`", sink.sink_type.name().replace('_', " "), sink.expression),
+    });
+
+    // Source and sink positions are known here; fill their function from the
+    // hops that repeated them.
+    for hop in &flow.path {
+        let hop_at = at(&hop.file, hop.line, hop.column);
+        let index = if hop_at == source_at { 0 } else if hop_at == sink_at { steps.len() - 1 } else { continue };
+        if steps[index].function.is_none() && !hop.function.is_empty() {
+            steps[index].function = Some(hop.function.clone());
+        }
+    }
+
+    let intermediate = steps.len() - 2;
+    let via = match intermediate {
+        0 => String::new(),
+        1 => " via 1 step".to_string(),
+        n => format!(" via {n} steps"),
+    };
+    let cwe = flow.cwe_id.map(|id| format!("CWE-{id}: ")).unwrap_or_default();
+    let sanitized = if flow.is_sanitized {
+        let names: Vec<&str> = flow.sanitizers_applied.iter().map(|s| s.sanitizer_type.name()).collect();
+        format!(" (sanitized by {})", names.join(", "))
+    } else {
+        String::new()
+    };
+    let summary = format!(
+        "{cwe}`{}` ({}:{}) flows to `{}` ({}:{}){via}{sanitized}",
+        source.expression,
+        steps[0].file,
+        steps[0].line,
+        sink.expression,
+        steps[steps.len() - 1].file,
+        steps[steps.len() - 1].line,
+    );
+
+    TaintPathView { steps, summary }
+}
+
+fn source_label(source_type: &str) -> String {
+    let mut label = source_type.replace('_', " ");
+    if let Some(first) = label.get_mut(..1) {
+        first.make_ascii_uppercase();
+    }
+    label
+}
@@ -0,0 +1,37 @@
+//! Impacted tests — which tests exercise a set of changed functions.
+//!
+//! The inverse of coverage mapping: instead of following a test's outgoing
+//! calls, follow callers backwards from each changed function and keep the
+//! test entry points reached, so CI can run only the affected tests.
+
+use drift_core::types::collections::FxHashSet;
+use petgraph::graph::NodeIndex;
+
+use crate::call_graph::types::CallGraph;
+use crate::graph::reachability::bfs::reachability_inverse;
+
+/// Tests in `test_entry_points` that transitively call any function in `changed`.
+///
+/// A changed function that is itself a test entry point is included. The
+/// result follows the order of `test_entry_points`, without duplicates.
+pub fn impacted_tests(
+    graph: &CallGraph,
+    changed: &[NodeIndex],
+    test_entry_points: &[NodeIndex],
+) -> Vec<NodeIndex> {
+    let mut reaching: FxHashSet<NodeIndex> = FxHashSet::default();
+    for &function in changed {
+        if graph.graph.node_weight(function).is_none() {
+            continue;
+        }
+        reaching.insert(function);
+        reaching.extend(reachability_inverse(graph, function, None).reachable);
+    }
+
+    let mut seen = FxHashSet::default();
+    test_entry_points
+        .iter()
+        .copied()
+        .filter(|test| reaching.contains(test) && seen.insert(*test))
+        .collect()
+}
@@ -10,6 +10,7 @@ pub mod smells;
 pub mod quality_scorer;
 pub mod minimum_set;
 pub mod frameworks;
+pub mod impact;
 
 pub use types::*;
 pub use coverage::compute_coverage;
@@ -17,3 +18,4 @@ pub use smells::detect_smells;
 pub use quality_scorer::compute_quality_score;
 pub use minimum_set::compute_minimum_test_set;
 pub use frameworks::detect_test_framework;
+pub use impact::impacted_tests;
@@ -0,0 +1,171 @@
+//! Symbol-level diff between two parses of the same file.
+//!
+//! A changed `content_hash` only says the file changed. `ParseDiff` says which
+//! functions, classes and imports changed, so the call graph and pattern
+//! aggregation can invalidate just the affected symbols. The diff ignores
+//! positions: moving a function without editing it is not a modification.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::types::{ClassInfo, FunctionInfo, ImportInfo, ParseResult};
+
+/// Symbols added, removed or modified between two parses.
+///
+/// Each list holds symbol keys (qualified name, class name or import source),
+/// sorted so the diff is stable across runs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolChanges {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub modified: Vec<String>,
+}
+
+impl SymbolChanges {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+
+    /// Every key touched by the diff, in sorted order.
+    pub fn affected(&self) -> Vec<&str> {
+        let mut keys: Vec<&str> = self
+            .added
+            .iter()
+            .chain(&self.removed)
+            .chain(&self.modified)
+            .map(String::as_str)
+            .collect();
+        keys.sort_unstable();
+        keys
+    }
+}
+
+/// Result of [`ParseResult::diff`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseDiff {
+    pub functions: SymbolChanges,
+    pub classes: SymbolChanges,
+    pub imports: SymbolChanges,
+    /// Functions whose signature changed, a subset of `functions.modified`.
+    /// Callers of these need re-resolution; body-only edits do not.
+    pub signature_changed: Vec<String>,
+}
+
+impl ParseDiff {
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty() && self.classes.is_empty() && self.imports.is_empty()
+    }
+}
+
+impl ParseResult {
+    /// Diff this (new) parse against an older parse of the same file.
+    ///
+    /// Functions are keyed by qualified name (falling back to the bare name)
+    /// and are modified when their `body_hash` or `signature_hash` differ.
+    /// Classes are keyed by name and compared by shape and method hashes.
+    /// Imports are keyed by source module and compared by specifiers.
+    pub fn diff(&self, old: &ParseResult) -> ParseDiff {
+        let old_fns = keyed(&old.functions, function_key);
+        let new_fns = keyed(&self.functions, function_key);
+        let functions = changes(&old_fns, &new_fns, |a, b| {
+            a.body_hash == b.body_hash && a.signature_hash == b.signature_hash
+        });
+        let signature_changed = functions
+            .modified
+            .iter()
+            .filter(|key| old_fns[key.as_str()].signature_hash != new_fns[key.as_str()].signature_hash)
+            .cloned()
+            .collect();
+
+        let classes = changes(
+            &keyed(&old.classes, |c| c.name.clone()),
+            &keyed(&self.classes, |c| c.name.clone()),
+            |a, b| class_fingerprint(a) == class_fingerprint(b),
+        );
+        let imports = changes(
+            &keyed(&old.imports, |i| i.source.clone()),
+            &keyed(&self.imports, |i| i.source.clone()),
+            |a, b| import_fingerprint(a) == import_fingerprint(b),
+        );
+
+        ParseDiff { functions, classes, imports, signature_changed }
+    }
+}
+
+fn function_key(f: &FunctionInfo) -> String {
+    f.qualified_name.clone().unwrap_or_else(|| f.name.clone())
+}
+
+/// Index items by key. Repeated keys (overloads, redeclarations) get a `#n`
+/// suffix in declaration order so each still pairs with its counterpart.
+fn keyed<T>(items: &[T], key: impl Fn(&T) -> String) -> BTreeMap<String, &T> {
+    let mut map = BTreeMap::new();
+    let mut seen: BTreeMap<String, usize> = BTreeMap::new();
+    for item in items {
+        let base = key(item);
+        let n = seen.entry(base.clone()).or_insert(0);
+        let k = if *n == 0 { base } else { format!("{base}#{n}") };
+        *n += 1;
+        map.insert(k, item);
+    }
+    map
+}
+
+fn changes<T>(
+    old: &BTreeMap<String, &T>,
+    new: &BTreeMap<String, &T>,
+    same: impl Fn(&T, &T) -> bool,
+) -> SymbolChanges {
+    let mut result = SymbolChanges::default();
+    for (key, new_item) in new {
+        match old.get(key) {
+            None => result.added.push(key.clone()),
+            Some(old_item) if !same(old_item, new_item) => result.modified.push(key.clone()),
+            Some(_) => {}
+        }
+    }
+    result.removed = old.keys().filter(|k| !new.contains_key(*k)).cloned().collect();
+    result
+}
+
+/// Position-independent shape of a class: heritage, kind, properties and
+/// method hashes, each sorted so member reordering is not a change.
+#[derive(PartialEq)]
+struct ClassShape {
+    heritage: String,
+    implements: Vec<String>,
+    properties: Vec<String>,
+    methods: Vec<(String, u64, u64)>,
+}
+
+fn class_fingerprint(c: &ClassInfo) -> ClassShape {
+    let mut implements: Vec<String> = c.implements.iter().cloned().collect();
+    implements.sort();
+    let mut properties: Vec<String> = c
+        .properties
+        .iter()
+        .map(|p| format!("{}:{}", p.name, p.type_annotation.as_deref().unwrap_or("")))
+        .collect();
+    properties.sort();
+    let mut methods: Vec<(String, u64, u64)> = c
+        .methods
+        .iter()
+        .map(|m| (m.name.clone(), m.body_hash, m.signature_hash))
+        .collect();
+    methods.sort();
+    let heritage = format!(
+        "{:?}:{}={}",
+        c.class_kind,
+        c.extends.as_deref().unwrap_or(""),
+        c.alias_target.as_deref().unwrap_or("")
+    );
+    ClassShape { heritage, implements, properties, methods }
+}
+
+fn import_fingerprint(i: &ImportInfo) -> (bool, Vec<(String, Option<String>)>) {
+    let mut specifiers: Vec<(String, Option<String>)> =
+        i.specifiers.iter().map(|s| (s.name.clone(), s.alias.clone())).collect();
+    specifiers.sort();
+    (i.is_type_only, specifiers)
+}
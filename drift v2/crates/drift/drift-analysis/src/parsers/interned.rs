@@ -0,0 +1,107 @@
+//! Interned symbol tables for large scans.
+//!
+//! Every `CallSite` owns its file path, callee and receiver names, so a large
+//! repository holds the same strings hundreds of thousands of times.
+//! [`SymbolTable`] interns them once (`FileId` for paths, `FunctionId` for
+//! identifiers) and [`InternedCallSite`] keeps only the ids. The call graph
+//! keeps its per-file call sites in this form for incremental rebuilds, and
+//! resolves them back to strings when resolving or reporting a call.
+//!
+//! Paths go through `PathInterner`, so resolved paths are normalized
+//! (forward slashes, no trailing slash). The table is `Sync`: files can be
+//! interned from parallel parsing workers.
+
+use drift_core::types::identifiers::{FileId, FunctionId};
+use drift_core::types::interning::{FunctionInterner, PathInterner};
+
+use super::types::CallSite;
+
+/// Interned file paths and identifiers.
+#[derive(Default)]
+pub struct SymbolTable {
+    paths: PathInterner,
+    identifiers: FunctionInterner,
+}
+
+impl SymbolTable {
+    /// Create an empty symbol table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Intern a file path.
+    pub fn file_id(&self, path: &str) -> FileId {
+        FileId::new(self.paths.intern(path))
+    }
+
+    /// Intern a function, callee or receiver name, or a call graph key.
+    pub fn function_id(&self, name: &str) -> FunctionId {
+        FunctionId::new(self.identifiers.intern(name))
+    }
+
+    /// Id of an already interned path, without inserting.
+    pub fn lookup_file(&self, path: &str) -> Option<FileId> {
+        self.paths.get(path).map(FileId::new)
+    }
+
+    /// Id of an already interned name, without inserting.
+    pub fn lookup_function(&self, name: &str) -> Option<FunctionId> {
+        self.identifiers.get(name).map(FunctionId::new)
+    }
+
+    /// Resolve a file id to its (normalized) path.
+    pub fn file(&self, id: FileId) -> &str {
+        self.paths.resolve(&id.inner())
+    }
+
+    /// Resolve a function id to its name.
+    pub fn function(&self, id: FunctionId) -> &str {
+        self.identifiers.resolve(&id.inner())
+    }
+
+    /// Intern the strings of a call site.
+    pub fn intern_call_site(&self, call: &CallSite) -> InternedCallSite {
+        self.intern_call_site_in(self.file_id(&call.file), call)
+    }
+
+    /// Intern a call site whose file is already interned (normalizing a
+    /// path allocates, so do it once per file).
+    pub fn intern_call_site_in(&self, file: FileId, call: &CallSite) -> InternedCallSite {
+        InternedCallSite {
+            file,
+            callee: self.function_id(&call.callee_name),
+            receiver: call.receiver.as_deref().map(|r| self.function_id(r)),
+            line: call.line,
+            column: call.column,
+            argument_count: call.argument_count,
+            is_await: call.is_await,
+        }
+    }
+}
+
+/// A `CallSite` with interned strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InternedCallSite {
+    pub file: FileId,
+    pub callee: FunctionId,
+    pub receiver: Option<FunctionId>,
+    pub line: u32,
+    pub column: u32,
+    pub argument_count: u8,
+    pub is_await: bool,
+}
+
+impl InternedCallSite {
+    /// Resolve back to a `CallSite`.
+    pub fn resolve(&self, symbols: &SymbolTable) -> CallSite {
+        CallSite {
+            callee_name: symbols.function(self.callee).to_string(),
+            receiver: self.receiver.map(|r| symbols.function(r).to_string()),
+            file: symbols.file(self.file).to_string(),
+            line: self.line,
+            column: self.column,
+            argument_count: self.argument_count,
+            is_await: self.is_await,
+        }
+    }
+}
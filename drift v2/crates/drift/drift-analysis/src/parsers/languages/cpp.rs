@@ -0,0 +1,233 @@
+//! C++ parser.
+//!
+//! tree-sitter-cpp keeps a function's name and parameters inside its
+//! `declarator` (possibly wrapped in pointer/reference declarators), and a
+//! member defined out of line names its class there: `void Foo::bar() {}`
+//! has a `qualified_identifier` declarator. Its scope becomes the
+//! `qualified_name` prefix (`Foo.bar`), and when `Foo` is declared in the same
+//! file the definition is also attached to its methods. Nested
+//! `namespace_definition`s are joined with `::`, and `template<...>`
+//! parameters live on the enclosing `template_declaration`.
+
+use std::path::Path;
+
+use drift_core::errors::ParseError;
+use smallvec::SmallVec;
+use tree_sitter::Node;
+
+use crate::parsers::traits::LanguageParser;
+use crate::parsers::types::{GenericParam, ParameterInfo, ParseResult, Visibility};
+use crate::scanner::language_detect::Language;
+
+use super::{node_text, parse_with_max_depth, DEFAULT_MAX_AST_DEPTH};
+
+pub struct CppParser;
+
+impl Default for CppParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CppParser {
+    pub fn new() -> Self { Self }
+}
+
+impl LanguageParser for CppParser {
+    fn language(&self) -> Language { Language::Cpp }
+    fn extensions(&self) -> &[&str] { &["cpp", "cc", "cxx", "hpp", "hxx", "hh"] }
+
+    fn parse(&self, source: &[u8], path: &Path) -> Result<ParseResult, ParseError> {
+        self.parse_with_max_depth(source, path, DEFAULT_MAX_AST_DEPTH)
+    }
+
+    fn parse_with_max_depth(&self, source: &[u8], path: &Path, max_depth: usize) -> Result<ParseResult, ParseError> {
+        let (mut result, _) = parse_with_max_depth(source, path, Language::Cpp, tree_sitter_cpp::LANGUAGE.into(), max_depth)?;
+        attach_out_of_line_methods(&mut result);
+        Ok(result)
+    }
+}
+
+/// Add `Foo::bar` definitions to `Foo`'s methods when `Foo` is in this file.
+fn attach_out_of_line_methods(result: &mut ParseResult) {
+    for func in &result.functions {
+        let Some((scope, _)) = func.qualified_name.as_deref().and_then(|qn| qn.rsplit_once('.')) else {
+            continue;
+        };
+        let class_name = scope.rsplit("::").next().unwrap_or(scope);
+        let Some(class) = result.classes.iter_mut().find(|c| c.name == class_name) else {
+            continue;
+        };
+        if !class.methods.iter().any(|m| m.line == func.line && m.name == func.name) {
+            class.methods.push(func.clone());
+        }
+    }
+}
+
+/// The `function_declarator` of a definition, under any pointer/reference
+/// declarators (`Foo& Foo::get()`).
+pub(super) fn function_declarator(node: Node) -> Option<Node> {
+    let mut declarator = node.child_by_field_name("declarator")?;
+    while declarator.kind() != "function_declarator" {
+        declarator = inner_declarator(declarator)?;
+    }
+    Some(declarator)
+}
+
+/// The declarator wrapped by a pointer/reference/array declarator.
+fn inner_declarator(node: Node) -> Option<Node> {
+    if let Some(inner) = node.child_by_field_name("declarator") {
+        return Some(inner);
+    }
+    // reference_declarator has no field for its inner declarator
+    let mut cursor = node.walk();
+    let inner = node.named_children(&mut cursor)
+        .find(|c| c.kind().ends_with("declarator") || c.kind().ends_with("identifier"));
+    inner
+}
+
+/// Name of a function definition and, for `A::Foo::bar`, its scope (`A::Foo`).
+pub(super) fn declarator_name(node: Node, source: &[u8]) -> Option<(Option<String>, String)> {
+    let name = function_declarator(node)?.child_by_field_name("declarator")?;
+    if name.kind() == "qualified_identifier" {
+        Some(qualified_parts(name, source))
+    } else {
+        Some((None, node_text(name, source)))
+    }
+}
+
+/// Split a `qualified_identifier` into its scope and final name, dropping
+/// template arguments from the scope (`Foo<T>::bar` is scoped to `Foo`).
+pub(super) fn qualified_parts(node: Node, source: &[u8]) -> (Option<String>, String) {
+    let mut scope = Vec::new();
+    let mut current = node;
+    while current.kind() == "qualified_identifier" {
+        if let Some(segment) = current.child_by_field_name("scope") {
+            let segment = segment.child_by_field_name("name").unwrap_or(segment);
+            scope.push(node_text(segment, source));
+        }
+        match current.child_by_field_name("name") {
+            Some(name) => current = name,
+            None => break,
+        }
+    }
+    let name = current.child_by_field_name("name")
+        .filter(|_| current.kind() == "template_function")
+        .unwrap_or(current);
+    let scope = Some(scope.join("::")).filter(|s| !s.is_empty());
+    (scope, node_text(name, source))
+}
+
+/// Parameters from the function declarator's `parameter_list`.
+pub(super) fn parameters(declarator: Node, source: &[u8]) -> SmallVec<[ParameterInfo; 4]> {
+    let Some(list) = declarator.child_by_field_name("parameters") else {
+        return SmallVec::new();
+    };
+    let mut cursor = list.walk();
+    list.named_children(&mut cursor)
+        .filter(|p| matches!(
+            p.kind(),
+            "parameter_declaration" | "optional_parameter_declaration" | "variadic_parameter_declaration"
+        ))
+        .map(|param| ParameterInfo {
+            name: param.child_by_field_name("declarator")
+                .map(|d| declared_identifier(d, source))
+                .unwrap_or_default(),
+            type_annotation: param.child_by_field_name("type").map(|t| node_text(t, source)),
+            default_value: param.child_by_field_name("default_value").map(|d| node_text(d, source)),
+            is_rest: param.kind() == "variadic_parameter_declaration",
+        })
+        .collect()
+}
+
+/// Identifier under pointer/reference/array declarators (`const T& value`).
+fn declared_identifier(node: Node, source: &[u8]) -> String {
+    let mut current = node;
+    while let Some(inner) = inner_declarator(current) {
+        current = inner;
+    }
+    node_text(current, source)
+}
+
+/// Template parameters from an enclosing `template<...>`.
+pub(super) fn template_params(node: Node, source: &[u8]) -> SmallVec<[GenericParam; 2]> {
+    let Some(list) = node.parent()
+        .filter(|p| p.kind() == "template_declaration")
+        .and_then(|p| p.child_by_field_name("parameters"))
+    else {
+        return SmallVec::new();
+    };
+    let mut cursor = list.walk();
+    list.named_children(&mut cursor)
+        .filter_map(|param| {
+            let name = match param.kind() {
+                // `typename T`, `typename... Ts`
+                "type_parameter_declaration" | "variadic_type_parameter_declaration" => {
+                    let mut cursor = param.walk();
+                    let name = param.named_children(&mut cursor).find(|c| c.kind() == "type_identifier");
+                    name.map(|n| node_text(n, source))
+                }
+                // `typename T = int`
+                "optional_type_parameter_declaration" => param.child_by_field_name("name").map(|n| node_text(n, source)),
+                // `int N`, `int N = 4`
+                "parameter_declaration" | "optional_parameter_declaration" => {
+                    param.child_by_field_name("declarator").map(|d| declared_identifier(d, source))
+                }
+                _ => None,
+            }?;
+            Some(GenericParam { name, bounds: SmallVec::new() })
+        })
+        .collect()
+}
+
+/// Enclosing namespaces, outermost first, joined with `::` (`A::B`).
+/// Anonymous namespaces contribute no segment.
+pub(super) fn namespace_path(node: Node, source: &[u8]) -> Option<String> {
+    let mut segments = Vec::new();
+    let mut current = Some(node);
+    while let Some(n) = current {
+        if n.kind() == "namespace_definition" {
+            if let Some(name) = n.child_by_field_name("name") {
+                segments.push(node_text(name, source));
+            }
+        }
+        current = n.parent();
+    }
+    segments.reverse();
+    Some(segments.join("::")).filter(|s| !s.is_empty())
+}
+
+/// Base classes from `class Foo : public Base, private Mixin`: the first is
+/// `extends`, the rest (multiple inheritance) go to `implements`.
+pub(super) fn base_classes(node: Node, source: &[u8]) -> (Option<String>, SmallVec<[String; 2]>) {
+    let mut cursor = node.walk();
+    let Some(clause) = node.children(&mut cursor).find(|c| c.kind() == "base_class_clause") else {
+        return (None, SmallVec::new());
+    };
+    let mut cursor = clause.walk();
+    let mut bases = clause.named_children(&mut cursor)
+        .filter(|c| c.kind() != "access_specifier" && c.kind() != "virtual")
+        .map(|c| node_text(c, source));
+    let extends = bases.next();
+    (extends, bases.collect())
+}
+
+/// Visibility of a member defined inside a class body: the nearest preceding
+/// access specifier, else the class (private) or struct (public) default.
+pub(super) fn member_visibility(node: Node, source: &[u8]) -> Option<Visibility> {
+    let member = node.parent().filter(|p| p.kind() == "template_declaration").unwrap_or(node);
+    let body = member.parent().filter(|p| p.kind() == "field_declaration_list")?;
+    let mut sibling = member.prev_sibling();
+    while let Some(s) = sibling {
+        if s.kind() == "access_specifier" {
+            return Some(match node_text(s, source).trim_end_matches(':').trim() {
+                "private" => Visibility::Private,
+                "protected" => Visibility::Protected,
+                _ => Visibility::Public,
+            });
+        }
+        sibling = s.prev_sibling();
+    }
+    let is_struct = body.parent().is_some_and(|c| c.kind() == "struct_specifier");
+    Some(if is_struct { Visibility::Public } else { Visibility::Private })
+}
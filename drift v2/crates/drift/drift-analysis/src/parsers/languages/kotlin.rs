@@ -5,7 +5,7 @@ use drift_core::errors::ParseError;
 use crate::scanner::language_detect::Language;
 use crate::parsers::traits::LanguageParser;
 use crate::parsers::types::ParseResult;
-use super::parse_with_language;
+use super::{parse_with_max_depth, DEFAULT_MAX_AST_DEPTH};
 
 pub struct KotlinParser;
 
@@ -24,6 +24,10 @@ impl LanguageParser for KotlinParser {
     fn extensions(&self) -> &[&str] { &["kt", "kts"] }
 
     fn parse(&self, source: &[u8], path: &Path) -> Result<ParseResult, ParseError> {
-        parse_with_language(source, path, Language::Kotlin, tree_sitter_kotlin_sg::LANGUAGE.into())
+        self.parse_with_max_depth(source, path, DEFAULT_MAX_AST_DEPTH)
+    }
+
+    fn parse_with_max_depth(&self, source: &[u8], path: &Path, max_depth: usize) -> Result<ParseResult, ParseError> {
+        parse_with_max_depth(source, path, Language::Kotlin, tree_sitter_kotlin_sg::LANGUAGE.into(), max_depth).map(|(result, _)| result)
     }
 }
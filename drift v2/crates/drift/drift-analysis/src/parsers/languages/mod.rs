@@ -1,5 +1,6 @@
 //! Per-language parser implementations.
 
+pub mod cpp;
 pub mod csharp;
 pub mod go;
 pub mod java;
@@ -9,6 +10,7 @@ pub mod php;
 pub mod python;
 pub mod ruby;
 pub mod rust_lang;
+pub mod swift;
 pub mod typescript;
 
 use std::path::Path;
@@ -22,6 +24,28 @@ use super::error_tolerant::count_errors;
 use super::types::*;
 use crate::scanner::language_detect::Language;
 use crate::scanner::hasher::hash_content;
+use crate::structural::complexity::cognitive_complexity;
+
+/// AST depth structural extraction descends to unless configured otherwise
+/// (`ScanConfig::max_ast_depth`).
+pub const DEFAULT_MAX_AST_DEPTH: usize = 50;
+
+/// Per-parse settings for [`parse_with_options`] and
+/// `ParserManager::parse_with_language_options`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// AST depth structural extraction descends to. Deeper subtrees are
+    /// skipped and counted in `ParseResult::truncated_at_depth`.
+    pub max_depth: usize,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_AST_DEPTH,
+        }
+    }
+}
 
 /// Shared parsing logic used by all language parsers via the `define_parser!` macro.
 pub fn parse_with_language(
@@ -30,7 +54,18 @@ pub fn parse_with_language(
     language: Language,
     ts_language: tree_sitter::Language,
 ) -> Result<ParseResult, ParseError> {
-    parse_with_language_and_tree(source, path, language, ts_language).map(|(r, _)| r)
+    parse_with_options(source, path, language, ts_language, &ParseOptions::default())
+}
+
+/// Like [`parse_with_language`] with explicit [`ParseOptions`].
+pub fn parse_with_options(
+    source: &[u8],
+    path: &Path,
+    language: Language,
+    ts_language: tree_sitter::Language,
+    options: &ParseOptions,
+) -> Result<ParseResult, ParseError> {
+    parse_with_max_depth(source, path, language, ts_language, options.max_depth).map(|(r, _)| r)
 }
 
 /// Like [`parse_with_language`] but also returns the tree-sitter Tree,
@@ -41,6 +76,19 @@ pub fn parse_with_language_and_tree(
     path: &Path,
     language: Language,
     ts_language: tree_sitter::Language,
+) -> Result<(ParseResult, tree_sitter::Tree), ParseError> {
+    parse_with_max_depth(source, path, language, ts_language, DEFAULT_MAX_AST_DEPTH)
+}
+
+/// Like [`parse_with_language_and_tree`] with a custom extraction depth
+/// limit. Nodes below `max_depth` are not visited; the first place that
+/// happens is recorded in `ParseResult::truncated_at_depth`.
+pub fn parse_with_max_depth(
+    source: &[u8],
+    path: &Path,
+    language: Language,
+    ts_language: tree_sitter::Language,
+    max_depth: usize,
 ) -> Result<(ParseResult, tree_sitter::Tree), ParseError> {
     let start = Instant::now();
     let file_str = path.to_string_lossy().to_string();
@@ -48,9 +96,9 @@ pub fn parse_with_language_and_tree(
 
     // Parse with tree-sitter
     let mut parser = Parser::new();
-    parser.set_language(&ts_language).map_err(|_e| ParseError::GrammarNotFound {
-        language: language.name().to_string(),
-    })?;
+    parser
+        .set_language(&ts_language)
+        .map_err(|_e| grammar_error(language, ts_language.abi_version()))?;
 
     let tree = parser.parse(source, None).ok_or_else(|| ParseError::TreeSitterError {
         path: path.to_path_buf(),
@@ -73,17 +121,38 @@ pub fn parse_with_language_and_tree(
     };
 
     // Extract functions, classes, imports, exports from the tree
-    extract_structure(&mut result, root, source, &file_str);
-    extract_calls(&mut result, root, source, &file_str);
+    extract_structure(&mut result, root, source, &file_str, max_depth);
+    extract_calls(&mut result, root, source, &file_str, max_depth);
 
     result.parse_time_us = start.elapsed().as_micros() as u64;
     Ok((result, tree))
 }
 
+/// Classify a `set_language` failure.
+///
+/// tree-sitter only rejects a grammar whose ABI version falls outside the
+/// range the linked core supports, which usually means a grammar crate was
+/// upgraded without bumping `tree-sitter`. Anything else is reported as a
+/// missing grammar.
+pub fn grammar_error(language: Language, grammar_abi: usize) -> ParseError {
+    let supported = tree_sitter::MIN_COMPATIBLE_LANGUAGE_VERSION..=tree_sitter::LANGUAGE_VERSION;
+    if supported.contains(&grammar_abi) {
+        ParseError::GrammarNotFound {
+            language: language.name().to_string(),
+        }
+    } else {
+        ParseError::GrammarAbiMismatch {
+            language: language.name().to_string(),
+            grammar_abi: grammar_abi as u32,
+            supported_range: *supported.start() as u32..=*supported.end() as u32,
+        }
+    }
+}
+
 /// Extract structural elements (functions, classes, imports, exports) from the AST.
-fn extract_structure(result: &mut ParseResult, root: Node, source: &[u8], file: &str) {
+fn extract_structure(result: &mut ParseResult, root: Node, source: &[u8], file: &str, max_depth: usize) {
     let mut cursor = root.walk();
-    extract_node_recursive(result, &mut cursor, source, file, 0);
+    extract_node_recursive(result, &mut cursor, source, file, 0, max_depth);
 }
 
 fn extract_node_recursive(
@@ -92,6 +161,7 @@ fn extract_node_recursive(
     source: &[u8],
     file: &str,
     depth: usize,
+    max_depth: usize,
 ) {
     let node = cursor.node();
     let kind = node.kind();
@@ -104,24 +174,44 @@ fn extract_node_recursive(
                 result.functions.push(func);
             }
         }
+        // Swift initializers
+        "init_declaration" => {
+            if let Some(func) = extract_function(node, source, file) {
+                result.functions.push(func);
+            }
+        }
         // Arrow functions (JS/TS)
         "arrow_function" => {
             if let Some(func) = extract_arrow_function(node, source, file) {
                 result.functions.push(func);
             }
         }
+        // C++ class/struct definitions (not forward declarations or type uses)
+        "class_specifier" | "struct_specifier"
+            if result.language == Language::Cpp && node.child_by_field_name("body").is_some() =>
+        {
+            if let Some(class) = extract_class(node, source, file, result.language) {
+                result.classes.push(class);
+            }
+        }
         // Classes
         "class_declaration" | "class_definition" | "class" => {
             if let Some(class) = extract_class(node, source, file, result.language) {
                 result.classes.push(class);
             }
         }
-        // Interfaces
-        "interface_declaration" => {
+        // Interfaces (Swift protocols)
+        "interface_declaration" | "protocol_declaration" => {
             if let Some(class) = extract_interface(node, source, file) {
                 result.classes.push(class);
             }
         }
+        // Type aliases (TypeScript)
+        "type_alias_declaration" => {
+            if let Some(alias) = extract_type_alias(node, source, file) {
+                result.classes.push(alias);
+            }
+        }
         // Structs (Rust, Go)
         "struct_item" | "type_spec" => {
             // Go type_spec: only extract if it contains a struct_type
@@ -181,6 +271,10 @@ fn extract_node_recursive(
                 result.exports.push(export);
             }
         }
+        // C++ namespaces nest: `namespace A { namespace B {` is `A::B`
+        "namespace_definition" if result.language == Language::Cpp => {
+            result.namespace = cpp::namespace_path(node, source);
+        }
         // Namespace/Package
         "package_declaration" | "package_clause" | "package_header"
         | "namespace_declaration" | "namespace_definition" => {
@@ -190,9 +284,11 @@ fn extract_node_recursive(
     }
 
     // Recurse into children
-    if depth < 50 && cursor.goto_first_child() {
+    if depth >= max_depth {
+        note_truncation(result, &node, max_depth);
+    } else if cursor.goto_first_child() {
         loop {
-            extract_node_recursive(result, cursor, source, file, depth + 1);
+            extract_node_recursive(result, cursor, source, file, depth + 1, max_depth);
             if !cursor.goto_next_sibling() {
                 break;
             }
@@ -202,9 +298,9 @@ fn extract_node_recursive(
 }
 
 /// Extract call sites, decorators, literals from the AST.
-fn extract_calls(result: &mut ParseResult, root: Node, source: &[u8], file: &str) {
+fn extract_calls(result: &mut ParseResult, root: Node, source: &[u8], file: &str, max_depth: usize) {
     let mut cursor = root.walk();
-    extract_calls_recursive(result, &mut cursor, source, file, 0);
+    extract_calls_recursive(result, &mut cursor, source, file, 0, max_depth);
 }
 
 fn extract_calls_recursive(
@@ -213,11 +309,38 @@ fn extract_calls_recursive(
     source: &[u8],
     file: &str,
     depth: usize,
+    max_depth: usize,
 ) {
     let node = cursor.node();
     let kind = node.kind();
 
     match kind {
+        // Swift `defer { }` parses as a call to `defer`
+        "call_expression" if result.language == Language::Swift && swift::is_defer(node, source) => {
+            result.error_handling.push(ErrorHandlingInfo {
+                kind: ErrorHandlingKind::Defer,
+                file: file.to_string(),
+                line: node.start_position().row as u32,
+                end_line: node.end_position().row as u32,
+                range: Range::from_ts_node(&node),
+                caught_type: None,
+                has_body: true,
+                function_scope: find_enclosing_function_name(node, source),
+            });
+        }
+        "call_expression" if result.language == Language::Swift => {
+            if let Some((callee_name, receiver)) = swift::call_target(node, source) {
+                result.call_sites.push(CallSite {
+                    callee_name,
+                    receiver,
+                    file: file.to_string(),
+                    line: node.start_position().row as u32,
+                    column: node.start_position().column as u32,
+                    argument_count: swift::argument_count(node),
+                    is_await: node.parent().is_some_and(|p| p.kind() == "await_expression"),
+                });
+            }
+        }
         "call_expression" | "call" | "method_invocation" | "invocation_expression"
         | "function_call_expression" | "member_call_expression" => {
             if let Some(call) = extract_call_site(node, source, file) {
@@ -361,8 +484,25 @@ fn extract_calls_recursive(
                 });
             }
         }
+        // Swift: `try` marks a throwing call and `do`/`catch` handles it;
+        // elsewhere `do_statement` is a do-while loop
+        "try_expression" if result.language == Language::Swift => {}
+        // DP-RERR-04: Rust `expr?` → TryOperator (propagation, not a handler)
+        "try_expression" if result.language == Language::Rust => {
+            result.error_handling.push(ErrorHandlingInfo {
+                kind: ErrorHandlingKind::TryOperator,
+                file: file.to_string(),
+                line: node.start_position().row as u32,
+                end_line: node.end_position().row as u32,
+                range: Range::from_ts_node(&node),
+                caught_type: None,
+                has_body: false,
+                function_scope: find_enclosing_function_name(node, source),
+            });
+        }
+        "do_statement" if result.language != Language::Swift => {}
         // Error handling: try/catch with proper has_body and caught_type extraction
-        "try_statement" | "try_expression" => {
+        "try_statement" | "try_expression" | "do_statement" => {
             let mut eh_kind = ErrorHandlingKind::TryCatch;
             let mut caught_type = None;
             let mut has_body = true;
@@ -376,7 +516,7 @@ fn extract_calls_recursive(
             for i in 0..node.child_count() {
                 if let Some(child) = node.child(i) {
                     match child.kind() {
-                        "catch_clause" | "except_clause" | "rescue_clause" | "rescue" => {
+                        "catch_clause" | "except_clause" | "rescue_clause" | "rescue" | "catch_block" => {
                             // DP-ERR-02: Extract caught type
                             caught_type = extract_catch_type(child, source);
                             // DP-ERR-01: Check if catch body is empty
@@ -495,9 +635,10 @@ fn extract_calls_recursive(
         _ => {}
     }
 
-    if depth < 50 && cursor.goto_first_child() {
+    // The structure pass walks the same nodes and has already noted any truncation
+    if depth < max_depth && cursor.goto_first_child() {
         loop {
-            extract_calls_recursive(result, cursor, source, file, depth + 1);
+            extract_calls_recursive(result, cursor, source, file, depth + 1, max_depth);
             if !cursor.goto_next_sibling() {
                 break;
             }
@@ -506,41 +647,76 @@ fn extract_calls_recursive(
     }
 }
 
+/// Record a node whose children extraction skipped; the first one gives the position.
+fn note_truncation(result: &mut ParseResult, node: &Node, max_depth: usize) {
+    if node.child_count() == 0 {
+        return;
+    }
+    let start = node.start_position();
+    let truncation = result.truncated_at_depth.get_or_insert(DepthTruncation {
+        max_depth: max_depth as u32,
+        position: Position {
+            line: start.row as u32,
+            column: start.column as u32,
+        },
+        skipped_subtrees: 0,
+    });
+    truncation.skipped_subtrees += 1;
+}
+
 // ---- Extraction helpers ----
 
 fn extract_function(node: Node, source: &[u8], file: &str) -> Option<FunctionInfo> {
-    let name = find_child_text(&node, source, &["identifier", "property_identifier",
-        "field_identifier", "name", "simple_identifier"])?;
+    // C++: an out-of-line member (`void Foo::bar()`) names its class
+    let mut qualified_name = None;
+    let name = if node.kind() == "init_declaration" {
+        "init".to_string()
+    } else if let Some((scope, name)) = cpp::declarator_name(node, source) {
+        qualified_name = scope.map(|scope| format!("{}.{}", scope, name));
+        name
+    } else {
+        find_child_text(&node, source, &["identifier", "property_identifier",
+            "field_identifier", "name", "simple_identifier"])?
+    };
     let body = node.child_by_field_name("body");
     let body_text = body.map(|b| node_text(b, source)).unwrap_or_default();
     let params_text = node.child_by_field_name("parameters")
+        .or_else(|| cpp::function_declarator(node).and_then(|d| d.child_by_field_name("parameters")))
         .map(|p| node_text(p, source))
         .unwrap_or_default();
     let return_type = node.child_by_field_name("return_type")
         .or_else(|| node.child_by_field_name("type"))
-        .map(|t| node_text(t, source));
+        .map(|t| node_text(t, source))
+        .or_else(|| swift::return_type(node, source));
 
     let sig_return = return_type.as_deref().unwrap_or("");
     let sig_hash = hash_content(format!("{}({}){}", name, params_text, sig_return).as_bytes());
 
-    // DP-FUNC-05: Extract visibility
-    let visibility = extract_visibility(node, source);
+    // DP-FUNC-05: Extract visibility (C++: from the class body's access specifiers)
+    let visibility = cpp::member_visibility(node, source)
+        .unwrap_or_else(|| extract_visibility(node, source));
 
     // DP-FUNC-01: Determine is_exported based on language conventions
     let is_exported = detect_is_exported(node, source, &name, visibility);
 
-    // DP-FUNC-03: Extract generic type parameters
-    let generic_params = extract_generic_params(node, source);
+    // DP-FUNC-03: Extract generic type parameters (C++: enclosing template)
+    let mut generic_params = extract_generic_params(node, source);
+    if generic_params.is_empty() {
+        generic_params = cpp::template_params(node, source);
+    }
 
     // DP-FUNC-02: Extract doc comment from previous sibling
     let doc_comment = extract_doc_comment_for_node(node, source);
 
-    // DP-FUNC-04: Link decorators from previous siblings
-    let decorators = extract_decorators_for_node(node, source);
+    // DP-FUNC-04: Link decorators from previous siblings (Swift: modifiers)
+    let mut decorators = extract_decorators_for_node(node, source);
+    if decorators.is_empty() {
+        decorators = swift::attributes(node, source);
+    }
 
     Some(FunctionInfo {
         name: name.clone(),
-        qualified_name: None,
+        qualified_name,
         file: file.to_string(),
         line: node.start_position().row as u32,
         column: node.start_position().column as u32,
@@ -558,6 +734,7 @@ fn extract_function(node: Node, source: &[u8], file: &str) -> Option<FunctionInf
         doc_comment,
         body_hash: hash_content(body_text.as_bytes()),
         signature_hash: sig_hash,
+        cognitive_complexity: cognitive_complexity(node, source),
     })
 }
 
@@ -607,23 +784,36 @@ fn extract_arrow_function(node: Node, source: &[u8], file: &str) -> Option<Funct
         doc_comment,
         body_hash: hash_content(body_text.as_bytes()),
         signature_hash: 0,
+        cognitive_complexity: cognitive_complexity(node, source),
     })
 }
 
-fn extract_class(node: Node, source: &[u8], file: &str, _lang: Language) -> Option<ClassInfo> {
-    let name = find_child_text(&node, source, &[
-        "identifier", "type_identifier", "constant", "name",
-    ])?;
-
-    let extends = node.child_by_field_name("superclass")
-        .or_else(|| find_child_by_kind(&node, "class_heritage"))
-        .and_then(|n| extract_text_from_node(n, source));
+fn extract_class(node: Node, source: &[u8], file: &str, lang: Language) -> Option<ClassInfo> {
+    let name = if lang == Language::Swift {
+        swift::type_name(node, source)?
+    } else {
+        find_child_text(&node, source, &[
+            "identifier", "type_identifier", "constant", "name",
+        ])?
+    };
 
-    // DP-CLASS-01: Extract implements
-    let implements = extract_implements(node, source);
+    let (extends, implements) = match lang {
+        Language::Swift => swift::supertypes(node, source),
+        Language::Cpp => cpp::base_classes(node, source),
+        _ => {
+            let extends = node.child_by_field_name("superclass")
+                .or_else(|| find_child_by_kind(&node, "class_heritage"))
+                .and_then(|n| extract_text_from_node(n, source));
+            // DP-CLASS-01: Extract implements
+            (extends, extract_implements(node, source))
+        }
+    };
 
-    // DP-CLASS-02: Extract generic params on classes
-    let generic_params = extract_generic_params(node, source);
+    // DP-CLASS-02: Extract generic params on classes (C++: enclosing template)
+    let mut generic_params = extract_generic_params(node, source);
+    if generic_params.is_empty() {
+        generic_params = cpp::template_params(node, source);
+    }
 
     // DP-CLASS-04: is_exported on classes
     let visibility = extract_visibility(node, source);
@@ -649,6 +839,20 @@ fn extract_class(node: Node, source: &[u8], file: &str, _lang: Language) -> Opti
                             methods.push(func);
                         }
                     }
+                    "function_declaration" | "init_declaration" if lang == Language::Swift => {
+                        if let Some(mut func) = extract_function(child, source, file) {
+                            func.qualified_name = Some(format!("{}.{}", name, func.name));
+                            methods.push(func);
+                        }
+                    }
+                    // C++ member templates: `template <typename T> void set(T value) {}`
+                    "template_declaration" if lang == Language::Cpp => {
+                        let definition = find_child_by_kind(&child, "function_definition");
+                        if let Some(mut func) = definition.and_then(|d| extract_function(d, source, file)) {
+                            func.qualified_name = Some(format!("{}.{}", name, func.name));
+                            methods.push(func);
+                        }
+                    }
                     "public_field_definition" | "field_declaration" | "property_declaration" => {
                         if let Some(prop) = extract_property(child, source) {
                             properties.push(prop);
@@ -665,17 +869,22 @@ fn extract_class(node: Node, source: &[u8], file: &str, _lang: Language) -> Opti
 
     Some(ClassInfo {
         name,
-        namespace: None,
+        namespace: if lang == Language::Cpp { cpp::namespace_path(node, source) } else { None },
         extends,
         implements,
         generic_params,
         is_exported,
         is_abstract: has_child_kind(&node, "abstract"),
-        class_kind: ClassKind::Class,
+        class_kind: match lang {
+            Language::Swift => swift::class_kind(node),
+            Language::Cpp if node.kind() == "struct_specifier" => ClassKind::Struct,
+            _ => ClassKind::Class,
+        },
         methods,
         properties,
         range: Range::from_ts_node(&node),
         decorators,
+        alias_target: None,
     })
 }
 
@@ -684,10 +893,18 @@ fn extract_interface(node: Node, source: &[u8], _file: &str) -> Option<ClassInfo
     let generic_params = extract_generic_params(node, source);
     let visibility = extract_visibility(node, source);
     let is_exported = detect_is_exported(node, source, &name, visibility);
+    // TypeScript `extends_type_clause`, Java `extends_interfaces`: `A, B`
+    let extends = find_child_by_kind(&node, "extends_type_clause")
+        .or_else(|| find_child_by_kind(&node, "extends_interfaces"))
+        .and_then(|n| extract_text_from_node(n, source))
+        .map(|text| text.trim_start_matches("extends").trim().to_string())
+        .filter(|text| !text.is_empty())
+        // Swift protocol inheritance: `protocol Store: AnyObject, Loader`
+        .or_else(|| Some(swift::inherited_types(node, source).join(", ")).filter(|t| !t.is_empty()));
     Some(ClassInfo {
         name,
         namespace: None,
-        extends: None,
+        extends,
         implements: SmallVec::new(),
         generic_params,
         is_exported,
@@ -697,6 +914,29 @@ fn extract_interface(node: Node, source: &[u8], _file: &str) -> Option<ClassInfo
         properties: Vec::new(),
         range: Range::from_ts_node(&node),
         decorators: extract_decorators_for_node(node, source),
+        alias_target: None,
+    })
+}
+
+fn extract_type_alias(node: Node, source: &[u8], _file: &str) -> Option<ClassInfo> {
+    let name = find_child_text(&node, source, &["type_identifier", "name"])?;
+    let generic_params = extract_generic_params(node, source);
+    let visibility = extract_visibility(node, source);
+    let is_exported = detect_is_exported(node, source, &name, visibility);
+    Some(ClassInfo {
+        name,
+        namespace: None,
+        extends: None,
+        implements: SmallVec::new(),
+        generic_params,
+        is_exported,
+        is_abstract: false,
+        class_kind: ClassKind::TypeAlias,
+        methods: Vec::new(),
+        properties: Vec::new(),
+        range: Range::from_ts_node(&node),
+        decorators: Vec::new(),
+        alias_target: node.child_by_field_name("value").map(|v| node_text(v, source)),
     })
 }
 
@@ -718,6 +958,7 @@ fn extract_struct(node: Node, source: &[u8], _file: &str) -> Option<ClassInfo> {
         properties: Vec::new(),
         range: Range::from_ts_node(&node),
         decorators: Vec::new(),
+        alias_target: None,
     })
 }
 
@@ -738,6 +979,7 @@ fn extract_enum(node: Node, source: &[u8], _file: &str) -> Option<ClassInfo> {
         properties: Vec::new(),
         range: Range::from_ts_node(&node),
         decorators: Vec::new(),
+        alias_target: None,
     })
 }
 
@@ -759,6 +1001,7 @@ fn extract_trait(node: Node, source: &[u8], _file: &str) -> Option<ClassInfo> {
         properties: Vec::new(),
         range: Range::from_ts_node(&node),
         decorators: Vec::new(),
+        alias_target: None,
     })
 }
 
@@ -1030,10 +1273,16 @@ fn extract_call_target(node: Node, source: &[u8]) -> Option<(String, Option<Stri
             "identifier" | "name" | "simple_identifier" => {
                 return Some((node_text(func, source), None));
             }
+            // C++: `Foo::create()`, `std::move(x)`
+            "qualified_identifier" => {
+                let (scope, name) = cpp::qualified_parts(func, source);
+                return Some((name, scope));
+            }
             "member_expression" | "member_access_expression" | "selector_expression"
             | "field_expression" | "attribute" | "navigation_expression" => {
                 let obj = func.child_by_field_name("object")
                     .or_else(|| func.child_by_field_name("operand"))
+                    .or_else(|| func.child_by_field_name("argument"))
                     .map(|n| node_text(n, source));
                 let prop = func.child_by_field_name("property")
                     .or_else(|| func.child_by_field_name("field"))
@@ -1064,18 +1313,106 @@ fn extract_call_target(node: Node, source: &[u8]) -> Option<(String, Option<Stri
 }
 
 fn extract_decorator(node: Node, source: &[u8]) -> Option<DecoratorInfo> {
+    if php::is_attribute(node) {
+        return Some(php::attribute(node, source));
+    }
     let name = find_child_text(&node, source, &[
         "identifier", "name", "type_identifier", "call_expression",
     ]).unwrap_or_else(|| node_text(node, source));
 
     Some(DecoratorInfo {
         name,
-        arguments: SmallVec::new(),
+        arguments: decorator_arguments(node, source),
         raw_text: node_text(node, source),
         range: Range::from_ts_node(&node),
     })
 }
 
+/// Argument lists of decorator calls: Python, JS/TS, Java, C#, Kotlin.
+const DECORATOR_ARGUMENT_LISTS: &[&str] = &[
+    "argument_list", "arguments", "annotation_argument_list", "attribute_argument_list",
+    "value_arguments",
+];
+
+/// Arguments of a decorator call, keyed where the source names them:
+/// Python `methods=["POST"]`, Java `name = "users"`, C# `Name = "users"`,
+/// Kotlin `name = "users"`, and the properties of an options object passed
+/// to a TS/JS decorator factory (`@Entity({ name: 'users' })`).
+fn decorator_arguments(node: Node, source: &[u8]) -> SmallVec<[DecoratorArgument; 2]> {
+    let mut arguments = SmallVec::new();
+    let Some(list) = find_argument_list(node) else {
+        return arguments;
+    };
+    let mut cursor = list.walk();
+    for arg in list.named_children(&mut cursor) {
+        match arg.kind() {
+            "comment" | "line_comment" | "block_comment" => {}
+            "keyword_argument" | "element_value_pair" => {
+                let key = arg.child_by_field_name("name").or_else(|| arg.child_by_field_name("key"));
+                if let Some(value) = arg.child_by_field_name("value") {
+                    arguments.push(DecoratorArgument {
+                        key: key.map(|k| node_text(k, source)),
+                        value: argument_value(value, source),
+                    });
+                }
+            }
+            "object" => {
+                let mut pairs = arg.walk();
+                for pair in arg.named_children(&mut pairs).filter(|p| p.kind() == "pair") {
+                    let (Some(key), Some(value)) = (pair.child_by_field_name("key"), pair.child_by_field_name("value")) else {
+                        continue;
+                    };
+                    arguments.push(DecoratorArgument {
+                        key: Some(argument_value(key, source)),
+                        value: argument_value(value, source),
+                    });
+                }
+            }
+            // C# `attribute_argument` and Kotlin `value_argument` wrap an
+            // optional `name =` / `name:` before the value.
+            "attribute_argument" | "value_argument" => {
+                let mut parts = arg.walk();
+                let named: Vec<Node> = arg.named_children(&mut parts).collect();
+                let Some(value) = named.last() else {
+                    continue;
+                };
+                let key = named.iter().rev().skip(1).find_map(|part| match part.kind() {
+                    "name_equals" | "name_colon" => find_child_text(part, source, &["identifier"]),
+                    "simple_identifier" | "identifier" => Some(node_text(*part, source)),
+                    _ => None,
+                });
+                arguments.push(DecoratorArgument { key, value: argument_value(*value, source) });
+            }
+            _ => arguments.push(DecoratorArgument { key: None, value: argument_value(arg, source) }),
+        }
+    }
+    arguments
+}
+
+/// The outermost decorator argument list under `node`.
+fn find_argument_list(node: Node) -> Option<Node> {
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.named_children(&mut cursor).collect();
+    children.into_iter().find_map(|child| {
+        if DECORATOR_ARGUMENT_LISTS.contains(&child.kind()) {
+            Some(child)
+        } else {
+            find_argument_list(child)
+        }
+    })
+}
+
+/// Decorator argument value: string literals without their quotes, other
+/// expressions as source text.
+fn argument_value(node: Node, source: &[u8]) -> String {
+    let text = node_text(node, source);
+    if node.kind().contains("string") {
+        text.trim_matches(|c| c == '"' || c == '\'' || c == '`').to_string()
+    } else {
+        text
+    }
+}
+
 fn extract_string_literal(node: Node, source: &[u8], file: &str) -> Option<StringLiteralInfo> {
     let text = node_text(node, source);
     // Strip quotes
@@ -1108,6 +1445,14 @@ fn extract_numeric_literal(node: Node, source: &[u8], file: &str) -> Option<Nume
 
 fn extract_parameters(node: Node, source: &[u8]) -> SmallVec<[ParameterInfo; 4]> {
     let mut params = SmallVec::new();
+    if has_child_kind(&node, "parameter") && node.child_by_field_name("parameters").is_none() {
+        // Swift: parameters are direct children of the declaration
+        return swift::parameters(node, source);
+    }
+    if let Some(declarator) = cpp::function_declarator(node) {
+        // C++: parameters belong to the function declarator
+        return cpp::parameters(declarator, source);
+    }
     if let Some(param_list) = node.child_by_field_name("parameters") {
         let mut cursor = param_list.walk();
         if cursor.goto_first_child() {
@@ -1148,7 +1493,11 @@ fn extract_property(node: Node, source: &[u8]) -> Option<PropertyInfo> {
     ])?;
     Some(PropertyInfo {
         name,
-        type_annotation: node.child_by_field_name("type").map(|t| node_text(t, source)),
+        type_annotation: node.child_by_field_name("type")
+            .map(|t| node_text(t, source))
+            // Swift: `let store: Store`
+            .or_else(|| find_child_by_kind(&node, "type_annotation")
+                .map(|t| node_text(t, source).trim_start_matches(':').trim().to_string())),
         is_static: has_child_kind(&node, "static"),
         is_readonly: has_child_kind(&node, "readonly"),
         visibility: Visibility::Public,
@@ -1239,7 +1588,8 @@ fn detect_is_exported(node: Node, source: &[u8], name: &str, visibility: Visibil
                 let kind = child.kind();
                 if kind == "modifiers" || kind == "visibility_modifier" || kind == "accessibility_modifier" {
                     let child_text = node_text(child, source).to_lowercase();
-                    if child_text.contains("public") {
+                    // Swift `open` is public and overridable
+                    if child_text.contains("public") || child_text.split_whitespace().any(|w| w == "open") {
                         return true;
                     }
                     // If it has a visibility modifier but it's not public, it's not exported
@@ -1375,9 +1725,13 @@ fn extract_doc_comment_for_node(node: Node, source: &[u8]) -> Option<String> {
     Some(doc_lines.join("\n"))
 }
 
-/// DP-FUNC-04 / DP-CLASS-03: Extract decorators from previous siblings of a node.
+/// DP-FUNC-04 / DP-CLASS-03: Extract decorators from previous siblings of a node
+/// (PHP: the declaration's own attribute list).
 fn extract_decorators_for_node(node: Node, source: &[u8]) -> Vec<DecoratorInfo> {
-    let mut decorators = Vec::new();
+    let mut decorators = php::attributes(node, source);
+    if !decorators.is_empty() {
+        return decorators;
+    }
     let mut prev = node.prev_named_sibling();
 
     while let Some(sibling) = prev {
@@ -1,11 +1,19 @@
 //! PHP parser.
+//!
+//! PHP 8 attributes (`#[Route('/users', methods: ['GET'])]`) are children of
+//! the declaration they annotate, grouped as `attribute_list` →
+//! `attribute_group` → `attribute`, rather than preceding siblings like
+//! decorators in other grammars. The helpers below turn them into
+//! `DecoratorInfo`s with their arguments.
 
 use std::path::Path;
 use drift_core::errors::ParseError;
+use smallvec::SmallVec;
+use tree_sitter::Node;
 use crate::scanner::language_detect::Language;
 use crate::parsers::traits::LanguageParser;
-use crate::parsers::types::ParseResult;
-use super::parse_with_language;
+use crate::parsers::types::{DecoratorArgument, DecoratorInfo, ParseResult, Range};
+use super::{argument_value, node_text, parse_with_max_depth, DEFAULT_MAX_AST_DEPTH};
 
 pub struct PhpParser;
 
@@ -24,6 +32,70 @@ impl LanguageParser for PhpParser {
     fn extensions(&self) -> &[&str] { &["php"] }
 
     fn parse(&self, source: &[u8], path: &Path) -> Result<ParseResult, ParseError> {
-        parse_with_language(source, path, Language::Php, tree_sitter_php::LANGUAGE_PHP.into())
+        self.parse_with_max_depth(source, path, DEFAULT_MAX_AST_DEPTH)
+    }
+
+    fn parse_with_max_depth(&self, source: &[u8], path: &Path, max_depth: usize) -> Result<ParseResult, ParseError> {
+        parse_with_max_depth(source, path, Language::Php, tree_sitter_php::LANGUAGE_PHP.into(), max_depth).map(|(result, _)| result)
+    }
+}
+
+/// Attributes declared on a class, method, function, property or parameter.
+pub(super) fn attributes(node: Node, source: &[u8]) -> Vec<DecoratorInfo> {
+    let Some(list) = node.child_by_field_name("attributes") else {
+        return Vec::new();
+    };
+    let mut decorators = Vec::new();
+    let mut groups = list.walk();
+    for group in list.named_children(&mut groups).filter(|g| g.kind() == "attribute_group") {
+        let mut cursor = group.walk();
+        decorators.extend(
+            group
+                .named_children(&mut cursor)
+                .filter(|a| a.kind() == "attribute")
+                .map(|a| attribute(a, source)),
+        );
+    }
+    decorators
+}
+
+/// Whether `node` is a PHP `attribute` (as opposed to a Python or Swift one).
+pub(super) fn is_attribute(node: Node) -> bool {
+    node.kind() == "attribute" && node.parent().is_some_and(|p| p.kind() == "attribute_group")
+}
+
+/// One attribute: its unqualified name and arguments. String, number,
+/// boolean and null arguments are stored as their value (quotes removed);
+/// anything else (arrays, constants, `new` expressions) as source text.
+pub(super) fn attribute(node: Node, source: &[u8]) -> DecoratorInfo {
+    let mut cursor = node.walk();
+    let name = node
+        .named_children(&mut cursor)
+        .find(|c| matches!(c.kind(), "name" | "qualified_name"))
+        .map(|n| node_text(n, source))
+        .unwrap_or_else(|| node_text(node, source));
+    let name = name.rsplit('\\').next().unwrap_or(&name).to_string();
+
+    let mut arguments = SmallVec::new();
+    if let Some(args) = node.child_by_field_name("parameters") {
+        let mut cursor = args.walk();
+        for arg in args.named_children(&mut cursor).filter(|a| a.kind() == "argument") {
+            let key_node = arg.child_by_field_name("name");
+            let mut values = arg.walk();
+            let value = arg.named_children(&mut values).filter(|v| Some(*v) != key_node).last();
+            if let Some(value) = value {
+                arguments.push(DecoratorArgument {
+                    key: key_node.map(|k| node_text(k, source)),
+                    value: argument_value(value, source),
+                });
+            }
+        }
+    }
+
+    DecoratorInfo {
+        name,
+        arguments,
+        raw_text: node_text(node, source),
+        range: Range::from_ts_node(&node),
     }
 }
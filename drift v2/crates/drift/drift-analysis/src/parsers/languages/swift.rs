@@ -0,0 +1,213 @@
+//! Swift parser.
+//!
+//! tree-sitter-swift differs from the other grammars in a few shapes the
+//! shared extraction handles through the helpers below: `class`, `struct`,
+//! `enum`, `actor` and `extension` are all `class_declaration` (told apart
+//! by their keyword), parameters are direct children of the function, calls
+//! are a callee followed by a `call_suffix`, and `defer { }` parses as a call.
+//!
+//! Supertypes are written as one list (`class A: Base, Proto`). Only a class
+//! can have a superclass, and only as the first entry, so that entry becomes
+//! `extends` unless it is a protocol (declared in the file, or a well-known
+//! one); everything else is a conformance in `implements`.
+
+use std::path::Path;
+
+use drift_core::errors::ParseError;
+use smallvec::SmallVec;
+use tree_sitter::Node;
+
+use crate::parsers::traits::LanguageParser;
+use crate::parsers::types::{ClassKind, DecoratorInfo, ParameterInfo, ParseResult};
+use crate::scanner::language_detect::Language;
+
+use super::{extract_decorator, node_text, parse_with_max_depth, DEFAULT_MAX_AST_DEPTH};
+
+/// Standard library and Foundation protocols commonly listed first.
+const KNOWN_PROTOCOLS: &[&str] = &[
+    "Codable", "Decodable", "Encodable", "Equatable", "Hashable", "Comparable", "Identifiable",
+    "CustomStringConvertible", "CustomDebugStringConvertible", "Sendable", "Error", "CaseIterable",
+    "ObservableObject", "Sequence", "Collection", "AnyObject",
+];
+
+pub struct SwiftParser;
+
+impl Default for SwiftParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SwiftParser {
+    pub fn new() -> Self { Self }
+}
+
+impl LanguageParser for SwiftParser {
+    fn language(&self) -> Language { Language::Swift }
+    fn extensions(&self) -> &[&str] { &["swift"] }
+
+    fn parse(&self, source: &[u8], path: &Path) -> Result<ParseResult, ParseError> {
+        self.parse_with_max_depth(source, path, DEFAULT_MAX_AST_DEPTH)
+    }
+
+    fn parse_with_max_depth(&self, source: &[u8], path: &Path, max_depth: usize) -> Result<ParseResult, ParseError> {
+        let (mut result, _) = parse_with_max_depth(source, path, Language::Swift, tree_sitter_swift::LANGUAGE.into(), max_depth)?;
+        resolve_superclasses(&mut result);
+        Ok(result)
+    }
+}
+
+/// Move a class's first supertype out of `extends` when it is a protocol.
+fn resolve_superclasses(result: &mut ParseResult) {
+    let protocols: Vec<String> = result
+        .classes
+        .iter()
+        .filter(|c| c.class_kind == ClassKind::Interface)
+        .map(|c| c.name.clone())
+        .collect();
+    for class in &mut result.classes {
+        let Some(first) = class.extends.take() else {
+            continue;
+        };
+        let name = first.split('<').next().unwrap_or(&first);
+        if protocols.iter().any(|p| p == name)
+            || KNOWN_PROTOCOLS.contains(&name)
+            || name.ends_with("Delegate")
+            || name.ends_with("DataSource")
+            || name.ends_with("Protocol")
+        {
+            class.implements.insert(0, first);
+        } else {
+            class.extends = Some(first);
+        }
+    }
+}
+
+/// Kind of a `class_declaration`, from its keyword.
+pub(super) fn class_kind(node: Node) -> ClassKind {
+    let mut cursor = node.walk();
+    let keyword = node.children(&mut cursor).find(|c| !c.is_named()).map(|c| c.kind());
+    match keyword {
+        Some("struct") => ClassKind::Struct,
+        Some("enum") => ClassKind::Enum,
+        _ => ClassKind::Class,
+    }
+}
+
+/// Declared name of a type; for an extension, the extended type.
+pub(super) fn type_name(node: Node, source: &[u8]) -> Option<String> {
+    let name = node.child_by_field_name("name")?;
+    Some(node_text(name, source))
+}
+
+/// Supertypes listed after `:`, in order.
+pub(super) fn inherited_types(node: Node, source: &[u8]) -> Vec<String> {
+    let mut cursor = node.walk();
+    node.children(&mut cursor)
+        .filter(|c| c.kind() == "inheritance_specifier")
+        .filter_map(|c| c.child_by_field_name("inherits_from"))
+        .map(|t| node_text(t, source))
+        .collect()
+}
+
+/// Supertypes of a class declaration: the first is a superclass candidate
+/// for classes (settled by `resolve_superclasses`), the rest conformances.
+pub(super) fn supertypes(node: Node, source: &[u8]) -> (Option<String>, SmallVec<[String; 2]>) {
+    let mut types = inherited_types(node, source).into_iter();
+    let is_class = class_kind(node) == ClassKind::Class && !is_extension(node);
+    let extends = if is_class { types.next() } else { None };
+    (extends, types.collect())
+}
+
+fn is_extension(node: Node) -> bool {
+    let mut cursor = node.walk();
+    let is_extension = node.children(&mut cursor).any(|c| c.kind() == "extension");
+    is_extension
+}
+
+/// Parameters of a function or initializer (direct `parameter` children).
+pub(super) fn parameters(node: Node, source: &[u8]) -> SmallVec<[ParameterInfo; 4]> {
+    let mut cursor = node.walk();
+    node.children(&mut cursor)
+        .filter(|c| c.kind() == "parameter")
+        .filter_map(|param| {
+            // `name` labels both the parameter name and its type.
+            let mut field_cursor = param.walk();
+            let mut named = param.children_by_field_name("name", &mut field_cursor);
+            let name = node_text(named.next()?, source);
+            let type_annotation = named.next().map(|t| node_text(t, source));
+            Some(ParameterInfo {
+                name,
+                is_rest: node_text(param, source).ends_with("..."),
+                type_annotation,
+                default_value: None,
+            })
+        })
+        .collect()
+}
+
+/// Return type: the type following `->`.
+pub(super) fn return_type(node: Node, source: &[u8]) -> Option<String> {
+    let mut cursor = node.walk();
+    let mut children = node.children(&mut cursor).skip_while(|c| c.kind() != "->");
+    children.next()?;
+    children.find(|c| c.is_named()).map(|t| node_text(t, source))
+}
+
+/// Attributes (`@objc`, `@MainActor`) among a declaration's modifiers.
+pub(super) fn attributes(node: Node, source: &[u8]) -> Vec<DecoratorInfo> {
+    let mut cursor = node.walk();
+    let Some(modifiers) = node.children(&mut cursor).find(|c| c.kind() == "modifiers") else {
+        return Vec::new();
+    };
+    let mut cursor = modifiers.walk();
+    let attributes = modifiers
+        .children(&mut cursor)
+        .filter(|c| c.kind() == "attribute")
+        .filter_map(|a| extract_decorator(a, source))
+        .collect();
+    attributes
+}
+
+/// Callee and receiver of a `call_expression`: `f(...)` or `a.b.f(...)`.
+pub(super) fn call_target(node: Node, source: &[u8]) -> Option<(String, Option<String>)> {
+    let callee = node.named_child(0)?;
+    match callee.kind() {
+        "simple_identifier" => Some((node_text(callee, source), None)),
+        "navigation_expression" => {
+            let receiver = callee.child_by_field_name("target").map(|t| node_text(t, source));
+            let suffix = callee.child_by_field_name("suffix")?;
+            let method = suffix.child_by_field_name("suffix").unwrap_or(suffix);
+            Some((node_text(method, source).trim_start_matches('.').to_string(), receiver))
+        }
+        _ => None,
+    }
+}
+
+/// Number of arguments, counting a trailing closure.
+pub(super) fn argument_count(node: Node) -> u8 {
+    let mut cursor = node.walk();
+    let Some(suffix) = node.children(&mut cursor).find(|c| c.kind() == "call_suffix") else {
+        return 0;
+    };
+    let mut count = 0u8;
+    let mut cursor = suffix.walk();
+    for part in suffix.children(&mut cursor) {
+        match part.kind() {
+            "value_arguments" => {
+                let mut args = part.walk();
+                let values = part.children(&mut args).filter(|a| a.kind() == "value_argument").count();
+                count = count.saturating_add(values.min(u8::MAX as usize) as u8);
+            }
+            "lambda_literal" => count = count.saturating_add(1),
+            _ => {}
+        }
+    }
+    count
+}
+
+/// Whether a `call_expression` is a `defer { }` block.
+pub(super) fn is_defer(node: Node, source: &[u8]) -> bool {
+    node.named_child(0)
+        .is_some_and(|callee| callee.kind() == "simple_identifier" && node_text(callee, source) == "defer")
+}
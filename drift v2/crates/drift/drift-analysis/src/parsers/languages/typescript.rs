@@ -5,7 +5,7 @@ use drift_core::errors::ParseError;
 use crate::scanner::language_detect::Language;
 use crate::parsers::traits::LanguageParser;
 use crate::parsers::types::ParseResult;
-use super::parse_with_language;
+use super::{parse_with_max_depth, DEFAULT_MAX_AST_DEPTH};
 
 pub struct TypeScriptParser;
 
@@ -24,12 +24,16 @@ impl LanguageParser for TypeScriptParser {
     fn extensions(&self) -> &[&str] { &["ts", "tsx", "mts", "cts"] }
 
     fn parse(&self, source: &[u8], path: &Path) -> Result<ParseResult, ParseError> {
+        self.parse_with_max_depth(source, path, DEFAULT_MAX_AST_DEPTH)
+    }
+
+    fn parse_with_max_depth(&self, source: &[u8], path: &Path, max_depth: usize) -> Result<ParseResult, ParseError> {
         let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("ts");
         let ts_lang = if ext == "tsx" {
             tree_sitter_typescript::LANGUAGE_TSX.into()
         } else {
             tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into()
         };
-        parse_with_language(source, path, Language::TypeScript, ts_lang)
+        parse_with_max_depth(source, path, Language::TypeScript, ts_lang, max_depth).map(|(result, _)| result)
     }
 }
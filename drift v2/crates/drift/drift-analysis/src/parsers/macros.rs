@@ -42,6 +42,22 @@ macro_rules! define_parser {
                     Self::ts_language(),
                 )
             }
+
+            fn parse_with_max_depth(
+                &self,
+                source: &[u8],
+                path: &std::path::Path,
+                max_depth: usize,
+            ) -> Result<$crate::parsers::types::ParseResult, drift_core::errors::ParseError> {
+                $crate::parsers::languages::parse_with_max_depth(
+                    source,
+                    path,
+                    $language,
+                    Self::ts_language(),
+                    max_depth,
+                )
+                .map(|(result, _)| result)
+            }
         }
     };
 }
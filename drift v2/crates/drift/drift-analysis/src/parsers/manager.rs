@@ -1,10 +1,13 @@
 //! ParserManager — routes files to the correct language parser.
 
+use std::collections::HashMap;
 use std::path::Path;
 
+use drift_core::config::VisibilityPolicy;
 use drift_core::errors::ParseError;
 
 use super::cache::ParseCache;
+use super::languages::cpp::CppParser;
 use super::languages::csharp::CSharpParser;
 use super::languages::go::GoParser;
 use super::languages::java::JavaParser;
@@ -14,9 +17,12 @@ use super::languages::php::PhpParser;
 use super::languages::python::PythonParser;
 use super::languages::ruby::RubyParser;
 use super::languages::rust_lang::RustParser;
+use super::languages::swift::SwiftParser;
 use super::languages::typescript::TypeScriptParser;
+use super::languages::{ParseOptions, DEFAULT_MAX_AST_DEPTH};
 use super::traits::LanguageParser;
 use super::types::ParseResult;
+use super::visibility::apply_visibility_policy;
 use crate::scanner::hasher::hash_content;
 use crate::scanner::language_detect::Language;
 
@@ -33,6 +39,11 @@ pub struct ParserManager {
     ruby: RubyParser,
     php: PhpParser,
     kotlin: KotlinParser,
+    swift: SwiftParser,
+    cpp: CppParser,
+    max_depth: usize,
+    /// Configured visibility/export rules per language.
+    visibility: HashMap<Language, VisibilityPolicy>,
 }
 
 impl ParserManager {
@@ -50,6 +61,10 @@ impl ParserManager {
             ruby: RubyParser::new(),
             php: PhpParser::new(),
             kotlin: KotlinParser::new(),
+            swift: SwiftParser::new(),
+            cpp: CppParser::new(),
+            max_depth: DEFAULT_MAX_AST_DEPTH,
+            visibility: HashMap::new(),
         }
     }
 
@@ -61,6 +76,23 @@ impl ParserManager {
         }
     }
 
+    /// Set the AST depth structural extraction descends to
+    /// (`ScanConfig::effective_max_ast_depth`).
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Apply per-language visibility policies (`ScanConfig::visibility`).
+    /// Keys that name no supported language are ignored.
+    pub fn with_visibility_policies(mut self, policies: &HashMap<String, VisibilityPolicy>) -> Self {
+        self.visibility = policies
+            .iter()
+            .filter_map(|(name, policy)| Some((Language::from_name(name)?, policy.clone())))
+            .collect();
+        self
+    }
+
     /// Get the parser for a given language.
     fn parser_for(&self, lang: Language) -> &dyn LanguageParser {
         match lang {
@@ -74,10 +106,12 @@ impl ParserManager {
             Language::Ruby => &self.ruby,
             Language::Php => &self.php,
             Language::Kotlin => &self.kotlin,
-            // C/C++ use C# parser as closest approximation until dedicated parsers are added
-            Language::Cpp | Language::C => &self.csharp,
-            // Swift/Scala use Java parser as closest approximation
-            Language::Swift | Language::Scala => &self.java,
+            Language::Cpp => &self.cpp,
+            // C uses the C# parser as closest approximation until a dedicated parser is added
+            Language::C => &self.csharp,
+            Language::Swift => &self.swift,
+            // Scala uses the Java parser as closest approximation
+            Language::Scala => &self.java,
         }
     }
 
@@ -107,8 +141,9 @@ impl ParserManager {
 
         // Parse (fallback parsers may set the wrong language, e.g. CSharp for C files)
         let parser = self.parser_for(lang);
-        let mut result = parser.parse(source, path)?;
+        let mut result = parser.parse_with_max_depth(source, path, self.max_depth)?;
         result.language = lang;
+        self.apply_policies(&mut result, source);
 
         // Cache the result
         self.cache.insert(content_hash, lang, result.clone());
@@ -123,16 +158,39 @@ impl ParserManager {
         path: &Path,
         lang: Language,
     ) -> Result<ParseResult, ParseError> {
+        let options = ParseOptions {
+            max_depth: self.max_depth,
+        };
+        self.parse_with_language_options(source, path, lang, &options)
+    }
+
+    /// Parse a file with a known language and explicit [`ParseOptions`].
+    ///
+    /// The cache holds results at the manager's own depth limit, so it is
+    /// bypassed when `options` asks for another one.
+    pub fn parse_with_language_options(
+        &self,
+        source: &[u8],
+        path: &Path,
+        lang: Language,
+        options: &ParseOptions,
+    ) -> Result<ParseResult, ParseError> {
+        let cacheable = options.max_depth == self.max_depth;
         let content_hash = hash_content(source);
 
-        if let Some(cached) = self.cache.get(content_hash, lang) {
-            return Ok(cached);
+        if cacheable {
+            if let Some(cached) = self.cache.get(content_hash, lang) {
+                return Ok(cached);
+            }
         }
 
         let parser = self.parser_for(lang);
-        let mut result = parser.parse(source, path)?;
+        let mut result = parser.parse_with_max_depth(source, path, options.max_depth)?;
         result.language = lang;
-        self.cache.insert(content_hash, lang, result.clone());
+        self.apply_policies(&mut result, source);
+        if cacheable {
+            self.cache.insert(content_hash, lang, result.clone());
+        }
         Ok(result)
     }
 
@@ -158,9 +216,10 @@ impl ParserManager {
 
         let ts_lang = lang.ts_language_for_ext(path.extension().and_then(|e| e.to_str()));
 
-        let (result, tree) = super::languages::parse_with_language_and_tree(
-            source, path, lang, ts_lang,
+        let (mut result, tree) = super::languages::parse_with_max_depth(
+            source, path, lang, ts_lang, self.max_depth,
         )?;
+        self.apply_policies(&mut result, source);
 
         let content_hash = hash_content(source);
         self.cache.insert(content_hash, lang, result.clone());
@@ -168,6 +227,12 @@ impl ParserManager {
         Ok((result, tree))
     }
 
+    fn apply_policies(&self, result: &mut ParseResult, source: &[u8]) {
+        if let Some(policy) = self.visibility.get(&result.language) {
+            apply_visibility_policy(result, source, policy);
+        }
+    }
+
     /// Get the number of cached parse results.
     pub fn cache_entry_count(&self) -> u64 {
         self.cache.entry_count()
@@ -1,13 +1,19 @@
 //! Tree-sitter parser subsystem — 10 languages, thread_local instances, parse cache.
 
 pub mod cache;
+pub mod diff;
 pub mod error_tolerant;
+pub mod interned;
 pub mod languages;
 pub mod macros;
 pub mod manager;
 pub mod queries;
+pub mod summary;
 pub mod traits;
 pub mod types;
+pub mod visibility;
 
+pub use diff::ParseDiff;
 pub use manager::ParserManager;
+pub use summary::{summarize, RepoSummary};
 pub use types::ParseResult;
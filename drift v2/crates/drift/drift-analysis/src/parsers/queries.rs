@@ -27,8 +27,10 @@ pub fn structure_query_for(lang: Language) -> &'static str {
         Language::Ruby => RUBY_STRUCTURE_QUERY,
         Language::Php => PHP_STRUCTURE_QUERY,
         Language::Kotlin => KOTLIN_STRUCTURE_QUERY,
-        Language::Cpp | Language::C => CSHARP_STRUCTURE_QUERY,
-        Language::Swift | Language::Scala => JAVA_STRUCTURE_QUERY,
+        Language::Swift => SWIFT_STRUCTURE_QUERY,
+        Language::Cpp => CPP_STRUCTURE_QUERY,
+        Language::C => CSHARP_STRUCTURE_QUERY,
+        Language::Scala => JAVA_STRUCTURE_QUERY,
     }
 }
 
@@ -44,8 +46,10 @@ pub fn calls_query_for(lang: Language) -> &'static str {
         Language::Ruby => RUBY_CALLS_QUERY,
         Language::Php => PHP_CALLS_QUERY,
         Language::Kotlin => KOTLIN_CALLS_QUERY,
-        Language::Cpp | Language::C => CSHARP_CALLS_QUERY,
-        Language::Swift | Language::Scala => JAVA_CALLS_QUERY,
+        Language::Swift => SWIFT_CALLS_QUERY,
+        Language::Cpp => CPP_CALLS_QUERY,
+        Language::C => CSHARP_CALLS_QUERY,
+        Language::Scala => JAVA_CALLS_QUERY,
     }
 }
 
@@ -395,3 +399,86 @@ const KOTLIN_CALLS_QUERY: &str = r#"
 (try_expression) @try_catch
 (throw) @throw
 "#;
+
+// ---- Swift ----
+
+const SWIFT_STRUCTURE_QUERY: &str = r#"
+(function_declaration
+  name: (simple_identifier) @function.name) @function.def
+
+(init_declaration) @constructor.def
+
+(class_declaration
+  name: (_) @class.name) @class.def
+
+(protocol_declaration
+  name: (type_identifier) @interface.name) @interface.def
+
+(import_declaration) @import
+"#;
+
+const SWIFT_CALLS_QUERY: &str = r#"
+(call_expression
+  (simple_identifier) @call.name) @call
+
+(call_expression
+  (navigation_expression
+    target: (_) @call.receiver
+    suffix: (navigation_suffix
+      suffix: (simple_identifier) @call.method))) @call.member
+
+(attribute
+  (user_type
+    (type_identifier) @decorator.name)) @decorator
+
+(line_string_literal) @string_literal
+(integer_literal) @numeric_literal
+(real_literal) @numeric_literal
+
+(do_statement) @try_catch
+(throw_keyword) @throw
+"#;
+
+// ---- C++ ----
+
+const CPP_STRUCTURE_QUERY: &str = r#"
+(function_definition
+  declarator: (function_declarator
+    declarator: (_) @function.name)) @function.def
+
+(class_specifier
+  name: (_) @class.name
+  body: (field_declaration_list)) @class.def
+
+(struct_specifier
+  name: (_) @struct.name
+  body: (field_declaration_list)) @struct.def
+
+(template_declaration
+  parameters: (template_parameter_list) @generic.params) @generic
+
+(preproc_include) @import
+
+(namespace_definition) @namespace
+"#;
+
+const CPP_CALLS_QUERY: &str = r#"
+(call_expression
+  function: (identifier) @call.name) @call
+
+(call_expression
+  function: (field_expression
+    argument: (_) @call.receiver
+    field: (field_identifier) @call.method)) @call.member
+
+(call_expression
+  function: (qualified_identifier
+    scope: (_) @call.receiver
+    name: (identifier) @call.method)) @call.qualified
+
+(string_literal) @string_literal
+(number_literal) @numeric_literal
+
+(try_statement) @try_catch
+(throw_statement) @throw
+"#;
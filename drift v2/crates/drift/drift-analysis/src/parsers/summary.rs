@@ -0,0 +1,74 @@
+//! Repository-at-a-glance statistics aggregated from parse results.
+//!
+//! A cheap pass over data the parsers already extracted: no re-parsing and
+//! no graph construction, so it can back a landing-page view before the full
+//! analysis finishes. Methods count as functions, since parsers record them
+//! in `ParseResult::functions` as well as on their class.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::types::ParseResult;
+
+/// Aggregate shape of a set of parsed files.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RepoSummary {
+    pub files: usize,
+    /// File count per language name, sorted by name.
+    pub files_by_language: BTreeMap<String, usize>,
+    pub functions: usize,
+    pub classes: usize,
+    pub imports: usize,
+    /// Mean function length in lines, including the signature line.
+    pub avg_function_length: f64,
+    /// Share of functions and classes that are exported.
+    pub exported_ratio: f64,
+    /// Share of functions that are `async`.
+    pub async_ratio: f64,
+    /// Error-handling constructs (try/catch, `?`, error checks) per function.
+    pub error_handling_density: f64,
+}
+
+/// Summarize `parse_results`. Ratios are 0.0 when there is nothing to divide by.
+pub fn summarize(parse_results: &[ParseResult]) -> RepoSummary {
+    let mut summary = RepoSummary {
+        files: parse_results.len(),
+        ..Default::default()
+    };
+    let mut function_lines = 0u64;
+    let mut exported = 0usize;
+    let mut async_functions = 0usize;
+    let mut error_handling = 0usize;
+
+    for pr in parse_results {
+        *summary
+            .files_by_language
+            .entry(pr.language.name().to_string())
+            .or_default() += 1;
+        summary.functions += pr.functions.len();
+        summary.classes += pr.classes.len();
+        summary.imports += pr.imports.len();
+        error_handling += pr.error_handling.len();
+        for func in &pr.functions {
+            function_lines += u64::from(func.end_line.saturating_sub(func.line)) + 1;
+            exported += usize::from(func.is_exported);
+            async_functions += usize::from(func.is_async);
+        }
+        exported += pr.classes.iter().filter(|c| c.is_exported).count();
+    }
+
+    summary.avg_function_length = ratio(function_lines as f64, summary.functions);
+    summary.exported_ratio = ratio(exported as f64, summary.functions + summary.classes);
+    summary.async_ratio = ratio(async_functions as f64, summary.functions);
+    summary.error_handling_density = ratio(error_handling as f64, summary.functions);
+    summary
+}
+
+fn ratio(numerator: f64, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator / denominator as f64
+    }
+}
@@ -17,4 +17,11 @@ pub trait LanguageParser: Send + Sync {
 
     /// Parse source code and produce a ParseResult.
     fn parse(&self, source: &[u8], path: &Path) -> Result<ParseResult, ParseError>;
+
+    /// Parse with a custom AST depth limit for structural extraction.
+    /// Parsers without a depth limit ignore `max_depth`.
+    fn parse_with_max_depth(&self, source: &[u8], path: &Path, max_depth: usize) -> Result<ParseResult, ParseError> {
+        let _ = max_depth;
+        self.parse(source, path)
+    }
 }
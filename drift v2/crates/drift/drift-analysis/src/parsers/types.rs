@@ -9,7 +9,7 @@ use smallvec::SmallVec;
 use crate::scanner::language_detect::Language;
 
 /// Canonical parse result produced by every language parser.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct ParseResult {
     pub file: String,
     pub language: Language,
@@ -37,6 +37,30 @@ pub struct ParseResult {
     pub error_count: u32,
     pub error_ranges: Vec<Range>,
     pub has_errors: bool,
+    /// Set when extraction hit the AST depth limit, so functions, calls or
+    /// literals below that level are missing from this result.
+    #[serde(default)]
+    pub truncated_at_depth: Option<DepthTruncation>,
+}
+
+/// Where structural extraction stopped descending.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct DepthTruncation {
+    /// The depth limit in effect.
+    pub max_depth: u32,
+    /// Start of the first node whose children were skipped.
+    pub position: Position,
+    /// Number of subtrees skipped, so a single overly nested expression can
+    /// be told apart from a file that is generated throughout.
+    #[serde(default)]
+    pub skipped_subtrees: u32,
+}
+
+impl ParseResult {
+    /// Whether extraction hit the AST depth limit (`truncated_at_depth` is set).
+    pub fn hit_depth_limit(&self) -> bool {
+        self.truncated_at_depth.is_some()
+    }
 }
 
 impl Default for ParseResult {
@@ -60,11 +84,33 @@ impl Default for ParseResult {
             error_count: 0,
             error_ranges: Vec::new(),
             has_errors: false,
+            truncated_at_depth: None,
+        }
+    }
+}
+
+/// JSON Schema (draft 2020-12) for a serialized [`ParseResult`] and all of
+/// its nested types, so non-Rust consumers and fixture checks have a
+/// contract to validate against.
+///
+/// schemars emits the 2019-09 vocabulary (no tuple `items`), which is valid
+/// 2020-12; only the `$schema` URI is changed, and definitions are placed
+/// under `$defs` (schemars 0.8 always serializes them as `definitions`).
+pub fn parse_result_schema() -> serde_json::Value {
+    let mut settings = schemars::gen::SchemaSettings::draft2019_09();
+    settings.meta_schema = Some("https://json-schema.org/draft/2020-12/schema".to_string());
+    settings.definitions_path = "#/$defs/".to_string();
+    let schema = settings.into_generator().into_root_schema_for::<ParseResult>();
+    let mut value = serde_json::to_value(schema).expect("JSON Schema serializes to JSON");
+    if let Some(root) = value.as_object_mut() {
+        if let Some(definitions) = root.remove("definitions") {
+            root.insert("$defs".to_string(), definitions);
         }
     }
+    value
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct FunctionInfo {
     pub name: String,
     pub qualified_name: Option<String>,
@@ -85,9 +131,13 @@ pub struct FunctionInfo {
     pub doc_comment: Option<String>,
     pub body_hash: u64,
     pub signature_hash: u64,
+    /// SonarSource cognitive complexity of the body
+    /// (`structural::complexity::cognitive_complexity`).
+    #[serde(default)]
+    pub cognitive_complexity: u32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct ClassInfo {
     pub name: String,
     pub namespace: Option<String>,
@@ -101,9 +151,12 @@ pub struct ClassInfo {
     pub properties: Vec<PropertyInfo>,
     pub range: Range,
     pub decorators: Vec<DecoratorInfo>,
+    /// For `ClassKind::TypeAlias`, the aliased type (`type Id = string | number`).
+    #[serde(default)]
+    pub alias_target: Option<String>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, schemars::JsonSchema)]
 pub enum ClassKind {
     Class,
     Interface,
@@ -115,7 +168,7 @@ pub enum ClassKind {
     TypeAlias,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct DecoratorInfo {
     pub name: String,
     pub arguments: SmallVec<[DecoratorArgument; 2]>,
@@ -123,13 +176,13 @@ pub struct DecoratorInfo {
     pub range: Range,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct DecoratorArgument {
     pub key: Option<String>,
     pub value: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct CallSite {
     pub callee_name: String,
     pub receiver: Option<String>,
@@ -140,7 +193,7 @@ pub struct CallSite {
     pub is_await: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct ImportInfo {
     pub source: String,
     pub specifiers: SmallVec<[ImportSpecifier; 4]>,
@@ -149,13 +202,13 @@ pub struct ImportInfo {
     pub line: u32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct ImportSpecifier {
     pub name: String,
     pub alias: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct ExportInfo {
     pub name: Option<String>,
     pub is_default: bool,
@@ -165,7 +218,7 @@ pub struct ExportInfo {
     pub line: u32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct StringLiteralInfo {
     pub value: String,
     pub context: StringContext,
@@ -175,7 +228,7 @@ pub struct StringLiteralInfo {
     pub range: Range,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
 pub enum StringContext {
     FunctionArgument,
     VariableAssignment,
@@ -186,7 +239,7 @@ pub enum StringContext {
     Unknown,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct NumericLiteralInfo {
     pub value: f64,
     pub raw: String,
@@ -197,7 +250,7 @@ pub struct NumericLiteralInfo {
     pub range: Range,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
 pub enum NumericContext {
     ConstDeclaration,
     VariableAssignment,
@@ -211,7 +264,7 @@ pub enum NumericContext {
     Unknown,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct ErrorHandlingInfo {
     pub kind: ErrorHandlingKind,
     pub file: String,
@@ -223,14 +276,17 @@ pub struct ErrorHandlingInfo {
     pub function_scope: Option<String>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
 pub enum ErrorHandlingKind {
     TryCatch,
     TryExcept,
     TryFinally,
     Throw,
     ResultMatch,
+    /// Rust `?` as reported before `TryOperator`; treated the same.
     QuestionMark,
+    /// Rust `?`: propagates the error to the caller rather than handling it.
+    TryOperator,
     Unwrap,
     PromiseCatch,
     AsyncAwaitTry,
@@ -240,7 +296,7 @@ pub enum ErrorHandlingKind {
     WithStatement,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct DocCommentInfo {
     pub text: String,
     pub style: DocCommentStyle,
@@ -249,7 +305,7 @@ pub struct DocCommentInfo {
     pub range: Range,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
 pub enum DocCommentStyle {
     JsDoc,
     TripleSlash,
@@ -260,7 +316,7 @@ pub enum DocCommentStyle {
     GoDoc,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct ParameterInfo {
     pub name: String,
     pub type_annotation: Option<String>,
@@ -268,7 +324,7 @@ pub struct ParameterInfo {
     pub is_rest: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct PropertyInfo {
     pub name: String,
     pub type_annotation: Option<String>,
@@ -277,13 +333,13 @@ pub struct PropertyInfo {
     pub visibility: Visibility,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 pub struct GenericParam {
     pub name: String,
     pub bounds: SmallVec<[String; 2]>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, schemars::JsonSchema)]
 pub enum Visibility {
     #[default]
     Public,
@@ -291,13 +347,13 @@ pub enum Visibility {
     Protected,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, schemars::JsonSchema)]
 pub struct Position {
     pub line: u32,
     pub column: u32,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, schemars::JsonSchema)]
 pub struct Range {
     pub start: Position,
     pub end: Position,
@@ -0,0 +1,177 @@
+//! Configured visibility and export rules (`ScanConfig::visibility`).
+//!
+//! Extraction reads `visibility` and `is_exported` by built-in, per-language
+//! conventions. A [`VisibilityPolicy`] revises them afterwards from the
+//! declaration's own text — the modifier keywords before its name and, for
+//! Python, the module's `__all__`. The default policy leaves every result
+//! exactly as extracted.
+
+use drift_core::config::{ExportRule, VisibilityLevel, VisibilityPolicy};
+
+use super::types::{FunctionInfo, ParseResult, Visibility};
+
+/// Keywords that give a declaration an explicit visibility.
+const VISIBILITY_KEYWORDS: &[&str] = &["public", "private", "protected", "internal", "fileprivate", "open", "pub"];
+
+/// How far past a declaration's start its name is looked for.
+const MAX_HEAD_LEN: usize = 512;
+
+/// Revise the visibility and export flags of `result` under `policy`.
+pub fn apply_visibility_policy(result: &mut ParseResult, source: &[u8], policy: &VisibilityPolicy) {
+    if *policy == VisibilityPolicy::default() {
+        return;
+    }
+    let text = String::from_utf8_lossy(source);
+    let resolver = Resolver {
+        policy,
+        text: &text,
+        line_starts: std::iter::once(0).chain(text.match_indices('\n').map(|(i, _)| i + 1)).collect(),
+        dunder_all: match policy.exports {
+            ExportRule::DunderAll => dunder_all(&text),
+            _ => None,
+        },
+    };
+
+    for func in &mut result.functions {
+        resolver.apply(func);
+    }
+    for class in &mut result.classes {
+        let start = class.range.start;
+        let head = resolver.head(start.line, start.column, &class.name);
+        let visibility = resolver.visibility(&class.name, head, keyword_visibility(head));
+        class.is_exported = resolver.is_exported(&class.name, start.column, visibility, class.is_exported);
+        for method in &mut class.methods {
+            resolver.apply(method);
+        }
+    }
+}
+
+struct Resolver<'a> {
+    policy: &'a VisibilityPolicy,
+    text: &'a str,
+    /// Byte offset of each line.
+    line_starts: Vec<usize>,
+    /// Names in the module's `__all__`, when it has one.
+    dunder_all: Option<Vec<String>>,
+}
+
+impl Resolver<'_> {
+    fn apply(&self, func: &mut FunctionInfo) {
+        let head = self.head(func.line, func.column, &func.name);
+        func.visibility = self.visibility(&func.name, head, func.visibility);
+        func.is_exported = self.is_exported(&func.name, func.column, func.visibility, func.is_exported);
+    }
+
+    /// Source from a declaration's start up to its name: the modifiers and
+    /// keywords (`internal fun `, `pub(crate) fn `), plus any modifiers
+    /// written before the node on the same line (Ruby `private def`). Empty
+    /// when the name is not found nearby (arrow functions named by their
+    /// variable).
+    fn head(&self, line: u32, column: u32, name: &str) -> &str {
+        let Some(&line_start) = self.line_starts.get(line as usize) else {
+            return "";
+        };
+        let start = line_start + column as usize;
+        let Some(rest) = self.text.get(start..) else {
+            return "";
+        };
+        // Stop at the previous declaration or block on the same line.
+        let lead = self
+            .text
+            .get(line_start..start)
+            .and_then(|prefix| prefix.rfind(['{', '}', ';']).map(|i| line_start + i + 1))
+            .unwrap_or(line_start);
+        let is_ident = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+        rest.match_indices(name)
+            .map(|(i, _)| i)
+            .take_while(|&i| i < MAX_HEAD_LEN)
+            .find(|&i| !is_ident(rest[..i].chars().next_back()) && !is_ident(rest[i + name.len()..].chars().next()))
+            .map_or("", |i| &self.text[lead..start + i])
+    }
+
+    /// A configured modifier wins; without any visibility keyword the
+    /// configured default applies; otherwise `extracted` stands.
+    /// ECMAScript `#private` members are always private.
+    fn visibility(&self, name: &str, head: &str, extracted: Visibility) -> Visibility {
+        if name.starts_with('#') {
+            return Visibility::Private;
+        }
+        let words: Vec<&str> = head.split_whitespace().collect();
+        if let Some(level) = words.iter().find_map(|w| self.policy.modifiers.get(*w)) {
+            return visibility_of(*level);
+        }
+        let has_keyword = words.iter().any(|w| VISIBILITY_KEYWORDS.contains(w) || w.starts_with("pub("));
+        match self.policy.default_visibility {
+            Some(level) if !has_keyword => visibility_of(level),
+            _ => extracted,
+        }
+    }
+
+    fn is_exported(&self, name: &str, column: u32, visibility: Visibility, extracted: bool) -> bool {
+        match self.policy.exports {
+            ExportRule::Conventions => extracted,
+            ExportRule::Visibility => visibility == Visibility::Public,
+            // Python declarations start at column 0 only at module level
+            ExportRule::DunderAll => {
+                column == 0
+                    && match &self.dunder_all {
+                        Some(names) => names.iter().any(|n| n == name),
+                        None => !name.starts_with('_'),
+                    }
+            }
+            ExportRule::Capitalized => name.chars().next().is_some_and(char::is_uppercase),
+        }
+    }
+}
+
+fn visibility_of(level: VisibilityLevel) -> Visibility {
+    match level {
+        VisibilityLevel::Public => Visibility::Public,
+        VisibilityLevel::Protected => Visibility::Protected,
+        VisibilityLevel::Private => Visibility::Private,
+    }
+}
+
+/// Built-in reading of a declaration's modifiers, for classes (which do not
+/// record a visibility of their own).
+fn keyword_visibility(head: &str) -> Visibility {
+    let words: Vec<&str> = head.split_whitespace().collect();
+    if words.iter().any(|w| matches!(*w, "private" | "fileprivate")) {
+        Visibility::Private
+    } else if words.contains(&"protected") {
+        Visibility::Protected
+    } else {
+        Visibility::Public
+    }
+}
+
+/// Names listed in module-level `__all__ = [...]` / `__all__ += (...)`
+/// assignments, or `None` when the module has none.
+fn dunder_all(text: &str) -> Option<Vec<String>> {
+    let mut names: Option<Vec<String>> = None;
+    for (at, _) in text.match_indices("__all__") {
+        if at > 0 && !text[..at].ends_with('\n') {
+            continue;
+        }
+        let rest = text[at + "__all__".len()..].trim_start();
+        let Some(rest) = rest.strip_prefix("+=").or_else(|| rest.strip_prefix('=')) else {
+            continue;
+        };
+        let rest = rest.trim_start();
+        let close = match rest.chars().next() {
+            Some('[') => ']',
+            Some('(') => ')',
+            _ => continue,
+        };
+        let Some(end) = rest.find(close) else {
+            continue;
+        };
+        let listed = rest[1..end]
+            .split(',')
+            .map(|item| item.trim().trim_matches(['"', '\'']))
+            .filter(|item| !item.is_empty())
+            .map(str::to_string);
+        names.get_or_insert_with(Vec::new).extend(listed);
+    }
+    names
+}
@@ -4,7 +4,7 @@
 //! collects all locations per pattern across files, and deduplicates
 //! by exact location (file:line:column).
 
-use drift_core::types::collections::FxHashMap;
+use drift_core::types::collections::{FxHashMap, FxHashSet};
 
 use crate::engine::types::PatternMatch;
 
@@ -19,6 +19,17 @@ impl PatternGrouper {
     /// Input: flat list of PatternMatch from all files.
     /// Output: map of pattern_id → AggregatedPattern with deduplicated locations.
     pub fn group(matches: &[PatternMatch]) -> FxHashMap<String, AggregatedPattern> {
+        Self::group_with_limit(matches, usize::MAX)
+    }
+
+    /// Like [`PatternGrouper::group`], but stores at most `max_locations`
+    /// locations per pattern. Larger patterns keep a representative sample
+    /// (see [`cap_locations`]) and are marked `over_limit`, while
+    /// `location_count` and `file_spread` still count every location.
+    pub fn group_with_limit(
+        matches: &[PatternMatch],
+        max_locations: usize,
+    ) -> FxHashMap<String, AggregatedPattern> {
         let mut groups: FxHashMap<String, Vec<&PatternMatch>> = FxHashMap::default();
 
         // Phase 1: Bucket by pattern_id
@@ -29,7 +40,7 @@ impl PatternGrouper {
         // Phase 2: Build AggregatedPattern per group with deduplication
         let mut result = FxHashMap::default();
         for (pattern_id, group_matches) in groups {
-            let pattern = Self::build_aggregated(&pattern_id, &group_matches);
+            let pattern = Self::build_aggregated(&pattern_id, &group_matches, max_locations.max(1));
             result.insert(pattern_id, pattern);
         }
 
@@ -37,36 +48,54 @@ impl PatternGrouper {
     }
 
     /// Build an AggregatedPattern from a group of matches sharing the same pattern_id.
-    fn build_aggregated(pattern_id: &str, matches: &[&PatternMatch]) -> AggregatedPattern {
-        // Deduplicate by exact location (file:line:column)
-        let mut seen = FxHashMap::default();
-        let mut locations = Vec::new();
+    fn build_aggregated(pattern_id: &str, matches: &[&PatternMatch], max_locations: usize) -> AggregatedPattern {
+        // Deduplicate by exact location (file:line:column). Every distinct
+        // location is counted, but past the limit only the sample is stored.
+        let mut seen: FxHashSet<u64> = FxHashSet::default();
+        let mut sampled: FxHashMap<u64, usize> = FxHashMap::default();
+        let mut locations: Vec<(u64, PatternLocation)> = Vec::new();
+        let mut files: FxHashSet<&str> = FxHashSet::default();
 
         for m in matches {
-            let key = format!("{}:{}:{}", m.file, m.line, m.column);
-            if let Some(existing_idx) = seen.get(&key) {
+            let key = location_key(&m.file, m.line, m.column);
+            if let Some(&existing_idx) = sampled.get(&key) {
                 // Keep higher confidence on collision
-                let existing: &mut PatternLocation = &mut locations[*existing_idx];
+                let existing = &mut locations[existing_idx].1;
                 if m.confidence > existing.confidence {
                     existing.confidence = m.confidence;
                 }
-            } else {
-                seen.insert(key, locations.len());
-                locations.push(PatternLocation {
-                    file: m.file.clone(),
-                    line: m.line,
-                    column: m.column,
-                    confidence: m.confidence,
-                    is_outlier: false,
-                    matched_text: Some(m.matched_text.clone()),
-                });
+                continue;
+            }
+            if !seen.insert(key) {
+                // A duplicate of a location already dropped from the sample.
+                continue;
+            }
+            files.insert(m.file.as_str());
+            sampled.insert(key, locations.len());
+            locations.push((key, PatternLocation {
+                file: m.file.clone(),
+                line: m.line,
+                column: m.column,
+                confidence: m.confidence,
+                is_outlier: false,
+                matched_text: Some(m.matched_text.clone()),
+            }));
+
+            // Shrink in batches so sampling stays amortized O(1) per match.
+            if locations.len() >= max_locations.saturating_mul(2) {
+                keep_smallest_keys(&mut locations, max_locations);
+                sampled = locations.iter().enumerate().map(|(i, (k, _))| (*k, i)).collect();
             }
         }
 
-        // Compute file spread
-        let mut files = drift_core::types::collections::FxHashSet::default();
-        for loc in &locations {
-            files.insert(loc.file.clone());
+        let location_count = seen.len();
+        let over_limit = location_count > max_locations;
+        if over_limit {
+            keep_smallest_keys(&mut locations, max_locations);
+        }
+        let mut locations: Vec<PatternLocation> = locations.into_iter().map(|(_, loc)| loc).collect();
+        if over_limit {
+            sort_locations(&mut locations);
         }
 
         // Compute confidence statistics
@@ -89,7 +118,7 @@ impl PatternGrouper {
         AggregatedPattern {
             pattern_id: pattern_id.to_string(),
             category,
-            location_count: locations.len() as u32,
+            location_count: location_count as u32,
             outlier_count: 0,
             file_spread: files.len() as u32,
             hierarchy: None,
@@ -101,10 +130,58 @@ impl PatternGrouper {
             confidence_values,
             is_dirty: true,
             location_hash,
+            over_limit,
         }
     }
 }
 
+/// Cap a pattern's stored locations at `max_locations`, keeping a sample.
+///
+/// The sample is the locations with the smallest location-key hashes: a
+/// uniform, deterministic choice that does not depend on match order, so a
+/// capped pattern looks the same however its matches arrived. The first cap
+/// records the full `location_count` and `file_spread`; after that callers
+/// maintain them.
+pub fn cap_locations(pattern: &mut AggregatedPattern, max_locations: usize) {
+    let max_locations = max_locations.max(1);
+    if pattern.locations.len() <= max_locations {
+        return;
+    }
+    if !pattern.over_limit {
+        let files: FxHashSet<&str> = pattern.locations.iter().map(|l| l.file.as_str()).collect();
+        pattern.file_spread = files.len() as u32;
+        pattern.location_count = pattern.locations.len() as u32;
+        pattern.over_limit = true;
+    }
+    let mut keyed: Vec<(u64, PatternLocation)> = pattern
+        .locations
+        .drain(..)
+        .map(|loc| (location_key(&loc.file, loc.line, loc.column), loc))
+        .collect();
+    keep_smallest_keys(&mut keyed, max_locations);
+    pattern.locations = keyed.into_iter().map(|(_, loc)| loc).collect();
+    sort_locations(&mut pattern.locations);
+}
+
+fn location_key(file: &str, line: u32, column: u32) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    (file, line, column).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Keep the `n` entries with the smallest keys (in no particular order).
+fn keep_smallest_keys(entries: &mut Vec<(u64, PatternLocation)>, n: usize) {
+    if entries.len() > n {
+        entries.select_nth_unstable_by_key(n, |(key, _)| *key);
+        entries.truncate(n);
+    }
+}
+
+fn sort_locations(locations: &mut [PatternLocation]) {
+    locations.sort_by(|a, b| (&a.file, a.line, a.column).cmp(&(&b.file, b.line, b.column)));
+}
+
 /// Compute mean and standard deviation of a slice of f64 values.
 pub fn compute_mean_stddev(values: &[f64]) -> (f64, f64) {
     if values.is_empty() {
@@ -125,13 +202,20 @@ pub fn compute_mean_stddev(values: &[f64]) -> (f64, f64) {
 }
 
 /// Compute a hash of the location set for change detection.
-fn compute_location_hash(locations: &[PatternLocation]) -> u64 {
+///
+/// Locations are hashed in sorted order so the hash depends only on the set,
+/// not on the order matches arrived in (incremental merges append).
+pub fn compute_location_hash(locations: &[PatternLocation]) -> u64 {
     use std::hash::{Hash, Hasher};
+    let mut keys: Vec<(&str, u32, u32)> = locations
+        .iter()
+        .map(|loc| (loc.file.as_str(), loc.line, loc.column))
+        .collect();
+    keys.sort_unstable();
+
     let mut hasher = std::collections::hash_map::DefaultHasher::new();
-    for loc in locations {
-        loc.file.hash(&mut hasher);
-        loc.line.hash(&mut hasher);
-        loc.column.hash(&mut hasher);
+    for key in keys {
+        key.hash(&mut hasher);
     }
     hasher.finish()
 }
@@ -32,13 +32,28 @@ pub fn build_hierarchies(
         };
 
         // Merge child into parent
-        let child_locations = patterns.get(&child_id).map(|p| p.locations.clone()).unwrap_or_default();
+        let (child_locations, child_count, child_over_limit) = patterns
+            .get(&child_id)
+            .map(|p| (p.locations.clone(), p.location_count, p.over_limit))
+            .unwrap_or_default();
         let child_pattern_id = child_id.clone();
 
         if let Some(parent) = patterns.get_mut(&parent_id) {
-            // Add child locations to parent
+            // Add child locations to parent. Over-limit patterns only store a
+            // sample, so their counts are added instead of recounted.
+            let over_limit = parent.over_limit || child_over_limit;
+            parent.location_count = if over_limit {
+                let parent_count = if parent.over_limit {
+                    parent.location_count
+                } else {
+                    parent.locations.len() as u32
+                };
+                parent_count + child_count
+            } else {
+                (parent.locations.len() + child_locations.len()) as u32
+            };
+            parent.over_limit = over_limit;
             parent.locations.extend(child_locations);
-            parent.location_count = parent.locations.len() as u32;
             parent.merged_from.push(child_pattern_id.clone());
             parent.aliases.push(child_pattern_id.clone());
             parent.is_dirty = true;
@@ -48,7 +63,11 @@ pub fn build_hierarchies(
             for loc in &parent.locations {
                 files.insert(loc.file.clone());
             }
-            parent.file_spread = files.len() as u32;
+            parent.file_spread = if over_limit {
+                parent.file_spread.max(files.len() as u32)
+            } else {
+                files.len() as u32
+            };
 
             // Build/update hierarchy
             let hierarchy = parent.hierarchy.get_or_insert_with(|| PatternHierarchy {
@@ -103,6 +122,7 @@ mod tests {
             confidence_values: vec![0.9; n_locations as usize],
             is_dirty: false,
             location_hash: 0,
+            over_limit: false,
         }
     }
 
@@ -44,12 +44,40 @@ pub fn patterns_needing_reaggregation(
 
 /// Remove locations from changed files in existing patterns.
 ///
-/// These will be re-added from the fresh match data.
+/// These will be re-added from the fresh match data. For an over-limit
+/// pattern only the sample is known, so its count drops by the removed share
+/// of the sample.
 pub fn remove_stale_locations(
     pattern: &mut AggregatedPattern,
     changed_files: &FxHashSet<String>,
 ) {
+    let before = pattern.locations.len();
     pattern.locations.retain(|loc| !changed_files.contains(&loc.file));
-    pattern.location_count = pattern.locations.len() as u32;
+    if pattern.over_limit && before > 0 {
+        let removed = (before - pattern.locations.len()) as f64;
+        let estimate = (removed * pattern.location_count as f64 / before as f64).round() as u32;
+        pattern.location_count = pattern.location_count.saturating_sub(estimate);
+    } else {
+        pattern.location_count = pattern.locations.len() as u32;
+    }
     pattern.is_dirty = true;
 }
+
+/// Merge freshly grouped locations into an existing pattern.
+///
+/// When either side is over the limit, the counts are added, since the
+/// stored locations no longer describe them; the caller re-caps the result.
+pub fn merge_locations(existing: &mut AggregatedPattern, new_pattern: AggregatedPattern) {
+    if existing.over_limit || new_pattern.over_limit {
+        if !existing.over_limit {
+            existing.location_count = existing.locations.len() as u32;
+            let files: FxHashSet<&str> = existing.locations.iter().map(|l| l.file.as_str()).collect();
+            existing.file_spread = files.len() as u32;
+        }
+        existing.location_count += new_pattern.location_count;
+        existing.file_spread = existing.file_spread.max(new_pattern.file_spread);
+        existing.over_limit = true;
+    }
+    existing.locations.extend(new_pattern.locations);
+    existing.is_dirty = true;
+}
@@ -13,7 +13,7 @@ use crate::patterns::outliers::conversion::{self, OutlierViolation};
 use crate::patterns::outliers::selector::OutlierDetector;
 
 use super::gold_layer::{self, GoldLayerResult};
-use super::grouper::PatternGrouper;
+use super::grouper::{cap_locations, PatternGrouper};
 use super::hierarchy;
 use super::incremental;
 use super::reconciliation;
@@ -48,7 +48,7 @@ impl AggregationPipeline {
         let raw_match_count = matches.len();
 
         // Phase 1-2: Group by pattern ID + cross-file merging + dedup
-        let mut grouped = PatternGrouper::group(matches);
+        let mut grouped = PatternGrouper::group_with_limit(matches, self.config.max_locations_per_pattern);
 
         // Phase 3-4: Near-duplicate detection
         let patterns_vec: Vec<&AggregatedPattern> = grouped.values().collect();
@@ -57,8 +57,9 @@ impl AggregationPipeline {
         // Phase 5: Hierarchy building (merge auto-merge candidates)
         hierarchy::build_hierarchies(&mut grouped, &candidates);
 
-        // Phase 6: Counter reconciliation
+        // Phase 6: Counter reconciliation (merges may push a parent over the limit)
         for pattern in grouped.values_mut() {
+            cap_locations(pattern, self.config.max_locations_per_pattern);
             reconciliation::reconcile(pattern);
         }
 
@@ -111,7 +112,7 @@ impl AggregationPipeline {
         }
 
         // Group the new matches
-        let new_grouped = PatternGrouper::group(&changed_matches);
+        let new_grouped = PatternGrouper::group_with_limit(&changed_matches, self.config.max_locations_per_pattern);
 
         // Merge new data into existing patterns
         let mut all_patterns: FxHashMap<String, AggregatedPattern> = existing_patterns
@@ -121,8 +122,7 @@ impl AggregationPipeline {
 
         for (id, new_pattern) in new_grouped {
             if let Some(existing) = all_patterns.get_mut(&id) {
-                existing.locations.extend(new_pattern.locations);
-                existing.is_dirty = true;
+                incremental::merge_locations(existing, new_pattern);
             } else {
                 all_patterns.insert(id, new_pattern);
             }
@@ -130,6 +130,7 @@ impl AggregationPipeline {
 
         // Reconcile all affected patterns
         for pattern in all_patterns.values_mut() {
+            cap_locations(pattern, self.config.max_locations_per_pattern);
             reconciliation::reconcile(pattern);
         }
 
@@ -169,6 +170,98 @@ impl AggregationPipeline {
         }
     }
 
+    /// Apply an incremental delta to a previous aggregation result in place.
+    ///
+    /// `changed_matches` holds the fresh matches for every changed file. Files
+    /// that were deleted, or changed and no longer produce any match, belong in
+    /// `removed_files`. Locations from both sets of files are dropped, the new
+    /// matches are merged in, and only the touched patterns are reconciled,
+    /// re-checked for outliers and marked `is_dirty`. Patterns left without
+    /// locations are removed.
+    ///
+    /// The patterns match a full `run` over the updated match set. Hierarchy
+    /// merges are not rebuilt here; they are left to the next full run.
+    pub fn apply_delta(
+        &self,
+        existing: &mut AggregationResult,
+        changed_matches: &[PatternMatch],
+        removed_files: &FxHashSet<String>,
+    ) {
+        let mut stale_files = removed_files.clone();
+        stale_files.extend(changed_matches.iter().map(|m| m.file.clone()));
+
+        let mut all_patterns: FxHashMap<String, AggregatedPattern> = existing
+            .patterns
+            .drain(..)
+            .map(|p| (p.pattern_id.clone(), p))
+            .collect();
+
+        // Drop locations from stale files; only touched patterns become dirty.
+        let mut removed_locations = 0usize;
+        for pattern in all_patterns.values_mut() {
+            pattern.is_dirty = false;
+            if pattern.locations.iter().any(|l| stale_files.contains(&l.file)) {
+                let before = pattern.locations.len();
+                incremental::remove_stale_locations(pattern, &stale_files);
+                removed_locations += before - pattern.locations.len();
+            }
+        }
+
+        // Merge the fresh matches
+        for (id, new_pattern) in PatternGrouper::group_with_limit(changed_matches, self.config.max_locations_per_pattern) {
+            if let Some(existing) = all_patterns.get_mut(&id) {
+                incremental::merge_locations(existing, new_pattern);
+            } else {
+                all_patterns.insert(id, new_pattern);
+            }
+        }
+        all_patterns.retain(|_, p| !p.locations.is_empty());
+
+        // Reconcile affected patterns, clearing outlier marks for a fresh pass
+        for pattern in all_patterns.values_mut().filter(|p| p.is_dirty) {
+            for loc in &mut pattern.locations {
+                loc.is_outlier = false;
+            }
+            cap_locations(pattern, self.config.max_locations_per_pattern);
+            reconciliation::reconcile(pattern);
+        }
+
+        let violations = self.run_outlier_detection(
+            all_patterns.values_mut().filter(|p| p.is_dirty)
+        );
+        for pattern in all_patterns.values_mut().filter(|p| p.is_dirty) {
+            pattern.outlier_count = pattern.locations.iter().filter(|l| l.is_outlier).count() as u32;
+        }
+
+        // Keep results for untouched patterns, recompute the rest
+        let is_clean = |id: &String| all_patterns.get(id).is_some_and(|p| !p.is_dirty);
+        existing.violations.retain(|v| is_clean(&v.pattern_id));
+        existing.violations.extend(violations);
+
+        existing
+            .merge_candidates
+            .retain(|c| is_clean(&c.pattern_a) && is_clean(&c.pattern_b));
+        let affected_patterns: Vec<&AggregatedPattern> = all_patterns
+            .values()
+            .filter(|p| p.is_dirty)
+            .collect();
+        if !affected_patterns.is_empty() {
+            let candidates = self.detect_duplicates(&affected_patterns);
+            existing.merge_candidates.extend(candidates);
+        }
+
+        let raw_match_count = existing
+            .diagnostics
+            .raw_match_count
+            .saturating_sub(removed_locations)
+            + changed_matches.len();
+
+        existing.patterns = all_patterns.into_values().collect();
+        existing.gold_layer = gold_layer::prepare_gold_layer(&existing.patterns);
+        existing.diagnostics =
+            Self::compute_diagnostics(&existing.patterns, raw_match_count, &existing.merge_candidates);
+    }
+
     /// Phase 3-4: Detect near-duplicate patterns.
     fn detect_duplicates(&self, patterns: &[&AggregatedPattern]) -> Vec<MergeCandidate> {
         let n = patterns.len();
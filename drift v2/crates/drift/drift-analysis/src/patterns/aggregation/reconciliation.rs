@@ -1,11 +1,13 @@
 //! Phase 6: Counter reconciliation.
 //!
 //! Recomputes cached counters (location_count, outlier_count, file_spread)
-//! from the actual location data to ensure consistency after merges.
+//! from the actual location data to ensure consistency after merges. For
+//! over-limit patterns the stored locations are a sample, so location_count
+//! and file_spread are kept as maintained by the pipeline.
 
 use drift_core::types::collections::FxHashSet;
 
-use super::grouper::compute_mean_stddev;
+use super::grouper::{compute_location_hash, compute_mean_stddev};
 use super::types::AggregatedPattern;
 
 /// Reconcile all cached counters on an aggregated pattern.
@@ -13,18 +15,18 @@ use super::types::AggregatedPattern;
 /// After merges and hierarchy building, counters may be stale.
 /// This recomputes them from the actual location data.
 pub fn reconcile(pattern: &mut AggregatedPattern) {
-    // Recompute location_count
-    pattern.location_count = pattern.locations.len() as u32;
-
     // Recompute outlier_count
     pattern.outlier_count = pattern.locations.iter().filter(|l| l.is_outlier).count() as u32;
 
-    // Recompute file_spread
-    let mut files = FxHashSet::default();
-    for loc in &pattern.locations {
-        files.insert(loc.file.clone());
+    // Recompute location_count and file_spread (a sample cannot tell them)
+    if !pattern.over_limit {
+        pattern.location_count = pattern.locations.len() as u32;
+        let mut files = FxHashSet::default();
+        for loc in &pattern.locations {
+            files.insert(loc.file.clone());
+        }
+        pattern.file_spread = files.len() as u32;
     }
-    pattern.file_spread = files.len() as u32;
 
     // Recompute confidence statistics
     let mut vals: Vec<f64> = pattern.locations.iter().map(|l| l.confidence as f64).collect();
@@ -35,14 +37,7 @@ pub fn reconcile(pattern: &mut AggregatedPattern) {
     pattern.confidence_values = vals;
 
     // Recompute location hash
-    use std::hash::{Hash, Hasher};
-    let mut hasher = std::collections::hash_map::DefaultHasher::new();
-    for loc in &pattern.locations {
-        loc.file.hash(&mut hasher);
-        loc.line.hash(&mut hasher);
-        loc.column.hash(&mut hasher);
-    }
-    pattern.location_hash = hasher.finish();
+    pattern.location_hash = compute_location_hash(&pattern.locations);
 
     // Update hierarchy aggregated count if present
     if let Some(ref mut hierarchy) = pattern.hierarchy {
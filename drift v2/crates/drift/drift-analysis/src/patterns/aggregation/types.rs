@@ -13,7 +13,8 @@ pub struct AggregatedPattern {
     pub pattern_id: String,
     /// Category of the pattern.
     pub category: PatternCategory,
-    /// Total deduplicated location count.
+    /// Total deduplicated location count. Counts every location, including
+    /// those not kept in `locations` when the pattern is over the limit.
     pub location_count: u32,
     /// Number of locations flagged as outliers.
     pub outlier_count: u32,
@@ -21,7 +22,8 @@ pub struct AggregatedPattern {
     pub file_spread: u32,
     /// Parent-child hierarchy (if part of a pattern group).
     pub hierarchy: Option<PatternHierarchy>,
-    /// All deduplicated locations.
+    /// All deduplicated locations, or a representative sample of them when
+    /// `over_limit` is set.
     pub locations: Vec<PatternLocation>,
     /// Aliases from merged patterns.
     pub aliases: Vec<String>,
@@ -36,16 +38,46 @@ pub struct AggregatedPattern {
     pub is_dirty: bool,
     /// Location set hash for change detection.
     pub location_hash: u64,
+    /// Whether the pattern exceeded `max_locations_per_pattern`: `locations`
+    /// (and the confidence statistics derived from it) is then a sample,
+    /// while `location_count` and `file_spread` still cover every location.
+    pub over_limit: bool,
 }
 
 impl AggregatedPattern {
-    /// Outlier rate: outlier_count / location_count.
+    /// Outlier rate: outlier_count / location_count. Outliers are marked on
+    /// stored locations, so an over-limit pattern's rate is over its sample.
     pub fn outlier_rate(&self) -> f64 {
-        if self.location_count == 0 {
+        let observed = if self.over_limit {
+            self.locations.len() as u32
+        } else {
+            self.location_count
+        };
+        if observed == 0 {
             return 0.0;
         }
-        self.outlier_count as f64 / self.location_count as f64
+        self.outlier_count as f64 / observed as f64
+    }
+
+    /// 95% Wilson score interval for the share of files that follow the
+    /// convention: the adherence rate `1 - outlier_rate()` over `file_spread`
+    /// files. Narrows as the pattern spreads; `(0.0, 1.0)` with no files.
+    pub fn adherence_ci(&self) -> (f64, f64) {
+        wilson_interval(1.0 - self.outlier_rate(), self.file_spread, 1.96)
+    }
+}
+
+/// Wilson score interval for an observed proportion `p` over `n` samples.
+fn wilson_interval(p: f64, n: u32, z: f64) -> (f64, f64) {
+    if n == 0 {
+        return (0.0, 1.0);
     }
+    let n = n as f64;
+    let z2 = z * z;
+    let denom = 1.0 + z2 / n;
+    let center = (p + z2 / (2.0 * n)) / denom;
+    let half = z * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / denom;
+    ((center - half).max(0.0), (center + half).min(1.0))
 }
 
 /// A single location within an aggregated pattern.
@@ -127,7 +159,8 @@ pub struct AggregationConfig {
     pub minhash_auto_threshold: usize,
     /// Whether to run incrementally (default: true).
     pub incremental: bool,
-    /// Maximum locations per pattern (default: 10_000).
+    /// Maximum locations stored per pattern (default: 10_000). Beyond it a
+    /// deterministic sample is kept and the pattern is marked `over_limit`.
     pub max_locations_per_pattern: usize,
 }
 
@@ -12,6 +12,6 @@ pub mod scorer;
 pub use types::{ConfidenceScore, ConfidenceTier, MomentumDirection};
 pub use scorer::{
     ConfidenceScorer, ScorerConfig, FeedbackStore, InMemoryFeedbackStore,
-    ConfidenceDiagnostics, CategoryConfidenceSummary,
+    ConfidenceDiagnostics, CategoryConfidenceSummary, CachedScore,
 };
 pub use beta::BetaPosterior;
@@ -7,6 +7,8 @@
 use std::collections::HashMap;
 use std::fmt;
 
+use drift_core::traits::PriorSource;
+
 use crate::engine::types::PatternCategory;
 use crate::patterns::aggregation::types::AggregatedPattern;
 
@@ -51,6 +53,22 @@ impl FeedbackStore for InMemoryFeedbackStore {
     }
 }
 
+/// A score kept between runs, with the location hash it was computed for.
+#[derive(Debug, Clone)]
+pub struct CachedScore {
+    pub location_hash: u64,
+    pub score: ConfidenceScore,
+}
+
+/// Per-category total locations for the frequency factor (PI-CONF-11).
+fn category_totals(patterns: &[AggregatedPattern]) -> HashMap<PatternCategory, u64> {
+    let mut totals: HashMap<PatternCategory, u64> = HashMap::new();
+    for p in patterns {
+        *totals.entry(p.category).or_insert(0) += p.location_count as u64;
+    }
+    totals
+}
+
 /// Configuration for the confidence scorer.
 #[derive(Debug, Clone)]
 pub struct ScorerConfig {
@@ -76,10 +94,12 @@ impl Default for ScorerConfig {
 /// The top-level confidence scorer.
 ///
 /// Takes aggregated patterns and produces ConfidenceScore for each.
-/// Supports optional feedback store for closed-loop confidence adjustment.
+/// Supports optional feedback store for closed-loop confidence adjustment
+/// and an optional prior source (e.g. grounding verdicts from the bridge).
 pub struct ConfidenceScorer {
     config: ScorerConfig,
     feedback_store: Option<Box<dyn FeedbackStore>>,
+    prior_source: Option<Box<dyn PriorSource>>,
 }
 
 impl ConfidenceScorer {
@@ -88,6 +108,7 @@ impl ConfidenceScorer {
         Self {
             config,
             feedback_store: None,
+            prior_source: None,
         }
     }
 
@@ -102,6 +123,12 @@ impl ConfidenceScorer {
         self
     }
 
+    /// Attach a source of per-pattern prior pseudo-counts.
+    pub fn with_prior_source(mut self, source: Box<dyn PriorSource>) -> Self {
+        self.prior_source = Some(source);
+        self
+    }
+
     /// Score a single aggregated pattern.
     ///
     /// Combines Beta distribution posterior with 6-factor model.
@@ -147,6 +174,18 @@ impl ConfidenceScorer {
         let mut final_alpha = base_alpha + alpha_adj;
         let mut final_beta = base_beta + beta_adj;
 
+        // Step 3b: Add external prior pseudo-counts, ignoring non-finite or negative ones
+        if let Some((prior_alpha, prior_beta)) = self
+            .prior_source
+            .as_ref()
+            .and_then(|source| source.prior(&pattern.pattern_id))
+        {
+            if prior_alpha.is_finite() && prior_beta.is_finite() {
+                final_alpha += prior_alpha.max(0.0);
+                final_beta += prior_beta.max(0.0);
+            }
+        }
+
         // Step 4: Apply feedback adjustments if store is available (PI-CONF-05/06/07)
         if let Some(ref store) = self.feedback_store {
             let adjustments = store.get_adjustments(&pattern.pattern_id);
@@ -170,34 +209,76 @@ impl ConfidenceScorer {
         patterns: &[AggregatedPattern],
         momentum_trackers: Option<&HashMap<String, MomentumTracker>>,
     ) -> Vec<(String, ConfidenceScore)> {
-        // Compute per-category total locations for frequency factor (PI-CONF-11)
-        let mut category_totals: HashMap<PatternCategory, u64> = HashMap::new();
-        for p in patterns {
-            *category_totals.entry(p.category).or_insert(0) += p.location_count as u64;
-        }
-
+        let category_totals = category_totals(patterns);
         patterns
             .iter()
             .map(|p| {
-                let momentum = momentum_trackers
-                    .and_then(|trackers| trackers.get(&p.pattern_id))
-                    .map(|t| t.direction())
-                    .unwrap_or(MomentumDirection::Stable);
-
-                let cat_total = category_totals.get(&p.category).copied();
-
-                let score = self.score(
-                    p,
-                    momentum,
-                    self.config.default_age_days,
-                    cat_total,
-                    None,
-                );
+                let score = self.score_in_batch(p, momentum_trackers, &category_totals);
                 (p.pattern_id.clone(), score)
             })
             .collect()
     }
 
+    /// Score a batch, reusing `previous` scores where a pattern's location
+    /// set has not changed since its score was computed (watch mode).
+    ///
+    /// Patterns whose `location_hash` matches their cached score get that
+    /// score back without recomputation. Changed and new patterns, and
+    /// patterns without a location hash (0), are scored as in
+    /// [`score_batch`](Self::score_batch). A fresh score already counts
+    /// every current location, so the cached posterior is replaced rather
+    /// than folded in, which would count the unchanged locations twice.
+    ///
+    /// Reused scores do not see later changes to category totals or
+    /// feedback, so they can drift slightly from `score_batch`; a periodic
+    /// full `score_batch` refreshes them.
+    pub fn score_batch_incremental(
+        &self,
+        patterns: &[AggregatedPattern],
+        momentum_trackers: Option<&HashMap<String, MomentumTracker>>,
+        previous: &HashMap<String, CachedScore>,
+    ) -> Vec<(String, CachedScore)> {
+        let category_totals = category_totals(patterns);
+        patterns
+            .iter()
+            .map(|p| {
+                let cached = previous.get(&p.pattern_id).filter(|_| p.location_hash != 0);
+                let score = match cached {
+                    Some(cached) if cached.location_hash == p.location_hash => cached.score.clone(),
+                    _ => self.score_in_batch(p, momentum_trackers, &category_totals),
+                };
+                let entry = CachedScore {
+                    location_hash: p.location_hash,
+                    score,
+                };
+                (p.pattern_id.clone(), entry)
+            })
+            .collect()
+    }
+
+    /// Score one pattern of a batch with its tracked momentum and category total.
+    fn score_in_batch(
+        &self,
+        pattern: &AggregatedPattern,
+        momentum_trackers: Option<&HashMap<String, MomentumTracker>>,
+        category_totals: &HashMap<PatternCategory, u64>,
+    ) -> ConfidenceScore {
+        let momentum = momentum_trackers
+            .and_then(|trackers| trackers.get(&pattern.pattern_id))
+            .map(|t| t.direction())
+            .unwrap_or(MomentumDirection::Stable);
+
+        let cat_total = category_totals.get(&pattern.category).copied();
+
+        self.score(
+            pattern,
+            momentum,
+            self.config.default_age_days,
+            cat_total,
+            None,
+        )
+    }
+
     /// Score with full context including momentum tracker.
     pub fn score_with_momentum(
         &self,
@@ -357,6 +438,7 @@ mod tests {
             confidence_values: vec![0.9; locations as usize],
             is_dirty: false,
             location_hash: 0,
+            over_limit: false,
         }
     }
 
@@ -338,6 +338,7 @@ mod tests {
             confidence_values: vec![0.9; locations as usize],
             is_dirty: false,
             location_hash: 0,
+            over_limit: false,
         }
     }
 
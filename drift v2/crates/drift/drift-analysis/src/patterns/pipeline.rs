@@ -7,6 +7,8 @@
 //! (4) ConventionDiscoverer.discover()
 //! (5) PromotionCheck
 
+use drift_core::traits::PriorSource;
+
 use crate::engine::types::PatternMatch;
 use crate::patterns::aggregation::pipeline::{AggregationPipeline, AggregationResult};
 use crate::patterns::aggregation::types::AggregatedPattern;
@@ -73,6 +75,13 @@ impl PatternIntelligencePipeline {
         self
     }
 
+    /// Attach a source of per-pattern prior pseudo-counts (e.g. grounding
+    /// verdicts from the bridge), added to each pattern's Beta parameters.
+    pub fn with_prior_source(mut self, source: Box<dyn PriorSource>) -> Self {
+        self.scorer = self.scorer.with_prior_source(source);
+        self
+    }
+
     /// Run the full pipeline.
     ///
     /// `matches`: raw pattern matches from all files.
@@ -3,6 +3,8 @@
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
+use drift_core::traits::cancellation::Cancellable;
+
 /// A cancellation handle for scan operations.
 ///
 /// Wraps an `AtomicBool` that can be shared across threads.
@@ -46,3 +48,13 @@ impl Default for ScanCancellation {
         Self::new()
     }
 }
+
+impl Cancellable for ScanCancellation {
+    fn is_cancelled(&self) -> bool {
+        ScanCancellation::is_cancelled(self)
+    }
+
+    fn cancel(&self) {
+        ScanCancellation::cancel(self)
+    }
+}
@@ -0,0 +1,76 @@
+//! Resumable scans: periodic checkpoints of processed files.
+//!
+//! A resumable scan hands every `ScanConfig::checkpoint_interval` processed
+//! files to a [`CheckpointSink`] (drift-napi writes them to the
+//! `scan_checkpoint` table). When an interrupted scan is restarted with that
+//! checkpoint, files whose path and mtime still match are taken from it
+//! without being read again, and are counted as resumed rather than fresh.
+
+use std::time::Instant;
+
+use super::incremental::mtime_parts;
+use super::types::{CachedFileMetadata, DiscoveredFile, FileStatus, ScanEntry};
+
+/// A file recorded in the checkpoint of an interrupted scan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckpointedFile {
+    pub content_hash: u64,
+    pub mtime_secs: i64,
+    pub mtime_nanos: u32,
+}
+
+impl From<&ScanEntry> for CheckpointedFile {
+    fn from(entry: &ScanEntry) -> Self {
+        Self {
+            content_hash: entry.content_hash,
+            mtime_secs: entry.mtime_secs,
+            mtime_nanos: entry.mtime_nanos,
+        }
+    }
+}
+
+/// Receives the files processed since the previous checkpoint.
+///
+/// Called once per `checkpoint_interval` files, from the scanning thread.
+/// Files resumed from the existing checkpoint are not passed again.
+pub trait CheckpointSink: Sync {
+    fn checkpoint(&self, entries: &[ScanEntry]);
+}
+
+/// Classify a file from its checkpoint, without reading it, if the file is
+/// unchanged since it was checkpointed.
+///
+/// The status is relative to `cached` (the last completed scan) as usual:
+/// the checkpoint only saves the I/O of re-hashing.
+pub fn resume_file(
+    file: &DiscoveredFile,
+    checkpointed: Option<&CheckpointedFile>,
+    cached: Option<&CachedFileMetadata>,
+) -> Option<(FileStatus, ScanEntry)> {
+    let start = Instant::now();
+    let checkpointed = checkpointed?;
+    let (mtime_secs, mtime_nanos) = mtime_parts(&file.mtime);
+    if mtime_secs != checkpointed.mtime_secs || mtime_nanos != checkpointed.mtime_nanos {
+        return None;
+    }
+
+    let status = match cached {
+        None => FileStatus::Added,
+        Some(c) if c.content_hash == checkpointed.content_hash => FileStatus::Unchanged,
+        Some(_) => FileStatus::Modified,
+    };
+    Some((
+        status,
+        ScanEntry {
+            path: file.path.clone(),
+            content_hash: checkpointed.content_hash,
+            mtime_secs,
+            mtime_nanos,
+            file_size: file.file_size,
+            language: file.language,
+            scan_duration_us: start.elapsed().as_micros() as u64,
+            root: None,
+            is_test_file: false,
+        },
+    ))
+}
@@ -0,0 +1,164 @@
+//! Focus paths: scope a scan to part of a monorepo.
+//!
+//! Files matching `ScanConfig::include_paths` are scanned and analyzed as
+//! usual. Everything else is kept as context: never analyzed, but available
+//! so that imports from focus files into the rest of the repo can still be
+//! resolved for the call graph. Patterns are matched against the
+//! root-relative path with `/` separators; a pattern without glob characters
+//! (e.g. `services/payments`) matches that directory and everything below it.
+
+use std::path::{Path, PathBuf};
+
+use drift_core::types::collections::FxHashSet;
+
+use crate::parsers::types::ParseResult;
+use crate::scanner::language_detect::Language;
+
+/// Compiled `include_paths` patterns.
+#[derive(Debug, Clone, Default)]
+pub struct FocusFilter {
+    patterns: Vec<glob::Pattern>,
+    directories: Vec<String>,
+}
+
+impl FocusFilter {
+    /// Compile focus patterns. Invalid globs are skipped with a warning.
+    pub fn new(patterns: &[String]) -> Self {
+        let mut filter = Self::default();
+        for pattern in patterns {
+            let pattern = pattern.trim().trim_start_matches("./").trim_end_matches('/');
+            if pattern.is_empty() {
+                continue;
+            }
+            if !pattern.contains(['*', '?', '[']) {
+                filter.directories.push(pattern.to_string());
+                continue;
+            }
+            match glob::Pattern::new(pattern) {
+                Ok(p) => filter.patterns.push(p),
+                Err(e) => tracing::warn!(pattern, error = %e, "invalid focus pattern"),
+            }
+        }
+        filter
+    }
+
+    /// Whether any focus pattern is configured.
+    pub fn is_active(&self) -> bool {
+        !self.patterns.is_empty() || !self.directories.is_empty()
+    }
+
+    /// Whether `path` (under `root`) is in focus. Always true when inactive.
+    pub fn contains(&self, path: &Path, root: &Path) -> bool {
+        if !self.is_active() {
+            return true;
+        }
+        let relative = relative_key(path, root);
+        self.directories
+            .iter()
+            .any(|d| relative == *d || relative.starts_with(&format!("{d}/")))
+            || self.patterns.iter().any(|p| p.matches(&relative))
+    }
+}
+
+/// Context files imported by the focus files, which callers parse for
+/// call-graph resolution without running detection on them. Only direct
+/// imports are followed; the result is sorted and logged.
+pub fn resolve_context_files(
+    focus_results: &[ParseResult],
+    context_files: &[PathBuf],
+    root: &Path,
+) -> Vec<PathBuf> {
+    let by_stem: Vec<(String, &PathBuf)> = context_files
+        .iter()
+        .map(|path| (module_stem(&relative_key(path, root)), path))
+        .collect();
+
+    let mut pulled_in: FxHashSet<&PathBuf> = FxHashSet::default();
+    for pr in focus_results {
+        let importer = relative_key(Path::new(&pr.file), root);
+        for import in &pr.imports {
+            let Some((candidate, suffix_match)) = import_target(&import.source, &importer, pr.language) else {
+                continue;
+            };
+            for (stem, path) in &by_stem {
+                let matched = *stem == candidate
+                    || (suffix_match && stem.ends_with(&format!("/{candidate}")));
+                if matched {
+                    pulled_in.insert(*path);
+                }
+            }
+        }
+    }
+
+    let mut files: Vec<PathBuf> = pulled_in.into_iter().cloned().collect();
+    files.sort();
+    if !files.is_empty() {
+        tracing::info!(count = files.len(), files = ?files, "out-of-focus files pulled in for resolution");
+    }
+    files
+}
+
+/// The root-relative module stem an import refers to, and whether it may sit
+/// under a source root (dotted Java/Python names match as a path suffix).
+fn import_target(source: &str, importer: &str, language: Language) -> Option<(String, bool)> {
+    let source = source.trim().trim_matches(['"', '\'']);
+    let importer_dir = importer.rsplit_once('/').map_or("", |(dir, _)| dir);
+
+    if source.starts_with("./") || source.starts_with("../") {
+        return Some((module_stem(&normalize(&format!("{importer_dir}/{source}"))?), false));
+    }
+    if language == Language::Python && source.starts_with('.') {
+        let dots = source.len() - source.trim_start_matches('.').len();
+        let mut base = importer_dir.to_string();
+        for _ in 1..dots {
+            base = base.rsplit_once('/').map_or(String::new(), |(dir, _)| dir.to_string());
+        }
+        let rest = source[dots..].replace('.', "/");
+        return Some((normalize(&format!("{base}/{rest}"))?, false));
+    }
+    if source.contains('/') {
+        let aliased = source.trim_start_matches("@/").trim_start_matches("~/");
+        return Some((module_stem(&normalize(aliased)?), true));
+    }
+    if source.contains('.') && !source.contains("::") {
+        let dotted = source.trim_end_matches(".*").replace('.', "/");
+        return Some((dotted, true));
+    }
+    None
+}
+
+/// Resolve `.` and `..` segments; `None` if the path escapes the root.
+fn normalize(path: &str) -> Option<String> {
+    let mut parts: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                parts.pop()?;
+            }
+            s => parts.push(s),
+        }
+    }
+    Some(parts.join("/"))
+}
+
+/// A path without its extension or a trailing `index`/`__init__` module.
+fn module_stem(path: &str) -> String {
+    let stem = match path.rsplit_once('.') {
+        Some((stem, ext)) if !ext.contains('/') => stem,
+        _ => path,
+    };
+    stem.strip_suffix("/index")
+        .or_else(|| stem.strip_suffix("/__init__"))
+        .unwrap_or(stem)
+        .to_string()
+}
+
+fn relative_key(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
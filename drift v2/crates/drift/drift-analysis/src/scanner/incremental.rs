@@ -38,6 +38,8 @@ pub fn classify_file(
                     file_size: file.file_size,
                     language: file.language,
                     scan_duration_us: start.elapsed().as_micros() as u64,
+                    root: None,
+                    is_test_file: false,
                 },
             ))
         }
@@ -57,6 +59,8 @@ pub fn classify_file(
                         file_size: file.file_size,
                         language: file.language,
                         scan_duration_us: start.elapsed().as_micros() as u64,
+                        root: None,
+                        is_test_file: false,
                     },
                 ));
             }
@@ -80,6 +84,8 @@ pub fn classify_file(
                     file_size: file.file_size,
                     language: file.language,
                     scan_duration_us: start.elapsed().as_micros() as u64,
+                    root: None,
+                    is_test_file: false,
                 },
             ))
         }
@@ -129,7 +135,7 @@ pub fn compute_diff(
 }
 
 /// Extract mtime as (seconds, nanoseconds) from SystemTime.
-fn mtime_parts(mtime: &std::time::SystemTime) -> (i64, u32) {
+pub(crate) fn mtime_parts(mtime: &std::time::SystemTime) -> (i64, u32) {
     match mtime.duration_since(std::time::UNIX_EPOCH) {
         Ok(d) => (d.as_secs() as i64, d.subsec_nanos()),
         Err(_) => (0, 0),
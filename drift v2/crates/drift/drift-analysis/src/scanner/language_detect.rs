@@ -3,7 +3,7 @@
 use serde::{Deserialize, Serialize};
 
 /// Supported programming languages.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, schemars::JsonSchema)]
 pub enum Language {
     TypeScript,
     JavaScript,
@@ -82,6 +82,28 @@ impl Language {
             Language::Scala => "Scala",
         }
     }
+
+    /// Parse a language name as written in configuration: the display name
+    /// or the variant name, case-insensitively (`"C#"` and `"csharp"`).
+    pub fn from_name(name: &str) -> Option<Language> {
+        match name.to_ascii_lowercase().as_str() {
+            "typescript" => Some(Language::TypeScript),
+            "javascript" => Some(Language::JavaScript),
+            "python" => Some(Language::Python),
+            "java" => Some(Language::Java),
+            "c#" | "csharp" => Some(Language::CSharp),
+            "go" => Some(Language::Go),
+            "rust" => Some(Language::Rust),
+            "ruby" => Some(Language::Ruby),
+            "php" => Some(Language::Php),
+            "kotlin" => Some(Language::Kotlin),
+            "c++" | "cpp" => Some(Language::Cpp),
+            "c" => Some(Language::C),
+            "swift" => Some(Language::Swift),
+            "scala" => Some(Language::Scala),
+            _ => None,
+        }
+    }
 }
 
 impl Language {
@@ -100,10 +122,12 @@ impl Language {
             Language::Ruby => tree_sitter_ruby::LANGUAGE.into(),
             Language::Php => tree_sitter_php::LANGUAGE_PHP.into(),
             Language::Kotlin => tree_sitter_kotlin_sg::LANGUAGE.into(),
-            // C/C++ use C# grammar as fallback until tree-sitter-c/tree-sitter-cpp deps are added
-            Language::Cpp | Language::C => tree_sitter_c_sharp::LANGUAGE.into(),
-            // Swift/Scala use Java grammar as fallback until dedicated deps are added
-            Language::Swift | Language::Scala => tree_sitter_java::LANGUAGE.into(),
+            Language::Cpp => tree_sitter_cpp::LANGUAGE.into(),
+            // C uses the C# grammar as fallback until tree-sitter-c is added
+            Language::C => tree_sitter_c_sharp::LANGUAGE.into(),
+            Language::Swift => tree_sitter_swift::LANGUAGE.into(),
+            // Scala uses the Java grammar as fallback until a dedicated dep is added
+            Language::Scala => tree_sitter_java::LANGUAGE.into(),
         }
     }
 
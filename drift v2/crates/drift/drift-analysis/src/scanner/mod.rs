@@ -2,15 +2,22 @@
 //!
 //! The scanner is the entry point to the entire Drift pipeline. It discovers files,
 //! computes content hashes, detects languages, and produces a `ScanDiff` describing
-//! what changed since the last scan.
+//! what changed since the last scan, or a `ScanStream` of changes as they are found.
 
 pub mod cancellation;
+pub mod checkpoint;
+pub mod focus;
+pub mod git_range;
 pub mod hasher;
 pub mod incremental;
 pub mod language_detect;
+pub mod sampling;
 pub mod scanner;
+pub mod streaming;
+pub mod test_files;
 pub mod types;
 pub mod walker;
 
 pub use scanner::Scanner;
+pub use streaming::{ScanEvent, ScanStream};
 pub use types::{ScanDiff, ScanEntry, ScanStats};
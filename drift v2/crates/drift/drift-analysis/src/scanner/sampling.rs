@@ -0,0 +1,38 @@
+//! Deterministic, seedable file sampling for very large repositories.
+//!
+//! Selection hashes each file's root-relative path (with `/` separators) with
+//! the configured seed, so it depends only on the path and seed: not on walk
+//! order, thread count or the checkout location.
+
+use std::path::Path;
+
+use drift_core::config::SampleConfig;
+use xxhash_rust::xxh3::xxh3_64_with_seed;
+
+/// Whether `path` (under `root`) is part of the sample.
+pub fn is_sampled(path: &Path, root: &Path, sample: &SampleConfig) -> bool {
+    let fraction = sample.effective_fraction();
+    if fraction >= 1.0 {
+        return true;
+    }
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    let key = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    let hash = xxh3_64_with_seed(key.as_bytes(), sample.seed);
+    (hash as f64) < fraction * u64::MAX as f64
+}
+
+/// Keep only the sampled items, returning how many were dropped.
+pub fn retain_sampled<T>(
+    items: &mut Vec<T>,
+    root: &Path,
+    sample: &SampleConfig,
+    path_of: impl Fn(&T) -> &Path,
+) -> usize {
+    let before = items.len();
+    items.retain(|item| is_sampled(path_of(item), root, sample));
+    before - items.len()
+}
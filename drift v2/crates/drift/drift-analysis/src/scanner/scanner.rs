@@ -12,10 +12,23 @@ use drift_core::types::collections::FxHashMap;
 use rayon::prelude::*;
 
 use super::cancellation::ScanCancellation;
+use super::checkpoint::{resume_file, CheckpointSink, CheckpointedFile};
+use super::focus::FocusFilter;
+use super::git_range;
 use super::incremental::{classify_file, compute_diff};
-use super::types::{CachedFileMetadata, DiscoveredFile, ScanDiff, ScanStats};
+use super::sampling::{is_sampled, retain_sampled};
+use super::streaming::ScanStream;
+use super::test_files::TestFileClassifier;
+use super::types::{CachedFileMetadata, DiscoveredFile, FileStatus, ScanDiff, ScanEntry, ScanStats};
 use super::walker;
 
+/// Checkpoint of an interrupted scan, and where to record new progress.
+#[derive(Clone, Copy)]
+struct Resume<'a> {
+    checkpoint: &'a FxHashMap<PathBuf, CheckpointedFile>,
+    sink: &'a dyn CheckpointSink,
+}
+
 /// The top-level scanner that orchestrates file discovery, hashing, and incremental detection.
 pub struct Scanner {
     config: ScanConfig,
@@ -45,6 +58,50 @@ impl Scanner {
         root: &Path,
         cached_metadata: &FxHashMap<PathBuf, CachedFileMetadata>,
         event_handler: &dyn DriftEventHandler,
+    ) -> Result<ScanDiff, ScanError> {
+        self.scan_with_resume(root, cached_metadata, None, event_handler)
+    }
+
+    /// Scan `root`, yielding added, modified and removed files as they are
+    /// found instead of after the whole walk.
+    ///
+    /// The stream ends with `ScanEvent::Complete` carrying the scan stats.
+    /// Cancelling through [`Scanner::cancellation`] stops the walk and ends
+    /// the stream early.
+    pub fn scan_streaming<'a>(
+        &'a self,
+        root: &Path,
+        cached_metadata: &'a FxHashMap<PathBuf, CachedFileMetadata>,
+        event_handler: &'a dyn DriftEventHandler,
+    ) -> ScanStream<'a> {
+        self.cancellation.reset();
+        ScanStream::new(root, &self.config, cached_metadata, &self.cancellation, event_handler)
+    }
+
+    /// Perform a scan that can be interrupted and resumed.
+    ///
+    /// Every `ScanConfig::checkpoint_interval` files, the files processed so
+    /// far are handed to `sink`. Files in `checkpoint` (from an earlier,
+    /// interrupted run) whose mtime is unchanged are not read again; the
+    /// diff's stats report them as `files_resumed`, the rest as `files_fresh`.
+    pub fn scan_resumable(
+        &self,
+        root: &Path,
+        cached_metadata: &FxHashMap<PathBuf, CachedFileMetadata>,
+        checkpoint: &FxHashMap<PathBuf, CheckpointedFile>,
+        sink: &dyn CheckpointSink,
+        event_handler: &dyn DriftEventHandler,
+    ) -> Result<ScanDiff, ScanError> {
+        let resume = Resume { checkpoint, sink };
+        self.scan_with_resume(root, cached_metadata, Some(resume), event_handler)
+    }
+
+    fn scan_with_resume(
+        &self,
+        root: &Path,
+        cached_metadata: &FxHashMap<PathBuf, CachedFileMetadata>,
+        resume: Option<Resume>,
+        event_handler: &dyn DriftEventHandler,
     ) -> Result<ScanDiff, ScanError> {
         self.cancellation.reset();
 
@@ -56,7 +113,7 @@ impl Scanner {
 
         // Phase 1: Discovery
         let discovery_start = Instant::now();
-        let files = match walker::walk_directory(
+        let mut files = match walker::walk_directory(
             root,
             &self.config,
             self.cancellation.as_atomic(),
@@ -71,8 +128,270 @@ impl Scanner {
         };
         let discovery_ms = discovery_start.elapsed().as_millis() as u64;
 
+        let focus = FocusFilter::new(&self.config.include_paths);
+        let sample = self.config.effective_sample();
+        let tests = TestFileClassifier::new(&self.config.test_patterns);
+        if !focus.is_active() && sample.is_none() {
+            let mut diff = self.process(&files, cached_metadata, resume, event_handler, discovery_ms);
+            mark_test_files(&mut diff, &tests, root);
+            return Ok(diff);
+        }
+
+        let mut context_files = Vec::new();
+        if focus.is_active() {
+            let (inside, outside): (Vec<_>, Vec<_>) =
+                files.into_iter().partition(|f| focus.contains(&f.path, root));
+            files = inside;
+            context_files = outside.into_iter().map(|f| f.path).collect();
+            context_files.sort();
+        }
+        let skipped = match &sample {
+            Some(sample) => retain_sampled(&mut files, root, sample, |f| &f.path),
+            None => 0,
+        };
+        // Out-of-focus and unsampled cached files are out of scope, not removed.
+        let cached: FxHashMap<PathBuf, CachedFileMetadata> = cached_metadata
+            .iter()
+            .filter(|(path, _)| {
+                focus.contains(path, root) && sample.map_or(true, |s| is_sampled(path, root, &s))
+            })
+            .map(|(path, meta)| (path.clone(), meta.clone()))
+            .collect();
+        let mut diff = self.process(&files, &cached, resume, event_handler, discovery_ms);
+        if let Some(sample) = sample {
+            diff.stats.sample_fraction = Some(sample.effective_fraction());
+            diff.stats.files_skipped_sampled = skipped;
+        }
+        diff.stats.files_outside_focus = context_files.len();
+        diff.context_files = context_files;
+        mark_test_files(&mut diff, &tests, root);
+        Ok(diff)
+    }
+
+    /// Scan several independent roots into a single diff.
+    ///
+    /// Files reachable from more than one root (nested or overlapping roots)
+    /// are scanned once and attributed to the most specific root. Every entry
+    /// in the resulting diff carries its originating root in `ScanEntry::root`.
+    pub fn scan_roots(
+        &self,
+        roots: &[PathBuf],
+        cached_metadata: &FxHashMap<PathBuf, CachedFileMetadata>,
+        event_handler: &dyn DriftEventHandler,
+    ) -> Result<ScanDiff, ScanError> {
+        self.cancellation.reset();
+
+        let discovery_start = Instant::now();
+        let mut by_path: FxHashMap<PathBuf, (DiscoveredFile, &PathBuf)> = FxHashMap::default();
+
+        for root in roots {
+            event_handler.on_scan_started(&ScanStartedEvent {
+                root: root.clone(),
+                file_count: None,
+            });
+
+            let files = match walker::walk_directory(
+                root,
+                &self.config,
+                self.cancellation.as_atomic(),
+            ) {
+                Ok(files) => files,
+                Err(e) => {
+                    event_handler.on_scan_error(&ScanErrorEvent {
+                        message: e.to_string(),
+                    });
+                    return Err(e);
+                }
+            };
+
+            for file in files {
+                match by_path.get(&file.path) {
+                    // Keep the deepest root for files shared by nested roots.
+                    Some((_, existing)) if existing.components().count() >= root.components().count() => {}
+                    _ => {
+                        by_path.insert(file.path.clone(), (file, root));
+                    }
+                }
+            }
+        }
+        let discovery_ms = discovery_start.elapsed().as_millis() as u64;
+
+        // Focus and sample after de-duplication so each file is judged
+        // against the root it is attributed to.
+        let focus = FocusFilter::new(&self.config.include_paths);
+        let mut context_files = Vec::new();
+        if focus.is_active() {
+            by_path.retain(|path, (_, root)| {
+                let inside = focus.contains(path, root);
+                if !inside {
+                    context_files.push(path.clone());
+                }
+                inside
+            });
+            context_files.sort();
+        }
+        let sample = self.config.effective_sample();
+        let mut skipped = 0;
+        if let Some(sample) = &sample {
+            let before = by_path.len();
+            by_path.retain(|path, (_, root)| is_sampled(path, root, sample));
+            skipped = before - by_path.len();
+        }
+
+        let mut roots_by_path: FxHashMap<PathBuf, PathBuf> = FxHashMap::default();
+        let mut files: Vec<DiscoveredFile> = Vec::with_capacity(by_path.len());
+        for (path, (file, root)) in by_path {
+            roots_by_path.insert(path, root.clone());
+            files.push(file);
+        }
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let mut diff = if focus.is_active() || sample.is_some() {
+            let cached: FxHashMap<PathBuf, CachedFileMetadata> = cached_metadata
+                .iter()
+                .filter(|(path, _)| {
+                    let root = roots
+                        .iter()
+                        .filter(|r| path.starts_with(r))
+                        .max_by_key(|r| r.components().count());
+                    root.map_or(true, |root| {
+                        focus.contains(path, root)
+                            && sample.map_or(true, |s| is_sampled(path, root, &s))
+                    })
+                })
+                .map(|(path, meta)| (path.clone(), meta.clone()))
+                .collect();
+            self.process(&files, &cached, None, event_handler, discovery_ms)
+        } else {
+            self.process(&files, cached_metadata, None, event_handler, discovery_ms)
+        };
+        if let Some(sample) = &sample {
+            diff.stats.sample_fraction = Some(sample.effective_fraction());
+            diff.stats.files_skipped_sampled = skipped;
+        }
+        diff.stats.files_outside_focus = context_files.len();
+        diff.context_files = context_files;
+        let tests = TestFileClassifier::new(&self.config.test_patterns);
+        for (path, entry) in diff.entries.iter_mut() {
+            entry.root = roots_by_path.get(path).cloned();
+            let root = entry.root.as_deref().unwrap_or(Path::new(""));
+            entry.is_test_file = tests.is_test_path(path, root);
+        }
+        Ok(diff)
+    }
+
+    /// Scan only the files changed between two git revisions of the
+    /// repository containing `root`, as `git diff --name-status base..head`
+    /// reports them.
+    ///
+    /// Added and modified paths are read from the working tree (expected to
+    /// be checked out at `head_ref`) and carry full `ScanEntry` metadata;
+    /// deleted paths are listed in `ScanDiff::removed`. Statuses come from
+    /// git, not from cached metadata. Focus (`include_paths`) and ignore
+    /// settings apply as in a full scan; sampling does not.
+    pub fn scan_git_range(
+        &self,
+        root: &Path,
+        base_ref: &str,
+        head_ref: &str,
+        event_handler: &dyn DriftEventHandler,
+    ) -> Result<ScanDiff, ScanError> {
+        self.cancellation.reset();
+        event_handler.on_scan_started(&ScanStartedEvent {
+            root: root.to_path_buf(),
+            file_count: None,
+        });
+
+        let discovery_start = Instant::now();
+        let changes = match git_range::changed_paths(root, base_ref, head_ref) {
+            Ok(changes) => changes,
+            Err(e) => {
+                event_handler.on_scan_error(&ScanErrorEvent {
+                    message: e.to_string(),
+                });
+                return Err(e);
+            }
+        };
+        let focus = FocusFilter::new(&self.config.include_paths);
+        let changed: Vec<PathBuf> = changes
+            .added
+            .iter()
+            .chain(&changes.modified)
+            .filter(|path| focus.contains(path, root))
+            .cloned()
+            .collect();
+        let files = walker::discover_paths(root, &self.config, &changed);
+        let discovery_ms = discovery_start.elapsed().as_millis() as u64;
+
+        let hashing_start = Instant::now();
+        let entries: Vec<(FileStatus, ScanEntry)> = files
+            .par_iter()
+            .filter_map(|file| {
+                if self.cancellation.is_cancelled() {
+                    return None;
+                }
+                match classify_file(file, None, false) {
+                    Ok((_, entry)) => {
+                        let status = if changes.modified.binary_search(&file.path).is_ok() {
+                            FileStatus::Modified
+                        } else {
+                            FileStatus::Added
+                        };
+                        Some((status, entry))
+                    }
+                    Err(e) => {
+                        tracing::warn!(path = %file.path.display(), error = %e, "file scan error");
+                        None
+                    }
+                }
+            })
+            .collect();
+        let hashing_ms = hashing_start.elapsed().as_millis() as u64;
+
+        let diff_start = Instant::now();
+        let mut languages_found = FxHashMap::default();
+        for (_, entry) in &entries {
+            if let Some(lang) = entry.language {
+                *languages_found.entry(lang).or_insert(0usize) += 1;
+            }
+        }
+        let stats = ScanStats {
+            discovery_ms,
+            hashing_ms,
+            languages_found,
+            files_outside_focus: changes.added.len() + changes.modified.len() - changed.len(),
+            ..Default::default()
+        };
+        let mut diff = compute_diff(entries, &FxHashMap::default(), stats);
+        diff.removed = changes
+            .removed
+            .into_iter()
+            .filter(|path| focus.contains(path, root))
+            .collect();
+        diff.stats.diff_ms = diff_start.elapsed().as_millis() as u64;
+        mark_test_files(&mut diff, &TestFileClassifier::new(&self.config.test_patterns), root);
+
+        event_handler.on_scan_complete(&ScanCompleteEvent {
+            added: diff.added.len(),
+            modified: diff.modified.len(),
+            removed: diff.removed.len(),
+            unchanged: 0,
+            duration_ms: discovery_ms + hashing_ms + diff.stats.diff_ms,
+        });
+        Ok(diff)
+    }
+
+    /// Hash, classify, and diff discovered files against the cache.
+    fn process(
+        &self,
+        files: &[DiscoveredFile],
+        cached_metadata: &FxHashMap<PathBuf, CachedFileMetadata>,
+        resume: Option<Resume>,
+        event_handler: &dyn DriftEventHandler,
+        discovery_ms: u64,
+    ) -> ScanDiff {
         if self.cancellation.is_cancelled() {
-            return Ok(self.partial_diff(&files, cached_metadata, discovery_ms));
+            return self.partial_diff(files, cached_metadata, discovery_ms);
         }
 
         // Emit progress with total count
@@ -88,36 +407,66 @@ impl Scanner {
         let total = files.len();
         let errors = Vec::new();
 
-        let entries: Vec<_> = files
-            .par_iter()
-            .filter_map(|file| {
-                if self.cancellation.is_cancelled() {
-                    return None;
-                }
+        // Resumable scans process files in checkpoint-sized chunks.
+        let chunk_size = match resume {
+            Some(_) => self.config.effective_checkpoint_interval(),
+            None => files.len().max(1),
+        };
+        let mut entries: Vec<(FileStatus, ScanEntry)> = Vec::with_capacity(files.len());
+        let (mut files_resumed, mut files_fresh) = (0, 0);
+        for chunk in files.chunks(chunk_size) {
+            if self.cancellation.is_cancelled() {
+                break;
+            }
+            let classified: Vec<_> = chunk
+                .par_iter()
+                .filter_map(|file| {
+                    if self.cancellation.is_cancelled() {
+                        return None;
+                    }
 
-                let count = processed.fetch_add(1, Ordering::Relaxed);
-                if count % 100 == 0 {
-                    event_handler.on_scan_progress(&ScanProgressEvent {
-                        processed: count,
-                        total,
-                    });
-                }
+                    let count = processed.fetch_add(1, Ordering::Relaxed);
+                    if count % 100 == 0 {
+                        event_handler.on_scan_progress(&ScanProgressEvent {
+                            processed: count,
+                            total,
+                        });
+                    }
 
-                let cached = cached_metadata.get(&file.path);
-                match classify_file(file, cached, force_full) {
-                    Ok(result) => Some(result),
-                    Err(e) => {
-                        // Non-fatal — skip file, continue scanning
-                        tracing::warn!(
-                            path = %file.path.display(),
-                            error = %e,
-                            "file scan error"
-                        );
-                        None
+                    let cached = cached_metadata.get(&file.path);
+                    let checkpointed = resume.and_then(|r| r.checkpoint.get(&file.path));
+                    if let Some((status, entry)) = resume_file(file, checkpointed, cached) {
+                        return Some((status, entry, true));
+                    }
+                    match classify_file(file, cached, force_full) {
+                        Ok((status, entry)) => Some((status, entry, false)),
+                        Err(e) => {
+                            // Non-fatal — skip file, continue scanning
+                            tracing::warn!(
+                                path = %file.path.display(),
+                                error = %e,
+                                "file scan error"
+                            );
+                            None
+                        }
                     }
+                })
+                .collect();
+
+            if let Some(resume) = resume {
+                let fresh: Vec<ScanEntry> = classified
+                    .iter()
+                    .filter(|(_, _, resumed)| !resumed)
+                    .map(|(_, entry, _)| entry.clone())
+                    .collect();
+                files_resumed += classified.len() - fresh.len();
+                files_fresh += fresh.len();
+                if !fresh.is_empty() {
+                    resume.sink.checkpoint(&fresh);
                 }
-            })
-            .collect();
+            }
+            entries.extend(classified.into_iter().map(|(status, entry, _)| (status, entry)));
+        }
 
         let hashing_ms = hashing_start.elapsed().as_millis() as u64;
 
@@ -135,7 +484,7 @@ impl Scanner {
         // Compute cache hit rate
         let mtime_hits = entries
             .iter()
-            .filter(|(status, _)| *status == super::types::FileStatus::Unchanged)
+            .filter(|(status, _)| *status == FileStatus::Unchanged)
             .count();
         let cache_hit_rate = if total > 0 {
             mtime_hits as f64 / total as f64
@@ -154,6 +503,11 @@ impl Scanner {
             files_skipped_ignored: 0,
             files_skipped_binary: 0,
             languages_found,
+            files_skipped_sampled: 0,
+            sample_fraction: None,
+            files_outside_focus: 0,
+            files_resumed,
+            files_fresh,
         };
 
         let mut diff = compute_diff(entries, cached_metadata, stats);
@@ -169,7 +523,7 @@ impl Scanner {
             duration_ms: discovery_ms + hashing_ms + diff.stats.diff_ms,
         });
 
-        Ok(diff)
+        diff
     }
 
     /// Build a partial diff when scan is cancelled mid-way.
@@ -186,3 +540,10 @@ impl Scanner {
         compute_diff(Vec::new(), cached_metadata, stats)
     }
 }
+
+/// Set `ScanEntry::is_test_file` on every entry, matching paths relative to `root`.
+fn mark_test_files(diff: &mut ScanDiff, tests: &TestFileClassifier, root: &Path) {
+    for (path, entry) in diff.entries.iter_mut() {
+        entry.is_test_file = tests.is_test_path(path, root);
+    }
+}
@@ -0,0 +1,230 @@
+//! Streaming scan: changed files are yielded while the walk is still running.
+//!
+//! The walk runs on a background thread and hands files over a bounded
+//! channel, so a slow consumer throttles discovery instead of buffering the
+//! whole tree. Files are classified (mtime, then content hash) as they are
+//! pulled from the stream, in discovery order rather than sorted. Removed
+//! files are only known once the walk has finished, so they come last,
+//! followed by a single `ScanEvent::Complete`.
+
+use std::path::{Path, PathBuf};
+use std::thread::JoinHandle;
+use std::time::Instant;
+
+use crossbeam_channel as channel;
+use drift_core::config::{SampleConfig, ScanConfig};
+use drift_core::events::handler::DriftEventHandler;
+use drift_core::events::types::{ScanCompleteEvent, ScanStartedEvent};
+use drift_core::types::collections::{FxHashMap, FxHashSet};
+
+use super::cancellation::ScanCancellation;
+use super::focus::FocusFilter;
+use super::incremental::classify_file;
+use super::sampling::is_sampled;
+use super::test_files::TestFileClassifier;
+use super::types::{CachedFileMetadata, DiscoveredFile, FileStatus, ScanEntry, ScanStats};
+use super::walker;
+
+/// Discovered files buffered between the walker and the consumer.
+const STREAM_BUFFER: usize = 1024;
+
+/// One change reported by [`ScanStream`].
+#[derive(Debug, Clone)]
+pub enum ScanEvent {
+    /// A file that is not in the cache.
+    Added(ScanEntry),
+    /// A cached file whose content changed.
+    Modified(ScanEntry),
+    /// A cached file that is no longer on disk.
+    Removed(PathBuf),
+    /// Totals for the whole scan; always the last event.
+    Complete(ScanStats),
+}
+
+/// Iterator of [`ScanEvent`]s, created by `Scanner::scan_streaming`.
+///
+/// Unchanged files are counted in the final stats but not yielded. After
+/// cancellation the stream skips removals (an interrupted walk cannot tell
+/// them apart from unvisited files) and ends with the stats so far.
+pub struct ScanStream<'a> {
+    root: PathBuf,
+    cached: &'a FxHashMap<PathBuf, CachedFileMetadata>,
+    cancellation: &'a ScanCancellation,
+    event_handler: &'a dyn DriftEventHandler,
+    files: channel::Receiver<DiscoveredFile>,
+    walker: Option<JoinHandle<()>>,
+    focus: FocusFilter,
+    sample: Option<SampleConfig>,
+    tests: TestFileClassifier,
+    force_full: bool,
+    seen: FxHashSet<PathBuf>,
+    removed: Option<std::vec::IntoIter<PathBuf>>,
+    stats: ScanStats,
+    counts: ScanCompleteEvent,
+    hashing_us: u64,
+    started: Instant,
+    done: bool,
+}
+
+impl<'a> ScanStream<'a> {
+    pub(super) fn new(
+        root: &Path,
+        config: &ScanConfig,
+        cached: &'a FxHashMap<PathBuf, CachedFileMetadata>,
+        cancellation: &'a ScanCancellation,
+        event_handler: &'a dyn DriftEventHandler,
+    ) -> Self {
+        event_handler.on_scan_started(&ScanStartedEvent {
+            root: root.to_path_buf(),
+            file_count: None,
+        });
+
+        let (tx, files) = channel::bounded(STREAM_BUFFER);
+        let walker = {
+            let root = root.to_path_buf();
+            let config = config.clone();
+            let cancellation = cancellation.clone();
+            std::thread::spawn(move || walker::walk_into(&root, &config, cancellation.as_atomic(), tx))
+        };
+        let sample = config.effective_sample();
+        Self {
+            root: root.to_path_buf(),
+            cached,
+            cancellation,
+            event_handler,
+            files,
+            walker: Some(walker),
+            focus: FocusFilter::new(&config.include_paths),
+            sample,
+            tests: TestFileClassifier::new(&config.test_patterns),
+            force_full: config.force_full_scan.unwrap_or(false),
+            seen: FxHashSet::default(),
+            removed: None,
+            stats: ScanStats {
+                sample_fraction: sample.map(|s| s.effective_fraction()),
+                ..Default::default()
+            },
+            counts: ScanCompleteEvent {
+                added: 0,
+                modified: 0,
+                removed: 0,
+                unchanged: 0,
+                duration_ms: 0,
+            },
+            hashing_us: 0,
+            started: Instant::now(),
+            done: false,
+        }
+    }
+
+    fn in_scope(&self, path: &Path) -> bool {
+        self.focus.contains(path, &self.root)
+            && self.sample.map_or(true, |s| is_sampled(path, &self.root, &s))
+    }
+
+    /// Classify a discovered file, returning an event for added and modified files.
+    fn classify(&mut self, file: DiscoveredFile) -> Option<ScanEvent> {
+        if !self.focus.contains(&file.path, &self.root) {
+            self.stats.files_outside_focus += 1;
+            return None;
+        }
+        if self.sample.is_some_and(|s| !is_sampled(&file.path, &self.root, &s)) {
+            self.stats.files_skipped_sampled += 1;
+            return None;
+        }
+
+        let (status, mut entry) = match classify_file(&file, self.cached.get(&file.path), self.force_full) {
+            Ok(classified) => classified,
+            Err(e) => {
+                // Non-fatal — skip file, continue scanning
+                tracing::warn!(path = %file.path.display(), error = %e, "file scan error");
+                return None;
+            }
+        };
+        entry.is_test_file = self.tests.is_test_path(&entry.path, &self.root);
+        self.seen.insert(file.path);
+
+        self.hashing_us += entry.scan_duration_us;
+        self.stats.total_files += 1;
+        self.stats.total_size_bytes += entry.file_size;
+        if let Some(lang) = entry.language {
+            *self.stats.languages_found.entry(lang).or_insert(0) += 1;
+        }
+        match status {
+            FileStatus::Added => {
+                self.counts.added += 1;
+                Some(ScanEvent::Added(entry))
+            }
+            FileStatus::Modified => {
+                self.counts.modified += 1;
+                Some(ScanEvent::Modified(entry))
+            }
+            FileStatus::Unchanged => {
+                self.counts.unchanged += 1;
+                None
+            }
+        }
+    }
+
+    /// Stop the walker and work out which in-scope cached files were not seen.
+    fn finish_walk(&mut self) -> std::vec::IntoIter<PathBuf> {
+        // Dropping the receiver unblocks a walker waiting on a full channel.
+        drop(std::mem::replace(&mut self.files, channel::never()));
+        if let Some(walker) = self.walker.take() {
+            let _ = walker.join();
+        }
+        self.stats.discovery_ms = self.started.elapsed().as_millis() as u64;
+
+        let mut removed = Vec::new();
+        if !self.cancellation.is_cancelled() {
+            removed = self
+                .cached
+                .keys()
+                .filter(|path| !self.seen.contains(*path) && self.in_scope(path))
+                .cloned()
+                .collect();
+            removed.sort();
+        }
+        removed.into_iter()
+    }
+
+    fn complete(&mut self) -> ScanStats {
+        let mut stats = std::mem::take(&mut self.stats);
+        stats.hashing_ms = self.hashing_us / 1000;
+        stats.cache_hit_rate = if stats.total_files > 0 {
+            self.counts.unchanged as f64 / stats.total_files as f64
+        } else {
+            0.0
+        };
+        self.counts.duration_ms = self.started.elapsed().as_millis() as u64;
+        self.event_handler.on_scan_complete(&self.counts);
+        stats
+    }
+}
+
+impl Iterator for ScanStream<'_> {
+    type Item = ScanEvent;
+
+    fn next(&mut self) -> Option<ScanEvent> {
+        if self.done {
+            return None;
+        }
+        if self.removed.is_none() {
+            while !self.cancellation.is_cancelled() {
+                let Ok(file) = self.files.recv() else {
+                    break;
+                };
+                if let Some(event) = self.classify(file) {
+                    return Some(event);
+                }
+            }
+            self.removed = Some(self.finish_walk());
+        }
+        if let Some(path) = self.removed.as_mut().and_then(Iterator::next) {
+            self.counts.removed += 1;
+            return Some(ScanEvent::Removed(path));
+        }
+        self.done = true;
+        Some(ScanEvent::Complete(self.complete()))
+    }
+}
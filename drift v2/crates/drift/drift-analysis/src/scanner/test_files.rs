@@ -0,0 +1,74 @@
+//! Test-file classification.
+//!
+//! A hard-coded password in `login.test.ts` is a fixture, not a leak, while
+//! the complexity of the same file still matters. The scanner marks every
+//! entry with `ScanEntry::is_test_file` so detectors and gates can leave test
+//! code out of some categories (see `DetectorRegistry::exclude_tests`) and
+//! keep it in others. Patterns are matched against the root-relative path
+//! with `/` separators; `*` does not cross directories.
+
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Globs that mark test files by default: test-suffixed and test-prefixed
+/// file names across languages, and test directories.
+pub const DEFAULT_TEST_PATTERNS: &[&str] = &[
+    "**/*.test.*", "**/*.spec.*", "**/*_test.*", "**/*_spec.*", "**/test_*.py", "**/*Test.*",
+    "**/*Tests.*", "**/conftest.py", "**/tests/**", "**/test/**", "**/__tests__/**", "**/spec/**",
+    "**/testdata/**",
+];
+
+const MATCH_OPTIONS: glob::MatchOptions = glob::MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Compiled test-file patterns: the defaults plus `ScanConfig::test_patterns`.
+#[derive(Debug, Clone)]
+pub struct TestFileClassifier {
+    patterns: Vec<glob::Pattern>,
+}
+
+impl TestFileClassifier {
+    /// Compile the default patterns plus `extra`. Invalid globs are skipped
+    /// with a warning.
+    pub fn new(extra: &[String]) -> Self {
+        let mut patterns = Vec::new();
+        for pattern in DEFAULT_TEST_PATTERNS.iter().copied().chain(extra.iter().map(|p| p.trim())) {
+            if pattern.is_empty() {
+                continue;
+            }
+            match glob::Pattern::new(pattern) {
+                Ok(p) => patterns.push(p),
+                Err(e) => tracing::warn!(pattern, error = %e, "invalid test-file pattern"),
+            }
+        }
+        Self { patterns }
+    }
+
+    /// Whether `path` (root-relative, either separator) is test code.
+    pub fn is_test_file(&self, path: &str) -> bool {
+        let path = path.replace('\\', "/");
+        let path = path.trim_start_matches("./");
+        self.patterns.iter().any(|p| p.matches_with(path, MATCH_OPTIONS))
+    }
+
+    /// Whether `path` under `root` is test code.
+    pub fn is_test_path(&self, path: &Path, root: &Path) -> bool {
+        let relative = path.strip_prefix(root).unwrap_or(path);
+        self.is_test_file(&relative.to_string_lossy())
+    }
+}
+
+impl Default for TestFileClassifier {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+/// Whether `path` is test code under the default patterns.
+pub fn is_test_file(path: &str) -> bool {
+    static DEFAULT: OnceLock<TestFileClassifier> = OnceLock::new();
+    DEFAULT.get_or_init(TestFileClassifier::default).is_test_file(path)
+}
@@ -18,6 +18,12 @@ pub struct ScanEntry {
     pub file_size: u64,
     pub language: Option<Language>,
     pub scan_duration_us: u64,
+    /// The scan root this file was discovered under (multi-root scans only).
+    #[serde(default)]
+    pub root: Option<PathBuf>,
+    /// Whether the path matches a test-file pattern (see `scanner::test_files`).
+    #[serde(default)]
+    pub is_test_file: bool,
 }
 
 /// The primary output of a scan operation. Classifies every file relative to the last scan.
@@ -30,6 +36,10 @@ pub struct ScanDiff {
     pub errors: Vec<String>,
     pub stats: ScanStats,
     pub entries: FxHashMap<PathBuf, ScanEntry>,
+    /// Files outside `ScanConfig::include_paths`, available for read-only
+    /// import resolution but not analyzed (focused scans only).
+    #[serde(default)]
+    pub context_files: Vec<PathBuf>,
 }
 
 /// Aggregate statistics for a scan operation.
@@ -45,6 +55,21 @@ pub struct ScanStats {
     pub files_skipped_ignored: usize,
     pub files_skipped_binary: usize,
     pub languages_found: FxHashMap<Language, usize>,
+    /// Files left out by `ScanConfig::sample`.
+    #[serde(default)]
+    pub files_skipped_sampled: usize,
+    /// Sampled fraction when the scan covered only a sample; `None` for a full scan.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sample_fraction: Option<f64>,
+    /// Files left out by `ScanConfig::include_paths`.
+    #[serde(default)]
+    pub files_outside_focus: usize,
+    /// Files taken from the checkpoint of an interrupted scan (resumable scans only).
+    #[serde(default)]
+    pub files_resumed: usize,
+    /// Files processed from scratch by a resumable scan.
+    #[serde(default)]
+    pub files_fresh: usize,
 }
 
 /// Intermediate type during discovery phase.
@@ -1,13 +1,16 @@
 //! Parallel file walker using the `ignore` crate's `WalkParallel`.
 //!
-//! Supports `.driftignore` (gitignore syntax, hierarchical) and 18 default ignore patterns.
+//! Supports `.gitignore` and `.driftignore` (gitignore syntax, hierarchical,
+//! inside or outside a git repository), `ScanConfig::extra_ignore` and 18
+//! default ignore patterns. Ignored directories are pruned, never descended.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
 
 use crossbeam_channel as channel;
 use drift_core::config::ScanConfig;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::overrides::{Override, OverrideBuilder};
 
 use super::language_detect::Language;
 use super::types::DiscoveredFile;
@@ -44,7 +47,24 @@ pub fn walk_directory(
     cancelled: &AtomicBool,
 ) -> Result<Vec<DiscoveredFile>, drift_core::errors::ScanError> {
     let (tx, rx) = channel::unbounded();
+    walk_into(root, config, cancelled, tx);
+    let mut files: Vec<DiscoveredFile> = rx.into_iter().collect();
+    // Sort for deterministic output
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
 
+/// Walk a directory tree in parallel, sending each discovered file to `tx`
+/// as soon as it is found (in no particular order).
+///
+/// Stops early once `cancelled` is set or the receiving side is dropped, so
+/// a bounded channel gives the caller backpressure.
+pub fn walk_into(
+    root: &Path,
+    config: &ScanConfig,
+    cancelled: &AtomicBool,
+    tx: channel::Sender<DiscoveredFile>,
+) {
     let max_file_size = config.effective_max_file_size();
     let follow_links = config.follow_symlinks.unwrap_or(false);
     let threads = config.effective_threads();
@@ -55,6 +75,7 @@ pub fn walk_directory(
         .git_ignore(true)
         .git_global(true)
         .git_exclude(true)
+        .require_git(false)
         .add_custom_ignore_filename(".driftignore")
         .max_filesize(Some(max_file_size))
         .follow_links(follow_links);
@@ -63,47 +84,23 @@ pub fn walk_directory(
         builder.threads(threads);
     }
 
-    // Build overrides: include patterns (whitelist) + ignore patterns (blacklist).
-    //
-    // The `ignore` crate's OverrideBuilder uses gitignore syntax:
-    // - Positive patterns act as a whitelist (only matching files are included)
-    // - Negated patterns (prefixed with !) act as a blacklist (matching files are excluded)
-    //
-    // When include patterns are present, we add them as positive patterns first,
-    // then add ignore patterns as negated patterns. The ignore crate evaluates
-    // overrides in order: if any positive pattern matches, the file is included;
-    // if any negated pattern matches, the file is excluded.
-    let mut overrides = ignore::overrides::OverrideBuilder::new(root);
-
-    // If include patterns are specified, add them as positive whitelist patterns.
-    // Files must match at least one include pattern to be scanned.
-    if !config.include.is_empty() {
-        for pattern in &config.include {
-            let _ = overrides.add(pattern);
-        }
+    if let Some(overrides) = build_overrides(root, config) {
+        builder.overrides(overrides);
     }
 
-    // Add default ignore patterns (blacklist)
-    for pattern in DEFAULT_IGNORES {
-        let _ = overrides.add(&format!("!{}/**", pattern));
-        let _ = overrides.add(&format!("!{}", pattern));
-    }
-    // Add user-configured extra ignores (blacklist)
-    for pattern in &config.extra_ignore {
-        let _ = overrides.add(&format!("!{}", pattern));
-    }
-    if let Ok(built) = overrides.build() {
-        builder.overrides(built);
+    // `extra_ignore` is matched per entry so a matching directory is
+    // skipped before anything under it is read.
+    if let Some(globs) = ignore_glob_matcher(root, &config.extra_ignore) {
+        builder.filter_entry(move |entry| {
+            let is_dir = entry.file_type().is_some_and(|ft| ft.is_dir());
+            !globs.matched(entry.path(), is_dir).is_ignore()
+        });
     }
 
     let walker = builder.build_parallel();
 
-    // Use Arc<AtomicBool> for safe cross-thread sharing
-    let cancelled = Arc::new(AtomicBool::new(cancelled.load(Ordering::Relaxed)));
-
     walker.run(|| {
         let tx = tx.clone();
-        let cancelled = Arc::clone(&cancelled);
         Box::new(move |entry| {
             if cancelled.load(Ordering::Relaxed) {
                 return ignore::WalkState::Quit;
@@ -135,20 +132,106 @@ pub fn walk_directory(
                 .modified()
                 .unwrap_or(std::time::SystemTime::UNIX_EPOCH);
 
-            let _ = tx.send(DiscoveredFile {
+            let sent = tx.send(DiscoveredFile {
                 path,
                 file_size: metadata.len(),
                 mtime,
                 language,
             });
 
-            ignore::WalkState::Continue
+            match sent {
+                Ok(()) => ignore::WalkState::Continue,
+                // The receiver is gone; nobody wants the rest of the walk.
+                Err(_) => ignore::WalkState::Quit,
+            }
         })
     });
+}
 
-    drop(tx);
-    let mut files: Vec<DiscoveredFile> = rx.into_iter().collect();
-    // Sort for deterministic output
+/// Discover the listed `paths` (under `root`) without walking the tree.
+///
+/// Applies the walk's filters: `include`, the default ignores, `extra_ignore`,
+/// the root `.driftignore` and the file size limit. `.gitignore` is not
+/// consulted (listed paths come from git and are tracked), nor are nested
+/// `.driftignore` files. Paths that no longer exist or are not regular files
+/// are skipped. Returns files sorted by path.
+pub fn discover_paths(root: &Path, config: &ScanConfig, paths: &[PathBuf]) -> Vec<DiscoveredFile> {
+    let max_file_size = config.effective_max_file_size();
+    let overrides = build_overrides(root, config);
+    let mut ignores = GitignoreBuilder::new(root);
+    let _ = ignores.add(root.join(".driftignore"));
+    for glob in &config.extra_ignore {
+        let _ = ignores.add_line(None, glob);
+    }
+    let ignores = ignores.build().ok();
+
+    let ignored = |path: &Path| {
+        // Walks prune ignored directories, so check every ancestor up to the root.
+        let dirs = path.ancestors().skip(1).take_while(|dir| dir.starts_with(root) && *dir != root);
+        let overridden = overrides.as_ref().is_some_and(|o| {
+            o.matched(path, false).is_ignore() || dirs.clone().any(|dir| o.matched(dir, true).is_ignore())
+        });
+        overridden || ignores.as_ref().is_some_and(|g| g.matched_path_or_any_parents(path, false).is_ignore())
+    };
+
+    let mut files: Vec<DiscoveredFile> = paths
+        .iter()
+        .filter(|path| !ignored(path))
+        .filter_map(|path| {
+            let metadata = std::fs::metadata(path).ok()?;
+            if !metadata.is_file() || metadata.len() > max_file_size {
+                return None;
+            }
+            Some(DiscoveredFile {
+                path: path.clone(),
+                file_size: metadata.len(),
+                mtime: metadata.modified().unwrap_or(std::time::SystemTime::UNIX_EPOCH),
+                language: Language::from_extension(path.extension().and_then(|e| e.to_str())),
+            })
+        })
+        .collect();
     files.sort_by(|a, b| a.path.cmp(&b.path));
-    Ok(files)
+    files
+}
+
+/// Include patterns (whitelist) plus the default ignores (blacklist).
+fn build_overrides(root: &Path, config: &ScanConfig) -> Option<Override> {
+    // The `ignore` crate's OverrideBuilder uses gitignore syntax:
+    // - Positive patterns act as a whitelist (only matching files are included)
+    // - Negated patterns (prefixed with !) act as a blacklist (matching files are excluded)
+    //
+    // When include patterns are present, we add them as positive patterns first,
+    // then add ignore patterns as negated patterns. The ignore crate evaluates
+    // overrides in order: if any positive pattern matches, the file is included;
+    // if any negated pattern matches, the file is excluded.
+    let mut overrides = OverrideBuilder::new(root);
+
+    // If include patterns are specified, add them as positive whitelist patterns.
+    // Files must match at least one include pattern to be scanned.
+    if !config.include.is_empty() {
+        for pattern in &config.include {
+            let _ = overrides.add(pattern);
+        }
+    }
+
+    // Add default ignore patterns (blacklist)
+    for pattern in DEFAULT_IGNORES {
+        let _ = overrides.add(&format!("!{}/**", pattern));
+        let _ = overrides.add(&format!("!{}", pattern));
+    }
+    overrides.build().ok()
+}
+
+/// Matcher for `ScanConfig::extra_ignore` with gitignore semantics: the last
+/// matching glob wins and `!glob` re-includes. Invalid globs are skipped, as
+/// in a malformed `.driftignore`. `None` when there are no globs.
+fn ignore_glob_matcher(root: &Path, globs: &[String]) -> Option<Gitignore> {
+    if globs.is_empty() {
+        return None;
+    }
+    let mut builder = GitignoreBuilder::new(root);
+    for glob in globs {
+        let _ = builder.add_line(None, glob);
+    }
+    builder.build().ok()
 }
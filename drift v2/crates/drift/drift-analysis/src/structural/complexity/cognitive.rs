@@ -0,0 +1,262 @@
+//! Cognitive complexity (SonarSource, 2017) over tree-sitter nodes.
+//!
+//! Structural increments: +1 for each `if`, `else if`, `else`, ternary,
+//! switch/match, loop, catch, labelled `break`/`continue`, `goto`, each run
+//! of like boolean operators (`a && b && c` is +1, `a && b || c` is +2) and
+//! each direct recursive call. `if`, ternaries, switches, loops and catches
+//! also add the current nesting level. Nesting grows inside those
+//! constructs and inside lambdas and closures, which add nothing
+//! themselves. `else if`/`else` stay at the level of their `if`. Nested
+//! functions the parser extracts on their own (declarations, methods,
+//! arrow functions) are scored separately and not walked again.
+//!
+//! The walk uses an explicit stack, so deeply nested code cannot overflow
+//! the thread stack.
+//!
+//! Node kinds cover the grammars of all supported languages. Where a
+//! grammar does not mark `else` branches (Kotlin, Swift), an `else if`
+//! counts as a nested `if`.
+
+use tree_sitter::Node;
+
+const IF_KINDS: &[&str] = &["if_statement", "if_expression", "if", "unless"];
+
+/// `elif`/`elsif`/`elseif` branches that have their own node.
+const ELSE_IF_KINDS: &[&str] = &["elif_clause", "elsif", "else_if_clause"];
+
+const ELSE_KINDS: &[&str] = &["else_clause", "else"];
+
+const TERNARY_KINDS: &[&str] = &["ternary_expression", "conditional_expression", "conditional"];
+
+const SWITCH_KINDS: &[&str] = &[
+    "switch_statement", "switch_expression", "expression_switch_statement", "type_switch_statement",
+    "select_statement", "match_expression", "match_statement", "when_expression", "case",
+];
+
+const LOOP_KINDS: &[&str] = &[
+    "for_statement", "for_in_statement", "enhanced_for_statement", "foreach_statement",
+    "range_based_for_statement", "for_expression", "while_statement", "while_expression",
+    "loop_expression", "do_statement", "do_while_statement", "repeat_while_statement", "for",
+    "while", "until",
+];
+
+const CATCH_KINDS: &[&str] = &["catch_clause", "except_clause", "rescue", "catch_block"];
+
+const JUMP_KINDS: &[&str] = &["break_statement", "continue_statement", "break_expression", "continue_expression"];
+
+/// Functions the parser extracts (and scores) on their own: not descended into.
+const SEPARATE_FUNCTION_KINDS: &[&str] = &[
+    "function_declaration", "function_definition", "function_item", "method_declaration",
+    "method_definition", "method", "singleton_method", "init_declaration", "arrow_function",
+];
+
+/// Lambdas and closures: nesting only.
+const FUNCTION_KINDS: &[&str] = &[
+    "function_expression", "lambda", "lambda_expression", "lambda_literal", "closure_expression",
+    "func_literal", "anonymous_function", "anonymous_function_creation_expression",
+    "anonymous_method_expression", "local_function_statement", "do_block",
+];
+
+const CALL_KINDS: &[&str] = &[
+    "call_expression", "call", "method_invocation", "invocation_expression",
+    "function_call_expression", "member_call_expression", "scoped_call_expression",
+];
+
+/// A node waiting to be visited.
+struct Pending<'tree> {
+    node: Node<'tree>,
+    nesting: u32,
+    /// The `alternative` branch of an `if`/`elif`.
+    is_alternative: bool,
+    /// Operand of a logical node, so part of an already scored chain.
+    in_chain: bool,
+}
+
+/// Cognitive complexity of the function or method at `func_node`.
+pub fn cognitive_complexity(func_node: Node, source: &[u8]) -> u32 {
+    let name = function_name(func_node, source);
+    let mut score = 0;
+    let mut stack = Vec::new();
+    push_children(func_node, source, 0, 0, &mut stack);
+    while let Some(pending) = stack.pop() {
+        score += visit(pending, source, name.as_deref(), &mut stack);
+    }
+    score
+}
+
+/// Score one node and queue its children.
+fn visit<'tree>(pending: Pending<'tree>, source: &[u8], name: Option<&str>, stack: &mut Vec<Pending<'tree>>) -> u32 {
+    let Pending { node, nesting, is_alternative, in_chain } = pending;
+    let kind = node.kind();
+
+    // `else` / `else if` reached through an `if`'s alternative
+    if is_alternative {
+        if let Some(inner_if) = ELSE_KINDS.contains(&kind).then(|| only_if(node)).flatten() {
+            // `else if` (JS, Rust, C++): the inner `if` is the branch, a flat +1
+            push_children(inner_if, source, nesting + 1, nesting, stack);
+            return 1;
+        }
+        push_children(node, source, nesting + 1, nesting, stack);
+        return 1;
+    }
+
+    if SEPARATE_FUNCTION_KINDS.contains(&kind) {
+        return 0;
+    }
+
+    let mut score = 0;
+    let inner = if IF_KINDS.contains(&kind)
+        || TERNARY_KINDS.contains(&kind)
+        || SWITCH_KINDS.contains(&kind)
+        || LOOP_KINDS.contains(&kind)
+        || CATCH_KINDS.contains(&kind)
+    {
+        score += 1 + nesting;
+        nesting + 1
+    } else if ELSE_IF_KINDS.contains(&kind) {
+        // Unattached `elif` (defensive; grammars normally use `alternative`)
+        score += 1;
+        nesting + 1
+    } else if FUNCTION_KINDS.contains(&kind) {
+        nesting + 1
+    } else {
+        if (JUMP_KINDS.contains(&kind) && has_label(node)) || kind == "goto_statement" {
+            score += 1;
+        }
+        if !in_chain && logical_operator(node, source).is_some() {
+            score += boolean_sequences(node, source);
+        }
+        if CALL_KINDS.contains(&kind) && name.is_some() && callee_name(node, source).as_deref() == name {
+            score += 1;
+        }
+        nesting
+    };
+    push_children(node, source, inner, nesting, stack);
+    score
+}
+
+/// Queue named children at `inner` nesting; an `if`'s alternatives stay at `outer`.
+fn push_children<'tree>(node: Node<'tree>, source: &[u8], inner: u32, outer: u32, stack: &mut Vec<Pending<'tree>>) {
+    let is_if = IF_KINDS.contains(&node.kind()) || ELSE_IF_KINDS.contains(&node.kind());
+    let is_logical = logical_operator(node, source).is_some();
+    let mut cursor = node.walk();
+    if !cursor.goto_first_child() {
+        return;
+    }
+    loop {
+        let child = cursor.node();
+        if child.is_named() {
+            let alternative = cursor.field_name() == Some("alternative");
+            stack.push(Pending {
+                node: child,
+                nesting: if alternative { outer } else { inner },
+                is_alternative: alternative && is_if,
+                in_chain: is_logical,
+            });
+        }
+        if !cursor.goto_next_sibling() {
+            break;
+        }
+    }
+}
+
+/// The `if` an `else` wraps when it is the branch's only child.
+fn only_if(node: Node) -> Option<Node> {
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.named_children(&mut cursor).filter(|c| !c.is_extra()).collect();
+    match children.as_slice() {
+        [only] if IF_KINDS.contains(&only.kind()) => Some(*only),
+        _ => None,
+    }
+}
+
+fn has_label(node: Node) -> bool {
+    let mut cursor = node.walk();
+    let labelled = node
+        .named_children(&mut cursor)
+        .any(|c| c.kind().contains("identifier") || c.kind().contains("label"));
+    labelled
+}
+
+/// `&&` or `||` for a logical binary node, normalising `and`/`or`.
+fn logical_operator(node: Node, source: &[u8]) -> Option<&'static str> {
+    match node.kind() {
+        "conjunction_expression" => return Some("&&"),
+        "disjunction_expression" => return Some("||"),
+        "binary_expression" | "boolean_operator" | "binary" => {}
+        _ => return None,
+    }
+    let operator = node.child_by_field_name("operator")?;
+    match operator.utf8_text(source).ok()? {
+        "&&" | "and" => Some("&&"),
+        "||" | "or" => Some("||"),
+        _ => None,
+    }
+}
+
+/// Runs of like operators in a chain of logical nodes, in source order.
+fn boolean_sequences(node: Node, source: &[u8]) -> u32 {
+    let mut operators = Vec::new();
+    collect_operators(node, source, &mut operators);
+    let changes = operators.windows(2).filter(|w| w[0] != w[1]).count() as u32;
+    1 + changes
+}
+
+fn collect_operators(node: Node, source: &[u8], operators: &mut Vec<&'static str>) {
+    enum Item<'tree> {
+        Operand(Node<'tree>),
+        Operator(&'static str),
+    }
+    // In-order walk over the chain; long chains nest as deep as they are long.
+    let mut stack = vec![Item::Operand(node)];
+    while let Some(item) = stack.pop() {
+        let node = match item {
+            Item::Operator(op) => {
+                operators.push(op);
+                continue;
+            }
+            Item::Operand(node) => node,
+        };
+        let Some(op) = logical_operator(node, source) else {
+            continue;
+        };
+        let mut cursor = node.walk();
+        let operands: Vec<Node> = node.named_children(&mut cursor).collect();
+        let Some((first, rest)) = operands.split_first() else {
+            continue;
+        };
+        stack.extend(rest.iter().rev().map(|operand| Item::Operand(*operand)));
+        stack.push(Item::Operator(op));
+        stack.push(Item::Operand(*first));
+    }
+}
+
+/// Name of the function being called, without receiver or path.
+fn callee_name(node: Node, source: &[u8]) -> Option<String> {
+    let callee = node
+        .child_by_field_name("function")
+        .or_else(|| node.child_by_field_name("method"))
+        .or_else(|| node.child_by_field_name("name"))
+        .or_else(|| node.named_child(0))?;
+    let text = callee.utf8_text(source).ok()?;
+    let last = text.rsplit(['.', ':', '>']).next()?;
+    Some(last.split('<').next().unwrap_or(last).trim().to_string())
+}
+
+/// Declared name, looking through C/C++ declarators.
+fn function_name(node: Node, source: &[u8]) -> Option<String> {
+    let mut current = node;
+    loop {
+        if let Some(name) = current.child_by_field_name("name") {
+            return name.utf8_text(source).ok().map(str::to_string);
+        }
+        match current.child_by_field_name("declarator") {
+            Some(declarator) if declarator.kind().contains("identifier") => {
+                let text = declarator.utf8_text(source).ok()?;
+                return text.rsplit(':').next().map(str::to_string);
+            }
+            Some(declarator) => current = declarator,
+            None => return None,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! Complexity metrics computed per function from the AST.
+
+pub mod cognitive;
+
+pub use cognitive::cognitive_complexity;
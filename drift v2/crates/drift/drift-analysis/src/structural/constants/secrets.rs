@@ -1,5 +1,9 @@
 //! Phase 3: Secret detection engine — 150+ patterns, format validation, CWE mappings.
 
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
 use super::entropy::shannon_entropy;
 use super::types::{Secret, SecretSeverity};
 
@@ -100,10 +104,100 @@ pub static SECRET_PATTERNS: &[SecretPattern] = &[
     SecretPattern { name: "linear_api_key", pattern: r"lin_api_[A-Za-z0-9]{40}", severity: SecretSeverity::High, format_prefix: Some("lin_api_"), cwe_ids: &[798], min_entropy: 0.0 },
 ];
 
+/// A user-defined secret pattern, loaded from TOML.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomSecretPattern {
+    /// Pattern name reported on matches (e.g., "acme_internal_token").
+    pub name: String,
+    /// Regex matched against each line.
+    pub regex: String,
+    /// Minimum Shannon entropy of the match (0.0 = no entropy check).
+    #[serde(default)]
+    pub entropy_threshold: f64,
+    /// Confidence reported on matches.
+    #[serde(default = "default_custom_confidence")]
+    pub confidence: f64,
+    /// Severity reported on matches.
+    #[serde(default = "default_custom_severity")]
+    pub severity: SecretSeverity,
+    /// CWE IDs reported on matches.
+    #[serde(default = "default_custom_cwe_ids")]
+    pub cwe_ids: Vec<u32>,
+}
+
+fn default_custom_confidence() -> f64 {
+    0.7
+}
+
+fn default_custom_severity() -> SecretSeverity {
+    SecretSeverity::High
+}
+
+fn default_custom_cwe_ids() -> Vec<u32> {
+    vec![798]
+}
+
+/// TOML-driven registry of custom secret patterns.
+///
+/// Custom patterns run in addition to the built-in [`SECRET_PATTERNS`]:
+///
+/// ```toml
+/// [[patterns]]
+/// name = "acme_internal_token"
+/// regex = "acme_tok_[A-Za-z0-9]{32}"
+/// entropy_threshold = 3.0
+/// confidence = 0.9
+/// severity = "Medium"   # default "High"
+/// cwe_ids = [798, 522]  # default [798]
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SecretPatternRegistry {
+    /// Custom patterns, in load order.
+    pub patterns: Vec<CustomSecretPattern>,
+}
+
+/// TOML configuration structure for the secret pattern registry.
+#[derive(Debug, Deserialize)]
+struct SecretRegistryConfig {
+    patterns: Option<Vec<CustomSecretPattern>>,
+}
+
+impl SecretPatternRegistry {
+    /// Create a new empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load additional patterns from TOML string. Patterns whose regex does
+    /// not compile are rejected.
+    pub fn load_toml(&mut self, toml_str: &str) -> Result<(), toml::de::Error> {
+        let config: SecretRegistryConfig = toml::from_str(toml_str)?;
+
+        let patterns = config.patterns.unwrap_or_default();
+        for pattern in &patterns {
+            if let Err(e) = regex::Regex::new(&pattern.regex) {
+                return Err(serde::de::Error::custom(format!(
+                    "invalid regex for secret pattern `{}`: {e}",
+                    pattern.name
+                )));
+            }
+        }
+        self.patterns.extend(patterns);
+
+        Ok(())
+    }
+
+    /// Add a custom pattern.
+    pub fn add_pattern(&mut self, pattern: CustomSecretPattern) {
+        self.patterns.push(pattern);
+    }
+}
+
 /// Pre-compiled secret detector — compiles all 45+ regex patterns once.
 /// Use this for batch detection across many files (avoids ~76,500 regex recompilations).
 pub struct CompiledSecretDetector {
     compiled: Vec<(&'static SecretPattern, regex::Regex)>,
+    custom: Vec<(CustomSecretPattern, regex::Regex)>,
 }
 
 impl Default for CompiledSecretDetector {
@@ -119,7 +213,25 @@ impl CompiledSecretDetector {
             .iter()
             .filter_map(|p| regex::Regex::new(p.pattern).ok().map(|re| (p, re)))
             .collect();
-        Self { compiled }
+        Self { compiled, custom: Vec::new() }
+    }
+
+    /// Compile the built-in patterns plus the custom patterns in `registry`.
+    /// A custom pattern whose regex does not compile is skipped with a warning.
+    pub fn with_registry(registry: &SecretPatternRegistry) -> Self {
+        let mut detector = Self::new();
+        detector.custom = registry
+            .patterns
+            .iter()
+            .filter_map(|p| match regex::Regex::new(&p.regex) {
+                Ok(re) => Some((p.clone(), re)),
+                Err(e) => {
+                    tracing::warn!(pattern = %p.name, error = %e, "invalid custom secret pattern regex");
+                    None
+                }
+            })
+            .collect();
+        detector
     }
 
     /// Detect secrets using pre-compiled patterns (no regex recompilation).
@@ -170,16 +282,215 @@ impl CompiledSecretDetector {
             }
         }
 
+        for (pattern_def, re) in &self.custom {
+            for (line_num, line) in content.lines().enumerate() {
+                for mat in re.find_iter(line) {
+                    let matched = mat.as_str();
+                    let ent = shannon_entropy(matched);
+                    if ent < pattern_def.entropy_threshold {
+                        continue;
+                    }
+
+                    results.push(Secret {
+                        pattern_name: pattern_def.name.clone(),
+                        redacted_value: redact_value(matched),
+                        file: file_path.to_string(),
+                        line: (line_num + 1) as u32,
+                        severity: pattern_def.severity,
+                        entropy: ent,
+                        confidence: pattern_def.confidence.clamp(0.0, 1.0),
+                        cwe_ids: pattern_def.cwe_ids.clone(),
+                    });
+                }
+            }
+        }
+
         results
     }
 }
 
+impl CompiledSecretDetector {
+    /// Detect secrets in a config or text file (`.env`, YAML, properties,
+    /// Dockerfile, ...). The built-in patterns run first; lines they miss are
+    /// then parsed as `key = value` / `key: value` pairs, and a value under a
+    /// credential-like key is reported when it is not a placeholder and passes
+    /// the same entropy bar as `generic_secret`. Config values are usually
+    /// unquoted, which the source-code patterns require.
+    pub fn scan_text_file(&self, path: &str, content: &str) -> Vec<Secret> {
+        let mut results = self.detect(content, path);
+        if content.bytes().any(|b| b == 0) {
+            return results;
+        }
+
+        let dockerfile = is_dockerfile(&file_name(path));
+        for (line_num, line) in content.lines().enumerate() {
+            let line_no = (line_num + 1) as u32;
+            if results.iter().any(|s| s.line == line_no) {
+                continue;
+            }
+            let Some((key, value)) = key_value(line, dockerfile) else {
+                continue;
+            };
+            if !is_credential_key(key) || is_placeholder(value) {
+                continue;
+            }
+            let ent = shannon_entropy(value);
+            if ent < CONFIG_MIN_ENTROPY {
+                continue;
+            }
+
+            let mut confidence: f64 = 0.7;
+            if ent > 3.5 {
+                confidence += 0.15;
+            }
+            results.push(Secret {
+                pattern_name: "config_file_secret".to_string(),
+                redacted_value: redact_value(value),
+                file: path.to_string(),
+                line: line_no,
+                severity: SecretSeverity::High,
+                entropy: ent,
+                confidence,
+                cwe_ids: vec![798, 260],
+            });
+        }
+
+        results.sort_by_key(|s| s.line);
+        results
+    }
+}
+
+/// Extensions of config and text files scanned by [`scan_text_file`].
+const CONFIG_EXTENSIONS: &[&str] = &[
+    "env", "yaml", "yml", "json", "properties", "ini", "cfg", "conf", "toml", "tfvars", "xml",
+];
+
+/// Config file names without a telling extension.
+const CONFIG_FILE_NAMES: &[&str] = &[".env", ".npmrc", ".pypirc", ".netrc", ".pgpass", "dockerfile"];
+
+/// Key fragments that mark a credential.
+const CREDENTIAL_KEY_WORDS: &[&str] = &[
+    "password", "passwd", "secret", "token", "credential", "api_key", "apikey", "api-key",
+    "private_key", "privatekey", "access_key", "accesskey",
+];
+
+/// Values that stand in for a real credential.
+const PLACEHOLDER_WORDS: &[&str] = &[
+    "example", "placeholder", "changeme", "change_me", "change-me", "your_", "your-", "xxxx",
+    "****", "redacted", "todo", "dummy",
+];
+
+/// Entropy a config value must reach, matching `generic_secret`.
+const CONFIG_MIN_ENTROPY: f64 = 2.5;
+
+/// Shortest config value treated as a credential.
+const CONFIG_MIN_LENGTH: usize = 8;
+
+/// Whether `path` is a config or text file that [`scan_text_file`] handles:
+/// `.env` and `.env.*`, YAML (including `docker-compose.yml`), JSON,
+/// properties, INI, TOML, Terraform variables, Dockerfiles and credential
+/// dotfiles such as `.npmrc`.
+pub fn is_config_text_file(path: &str) -> bool {
+    let name = file_name(path);
+    if CONFIG_FILE_NAMES.contains(&name.as_str()) || name.starts_with(".env.") || is_dockerfile(&name) {
+        return true;
+    }
+    name.rsplit_once('.')
+        .is_some_and(|(stem, ext)| !stem.is_empty() && CONFIG_EXTENSIONS.contains(&ext))
+}
+
+/// Detect secrets in a config or text file.
+/// See [`CompiledSecretDetector::scan_text_file`]; the patterns are compiled once per process.
+pub fn scan_text_file(path: &str, content: &str) -> Vec<Secret> {
+    static DETECTOR: OnceLock<CompiledSecretDetector> = OnceLock::new();
+    DETECTOR.get_or_init(CompiledSecretDetector::new).scan_text_file(path, content)
+}
+
+/// Lowercased final path component.
+fn file_name(path: &str) -> String {
+    path.rsplit(['/', '\\']).next().unwrap_or(path).to_lowercase()
+}
+
+fn is_dockerfile(name: &str) -> bool {
+    name == "dockerfile" || name.starts_with("dockerfile.") || name.ends_with(".dockerfile")
+}
+
+/// Split a config line into key and value. Handles `KEY=value`,
+/// `export KEY=value`, YAML `key: value` and `- KEY=value`, JSON
+/// `"key": "value",` and Dockerfile `ENV`/`ARG`. Comments yield `None`.
+fn key_value(line: &str, dockerfile: bool) -> Option<(&str, &str)> {
+    let mut line = line.trim();
+    if line.is_empty() || line.starts_with(['#', ';', '!']) || line.starts_with("//") {
+        return None;
+    }
+    line = line.strip_prefix("- ").unwrap_or(line).trim_start();
+    line = line.strip_prefix("export ").unwrap_or(line).trim_start();
+    if dockerfile {
+        let rest = line.strip_prefix("ENV ").or_else(|| line.strip_prefix("ARG "))?.trim_start();
+        // Legacy `ENV KEY value` form.
+        if !rest.contains('=') {
+            return rest.split_once(char::is_whitespace).map(|(k, v)| (k, unquote(v.trim())));
+        }
+        line = rest;
+    }
+
+    let split = line.find(['=', ':'])?;
+    let key = line[..split].trim().trim_matches(['"', '\'']);
+    let value = line[split + 1..].trim();
+    if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')) {
+        return None;
+    }
+    let value = value.strip_suffix(',').unwrap_or(value).trim_end();
+    let value = if value.starts_with(['"', '\'']) {
+        unquote(value)
+    } else {
+        // Unquoted values end at an inline comment.
+        value.split(" #").next().unwrap_or(value).trim_end()
+    };
+    Some((key, value))
+}
+
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = value.strip_prefix(quote).and_then(|v| v.split(quote).next()) {
+            return inner;
+        }
+    }
+    value
+}
+
+fn is_credential_key(key: &str) -> bool {
+    let lower = key.to_lowercase();
+    CREDENTIAL_KEY_WORDS.iter().any(|w| lower.contains(w))
+        || lower.split(['_', '-', '.']).any(|segment| segment == "pwd")
+}
+
+/// Empty, short, boolean, templated (`${VAR}`, `{{ var }}`, `<token>`) or
+/// obviously fake values, and file paths to a mounted secret.
+fn is_placeholder(value: &str) -> bool {
+    let lower = value.to_lowercase();
+    value.chars().count() < CONFIG_MIN_LENGTH
+        || value.starts_with(['$', '<', '/', '~', '%'])
+        || value.starts_with("{{")
+        || value.starts_with("./")
+        || matches!(lower.as_str(), "true" | "false" | "null" | "none")
+        || PLACEHOLDER_WORDS.iter().any(|w| lower.contains(w))
+        || value.chars().all(|c| value.starts_with(c))
+}
+
 /// Detect secrets in source code content.
 /// NOTE: Compiles regexes per call — use CompiledSecretDetector for batch.
 pub fn detect_secrets(content: &str, file_path: &str) -> Vec<Secret> {
     CompiledSecretDetector::new().detect(content, file_path)
 }
 
+/// Detect secrets using the built-in patterns plus the custom patterns in
+/// `registry`.
+/// NOTE: Compiles regexes per call — use CompiledSecretDetector for batch.
+pub fn detect_secrets_with(content: &str, file_path: &str, registry: &SecretPatternRegistry) -> Vec<Secret> {
+    CompiledSecretDetector::with_registry(registry).detect(content, file_path)
+}
+
 /// Redact a secret value, keeping only the first 4 and last 4 characters.
 fn redact_value(value: &str) -> String {
     if value.len() <= 12 {
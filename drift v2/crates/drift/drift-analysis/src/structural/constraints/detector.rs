@@ -1,6 +1,7 @@
 //! AST-based invariant detection (not regex).
 
 use drift_core::types::collections::FxHashMap;
+use drift_core::workspace::monorepo::PackageInfo;
 
 use super::types::{Constraint, ConstraintViolation, InvariantType, VerificationResult};
 
@@ -15,6 +16,8 @@ pub struct InvariantDetector {
     imports: FxHashMap<String, Vec<String>>,
     /// File sizes (line counts).
     file_sizes: FxHashMap<String, u32>,
+    /// Monorepo packages as (name, root-relative path), deepest path first.
+    packages: Vec<(String, String)>,
 }
 
 /// Minimal function info for constraint checking.
@@ -31,6 +34,7 @@ impl InvariantDetector {
             functions: FxHashMap::default(),
             imports: FxHashMap::default(),
             file_sizes: FxHashMap::default(),
+            packages: Vec::new(),
         }
     }
 
@@ -47,6 +51,18 @@ impl InvariantDetector {
         self.file_sizes.insert(file.to_string(), line_count);
     }
 
+    /// Register monorepo packages (from `workspace::monorepo::detect_workspace`)
+    /// so `pkg:` targets can map files and imports to their owning package.
+    /// File paths passed to `add_file` must be relative to the same root.
+    pub fn set_packages(&mut self, packages: &[PackageInfo]) {
+        self.packages = packages
+            .iter()
+            .map(|p| (p.name.clone(), normalize_path(&p.path.to_string_lossy())))
+            .collect();
+        // Nested packages win over the package containing them
+        self.packages.sort_by_key(|p| std::cmp::Reverse(p.1.len()));
+    }
+
     /// Verify a constraint against the registered codebase data.
     pub fn verify(&self, constraint: &Constraint) -> VerificationResult {
         if !constraint.enabled {
@@ -197,6 +213,9 @@ impl InvariantDetector {
     }
 
     fn check_dependency_direction(&self, constraint: &Constraint) -> Vec<ConstraintViolation> {
+        if let Some((from, to)) = forbidden_package_edge(&constraint.target) {
+            return self.check_package_edge(from, to);
+        }
         // Target format: "moduleA->moduleB" — A may depend on B, but B must not depend on A
         let parts: Vec<&str> = constraint.target.split("->").collect();
         if parts.len() != 2 {
@@ -228,6 +247,9 @@ impl InvariantDetector {
     }
 
     fn check_layer_boundary(&self, constraint: &Constraint) -> Vec<ConstraintViolation> {
+        if let Some((from, to)) = forbidden_package_edge(&constraint.target) {
+            return self.check_package_edge(from, to);
+        }
         // Target format: "ui!->db" — ui layer must not import from db layer
         let parts: Vec<&str> = constraint.target.split("!->").collect();
         if parts.len() != 2 {
@@ -257,6 +279,67 @@ impl InvariantDetector {
         violations
     }
 
+    /// Imports from a file in package `from` into package `to`.
+    fn check_package_edge(&self, from: &str, to: &str) -> Vec<ConstraintViolation> {
+        let mut violations = Vec::new();
+
+        for (file, imports) in &self.imports {
+            if self.package_of_path(&normalize_path(file)) != Some(from) {
+                continue;
+            }
+            for import in imports {
+                if self.package_of_import(file, import) == Some(to) {
+                    violations.push(ConstraintViolation {
+                        file: file.clone(),
+                        line: None,
+                        message: format!(
+                            "Package violation: '{}' (package {}) imports '{}' from forbidden package {}",
+                            file, from, import, to
+                        ),
+                        expected: format!("No imports from package {} to package {}", from, to),
+                        actual: format!("{} imports {}", file, import),
+                    });
+                }
+            }
+        }
+        violations
+    }
+
+    /// Package whose directory contains `path` (root-relative, `/`-separated).
+    fn package_of_path(&self, path: &str) -> Option<&str> {
+        self.packages
+            .iter()
+            .find(|(_, root)| {
+                path.strip_prefix(root.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            })
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Package an import in `file` points into. Relative specifiers are
+    /// resolved against the importing file, root-relative paths are matched
+    /// against package directories, and bare specifiers (`@acme/billing`,
+    /// `billing/utils`) against package names.
+    fn package_of_import(&self, file: &str, import: &str) -> Option<&str> {
+        if import.starts_with('.') {
+            let file = normalize_path(file);
+            let dir = file.rsplit_once('/').map_or("", |(dir, _)| dir);
+            return self.package_of_path(&resolve_relative(dir, import));
+        }
+        if let Some(package) = self.package_of_path(&normalize_path(import)) {
+            return Some(package);
+        }
+        let unscoped = match import.strip_prefix('@') {
+            Some(scoped) => scoped.split_once('/').map_or(scoped, |(_, rest)| rest),
+            None => import,
+        };
+        let name = unscoped.split('/').next().unwrap_or(unscoped);
+        self.packages
+            .iter()
+            .find(|(package, _)| package == name)
+            .map(|(package, _)| package.as_str())
+    }
+
     fn check_size_limit(&self, constraint: &Constraint) -> Vec<ConstraintViolation> {
         let limit: u32 = constraint.target.parse().unwrap_or(500);
         let mut violations = Vec::new();
@@ -382,6 +465,41 @@ impl Default for InvariantDetector {
     }
 }
 
+/// The forbidden `(from, to)` package edge of a `pkg:` target, or `None` for
+/// path-based targets. `pkg:a!->pkg:b` forbids a → b; `pkg:a->pkg:b` only
+/// allows a → b, so it forbids b → a.
+fn forbidden_package_edge(target: &str) -> Option<(&str, &str)> {
+    fn package(side: &str) -> Option<&str> {
+        side.trim().strip_prefix("pkg:").map(str::trim)
+    }
+    if let Some((from, to)) = target.split_once("!->") {
+        return Some((package(from)?, package(to)?));
+    }
+    let (allowed_src, allowed_dst) = target.split_once("->")?;
+    Some((package(allowed_dst)?, package(allowed_src)?))
+}
+
+/// `/`-separated path without a leading `./` or trailing `/`.
+fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.trim_start_matches("./").trim_end_matches('/').to_string()
+}
+
+/// Resolve a `./` or `../` specifier against the importing file's directory.
+fn resolve_relative(dir: &str, import: &str) -> String {
+    let mut segments: Vec<&str> = dir.split('/').filter(|s| !s.is_empty()).collect();
+    for segment in import.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            _ => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
+
 /// Check if a name matches a naming convention.
 fn matches_convention(name: &str, convention: &str) -> bool {
     if name.is_empty() {
@@ -0,0 +1,360 @@
+//! Client-only access control (CWE-602).
+//!
+//! `{user.isAdmin && <button onClick={() => deleteUser(id)}>}` hides the
+//! button from non-admins, but anyone can still send the request. Calls made
+//! inside a client-side role branch, directly or through a same-file helper,
+//! are linked to the backend route they hit via contract matching. A finding
+//! is emitted when that route's registration and handler have no auth
+//! middleware, guard, decorator or role check. Role checks and auth markers
+//! are recognised by name only, so findings are advisory.
+
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+
+use super::extractors::frontend::FrontendExtractor;
+use super::extractors::{EndpointExtractor, ExtractorRegistry};
+use super::matching::match_contracts;
+use super::types::Endpoint;
+use crate::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
+
+/// Client-side role checks recognised by default (lowercase fragments).
+pub const DEFAULT_ROLE_MARKERS: &[&str] = &[
+    "isadmin", "is_admin", "isstaff", "is_staff", "issuperuser", "is_superuser", "ismoderator",
+    "hasrole", "has_role", "hasanyrole", "haspermission", "has_permission", "role ===",
+    "role ==", "role !==", "role !=", "roles.includes", "permissions.includes",
+];
+
+/// Server-side auth markers recognised by default (lowercase fragments):
+/// auth middleware, guards, decorators and attributes across frameworks.
+pub const DEFAULT_AUTH_MARKERS: &[&str] = &[
+    "auth", "login_required", "permission", "guard", "passport", "jwt", "verifytoken",
+    "requireadmin", "ensureadmin", "adminonly", "admin_required", "staff_member_required",
+    "requirerole", "checkrole", "preauthorize", "secured", "rolesallowed", "current_user",
+    "forbidden", "403",
+];
+
+/// A role check in client code guarding a branch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientRoleCheck {
+    pub file: String,
+    pub line: u32,
+    /// The source line holding the check, trimmed.
+    pub condition: String,
+}
+
+/// A request issued only behind a client role check, linked to a backend
+/// handler that performs no auth check of its own.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientOnlyAuthFinding {
+    pub client_check: ClientRoleCheck,
+    /// The request made inside the guarded branch.
+    pub client_call: Endpoint,
+    /// The backend route the request was linked to.
+    pub server_handler: Endpoint,
+    /// Contract-match confidence of the client→server link.
+    pub link_confidence: f64,
+}
+
+impl ClientOnlyAuthFinding {
+    /// Advisory pattern match, located at the server handler where the
+    /// missing check belongs.
+    pub fn to_pattern_match(&self) -> PatternMatch {
+        PatternMatch {
+            file: self.server_handler.file.clone(),
+            line: self.server_handler.line,
+            column: 0,
+            pattern_id: "AUTH-CLIENT-005".to_string(),
+            confidence: (0.40 + 0.25 * self.link_confidence) as f32,
+            cwe_ids: SmallVec::from_buf([602, 0]),
+            owasp: Some("A01:2021".to_string()),
+            detection_method: DetectionMethod::Semantic,
+            category: PatternCategory::Auth,
+            matched_text: format!(
+                "{} {} is guarded only client-side by `{}` ({}:{}); the server handler has no auth check",
+                self.server_handler.method,
+                self.server_handler.path,
+                self.client_check.condition,
+                self.client_check.file,
+                self.client_check.line,
+            ),
+        }
+    }
+}
+
+/// Links client role-check branches to backend handlers lacking auth.
+#[derive(Debug, Clone)]
+pub struct ClientOnlyAuthCheck {
+    role_markers: Vec<String>,
+    auth_markers: Vec<String>,
+}
+
+impl ClientOnlyAuthCheck {
+    /// Create a check over custom role-check and server auth marker sets.
+    pub fn new(role_markers: &[&str], auth_markers: &[&str]) -> Self {
+        Self {
+            role_markers: role_markers.iter().map(|s| s.to_lowercase()).collect(),
+            auth_markers: auth_markers.iter().map(|s| s.to_lowercase()).collect(),
+        }
+    }
+
+    /// Add project-specific client role checks (e.g. `canManage`).
+    pub fn with_role_markers(mut self, markers: &[&str]) -> Self {
+        self.role_markers.extend(markers.iter().map(|s| s.to_lowercase()));
+        self
+    }
+
+    /// Add project-specific server auth middleware (e.g. `withSession`).
+    pub fn with_auth_markers(mut self, markers: &[&str]) -> Self {
+        self.auth_markers.extend(markers.iter().map(|s| s.to_lowercase()));
+        self
+    }
+
+    /// Analyze a set of `(path, content)` files spanning client and server.
+    pub fn detect(&self, files: &[(&str, &str)]) -> Vec<ClientOnlyAuthFinding> {
+        let registry = ExtractorRegistry::new();
+        let backend: Vec<Endpoint> = files
+            .iter()
+            .flat_map(|(path, content)| registry.extract_all(content, path))
+            .filter(|(framework, _)| framework != "frontend")
+            .flat_map(|(_, endpoints)| endpoints)
+            .collect();
+        if backend.is_empty() {
+            return Vec::new();
+        }
+
+        let mut findings = Vec::new();
+        for (path, content) in files {
+            for (check, call) in self.guarded_calls(path, content) {
+                let Some(link) = best_link(&backend, &call) else {
+                    continue;
+                };
+                let Some(server_source) = files.iter().find(|(p, _)| *p == link.0.file).map(|(_, c)| *c) else {
+                    continue;
+                };
+                if self.handler_has_auth(server_source, link.0.line) {
+                    continue;
+                }
+                findings.push(ClientOnlyAuthFinding {
+                    client_check: check,
+                    client_call: call,
+                    server_handler: link.0,
+                    link_confidence: link.1,
+                });
+            }
+        }
+        findings
+    }
+
+    /// Requests made inside role-check branches of a client file, paired
+    /// with the check that guards them.
+    fn guarded_calls(&self, path: &str, content: &str) -> Vec<(ClientRoleCheck, Endpoint)> {
+        let extractor = FrontendExtractor;
+        if !extractor.matches(content) {
+            return Vec::new();
+        }
+        let calls = extractor.extract(content, path);
+        if calls.is_empty() {
+            return Vec::new();
+        }
+        let lines: Vec<&str> = content.lines().collect();
+
+        let mut guarded = Vec::new();
+        for (idx, line) in lines.iter().enumerate() {
+            if !self.is_role_branch(line) {
+                continue;
+            }
+            let mut ranges = vec![(idx, block_end(&lines, idx))];
+            // One hop: same-file helpers called from the branch.
+            let branch = lines[idx..=ranges[0].1].join("\n");
+            for name in called_names(&branch) {
+                if let Some(def) = find_definition(&lines, name) {
+                    ranges.push((def, block_end(&lines, def)));
+                }
+            }
+            for call in &calls {
+                let call_idx = call.line.saturating_sub(1) as usize;
+                let already = guarded.iter().any(|(_, c): &(ClientRoleCheck, Endpoint)| c.line == call.line);
+                if !already && ranges.iter().any(|(start, end)| (*start..=*end).contains(&call_idx)) {
+                    let check = ClientRoleCheck {
+                        file: path.to_string(),
+                        line: (idx + 1) as u32,
+                        condition: line.trim().to_string(),
+                    };
+                    guarded.push((check, call.clone()));
+                }
+            }
+        }
+        guarded
+    }
+
+    /// Whether a line branches on a role check: `if (...)`, `cond && ...`,
+    /// `cond ? ... : ...`, or a template directive.
+    fn is_role_branch(&self, line: &str) -> bool {
+        let lower = line.to_lowercase();
+        let Some(pos) = self.role_markers.iter().filter_map(|m| lower.find(m.as_str())).min() else {
+            return false;
+        };
+        let trimmed = lower.trim_start().trim_start_matches(['}', ' ', '{']);
+        let conditional_statement = trimmed.starts_with("if") || trimmed.starts_with("else if");
+        let template_directive = ["v-if", "v-show", "*ngif", "{#if"].iter().any(|d| lower.contains(d));
+        let after = &lower[pos..];
+        conditional_statement || template_directive || after.contains("&&") || after.contains(" ? ")
+    }
+
+    /// Whether the route registered at `line` (1-indexed) has an auth or role check.
+    fn handler_has_auth(&self, content: &str, line: u32) -> bool {
+        route_has_auth(content, line, self.auth_markers.iter().chain(&self.role_markers))
+    }
+}
+
+impl Default for ClientOnlyAuthCheck {
+    fn default() -> Self {
+        Self::new(DEFAULT_ROLE_MARKERS, DEFAULT_AUTH_MARKERS)
+    }
+}
+
+/// Whether the route registered at `line` (1-indexed) is protected: its
+/// registration, decorators, decorated or named handler, or a preceding
+/// `.use(...)` contains one of the lowercase `markers`. String literals are
+/// ignored so route paths like `/api/authors` do not count.
+pub(crate) fn route_has_auth<'a>(content: &str, line: u32, mut markers: impl Iterator<Item = &'a String>) -> bool {
+    let lines: Vec<&str> = content.lines().collect();
+    if lines.is_empty() {
+        return false;
+    }
+    let idx = (line.saturating_sub(1) as usize).min(lines.len() - 1);
+    let end = block_end(&lines, idx);
+    let is_decorator = |l: &str| {
+        let t = l.trim_start();
+        t.starts_with('@') || t.starts_with('[')
+    };
+    let decorators = lines[..idx].iter().rev().take_while(|l| is_decorator(l)).count();
+    let mut scope = lines[idx - decorators..=end].join("\n");
+    // A route decorator also covers the decorators and definition below it.
+    if is_decorator(lines[idx]) {
+        let below = lines[end + 1..].iter().take_while(|l| is_decorator(l)).count();
+        let def = end + 1 + below;
+        if def < lines.len() {
+            scope.push('\n');
+            scope.push_str(&lines[end + 1..=block_end(&lines, def)].join("\n"));
+        }
+    }
+    let registration = lines[idx..=end].join("\n");
+    for name in handler_args(&registration) {
+        if let Some(def) = find_definition(&lines, name) {
+            scope.push('\n');
+            scope.push_str(&lines[def..=block_end(&lines, def)].join("\n"));
+        }
+    }
+    for use_line in lines[..idx].iter().filter(|l| l.contains(".use(")) {
+        scope.push('\n');
+        scope.push_str(use_line);
+    }
+    let code = strip_string_literals(&scope).to_lowercase();
+    markers.any(|m| code.contains(m.as_str()))
+}
+
+/// The best backend match for a client call: highest confidence, then an
+/// exact method match.
+fn best_link(backend: &[Endpoint], call: &Endpoint) -> Option<(Endpoint, f64)> {
+    match_contracts(backend, std::slice::from_ref(call))
+        .into_iter()
+        .max_by(|a, b| {
+            a.confidence
+                .partial_cmp(&b.confidence)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| (a.backend.method == call.method).cmp(&(b.backend.method == call.method)))
+        })
+        .map(|m| (m.backend, m.confidence))
+}
+
+/// Last line (0-indexed) of the statement or block starting at `start`:
+/// brackets are balanced for brace languages; a line ending in `:` opens an
+/// indentation block.
+fn block_end(lines: &[&str], start: usize) -> usize {
+    let first = lines[start];
+    if first.trim_end().ends_with(':') {
+        let indent = indentation(first);
+        return lines[start + 1..]
+            .iter()
+            .position(|l| !l.trim().is_empty() && indentation(l) <= indent)
+            .map_or(lines.len() - 1, |offset| start + offset);
+    }
+    let mut depth = 0i32;
+    for (idx, line) in lines.iter().enumerate().skip(start) {
+        // `} else if (...) {` closes the previous block before opening its own.
+        let line = if idx == start { line.trim_start().trim_start_matches('}') } else { line };
+        for c in strip_string_literals(line).chars() {
+            match c {
+                '(' | '{' | '[' => depth += 1,
+                ')' | '}' | ']' => depth -= 1,
+                _ => {}
+            }
+        }
+        if depth <= 0 {
+            return idx;
+        }
+    }
+    lines.len() - 1
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Names called in `text` (`name(`), excluding keywords.
+fn called_names(text: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    for (pos, _) in text.match_indices('(') {
+        let name_start = text[..pos]
+            .rfind(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+            .map_or(0, |i| i + 1);
+        let name = &text[name_start..pos];
+        if !name.is_empty() && !matches!(name, "if" | "for" | "while" | "switch" | "return") && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// Bare identifiers passed to a route registration after the path, e.g.
+/// `deleteUser` in `router.delete('/users/:id', deleteUser)`.
+fn handler_args(registration: &str) -> Vec<&str> {
+    registration
+        .split([',', '(', ')'])
+        .map(str::trim)
+        .filter(|a| !a.is_empty() && a.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '$'))
+        .collect()
+}
+
+/// Line (0-indexed) defining `name` as a function in this file.
+fn find_definition(lines: &[&str], name: &str) -> Option<usize> {
+    let patterns = [
+        format!("function {name}("),
+        format!("def {name}("),
+        format!("const {name} = "),
+        format!("let {name} = "),
+        format!("{name}("),
+    ];
+    lines.iter().position(|line| {
+        let trimmed = line.trim_start().trim_start_matches("export ").trim_start_matches("async ");
+        patterns[..4].iter().any(|p| trimmed.starts_with(p.as_str()))
+            // Class/object methods: `deleteUser(id) {`.
+            || (trimmed.starts_with(patterns[4].as_str()) && trimmed.trim_end().ends_with('{'))
+    })
+}
+
+/// `text` with the contents of quoted string literals removed.
+fn strip_string_literals(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut quote: Option<char> = None;
+    for c in text.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if matches!(c, '\'' | '"' | '`') => quote = Some(c),
+            None => out.push(c),
+        }
+    }
+    out
+}
@@ -6,5 +6,6 @@ pub mod extractors;
 pub mod matching;
 pub mod breaking_changes;
 pub mod confidence;
+pub mod client_auth;
 
 pub use types::*;
@@ -0,0 +1,142 @@
+//! File-level circular imports.
+//!
+//! `detect_cycles` works on modules (directories), so `a.ts ↔ b.ts` in one
+//! directory never shows up there, yet it is exactly the cycle that leaves
+//! an import `undefined` at runtime. Here every relative import (`./b`,
+//! `../lib/c.js`) is resolved to one of the parsed files — as written, with
+//! a JS/TS extension, as a directory `index`, or with `.js` mapped to its
+//! TypeScript source — and Tarjan's SCC runs on the resulting file graph.
+//! Package imports and `import type` (erased before runtime) add no edges.
+
+use drift_core::types::collections::{FxHashMap, FxHashSet};
+use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::EdgeRef;
+
+use crate::parsers::types::ParseResult;
+
+use super::types::{FileCycle, FileImport};
+
+/// Extensions tried for an extensionless specifier, in resolution order.
+const EXTENSIONS: &[&str] = &["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+/// Compiled extension → TypeScript sources it is emitted from.
+const TS_SOURCES: &[(&str, &[&str])] = &[
+    ("js", &["ts", "tsx"]),
+    ("jsx", &["tsx"]),
+    ("mjs", &["mts"]),
+    ("cjs", &["cts"]),
+];
+
+type FileGraph<'a> = DiGraph<&'a str, FileImport>;
+
+/// Detect circular imports between the files of `parse_results`, sorted by
+/// their file lists. A file importing itself is a cycle of one.
+pub fn detect_file_cycles(parse_results: &[ParseResult]) -> Vec<FileCycle> {
+    let mut graph: FileGraph = DiGraph::new();
+    let mut nodes: FxHashMap<String, NodeIndex> = FxHashMap::default();
+    for pr in parse_results {
+        nodes
+            .entry(pr.file.replace('\\', "/"))
+            .or_insert_with(|| graph.add_node(&pr.file));
+    }
+
+    for pr in parse_results {
+        let importer = pr.file.replace('\\', "/");
+        let from = nodes[&importer];
+        for import in pr.imports.iter().filter(|i| !i.is_type_only) {
+            let Some(to) = resolve(&importer, &import.source, &nodes) else {
+                continue;
+            };
+            // The first import of a file is the one that loads it
+            if graph.find_edge(from, to).is_none() {
+                let edge = FileImport {
+                    from: pr.file.clone(),
+                    to: graph[to].to_string(),
+                    line: import.line,
+                    source: import.source.clone(),
+                };
+                graph.add_edge(from, to, edge);
+            }
+        }
+    }
+
+    let mut cycles: Vec<FileCycle> = petgraph::algo::tarjan_scc(&graph)
+        .into_iter()
+        .filter(|scc| scc.len() > 1 || graph.find_edge(scc[0], scc[0]).is_some())
+        .map(|scc| walk_cycle(&graph, &scc))
+        .collect();
+    cycles.sort_by(|a, b| a.files.cmp(&b.files));
+    cycles
+}
+
+/// Depth-first walk of one SCC from its first file, in file-name order.
+/// Imports reaching a file still on the walk's path are the back edges.
+fn walk_cycle(graph: &FileGraph, scc: &[NodeIndex]) -> FileCycle {
+    let members: FxHashSet<NodeIndex> = scc.iter().copied().collect();
+    let successors = |node: NodeIndex| {
+        let mut edges: Vec<_> = graph.edges(node).filter(|e| members.contains(&e.target())).collect();
+        edges.sort_by_key(|e| graph[e.target()]);
+        edges
+    };
+
+    let start = scc.iter().copied().min_by_key(|n| graph[*n]).unwrap_or(scc[0]);
+    let mut files = vec![graph[start].to_string()];
+    let mut back_edges = Vec::new();
+    let mut visited: FxHashSet<NodeIndex> = FxHashSet::from_iter([start]);
+    let mut on_path = visited.clone();
+    let mut stack = vec![(start, successors(start), 0)];
+    while let Some((node, edges, next)) = stack.last_mut() {
+        let Some(edge) = edges.get(*next).copied() else {
+            on_path.remove(node);
+            stack.pop();
+            continue;
+        };
+        *next += 1;
+        let target = edge.target();
+        if on_path.contains(&target) {
+            back_edges.push(edge.weight().clone());
+        } else if visited.insert(target) {
+            on_path.insert(target);
+            files.push(graph[target].to_string());
+            stack.push((target, successors(target), 0));
+        }
+    }
+
+    FileCycle { files, back_edges }
+}
+
+/// The parsed file a relative specifier loads, if any.
+fn resolve(importer: &str, specifier: &str, nodes: &FxHashMap<String, NodeIndex>) -> Option<NodeIndex> {
+    if !specifier.starts_with("./") && !specifier.starts_with("../") {
+        return None;
+    }
+    let dir = importer.rsplit_once('/').map_or("", |(dir, _)| dir);
+    let base = join(dir, specifier);
+
+    let mut candidates = vec![base.clone()];
+    candidates.extend(EXTENSIONS.iter().map(|ext| format!("{base}.{ext}")));
+    candidates.extend(EXTENSIONS.iter().map(|ext| format!("{base}/index.{ext}")));
+    if let Some((stem, ext)) = base.rsplit_once('.') {
+        if let Some((_, sources)) = TS_SOURCES.iter().find(|(js, _)| *js == ext) {
+            candidates.extend(sources.iter().map(|ts| format!("{stem}.{ts}")));
+        }
+    }
+    candidates.iter().find_map(|path| nodes.get(path).copied())
+}
+
+/// `dir` joined with a `./`/`../` specifier, keeping a leading `/`.
+fn join(dir: &str, specifier: &str) -> String {
+    let mut segments: Vec<&str> = if dir.is_empty() { Vec::new() } else { dir.split('/').collect() };
+    for segment in specifier.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                if segments.last().is_some_and(|s| !s.is_empty()) {
+                    segments.pop();
+                }
+            }
+            _ => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
@@ -2,16 +2,19 @@
 //!
 //! Computes Ce (efferent), Ca (afferent), I (instability), A (abstractness),
 //! D (distance from main sequence) per module. Detects dependency cycles via
-//! Tarjan's SCC and suggests cycle-breaking edges.
+//! Tarjan's SCC and suggests cycle-breaking edges, and circular imports
+//! between individual files.
 
 pub mod types;
 pub mod import_graph;
 pub mod martin_metrics;
 pub mod cycle_detection;
+pub mod file_cycles;
 pub mod zones;
 
 pub use types::*;
 pub use import_graph::ImportGraphBuilder;
 pub use martin_metrics::compute_martin_metrics;
 pub use cycle_detection::detect_cycles;
+pub use file_cycles::detect_file_cycles;
 pub use zones::classify_zone;
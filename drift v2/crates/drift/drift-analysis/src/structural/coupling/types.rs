@@ -58,6 +58,30 @@ pub struct CycleInfo {
     pub break_suggestions: Vec<CycleBreakSuggestion>,
 }
 
+/// A circular import between individual files.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileCycle {
+    /// Files in the cycle, in depth-first import order from the
+    /// lexicographically first one.
+    pub files: Vec<String>,
+    /// Imports that close the cycle: each leads back to a file that is still
+    /// being loaded when it runs.
+    pub back_edges: Vec<FileImport>,
+}
+
+/// One import statement, resolved to the file it loads.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileImport {
+    /// Importing file.
+    pub from: String,
+    /// Imported file.
+    pub to: String,
+    /// Line of the import statement (`ImportInfo::line`).
+    pub line: u32,
+    /// Specifier as written (`./b`).
+    pub source: String,
+}
+
 /// A suggestion for breaking a dependency cycle.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CycleBreakSuggestion {
@@ -2,13 +2,16 @@
 
 use regex::Regex;
 use super::types::CryptoFinding;
+use crate::enforcement::rules::FixRange;
 use super::patterns::{CRYPTO_PATTERNS, CRYPTO_IMPORT_INDICATORS};
-use super::remediation::get_remediation;
+use super::remediation::{get_remediation, has_algorithm_fix, AlgorithmFix, ALGORITHM_FIXES};
 
 /// Cryptographic failure detector.
 pub struct CryptoDetector {
     /// Compiled regex patterns (lazily compiled on first use).
     compiled: Vec<(Regex, &'static super::patterns::CryptoPattern)>,
+    /// Compiled identifier swaps for weak hashes and ciphers.
+    fixes: Vec<(Regex, &'static AlgorithmFix)>,
 }
 
 impl CryptoDetector {
@@ -19,8 +22,13 @@ impl CryptoDetector {
                 Regex::new(p.pattern).ok().map(|re| (re, p))
             })
             .collect();
+        let fixes = ALGORITHM_FIXES.iter()
+            .filter_map(|f| {
+                Regex::new(f.pattern).ok().map(|re| (re, f))
+            })
+            .collect();
 
-        Self { compiled }
+        Self { compiled, fixes }
     }
 
     /// Detect cryptographic failures in a file.
@@ -54,10 +62,28 @@ impl CryptoDetector {
                     }
 
                     let remediation = get_remediation(pattern.category);
+                    let fix = if has_algorithm_fix(pattern.category) {
+                        self.algorithm_fix(line, language)
+                    } else {
+                        None
+                    };
+                    let line_number = (line_idx + 1) as u32;
+                    let (range, suggested_replacement) = match fix {
+                        Some((alg, fix)) => {
+                            let range = fix.machine_applicable.then_some(FixRange {
+                                start_line: line_number,
+                                start_column: alg.start as u32,
+                                end_line: line_number,
+                                end_column: alg.end as u32,
+                            });
+                            (range, Some(fix.replacement.to_string()))
+                        }
+                        None => (None, None),
+                    };
 
                     findings.push(CryptoFinding {
                         file: file_path.to_string(),
-                        line: (line_idx + 1) as u32,
+                        line: line_number,
                         category: pattern.category,
                         description: pattern.description.to_string(),
                         code: trimmed.to_string(),
@@ -66,6 +92,8 @@ impl CryptoDetector {
                         owasp: "A02:2025".to_string(),
                         remediation,
                         language: language.to_string(),
+                        suggested_replacement,
+                        range,
                     });
                 }
             }
@@ -83,6 +111,17 @@ impl CryptoDetector {
 
         findings
     }
+
+    /// The weak algorithm identifier in `line` (byte range within the line)
+    /// and the fix for it in `language`.
+    fn algorithm_fix(&self, line: &str, language: &str) -> Option<(std::ops::Range<usize>, &'static AlgorithmFix)> {
+        self.fixes.iter()
+            .filter(|(_, fix)| fix.languages.contains(&language))
+            .find_map(|(regex, fix)| {
+                let alg = regex.captures(line)?.name("alg")?;
+                Some((alg.range(), *fix))
+            })
+    }
 }
 
 impl Default for CryptoDetector {
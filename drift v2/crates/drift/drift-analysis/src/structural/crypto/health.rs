@@ -48,7 +48,7 @@ pub fn calculate_crypto_health(findings: &[CryptoFinding]) -> CryptoHealthScore
     let overall = (100.0 - penalty).clamp(0.0, 100.0);
 
     let mut by_category_vec: Vec<(CryptoCategory, u32)> = by_category.into_iter().collect();
-    by_category_vec.sort_by(|a, b| b.1.cmp(&a.1));
+    by_category_vec.sort_by_key(|b| std::cmp::Reverse(b.1));
 
     CryptoHealthScore {
         overall,
@@ -1,4 +1,15 @@
 //! Remediation suggestion engine for cryptographic failures.
+//!
+//! Besides prose guidance per category, weak hashes and deprecated ciphers
+//! get a swap of the algorithm identifier, following each language's API:
+//! Java's `MessageDigest.getInstance("MD5")` becomes `"SHA-256"`, Node's
+//! `createHash('md5')` becomes `'sha256'`, Go's `md5.Sum` becomes
+//! `sha256.Sum256`. Imports are left to the caller.
+//!
+//! Only swaps that keep the call working as written are machine-applicable.
+//! Cipher swaps change key, IV and mode handling, and Go's `sha256.Sum256`,
+//! Rust's `Sha256::new` and `CC_SHA256` produce a larger digest than the
+//! MD5/SHA1 code around them expects, so those are advisory.
 
 use super::types::CryptoCategory;
 
@@ -62,3 +73,43 @@ pub fn get_remediation(category: CryptoCategory) -> String {
         }
     }
 }
+
+/// A language-specific swap for a weak algorithm identifier.
+#[derive(Debug, Clone)]
+pub struct AlgorithmFix {
+    /// Regex whose `alg` group is the identifier to replace.
+    pub pattern: &'static str,
+    /// Text that replaces the `alg` group.
+    pub replacement: &'static str,
+    /// Languages this fix applies to.
+    pub languages: &'static [&'static str],
+    /// Whether the swap alone leaves working code; otherwise it is advisory.
+    pub machine_applicable: bool,
+}
+
+/// Replacements for weak hashes (→ SHA-256) and deprecated ciphers (→ AES-256-GCM).
+pub static ALGORITHM_FIXES: &[AlgorithmFix] = &[
+    // ── WeakHash ──
+    AlgorithmFix { pattern: r#"createHash\s*\(\s*["'](?P<alg>(?i:md5|sha-?1))["']"#, replacement: "sha256", languages: &["javascript", "typescript"], machine_applicable: true },
+    AlgorithmFix { pattern: r#"MessageDigest\.getInstance\s*\(\s*"(?P<alg>(?i:md5|sha-?1))""#, replacement: "SHA-256", languages: &["java", "kotlin"], machine_applicable: true },
+    AlgorithmFix { pattern: r"hashlib\.(?P<alg>md5|sha1)\b", replacement: "sha256", languages: &["python"], machine_applicable: true },
+    AlgorithmFix { pattern: r#"hashlib\.new\s*\(\s*["'](?P<alg>(?i:md5|sha1))["']"#, replacement: "sha256", languages: &["python"], machine_applicable: true },
+    AlgorithmFix { pattern: r"\b(?P<alg>MD5|SHA1)\.Create\(", replacement: "SHA256", languages: &["csharp"], machine_applicable: true },
+    AlgorithmFix { pattern: r#"\bhash\s*\(\s*["'](?P<alg>md5|sha1)["']"#, replacement: "sha256", languages: &["php"], machine_applicable: true },
+    AlgorithmFix { pattern: r"\b(?P<alg>(?:md5|sha1)\s*\()", replacement: "hash('sha256', ", languages: &["php"], machine_applicable: true },
+    AlgorithmFix { pattern: r"Digest::(?P<alg>MD5|SHA1)\b", replacement: "SHA256", languages: &["ruby"], machine_applicable: true },
+    AlgorithmFix { pattern: r"\b(?P<alg>(?:md5|sha1)\.Sum)\(", replacement: "sha256.Sum256", languages: &["go"], machine_applicable: false },
+    AlgorithmFix { pattern: r"\b(?P<alg>Md5|Sha1)::new\(", replacement: "Sha256", languages: &["rust"], machine_applicable: false },
+    AlgorithmFix { pattern: r"\b(?P<alg>CC_MD5|CC_SHA1)\(", replacement: "CC_SHA256", languages: &["swift", "c", "cpp"], machine_applicable: false },
+
+    // ── DeprecatedCipher ──
+    AlgorithmFix { pattern: r#"Cipher\.getInstance\s*\(\s*"(?P<alg>(?:DES|DESede|RC4|ARCFOUR|Blowfish)[^"]*)""#, replacement: "AES/GCM/NoPadding", languages: &["java", "kotlin"], machine_applicable: false },
+    AlgorithmFix { pattern: r"\b(?P<alg>DES3|DES|ARC4|Blowfish)\.new\(", replacement: "AES", languages: &["python"], machine_applicable: false },
+    AlgorithmFix { pattern: r"\b(?P<alg>(?:TripleDES|DES)CryptoServiceProvider)\b", replacement: "AesGcm", languages: &["csharp"], machine_applicable: false },
+    AlgorithmFix { pattern: r"\b(?P<alg>des\.NewCipher)\(", replacement: "aes.NewCipher", languages: &["go"], machine_applicable: false },
+];
+
+/// Whether findings in `category` can carry an [`AlgorithmFix`].
+pub fn has_algorithm_fix(category: CryptoCategory) -> bool {
+    matches!(category, CryptoCategory::WeakHash | CryptoCategory::DeprecatedCipher)
+}
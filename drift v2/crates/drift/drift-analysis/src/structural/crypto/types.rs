@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::enforcement::rules::FixRange;
+
 /// A detected cryptographic failure.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CryptoFinding {
@@ -25,6 +27,14 @@ pub struct CryptoFinding {
     pub remediation: String,
     /// Language of the source file.
     pub language: String,
+    /// Replacement for the weak algorithm identifier (`"SHA-256"` for
+    /// Java's `"MD5"`, `sha256` for Node's `'md5'`), when known.
+    #[serde(default)]
+    pub suggested_replacement: Option<String>,
+    /// Span of the identifier `suggested_replacement` replaces. Set only when
+    /// the swap is machine-applicable; without it the replacement is advisory.
+    #[serde(default)]
+    pub range: Option<FixRange>,
 }
 
 /// The 14 categories of cryptographic failures.
@@ -146,7 +146,7 @@ fn refine_by_call_graph(modules: &mut Vec<LogicalModule>, call_edges: &[(String,
     }
 
     // Apply merges (in reverse order to preserve indices)
-    merges.sort_by(|a, b| b.1.cmp(&a.1));
+    merges.sort_by_key(|b| std::cmp::Reverse(b.1));
     for (keep, remove) in merges {
         if keep < modules.len() && remove < modules.len() && keep != remove {
             let removed_files = modules[remove].files.clone();
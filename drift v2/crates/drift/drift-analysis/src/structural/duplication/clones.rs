@@ -0,0 +1,134 @@
+//! Clone pairs via rolling hashes over normalized token windows.
+//!
+//! Every window of `CloneConfig::window` consecutive tokens is hashed with a
+//! polynomial rolling hash. Windows shared by two functions (confirmed by
+//! comparing tokens, so hash collisions do not count) mark the tokens they
+//! span as shared; a pair is reported when the shared part covers at least
+//! `min_similarity` of both functions.
+
+use drift_core::types::collections::{FxHashMap, FxHashSet};
+
+use super::types::{CloneConfig, CloneKind, CloneLocation, ClonePair, FunctionTokens};
+
+const BASE: u64 = 1_000_003;
+
+/// Windows whose hash occurs in more places than this are boilerplate
+/// (`} }` runs, long argument lists) and are not used to pair functions.
+const MAX_BUCKET: usize = 64;
+
+/// Find exact and renamed clone pairs among `functions`.
+///
+/// Pairs are ordered by the position of their first function, then their second.
+pub fn detect_clones(functions: &[FunctionTokens], config: &CloneConfig) -> Vec<ClonePair> {
+    let window = config.window.max(1);
+    let mut buckets: FxHashMap<u64, Vec<(usize, usize)>> = FxHashMap::default();
+    for (index, function) in functions.iter().enumerate() {
+        for (offset, hash) in window_hashes(function, window) {
+            buckets.entry(hash).or_default().push((index, offset));
+        }
+    }
+
+    // Per pair of functions, the window offsets they share on each side.
+    let mut shared: FxHashMap<(usize, usize), (FxHashSet<usize>, FxHashSet<usize>)> = FxHashMap::default();
+    for entries in buckets.values().filter(|e| e.len() > 1 && e.len() <= MAX_BUCKET) {
+        for (i, &(a, a_offset)) in entries.iter().enumerate() {
+            for &(b, b_offset) in &entries[i + 1..] {
+                if a == b || nested(&functions[a], &functions[b]) || !same_window(&functions[a], a_offset, &functions[b], b_offset, window) {
+                    continue;
+                }
+                let (key, first, second) = if a < b { ((a, b), a_offset, b_offset) } else { ((b, a), b_offset, a_offset) };
+                let entry = shared.entry(key).or_default();
+                entry.0.insert(first);
+                entry.1.insert(second);
+            }
+        }
+    }
+
+    let mut pairs: Vec<ClonePair> = shared
+        .into_iter()
+        .filter_map(|((a, b), (a_offsets, b_offsets))| {
+            let (first, second) = (&functions[a], &functions[b]);
+            let similarity = coverage(&a_offsets, first.tokens.len(), window)
+                .min(coverage(&b_offsets, second.tokens.len(), window));
+            if similarity < config.min_similarity {
+                return None;
+            }
+            // A copy must be renamed to coexist; its own name does not count.
+            let exact = first.tokens.len() == second.tokens.len()
+                && first.tokens.iter().zip(&second.tokens).all(|(x, y)| {
+                    x.raw == y.raw || (x.raw == first.name && y.raw == second.name)
+                });
+            Some(ClonePair {
+                first: location(first),
+                second: location(second),
+                kind: if exact { CloneKind::Exact } else { CloneKind::Renamed },
+                similarity,
+            })
+        })
+        .collect();
+    pairs.sort_by(|x, y| {
+        (&x.first.file, x.first.line, &x.second.file, x.second.line)
+            .cmp(&(&y.first.file, y.first.line, &y.second.file, y.second.line))
+    });
+    pairs
+}
+
+/// `(offset, hash)` for every full window of the function's token stream.
+fn window_hashes(function: &FunctionTokens, window: usize) -> Vec<(usize, u64)> {
+    let tokens: Vec<u64> = function.tokens.iter().map(|t| token_hash(&t.text)).collect();
+    if tokens.len() < window {
+        return Vec::new();
+    }
+    let high = (1..window).fold(1u64, |acc, _| acc.wrapping_mul(BASE));
+    let mut hash = tokens[..window].iter().fold(0u64, |acc, &t| acc.wrapping_mul(BASE).wrapping_add(t));
+    let mut hashes = vec![(0, hash)];
+    for offset in 1..=tokens.len() - window {
+        hash = hash
+            .wrapping_sub(tokens[offset - 1].wrapping_mul(high))
+            .wrapping_mul(BASE)
+            .wrapping_add(tokens[offset + window - 1]);
+        hashes.push((offset, hash));
+    }
+    hashes
+}
+
+/// FNV-1a of a normalized token.
+fn token_hash(text: &str) -> u64 {
+    text.bytes()
+        .fold(0xcbf2_9ce4_8422_2325u64, |acc, b| (acc ^ b as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+fn same_window(a: &FunctionTokens, a_offset: usize, b: &FunctionTokens, b_offset: usize, window: usize) -> bool {
+    a.tokens[a_offset..a_offset + window]
+        .iter()
+        .zip(&b.tokens[b_offset..b_offset + window])
+        .all(|(x, y)| x.text == y.text)
+}
+
+/// Whether one function contains the other (a nested closure shares its
+/// parent's tokens without being a copy of it).
+fn nested(a: &FunctionTokens, b: &FunctionTokens) -> bool {
+    a.file == b.file
+        && ((a.line <= b.line && b.end_line <= a.end_line) || (b.line <= a.line && a.end_line <= b.end_line))
+}
+
+/// Share of `len` tokens covered by windows starting at `offsets`.
+fn coverage(offsets: &FxHashSet<usize>, len: usize, window: usize) -> f64 {
+    if len == 0 {
+        return 0.0;
+    }
+    let mut covered = vec![false; len];
+    for &offset in offsets {
+        covered[offset..(offset + window).min(len)].iter_mut().for_each(|c| *c = true);
+    }
+    covered.iter().filter(|c| **c).count() as f64 / len as f64
+}
+
+fn location(function: &FunctionTokens) -> CloneLocation {
+    CloneLocation {
+        file: function.file.clone(),
+        function: function.name.clone(),
+        line: function.line,
+        end_line: function.end_line,
+    }
+}
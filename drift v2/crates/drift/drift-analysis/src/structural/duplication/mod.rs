@@ -0,0 +1,15 @@
+//! Duplication — exact (Type-1) and renamed (Type-2) function clones.
+//!
+//! Each function body is turned into a normalized token stream: keywords,
+//! operators and punctuation are kept, identifiers and literals become
+//! placeholders, comments are dropped. Rolling hashes over fixed-size token
+//! windows then find function pairs whose streams largely coincide, so
+//! copies that only rename variables or change constants are still found.
+
+pub mod types;
+pub mod tokens;
+pub mod clones;
+
+pub use types::*;
+pub use tokens::{function_tokens, normalize_tokens};
+pub use clones::detect_clones;
@@ -0,0 +1,144 @@
+//! Normalized token streams from tree-sitter leaves.
+//!
+//! Grammar keywords and operators are anonymous leaves and keep their text.
+//! Named leaves are identifiers or literals and become `$id` / `$lit`,
+//! except words in the language's keyword set (`this`, `self`, `nil`,
+//! builtin types), which some grammars expose as identifiers but which carry
+//! structure rather than naming. String and template nodes are one literal
+//! token regardless of their inner parts.
+
+use tree_sitter::{Node, Point, Tree};
+
+use crate::parsers::types::{FunctionInfo, ParseResult};
+use crate::scanner::language_detect::Language;
+
+use super::types::{FunctionTokens, Token, TokenKind};
+
+const PUNCTUATION: &[&str] = &["(", ")", "{", "}", "[", "]", ";", ",", ".", ":"];
+
+/// Tokenize `function_node` into a normalized stream.
+pub fn normalize_tokens(function_node: Node, source: &[u8], language: Language) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    collect(function_node, source, keywords(language), &mut tokens);
+    tokens
+}
+
+/// Token streams for every function and method in `parse_result`.
+pub fn function_tokens(parse_result: &ParseResult, tree: &Tree, source: &[u8]) -> Vec<FunctionTokens> {
+    let mut functions: Vec<&FunctionInfo> = parse_result
+        .functions
+        .iter()
+        .chain(parse_result.classes.iter().flat_map(|c| c.methods.iter()))
+        .collect();
+    functions.sort_by_key(|f| (f.line, f.end_line));
+    functions.dedup_by(|a, b| a.line == b.line && a.end_line == b.end_line && a.name == b.name);
+
+    let root = tree.root_node();
+    functions
+        .into_iter()
+        .filter_map(|f| {
+            let start = Point::new(f.range.start.line as usize, f.range.start.column as usize);
+            let end = Point::new(f.range.end.line as usize, f.range.end.column as usize);
+            let node = root.descendant_for_point_range(start, end)?;
+            Some(FunctionTokens {
+                file: parse_result.file.clone(),
+                name: f.name.clone(),
+                line: f.line,
+                end_line: f.end_line,
+                tokens: normalize_tokens(node, source, parse_result.language),
+            })
+        })
+        .collect()
+}
+
+fn collect(node: Node, source: &[u8], keywords: &[&str], tokens: &mut Vec<Token>) {
+    let kind = node.kind();
+    if kind.contains("comment") {
+        return;
+    }
+    if node.child_count() > 0 && !is_literal(kind) {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            collect(child, source, keywords, tokens);
+        }
+        return;
+    }
+
+    let Ok(raw) = node.utf8_text(source) else {
+        return;
+    };
+    // Zero-width and layout tokens (Python indent/dedent, newlines).
+    if raw.trim().is_empty() {
+        return;
+    }
+    let (kind, text) = if is_literal(kind) {
+        (TokenKind::Literal, "$lit")
+    } else if keywords.contains(&raw) {
+        (TokenKind::Keyword, raw)
+    } else if node.is_named() {
+        if kind.contains("identifier") || matches!(kind, "name" | "constant" | "variable_name" | "word") {
+            (TokenKind::Identifier, "$id")
+        } else {
+            (TokenKind::Keyword, raw)
+        }
+    } else if raw.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+        (TokenKind::Keyword, raw)
+    } else if PUNCTUATION.contains(&raw) {
+        (TokenKind::Punctuation, raw)
+    } else {
+        (TokenKind::Operator, raw)
+    };
+    tokens.push(Token {
+        kind,
+        text: text.to_string(),
+        raw: raw.to_string(),
+        line: node.start_position().row as u32,
+    });
+}
+
+/// String, number, boolean and null nodes across the supported grammars.
+fn is_literal(kind: &str) -> bool {
+    kind.ends_with("_literal")
+        || kind.starts_with("string")
+        || matches!(
+            kind,
+            "template_string" | "encapsed_string" | "heredoc" | "regex" | "number" | "integer"
+                | "float" | "true" | "false" | "null" | "none" | "nil" | "boolean"
+                | "concatenated_string" | "simple_symbol"
+        )
+}
+
+/// Words kept verbatim even when the grammar exposes them as identifiers.
+fn keywords(language: Language) -> &'static [&'static str] {
+    match language {
+        Language::TypeScript | Language::JavaScript => &[
+            "this", "super", "undefined", "arguments", "NaN", "Infinity", "string", "number",
+            "boolean", "any", "unknown", "void", "never", "object",
+        ],
+        Language::Python => &["self", "cls", "None", "True", "False", "print", "len", "range"],
+        Language::Java | Language::Kotlin | Language::Scala => &[
+            "this", "super", "null", "int", "long", "double", "float", "boolean", "char", "byte",
+            "short", "void", "String", "Object", "it",
+        ],
+        Language::CSharp => &[
+            "this", "base", "null", "int", "long", "double", "float", "bool", "char", "byte",
+            "string", "object", "void", "var", "value",
+        ],
+        Language::Go => &[
+            "nil", "iota", "true", "false", "int", "int64", "int32", "uint", "float64", "string",
+            "bool", "byte", "rune", "error", "any", "len", "cap", "make", "append", "new",
+        ],
+        Language::Rust => &[
+            "self", "Self", "super", "crate", "i32", "i64", "u8", "u32", "u64", "usize", "isize",
+            "f32", "f64", "bool", "char", "str", "String", "Vec", "Option", "Result", "Some",
+            "None", "Ok", "Err",
+        ],
+        Language::Ruby => &["self", "nil", "true", "false", "super"],
+        Language::Php => &["this", "self", "parent", "static", "null", "true", "false"],
+        Language::Swift => &["self", "Self", "super", "nil", "Int", "String", "Bool", "Double"],
+        Language::Cpp | Language::C => &[
+            "this", "nullptr", "NULL", "int", "long", "double", "float", "char", "bool", "void",
+            "size_t", "auto",
+        ],
+    }
+}
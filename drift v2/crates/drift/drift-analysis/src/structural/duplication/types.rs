@@ -0,0 +1,85 @@
+//! Duplication types.
+
+use serde::{Deserialize, Serialize};
+
+/// Class of a normalized token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TokenKind {
+    Keyword,
+    Operator,
+    Punctuation,
+    /// Normalized to `$id`.
+    Identifier,
+    /// Normalized to `$lit`.
+    Literal,
+}
+
+/// One token of a normalized stream.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Token {
+    pub kind: TokenKind,
+    /// Normalized text: the token itself, or `$id` / `$lit`.
+    pub text: String,
+    /// Source text before normalization.
+    pub raw: String,
+    /// 0-based line.
+    pub line: u32,
+}
+
+/// The normalized token stream of one function.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionTokens {
+    pub file: String,
+    pub name: String,
+    /// First line (0-based, as in `FunctionInfo`).
+    pub line: u32,
+    pub end_line: u32,
+    pub tokens: Vec<Token>,
+}
+
+/// How two functions are duplicated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CloneKind {
+    /// Type-1: identical token streams, apart from the function's own name.
+    Exact,
+    /// Type-2: identical after normalizing identifiers and literals.
+    Renamed,
+}
+
+/// One side of a clone pair.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CloneLocation {
+    pub file: String,
+    pub function: String,
+    pub line: u32,
+    pub end_line: u32,
+}
+
+/// Two functions whose normalized token streams largely coincide.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClonePair {
+    pub first: CloneLocation,
+    pub second: CloneLocation,
+    pub kind: CloneKind,
+    /// Share of each function covered by shared windows (the smaller of the
+    /// two), in `[0.0, 1.0]`.
+    pub similarity: f64,
+}
+
+/// Clone detection thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CloneConfig {
+    /// Tokens per hashed window; functions shorter than this are skipped.
+    pub window: usize,
+    /// Minimum similarity for a pair to be reported.
+    pub min_similarity: f64,
+}
+
+impl Default for CloneConfig {
+    fn default() -> Self {
+        Self {
+            window: 25,
+            min_similarity: 0.8,
+        }
+    }
+}
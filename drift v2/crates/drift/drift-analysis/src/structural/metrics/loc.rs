@@ -0,0 +1,285 @@
+//! Line classification: source, comment-only and blank lines.
+//!
+//! Lines are classified with a small per-language lexer that tracks block
+//! comments and strings across lines, so `//` inside a string is code and a
+//! line inside `/* ... */` is a comment. A line with code and a trailing
+//! comment counts as source. Python docstrings (a triple-quoted string that
+//! starts a line) count as comments.
+
+use crate::parsers::types::{FunctionInfo, ParseResult};
+use crate::scanner::language_detect::Language;
+
+use super::types::{FunctionLoc, LineCounts, LocMetrics};
+
+/// Compute file and per-function line counts. Function ranges come from the
+/// already-extracted `functions` and class `methods`.
+pub fn loc_metrics(parse_result: &ParseResult, source: &[u8]) -> LocMetrics {
+    let text = String::from_utf8_lossy(source);
+    let kinds = classify_lines(&text, parse_result.language);
+
+    let mut functions: Vec<&FunctionInfo> = parse_result
+        .functions
+        .iter()
+        .chain(parse_result.classes.iter().flat_map(|c| c.methods.iter()))
+        .collect();
+    functions.sort_by_key(|f| (f.line, f.end_line));
+    functions.dedup_by(|a, b| a.line == b.line && a.end_line == b.end_line && a.name == b.name);
+
+    LocMetrics {
+        file: parse_result.file.clone(),
+        counts: count(&kinds),
+        functions: functions
+            .into_iter()
+            .map(|f| {
+                let end = (f.end_line as usize + 1).min(kinds.len());
+                let span = kinds.get(f.line as usize..end).unwrap_or_default();
+                FunctionLoc {
+                    name: f.name.clone(),
+                    qualified_name: f.qualified_name.clone(),
+                    line: f.line,
+                    end_line: f.end_line,
+                    counts: count(span),
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Classification of one physical line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineKind {
+    Source,
+    Comment,
+    Blank,
+}
+
+fn count(kinds: &[LineKind]) -> LineCounts {
+    let mut counts = LineCounts {
+        loc: kinds.len() as u32,
+        ..LineCounts::default()
+    };
+    for kind in kinds {
+        match kind {
+            LineKind::Source => counts.sloc += 1,
+            LineKind::Comment => counts.comment += 1,
+            LineKind::Blank => counts.blank += 1,
+        }
+    }
+    counts
+}
+
+/// Comment and string syntax of a language.
+struct Syntax {
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    /// Block comments nest (`/* /* */ */` in Rust, Kotlin, Swift, Scala).
+    nested_blocks: bool,
+    /// Ruby `=begin`/`=end`: block markers only count at the start of a line.
+    line_start_blocks: bool,
+    /// String delimiters, longest first.
+    quotes: &'static [&'static str],
+    /// Delimiters whose strings may span lines.
+    multiline_quotes: &'static [&'static str],
+    /// Python: a triple-quoted string starting a line is a docstring.
+    docstrings: bool,
+}
+
+const C_BLOCK: Option<(&str, &str)> = Some(("/*", "*/"));
+
+fn syntax(language: Language) -> Syntax {
+    let c_like = Syntax {
+        line_comments: &["//"],
+        block_comment: C_BLOCK,
+        nested_blocks: false,
+        line_start_blocks: false,
+        quotes: &["\"", "'"],
+        multiline_quotes: &[],
+        docstrings: false,
+    };
+    match language {
+        Language::TypeScript | Language::JavaScript => Syntax {
+            quotes: &["\"", "'", "`"],
+            multiline_quotes: &["`"],
+            ..c_like
+        },
+        Language::Go => Syntax {
+            quotes: &["\"", "'", "`"],
+            multiline_quotes: &["`"],
+            ..c_like
+        },
+        Language::Java => Syntax {
+            quotes: &["\"\"\"", "\"", "'"],
+            multiline_quotes: &["\"\"\""],
+            ..c_like
+        },
+        Language::Kotlin | Language::Swift | Language::Scala => Syntax {
+            nested_blocks: true,
+            quotes: &["\"\"\"", "\"", "'"],
+            multiline_quotes: &["\"\"\""],
+            ..c_like
+        },
+        // `'` starts lifetimes as well as chars; char literals are skipped in the lexer.
+        Language::Rust => Syntax {
+            nested_blocks: true,
+            quotes: &["\""],
+            multiline_quotes: &["\""],
+            ..c_like
+        },
+        Language::Php => Syntax {
+            line_comments: &["//", "#"],
+            multiline_quotes: &["\"", "'"],
+            ..c_like
+        },
+        Language::Python => Syntax {
+            line_comments: &["#"],
+            block_comment: None,
+            quotes: &["\"\"\"", "'''", "\"", "'"],
+            multiline_quotes: &["\"\"\"", "'''"],
+            docstrings: true,
+            ..c_like
+        },
+        Language::Ruby => Syntax {
+            line_comments: &["#"],
+            block_comment: Some(("=begin", "=end")),
+            line_start_blocks: true,
+            ..c_like
+        },
+        Language::CSharp | Language::Cpp | Language::C => c_like,
+    }
+}
+
+enum State {
+    Code,
+    Block(u32),
+    Str { close: &'static str, doc: bool },
+}
+
+/// Classify each physical line of `text`.
+pub fn classify_lines(text: &str, language: Language) -> Vec<LineKind> {
+    let syntax = syntax(language);
+    let mut state = State::Code;
+    let mut kinds = Vec::new();
+
+    for line in text.lines() {
+        let mut has_code = false;
+        let mut has_comment = false;
+        let mut i = 0;
+
+        while i < line.len() {
+            let rest = &line[i..];
+            let c = rest.chars().next().unwrap_or(' ');
+            match state {
+                State::Block(depth) => {
+                    let (open, close) = syntax.block_comment.unwrap_or(("", ""));
+                    if syntax.line_start_blocks {
+                        has_comment |= !line.trim().is_empty();
+                        if line.starts_with(close) {
+                            state = State::Code;
+                        }
+                        break;
+                    }
+                    if rest.starts_with(close) {
+                        has_comment = true;
+                        i += close.len();
+                        state = if depth > 1 { State::Block(depth - 1) } else { State::Code };
+                        continue;
+                    }
+                    if syntax.nested_blocks && rest.starts_with(open) {
+                        i += open.len();
+                        state = State::Block(depth + 1);
+                        continue;
+                    }
+                    has_comment |= !c.is_whitespace();
+                }
+                State::Str { close, doc } => {
+                    if !c.is_whitespace() {
+                        if doc {
+                            has_comment = true;
+                        } else {
+                            has_code = true;
+                        }
+                    }
+                    // Go raw strings have no escapes.
+                    if c == '\\' && !(language == Language::Go && close == "`") {
+                        i += 1;
+                        i += line[i..].chars().next().map_or(0, char::len_utf8);
+                        continue;
+                    }
+                    if rest.starts_with(close) {
+                        i += close.len();
+                        state = State::Code;
+                        continue;
+                    }
+                }
+                State::Code => {
+                    if c.is_whitespace() {
+                        i += c.len_utf8();
+                        continue;
+                    }
+                    if syntax.line_comments.iter().any(|m| rest.starts_with(m))
+                        && !(language == Language::Php && rest.starts_with("#["))
+                    {
+                        has_comment = true;
+                        break;
+                    }
+                    if let Some((open, _)) = syntax.block_comment {
+                        let at_line_start = !syntax.line_start_blocks || i == 0;
+                        if at_line_start && rest.starts_with(open) {
+                            has_comment = true;
+                            i += open.len();
+                            state = State::Block(1);
+                            continue;
+                        }
+                    }
+                    if let Some(quote) = syntax.quotes.iter().find(|q| rest.starts_with(**q)) {
+                        let doc = syntax.docstrings && !has_code && quote.len() == 3;
+                        if doc {
+                            has_comment = true;
+                        } else {
+                            has_code = true;
+                        }
+                        i += quote.len();
+                        state = State::Str { close: quote, doc };
+                        continue;
+                    }
+                    has_code = true;
+                    if language == Language::Rust && c == '\'' {
+                        i += rust_char_literal_len(rest);
+                        continue;
+                    }
+                }
+            }
+            i += c.len_utf8();
+        }
+
+        // Strings that cannot span lines end with the line.
+        if let State::Str { close, .. } = state {
+            if !syntax.multiline_quotes.contains(&close) {
+                state = State::Code;
+            }
+        }
+
+        kinds.push(if has_code {
+            LineKind::Source
+        } else if has_comment {
+            LineKind::Comment
+        } else {
+            LineKind::Blank
+        });
+    }
+    kinds
+}
+
+/// Length of a Rust char literal (`'x'`, `'\n'`, `'\u{1F600}'`) at the start
+/// of `rest`, or 1 for a lifetime's `'`.
+fn rust_char_literal_len(rest: &str) -> usize {
+    let body = &rest[1..];
+    if let Some(escaped) = body.strip_prefix('\\') {
+        return escaped.find('\'').map_or(1, |end| end + 3);
+    }
+    let mut chars = body.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), Some('\'')) => 2 + c.len_utf8(),
+        _ => 1,
+    }
+}
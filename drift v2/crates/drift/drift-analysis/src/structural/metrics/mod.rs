@@ -0,0 +1,8 @@
+//! Size metrics — physical, source and comment line counts per file and
+//! per function, feeding complexity, duplication ratio and doc coverage.
+
+pub mod types;
+pub mod loc;
+
+pub use types::*;
+pub use loc::loc_metrics;
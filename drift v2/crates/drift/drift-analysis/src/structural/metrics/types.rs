@@ -0,0 +1,48 @@
+//! Size metric types.
+
+use serde::{Deserialize, Serialize};
+
+/// Line counts for a span of source. Every physical line is exactly one of
+/// source (has code, possibly with a trailing comment), comment-only, or blank.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineCounts {
+    /// Physical lines.
+    pub loc: u32,
+    /// Lines containing code.
+    pub sloc: u32,
+    /// Lines containing only comments (including docstrings).
+    pub comment: u32,
+    /// Lines containing only whitespace.
+    pub blank: u32,
+}
+
+impl LineCounts {
+    /// Comment-only lines per line of code + comment, 0.0 when empty.
+    pub fn comment_ratio(&self) -> f64 {
+        let total = self.sloc + self.comment;
+        if total == 0 {
+            0.0
+        } else {
+            self.comment as f64 / total as f64
+        }
+    }
+}
+
+/// Size metrics for one function or method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionLoc {
+    pub name: String,
+    pub qualified_name: Option<String>,
+    /// First line (0-based, as in `FunctionInfo`).
+    pub line: u32,
+    pub end_line: u32,
+    pub counts: LineCounts,
+}
+
+/// Size metrics for a file with a per-function breakdown.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocMetrics {
+    pub file: String,
+    pub counts: LineCounts,
+    pub functions: Vec<FunctionLoc>,
+}
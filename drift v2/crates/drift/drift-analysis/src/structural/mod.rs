@@ -12,3 +12,6 @@ pub mod dna;
 pub mod owasp_cwe;
 pub mod crypto;
 pub mod decomposition;
+pub mod metrics;
+pub mod complexity;
+pub mod duplication;
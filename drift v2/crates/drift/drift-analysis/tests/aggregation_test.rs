@@ -1,12 +1,14 @@
-//! Phase 3 Aggregation Tests — T3-AGG-01 through T3-AGG-10.
+//! Phase 3 Aggregation Tests — T3-AGG-01 through T3-AGG-13.
 
 use drift_analysis::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
 use drift_analysis::patterns::aggregation::pipeline::AggregationPipeline;
 use drift_analysis::patterns::aggregation::similarity::{jaccard_similarity, MinHashIndex};
 use drift_analysis::patterns::aggregation::types::{
-    AggregatedPattern, MergeDecision, PatternLocation,
+    AggregatedPattern, AggregationConfig, MergeDecision, PatternLocation,
 };
 use drift_analysis::patterns::aggregation::reconciliation;
+use drift_analysis::patterns::confidence::scorer::{ConfidenceScorer, ScorerConfig};
+use drift_analysis::patterns::confidence::types::MomentumDirection;
 use drift_core::types::collections::FxHashSet;
 use smallvec::smallvec;
 
@@ -51,6 +53,7 @@ fn make_pattern(id: &str, n_locations: u32, n_files: u32) -> AggregatedPattern {
         confidence_values: vec![0.9; n_locations as usize],
         is_dirty: true,
         location_hash: 0,
+        over_limit: false,
     }
 }
 
@@ -390,3 +393,146 @@ fn t3_agg_10_single_pattern() {
     assert!(pattern.merged_from.is_empty(), "Single pattern should not be merged");
     assert!(result.merge_candidates.is_empty(), "No merge candidates for single pattern");
 }
+
+// ---- T3-AGG-11: apply_delta equals a full re-aggregation ----
+
+#[test]
+fn t3_agg_11_apply_delta_matches_full_run() {
+    let before = vec![
+        make_match("src/a.ts", 1, "no-console", 0.9),
+        make_match("src/b.ts", 2, "no-console", 0.8),
+        make_match("src/c.ts", 3, "no-console", 0.7),
+        make_match("src/a.ts", 4, "no-var", 0.6),
+        make_match("src/b.ts", 5, "no-var", 0.5),
+        make_match("src/d.ts", 6, "strict-eq", 0.95),
+        make_match("src/d.ts", 7, "strict-eq", 0.85),
+    ];
+    let pipeline = AggregationPipeline::with_defaults();
+    let mut result = pipeline.run(&before);
+
+    // b.ts changes (no-var disappears, a new pattern appears), c.ts is deleted.
+    let changed = vec![
+        make_match("src/b.ts", 12, "no-console", 0.65),
+        make_match("src/b.ts", 14, "no-console", 0.75),
+        make_match("src/b.ts", 20, "prefer-const", 0.9),
+    ];
+    let removed: FxHashSet<String> = ["src/c.ts".to_string()].into_iter().collect();
+    pipeline.apply_delta(&mut result, &changed, &removed);
+
+    let after: Vec<PatternMatch> = before
+        .iter()
+        .filter(|m| m.file != "src/b.ts" && m.file != "src/c.ts")
+        .cloned()
+        .chain(changed.iter().cloned())
+        .collect();
+    let full = pipeline.run(&after);
+
+    let summarize = |patterns: &[AggregatedPattern]| {
+        let mut rows: Vec<_> = patterns
+            .iter()
+            .map(|p| {
+                (
+                    p.pattern_id.clone(),
+                    p.location_count,
+                    p.file_spread,
+                    p.outlier_count,
+                    (p.confidence_mean * 1e9).round() as i64,
+                    p.location_hash,
+                )
+            })
+            .collect();
+        rows.sort();
+        rows
+    };
+    assert_eq!(summarize(&result.patterns), summarize(&full.patterns));
+    assert_eq!(result.diagnostics.raw_match_count, full.diagnostics.raw_match_count);
+    assert_eq!(result.gold_layer.total_locations, full.gold_layer.total_locations);
+
+    // Only the patterns touched by the delta are dirty.
+    let mut dirty: Vec<&str> = result
+        .patterns
+        .iter()
+        .filter(|p| p.is_dirty)
+        .map(|p| p.pattern_id.as_str())
+        .collect();
+    dirty.sort();
+    assert_eq!(dirty, vec!["no-console", "no-var", "prefer-const"]);
+}
+
+// ---- T3-AGG-12: Location cap keeps a bounded sample and an accurate count ----
+
+#[test]
+fn t3_agg_12_location_cap_bounds_memory() {
+    let cap = 1_000;
+    // 50K distinct locations over 500 files, plus a duplicate of every tenth.
+    let mut matches: Vec<PatternMatch> = (0..50_000u32)
+        .map(|i| make_match(&format!("src/f{}.ts", i % 500), i, "too-broad", 0.5 + (i % 5) as f32 * 0.1))
+        .collect();
+    matches.extend((0..50_000u32).step_by(10).map(|i| make_match(&format!("src/f{}.ts", i % 500), i, "too-broad", 0.4)));
+    matches.push(make_match("src/small.ts", 1, "narrow", 0.9));
+
+    let pipeline = AggregationPipeline::new(AggregationConfig {
+        max_locations_per_pattern: cap,
+        ..AggregationConfig::default()
+    });
+    let result = pipeline.run(&matches);
+    let broad = result.patterns.iter().find(|p| p.pattern_id == "too-broad").unwrap();
+
+    assert!(broad.over_limit);
+    assert_eq!(broad.locations.len(), cap, "stored locations are bounded");
+    assert!(broad.confidence_values.len() <= cap);
+    assert_eq!(broad.location_count, 50_000, "count covers every deduplicated location");
+    assert_eq!(broad.file_spread, 500);
+    assert!(broad.outlier_rate() <= 1.0);
+    assert_eq!(result.gold_layer.total_locations, 50_001);
+
+    let narrow = result.patterns.iter().find(|p| p.pattern_id == "narrow").unwrap();
+    assert!(!narrow.over_limit);
+    assert_eq!(narrow.locations.len(), 1);
+
+    // The sample does not depend on match order.
+    let reversed: Vec<PatternMatch> = matches.iter().rev().cloned().collect();
+    let again = pipeline.run(&reversed);
+    let broad_again = again.patterns.iter().find(|p| p.pattern_id == "too-broad").unwrap();
+    assert_eq!(broad_again.location_hash, broad.location_hash);
+
+    // Confidence scoring sees the full count, not the sample size.
+    let scorer = ConfidenceScorer::new(ScorerConfig { total_files: 500, ..ScorerConfig::default() });
+    let mut uncapped = broad.clone();
+    uncapped.location_count = cap as u32;
+    let full = scorer.score(broad, MomentumDirection::Stable, 30, Some(50_001), None);
+    let sample_only = scorer.score(&uncapped, MomentumDirection::Stable, 30, Some(50_001), None);
+    assert!(full.posterior_mean > sample_only.posterior_mean, "{} vs {}", full.posterior_mean, sample_only.posterior_mean);
+}
+
+// ---- T3-AGG-13: Adherence interval narrows as file spread grows ----
+
+#[test]
+fn t3_agg_13_adherence_ci_narrows_with_sample_size() {
+    // 20% of locations are outliers at every size: 80% adherence.
+    let with_outliers = |n: u32| {
+        let mut p = make_pattern("naming", n, n);
+        for loc in p.locations.iter_mut().take((n / 5) as usize) {
+            loc.is_outlier = true;
+        }
+        reconciliation::reconcile(&mut p);
+        p
+    };
+
+    let mut previous_width = f64::INFINITY;
+    for n in [10, 50, 250, 1000] {
+        let (low, high) = with_outliers(n).adherence_ci();
+        assert!(low <= 0.8 && 0.8 <= high, "n={n}: ({low}, {high}) should contain 0.8");
+        assert!((0.0..=1.0).contains(&low) && (0.0..=1.0).contains(&high));
+        let width = high - low;
+        assert!(width < previous_width, "n={n}: width {width} should narrow from {previous_width}");
+        previous_width = width;
+    }
+    // About 80% ± 2.5% at 1000 files.
+    assert!(previous_width < 0.06, "width at n=1000 was {previous_width}");
+
+    // Full adherence stays below 1.0 on the low side; no files are uninformative.
+    let (low, high) = make_pattern("all", 20, 20).adherence_ci();
+    assert!(low > 0.8 && low < 1.0 && high > 0.999, "({low}, {high})");
+    assert_eq!(make_pattern("none", 0, 0).adherence_ci(), (0.0, 1.0));
+}
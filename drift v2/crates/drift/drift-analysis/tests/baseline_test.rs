@@ -0,0 +1,186 @@
+//! Phase 6 tests: Baseline — first-seen tracking and aging
+//! T6-BSL-01 through T6-BSL-04
+
+use drift_analysis::enforcement::baseline::*;
+use drift_analysis::enforcement::fingerprint::{finding_fingerprint, violation_fingerprint};
+use drift_analysis::enforcement::gates::{GateInputBuilder, GateOrchestrator, SecurityFindingInput};
+use drift_analysis::enforcement::rules::{
+    OutlierLocation, PatternInfo, RulesEvaluator, RulesInput, Severity, Violation,
+};
+
+const DAY: u64 = 86_400;
+
+fn violation(file: &str, line: u32, rule_id: &str) -> Violation {
+    Violation {
+        id: format!("{rule_id}-{file}-{line}"),
+        file: file.to_string(),
+        line,
+        column: None,
+        end_line: None,
+        end_column: None,
+        severity: Severity::Warning,
+        pattern_id: rule_id.to_string(),
+        rule_id: rule_id.to_string(),
+        message: "baselined".to_string(),
+        quick_fix: None,
+        cwe_id: None,
+        owasp_category: None,
+        suppressed: false,
+        is_new: false,
+        fingerprint: None,
+    }
+}
+
+// T6-BSL-01: An old baselined finding is reported as aged.
+#[test]
+fn test_old_baselined_finding_is_aged() {
+    let start = 1_700_000_000;
+    let old = violation("src/a.ts", 10, "sec/sqli");
+    let fixed = violation("src/b.ts", 3, "naming");
+    let mut baseline = Baseline::from_violations(&[old.clone(), fixed], start);
+
+    // A later re-baseline keeps the original first_seen and adds the new entry.
+    let recent = violation("src/c.ts", 7, "errors/empty-catch");
+    baseline.update(&[old.clone(), recent.clone()], start + 100 * DAY);
+    let baseline = Baseline::from_json(&baseline.to_json().unwrap()).unwrap();
+
+    let report = age_report(&baseline, &[old, recent], start + 120 * DAY);
+    assert_eq!(report.len(), 2, "the fixed finding is gone: {report:?}");
+    assert_eq!(report[0].file, "src/a.ts");
+    assert_eq!(report[0].age_days, 120);
+    assert!(report[0].requires_remediation);
+    assert_eq!(report[1].age_days, 20);
+    assert!(!report[1].requires_remediation);
+}
+
+// T6-BSL-02: Baseline keys and the remediation threshold.
+#[test]
+fn test_baseline_keys_and_threshold() {
+    let v = violation("src/a.ts", 10, "sec/sqli");
+    let mut baseline = Baseline::from_violations(std::slice::from_ref(&v), 0);
+    assert!(baseline.keys().contains("src/a.ts:10:sec/sqli"));
+
+    baseline.remediation_after_days = 7;
+    let report = age_report(&baseline, &[v], 8 * DAY);
+    assert!(report[0].requires_remediation);
+
+    // Older baseline files without a threshold fall back to the default.
+    let parsed = Baseline::from_json(r#"{"entries": []}"#).unwrap();
+    assert_eq!(parsed.remediation_after_days, DEFAULT_REMEDIATION_DAYS);
+}
+
+// T6-BSL-03: A finding keeps its fingerprint, and its baseline entry, across a line shift.
+#[test]
+fn test_fingerprint_survives_line_shift() {
+    assert_eq!(
+        finding_fingerprint("src/a.ts", "security/sqli", "    db.query(sql + id); // legacy", ""),
+        finding_fingerprint("src/a.ts", "security/sqli", "db.query(sql  +  id);", ""),
+    );
+    assert_ne!(
+        finding_fingerprint("src/a.ts", "security/sqli", "db.query(sql + id);", ""),
+        finding_fingerprint("src/a.ts", "security/sqli", "db.query(sql, [id]);", ""),
+    );
+
+    let evaluate = |source: &[&str], line: u32, baseline: &Baseline| {
+        let input = RulesInput {
+            patterns: vec![PatternInfo {
+                pattern_id: "sqli".to_string(),
+                category: "security".to_string(),
+                confidence: 0.9,
+                locations: Vec::new(),
+                outliers: vec![OutlierLocation {
+                    file: "src/a.ts".to_string(),
+                    line,
+                    message: "string-built query".to_string(),
+                    ..Default::default()
+                }],
+                cwe_ids: vec![89],
+                owasp_categories: Vec::new(),
+            }],
+            source_lines: [("src/a.ts".to_string(), source.iter().map(|l| l.to_string()).collect())].into(),
+            baseline_violation_ids: baseline.keys(),
+        };
+        RulesEvaluator::new().evaluate(&input).remove(0)
+    };
+
+    let before = evaluate(&["const id = req.query.id;", "db.query(sql + id);"], 2, &Baseline::default());
+    let mut baseline = Baseline::from_violations(std::slice::from_ref(&before), 0);
+
+    // Two lines inserted above the finding.
+    let after = evaluate(
+        &["import db from './db';", "", "const id = req.query.id;", "  db.query(sql + id);"],
+        4,
+        &baseline,
+    );
+    assert_eq!(violation_fingerprint(&after), violation_fingerprint(&before));
+    assert!(!after.is_new, "the shifted finding is still baselined");
+
+    baseline.update(std::slice::from_ref(&after), 30 * DAY);
+    assert_eq!(baseline.entries[0].line, 4);
+    assert_eq!(baseline.entries[0].first_seen, 0);
+    assert_eq!(age_report(&baseline, &[after], 30 * DAY)[0].age_days, 30);
+}
+
+// T6-BSL-04: Gate violations are fingerprinted by content, with or without source.
+#[test]
+fn test_gate_fingerprints_survive_line_shift() {
+    // One high-confidence outlier (PatternCompliance) or one security finding
+    // (SecurityBoundaries, which is skipped once PatternCompliance fails).
+    let run = |security: bool, source: Option<&[&str]>, line: u32, baseline: &Baseline| {
+        let mut builder = GateInputBuilder::new()
+            .files(vec!["src/a.ts".to_string()])
+            .baseline_violations(baseline.keys());
+        if security {
+            builder = builder.security_findings(vec![SecurityFindingInput {
+                file: "src/a.ts".to_string(),
+                line,
+                description: "Taint flow: req.query.id → db.query".to_string(),
+                severity: "critical".to_string(),
+                cwe_ids: vec![89],
+                owasp_categories: Vec::new(),
+                confidence: None,
+            }]);
+        } else {
+            builder = builder.patterns(vec![PatternInfo {
+                pattern_id: "sqli".to_string(),
+                category: "security".to_string(),
+                confidence: 0.9,
+                locations: Vec::new(),
+                outliers: vec![OutlierLocation {
+                    file: "src/a.ts".to_string(),
+                    line,
+                    message: "string-built query".to_string(),
+                    ..Default::default()
+                }],
+                cwe_ids: vec![89],
+                owasp_categories: Vec::new(),
+            }]);
+        }
+        if let Some(source) = source {
+            builder = builder.source_lines(
+                [("src/a.ts".to_string(), source.iter().map(|l| l.to_string()).collect())].into(),
+            );
+        }
+        let mut violations: Vec<Violation> = GateOrchestrator::new()
+            .execute(&builder.build())
+            .unwrap()
+            .into_iter()
+            .flat_map(|r| r.violations)
+            .collect();
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].fingerprint.is_some());
+        violations.remove(0)
+    };
+
+    let before_source = ["const id = req.query.id;", "db.query(sql + id);"];
+    let after_source = ["import db from './db';", "", "const id = req.query.id;", "  db.query(sql + id);"];
+    for security in [false, true] {
+        for (before_source, after_source) in [(Some(&before_source[..]), Some(&after_source[..])), (None, None)] {
+            let before = run(security, before_source, 2, &Baseline::default());
+            let baseline = Baseline::from_violations(std::slice::from_ref(&before), 0);
+            let after = run(security, after_source, 4, &baseline);
+            assert_eq!(violation_fingerprint(&after), violation_fingerprint(&before), "{}", after.rule_id);
+            assert!(!after.is_new, "{}: the shifted finding is still baselined", after.rule_id);
+        }
+    }
+}
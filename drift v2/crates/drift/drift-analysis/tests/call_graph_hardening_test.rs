@@ -78,6 +78,7 @@ fn make_function(name: &str, line: u32, end_line: u32) -> FunctionInfo {
         doc_comment: None,
         body_hash: 0,
         signature_hash: 0,
+        cognitive_complexity: 0,
     }
 }
 
@@ -792,6 +793,7 @@ fn ct_coup_02_abstractness() {
         properties: Vec::new(),
         range: Range::default(),
         decorators: Vec::new(),
+        alias_target: None,
     });
     pr.classes.push(ClassInfo {
         name: "UserService".to_string(),
@@ -806,6 +808,7 @@ fn ct_coup_02_abstractness() {
         properties: Vec::new(),
         range: Range::default(),
         decorators: Vec::new(),
+        alias_target: None,
     });
 
     let graph = ImportGraphBuilder::from_parse_results(&[pr], 1);
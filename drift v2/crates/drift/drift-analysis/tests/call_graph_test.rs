@@ -1,5 +1,5 @@
 #![allow(clippy::field_reassign_with_default, clippy::redundant_closure, clippy::useless_vec, unused_variables, unused_imports)]
-//! Call Graph tests — T2-CG-01 through T2-CG-12.
+//! Call Graph tests — T2-CG-01 through T2-CG-15.
 //!
 //! Tests for the call graph builder: 6 resolution strategies, BFS traversal,
 //! entry point detection, cycle handling, incremental updates, CTE fallback.
@@ -503,3 +503,169 @@ fn t2_cg_12_empty_codebase() {
     assert_eq!(stats.total_edges, 0);
     assert_eq!(stats.entry_points, 0);
 }
+
+// ---- T2-CG-13: Function cycles report SCC members and the edge to break ----
+
+#[test]
+fn t2_cg_13_function_cycles_with_break_suggestions() {
+    use drift_analysis::graph::cycles::function_cycles;
+
+    let source = r#"export function isEven(n: number): boolean {
+    if (n === 0) return true;
+    return isOdd(n - 1);
+}
+
+export function isOdd(n: number): boolean {
+    if (n === 0) return false;
+    return isEven(n - 1);
+}
+
+export function factorial(n: number): number {
+    return n <= 1 ? 1 : n * factorial(n - 1);
+}
+"#;
+    let (graph, _stats) = CallGraphBuilder::new().build(&[parse_file(source, "mutual.ts")]).unwrap();
+
+    // Self-recursion is not a cycle; the mutual recursion is exactly one.
+    let cycles = function_cycles(&graph);
+    assert_eq!(cycles.len(), 1, "{cycles:?}");
+    assert_eq!(cycles[0].members, vec!["mutual.ts::isEven", "mutual.ts::isOdd"]);
+    let best = &cycles[0].break_suggestions[0];
+    assert!(best.breaks_cycle);
+    assert_eq!((best.caller.as_str(), best.callee.as_str()), ("mutual.ts::isEven", "mutual.ts::isOdd"));
+
+    // A→B→C→A plus C→B: removing B→C breaks both loops and carries the most paths.
+    let mut g = CallGraph::new();
+    let node = |name: &str| FunctionNode {
+        file: "m.ts".to_string(),
+        name: name.to_string(),
+        qualified_name: None,
+        language: "typescript".to_string(),
+        line: 0,
+        end_line: 1,
+        is_entry_point: false,
+        is_exported: false,
+        signature_hash: 0,
+        body_hash: 0,
+    };
+    let edge = || CallEdge { resolution: Resolution::SameFile, confidence: 0.95, call_site_line: 0 };
+    let (a, b, c) = (g.add_function(node("a")), g.add_function(node("b")), g.add_function(node("c")));
+    g.add_edge(a, b, edge());
+    g.add_edge(b, c, edge());
+    g.add_edge(c, a, edge());
+    g.add_edge(c, b, edge());
+
+    let cycles = function_cycles(&g);
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(cycles[0].members.len(), 3);
+    let best = &cycles[0].break_suggestions[0];
+    assert_eq!((best.caller.as_str(), best.callee.as_str()), ("m.ts::b", "m.ts::c"));
+    assert!(best.breaks_cycle);
+    assert_eq!(best.score, 4.0);
+    assert!(cycles[0].break_suggestions[1..].iter().all(|s| !s.breaks_cycle));
+}
+
+// ---- T2-CG-14: Incremental rebuild matches a from-scratch build ----
+
+#[test]
+fn t2_cg_14_incremental_rebuild_matches_full_build() {
+    let a1 = parse_file("export function helper() { return 1; }\nexport function format(x: string) { return x; }\n", "a.ts");
+    let a2 = parse_file("export function helper() { return 2; }\nexport function slugify(x: string) { return x; }\n", "a.ts");
+    let b = parse_file(
+        "import { helper, slugify, format } from './a';\nfunction run() {\n    helper();\n    slugify('x');\n    format('y');\n}\nexport function main() { run(); }\n",
+        "b.ts",
+    );
+    let c = parse_file("import { legacy } from './d';\nexport function useLegacy() { return legacy(); }\n", "c.ts");
+    let d = parse_file("export function legacy() { return 0; }\n", "d.ts");
+    let e = parse_file("import { helper } from './a';\nexport function fresh() { return helper(); }\n", "e.ts");
+
+    let builder = CallGraphBuilder::new();
+    let (previous, _) = builder.build(&[a1, b.clone(), c.clone(), d]).unwrap();
+    // a.ts modified, d.ts removed, e.ts added
+    let (incremental, inc_stats) =
+        builder.rebuild_incremental(previous, &[a2.clone(), e.clone()], &["d.ts".to_string()]);
+    let (full, full_stats) = builder.build(&[a2, b, c, e]).unwrap();
+
+    let edges = |g: &CallGraph| {
+        let key = |n: &FunctionNode| format!("{}::{}", n.file, n.name);
+        let mut edges: Vec<(String, String, u32)> = g.graph.edge_indices()
+            .map(|idx| {
+                let (from, to) = g.graph.edge_endpoints(idx).unwrap();
+                (key(&g.graph[from]), key(&g.graph[to]), g.graph[idx].call_site_line)
+            })
+            .collect();
+        edges.sort();
+        edges
+    };
+    let entry_points = |g: &CallGraph| {
+        let mut names: Vec<String> = g.graph.node_indices()
+            .filter(|&i| g.graph[i].is_entry_point)
+            .map(|i| format!("{}::{}", g.graph[i].file, g.graph[i].name))
+            .collect();
+        names.sort();
+        names
+    };
+
+    assert_eq!(edges(&incremental), edges(&full));
+    assert_eq!(entry_points(&incremental), entry_points(&full));
+    assert!(edges(&full).iter().any(|(from, to, _)| from == "b.ts::run" && to == "a.ts::slugify"));
+    assert!(incremental.get_node("d.ts::legacy").is_none());
+
+    assert_eq!(inc_stats.total_functions, full_stats.total_functions);
+    assert_eq!(inc_stats.total_edges, full_stats.total_edges);
+    assert_eq!(inc_stats.entry_points, full_stats.entry_points);
+    assert_eq!(inc_stats.resolution_rate, full_stats.resolution_rate);
+    assert_eq!(inc_stats.resolution_counts, full_stats.resolution_counts);
+    assert_eq!(inc_stats.diagnostics.total_call_sites, full_stats.diagnostics.total_call_sites);
+    assert_eq!(inc_stats.diagnostics.resolved, full_stats.diagnostics.resolved);
+}
+
+// ---- T2-CG-15: GraphML export keeps unresolved calls as phantom nodes ----
+
+#[test]
+fn t2_cg_15_graphml_export() {
+    use drift_analysis::call_graph::export::to_graphml;
+    use quick_xml::events::Event;
+    use quick_xml::Reader;
+
+    let source = r#"
+export function load() {
+    const raw = fetchRemote();
+    return parse(raw);
+}
+function parse(raw: string) {
+    return fetchRemote() + raw.trim();
+}
+"#;
+    let (graph, stats) = CallGraphBuilder::new().build(&[parse_file(source, "loader.ts")]).unwrap();
+    let xml = to_graphml(&graph);
+
+    let mut reader = Reader::from_str(&xml);
+    let (mut nodes, mut edges, mut phantom_values) = (0, 0, Vec::new());
+    let mut in_phantom = false;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) if e.name().as_ref() == b"node" => nodes += 1,
+            Ok(Event::Start(e)) if e.name().as_ref() == b"edge" => edges += 1,
+            Ok(Event::Start(e)) if e.name().as_ref() == b"data" => {
+                in_phantom = e.attributes().flatten().any(|a| a.value.as_ref() == b"phantom");
+            }
+            Ok(Event::Text(t)) if in_phantom => {
+                phantom_values.push(t.unescape().unwrap().into_owned());
+                in_phantom = false;
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => panic!("GraphML is not valid XML: {e}"),
+            _ => {}
+        }
+    }
+
+    // fetchRemote (called twice) and raw.trim have no definition
+    let phantoms = 2;
+    assert_eq!(phantom_values, vec!["true"; phantoms]);
+    assert_eq!(nodes, graph.function_count() + phantoms);
+    assert_eq!(edges, graph.edge_count() + 3);
+    assert_eq!(stats.total_edges, 1);
+    assert!(xml.contains("<data key=\"status\">unresolved</data>"));
+    assert!(xml.contains("<data key=\"name\">fetchRemote</data>"));
+}
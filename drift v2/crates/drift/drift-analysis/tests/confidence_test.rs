@@ -1,4 +1,4 @@
-//! Phase 3 Confidence Tests — T3-BAY-01 through T3-BAY-10.
+//! Phase 3 Confidence Tests — T3-BAY-01 through T3-BAY-12.
 
 use drift_analysis::engine::types::PatternCategory;
 use drift_analysis::patterns::aggregation::types::{AggregatedPattern, PatternLocation};
@@ -34,6 +34,7 @@ fn make_pattern(id: &str, locations: u32, files: u32) -> AggregatedPattern {
         confidence_values: vec![0.9; locations as usize],
         is_dirty: false,
         location_hash: 0,
+        over_limit: false,
     }
 }
 
@@ -311,3 +312,106 @@ fn t3_bay_10_nan_inf_handling() {
     let var = BetaPosterior::posterior_variance(0.0, 0.0);
     assert!(var.is_finite());
 }
+
+// ---- T3-BAY-11: External priors shift the posterior ----
+
+#[test]
+fn t3_bay_11_prior_source_shifts_posterior() {
+    use drift_core::traits::PriorSource;
+
+    struct GroundingPriors;
+    impl PriorSource for GroundingPriors {
+        fn prior(&self, pattern_id: &str) -> Option<(f64, f64)> {
+            match pattern_id {
+                "validated" => Some((20.0, 0.0)),
+                "invalidated" => Some((0.0, 20.0)),
+                "broken" => Some((f64::NAN, f64::INFINITY)),
+                _ => None,
+            }
+        }
+    }
+
+    let patterns: Vec<AggregatedPattern> = ["validated", "invalidated", "unknown", "broken"]
+        .iter()
+        .map(|id| make_pattern(id, 50, 40))
+        .collect();
+    let baseline: std::collections::HashMap<_, _> = ConfidenceScorer::with_defaults()
+        .score_batch(&patterns, None)
+        .into_iter()
+        .collect();
+    let primed: std::collections::HashMap<_, _> = ConfidenceScorer::with_defaults()
+        .with_prior_source(Box::new(GroundingPriors))
+        .score_batch(&patterns, None)
+        .into_iter()
+        .collect();
+
+    let shift = |id: &str| primed[id].posterior_mean - baseline[id].posterior_mean;
+    assert!(shift("validated") > 0.0, "validated prior should raise the posterior");
+    assert!((primed["validated"].alpha - baseline["validated"].alpha - 20.0).abs() < 1e-9);
+    assert!(shift("invalidated") < 0.0, "invalidated prior should lower the posterior");
+    assert!((primed["invalidated"].beta - baseline["invalidated"].beta - 20.0).abs() < 1e-9);
+    assert_eq!(shift("unknown"), 0.0);
+    assert_eq!(shift("broken"), 0.0, "non-finite priors are ignored");
+}
+
+// ---- T3-BAY-12: Incremental scoring reuses unchanged posteriors ----
+
+#[test]
+fn t3_bay_12_incremental_scoring() {
+    use drift_analysis::patterns::aggregation::grouper::compute_location_hash;
+    use drift_analysis::patterns::confidence::scorer::CachedScore;
+    use std::collections::HashMap;
+
+    let with_hash = |mut p: AggregatedPattern| {
+        p.location_hash = compute_location_hash(&p.locations);
+        p
+    };
+    let mut patterns: Vec<AggregatedPattern> = (0..2000)
+        .map(|i| with_hash(make_pattern(&format!("p-{i}"), 10 + i % 50, 1 + i % 9)))
+        .collect();
+    let scorer = ConfidenceScorer::new(ScorerConfig {
+        total_files: 200,
+        ..Default::default()
+    });
+
+    let full = scorer.score_batch(&patterns, None);
+    let cache: HashMap<String, CachedScore> = patterns
+        .iter()
+        .zip(&full)
+        .map(|(p, (id, score))| {
+            let entry = CachedScore { location_hash: p.location_hash, score: score.clone() };
+            (id.clone(), entry)
+        })
+        .collect();
+
+    // Nothing changed (only the dirty flag): identical to a full recompute.
+    for p in &mut patterns {
+        p.is_dirty = true;
+    }
+    let incremental = scorer.score_batch_incremental(&patterns, None, &cache);
+    assert_eq!(incremental.len(), full.len());
+    for ((id, cached), (full_id, score)) in incremental.iter().zip(&full) {
+        assert_eq!(id, full_id);
+        assert_eq!((cached.score.alpha, cached.score.beta), (score.alpha, score.beta));
+        assert_eq!(cached.score.tier, score.tier);
+    }
+
+    // p-3 gains locations: rescored from its current locations alone, so the
+    // result is the full recompute's, with no prior evidence counted twice.
+    patterns[3] = with_hash(make_pattern("p-3", 40, 8));
+    let rescored = scorer.score_batch(&patterns, None);
+    let updated = scorer.score_batch_incremental(&patterns, None, &cache);
+    let (_, p3) = updated.iter().find(|(id, _)| id == "p-3").unwrap();
+    let (_, fresh) = rescored.iter().find(|(id, _)| id == "p-3").unwrap();
+    assert_eq!(p3.location_hash, patterns[3].location_hash);
+    assert_eq!((p3.score.alpha, p3.score.beta), (fresh.alpha, fresh.beta));
+
+    // Everything else is still the cached score, which only misses the
+    // small shift in the category total, so it stays close to score_batch.
+    let (_, p4) = updated.iter().find(|(id, _)| id == "p-4").unwrap();
+    assert_eq!(p4.score.alpha, cache["p-4"].score.alpha);
+    for ((id, cached), (_, score)) in updated.iter().zip(&rescored) {
+        let drift = (cached.score.posterior_mean - score.posterior_mean).abs();
+        assert!(drift < 1e-3, "{id}: incremental mean drifts {drift} from score_batch");
+    }
+}
@@ -1,4 +1,4 @@
-//! Phase 5 constraint system tests (T5-CON-01 through T5-CON-06).
+//! Phase 5 constraint system tests (T5-CON-01 through T5-CON-07).
 
 use drift_analysis::structural::constraints::types::*;
 use drift_analysis::structural::constraints::detector::{InvariantDetector, FunctionInfo};
@@ -171,3 +171,68 @@ fn test_empty_codebase_constraints() {
     // must_not_exist should pass on empty codebase
     assert!(result_not_exist.passed);
 }
+
+/// T5-CON-07: `pkg:` dependency rules resolve files and imports to monorepo packages.
+#[test]
+fn test_package_dependency_direction() {
+    use drift_core::workspace::monorepo::{detect_workspace, WorkspaceLayout};
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let root = dir.path();
+    std::fs::write(root.join("package.json"), r#"{"name": "acme", "workspaces": ["packages/*"]}"#).unwrap();
+    for package in ["billing", "checkout"] {
+        std::fs::create_dir_all(root.join("packages").join(package).join("src")).unwrap();
+        std::fs::write(
+            root.join("packages").join(package).join("package.json"),
+            format!(r#"{{"name": "@acme/{package}"}}"#),
+        )
+        .unwrap();
+    }
+    let WorkspaceLayout::Monorepo { packages, .. } = detect_workspace(root).unwrap() else {
+        panic!("npm workspace should be detected as a monorepo");
+    };
+
+    let mut detector = InvariantDetector::new();
+    detector.add_file(
+        "packages/billing/src/invoice.ts",
+        vec![],
+        vec!["./tax".into(), "../../checkout/src/cart".into()],
+        40,
+    );
+    detector.add_file("packages/billing/src/refunds/issue.ts", vec![], vec!["@acme/checkout".into()], 20);
+    detector.add_file("packages/billing/src/tax.ts", vec![], vec!["lodash".into()], 10);
+    detector.add_file("packages/checkout/src/cart.ts", vec![], vec!["@acme/billing".into()], 30);
+
+    let constraint = |target: &str| Constraint {
+        id: "billing-not-checkout".into(),
+        description: "billing must not depend on checkout".into(),
+        invariant_type: InvariantType::DependencyDirection,
+        target: target.into(),
+        scope: None,
+        source: ConstraintSource::Manual,
+        enabled: true,
+    };
+
+    // Without package partitioning nothing maps to a package.
+    assert!(detector.verify(&constraint("pkg:billing!->pkg:checkout")).passed);
+
+    detector.set_packages(&packages);
+    let result = detector.verify(&constraint("pkg:billing!->pkg:checkout"));
+    assert!(!result.passed);
+    let mut actual: Vec<&str> = result.violations.iter().map(|v| v.actual.as_str()).collect();
+    actual.sort();
+    assert_eq!(
+        actual,
+        vec![
+            "packages/billing/src/invoice.ts imports ../../checkout/src/cart",
+            "packages/billing/src/refunds/issue.ts imports @acme/checkout",
+        ]
+    );
+
+    // "checkout may depend on billing, not the reverse" forbids the same edge.
+    assert_eq!(detector.verify(&constraint("pkg:checkout->pkg:billing")).violations.len(), 2);
+    // The opposite rule catches only checkout's import of billing.
+    let reverse = detector.verify(&constraint("pkg:checkout!->pkg:billing"));
+    assert_eq!(reverse.violations.len(), 1);
+    assert_eq!(reverse.violations[0].file, "packages/checkout/src/cart.ts");
+}
@@ -680,6 +680,7 @@ fn make_parse_result_with_func(file: &str, func_name: &str, line: u32, end_line:
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            cognitive_complexity: 0,
         }],
         ..Default::default()
     }
@@ -1,7 +1,8 @@
-//! Phase 5 contract tracking tests (T5-CTR-01 through T5-CTR-08).
+//! Phase 5 contract tracking tests (T5-CTR-01 through T5-CTR-09).
 
 use drift_analysis::structural::contracts::types::*;
 use drift_analysis::structural::contracts::breaking_changes::classify_breaking_changes;
+use drift_analysis::structural::contracts::client_auth::ClientOnlyAuthCheck;
 use drift_analysis::structural::contracts::confidence::bayesian_confidence;
 use drift_analysis::structural::contracts::matching::match_contracts;
 
@@ -240,3 +241,61 @@ fn test_mismatch_types() {
     ];
     assert_eq!(types.len(), 7);
 }
+
+/// T5-CTR-09: Admin action guarded only by a client role check is linked to
+/// its unprotected server handler; the protected admin route is not flagged.
+#[test]
+fn test_client_only_role_check() {
+    let client = r#"
+async function deleteUser(id) {
+  await fetch(`/api/admin/users/${id}`, { method: 'DELETE' });
+}
+
+export function UserRow({ user, currentUser }) {
+  if (currentUser.isAdmin) loadStats();
+  return (
+    <div>
+      {currentUser.isAdmin && (
+        <button onClick={() => deleteUser(user.id)}>Delete</button>
+      )}
+    </div>
+  );
+}
+
+function loadStats() {
+  return fetch('/api/admin/stats');
+}
+"#;
+    let server = r#"
+const express = require('express');
+const router = express.Router();
+
+router.delete('/api/admin/users/:id', async (req, res) => {
+  await User.deleteOne({ _id: req.params.id });
+  res.sendStatus(204);
+});
+
+router.get('/api/admin/stats', requireAdmin, (req, res) => res.json(stats()));
+"#;
+    let files = [("src/UserRow.jsx", client), ("server/admin.js", server)];
+    let findings = ClientOnlyAuthCheck::default().detect(&files);
+
+    assert_eq!(findings.len(), 1, "only the unprotected route: {findings:?}");
+    let finding = &findings[0];
+    assert_eq!(finding.server_handler.method, "DELETE");
+    assert_eq!(finding.server_handler.path, "/api/admin/users/:id");
+    assert_eq!(finding.server_handler.file, "server/admin.js");
+    assert_eq!(finding.client_check.file, "src/UserRow.jsx");
+    assert_eq!(finding.client_check.line, 10);
+    assert!(finding.client_check.condition.contains("isAdmin"));
+
+    let pm = finding.to_pattern_match();
+    assert_eq!(pm.pattern_id, "AUTH-CLIENT-005");
+    assert_eq!(pm.cwe_ids[0], 602);
+    assert_eq!(pm.line, 5);
+
+    // Adding auth middleware on the server clears the finding.
+    let protected = server.replace("router.delete('/api/admin/users/:id', async", "router.delete('/api/admin/users/:id', requireAuth, async");
+    let files = [("src/UserRow.jsx", client), ("server/admin.js", protected.as_str())];
+    assert!(ClientOnlyAuthCheck::default().detect(&files).is_empty());
+}
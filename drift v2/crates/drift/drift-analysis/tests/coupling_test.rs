@@ -1,10 +1,12 @@
 #![allow(clippy::field_reassign_with_default, unused_imports)]
-//! Phase 5 coupling analysis tests (T5-CPL-01 through T5-CPL-07).
+//! Phase 5 coupling analysis tests (T5-CPL-01 through T5-CPL-08).
 
 use drift_analysis::structural::coupling::types::*;
 use drift_analysis::structural::coupling::martin_metrics::compute_martin_metrics;
 use drift_analysis::structural::coupling::cycle_detection::detect_cycles;
 use drift_analysis::structural::coupling::zones::classify_zone;
+use drift_analysis::structural::coupling::file_cycles::detect_file_cycles;
+use drift_analysis::parsers::manager::ParserManager;
 
 /// T5-CPL-01: Martin metrics computed correctly on known module graph.
 #[test]
@@ -146,3 +148,43 @@ fn test_single_module_graph() {
     let cycles = detect_cycles(&graph);
     assert!(cycles.is_empty());
 }
+
+/// T5-CPL-08: Mutual imports between two files in one module are a file cycle.
+#[test]
+fn test_file_level_circular_imports() {
+    let parser = ParserManager::new();
+    let parse = |file: &str, source: &str| parser.parse(source.as_bytes(), std::path::Path::new(file)).unwrap();
+    let results = vec![
+        parse(
+            "src/a.ts",
+            "import { helper } from './b';\nexport const config = { name: 'a' };\nexport function run() { return helper(); }\n",
+        ),
+        parse(
+            "src/b.ts",
+            "import { format } from 'util';\nimport { config } from './a.js';\nexport function helper() { return config.name; }\n",
+        ),
+        // Imports into the cycle without being part of it
+        parse("src/main.ts", "import { run } from './a';\nrun();\n"),
+        // Type-only imports are erased before runtime
+        parse("src/types.ts", "import type { Shape } from './shapes';\nexport type Id = string;\n"),
+        parse("src/shapes.ts", "import { Id } from './types';\nexport interface Shape { id: Id }\n"),
+    ];
+
+    let cycles = detect_file_cycles(&results);
+    assert_eq!(cycles.len(), 1, "{cycles:?}");
+    let cycle = &cycles[0];
+    assert_eq!(cycle.files, vec!["src/a.ts", "src/b.ts"]);
+    assert_eq!(
+        cycle.back_edges,
+        vec![FileImport {
+            from: "src/b.ts".into(),
+            to: "src/a.ts".into(),
+            line: 1,
+            source: "./a.js".into(),
+        }]
+    );
+
+    // The module-level graph folds both files into `src` and sees no cycle
+    let graph = drift_analysis::structural::coupling::ImportGraphBuilder::from_parse_results(&results, 1);
+    assert!(detect_cycles(&graph).is_empty());
+}
@@ -217,6 +217,7 @@ fn engine_resolution_resolve() {
         is_exported: true, is_async: false, is_generator: false, is_abstract: false,
         range: Range::default(), decorators: vec![], doc_comment: None,
         body_hash: 0, signature_hash: 0,
+        cognitive_complexity: 0,
     });
     pr.classes.push(ClassInfo {
         name: "UserService".to_string(), namespace: None, extends: None,
@@ -233,9 +234,11 @@ fn engine_resolution_resolve() {
             is_exported: false, is_async: false, is_generator: false, is_abstract: false,
             range: Range::default(), decorators: vec![], doc_comment: None,
             body_hash: 0, signature_hash: 0,
+            cognitive_complexity: 0,
         }],
         properties: vec![],
         range: Range::default(), decorators: vec![],
+        alias_target: None,
     });
     pr.imports.push(ImportInfo {
         source: "./utils".to_string(),
@@ -261,6 +264,7 @@ fn engine_resolution_resolve() {
         is_exported: true, is_async: false, is_generator: false, is_abstract: false,
         range: Range::default(), decorators: vec![], doc_comment: None,
         body_hash: 0, signature_hash: 0,
+        cognitive_complexity: 0,
     });
 
     let index = ResolutionIndex::build(&[pr, pr2]);
@@ -387,6 +391,7 @@ fn cg_di_support() {
         range: Range::default(),
         decorators: vec![DecoratorInfo { name: "Injectable".to_string(), arguments: SmallVec::new(), raw_text: "@Injectable()".to_string(), range: Range::default() }],
         doc_comment: None, body_hash: 0, signature_hash: 0,
+        cognitive_complexity: 0,
     });
 
     let detected = di_support::detect_di_frameworks(&[pr]);
@@ -644,6 +649,7 @@ fn extractor_ef_core_with_data() {
         ],
         range: Range { start: Position { line: 5, column: 0 }, end: Position { line: 20, column: 0 } },
         decorators: vec![],
+        alias_target: None,
     });
     let models = ext.extract_models(&pr);
     assert_eq!(models.len(), 1);
@@ -674,6 +680,7 @@ fn extractor_mongoose_with_data() {
         ],
         range: Range { start: Position { line: 3, column: 0 }, end: Position { line: 10, column: 0 } },
         decorators: vec![],
+        alias_target: None,
     });
     let models = ext.extract_models(&pr);
     assert_eq!(models.len(), 1);
@@ -702,6 +709,7 @@ fn extractor_prisma_with_data() {
         ],
         range: Range { start: Position { line: 5, column: 0 }, end: Position { line: 10, column: 0 } },
         decorators: vec![],
+        alias_target: None,
     });
     let models = ext.extract_models(&pr);
     assert_eq!(models.len(), 1);
@@ -725,6 +733,7 @@ fn extractor_typeorm_with_data() {
         ],
         range: Range { start: Position { line: 3, column: 0 }, end: Position { line: 10, column: 0 } },
         decorators: vec![DecoratorInfo { name: "Entity".to_string(), arguments: SmallVec::new(), raw_text: "@Entity()".to_string(), range: Range::default() }],
+        alias_target: None,
     });
     let models = ext.extract_models(&pr);
     assert_eq!(models.len(), 1);
@@ -748,6 +757,7 @@ fn extractor_hibernate_with_data() {
         ],
         range: Range { start: Position { line: 5, column: 0 }, end: Position { line: 20, column: 0 } },
         decorators: vec![DecoratorInfo { name: "Entity".to_string(), arguments: SmallVec::new(), raw_text: "@Entity".to_string(), range: Range::default() }],
+        alias_target: None,
     });
     let models = ext.extract_models(&pr);
     assert_eq!(models.len(), 1);
@@ -772,6 +782,7 @@ fn extractor_eloquent_with_data() {
         ],
         range: Range { start: Position { line: 5, column: 0 }, end: Position { line: 15, column: 0 } },
         decorators: vec![],
+        alias_target: None,
     });
     let models = ext.extract_models(&pr);
     assert_eq!(models.len(), 1);
@@ -795,6 +806,7 @@ fn extractor_active_record_with_data() {
         ],
         range: Range { start: Position { line: 1, column: 0 }, end: Position { line: 5, column: 0 } },
         decorators: vec![],
+        alias_target: None,
     });
     let models = ext.extract_models(&pr);
     assert_eq!(models.len(), 1);
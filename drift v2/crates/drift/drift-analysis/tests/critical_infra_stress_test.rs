@@ -953,9 +953,12 @@ fn critical_sarif_line_zero_clamped() {
             rule_id: "test/rule".to_string(), message: "test".to_string(),
             quick_fix: None, cwe_id: None, owasp_category: None,
             suppressed: false, is_new: false,
+            fingerprint: None,
         }],
         warnings: vec![], execution_time_ms: 0,
         details: serde_json::Value::Null, error: None,
+        explanation: None,
+        advisories: Vec::new(),
     }];
 
     let output = reporter.generate(&results).unwrap();
@@ -984,9 +987,12 @@ fn critical_sarif_both_cwe_and_owasp() {
             cwe_id: Some(89),
             owasp_category: Some("A03:2021-Injection".to_string()),
             suppressed: false, is_new: false,
+            fingerprint: None,
         }],
         warnings: vec![], execution_time_ms: 0,
         details: serde_json::Value::Null, error: None,
+        explanation: None,
+        advisories: Vec::new(),
     }];
 
     let output = reporter.generate(&results).unwrap();
@@ -1018,6 +1024,7 @@ fn critical_sarif_rule_deduplication() {
         message: format!("Violation {i}"),
         quick_fix: None, cwe_id: None, owasp_category: None,
         suppressed: false, is_new: false,
+        fingerprint: None,
     }).collect();
 
     let results = vec![GateResult {
@@ -1026,6 +1033,8 @@ fn critical_sarif_rule_deduplication() {
         summary: "test".to_string(), violations,
         warnings: vec![], execution_time_ms: 0,
         details: serde_json::Value::Null, error: None,
+        explanation: None,
+        advisories: Vec::new(),
     }];
 
     let output = reporter.generate(&results).unwrap();
@@ -1123,6 +1132,7 @@ fn critical_is_new_flag_preserved() {
         rule_id: "test/new".to_string(), message: "New violation".to_string(),
         quick_fix: None, cwe_id: None, owasp_category: None,
         suppressed: false, is_new: true,
+        fingerprint: None,
     };
 
     // Through JSON serialization
@@ -1,5 +1,6 @@
-//! Phase 5 cryptographic failure detection tests (T5-CRY-01 through T5-CRY-06).
+//! Phase 5 cryptographic failure detection tests (T5-CRY-01 through T5-CRY-07).
 
+use drift_analysis::enforcement::rules::FixRange;
 use drift_analysis::structural::crypto::types::*;
 use drift_analysis::structural::crypto::detector::CryptoDetector;
 use drift_analysis::structural::crypto::patterns::{CRYPTO_PATTERNS, patterns_for_language, patterns_for_category, CRYPTO_IMPORT_INDICATORS};
@@ -86,6 +87,8 @@ fn test_crypto_health_score() {
             owasp: "A02:2025".into(),
             remediation: "Use key vault".into(),
             language: "python".into(),
+            suggested_replacement: None,
+            range: None,
         },
     ];
     let critical_health = calculate_crypto_health(&critical_findings);
@@ -106,6 +109,8 @@ fn test_confidence_scoring() {
         owasp: "A02:2025".into(),
         remediation: "Use SHA-256".into(),
         language: "python".into(),
+        suggested_replacement: None,
+        range: None,
     };
 
     let file_content = "import hashlib\nhash = hashlib.md5(data)";
@@ -136,6 +141,58 @@ fn test_remediation_suggestions() {
     }
 }
 
+/// T5-CRY-07: Weak hashes and ciphers carry a per-language replacement;
+/// drop-in swaps also carry the span of the identifier it replaces.
+#[test]
+fn test_algorithm_fix_suggestions() {
+    let detector = CryptoDetector::new();
+    let cases: &[(&str, &str, &str, &str, bool)] = &[
+        // (language, source, weak identifier, replacement, machine-applicable)
+        ("javascript", "const crypto = require('crypto');\nconst h = crypto.createHash('md5');", "md5", "sha256", true),
+        ("typescript", "import * as crypto from 'crypto';\nconst h = crypto.createHash('sha1');", "sha1", "sha256", true),
+        ("javascript", "const crypto = require('crypto');\nconst clé = crypto.createHash('md5');", "md5", "sha256", true),
+        ("java", "import java.security.MessageDigest;\nMessageDigest md = MessageDigest.getInstance(\"MD5\");", "MD5", "SHA-256", true),
+        ("java", "import java.security.MessageDigest;\nMessageDigest md = MessageDigest.getInstance(\"SHA-1\");", "SHA-1", "SHA-256", true),
+        ("java", "import javax.crypto.Cipher;\nCipher c = Cipher.getInstance(\"DES/CBC/PKCS5Padding\");", "DES/CBC/PKCS5Padding", "AES/GCM/NoPadding", false),
+        ("python", "import hashlib\nh = hashlib.md5(data)", "md5", "sha256", true),
+        ("python", "import hashlib\nh = hashlib.sha1(data)", "sha1", "sha256", true),
+        ("python", "from Crypto.Cipher import DES\ncipher = DES.new(key)", "DES", "AES", false),
+        ("csharp", "using System.Security.Cryptography;\nvar h = MD5.Create();", "MD5", "SHA256", true),
+        ("csharp", "using System.Security.Cryptography;\nvar d = new DESCryptoServiceProvider();", "DESCryptoServiceProvider", "AesGcm", false),
+        ("php", "<?php\n$h = md5($password);", "md5(", "hash('sha256', ", true),
+        ("ruby", "require 'digest'\nh = Digest::MD5.hexdigest(s)", "MD5", "SHA256", true),
+        ("go", "import \"crypto/md5\"\nsum := md5.Sum(data)", "md5.Sum", "sha256.Sum256", false),
+        ("go", "import \"crypto/des\"\nblock, _ := des.NewCipher(key)", "des.NewCipher", "aes.NewCipher", false),
+        ("rust", "fn hash_password(pw: &[u8]) {\n    let mut hasher = Md5::new();", "Md5", "Sha256", false),
+        ("swift", "import CommonCrypto\nCC_SHA1(ptr, len, &digest)", "CC_SHA1", "CC_SHA256", false),
+    ];
+
+    for &(language, source, weak, replacement, applicable) in cases {
+        let findings = detector.detect(source, "file", language);
+        let fix = findings.iter()
+            .find(|f| f.line == 2 && f.suggested_replacement.is_some())
+            .unwrap_or_else(|| panic!("{language}: no fix for `{weak}` in {findings:?}"));
+        assert_eq!(fix.suggested_replacement.as_deref(), Some(replacement), "{language}: {weak}");
+        if !applicable {
+            assert_eq!(fix.range, None, "{language}: `{weak}` swap is advisory");
+            continue;
+        }
+        let line = source.lines().nth(1).unwrap();
+        // Columns are byte offsets within the line
+        let start = line.find(weak).unwrap() as u32;
+        assert_eq!(
+            fix.range,
+            Some(FixRange { start_line: 2, start_column: start, end_line: 2, end_column: start + weak.len() as u32 }),
+            "{language}: range should cover the identifier"
+        );
+    }
+
+    // Findings outside weak hashes and ciphers carry no fix
+    let findings = detector.detect("import random\ntoken = random.random()", "t.py", "python");
+    assert!(!findings.is_empty());
+    assert!(findings.iter().all(|f| f.suggested_replacement.is_none() && f.range.is_none()));
+}
+
 /// T5-CRY-04 extended: CWE IDs for all categories.
 #[test]
 fn test_category_cwe_ids() {
@@ -1,4 +1,4 @@
-//! Detector tests — T2-DET-01 through T2-DET-08.
+//! Detector tests — T2-DET-01 through T2-DET-41.
 //!
 //! Tests for the detector system: 16 categories, registry, enable/disable,
 //! panic safety, false-positive rate, CWE/OWASP mapping.
@@ -415,3 +415,1646 @@ fn t2_det_08_all_16_categories_registered() {
         );
     }
 }
+
+// ---- T2-DET-09: Concatenated logger messages are flagged, parameterized ones are not ----
+
+#[test]
+fn t2_det_09_log_concatenation() {
+    use drift_analysis::detectors::logging::concatenation::LogConcatenationCheck;
+    use drift_analysis::detectors::logging::LoggingDetector;
+
+    let source = r#"
+public class UserService {
+    private static final Logger log = LoggerFactory.getLogger(UserService.class);
+
+    public void update(String name) {
+        log.info("user " + name + " did X");
+        log.info("user {} did X", name);
+        log.debug("count: " + count(name), name);
+        LOGGER.warn("plain message");
+    }
+}
+"#;
+    let (pr, bytes) = make_context_from_source(source, "UserService.java");
+    let ctx = make_detection_context(&pr, &bytes);
+
+    let matches: Vec<_> = LoggingDetector::default()
+        .detect(&ctx)
+        .into_iter()
+        .filter(|m| m.pattern_id == "LOG-CONCAT-004")
+        .collect();
+    let lines: Vec<u32> = matches.iter().map(|m| m.line).collect();
+    assert_eq!(lines, vec![5, 7], "only concatenated messages should be flagged: {:?}", matches);
+    assert!(matches.iter().all(|m| m.category == PatternCategory::Logging));
+
+    // The logger method set is configurable.
+    let info_only = LogConcatenationCheck::new(&["info"]);
+    let lines: Vec<u32> = info_only.detect(&ctx).iter().map(|m| m.line).collect();
+    assert_eq!(lines, vec![5]);
+
+    // ...including from `[analysis.detectors]`, through the registry.
+    let options = drift_core::config::DetectorOptions {
+        logger_methods: vec!["debug".to_string()],
+        ..Default::default()
+    };
+    let lines: Vec<u32> = drift_analysis::detectors::registry::create_registry(&options)
+        .run_all(&ctx)
+        .iter()
+        .filter(|m| m.pattern_id == "LOG-CONCAT-004")
+        .map(|m| m.line)
+        .collect();
+    assert_eq!(lines, vec![7]);
+}
+
+// ---- T2-DET-10: Fast hashes used for password storage (CWE-916) ----
+
+#[test]
+fn t2_det_10_fast_password_hash() {
+    use drift_analysis::detectors::security::password_hash::PasswordHashCheck;
+
+    let password_hashes = |source: &str, file: &str| -> Vec<PatternMatch> {
+        let (pr, bytes) = make_context_from_source(source, file);
+        let ctx = make_detection_context(&pr, &bytes);
+        PasswordHashCheck.detect(&ctx)
+    };
+
+    // Demo TypeScript: MD5 inside hashPassword, result stored in the password column.
+    let ts = r#"
+function hashPassword(password: string): string {
+    const crypto = require('crypto');
+    return crypto.createHash('md5').update(password).digest('hex');
+}
+
+function fileChecksum(data: string): string {
+    return crypto.createHash('md5').update(data).digest('hex');
+}
+
+export class UserService {
+    async createUser(name: string, email: string, ssn: string) {
+        const hashedPw = hashPassword('default');
+        return db.query('INSERT INTO users (name, email, ssn, password) VALUES (?, ?, ?, ?)',
+            [name, email, ssn, hashedPw]);
+    }
+}
+"#;
+    let matches = password_hashes(ts, "user_service.ts");
+    assert_eq!(matches.len(), 1, "only the password hash should be flagged: {:?}", matches);
+    assert_eq!(matches[0].line, 3);
+    assert_eq!(matches[0].pattern_id, "SEC-PWHASH-001");
+    assert_eq!(matches[0].cwe_ids[0], 916);
+    assert!(matches[0].matched_text.contains("md5"));
+
+    // Demo Go: md5.Sum inside hashPassword.
+    let go = r#"
+package main
+
+func hashPassword(password string) string {
+    h := md5.Sum([]byte(password))
+    return fmt.Sprintf("%x", h)
+}
+"#;
+    let lines: Vec<u32> = password_hashes(go, "main.go").iter().map(|m| m.line).collect();
+    assert_eq!(lines, vec![4]);
+
+    // Assignment into a password field, outside a password-named function.
+    let py = r#"
+import hashlib
+
+class User:
+    def save(self, pw):
+        self.password_hash = hashlib.sha256(pw.encode()).hexdigest()
+        self.etag = hashlib.sha256(self.name.encode()).hexdigest()
+"#;
+    let lines: Vec<u32> = password_hashes(py, "user.py").iter().map(|m| m.line).collect();
+    assert_eq!(lines, vec![5]);
+
+    // A proper KDF in a password function is not flagged.
+    let kdf = r#"
+function hashPassword(password: string): string {
+    return crypto.pbkdf2Sync(password, salt, 310000, 32, 'sha256').toString('hex');
+}
+"#;
+    assert!(password_hashes(kdf, "kdf.ts").is_empty());
+}
+
+// ---- T2-DET-11: Unbounded queries in request handlers ----
+
+#[test]
+fn t2_det_11_unbounded_query_in_handler() {
+    use drift_analysis::detectors::performance::pagination::UnboundedQueryCheck;
+
+    let source = r#"
+export async function listUsers(req, res) {
+    const users = await User.findAll();
+    res.json(users);
+}
+
+export async function pagedUsers(req, res) {
+    const users = await User.findAll({ limit: 20, offset: req.query.offset });
+    const all = await Promise.all([users]);
+    res.json(all);
+}
+
+export async function rawUsers(req, res) {
+    const rows = await db.query('SELECT * FROM users');
+    const one = await db.query('SELECT * FROM users WHERE id = ?', [req.params.id]);
+    res.json(rows.concat(one));
+}
+
+function seedCache() {
+    return User.findAll();
+}
+"#;
+    let (pr, bytes) = make_context_from_source(source, "users.ts");
+    let ctx = make_detection_context(&pr, &bytes);
+
+    let matches = UnboundedQueryCheck::default().detect(&ctx);
+    let mut lines: Vec<u32> = matches.iter().map(|m| m.line).collect();
+    lines.sort();
+    assert_eq!(lines, vec![2, 13], "only unbounded handler queries should be flagged: {:?}", matches);
+    assert!(matches.iter().all(|m| m.pattern_id == "PERF-UNBOUNDED-004"
+        && m.category == PatternCategory::Performance));
+
+    // Framework-specific sinks are configurable.
+    let custom = r#"
+export async function listOrders(req, res) {
+    res.json(await Order.scan());
+}
+"#;
+    let (pr, bytes) = make_context_from_source(custom, "orders.ts");
+    let ctx = make_detection_context(&pr, &bytes);
+    assert!(UnboundedQueryCheck::default().detect(&ctx).is_empty());
+    let dynamo = UnboundedQueryCheck::default().with_sinks(&["scan"]);
+    assert_eq!(dynamo.detect(&ctx).len(), 1);
+
+    // ...including from `[analysis.detectors]`, through the performance detector.
+    use drift_analysis::detectors::performance::PerformanceDetector;
+    let unbounded = |detector: &PerformanceDetector| {
+        detector.detect(&ctx).iter().filter(|m| m.pattern_id == "PERF-UNBOUNDED-004").count()
+    };
+    assert_eq!(unbounded(&PerformanceDetector::default()), 0);
+    let options = drift_core::config::DetectorOptions {
+        fetch_all_sinks: vec!["scan".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(unbounded(&PerformanceDetector::new(&options)), 1);
+}
+
+// ---- T2-DET-12: test_support::run_detector one-liner ----
+
+#[test]
+fn t2_det_12_run_detector_helper() {
+    use drift_analysis::detectors::security::SecurityDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let matches = run_detector(&SecurityDetector::default(), "const out = eval(userInput);\n", Language::JavaScript);
+    assert!(matches.iter().any(|m| m.pattern_id == "SEC-EVAL-001" && m.line == 0));
+
+    let clean = run_detector(&SecurityDetector::default(), "const out = JSON.parse(userInput);\n", Language::JavaScript);
+    assert!(clean.iter().all(|m| m.pattern_id != "SEC-EVAL-001"));
+}
+
+// ---- T2-DET-13: environment-keyed branches that change security behavior ----
+
+#[test]
+fn t2_det_13_env_branch_with_security_call() {
+    use drift_analysis::detectors::config::ConfigDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let source = "if (DEBUG) { disableAuth() }\n";
+    let matches = run_detector(&ConfigDetector, source, Language::JavaScript);
+    let hit = matches
+        .iter()
+        .find(|m| m.pattern_id == "CFG-ENV-BRANCH-004")
+        .expect("DEBUG branch disabling auth should be flagged");
+    assert_eq!(hit.line, 0);
+    assert!(hit.matched_text.contains("if (DEBUG)"), "{}", hit.matched_text);
+    assert!(hit.matched_text.contains("disableAuth"), "{}", hit.matched_text);
+
+    let node_env = r#"
+function setup(app) {
+    if (process.env.NODE_ENV !== 'production') {
+        app.use(skipCsrfCheck());
+    }
+}
+"#;
+    let matches = run_detector(&ConfigDetector, node_env, Language::TypeScript);
+    assert!(matches.iter().any(|m| m.pattern_id == "CFG-ENV-BRANCH-004" && m.line == 2));
+
+    let python = "if settings.DEBUG:\n    disable_csrf_verification()\nlogin(user)\n";
+    let matches = run_detector(&ConfigDetector, python, Language::Python);
+    assert!(matches.iter().any(|m| m.pattern_id == "CFG-ENV-BRANCH-004" && m.line == 0));
+
+    // Benign debug logging and security calls outside the branch are not flagged.
+    let benign = "if (DEBUG) { console.log('state') }\ndisableAuth()\n";
+    let matches = run_detector(&ConfigDetector, benign, Language::JavaScript);
+    assert!(matches.iter().all(|m| m.pattern_id != "CFG-ENV-BRANCH-004"));
+}
+
+// ---- T2-DET-14: fetch-by-request-id without an ownership check (IDOR) ----
+
+#[test]
+fn t2_det_14_idor_fetch_by_request_id() {
+    use drift_analysis::detectors::auth::AuthDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let unchecked = r#"
+export async function getDocument(req, res) {
+    const doc = await Document.findById(req.params.id);
+    res.json(doc);
+}
+"#;
+    let matches = run_detector(&AuthDetector, unchecked, Language::TypeScript);
+    let hit = matches
+        .iter()
+        .find(|m| m.pattern_id == "AUTH-IDOR-004")
+        .expect("fetch by request id without an ownership check should be flagged");
+    assert_eq!(hit.line, 2);
+    assert_eq!(hit.cwe_ids.first(), Some(&639));
+    assert!(hit.confidence < 0.6, "IDOR matches are advisory");
+
+    let checked = r#"
+export async function getDocument(req, res) {
+    const doc = await Document.findById(req.params.id);
+    checkOwner(doc, req.user);
+    res.json(doc);
+}
+"#;
+    let matches = run_detector(&AuthDetector, checked, Language::TypeScript);
+    assert!(matches.iter().all(|m| m.pattern_id != "AUTH-IDOR-004"));
+
+    // Scoping the query to the current user is an ownership check too.
+    let scoped = r#"
+export async function getDocument(req, res) {
+    const doc = await Document.findOne({ _id: req.params.id, owner: req.user.id });
+    res.json(doc);
+}
+"#;
+    let matches = run_detector(&AuthDetector, scoped, Language::TypeScript);
+    assert!(matches.iter().all(|m| m.pattern_id != "AUTH-IDOR-004"));
+
+    // Generic verbs are fetches only on a model class or a repository.
+    let generic = r#"
+export async function getDocument(req, res) {
+    const cached = cache.get(req.params.id);
+    const doc = await this.documentRepo.load(req.params.id);
+    res.json(cached || doc);
+}
+"#;
+    let matches = run_detector(&AuthDetector, generic, Language::TypeScript);
+    let lines: Vec<u32> = matches
+        .iter()
+        .filter(|m| m.pattern_id == "AUTH-IDOR-004")
+        .map(|m| m.line)
+        .collect();
+    assert_eq!(lines, vec![3]);
+}
+
+// ---- T2-DET-15: outbound calls without a timeout ----
+
+#[test]
+fn t2_det_15_outbound_call_without_timeout() {
+    use drift_analysis::detectors::performance::PerformanceDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let timeouts_with = |detector: &PerformanceDetector, source: &str, language: Language| -> Vec<PatternMatch> {
+        run_detector(detector, source, language)
+            .into_iter()
+            .filter(|m| m.pattern_id == "PERF-TIMEOUT-005")
+            .collect()
+    };
+    let timeouts = |source: &str, language: Language| timeouts_with(&PerformanceDetector::default(), source, language);
+
+    let flagged = timeouts("import requests\nresp = requests.get(url)\n", Language::Python);
+    assert_eq!(flagged.len(), 1);
+    assert_eq!(flagged[0].line, 1);
+    assert_eq!(flagged[0].cwe_ids.first(), Some(&1088));
+
+    assert!(timeouts("import requests\nresp = requests.get(url, timeout=5)\n", Language::Python).is_empty());
+
+    // fetch with an abort signal is bounded; bare fetch is not.
+    assert_eq!(timeouts("const r = await fetch(url);\n", Language::JavaScript).len(), 1);
+    assert!(timeouts(
+        "const r = await fetch(url, { signal: AbortSignal.timeout(5000) });\n",
+        Language::JavaScript
+    )
+    .is_empty());
+
+    // Go clients must set Timeout.
+    let go = r#"package main
+
+import "net/http"
+
+var slow = &http.Client{}
+var fast = &http.Client{Timeout: 5 * time.Second}
+"#;
+    let flagged = timeouts(go, Language::Go);
+    assert_eq!(flagged.len(), 1);
+    assert_eq!(flagged[0].line, 4);
+
+    // Extra clients come from `[analysis.detectors]`.
+    let ky = "const r = await ky.get(url);\n";
+    assert!(timeouts(ky, Language::JavaScript).is_empty());
+    let options = drift_core::config::DetectorOptions {
+        outbound_calls: vec!["ky.get".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(timeouts_with(&PerformanceDetector::new(&options), ky, Language::JavaScript).len(), 1);
+}
+
+// ---- T2-DET-16: unrestricted file upload ----
+
+#[test]
+fn t2_det_16_unvalidated_upload_with_request_filename() {
+    use drift_analysis::detectors::security::SecurityDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let uploads = |source: &str, language: Language| -> Vec<PatternMatch> {
+        run_detector(&SecurityDetector::default(), source, language)
+            .into_iter()
+            .filter(|m| m.pattern_id == "SEC-UPLOAD-001")
+            .collect()
+    };
+
+    let unvalidated = r#"
+@app.route("/upload", methods=["POST"])
+def upload():
+    f = request.files["file"]
+    f.save(os.path.join(UPLOAD_DIR, f.filename))
+    return "ok"
+"#;
+    let flagged = uploads(unvalidated, Language::Python);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 4);
+    assert_eq!(flagged[0].cwe_ids.first(), Some(&434));
+    assert!(flagged[0].confidence >= 0.8);
+    assert!(flagged[0].matched_text.contains("request-derived path"));
+
+    // Sanitized name plus an extension allow-list: nothing to report.
+    let validated = r#"
+@app.route("/upload", methods=["POST"])
+def upload():
+    f = request.files["file"]
+    if not allowed_file(f.filename):
+        abort(400)
+    f.save(os.path.join(UPLOAD_DIR, secure_filename(f.filename)))
+    return "ok"
+"#;
+    assert!(uploads(validated, Language::Python).is_empty());
+
+    // ORM saves are not uploads.
+    let orm = "def update(user):\n    user.name = request.form['name']\n    user.save()\n";
+    assert!(uploads(orm, Language::Python).is_empty());
+}
+
+// ---- T2-DET-17: heavy synchronous crypto in request handlers ----
+
+#[test]
+fn t2_det_17_sync_crypto_in_handler() {
+    use drift_analysis::detectors::performance::PerformanceDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let blocking = |source: &str| -> Vec<PatternMatch> {
+        run_detector(&PerformanceDetector::default(), source, Language::JavaScript)
+            .into_iter()
+            .filter(|m| m.pattern_id == "PERF-CRYPTO-006")
+            .collect()
+    };
+
+    let sync = r#"
+async function handleRegister(req, res) {
+  const hash = bcrypt.hashSync(req.body.password, 12);
+  await User.create({ email: req.body.email, hash });
+  res.sendStatus(201);
+}
+"#;
+    let flagged = blocking(sync);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 2);
+    assert_eq!(flagged[0].cwe_ids.first(), Some(&400));
+    assert!(flagged[0].matched_text.contains("cost 12"));
+
+    let awaited = r#"
+async function handleRegister(req, res) {
+  const hash = await bcrypt.hash(req.body.password, 12);
+  await User.create({ email: req.body.email, hash });
+  res.sendStatus(201);
+}
+"#;
+    assert!(blocking(awaited).is_empty());
+
+    // Outside a request handler (e.g. a seed script) the cost is acceptable.
+    assert!(blocking("function seed() {\n  return bcrypt.hashSync('admin', 12);\n}\n").is_empty());
+}
+
+// ---- T2-DET-18: cleartext http:// endpoints ----
+
+#[test]
+fn t2_det_18_cleartext_http_endpoint() {
+    use drift_analysis::detectors::security::SecurityDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let cleartext_with = |detector: &SecurityDetector, source: &str| -> Vec<PatternMatch> {
+        run_detector(detector, source, Language::JavaScript)
+            .into_iter()
+            .filter(|m| m.pattern_id == "SEC-HTTP-001")
+            .collect()
+    };
+    let cleartext = |source: &str| cleartext_with(&SecurityDetector::default(), source);
+
+    let flagged = cleartext("const res = await fetch(\"http://api.example.com/users\");\n");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].cwe_ids.first(), Some(&319));
+    assert!(flagged[0].confidence >= 0.8);
+    assert!(flagged[0].matched_text.contains("api.example.com"));
+    assert!(flagged[0].matched_text.contains("https://"));
+
+    assert!(cleartext("const res = await fetch(\"http://localhost:3000/users\");\n").is_empty());
+    assert!(cleartext("const res = await fetch(\"http://127.0.0.1:8080/\");\n").is_empty());
+    assert!(cleartext("const res = await fetch(\"https://api.example.com/users\");\n").is_empty());
+    // Comments are documentation, not endpoints.
+    assert!(cleartext("// see http://api.example.com/docs\nconst x = 1;\n").is_empty());
+    // XML namespaces are identifiers.
+    assert!(cleartext("const ns = \"http://www.w3.org/2000/svg\";\n").is_empty());
+
+    // Allowed hosts come from `[analysis.detectors]`.
+    let mesh = "const res = await fetch(\"http://billing.mesh.corp/invoices\");\n";
+    assert_eq!(cleartext(mesh).len(), 1);
+    let options = drift_core::config::DetectorOptions {
+        cleartext_allowed_hosts: vec!["mesh.corp".to_string()],
+        ..Default::default()
+    };
+    assert!(cleartext_with(&SecurityDetector::new(&options), mesh).is_empty());
+}
+
+// ---- T2-DET-19: unsafe reflection / dynamic loading ----
+
+#[test]
+fn t2_det_19_unsafe_reflection() {
+    use drift_analysis::detectors::security::SecurityDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let reflection = |source: &str, language: Language| -> Vec<PatternMatch> {
+        run_detector(&SecurityDetector::default(), source, language)
+            .into_iter()
+            .filter(|m| m.pattern_id == "SEC-REFLECT-001")
+            .collect()
+    };
+
+    // Java: class name read from the request, directly and one hop away.
+    let java = r#"
+class PluginController {
+    Object direct(HttpServletRequest request) throws Exception {
+        return Class.forName(request.getParameter("type")).getDeclaredConstructor().newInstance();
+    }
+
+    Object assigned(HttpServletRequest request) throws Exception {
+        String type = request.getParameter("type");
+        return Class.forName(type).getDeclaredConstructor().newInstance();
+    }
+
+    Object allowListed(HttpServletRequest request) throws Exception {
+        String type = request.getParameter("type");
+        if (!ALLOWED_TYPES.contains(type)) {
+            throw new IllegalArgumentException(type);
+        }
+        return Class.forName(type).getDeclaredConstructor().newInstance();
+    }
+
+    Object constant() throws Exception {
+        return Class.forName("com.example.Plugin");
+    }
+}
+"#;
+    let flagged = reflection(java, Language::Java);
+    assert_eq!(flagged.len(), 2, "{flagged:?}");
+    assert_eq!(flagged[0].cwe_ids.first(), Some(&470));
+    assert_eq!(flagged[0].line, 3);
+    assert!(flagged[0].confidence > flagged[1].confidence, "direct taint ranks above one-hop");
+    assert_eq!(flagged[1].line, 8);
+    assert!(flagged[1].matched_text.contains("`type`"));
+
+    // Python: __import__ and getattr on request data; allow-listed module.
+    let python = "def load(request):\n    mod = __import__(request.args['module'])\n    return getattr(mod, request.GET['fn'])()\n\ndef safe(request):\n    name = request.args['module']\n    if name not in ALLOWED_MODULES:\n        abort(400)\n    return importlib.import_module(name)\n";
+    let flagged = reflection(python, Language::Python);
+    assert_eq!(flagged.len(), 2, "{flagged:?}");
+    assert!(flagged.iter().all(|m| m.line < 3));
+
+    // PHP: variable function named by the query string.
+    let php = "<?php\n$fn = $_GET['action'];\n$fn();\ncall_user_func($_POST['cb']);\n";
+    let flagged = reflection(php, Language::Php);
+    assert_eq!(flagged.len(), 2, "{flagged:?}");
+    assert!(flagged.iter().any(|m| m.matched_text.starts_with("$fn()")));
+
+    // JavaScript: require of a request-chosen module, not of a literal.
+    let flagged = reflection("const plugin = require(req.query.plugin);\nconst fs = require('fs');\n", Language::JavaScript);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert!(flagged[0].confidence >= 0.8);
+}
+
+// ---- T2-DET-20: dereference without a null/None check ----
+
+#[test]
+fn t2_det_20_null_dereference() {
+    use drift_analysis::detectors::errors::ErrorsDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let null_derefs = |source: &str, language: Language| -> Vec<PatternMatch> {
+        run_detector(&ErrorsDetector, source, language)
+            .into_iter()
+            .filter(|m| m.pattern_id == "ERR-NULL-DEREF-001")
+            .collect()
+    };
+
+    // Python: `.get(k).attr` is flagged; a default, an HTTP get or a guard is not.
+    let python = "def load(config, key):\n    return config.get(key).value\n\ndef safe(config, key):\n    return config.get(key, {}).get('value')\n\ndef fetch(url):\n    return requests.get(url).json()\n\ndef guarded(config, key):\n    entry = config.get(key)\n    return entry.value if entry else None\n";
+    let flagged = null_derefs(python, Language::Python);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 1);
+    assert_eq!(flagged[0].cwe_ids.first(), Some(&476));
+    assert!(flagged[0].matched_text.contains(".value"));
+
+    // Python: regex match result and a `= None` parameter used directly.
+    let flagged = null_derefs("def version(text, user=None):\n    major = re.match(r'(\\d+)', text).group(1)\n    return user.name + major\n", Language::Python);
+    assert_eq!(flagged.len(), 2, "{flagged:?}");
+
+    // TypeScript: optional parameter and `.find()` result dereferenced.
+    let ts = "function greet(user?: User) {\n  return user.name;\n}\n\nfunction safe(user?: User) {\n  return user?.name;\n}\n\nfunction lookup(items: Item[], id: string) {\n  return items.find(i => i.id === id).label;\n}\n";
+    let flagged = null_derefs(ts, Language::TypeScript);
+    assert_eq!(flagged.len(), 2, "{flagged:?}");
+    assert!(flagged.iter().any(|m| m.line == 1 && m.matched_text.contains("`user`")));
+    assert!(flagged.iter().any(|m| m.line == 9));
+}
+
+// ---- T2-DET-21: error details exposed to the client ----
+
+#[test]
+fn t2_det_21_error_message_leak() {
+    use drift_analysis::detectors::errors::ErrorsDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let leaks = |source: &str, language: Language| -> Vec<PatternMatch> {
+        run_detector(&ErrorsDetector, source, language)
+            .into_iter()
+            .filter(|m| m.pattern_id == "ERR-INFO-LEAK-001")
+            .collect()
+    };
+
+    // Express: stack trace returned from a catch block is flagged; logging
+    // the error and sending a generic message is not.
+    let js = r#"app.get('/orders', async (req, res) => {
+  try {
+    res.json(await loadOrders());
+  } catch (err) {
+    res.status(500).send(err.stack);
+  }
+});
+
+app.get('/users', async (req, res) => {
+  try {
+    res.json(await loadUsers());
+  } catch (err) {
+    logger.error(err);
+    res.status(500).json({ error: 'Internal error', code: err.code });
+  }
+});
+"#;
+    let flagged = leaks(js, Language::JavaScript);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 4);
+    assert_eq!(flagged[0].cwe_ids.first(), Some(&209));
+    assert!(flagged[0].matched_text.contains("stack trace"));
+
+    // Error middleware and promise catch sending the message.
+    let js = "app.use((err, req, res, next) => {\n  res.status(500).json({ message: err.message });\n});\nrunJob().catch(e => res.send(e));\n";
+    assert_eq!(leaks(js, Language::JavaScript).len(), 2);
+
+    // A bare writer outside a request handler does not reach a client.
+    let js = "function runTask() {\n  try {\n    step();\n  } catch (e) {\n    write(e.stack);\n  }\n}\n";
+    assert!(leaks(js, Language::JavaScript).is_empty());
+
+    // Flask: `return str(e), 500` leaks; a dev-only traceback does not.
+    let python = "@app.route('/pay')\ndef pay():\n    try:\n        charge()\n    except Exception as e:\n        return str(e), 500\n\n@app.route('/refund')\ndef refund():\n    try:\n        charge()\n    except Exception as e:\n        log.exception(e)\n        return jsonify(error='failed', trace=traceback.format_exc() if app.debug else None), 500\n";
+    let flagged = leaks(python, Language::Python);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 5);
+
+    // Spring: exception message in the response body.
+    let java = "class PayController {\n  ResponseEntity<String> pay() {\n    try {\n      run();\n    } catch (SQLException e) {\n      return ResponseEntity.status(500).body(e.getMessage());\n    }\n  }\n}\n";
+    let flagged = leaks(java, Language::Java);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert!(flagged[0].matched_text.contains("`e`"));
+}
+
+// ---- T2-DET-22: prototype pollution ----
+
+#[test]
+fn t2_det_22_prototype_pollution() {
+    use drift_analysis::detectors::security::SecurityDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let pollution = |source: &str, language: Language| -> Vec<PatternMatch> {
+        run_detector(&SecurityDetector::default(), source, language)
+            .into_iter()
+            .filter(|m| m.pattern_id == "SEC-PROTO-POLLUTION-001")
+            .collect()
+    };
+
+    // Computed key straight from the query string; a literal key is fine.
+    let js = "app.post('/prefs', (req, res) => {\n  const prefs = {};\n  prefs[req.query.key] = req.query.value;\n  prefs['theme'] = 'dark';\n  res.json(prefs);\n});\n";
+    let flagged = pollution(js, Language::JavaScript);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 2);
+    assert_eq!(flagged[0].cwe_ids.first(), Some(&1321));
+    assert!(flagged[0].confidence >= 0.8);
+
+    // Unguarded recursive merge and a deep merge of the request body.
+    let ts = r#"function merge(target: any, source: any) {
+  for (const key in source) {
+    if (typeof source[key] === 'object') {
+      target[key] = merge(target[key] || {}, source[key]);
+    } else {
+      target[key] = source[key];
+    }
+  }
+  return target;
+}
+
+export function update(req: Request, res: Response) {
+  const settings = _.merge({}, req.body);
+  res.json(settings);
+}
+"#;
+    let flagged = pollution(ts, Language::TypeScript);
+    assert_eq!(flagged.len(), 2, "{flagged:?}");
+    assert!(flagged.iter().any(|m| m.matched_text.starts_with("recursive merge `merge`")));
+    assert!(flagged.iter().any(|m| m.matched_text.starts_with("_.merge()")));
+
+    // The same merge guarded against prototype keys is not flagged.
+    let guarded = r#"function merge(target, source) {
+  for (const key in source) {
+    if (key === '__proto__' || key === 'constructor' || key === 'prototype') continue;
+    if (typeof source[key] === 'object') {
+      target[key] = merge(target[key] || {}, source[key]);
+    } else {
+      target[key] = source[key];
+    }
+  }
+  return target;
+}
+"#;
+    assert!(pollution(guarded, Language::JavaScript).is_empty());
+
+    // Keys copied one hop from the request, including loop variables.
+    let js = "function save(req, res) {\n  const field = req.body.field;\n  record[field] = req.body.value;\n  for (const k in req.body) {\n    config[k] = req.body[k];\n  }\n}\n";
+    let flagged = pollution(js, Language::JavaScript);
+    assert_eq!(flagged.len(), 2, "{flagged:?}");
+    assert!(flagged.iter().all(|m| (m.confidence - 0.65).abs() < 1e-6));
+
+    // Other languages are out of scope.
+    assert!(pollution("def f(request):\n    obj[request.GET['k']] = 1\n", Language::Python).is_empty());
+}
+
+// ---- T2-DET-23: disabled security headers ----
+
+#[test]
+fn t2_det_23_disabled_security_headers() {
+    use drift_analysis::detectors::security::SecurityDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let headers = |source: &str, language: Language| -> Vec<PatternMatch> {
+        run_detector(&SecurityDetector::default(), source, language)
+            .into_iter()
+            .filter(|m| m.pattern_id == "SEC-HEADERS-001")
+            .collect()
+    };
+
+    // helmet with CSP turned off names the header; default helmet is fine.
+    let js = "const app = express();\napp.use(helmet({ contentSecurityPolicy: false }));\napp.listen(3000);\n";
+    let flagged = headers(js, Language::JavaScript);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 1);
+    assert!(flagged[0].matched_text.contains("Content-Security-Policy"));
+    assert_eq!(flagged[0].cwe_ids.first(), Some(&693));
+    assert!(headers("const app = express();\napp.use(helmet());\napp.listen(3000);\n", Language::JavaScript).is_empty());
+
+    // Removing X-Frame-Options and advertising X-Powered-By; disabling it is fine.
+    let js = "function h(req, res) {\n  res.removeHeader('X-Frame-Options');\n  res.setHeader('X-Powered-By', 'Express');\n  app.set('x-powered-by', false);\n}\n";
+    let flagged = headers(js, Language::JavaScript);
+    assert_eq!(flagged.len(), 2, "{flagged:?}");
+    assert!(flagged.iter().any(|m| m.matched_text.contains("removes the X-Frame-Options")));
+    assert!(flagged.iter().any(|m| m.matched_text.contains("X-Powered-By")));
+
+    // An Express app with no header middleware at all is advisory.
+    let bare = headers("const app = express();\napp.get('/', (req, res) => res.send('ok'));\napp.listen(3000);\n", Language::JavaScript);
+    assert_eq!(bare.len(), 1, "{bare:?}");
+    assert!(bare[0].confidence < 0.5);
+
+    // Django settings and Spring Security configurers.
+    let py = "SECURE_HSTS_SECONDS = 0\nSECURE_CONTENT_TYPE_NOSNIFF = True\n";
+    let flagged = headers(py, Language::Python);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert!(flagged[0].matched_text.contains("Strict-Transport-Security"));
+
+    let java = "class SecurityConfig {\n  SecurityFilterChain chain(HttpSecurity http) throws Exception {\n    http.headers(h -> h.frameOptions(f -> f.disable()));\n    http.csrf().disable();\n    return http.build();\n  }\n}\n";
+    let flagged = headers(java, Language::Java);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert!(flagged[0].matched_text.contains("X-Frame-Options"));
+}
+
+// ---- T2-DET-24: request body parsers without a size limit ----
+
+#[test]
+fn t2_det_24_body_parser_limits() {
+    use drift_analysis::detectors::performance::PerformanceDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let unlimited_with = |detector: &PerformanceDetector, source: &str| -> Vec<PatternMatch> {
+        run_detector(detector, source, Language::JavaScript)
+            .into_iter()
+            .filter(|m| m.pattern_id == "PERF-BODY-LIMIT-007")
+            .collect()
+    };
+    let unlimited = |source: &str| unlimited_with(&PerformanceDetector::default(), source);
+
+    let flagged = unlimited("app.use(express.json());\n");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert!(flagged[0].matched_text.starts_with("express.json()"));
+    assert_eq!(flagged[0].cwe_ids.first(), Some(&770));
+    assert!(unlimited("app.use(express.json({ limit: '1mb' }));\n").is_empty());
+
+    // multer has no default cap; `limits.fileSize` or opaque options suppress.
+    let flagged = unlimited("const upload = multer({ dest: 'uploads/' });\n");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert!(flagged[0].confidence > 0.6);
+    assert!(unlimited("const upload = multer({ dest: 'uploads/', limits: { fileSize: 5e6 } });\n").is_empty());
+    assert!(unlimited("app.use(bodyParser.urlencoded(parserOptions));\n").is_empty());
+    assert!(unlimited("const data = response.json();\n").is_empty());
+
+    // Extra parsers come from `[analysis.detectors]`.
+    let fastify = "app.register(fastifyMultipart());\n";
+    assert!(unlimited(fastify).is_empty());
+    let options = drift_core::config::DetectorOptions {
+        body_parsers: vec!["fastifyMultipart".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(unlimited_with(&PerformanceDetector::new(&options), fastify).len(), 1);
+}
+
+// ---- T2-DET-25: development-only endpoints outside tests ----
+
+#[test]
+fn t2_det_25_dev_endpoints() {
+    use drift_analysis::detectors::config::ConfigDetector;
+
+    let dev_endpoints = |source: &str, file: &str| -> Vec<PatternMatch> {
+        let (pr, bytes) = make_context_from_source(source, file);
+        ConfigDetector
+            .detect(&make_detection_context(&pr, &bytes))
+            .into_iter()
+            .filter(|m| m.pattern_id == "CFG-DEV-ENDPOINT-005")
+            .collect()
+    };
+
+    let config = "export const config = {\n  apiUrl: 'http://localhost:3000/api',\n  cache: 'redis.internal:6379',\n};\n";
+    let flagged = dev_endpoints(config, "src/services/config.ts");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 1);
+    assert!(flagged[0].matched_text.contains("localhost"));
+
+    // The same config in a test file is intended.
+    assert!(dev_endpoints(config, "src/services/config.test.ts").is_empty());
+    assert!(dev_endpoints(config, "tests/config.ts").is_empty());
+
+    // Tunnels and test subdomains; comparisons are not endpoints.
+    let js = "const hook = 'https://abc123.ngrok.io/hook';\nconst api = 'https://test.example.com';\nif (host === 'localhost') { warn(); }\n";
+    let flagged = dev_endpoints(js, "src/hooks.js");
+    assert_eq!(flagged.len(), 2, "{flagged:?}");
+
+    // Environment fallbacks are advisory; allowlisted hosts are skipped.
+    let py = "API = os.environ.get('API_URL', 'http://127.0.0.1:8000')\n";
+    let flagged = dev_endpoints(py, "app/settings.py");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert!(flagged[0].confidence < 0.5);
+
+    use drift_analysis::detectors::config::dev_endpoint::DevEndpointCheck;
+    let (pr, bytes) = make_context_from_source(config, "src/services/config.ts");
+    let allowed = DevEndpointCheck::new(&["localhost"]).detect(&make_detection_context(&pr, &bytes));
+    assert!(allowed.is_empty(), "{allowed:?}");
+}
+
+// ---- T2-DET-26: external resources without integrity checks ----
+
+#[test]
+fn t2_det_26_missing_integrity() {
+    use drift_analysis::detectors::security::SecurityDetector;
+
+    let integrity = |source: &str, file: &str| -> Vec<PatternMatch> {
+        let (pr, bytes) = make_context_from_source(source, file);
+        SecurityDetector::default()
+            .detect(&make_detection_context(&pr, &bytes))
+            .into_iter()
+            .filter(|m| m.pattern_id == "SEC-INTEGRITY-001")
+            .collect()
+    };
+
+    // A CDN script tag in a template string, without SRI.
+    let page = "export const layout = (body) => `<html><head>\n  <script src=\"https://cdn.jsdelivr.net/npm/lodash@4.17.21/lodash.min.js\"></script>\n  <script src=\"/static/app.js\"></script>\n</head><body>${body}</body></html>`;\n";
+    let flagged = integrity(page, "src/layout.js");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 1);
+    assert!(flagged[0].matched_text.contains("cdn.jsdelivr.net"));
+    assert!(flagged[0].cwe_ids.contains(&353));
+
+    // With an integrity attribute the tag is pinned.
+    let pinned = "const tag = '<script src=\"https://cdn.jsdelivr.net/npm/lodash.min.js\" integrity=\"sha384-abc\" crossorigin=\"anonymous\"></script>';\n";
+    assert!(integrity(pinned, "src/tag.js").is_empty());
+
+    // Piping a download into a shell.
+    let install = "import subprocess\nsubprocess.run('curl -fsSL https://get.example.com/install.sh | sudo bash', shell=True)\n";
+    let flagged = integrity(install, "scripts/setup.py");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 1);
+
+    // Trusted first-party hosts need no SRI.
+    use drift_analysis::detectors::security::integrity::IntegrityCheck;
+    let (pr, bytes) = make_context_from_source(page, "src/layout.js");
+    let trusted = IntegrityCheck::new(&["jsdelivr.net"]).detect(&make_detection_context(&pr, &bytes));
+    assert!(trusted.is_empty(), "{trusted:?}");
+}
+
+// ---- T2-DET-27: server-side template injection ----
+
+#[test]
+fn t2_det_27_template_injection() {
+    use drift_analysis::detectors::security::SecurityDetector;
+
+    let ssti = |source: &str, file: &str| -> Vec<PatternMatch> {
+        let (pr, bytes) = make_context_from_source(source, file);
+        SecurityDetector::default()
+            .detect(&make_detection_context(&pr, &bytes))
+            .into_iter()
+            .filter(|m| m.pattern_id == "SEC-SSTI-001")
+            .collect()
+    };
+
+    // The request chooses the template: flagged.
+    let flask = "from flask import Flask, request, render_template_string\napp = Flask(__name__)\n\n@app.route('/hello')\ndef hello():\n    user_input = request.args.get('tpl')\n    return render_template_string(user_input)\n";
+    let flagged = ssti(flask, "app/views.py");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 6);
+    assert!(flagged[0].cwe_ids.contains(&1336));
+    assert!(flagged[0].confidence >= 0.7);
+
+    // A fixed template rendered with user data: not flagged.
+    let data = "from flask import Flask, request, render_template_string\napp = Flask(__name__)\n\n@app.route('/greet')\ndef greet():\n    name = request.args.get('name')\n    return render_template_string('<h1>Hello {{ name }}</h1>', name=name)\n";
+    let flagged = ssti(data, "app/views.py");
+    assert!(flagged.is_empty(), "{flagged:?}");
+
+    // Handlebars compiling the request body directly.
+    let js = "app.post('/preview', (req, res) => {\n  const render = Handlebars.compile(req.body.template);\n  res.send(render({}));\n});\n";
+    let flagged = ssti(js, "src/preview.js");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert!(flagged[0].matched_text.contains("Handlebars.compile"));
+}
+
+// ---- T2-DET-28: transactions held open across external calls ----
+
+#[test]
+fn t2_det_28_transaction_across_external_call() {
+    use drift_analysis::detectors::performance::PerformanceDetector;
+
+    let held = |source: &str, file: &str| -> Vec<PatternMatch> {
+        let (pr, bytes) = make_context_from_source(source, file);
+        PerformanceDetector::default()
+            .detect(&make_detection_context(&pr, &bytes))
+            .into_iter()
+            .filter(|m| m.pattern_id == "PERF-TXN-IO-008")
+            .collect()
+    };
+
+    let js = "async function transfer(db, amount) {\n  await db.beginTransaction();\n  await db.query('UPDATE accounts SET balance = balance - ?', [amount]);\n  await httpCall('https://ledger.example.com/entries');\n  await db.commit();\n}\n";
+    let flagged = held(js, "src/transfer.js");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 3);
+    assert!(flagged[0].matched_text.contains("httpCall"));
+
+    // The same call after the commit holds no locks.
+    let after_commit = "async function transfer(db, amount) {\n  await db.beginTransaction();\n  await db.query('UPDATE accounts SET balance = balance - ?', [amount]);\n  await db.commit();\n  await httpCall('https://ledger.example.com/entries');\n}\n";
+    let flagged = held(after_commit, "src/transfer.js");
+    assert!(flagged.is_empty(), "{flagged:?}");
+
+    // Django atomic blocks close with their indentation.
+    let py = "def checkout(order):\n    with transaction.atomic():\n        order.save()\n        requests.post(PAYMENT_URL, json=order.payload(), timeout=5)\n    send_mail('Order placed', body, FROM, [order.email])\n";
+    let flagged = held(py, "shop/views.py");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 3);
+}
+
+// ---- T2-DET-29: insecure cookie attributes ----
+
+#[test]
+fn t2_det_29_insecure_cookies() {
+    use drift_analysis::detectors::security::SecurityDetector;
+
+    let cookies_with = |detector: &SecurityDetector, source: &str, file: &str| -> Vec<PatternMatch> {
+        let (pr, bytes) = make_context_from_source(source, file);
+        detector
+            .detect(&make_detection_context(&pr, &bytes))
+            .into_iter()
+            .filter(|m| m.pattern_id == "SEC-COOKIE-001")
+            .collect()
+    };
+    let cookies = |source: &str, file: &str| cookies_with(&SecurityDetector::default(), source, file);
+
+    let bare = "app.post('/login', (req, res) => {\n  res.cookie('sid', v);\n  res.send('ok');\n});\n";
+    let flagged = cookies(bare, "src/login.js");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 1);
+    assert!(flagged[0].cwe_ids.contains(&1004) && flagged[0].cwe_ids.contains(&614));
+    assert!(flagged[0].confidence >= 0.7, "session cookies are escalated");
+
+    let hardened = "app.post('/login', (req, res) => {\n  res.cookie('sid', v, { httpOnly: true, secure: true, sameSite: 'strict' });\n  res.send('ok');\n});\n";
+    let flagged = cookies(hardened, "src/login.js");
+    assert!(flagged.is_empty(), "{flagged:?}");
+
+    // Flask: only the missing attribute is reported; a preference cookie is advisory.
+    let flask = "def prefs():\n    resp = make_response('ok')\n    resp.set_cookie('theme', 'dark', secure=True, httponly=True)\n    return resp\n";
+    let flagged = cookies(flask, "app/views.py");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert!(flagged[0].matched_text.ends_with("without SameSite"));
+    assert!(flagged[0].confidence < 0.5);
+
+    // Java servlet cookie hardened by its setters.
+    let java = "class AuthController {\n  void login(HttpServletResponse response) {\n    Cookie cookie = new Cookie(\"session\", token);\n    cookie.setHttpOnly(true);\n    cookie.setSecure(true);\n    response.addCookie(cookie);\n  }\n}\n";
+    let flagged = cookies(java, "src/AuthController.java");
+    assert!(flagged.is_empty(), "{flagged:?}");
+
+    // Extra setters come from `[analysis.detectors]`.
+    let hapi = "const login = (request, h) => {\n  h.state('sid', v);\n  return 'ok';\n};\n";
+    assert!(cookies(hapi, "src/login.js").is_empty());
+    let options = drift_core::config::DetectorOptions {
+        cookie_setters: vec!["h.state".to_string()],
+        ..Default::default()
+    };
+    let flagged = cookies_with(&SecurityDetector::new(&options), hapi, "src/login.js");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+}
+
+// ---- T2-DET-30: webhook handlers without signature verification ----
+
+#[test]
+fn t2_det_30_unverified_webhooks() {
+    use drift_analysis::detectors::security::SecurityDetector;
+
+    let webhooks = |source: &str, file: &str| -> Vec<PatternMatch> {
+        let (pr, bytes) = make_context_from_source(source, file);
+        SecurityDetector::default()
+            .detect(&make_detection_context(&pr, &bytes))
+            .into_iter()
+            .filter(|m| m.pattern_id == "SEC-WEBHOOK-001")
+            .collect()
+    };
+
+    let unsigned = "app.post('/webhook', (req, res) => {\n  const event = req.body;\n  fulfillOrder(event.data.object);\n  res.sendStatus(200);\n});\n";
+    let flagged = webhooks(unsigned, "src/webhooks.js");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 1);
+    assert_eq!(flagged[0].cwe_ids[0], 345);
+    assert!(flagged[0].confidence >= 0.7);
+
+    // Stripe's constructEvent verifies the signature as it reads the body.
+    let stripe = "app.post('/webhook', (req, res) => {\n  const sig = req.headers['stripe-signature'];\n  const event = stripe.webhooks.constructEvent(req.body, sig, secret);\n  fulfillOrder(event.data.object);\n});\n";
+    let flagged = webhooks(stripe, "src/webhooks.js");
+    assert!(flagged.is_empty(), "{flagged:?}");
+
+    // Flask: the header is read, but compared nowhere.
+    let flask = "@app.route('/github/webhook', methods=['POST'])\ndef github_webhook():\n    sig = request.headers.get('X-Hub-Signature-256')\n    payload = request.get_json()\n    deploy(payload['ref'])\n    return ''\n";
+    let flagged = webhooks(flask, "app/hooks.py");
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert!(flagged[0].confidence < 0.7);
+
+    // Verified before use with a constant-time compare.
+    let verified = "@app.route('/github/webhook', methods=['POST'])\ndef github_webhook():\n    expected = sign(request.headers.get('X-Hub-Signature-256'))\n    if not hmac.compare_digest(expected, computed()):\n        abort(401)\n    payload = request.get_json()\n    deploy(payload['ref'])\n";
+    let flagged = webhooks(verified, "app/hooks.py");
+    assert!(flagged.is_empty(), "{flagged:?}");
+
+    // A configured project verifier counts too.
+    let custom = "app.post('/webhook', (req, res) => {\n  checkPaddleSignature(req);\n  handle(req.body);\n});\n";
+    let (pr, bytes) = make_context_from_source(custom, "src/paddle.js");
+    let check = drift_analysis::detectors::security::webhooks::WebhookSignatureCheck::default()
+        .with_verifiers(&["checkPaddleSignature"]);
+    assert!(check.detect(&make_detection_context(&pr, &bytes)).is_empty());
+}
+
+// ---- T2-DET-31: synchronous I/O weighed by placement ----
+
+#[test]
+fn t2_det_31_sync_io_placement() {
+    use drift_analysis::detectors::performance::PerformanceDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let blocking = |source: &str| -> Vec<PatternMatch> {
+        run_detector(&PerformanceDetector::default(), source, Language::JavaScript)
+            .into_iter()
+            .filter(|m| m.pattern_id == "PERF-SYNC-IO-009")
+            .collect()
+    };
+
+    // Module scope and startup functions: config loading is fine.
+    let startup = r#"
+const config = JSON.parse(fs.readFileSync('config.json', 'utf8'));
+
+function loadConfig() {
+  return fs.readFileSync('.env', 'utf8');
+}
+"#;
+    assert!(blocking(startup).is_empty(), "{:?}", blocking(startup));
+
+    // Inside a request handler: escalated.
+    let handler = r#"
+app.get('/report', (req, res) => {
+  const body = fs.readFileSync('reports/' + req.query.name);
+  res.send(body);
+});
+"#;
+    let flagged = blocking(handler);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 2);
+    assert!(flagged[0].confidence >= 0.7);
+    assert!(flagged[0].matched_text.contains("request handler"));
+
+    // A plain helper is reported, but below handler confidence.
+    let helper = "function renderTemplate(name) {\n  return fs.readFileSync(name, 'utf8');\n}\n";
+    let flagged = blocking(helper);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert!(flagged[0].confidence < 0.5);
+}
+
+// ---- T2-DET-32: security detectors skip test files when excluded ----
+
+#[test]
+fn t2_det_32_test_files_excluded_per_category() {
+    use drift_analysis::scanner::test_files::{is_test_file, TestFileClassifier};
+
+    let source = "export const creds = { value: \"password=SuperSecret123\" };\n";
+    // The scanner's classification (`ScanEntry::is_test_file`) decides, not the path
+    let has_secret = |registry: &DetectorRegistry, file: &str| {
+        let (pr, bytes) = make_context_from_source(source, file);
+        let ctx = make_detection_context(&pr, &bytes);
+        registry.run_all_for(&ctx, is_test_file(file)).iter().any(|m| m.pattern_id == "SEC-SECRET-001")
+    };
+
+    let mut registry = create_default_registry();
+    assert!(has_secret(&registry, "src/foo.test.ts"), "test files are analyzed by default");
+
+    registry.exclude_tests_from(&["security".to_string()]);
+    assert!(!has_secret(&registry, "src/foo.test.ts"), "secret in a test file must be excluded");
+    assert!(has_secret(&registry, "src/foo.ts"), "production files are still checked");
+
+    let (pr, bytes) = make_context_from_source(source, "src/foo.test.ts");
+    let ctx = make_detection_context(&pr, &bytes);
+    assert!(registry.run_all(&ctx).iter().any(|m| m.pattern_id == "SEC-SECRET-001"), "unclassified files are not skipped");
+    registry.include_tests(DetectorCategory::Security);
+    assert!(has_secret(&registry, "src/foo.test.ts"));
+
+    for path in ["foo.test.ts", "src/foo.spec.ts", "pkg/handler_test.go", "tests/api.rs", "app/__tests__/a.js", "test_views.py"] {
+        assert!(is_test_file(path), "{path} should be a test file");
+    }
+    for path in ["src/latest.ts", "src/attestation.go", "src/contest/entry.py", "src/foo.ts"] {
+        assert!(!is_test_file(path), "{path} should not be a test file");
+    }
+    let custom = TestFileClassifier::new(&["**/e2e/**".to_string()]);
+    assert!(custom.is_test_file("web/e2e/login.ts"));
+    assert!(!is_test_file("web/e2e/login.ts"));
+}
+
+// ---- T2-DET-33: hard-coded feature flags left in a risky state ----
+
+#[test]
+fn t2_det_33_hardcoded_flags() {
+    use drift_analysis::detectors::config::ConfigDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let flags = |source: &str, language: Language| -> Vec<PatternMatch> {
+        run_detector(&ConfigDetector, source, language)
+            .into_iter()
+            .filter(|m| m.pattern_id == "CFG-FLAG-004")
+            .collect()
+    };
+
+    let ts = "const SKIP_AUTH = true;\nconst MAX_RETRIES = 3;\nconst FEATURE_NEW_UI = true;\n";
+    let found = flags(ts, Language::TypeScript);
+    assert_eq!(found.len(), 2, "{found:?}");
+    assert_eq!(found[0].line, 0);
+    assert!(found[0].matched_text.contains("SKIP_AUTH"));
+    assert_eq!(found[0].confidence, 0.75, "security-relevant flags escalate");
+    assert_eq!(found[0].cwe_ids[0], 489);
+    assert_eq!(found[1].line, 2);
+    assert_eq!(found[1].confidence, 0.40, "other flags stay advisory");
+
+    // Safe values and safeguards left on are not reported.
+    let safe = "const SKIP_AUTH = false;\nconst VERIFY_SSL = true;\nconst debugMode = true;\n";
+    assert!(flags(safe, Language::TypeScript).is_empty());
+
+    // A disabled safeguard is reported; so is Django-style DEBUG.
+    assert_eq!(flags("const VERIFY_SSL = false;\n", Language::TypeScript).len(), 1);
+    assert_eq!(flags("DEBUG = True\n", Language::Python).len(), 1);
+}
+
+// ---- T2-DET-34: privileged routes without an auth guard ----
+
+#[test]
+fn t2_det_34_privileged_routes_need_auth() {
+    use drift_analysis::detectors::auth::AuthDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let unguarded = |source: &str, language: Language| -> Vec<PatternMatch> {
+        run_detector(&AuthDetector, source, language)
+            .into_iter()
+            .filter(|m| m.pattern_id == "AUTH-PRIVILEGED-006")
+            .collect()
+    };
+
+    let open = r#"
+router.get("/admin/users", (req, res) => {
+  res.json(listUsers());
+});
+router.get("/api/articles", listArticles);
+"#;
+    let found = unguarded(open, Language::JavaScript);
+    assert_eq!(found.len(), 1, "{found:?}");
+    assert_eq!(found[0].line, 1);
+    assert_eq!(found[0].cwe_ids[0], 306);
+    assert!(found[0].matched_text.contains("/admin/users"));
+
+    let guarded = r#"
+router.get("/admin/users", requireAuth, (req, res) => {
+  res.json(listUsers());
+});
+"#;
+    assert!(unguarded(guarded, Language::JavaScript).is_empty());
+
+    // Guards applied through middleware or decorators count too.
+    let mounted = "app.use(passport.authenticate('jwt'));\napp.post('/internal/reindex', reindex);\n";
+    assert!(unguarded(mounted, Language::JavaScript).is_empty());
+    let flask = "@app.route('/debug/vars')\n@login_required\ndef debug_vars():\n    return dump()\n";
+    assert!(unguarded(flask, Language::Python).is_empty());
+    let flask_open = "@app.route('/debug/vars')\ndef debug_vars():\n    return dump()\n";
+    assert_eq!(unguarded(flask_open, Language::Python).len(), 1);
+}
+
+// ---- T2-DET-35: long parameter lists ----
+
+#[test]
+fn t2_det_35_long_parameter_lists() {
+    use drift_analysis::detectors::structural::parameters::LongParameterListCheck;
+    use drift_analysis::detectors::structural::StructuralDetector;
+    use drift_analysis::detectors::test_support::parse_snippet;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let source = r#"
+function createOrder(customer, items, coupon, currency, region, channel, notes, priority) {
+  return { customer, items, coupon, currency, region, channel, notes, priority };
+}
+
+function add(a, b, c) {
+  return a + b + c;
+}
+
+class BillingService {
+  constructor(repo, mailer, logger, clock, config, metrics, tracer) {
+    this.repo = repo;
+  }
+}
+"#;
+    let long_params = |check: &LongParameterListCheck| -> Vec<PatternMatch> {
+        let pr = parse_snippet(source, Language::JavaScript);
+        let ctx = DetectionContext::from_parse_result(&pr, source.as_bytes());
+        check.detect(&ctx)
+    };
+
+    let found = long_params(&LongParameterListCheck::default());
+    assert_eq!(found.len(), 2, "{found:?}");
+    assert!(found[0].matched_text.contains("`createOrder` takes 8 parameters"), "{}", found[0].matched_text);
+    assert_eq!(found[0].line, 1);
+    assert_eq!(found[0].cwe_ids[0], 1064);
+    assert!(found[1].matched_text.contains("`constructor` takes 7"));
+    assert!(!found.iter().any(|m| m.matched_text.contains("`add`")));
+
+    // Constructors can be excluded, and the threshold is configurable.
+    let found = long_params(&LongParameterListCheck::default().with_constructors_excluded(true));
+    assert_eq!(found.len(), 1);
+    assert!(long_params(&LongParameterListCheck::new(8)).is_empty());
+    assert_eq!(long_params(&LongParameterListCheck::new(2)).len(), 3);
+
+    // Wired into the structural detector, with the threshold from `[analysis.detectors]`.
+    let long_param_count = |detector: &StructuralDetector| {
+        drift_analysis::detectors::test_support::run_detector(detector, source, Language::JavaScript)
+            .iter()
+            .filter(|m| m.pattern_id == "STRUCT-LONG-PARAMS")
+            .count()
+    };
+    assert_eq!(long_param_count(&StructuralDetector::default()), 2);
+    let options = drift_core::config::DetectorOptions {
+        max_parameters: Some(7),
+        exclude_constructors: Some(true),
+        ..Default::default()
+    };
+    assert_eq!(long_param_count(&StructuralDetector::new(&options)), 1);
+}
+
+// ---- T2-DET-36: inconsistent API error response shapes ----
+
+#[test]
+fn t2_det_36_inconsistent_error_response_shapes() {
+    use drift_analysis::detectors::api::error_shapes::ErrorResponseShapeCheck;
+    use drift_analysis::detectors::api::ApiDetector;
+    use drift_analysis::detectors::test_support::{parse_snippet, run_detector};
+    use drift_analysis::scanner::language_detect::Language;
+
+    let source = r#"
+router.get("/users/:id", async (req, res) => {
+  const user = await users.find(req.params.id);
+  if (!user) {
+    return res.status(404).json({ error: "User not found" });
+  }
+  res.json({ user });
+});
+
+router.post("/users", async (req, res) => {
+  if (!req.body.email) {
+    return res.status(400).json({ error: "Email is required" });
+  }
+  res.status(201).json(await users.create(req.body));
+});
+
+router.delete("/users/:id", async (req, res) => {
+  try {
+    await users.remove(req.params.id);
+    res.sendStatus(204);
+  } catch (err) {
+    res.status(500).json({ error: err.message });
+  }
+});
+
+function handleLogin(req, res) {
+  if (!valid(req.body)) {
+    return res.status(401).json({ message: "Invalid credentials" });
+  }
+  res.json({ token: issue(req.body) });
+}
+"#;
+    let check = |check: &ErrorResponseShapeCheck| -> Vec<PatternMatch> {
+        let pr = parse_snippet(source, Language::JavaScript);
+        let ctx = DetectionContext::from_parse_result(&pr, source.as_bytes());
+        check.detect(&ctx)
+    };
+
+    let found = check(&ErrorResponseShapeCheck::default());
+    assert_eq!(found.len(), 1, "{found:?}");
+    assert_eq!(found[0].pattern_id, "API-ERROR-SHAPE-004");
+    assert_eq!(found[0].line, 27);
+    assert!(
+        found[0].matched_text.contains("`{ message }`; 3 of 4 error responses use `{ error }` (deviating: `handleLogin`)"),
+        "{}",
+        found[0].matched_text
+    );
+
+    // Too few responses to establish a convention.
+    assert!(check(&ErrorResponseShapeCheck::default().with_min_responses(5)).is_empty());
+
+    // Wired into the API detector.
+    let all = run_detector(&ApiDetector, source, Language::JavaScript);
+    assert_eq!(all.iter().filter(|m| m.pattern_id == "API-ERROR-SHAPE-004").count(), 1);
+
+    // A consistent service is not flagged.
+    let consistent = source.replace("{ message: \"Invalid credentials\" }", "{ error: \"Invalid credentials\" }");
+    let pr = parse_snippet(&consistent, Language::JavaScript);
+    let ctx = DetectionContext::from_parse_result(&pr, consistent.as_bytes());
+    assert!(ErrorResponseShapeCheck::default().detect(&ctx).is_empty());
+}
+
+// ---- T2-DET-37: XML external entity processing ----
+
+#[test]
+fn t2_det_37_xml_external_entities() {
+    use drift_analysis::detectors::security::SecurityDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let xxe = |source: &str, language: Language| -> Vec<PatternMatch> {
+        run_detector(&SecurityDetector::default(), source, language)
+            .into_iter()
+            .filter(|m| m.pattern_id.starts_with("SEC-XXE"))
+            .collect()
+    };
+
+    // Java: a default DocumentBuilderFactory is flagged, a hardened one is not.
+    let java = r#"
+class Importer {
+    Document parse(InputStream in) throws Exception {
+        DocumentBuilderFactory dbf = DocumentBuilderFactory.newInstance();
+        return dbf.newDocumentBuilder().parse(in);
+    }
+
+    Document parseSafely(InputStream in) throws Exception {
+        DocumentBuilderFactory dbf = DocumentBuilderFactory.newInstance();
+        dbf.setFeature("http://apache.org/xml/features/disallow-doctype-decl", true);
+        return dbf.newDocumentBuilder().parse(in);
+    }
+}
+"#;
+    let flagged = xxe(java, Language::Java);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].pattern_id, "SEC-XXE-001");
+    assert_eq!(flagged[0].line, 3);
+    assert_eq!(flagged[0].cwe_ids.first(), Some(&611));
+    assert!(flagged[0].matched_text.contains("DocumentBuilderFactory"));
+
+    // Python lxml with entity resolution turned on, and turned off.
+    let python = "from lxml import etree\n\ndef load(data):\n    parser = etree.XMLParser(resolve_entities=True)\n    return etree.fromstring(data, parser)\n\ndef load_safe(data):\n    parser = etree.XMLParser(resolve_entities=False)\n    return etree.fromstring(data, parser)\n";
+    let flagged = xxe(python, Language::Python);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!((flagged[0].pattern_id.as_str(), flagged[0].line), ("SEC-XXE-002", 3));
+
+    // C#: DTD processing enabled on the reader settings.
+    let csharp = "class Feed {\n    XmlReader Open(Stream s) {\n        var settings = new XmlReaderSettings();\n        settings.DtdProcessing = DtdProcessing.Parse;\n        return XmlReader.Create(s, settings);\n    }\n}\n";
+    let flagged = xxe(csharp, Language::CSharp);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert!(flagged[0].confidence >= 0.9);
+}
+
+// ---- T2-DET-38: Opened resources without cleanup ----
+
+#[test]
+fn t2_det_38_resource_leaks() {
+    use drift_analysis::detectors::errors::ErrorsDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let leaks = |source: &str, language: Language| -> Vec<PatternMatch> {
+        run_detector(&ErrorsDetector, source, language)
+            .into_iter()
+            .filter(|m| m.pattern_id == "ERR-RESOURCE-LEAK-001")
+            .collect()
+    };
+
+    // Go: os.Open without `defer f.Close()` is flagged; with it, or when the
+    // file is returned to the caller, it is not.
+    let go = r#"package config
+
+import "os"
+
+func Load(path string) ([]byte, error) {
+	f, err := os.Open(path)
+	if err != nil {
+		return nil, err
+	}
+	buf := make([]byte, 1024)
+	n, err := f.Read(buf)
+	return buf[:n], err
+}
+
+func LoadClosed(path string) ([]byte, error) {
+	f, err := os.Open(path)
+	if err != nil {
+		return nil, err
+	}
+	defer f.Close()
+	buf := make([]byte, 1024)
+	n, err := f.Read(buf)
+	return buf[:n], err
+}
+
+func OpenLog(path string) (*os.File, error) {
+	f, err := os.Create(path)
+	if err != nil {
+		return nil, err
+	}
+	return f, nil
+}
+"#;
+    let flagged = leaks(go, Language::Go);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 5);
+    assert_eq!(flagged[0].cwe_ids.first(), Some(&772));
+    assert_eq!(flagged[0].category, PatternCategory::Errors);
+    assert!(flagged[0].matched_text.contains("os.Open"));
+
+    // Python: open() outside `with` and never closed, against a `with` block.
+    let python = "def read(path):\n    f = open(path)\n    return len(f.read())\n\ndef read_with(path):\n    with open(path) as f:\n        return len(f.read())\n";
+    let flagged = leaks(python, Language::Python);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 1);
+
+    // Java: try-with-resources releases the stream.
+    let java = "class Reader {\n    int first(String p) throws IOException {\n        FileInputStream in = new FileInputStream(p);\n        return in.read();\n    }\n\n    int firstSafely(String p) throws IOException {\n        try (FileInputStream in = new FileInputStream(p)) {\n            return in.read();\n        }\n    }\n}\n";
+    let flagged = leaks(java, Language::Java);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 2);
+
+    // Node: closing something else on the line that uses the stream is not a
+    // release; passing the descriptor to a close call is.
+    let js = "function copy(src, db) {\n  const out = fs.createWriteStream(src);\n  db.close(out.path);\n}\n\nfunction stamp(path) {\n  const fd = fs.openSync(path);\n  fs.writeSync(fd, 'x');\n  fs.closeSync(fd);\n}\n";
+    let flagged = leaks(js, Language::JavaScript);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 1);
+}
+
+// ---- T2-DET-39: path traversal through file APIs ----
+
+#[test]
+fn t2_det_39_path_traversal() {
+    use drift_analysis::detectors::security::SecurityDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let traversal = |source: &str, language: Language| -> Vec<PatternMatch> {
+        run_detector(&SecurityDetector::default(), source, language)
+            .into_iter()
+            .filter(|m| m.pattern_id == "SEC-PATH-001")
+            .collect()
+    };
+
+    // JS: a query parameter read straight from disk is flagged; reduced to
+    // its basename first, it is not.
+    let js = r#"const fs = require('fs');
+const path = require('path');
+
+app.get('/download', (req, res) => {
+    res.send(fs.readFileSync(req.query.file));
+});
+
+app.get('/safe', (req, res) => {
+    res.send(fs.readFileSync(path.join(DIR, path.basename(req.query.file))));
+});
+"#;
+    let flagged = traversal(js, Language::JavaScript);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 4);
+    assert_eq!(flagged[0].cwe_ids.first(), Some(&22));
+    assert_eq!(flagged[0].owasp.as_deref(), Some("A01:2021"));
+    assert!(flagged[0].confidence >= 0.8);
+
+    // Python: request input reaching open() through a variable, and a
+    // literal that climbs out of the working directory.
+    let python = "from flask import request\n\n@app.route('/view')\ndef view():\n    name = request.args['f']\n    return open(name).read()\n\ndef config():\n    return open('../../etc/app.yml').read()\n";
+    let flagged = traversal(python, Language::Python);
+    assert_eq!(flagged.len(), 2, "{flagged:?}");
+    assert_eq!(flagged[0].line, 5);
+    assert!(flagged[0].matched_text.contains("`name`"));
+    assert_eq!(flagged[1].line, 8);
+    assert!(flagged[1].confidence < flagged[0].confidence);
+
+    // The request sources are shared with the other taint checks, so a
+    // header read counts here as it does for unsafe reflection.
+    let java = "class FileController {\n    Path resolve(HttpServletRequest request) {\n        return Paths.get(request.getHeader(\"X-File\"));\n    }\n}\n";
+    let flagged = traversal(java, Language::Java);
+    assert_eq!(flagged.len(), 1, "{flagged:?}");
+    assert_eq!(flagged[0].line, 2);
+}
+
+// ---- T2-DET-40: high-entropy string literals ----
+
+#[test]
+fn t2_det_40_high_entropy_strings() {
+    use drift_analysis::detectors::security::high_entropy::HighEntropyCheck;
+    use drift_analysis::detectors::test_support::parse_snippet;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let source = r#"import { TextInput } from '@acme/design-system/components/forms/TextInput2';
+
+const sessionSalt = "Zx8Kq2LmV9wR4tNb7YcP3sHf";
+const signingKey = "7c4a8d09ca3762af61e59520943dc26494f8941b1f0a3e5d";
+const message = "Please confirm your email address before continuing to checkout";
+const docs = "https://docs.example.com/guides/Setup2FA/overview";
+const widget = require("components/forms/inputs/TextInput2");
+const label = "customerAccountSettingsPanelTitle";
+"#;
+    let pr = parse_snippet(source, Language::JavaScript);
+    let ctx = DetectionContext::from_parse_result(&pr, source.as_bytes());
+    let flagged = HighEntropyCheck::default().detect(&ctx);
+    let lines: Vec<u32> = flagged.iter().map(|m| m.line).collect();
+    assert_eq!(lines, vec![2, 3], "{flagged:?}");
+    assert!(flagged.iter().all(|m| m.pattern_id == "SEC-SECRET-ENTROPY"));
+    assert_eq!(flagged[0].cwe_ids.first(), Some(&798));
+    assert!(!flagged[0].matched_text.contains("Zx8Kq2"), "value must not be echoed");
+    assert!(flagged.iter().all(|m| (0.5..=0.9).contains(&m.confidence)));
+
+    // Digests and UUIDs are random but are identifiers, not secrets.
+    let identifiers = r#"
+const checksum = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+const commit = "e83c5163316f89bfbde7d9ab23ca2e25604af290";
+const etag = "5d41402abc4b2a76b9719d911017c592";
+const requestId = "3f2504e0-4f89-11d3-9a0c-0305e82c3301";
+const traceId = "3F2504E04F8911D39A0C0305E82C3301";
+"#;
+    let pr = parse_snippet(identifiers, Language::JavaScript);
+    let flagged = HighEntropyCheck::default().detect(&DetectionContext::from_parse_result(&pr, identifiers.as_bytes()));
+    assert!(flagged.is_empty(), "{flagged:?}");
+
+    // A stricter bar drops the 24-character key (log2(24) ≈ 4.6 bits max).
+    let strict = HighEntropyCheck::new(4.8).detect(&ctx);
+    assert_eq!(strict.iter().map(|m| m.line).collect::<Vec<_>>(), vec![3]);
+
+    // Registered in the security detector, with the bar from `[analysis.detectors]`.
+    use drift_analysis::detectors::security::SecurityDetector;
+    let all = SecurityDetector::default().detect(&ctx);
+    assert_eq!(all.iter().filter(|m| m.pattern_id == "SEC-SECRET-ENTROPY").count(), 2);
+    let options = drift_core::config::DetectorOptions {
+        entropy_threshold: Some(4.8),
+        ..Default::default()
+    };
+    let all = SecurityDetector::new(&options).detect(&ctx);
+    assert_eq!(all.iter().filter(|m| m.pattern_id == "SEC-SECRET-ENTROPY").count(), 1);
+}
+
+// ---- T2-DET-41: insecure deserialization sinks ----
+
+#[test]
+fn t2_det_41_insecure_deserialization() {
+    use drift_analysis::detectors::security::SecurityDetector;
+    use drift_analysis::detectors::test_support::run_detector;
+    use drift_analysis::scanner::language_detect::Language;
+
+    let deser = |source: &str, language: Language| -> Vec<PatternMatch> {
+        run_detector(&SecurityDetector::default(), source, language)
+            .into_iter()
+            .filter(|m| m.pattern_id == "SEC-DESER-001")
+            .collect()
+    };
+    let lines = |matches: &[PatternMatch]| matches.iter().map(|m| m.line).collect::<Vec<_>>();
+
+    // Python: pickle and a bare yaml.load are flagged; yaml.safe_load and
+    // yaml.load with a SafeLoader are not.
+    let python = "import pickle, yaml\n\ndef load_session(blob):\n    return pickle.loads(blob)\n\ndef load_config(path):\n    with open(path) as f:\n        return yaml.load(f)\n\ndef load_safe(path):\n    with open(path) as f:\n        return yaml.safe_load(f)\n\ndef load_explicit(path):\n    with open(path) as f:\n        return yaml.load(f, Loader=yaml.SafeLoader)\n";
+    let flagged = deser(python, Language::Python);
+    assert_eq!(lines(&flagged), vec![3, 7], "{flagged:?}");
+    assert!(flagged.iter().all(|m| m.cwe_ids.first() == Some(&502)));
+    assert!(flagged.iter().all(|m| m.owasp.as_deref() == Some("A08:2021")));
+    assert!(flagged[0].matched_text.contains("pickle.loads()"));
+
+    // A safe variant in the same function lowers confidence.
+    let mixed = "import yaml\n\ndef load(path, trusted):\n    if trusted:\n        return yaml.load(open(path))\n    return yaml.safe_load(open(path))\n";
+    let flagged = deser(mixed, Language::Python);
+    assert_eq!(lines(&flagged), vec![4], "{flagged:?}");
+    assert!(flagged[0].confidence < 0.5);
+    assert!(flagged[0].matched_text.contains("yaml.safe_load"));
+
+    // Java: readObject on an ObjectInputStream, not on other receivers.
+    let java = r#"class Sessions {
+    Object read(InputStream in) throws Exception {
+        ObjectInputStream ois = new ObjectInputStream(in);
+        return ois.readObject();
+    }
+
+    Object copy(Object o) throws Exception {
+        return mapper.readObject(o);
+    }
+}
+"#;
+    let flagged = deser(java, Language::Java);
+    assert_eq!(lines(&flagged), vec![3], "{flagged:?}");
+    assert!(flagged[0].matched_text.contains("ObjectInputStream.readObject()"));
+
+    // PHP: unserialize unless object instantiation is disabled.
+    let php = "<?php\n$cart = unserialize($_COOKIE['cart']);\n$prefs = unserialize($raw, ['allowed_classes' => false]);\n";
+    assert_eq!(lines(&deser(php, Language::Php)), vec![1]);
+
+    // Ruby: Marshal.load.
+    let ruby = "def restore(data)\n  Marshal.load(data)\nend\n";
+    assert_eq!(lines(&deser(ruby, Language::Ruby)), vec![1]);
+
+    // C#: BinaryFormatter.Deserialize, but not JsonSerializer.Deserialize.
+    let csharp = r#"class Store {
+    object Load(Stream s) {
+        var formatter = new BinaryFormatter();
+        return formatter.Deserialize(s);
+    }
+
+    T Parse<T>(string json) {
+        return JsonSerializer.Deserialize<T>(json);
+    }
+}
+"#;
+    let flagged = deser(csharp, Language::CSharp);
+    assert_eq!(lines(&flagged), vec![3], "{flagged:?}");
+    assert!(flagged[0].confidence >= 0.9);
+}
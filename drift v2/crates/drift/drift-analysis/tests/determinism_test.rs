@@ -58,6 +58,7 @@ fn make_pattern(id: &str, locations: u32, files: u32) -> AggregatedPattern {
         confidence_values: vec![0.9; locations as usize],
         is_dirty: false,
         location_hash: 0,
+        over_limit: false,
     }
 }
 
@@ -383,3 +384,49 @@ fn determinism_full_pipeline_end_to_end() {
         );
     }
 }
+
+// ─── Determinism audit ────────────────────────────────────────────────────
+
+#[test]
+fn determinism_audit_clean_run_on_fixtures() {
+    use drift_analysis::engine::audit::verify_determinism;
+    use drift_core::config::DriftConfig;
+
+    let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../../test-fixtures");
+    let report = verify_determinism(&root, &DriftConfig::default()).unwrap();
+
+    assert!(report.files > 10, "expected the fixture sources to parse, got {} files", report.files);
+    assert!(report.findings > 0, "expected findings on the fixture sources");
+    assert!(report.violations > 0, "expected outlier violations on the fixture sources");
+    assert!(report.reports.iter().any(|f| f == "sarif"));
+    assert!(
+        report.is_deterministic(),
+        "pipeline output differs between runs: {:#?}",
+        report.differences
+    );
+}
+
+#[test]
+fn determinism_audit_honors_exclude_tests_from() {
+    use drift_analysis::engine::audit::verify_determinism;
+    use drift_core::config::DriftConfig;
+
+    let dir = tempfile::tempdir().unwrap();
+    let secret = "export const creds = { value: \"password=SuperSecret123\" };\n";
+    std::fs::create_dir_all(dir.path().join("src")).unwrap();
+    std::fs::write(dir.path().join("src/app.ts"), secret).unwrap();
+    std::fs::write(dir.path().join("src/app.test.ts"), secret).unwrap();
+
+    let all = verify_determinism(dir.path(), &DriftConfig::default()).unwrap();
+    let mut config = DriftConfig::default();
+    config.analysis.exclude_tests_from = vec!["security".to_string()];
+    let excluded = verify_determinism(dir.path(), &config).unwrap();
+
+    assert_eq!(all.files, 2);
+    assert!(
+        excluded.findings < all.findings,
+        "security findings in src/app.test.ts should be skipped: {} vs {}",
+        excluded.findings,
+        all.findings
+    );
+}
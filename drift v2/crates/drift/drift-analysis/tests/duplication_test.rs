@@ -0,0 +1,85 @@
+//! Phase 5 duplication tests (T5-DUP-01 through T5-DUP-02).
+
+use std::path::Path;
+
+use drift_analysis::parsers::manager::ParserManager;
+use drift_analysis::structural::duplication::{
+    detect_clones, function_tokens, CloneConfig, CloneKind, FunctionTokens, TokenKind,
+};
+
+fn tokens_of(source: &str, file: &str) -> Vec<FunctionTokens> {
+    let (parse_result, tree) = ParserManager::new()
+        .parse_returning_tree(source.as_bytes(), Path::new(file))
+        .unwrap();
+    function_tokens(&parse_result, &tree, source.as_bytes())
+}
+
+const TOTALS: &str = r#"
+export function orderTotal(items, taxRate) {
+  let total = 0;
+  for (const item of items) {
+    if (item.quantity > 0) {
+      total += item.price * item.quantity;
+    }
+  }
+  return Math.round(total * (1 + taxRate) * 100) / 100;
+}
+
+export function invoiceSum(lines, vat) {
+  let sum = 0;
+  for (const line of lines) {
+    if (line.quantity > 0) {
+      sum += line.price * line.quantity;
+    }
+  }
+  return Math.round(sum * (1 + vat) * 100) / 100;
+}
+
+export function describe(user) {
+  const parts = [user.first, user.last].filter(Boolean);
+  return parts.length ? parts.join(" ") : "anonymous";
+}
+"#;
+
+/// T5-DUP-01: Functions differing only in variable names are a Type-2 clone.
+#[test]
+fn t5_dup_01_renamed_functions_are_type2_clones() {
+    let functions = tokens_of(TOTALS, "src/billing.js");
+    let order = functions.iter().find(|f| f.name == "orderTotal").expect("orderTotal tokenized");
+    let invoice = functions.iter().find(|f| f.name == "invoiceSum").expect("invoiceSum tokenized");
+
+    // Identifiers and literals are placeholders; keywords and operators stay.
+    let texts: Vec<&str> = order.tokens.iter().map(|t| t.text.as_str()).collect();
+    assert!(texts.starts_with(&["function", "$id", "(", "$id", ",", "$id", ")", "{", "let", "$id", "=", "$lit"]), "{texts:?}");
+    assert!(order.tokens.iter().any(|t| t.kind == TokenKind::Operator && t.text == "+="));
+    assert_eq!(
+        order.tokens.iter().map(|t| &t.text).collect::<Vec<_>>(),
+        invoice.tokens.iter().map(|t| &t.text).collect::<Vec<_>>()
+    );
+
+    let pairs = detect_clones(&functions, &CloneConfig::default());
+    assert_eq!(pairs.len(), 1, "{pairs:?}");
+    assert_eq!(pairs[0].first.function, "orderTotal");
+    assert_eq!(pairs[0].second.function, "invoiceSum");
+    assert_eq!(pairs[0].kind, CloneKind::Renamed);
+    assert_eq!(pairs[0].similarity, 1.0);
+}
+
+/// T5-DUP-02: Exact copies are Type-1; short or dissimilar functions are not paired.
+#[test]
+fn t5_dup_02_exact_copies_and_thresholds() {
+    let copy = TOTALS.replace("invoiceSum(lines, vat)", "orderTotalCopy(items, taxRate)")
+        .replace("let sum = 0;", "let total = 0;")
+        .replace("const line of lines", "const item of items")
+        .replace("line.", "item.")
+        .replace("sum +=", "total +=")
+        .replace("(sum * (1 + vat)", "(total * (1 + taxRate)");
+    let functions = tokens_of(&copy, "src/billing.js");
+    let pairs = detect_clones(&functions, &CloneConfig::default());
+    assert_eq!(pairs.len(), 1, "{pairs:?}");
+    assert_eq!(pairs[0].kind, CloneKind::Exact);
+
+    // A window longer than every function finds nothing.
+    let strict = CloneConfig { window: 500, ..CloneConfig::default() };
+    assert!(detect_clones(&functions, &strict).is_empty());
+}
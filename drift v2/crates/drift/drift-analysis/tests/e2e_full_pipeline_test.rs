@@ -28,7 +28,7 @@
 //!   OWASP/CWE → Crypto → Rules → Gates → Policy → Audit → Feedback →
 //!   Reporters → Simulation → Decisions → Context Generation
 //!
-//! Tests all 10 languages, cross-system data flow, error recovery, storage
+//! Tests all 11 languages, cross-system data flow, error recovery, storage
 //! integrity, incremental correctness, and reporter output validity.
 
 use std::path::{Path, PathBuf};
@@ -458,8 +458,40 @@ const val MAX_RETRIES = 3
 "#
 }
 
+fn swift_source() -> &'static str {
+    r#"import Foundation
+import CryptoKit
+
+protocol UserRepository {
+    func find(id: Int) async throws -> User?
+}
+
+public final class UserService: NSObject, UserRepository {
+    private let session: URLSession
+
+    public init(session: URLSession) {
+        self.session = session
+        super.init()
+    }
+
+    public func find(id: Int) async throws -> User? {
+        let url = URL(string: "https://api.example.com/users/\(id)")!
+        let (data, _) = try await session.data(from: url)
+        return try JSONDecoder().decode(User.self, from: data)
+    }
+
+    fileprivate func digest(_ password: String) -> String {
+        let hash = Insecure.MD5.hash(data: Data(password.utf8))
+        return hash.map { String(format: "%02x", $0) }.joined()
+    }
+}
+
+let apiKey = "AKIA1234567890ABCDEF"
+"#
+}
+
 // ============================================================================
-// E2E Test 1: Full Pipeline — All 10 Languages
+// E2E Test 1: Full Pipeline — All 11 Languages
 // ============================================================================
 
 #[test]
@@ -480,6 +512,7 @@ fn e2e_full_pipeline_all_languages() {
         ("src/users/controller.rb", ruby_source()),
         ("src/users/UserController.php", php_source()),
         ("src/users/UserController.kt", kotlin_source()),
+        ("src/users/UserService.swift", swift_source()),
     ];
 
     for (path, content) in &files {
@@ -522,8 +555,9 @@ fn e2e_full_pipeline_all_languages() {
         .collect();
     eprintln!("[Scanner] Languages detected: {:?}", languages);
     assert!(languages.len() >= 5, "Should detect at least 5 languages, got {}", languages.len());
+    assert!(languages.contains(&Language::Swift), "Should detect Swift: {:?}", languages);
 
-    // ---- Phase 1: Parsers — all 10 languages ----
+    // ---- Phase 1: Parsers — all 11 languages ----
     let parse_start = Instant::now();
     let parser = ParserManager::new();
     let mut parse_results: Vec<ParseResult> = Vec::new();
@@ -621,7 +655,7 @@ fn e2e_full_pipeline_all_languages() {
         write_stats.file_metadata_rows, write_stats.function_rows, storage_time
     );
 
-    assert_eq!(write_stats.file_metadata_rows as usize, total_files);
+    assert_eq!(write_stats.file_metadata_rows, total_files);
     assert!(write_stats.function_rows > 0, "Should persist function rows");
 
     // ---- Phase 2: Analysis Engine — 4-phase pipeline ----
@@ -962,7 +996,8 @@ fn e2e_full_pipeline_all_languages() {
         let reporter = reporters::create_reporter(format).unwrap();
         match reporter.generate(&gate_results) {
             Ok(output) => {
-                assert!(!output.is_empty(), "{format} reporter produced empty output");
+                // NDJSON writes one line per violation, so a clean run is empty
+                assert!(!output.is_empty() || format == "ndjson", "{format} reporter produced empty output");
                 reporter_outputs.push((format.to_string(), output.len()));
 
                 if format == "sarif" {
@@ -1338,7 +1373,8 @@ fn e2e_error_recovery_malformed_inputs() {
             result.err()
         );
         let output = result.unwrap();
-        assert!(!output.is_empty(), "{format} reporter should produce non-empty output");
+        // NDJSON writes one line per violation, so a clean run is empty
+        assert!(!output.is_empty() || format == "ndjson", "{format} reporter should produce non-empty output");
     }
 
     eprintln!("[ErrorRecovery] All error recovery checks passed");
@@ -1428,6 +1464,7 @@ fn e2e_reporter_output_validation() {
                     owasp_category: Some("A03:2025".to_string()),
                     suppressed: false,
                     is_new: true,
+                    fingerprint: None,
                 },
                 drift_analysis::enforcement::rules::Violation {
                     id: "sec-002".to_string(),
@@ -1445,6 +1482,7 @@ fn e2e_reporter_output_validation() {
                     owasp_category: Some("A02:2025".to_string()),
                     suppressed: false,
                     is_new: false,
+                    fingerprint: None,
                 },
             ],
         ),
@@ -1572,6 +1610,7 @@ fn get_tree_sitter_tree(source: &[u8], language: Language) -> Option<tree_sitter
         Language::Ruby => tree_sitter_ruby::LANGUAGE.into(),
         Language::Php => tree_sitter_php::LANGUAGE_PHP.into(),
         Language::Kotlin => tree_sitter_kotlin_sg::LANGUAGE.into(),
+        Language::Swift => tree_sitter_swift::LANGUAGE.into(),
         _ => return None,
     };
     parser.set_language(&ts_lang).ok()?;
@@ -1590,6 +1629,7 @@ fn ext_to_lang(path: &str) -> &str {
         Some("rb") => "ruby",
         Some("php") => "php",
         Some("kt") => "kotlin",
+        Some("swift") => "swift",
         _ => "",
     }
 }
@@ -1653,6 +1693,9 @@ fn build_gate_input(files: &[(&str, &str)]) -> GateInput {
         predecessor_results: std::collections::HashMap::new(),
         baseline_violations: std::collections::HashSet::new(),
         feedback_stats: None,
+        min_confidence: None,
+        sample_fraction: None,
+        source_lines: Default::default(),
     }
 }
 
@@ -2407,7 +2450,7 @@ const API_KEY = 'AKIA1234567890ABCDEF';
 
     // CRITICAL CHECK: All functions should be persisted
     assert_eq!(
-        stats.function_rows as usize, stored_count,
+        stats.function_rows, stored_count,
         "All functions should be persisted: {} stored vs {} sent",
         stats.function_rows, stored_count
     );
@@ -2453,6 +2496,7 @@ fn e2e_confidence_scoring_edge_cases() {
         confidence_values: vec![0.9],
         is_dirty: true,
         location_hash: 0,
+        over_limit: false,
     };
 
     let scores = scorer.score_batch(&[single_loc.clone()], None);
@@ -3776,6 +3820,9 @@ fn e2e_gate_predecessor_dag() {
         predecessor_results,
         baseline_violations: std::collections::HashSet::new(),
         feedback_stats: None,
+        min_confidence: None,
+        sample_fraction: None,
+        source_lines: Default::default(),
     };
 
     let orchestrator = GateOrchestrator::new();
@@ -3813,6 +3860,9 @@ fn e2e_gate_predecessor_dag() {
         predecessor_results: std::collections::HashMap::new(),
         baseline_violations: std::collections::HashSet::new(),
         feedback_stats: None,
+        min_confidence: None,
+        sample_fraction: None,
+        source_lines: Default::default(),
     };
 
     let stable_results = orchestrator.execute(&stable_input).unwrap();
@@ -4117,6 +4167,7 @@ fn e2e_reporter_idempotency() {
                     owasp_category: Some("A03:2025".to_string()),
                     suppressed: false,
                     is_new: true,
+                    fingerprint: None,
                 },
             ],
         ),
@@ -5926,7 +5977,7 @@ fn e2e_convention_discovery_pipeline() {
         aliases: vec![], merged_from: vec![],
         confidence_mean: 0.92, confidence_stddev: 0.03,
         confidence_values: vec![0.92; 85],
-        is_dirty: false, location_hash: 0,
+        is_dirty: false, location_hash: 0, over_limit: false,
     };
 
     let emerging_pattern = AggregatedPattern {
@@ -5943,7 +5994,7 @@ fn e2e_convention_discovery_pipeline() {
         aliases: vec![], merged_from: vec![],
         confidence_mean: 0.88, confidence_stddev: 0.04,
         confidence_values: vec![0.88; 15],
-        is_dirty: false, location_hash: 0,
+        is_dirty: false, location_hash: 0, over_limit: false,
     };
 
     // Contested pair: two patterns with similar frequency in same category
@@ -5961,7 +6012,7 @@ fn e2e_convention_discovery_pipeline() {
         aliases: vec![], merged_from: vec![],
         confidence_mean: 0.9, confidence_stddev: 0.02,
         confidence_values: vec![0.9; 45],
-        is_dirty: false, location_hash: 0,
+        is_dirty: false, location_hash: 0, over_limit: false,
     };
 
     let contested_b = AggregatedPattern {
@@ -5978,7 +6029,7 @@ fn e2e_convention_discovery_pipeline() {
         aliases: vec![], merged_from: vec![],
         confidence_mean: 0.9, confidence_stddev: 0.02,
         confidence_values: vec![0.9; 40],
-        is_dirty: false, location_hash: 0,
+        is_dirty: false, location_hash: 0, over_limit: false,
     };
 
     let patterns = vec![dominant_pattern, emerging_pattern, contested_a, contested_b];
@@ -6550,6 +6601,7 @@ fn e2e_call_graph_incremental_update() {
             is_exported: exported, is_async: false, is_generator: false, is_abstract: false,
             range, decorators: vec![], doc_comment: None,
             body_hash: body, signature_hash: sig,
+            cognitive_complexity: 0,
         }
     };
 
@@ -6860,6 +6912,7 @@ fn e2e_gate_orchestrator_dag() {
             description: "Hardcoded password".to_string(),
             cwe_ids: vec![798],
             owasp_categories: vec!["A07:2021".to_string()],
+            confidence: None,
         },
     ];
 
@@ -6996,6 +7049,7 @@ fn e2e_policy_engine_all_modes() {
 fn e2e_reporter_all_formats() {
     use drift_analysis::enforcement::gates::{GateId, GateResult};
     use drift_analysis::enforcement::reporters::{create_reporter, available_formats, Reporter};
+    use drift_analysis::enforcement::reporters::ndjson::NdjsonRecord;
     use drift_analysis::enforcement::rules::{Severity, Violation};
 
     // Build gate results with violations for meaningful output
@@ -7016,6 +7070,7 @@ fn e2e_reporter_all_formats() {
             owasp_category: Some("A07:2021".to_string()),
             suppressed: false,
             is_new: true,
+            fingerprint: None,
         },
         Violation {
             id: "naming-001".to_string(),
@@ -7033,6 +7088,7 @@ fn e2e_reporter_all_formats() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            fingerprint: None,
         },
     ];
 
@@ -7057,7 +7113,7 @@ fn e2e_reporter_all_formats() {
     ];
 
     let formats = available_formats();
-    assert_eq!(formats.len(), 8, "Should have 8 reporter formats");
+    assert_eq!(formats.len(), 10, "Should have 10 reporter formats");
 
     eprintln!("[Reporters] Testing {} formats:", formats.len());
     for format in formats {
@@ -7100,6 +7156,23 @@ fn e2e_reporter_all_formats() {
                 assert!(content.contains("<html") || content.contains("<div"),
                     "HTML should contain HTML tags");
             }
+            "ndjson" => {
+                let lines: Vec<&str> = content.lines().collect();
+                let total: usize = gate_results.iter().map(|r| r.violations.len()).sum();
+                assert_eq!(lines.len(), total, "NDJSON should have one line per violation");
+                assert!(content.ends_with('\n'), "NDJSON lines should be newline-terminated");
+                for line in lines {
+                    let record: NdjsonRecord = serde_json::from_str(line)
+                        .unwrap_or_else(|e| panic!("NDJSON line is not valid JSON: {e}: {line}"));
+                    assert_eq!(record.gate_id, GateId::SecurityBoundaries);
+                }
+                let first: NdjsonRecord = serde_json::from_str(content.lines().next().unwrap()).unwrap();
+                assert_eq!(first.rule_id, "security/hardcoded-secret");
+                assert_eq!((first.file.as_str(), first.line), ("src/auth.ts", 42));
+                assert_eq!(first.severity, Severity::Error);
+                assert_eq!(first.cwe_id, Some(798));
+                assert_eq!(first.owasp_category.as_deref(), Some("A07:2021"));
+            }
             _ => {}
         }
     }
@@ -7155,6 +7228,7 @@ fn e2e_bayesian_confidence_scorer() {
         confidence_values: vec![0.95; 500],
         is_dirty: true,
         location_hash: 12345,
+        over_limit: false,
     };
 
     let score_a = scorer.score(&established_pattern, MomentumDirection::Stable, 365, None, None);
@@ -7186,6 +7260,7 @@ fn e2e_bayesian_confidence_scorer() {
         confidence_values: vec![0.3, 0.4, 0.5],
         is_dirty: true,
         location_hash: 67890,
+        over_limit: false,
     };
 
     let score_b = scorer.score(&uncertain_pattern, MomentumDirection::Falling, 1, None, None);
@@ -7219,6 +7294,7 @@ fn e2e_bayesian_confidence_scorer() {
         confidence_values: vec![0.8; 50],
         is_dirty: true,
         location_hash: 11111,
+        over_limit: false,
     };
 
     let score_c = scorer.score(&emerging_pattern, MomentumDirection::Rising, 30, None, None);
@@ -7449,6 +7525,7 @@ fn e2e_di_framework_detection() {
                     },
                 ],
                 body_hash: 100, signature_hash: 200,
+                cognitive_complexity: 0,
             },
         ],
         ..ParseResult::default()
@@ -7485,6 +7562,7 @@ fn e2e_di_framework_detection() {
                     },
                 ],
                 body_hash: 300, signature_hash: 400,
+                cognitive_complexity: 0,
             },
         ],
         ..ParseResult::default()
@@ -7505,6 +7583,7 @@ fn e2e_di_framework_detection() {
                 range: Range::default(), doc_comment: None,
                 decorators: vec![],
                 body_hash: 500, signature_hash: 600,
+                cognitive_complexity: 0,
             },
         ],
         ..ParseResult::default()
@@ -8251,6 +8330,7 @@ fn e2e_test_topology() {
                 is_exported: false, is_async: false, is_generator: false, is_abstract: false,
                 range: Range::default(), doc_comment: None,
                 decorators: vec![], body_hash: 10, signature_hash: 10,
+                cognitive_complexity: 0,
             },
         ],
         call_sites: vec![], // No assertions → AssertionFree
@@ -8377,6 +8457,7 @@ fn e2e_boundary_detector() {
                 ],
                 range: Range::default(),
                 decorators: vec![],
+                alias_target: None,
             },
         ],
         ..ParseResult::default()
@@ -9335,6 +9416,7 @@ fn e2e_quality_scorer() {
                 is_exported: false, is_async: false, is_generator: false, is_abstract: false,
                 range: Range::default(), doc_comment: None,
                 decorators: vec![], body_hash: 10, signature_hash: 10,
+                cognitive_complexity: 0,
             },
         ],
         call_sites: vec![
@@ -9370,6 +9452,7 @@ fn e2e_quality_scorer() {
                 is_exported: true, is_async: false, is_generator: false, is_abstract: false,
                 range: Range::default(), doc_comment: None,
                 decorators: vec![], body_hash: 1, signature_hash: 1,
+                cognitive_complexity: 0,
             },
         ],
         ..ParseResult::default()
@@ -10738,6 +10821,7 @@ fn e2e_pattern_similarity() {
             confidence_values: vec![],
             is_dirty: false,
             location_hash: 0,
+            over_limit: false,
         }
     };
 
@@ -11058,6 +11142,7 @@ fn e2e_learning_system() {
             confidence_values: vec![0.9; count as usize],
             is_dirty: false,
             location_hash: 0,
+            over_limit: false,
         }
     };
 
@@ -11541,6 +11626,7 @@ fn e2e_n_plus_one_detection() {
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            cognitive_complexity: 0,
         }],
         call_sites: vec![
             CallSite {
@@ -11597,6 +11683,7 @@ fn e2e_n_plus_one_detection() {
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            cognitive_complexity: 0,
         }],
         call_sites: vec![
             CallSite {
@@ -12639,6 +12726,7 @@ fn e2e_error_handling_antipatterns() {
             decorators: vec![],
             doc_comment: None,
             body_hash: 0, signature_hash: 0,
+            cognitive_complexity: 0,
         }],
         call_sites: vec![
             CallSite {
@@ -12680,6 +12768,7 @@ fn e2e_error_handling_antipatterns() {
             decorators: vec![],
             doc_comment: None,
             body_hash: 0, signature_hash: 0,
+            cognitive_complexity: 0,
         }],
         call_sites: vec![
             CallSite {
@@ -14300,3 +14389,219 @@ fn adversarial_batch_writer_empty_commands() {
     assert_eq!(count, 0);
     eprintln!("[CT-ADV-15] Empty commands handled gracefully");
 }
+
+// ============================================================================
+// ParseResult JSON Schema — serialized fixtures match the published contract
+// ============================================================================
+
+/// Check `value` against `schema`, covering the keywords schemars emits
+/// (`$ref`, `type`, `enum`, `minimum`, `anyOf`/`oneOf`/`allOf`,
+/// `properties`, `required`, `additionalProperties`, `items`).
+fn schema_errors(value: &serde_json::Value, schema: &serde_json::Value, root: &serde_json::Value, path: &str, errors: &mut Vec<String>) {
+    use serde_json::Value;
+
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        let name = reference.trim_start_matches("#/$defs/");
+        schema_errors(value, &root["$defs"][name], root, path, errors);
+    }
+    if let Some(types) = schema.get("type") {
+        let allowed: Vec<&str> = match types {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        let actual = match value {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(n) if n.is_u64() || n.is_i64() => "integer",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        };
+        if !allowed.iter().any(|t| *t == actual || (*t == "number" && actual == "integer")) {
+            errors.push(format!("{path}: expected {allowed:?}, got {actual}"));
+            return;
+        }
+    }
+    if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+        if !options.contains(value) {
+            errors.push(format!("{path}: {value} is not one of {options:?}"));
+        }
+    }
+    if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+        if value.as_f64().is_some_and(|v| v < min) {
+            errors.push(format!("{path}: {value} is below {min}"));
+        }
+    }
+    for keyword in ["anyOf", "oneOf"] {
+        if let Some(branches) = schema.get(keyword).and_then(Value::as_array) {
+            let passing = branches
+                .iter()
+                .filter(|branch| {
+                    let mut branch_errors = Vec::new();
+                    schema_errors(value, branch, root, path, &mut branch_errors);
+                    branch_errors.is_empty()
+                })
+                .count();
+            if passing == 0 || (keyword == "oneOf" && passing > 1) {
+                errors.push(format!("{path}: {passing} of the {keyword} branches match"));
+            }
+        }
+    }
+    for branch in schema.get("allOf").and_then(Value::as_array).into_iter().flatten() {
+        schema_errors(value, branch, root, path, errors);
+    }
+    if let Value::Object(map) = value {
+        let required = schema.get("required").and_then(Value::as_array).into_iter().flatten();
+        for field in required.filter_map(Value::as_str) {
+            if !map.contains_key(field) {
+                errors.push(format!("{path}: missing `{field}`"));
+            }
+        }
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (key, field) in map {
+            match properties.and_then(|p| p.get(key)) {
+                Some(field_schema) => schema_errors(field, field_schema, root, &format!("{path}.{key}"), errors),
+                None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                    errors.push(format!("{path}: unexpected `{key}`"));
+                }
+                None => {}
+            }
+        }
+    }
+    if let (Value::Array(items), Some(item_schema)) = (value, schema.get("items")) {
+        for (i, item) in items.iter().enumerate() {
+            schema_errors(item, item_schema, root, &format!("{path}[{i}]"), errors);
+        }
+    }
+}
+
+#[test]
+fn e2e_parse_result_matches_json_schema() {
+    use drift_analysis::parsers::types::parse_result_schema;
+
+    let schema = parse_result_schema();
+    assert_eq!(schema["$schema"], "https://json-schema.org/draft/2020-12/schema");
+    for name in ["FunctionInfo", "ClassInfo", "ImportInfo", "ExportInfo", "CallSite", "Range", "Language"] {
+        assert!(schema["$defs"].get(name).is_some(), "schema is missing $defs/{name}");
+    }
+
+    let parser = ParserManager::new();
+    let pr = parser
+        .parse(typescript_source().as_bytes(), Path::new("src/users/controller.ts"))
+        .unwrap();
+    assert!(!pr.functions.is_empty() && !pr.classes.is_empty() && !pr.imports.is_empty());
+    let value = serde_json::to_value(&pr).unwrap();
+
+    let mut errors = Vec::new();
+    schema_errors(&value, &schema, &schema, "$", &mut errors);
+    assert!(errors.is_empty(), "serialized ParseResult violates its schema: {errors:#?}");
+
+    // The check catches the kind of drift the schema exists for.
+    let mut missing = value.clone();
+    missing.as_object_mut().unwrap().remove("functions");
+    let mut retyped = value.clone();
+    retyped["functions"][0]["line"] = serde_json::json!("12");
+    for broken in [missing, retyped] {
+        let mut errors = Vec::new();
+        schema_errors(&broken, &schema, &schema, "$", &mut errors);
+        assert!(!errors.is_empty());
+    }
+}
+
+// ============================================================================
+// Rules: parameterized-query quick fixes for interpolated SQL
+// ============================================================================
+
+#[test]
+fn e2e_sql_injection_quick_fix_for_fixtures() {
+    use drift_analysis::enforcement::gates::{GateId, GateResult};
+    use drift_analysis::enforcement::rules::FixRange;
+
+    let sql_pattern = |file: &str, line: u32| PatternInfo {
+        pattern_id: "sql-injection".to_string(),
+        category: "security".to_string(),
+        confidence: 0.9,
+        locations: vec![],
+        outliers: vec![OutlierLocation {
+            file: file.to_string(),
+            line,
+            column: None,
+            end_line: None,
+            end_column: None,
+            deviation_score: 3.0,
+            message: "SQL query built by string interpolation".to_string(),
+        }],
+        cwe_ids: vec![89],
+        owasp_categories: vec!["A03:2021".to_string()],
+    };
+    let line_of = |source: &str, needle: &str| {
+        source.lines().position(|l| l.contains(needle)).unwrap() as u32 + 1
+    };
+
+    let ts_line = line_of(typescript_source(), "SELECT * FROM users");
+    let py_line = line_of(python_source(), "SELECT * FROM users");
+    let mut input = RulesInput {
+        patterns: vec![sql_pattern("src/users.ts", ts_line), sql_pattern("app/users.py", py_line)],
+        ..Default::default()
+    };
+    for (file, source) in [("src/users.ts", typescript_source()), ("app/users.py", python_source())] {
+        input
+            .source_lines
+            .insert(file.to_string(), source.lines().map(String::from).collect());
+    }
+
+    let violations = RulesEvaluator::new().evaluate(&input);
+    let fix_for = |file: &str| {
+        violations
+            .iter()
+            .find(|v| v.file == file)
+            .and_then(|v| v.quick_fix.clone())
+            .unwrap_or_else(|| panic!("no quick fix for {file}"))
+    };
+
+    // `const query = `SELECT * FROM users WHERE id = ${userId}`;`
+    let ts = fix_for("src/users.ts");
+    assert_eq!(ts.replacement.as_deref(), Some("'SELECT * FROM users WHERE id = ?'"));
+    assert_eq!(
+        ts.description,
+        "Use a parameterized query: replace the interpolated SQL with placeholders and pass [userId] as bound parameters where it is executed"
+    );
+    assert_eq!(
+        ts.range,
+        Some(FixRange { start_line: ts_line, start_column: 18, end_line: ts_line, end_column: 60 })
+    );
+
+    // `query = f"SELECT * FROM users WHERE id = {user_id}"`
+    let py = fix_for("app/users.py");
+    assert_eq!(py.replacement.as_deref(), Some("\"SELECT * FROM users WHERE id = %s\""));
+    assert_eq!(
+        py.description,
+        "Use a parameterized query: replace the interpolated SQL with placeholders and pass (user_id,) as bound parameters where it is executed"
+    );
+    assert_eq!(
+        py.range,
+        Some(FixRange { start_line: py_line, start_column: 12, end_line: py_line, end_column: 55 })
+    );
+
+    // The range covers exactly the interpolated literal
+    let ts_text = typescript_source().lines().nth(ts_line as usize - 1).unwrap();
+    assert_eq!(&ts_text[18..60], "`SELECT * FROM users WHERE id = ${userId}`");
+    let py_text = python_source().lines().nth(py_line as usize - 1).unwrap();
+    assert_eq!(&py_text[12..55], "f\"SELECT * FROM users WHERE id = {user_id}\"");
+
+    // SARIF carries the edit as an artifactChange with 1-based columns
+    let gate = GateResult::fail(GateId::SecurityBoundaries, 0.0, "SQL injection".to_string(), violations.clone());
+    let sarif = reporters::create_reporter("sarif").unwrap().generate(&[gate]).unwrap();
+    let sarif: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+    let ts_result = sarif["runs"][0]["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|r| r["locations"][0]["physicalLocation"]["artifactLocation"]["uri"] == "src/users.ts")
+        .unwrap();
+    let replacement = &ts_result["fixes"][0]["artifactChanges"][0]["replacements"][0];
+    assert_eq!(replacement["deletedRegion"]["startColumn"], 19);
+    assert_eq!(replacement["insertedContent"]["text"], "'SELECT * FROM users WHERE id = ?'");
+}
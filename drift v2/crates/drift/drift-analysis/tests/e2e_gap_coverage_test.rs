@@ -275,7 +275,8 @@ fn isolation_zero_violations_gates_policy_reporters() {
     for &format in formats {
         let reporter = reporters::create_reporter(format).unwrap();
         match reporter.generate(&results) {
-            Ok(output) => assert!(!output.is_empty(), "{format} should produce output"),
+            // NDJSON writes one line per violation, so a clean run is empty
+            Ok(output) => assert!(!output.is_empty() || format == "ndjson", "{format} should produce output"),
             Err(e) => eprintln!("  [WARN] {format}: {e}"),
         }
     }
@@ -523,6 +524,9 @@ fn edge_case_gate_input_with_feedback_stats() {
 
     let input = GateInput {
         feedback_stats: Some(Arc::new(MockStats)),
+        min_confidence: None,
+        sample_fraction: None,
+        source_lines: Default::default(),
         files: vec!["src/test.ts".to_string()],
         ..GateInput::default()
     };
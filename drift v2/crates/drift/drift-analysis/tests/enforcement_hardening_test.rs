@@ -330,12 +330,15 @@ fn eft_gate_12_regression_fails_on_new_errors() {
                 cwe_id: None,
                 owasp_category: None,
                 suppressed: false,
-                is_new: true, // This is a NEW error
+                is_new: true, // This is a NEW error,
+                fingerprint: None,
             }],
             warnings: vec![],
             execution_time_ms: 0,
             details: serde_json::Value::Null,
             error: None,
+            explanation: None,
+            advisories: Vec::new(),
         },
     );
 
@@ -381,12 +384,15 @@ fn eft_gate_13_regression_passes_existing_only() {
                 cwe_id: None,
                 owasp_category: None,
                 suppressed: false,
-                is_new: false, // NOT new
+                is_new: false, // NOT new,
+                fingerprint: None,
             }],
             warnings: vec![],
             execution_time_ms: 0,
             details: serde_json::Value::Null,
             error: None,
+            explanation: None,
+            advisories: Vec::new(),
         },
     );
 
@@ -432,6 +438,7 @@ fn eft_gate_14_orchestrator_dependency_order() {
             severity: "medium".to_string(),
             cwe_ids: vec![79],
             owasp_categories: vec![],
+            confidence: None,
         }])
         .error_gaps(vec![ErrorGapInput {
             file: "src/a.ts".to_string(),
@@ -600,6 +607,7 @@ fn eft_gate_18_full_pipeline_real_scores() {
             severity: "critical".to_string(),
             cwe_ids: vec![89],
             owasp_categories: vec!["A03:2021-Injection".to_string()],
+            confidence: None,
         }])
         .error_gaps(vec![ErrorGapInput {
             file: "src/handler.ts".to_string(),
@@ -961,6 +969,8 @@ fn eft_rule_12_policy_fails_missing_required_gate() {
         execution_time_ms: 1,
         details: serde_json::Value::Null,
         error: None,
+        explanation: None,
+        advisories: Vec::new(),
     }];
 
     let decision = engine.evaluate(&results);
@@ -991,6 +1001,8 @@ fn eft_rule_13_policy_passes_all_required_present() {
         execution_time_ms: 1,
         details: serde_json::Value::Null,
         error: None,
+        explanation: None,
+        advisories: Vec::new(),
     }];
 
     let decision = engine.evaluate(&results);
@@ -1023,11 +1035,13 @@ fn make_reporter_test_results() -> Vec<GateResult> {
                     strategy: QuickFixStrategy::UseParameterizedQuery,
                     description: "Use parameterized query".to_string(),
                     replacement: None,
+                    range: None,
                 }),
                 cwe_id: Some(89),
                 owasp_category: Some("A03:2021-Injection".to_string()),
                 suppressed: false,
                 is_new: true,
+                fingerprint: None,
             },
             Violation {
                 id: "v2".to_string(),
@@ -1045,6 +1059,7 @@ fn make_reporter_test_results() -> Vec<GateResult> {
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                fingerprint: None,
             },
             Violation {
                 id: "v3".to_string(),
@@ -1062,12 +1077,15 @@ fn make_reporter_test_results() -> Vec<GateResult> {
                 owasp_category: None,
                 suppressed: true, // suppressed — should be excluded from most outputs
                 is_new: false,
+                fingerprint: None,
             },
         ],
         warnings: vec!["Health score dropped 5 points".to_string()],
         execution_time_ms: 42,
         details: serde_json::Value::Null,
         error: None,
+        explanation: None,
+        advisories: Vec::new(),
     }]
 }
 
@@ -1263,6 +1281,8 @@ fn eft_rpt_08_junit_skipped_gate() {
         execution_time_ms: 0,
         details: serde_json::Value::Null,
         error: None,
+        explanation: None,
+        advisories: Vec::new(),
     }];
     let reporter = JUnitReporter::new();
     let output = reporter.generate(&results).unwrap();
@@ -1392,13 +1412,13 @@ fn eft_rpt_15_html_new_badge() {
     assert!(output.contains(">NEW</span>"), "HTML should show NEW badge text");
 }
 
-/// EFT-RPT-16: create_reporter returns all 8 formats.
+/// EFT-RPT-16: create_reporter returns all 10 formats.
 #[test]
 fn eft_rpt_16_all_formats_available() {
     use drift_analysis::enforcement::reporters::{create_reporter, available_formats};
 
     let formats = available_formats();
-    assert_eq!(formats.len(), 8, "Should have 8 reporter formats");
+    assert_eq!(formats.len(), 10, "Should have 10 reporter formats");
 
     for format in formats {
         let reporter = create_reporter(format);
@@ -2111,7 +2131,7 @@ fn eft_napi_08_gate_id_mapping() {
 fn eft_napi_09_create_reporter_all_formats() {
     use drift_analysis::enforcement::reporters::create_reporter;
 
-    let valid_formats = ["sarif", "json", "html", "junit", "sonarqube", "console", "github", "gitlab"];
+    let valid_formats = ["sarif", "json", "html", "junit", "sonarqube", "console", "github", "gitlab", "vulnreport"];
     for fmt in &valid_formats {
         let reporter = create_reporter(fmt);
         assert!(reporter.is_some(), "create_reporter should return Some for format '{fmt}'");
@@ -2145,6 +2165,7 @@ fn eft_napi_10_reporter_generates_from_gate_results() {
         owasp_category: Some("A03:2021-Injection".to_string()),
         suppressed: false,
         is_new: true,
+        fingerprint: None,
     }];
 
     let gate_results = vec![GateResult {
@@ -2158,6 +2179,8 @@ fn eft_napi_10_reporter_generates_from_gate_results() {
         execution_time_ms: 10,
         details: serde_json::Value::Null,
         error: None,
+        explanation: None,
+        advisories: Vec::new(),
     }];
 
     // SARIF reporter
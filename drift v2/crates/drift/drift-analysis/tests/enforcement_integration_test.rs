@@ -151,6 +151,7 @@ fn test_full_enforcement_round_trip() {
             severity: "error".to_string(),
             cwe_ids: vec![89],
             owasp_categories: vec!["A03:2021-Injection".to_string()],
+            confidence: None,
         }],
         test_coverage: Some(TestCoverageInput {
             overall_coverage: 82.0,
@@ -338,6 +339,7 @@ fn test_napi_type_contracts() {
         owasp_category: Some("A03:2021".to_string()),
         suppressed: false,
         is_new: true,
+        fingerprint: None,
     };
     let json = serde_json::to_string(&v).unwrap();
     assert!(json.contains("\"severity\":\"error\""));
@@ -463,11 +465,14 @@ fn test_sarif_upload_format() {
             owasp_category: Some("A03:2021-Injection".to_string()),
             suppressed: false,
             is_new: true,
+            fingerprint: None,
         }],
         warnings: vec![],
         execution_time_ms: 10,
         details: serde_json::Value::Null,
         error: None,
+        explanation: None,
+        advisories: Vec::new(),
     }];
 
     let sarif_output = reporter.generate(&results).unwrap();
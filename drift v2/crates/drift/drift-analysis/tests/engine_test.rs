@@ -1,5 +1,5 @@
 #![allow(dead_code, unused_imports, clippy::field_reassign_with_default)]
-//! Engine tests — T2-UAE-01 through T2-UAE-15.
+//! Engine tests — T2-UAE-01 through T2-UAE-18.
 //!
 //! Tests for the Unified Analysis Engine: 4-phase pipeline, GAST normalization,
 //! visitor pattern, string extraction, regex engine, resolution index, TOML patterns.
@@ -827,3 +827,249 @@ fn t2_uae_15_content_hash_skip() {
         "string extraction should be deterministic"
     );
 }
+
+// ---- T2-UAE-16: User-defined tree-sitter queries ----
+
+#[test]
+fn t2_uae_16_user_query_matches_eval() {
+    use drift_analysis::engine::ts_query::UserQuery;
+    use drift_analysis::enforcement::rules::Severity;
+
+    let query_file = r#"
+; id: user-no-eval
+; language: javascript
+; severity: error
+; category: security
+; cwe: 95
+(call_expression function: (identifier) @fn (#eq? @fn "eval"))
+"#;
+    let query = UserQuery::load_from_str(query_file).unwrap();
+    assert_eq!(query.id, "user-no-eval");
+    assert_eq!(query.language, Language::JavaScript);
+    assert_eq!(query.severity, Severity::Error);
+    assert_eq!(query.category, PatternCategory::Security);
+
+    let source = b"const a = eval(input);\nconst b = evaluate(input);\nfoo.eval(x);\n";
+    let mut ts_parser = tree_sitter::Parser::new();
+    ts_parser
+        .set_language(&tree_sitter_javascript::LANGUAGE.into())
+        .unwrap();
+    let tree = ts_parser.parse(source, None).unwrap();
+
+    let matches = query.run(&tree, source, "app.js");
+    assert_eq!(matches.len(), 1, "only the bare eval() call should match: {:?}", matches);
+    assert_eq!(matches[0].pattern_id, "user-no-eval");
+    assert_eq!(matches[0].line, 0);
+    assert_eq!(matches[0].matched_text, "eval");
+    assert_eq!(matches[0].cwe_ids.as_slice(), &[95]);
+}
+
+#[test]
+fn t2_uae_16_user_query_rejects_invalid() {
+    use drift_analysis::engine::ts_query::UserQuery;
+
+    let missing_id = "; language: javascript\n(identifier) @id\n";
+    assert!(UserQuery::load_from_str(missing_id).is_err());
+
+    let bad_syntax = "; id: broken\n; language: javascript\n(call_expression function:\n";
+    assert!(UserQuery::load_from_str(bad_syntax).is_err());
+
+    let bad_capture = "; id: cap\n; language: javascript\n; capture: missing\n(identifier) @id\n";
+    assert!(UserQuery::load_from_str(bad_capture).is_err());
+}
+
+#[test]
+fn t2_uae_16_user_query_load_dir() {
+    use drift_analysis::engine::ts_query::UserQuery;
+
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("no-eval.scm"),
+        "; id: no-eval\n; language: js\n(call_expression function: (identifier) @fn (#eq? @fn \"eval\"))\n",
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("broken.scm"), "; id: broken\n(((\n").unwrap();
+    std::fs::write(dir.path().join("notes.txt"), "not a query").unwrap();
+
+    let queries = UserQuery::load_dir(dir.path());
+    assert_eq!(queries.len(), 1);
+    assert_eq!(queries[0].id, "no-eval");
+
+    assert!(UserQuery::load_dir(&dir.path().join("missing")).is_empty());
+}
+
+// ---- T2-UAE-17: All findings for one file, sorted by line ----
+
+#[test]
+fn t2_uae_17_findings_for_file() {
+    use drift_analysis::engine::orchestrator::{findings_for_file, ComplexityWarning, Finding, RunResults};
+    use drift_analysis::engine::types::{AnalysisResult, DetectionMethod};
+    use drift_analysis::graph::taint::{SinkType, SourceType, TaintFlow, TaintLabel, TaintSink, TaintSource};
+    use drift_analysis::structural::constants::{Secret, SecretSeverity};
+    use drift_analysis::structural::constraints::{ConstraintViolation, VerificationResult};
+    use drift_analysis::structural::crypto::{CryptoCategory, CryptoFinding};
+
+    let pattern = |file: &str, line: u32| PatternMatch {
+        file: file.to_string(),
+        line,
+        column: 4,
+        pattern_id: "SEC-EVAL-001".to_string(),
+        confidence: 0.9,
+        cwe_ids: Default::default(),
+        owasp: None,
+        detection_method: DetectionMethod::AstVisitor,
+        category: PatternCategory::Security,
+        matched_text: "eval()".to_string(),
+    };
+    let flow = TaintFlow {
+        source: TaintSource {
+            file: "src/routes.ts".to_string(),
+            line: 2,
+            column: 0,
+            expression: "req.query.id".to_string(),
+            source_type: SourceType::UserInput,
+            label: TaintLabel::new(1, SourceType::UserInput),
+        },
+        sink: TaintSink {
+            file: "src/foo.ts".to_string(),
+            line: 19,
+            column: 2,
+            expression: "db.query(sql)".to_string(),
+            sink_type: SinkType::SqlQuery,
+            required_sanitizers: Vec::new(),
+        },
+        path: Vec::new(),
+        is_sanitized: false,
+        sanitizers_applied: Vec::new(),
+        cwe_id: Some(89),
+        confidence: 0.8,
+    };
+    let results = RunResults {
+        files: vec![
+            AnalysisResult { file: "src/foo.ts".to_string(), matches: vec![pattern("src/foo.ts", 29)], ..Default::default() },
+            AnalysisResult { file: "src/bar.ts".to_string(), matches: vec![pattern("src/bar.ts", 1)], ..Default::default() },
+        ],
+        taint_flows: vec![flow],
+        crypto: vec![CryptoFinding {
+            file: "src/foo.ts".to_string(),
+            line: 5,
+            category: CryptoCategory::WeakHash,
+            description: "MD5".to_string(),
+            code: "createHash('md5')".to_string(),
+            confidence: 0.9,
+            cwe_id: 328,
+            owasp: "A02:2021".to_string(),
+            remediation: "Use SHA-256".to_string(),
+            language: "typescript".to_string(),
+            suggested_replacement: None,
+            range: None,
+        }],
+        secrets: vec![Secret {
+            pattern_name: "aws_access_key".to_string(),
+            redacted_value: "AKIA****".to_string(),
+            file: "src/foo.ts".to_string(),
+            line: 12,
+            severity: SecretSeverity::Critical,
+            entropy: 4.5,
+            confidence: 0.95,
+            cwe_ids: vec![798],
+        }],
+        constraints: vec![VerificationResult {
+            constraint_id: "max-file-size".to_string(),
+            passed: false,
+            violations: vec![ConstraintViolation {
+                file: "src/foo.ts".to_string(),
+                line: None,
+                message: "File exceeds size limit".to_string(),
+                expected: "<= 500".to_string(),
+                actual: "812".to_string(),
+            }],
+        }],
+        complexity: vec![ComplexityWarning {
+            file: "src/foo.ts".to_string(),
+            function: "handle".to_string(),
+            line: 39,
+            metric: "cyclomatic".to_string(),
+            value: 24.0,
+            threshold: 15.0,
+        }],
+    };
+
+    let findings = findings_for_file(&results, "src/foo.ts");
+    let kinds: Vec<(&str, u32)> = findings
+        .findings
+        .iter()
+        .map(|f| {
+            let kind = match &f.finding {
+                Finding::Pattern(_) => "pattern",
+                Finding::Taint(_) => "taint",
+                Finding::Crypto(_) => "crypto",
+                Finding::Secret(_) => "secret",
+                Finding::Constraint { .. } => "constraint",
+                Finding::Complexity(_) => "complexity",
+            };
+            (kind, f.line)
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![("constraint", 0), ("crypto", 5), ("secret", 12), ("taint", 20), ("pattern", 30), ("complexity", 40)]
+    );
+
+    // The flow's source file sees it at the source; unrelated files see nothing.
+    let routes = findings_for_file(&results, "src/routes.ts");
+    assert_eq!(routes.len(), 1);
+    assert_eq!(routes.findings[0].line, 3);
+    assert!(findings_for_file(&results, "src/none.ts").is_empty());
+
+    let json = serde_json::to_value(&findings).unwrap();
+    assert_eq!(json["findings"][0]["kind"], "constraint");
+    assert_eq!(json["findings"][5]["data"]["metric"], "cyclomatic");
+}
+
+// ---- T2-UAE-18: Patch analysis reports findings only on added lines ----
+
+#[test]
+fn t2_uae_18_analyze_patch_scopes_findings_to_added_lines() {
+    use drift_analysis::engine::patch::{analyze_patch, parse_unified_diff};
+    use drift_analysis::parsers::manager::ParserManager;
+
+    // eval() already on line 2 of the base; the patch adds a second one.
+    let base = "function render(input) {\n  const legacy = eval(input.old);\n  return legacy;\n}\n\nfunction untouched() {\n  return 1;\n}\n";
+    let new = "function render(input) {\n  const legacy = eval(input.old);\n  const value = eval(input.code);\n  return legacy + value;\n}\n\nfunction untouched() {\n  return 1;\n}\n";
+    let diff = "diff --git a/src/render.js b/src/render.js
+index 3b18e51..a9c2f40 100644
+--- a/src/render.js
++++ b/src/render.js
+@@ -1,4 +1,5 @@
+ function render(input) {
+   const legacy = eval(input.old);
+-  return legacy;
++  const value = eval(input.code);
++  return legacy + value;
+ }
+";
+    let patches = parse_unified_diff(diff);
+    assert_eq!(patches.len(), 1);
+    assert_eq!(patches[0].new_path.as_deref(), Some("src/render.js"));
+    assert_eq!(patches[0].added_lines, vec![2, 3]);
+    assert_eq!(patches[0].removed_lines, vec![2]);
+
+    let base_results = vec![ParserManager::new().parse(base.as_bytes(), Path::new("src/render.js")).unwrap()];
+    let analysis = analyze_patch(diff, &base_results, |path| (path == "src/render.js").then(|| new.as_bytes().to_vec()));
+
+    let evals: Vec<&PatternMatch> = analysis.findings.iter().filter(|m| m.pattern_id == "SEC-EVAL-001").collect();
+    assert_eq!(evals.len(), 1, "only the added eval is reported: {:?}", analysis.findings);
+    assert_eq!(evals[0].line, 2);
+    assert!(analysis.findings.iter().all(|m| [2, 3].contains(&m.line)));
+
+    assert_eq!(analysis.files.len(), 1);
+    assert_eq!(analysis.files[0].touched_functions, vec!["render".to_string()]);
+    let changes = analysis.files[0].symbol_changes.as_ref().unwrap();
+    assert!(changes.functions.modified.iter().any(|f| f.contains("render")), "{changes:?}");
+
+    // Without new content the file is skipped, not guessed.
+    let analysis = analyze_patch(diff, &base_results, |_| None);
+    assert!(analysis.findings.is_empty());
+    assert_eq!(analysis.skipped, vec![("src/render.js".to_string(), "new content unavailable".to_string())]);
+}
@@ -1,7 +1,8 @@
 #![allow(clippy::cloned_ref_to_slice_refs)]
-//! T4-ERR-01 through T4-ERR-05: Error handling analysis tests.
+//! T4-ERR-01 through T4-ERR-06: Error handling analysis tests.
 
 use drift_analysis::call_graph::types::{CallEdge, CallGraph, FunctionNode, Resolution};
+use drift_analysis::detectors::test_support::parse_snippet;
 use drift_analysis::graph::error_handling::*;
 use drift_analysis::parsers::types::*;
 use drift_analysis::scanner::language_detect::Language;
@@ -48,6 +49,7 @@ fn make_parse_result(
         error_count: 0,
         error_ranges: Vec::new(),
         has_errors: false,
+        truncated_at_depth: None,
     }
 }
 
@@ -72,6 +74,7 @@ fn make_function(name: &str, line: u32, end_line: u32, is_async: bool) -> Functi
         doc_comment: None,
         body_hash: 0,
         signature_hash: 0,
+        cognitive_complexity: 0,
     }
 }
 
@@ -366,6 +369,71 @@ fn test_async_error_handling() {
     assert!(handled_async.is_empty(), "Handled async should not be flagged");
 }
 
+// T4-ERR-06: Interval relations — catch inside try inside with nests,
+// sequential regions are ordered, and pairs never cross functions
+#[test]
+fn test_region_relationships_nesting() {
+    let source = r#"
+def fetch(path):
+    with open(path) as f:
+        try:
+            data = f.read()
+        except IOError:
+            try:
+                data = f.read()
+            except Exception:
+                pass
+            log.warning("retried")
+        f.close()
+    return data
+
+def save(path, data):
+    try:
+        write(path, data)
+    except IOError:
+        pass
+    try:
+        flush(path)
+    except IOError:
+        pass
+"#;
+    let pr = parse_snippet(source, Language::Python);
+    let region = |kind: ErrorHandlingKind, line: u32| {
+        let idx = pr
+            .error_handling
+            .iter()
+            .position(|eh| eh.kind == kind && eh.line == line)
+            .unwrap_or_else(|| panic!("no {kind:?} region at line {line}: {:?}", pr.error_handling));
+        RegionId(idx)
+    };
+    let with = region(ErrorHandlingKind::WithStatement, 2);
+    let outer_try = region(ErrorHandlingKind::TryExcept, 3);
+    let retry = region(ErrorHandlingKind::TryExcept, 6);
+    let first_save = region(ErrorHandlingKind::TryExcept, 15);
+    let second_save = region(ErrorHandlingKind::TryExcept, 19);
+
+    let relations = region_relationships(&pr);
+    let relation = |a: RegionId, b: RegionId| {
+        relations.iter().find_map(|&(x, y, rel)| {
+            if (x, y) == (a, b) {
+                Some(rel)
+            } else if (x, y) == (b, a) {
+                Some(rel.inverse())
+            } else {
+                None
+            }
+        })
+    };
+
+    assert_eq!(relation(with, outer_try), Some(IntervalRelation::Contains));
+    assert_eq!(relation(outer_try, retry), Some(IntervalRelation::Contains));
+    assert_eq!(relation(retry, with), Some(IntervalRelation::During));
+    assert_eq!(relation(first_save, second_save), Some(IntervalRelation::Before));
+    assert!(!IntervalRelation::Before.intersects());
+    assert_eq!(relation(with, first_save), None, "regions in different functions are not related");
+    assert!(relations.iter().all(|(a, b, _)| a < b));
+}
+
 // Additional: CWE mapping verification
 #[test]
 fn test_cwe_mapping() {
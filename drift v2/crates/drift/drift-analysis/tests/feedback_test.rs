@@ -1,5 +1,5 @@
 //! Phase 6 tests: Feedback Loop — FP Tracking & Auto-Disable
-//! T6-FBK-01 through T6-FBK-06
+//! T6-FBK-01 through T6-FBK-07
 
 use drift_analysis::enforcement::feedback::*;
 
@@ -225,3 +225,65 @@ fn test_feedback_stats_provider_trait() {
     assert!(!stats.is_detector_disabled("clean"));
     assert_eq!(stats.total_actions_for_detector("any"), 42);
 }
+
+/// T6-FBK-07: Calibration report buckets confidence against observed outcomes.
+#[test]
+fn test_calibration_report() {
+    use drift_analysis::enforcement::feedback::calibration::CalibrationVerdict;
+
+    let mut tracker = FeedbackTracker::new();
+    let fp = Some(DismissalReason::FalsePositive);
+    let mut ts = 0;
+    let mut record = |tracker: &mut FeedbackTracker, detector: &str, confidence: f64, action, reason| {
+        ts += 1;
+        tracker.record_with_confidence(&make_record(detector, action, reason, None, ts), confidence);
+    };
+
+    // "sql": reports 0.9 but only half are real; 0.35 findings are all real.
+    for i in 0..8 {
+        let (action, reason) = if i % 2 == 0 { (FeedbackAction::Fix, None) } else { (FeedbackAction::Dismiss, fp) };
+        record(&mut tracker, "sql", 0.9, action, reason);
+    }
+    for _ in 0..4 {
+        record(&mut tracker, "sql", 0.35, FeedbackAction::Escalate, None);
+    }
+    // Outcomes that say nothing about correctness are not samples.
+    record(&mut tracker, "sql", 0.9, FeedbackAction::Suppress, None);
+    record(&mut tracker, "sql", 0.9, FeedbackAction::Dismiss, Some(DismissalReason::WontFix));
+
+    // "xss": 0.7 findings, 7 of 10 real — calibrated.
+    for i in 0..10 {
+        let (action, reason) = if i < 7 { (FeedbackAction::Fix, None) } else { (FeedbackAction::Dismiss, fp) };
+        record(&mut tracker, "xss", 0.7, action, reason);
+    }
+    // "new": too few outcomes to judge.
+    record(&mut tracker, "new", 0.5, FeedbackAction::Fix, None);
+
+    let report = calibration_report(&tracker);
+    let ids: Vec<&str> = report.detectors.iter().map(|d| d.detector_id.as_str()).collect();
+    assert_eq!(ids, vec!["new", "sql", "xss"]);
+
+    let sql = report.detector("sql").unwrap();
+    assert_eq!(sql.samples, 12);
+    assert_eq!(sql.buckets.len(), 2);
+    let low = &sql.buckets[0];
+    assert_eq!((low.lower, low.upper, low.count), (0.3, 0.4, 4));
+    assert!((low.mean_confidence - 0.35).abs() < 1e-9);
+    assert_eq!(low.true_positive_rate, 1.0);
+    let high = &sql.buckets[1];
+    assert_eq!((high.lower, high.count), (0.9, 8));
+    assert_eq!(high.true_positive_rate, 0.5);
+    // ECE = 8/12 * 0.4 + 4/12 * 0.65; gap = 8/12 * 0.4 - 4/12 * 0.65
+    assert!((sql.expected_calibration_error - (0.8 / 3.0 + 0.65 / 3.0)).abs() < 1e-9);
+    assert!((sql.confidence_gap - (0.8 / 3.0 - 0.65 / 3.0)).abs() < 1e-9);
+    assert_eq!(sql.verdict, CalibrationVerdict::Overconfident);
+
+    let xss = report.detector("xss").unwrap();
+    assert_eq!(xss.buckets[0].true_positive_rate, 0.7);
+    assert!(xss.expected_calibration_error < 1e-9);
+    assert_eq!(xss.verdict, CalibrationVerdict::Calibrated);
+    assert_eq!(report.detector("new").unwrap().verdict, CalibrationVerdict::InsufficientData);
+
+    let miscalibrated: Vec<&str> = report.miscalibrated().iter().map(|d| d.detector_id.as_str()).collect();
+    assert_eq!(miscalibrated, vec!["sql"]);
+}
@@ -354,6 +354,7 @@ decorators = ["Service"]
                 start: Position { line: 2, column: 0 },
                 end: Position { line: 10, column: 1 },
             },
+            alias_target: None,
         }],
         ..Default::default()
     };
@@ -895,6 +896,7 @@ deviation_threshold = 0.15
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            cognitive_complexity: 0,
         }
     }
 
@@ -1065,6 +1067,7 @@ deviation_threshold = 0.15
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            cognitive_complexity: 0,
         }
     }
 
@@ -1181,7 +1184,7 @@ content_patterns = ["export"]
     };
     let ctx = DetectionContext::from_parse_result(&pr, source);
     matcher.analyze_file(&ctx);
-    assert!(matcher.results().len() >= 1, "Should match file in types directory");
+    assert!(!matcher.results().is_empty(), "Should match file in types directory");
 }
 
 /// FWT-PRED-03: type_annotations \\bany\\b matches function with param: any
@@ -1228,6 +1231,7 @@ type_annotations = ["\\bany\\b"]
             is_exported: false, is_async: false, is_generator: false, is_abstract: false,
             range: Range { start: Position { line: 1, column: 0 }, end: Position { line: 1, column: 34 } },
             decorators: vec![], doc_comment: None, body_hash: 0, signature_hash: 0,
+            cognitive_complexity: 0,
         }],
         ..Default::default()
     };
@@ -1284,6 +1288,7 @@ type_annotations = ["Request|Response"]
             is_exported: false, is_async: false, is_generator: false, is_abstract: false,
             range: Range { start: Position { line: 2, column: 0 }, end: Position { line: 2, column: 20 } },
             decorators: vec![], doc_comment: None, body_hash: 0, signature_hash: 0,
+            cognitive_complexity: 0,
         }],
         ..Default::default()
     };
@@ -1319,12 +1324,13 @@ type_annotations = ["Request|Response"]
             is_exported: false, is_async: false, is_generator: false, is_abstract: false,
             range: Range { start: Position { line: 2, column: 0 }, end: Position { line: 2, column: 32 } },
             decorators: vec![], doc_comment: None, body_hash: 0, signature_hash: 0,
+            cognitive_complexity: 0,
         }],
         ..Default::default()
     };
     let ctx2 = DetectionContext::from_parse_result(&pr2, source2);
     matcher2.analyze_file(&ctx2);
-    assert!(matcher2.results().len() >= 1, "Import + type annotation = match");
+    assert!(!matcher2.results().is_empty(), "Import + type annotation = match");
 }
 
 /// FWT-PRED-05: file_patterns + content_patterns AND: matches only in matching files with matching content
@@ -1849,12 +1855,14 @@ signal = "convention"
 #[test]
 fn fwt_diag_06_diagnostics_summary_format() {
     use drift_analysis::frameworks::FrameworkDiagnostics;
-    let mut diag = FrameworkDiagnostics::default();
-    diag.builtin_packs_loaded = 22;
-    diag.total_patterns_compiled = 150;
-    diag.files_processed = 100;
-    diag.total_hits = 42;
-    diag.learning_deviations = 3;
+    let diag = FrameworkDiagnostics {
+        builtin_packs_loaded: 22,
+        total_patterns_compiled: 150,
+        files_processed: 100,
+        total_hits: 42,
+        learning_deviations: 3,
+        ..Default::default()
+    };
     let summary = diag.summary();
     assert!(summary.contains("[drift-analyze] framework diagnostics:"), "Should contain prefix");
     assert!(summary.contains("22 builtin"), "Should contain builtin count");
@@ -2354,6 +2362,7 @@ signal = "frequency"
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            cognitive_complexity: 0,
         }
     }
 
@@ -2449,6 +2458,7 @@ signal = "frequency"
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            cognitive_complexity: 0,
         }
     }
 
@@ -2552,6 +2562,7 @@ signal = "presence"
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            cognitive_complexity: 0,
         }
     }
 
@@ -2646,6 +2657,7 @@ signal = "co_occurrence"
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            cognitive_complexity: 0,
         }
     }
 
@@ -2731,6 +2743,7 @@ signal = "nonexistent_signal_type"
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            cognitive_complexity: 0,
         }
     }
 
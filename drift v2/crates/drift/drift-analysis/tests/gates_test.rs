@@ -1,5 +1,5 @@
 //! Phase 6 tests: Quality Gates — DAG Orchestration & Progressive Enforcement
-//! T6-GAT-01 through T6-GAT-08
+//! T6-GAT-01 through T6-GAT-11
 
 use drift_analysis::enforcement::gates::*;
 use drift_analysis::enforcement::rules::*;
@@ -47,6 +47,9 @@ fn make_gate_input() -> GateInput {
         predecessor_results: std::collections::HashMap::new(),
         baseline_violations: std::collections::HashSet::new(),
         feedback_stats: None,
+        min_confidence: None,
+        sample_fraction: None,
+        source_lines: Default::default(),
     }
 }
 
@@ -284,3 +287,191 @@ fn test_regression_gate_health_drop() {
     assert!(result.summary.contains("Critical") || result.summary.contains("critical"),
         "Should indicate critical regression");
 }
+
+/// T6-GAT-09: Gate explanation lists the metric, threshold and contributing violations.
+#[test]
+fn test_gate_explanation_lists_contributing_violations() {
+    use drift_analysis::enforcement::gates::error_handling::ErrorHandlingGate;
+    use drift_analysis::enforcement::reporters::console::ConsoleReporter;
+    use drift_analysis::enforcement::reporters::Reporter;
+
+    let gap = |file: &str, line: u32, gap_type: &str| ErrorGapInput {
+        file: file.to_string(),
+        line,
+        gap_type: gap_type.to_string(),
+        message: format!("{gap_type} at {file}:{line}"),
+    };
+    let input = GateInput {
+        error_gaps: vec![
+            gap("src/a.ts", 10, "swallowed"),
+            gap("src/b.ts", 5, "empty_catch"),
+            gap("src/a.ts", 3, "swallowed"),
+            gap("src/c.ts", 7, "unhandled"),
+        ],
+        ..Default::default()
+    };
+
+    let result = ErrorHandlingGate.evaluate(&input);
+    assert!(!result.passed);
+    let explanation = result.explanation.as_ref().expect("failed gate should be explained");
+
+    assert_eq!(explanation.value, 3.0);
+    assert_eq!(explanation.threshold, 0.0);
+    assert_eq!(explanation.headline(), "3 critical error handling gaps (threshold: 0)");
+    assert!(explanation.metrics.contains(&GateMetric {
+        name: "empty catch blocks".to_string(),
+        value: 1.0,
+    }));
+
+    // Only the error-severity gaps pushed the gate over, sorted by location.
+    let contributing: Vec<(&str, u32)> = explanation
+        .contributing
+        .iter()
+        .map(|f| (f.file.as_str(), f.line))
+        .collect();
+    assert_eq!(contributing, vec![("src/a.ts", 3), ("src/a.ts", 10), ("src/c.ts", 7)]);
+    assert!(explanation.contributing.iter().all(|f| f.severity == Severity::Error));
+
+    let report = ConsoleReporter::new(false).generate(&[result]).unwrap();
+    assert!(report.contains("3 critical error handling gaps (threshold: 0)"));
+
+    // Skipped gates carry no explanation.
+    assert!(ErrorHandlingGate.evaluate(&GateInput::default()).explanation.is_none());
+}
+
+/// T6-GAT-10: Patterns below min_confidence are not scored but are listed as advisories.
+#[test]
+fn test_min_confidence_moves_findings_to_advisories() {
+    let pattern = |id: &str, confidence: f64, outlier_line: u32| PatternInfo {
+        pattern_id: id.to_string(),
+        category: "naming".to_string(),
+        confidence,
+        locations: vec![PatternLocation {
+            file: "src/main.ts".to_string(),
+            line: 1,
+            column: None,
+        }],
+        outliers: vec![OutlierLocation {
+            file: "src/main.ts".to_string(),
+            line: outlier_line,
+            message: "deviation".to_string(),
+            ..Default::default()
+        }],
+        cwe_ids: vec![],
+        owasp_categories: vec![],
+    };
+    let input = GateInputBuilder::new()
+        .patterns(vec![pattern("noisy", 0.4, 20), pattern("solid", 0.95, 30)])
+        .min_confidence(0.6)
+        .build();
+
+    let results = GateOrchestrator::new().execute(&input).unwrap();
+    let compliance = results
+        .iter()
+        .find(|r| r.gate_id == GateId::PatternCompliance)
+        .unwrap();
+
+    // Only the 0.95 pattern is scored: 1 location + 1 outlier.
+    assert_eq!(compliance.score, 50.0);
+    assert!(compliance.violations.iter().all(|v| v.pattern_id == "solid"));
+    assert_eq!(compliance.violations.len(), 1);
+
+    assert_eq!(compliance.advisories.len(), 1);
+    let advisory = &compliance.advisories[0];
+    assert_eq!(advisory.pattern_id, "noisy");
+    assert_eq!(advisory.confidence, 0.4);
+    assert_eq!((advisory.file.as_str(), advisory.line), ("src/main.ts", 20));
+    assert!(results
+        .iter()
+        .filter(|r| r.gate_id != GateId::PatternCompliance)
+        .all(|r| r.advisories.is_empty()));
+
+    // Without a threshold both patterns are scored and nothing is advisory.
+    let unfiltered = GateInputBuilder::new()
+        .patterns(vec![pattern("noisy", 0.4, 20), pattern("solid", 0.95, 30)])
+        .build();
+    let results = GateOrchestrator::new().execute(&unfiltered).unwrap();
+    let compliance = results
+        .iter()
+        .find(|r| r.gate_id == GateId::PatternCompliance)
+        .unwrap();
+    assert_eq!(compliance.violations.len(), 2);
+    assert!(compliance.advisories.is_empty());
+}
+
+/// T6-GAT-11: Results from a sampled scan are non-authoritative with extrapolated counts.
+#[test]
+fn test_sampled_input_marks_results_non_authoritative() {
+    let pattern = PatternInfo {
+        pattern_id: "naming".to_string(),
+        category: "naming".to_string(),
+        confidence: 0.9,
+        locations: vec![],
+        outliers: vec![OutlierLocation {
+            file: "src/main.ts".to_string(),
+            line: 10,
+            message: "deviation".to_string(),
+            ..Default::default()
+        }],
+        cwe_ids: vec![],
+        owasp_categories: vec![],
+    };
+    let input = GateInputBuilder::new()
+        .patterns(vec![pattern])
+        .sample_fraction(0.25)
+        .build();
+
+    let results = GateOrchestrator::new().execute(&input).unwrap();
+    assert!(results.iter().all(|r| r.warnings.iter().any(|w| w.contains("not authoritative"))));
+    let compliance = results
+        .iter()
+        .find(|r| r.gate_id == GateId::PatternCompliance)
+        .unwrap();
+    assert_eq!(compliance.details["sample"]["authoritative"], false);
+    assert_eq!(compliance.details["sample"]["observed_violations"], 1);
+    assert_eq!(compliance.details["sample"]["estimated_violations"], 4);
+}
+
+/// T6-GAT-11: min_confidence applies to security findings too, and each
+/// advisory is listed on the gate that would have scored it.
+#[test]
+fn test_min_confidence_applies_to_security_findings() {
+    let finding = |line: u32, confidence: Option<f64>| SecurityFindingInput {
+        file: "src/api.ts".to_string(),
+        line,
+        description: "SQL injection".to_string(),
+        severity: "critical".to_string(),
+        cwe_ids: vec![89],
+        owasp_categories: vec![],
+        confidence,
+    };
+    let input = GateInputBuilder::new()
+        .security_findings(vec![
+            finding(10, Some(0.3)),
+            finding(20, Some(0.9)),
+            finding(30, None),
+        ])
+        .min_confidence(0.6)
+        .build();
+
+    let results = GateOrchestrator::new().execute(&input).unwrap();
+    let security = results
+        .iter()
+        .find(|r| r.gate_id == GateId::SecurityBoundaries)
+        .unwrap();
+
+    // The 0.9 finding and the one without a confidence are scored.
+    let mut scored: Vec<u32> = security.violations.iter().map(|v| v.line).collect();
+    scored.sort_unstable();
+    assert_eq!(scored, vec![20, 30]);
+
+    assert_eq!(security.advisories.len(), 1);
+    let advisory = &security.advisories[0];
+    assert_eq!(advisory.gate_id, GateId::SecurityBoundaries);
+    assert_eq!(advisory.line, 10);
+    assert_eq!(advisory.confidence, 0.3);
+    assert!(results
+        .iter()
+        .filter(|r| r.gate_id != GateId::SecurityBoundaries)
+        .all(|r| r.advisories.is_empty()));
+}
@@ -1,5 +1,5 @@
 #![allow(clippy::field_reassign_with_default, clippy::redundant_closure, clippy::cloned_ref_to_slice_refs, clippy::assertions_on_constants, unused_variables)]
-//! T4-INT-01 through T4-INT-11: Graph intelligence integration tests.
+//! T4-INT-01 through T4-INT-12: Graph intelligence integration tests.
 
 use drift_analysis::call_graph::types::{CallEdge, CallGraph, FunctionNode, Resolution};
 use drift_analysis::graph::error_handling;
@@ -65,6 +65,7 @@ fn make_function(name: &str, line: u32, end_line: u32) -> FunctionInfo {
         doc_comment: None,
         body_hash: 0,
         signature_hash: 0,
+        cognitive_complexity: 0,
     }
 }
 
@@ -466,3 +467,40 @@ fn test_cte_diamond_graph() {
     let cte_inv = bfs::reachability_inverse_cte(&conn, 3, None).unwrap();
     assert_eq!(cte_inv.len(), 3, "CTE inverse diamond: expected 3 (A,B,C), got {}", cte_inv.len());
 }
+
+// T4-INT-12: Symbol graph finds implementors and type users across files
+#[test]
+fn test_symbol_graph_implementors_across_files() {
+    use drift_analysis::graph::symbol_graph::{build_symbol_graph, ReferenceKind, SymbolKind};
+    use drift_analysis::parsers::manager::ParserManager;
+    use std::path::Path;
+
+    let files = [
+        ("src/repo.ts", "export interface Repository {\n  find(id: string): Entity;\n}\n\nexport interface Entity {\n  id: string;\n}\n"),
+        ("src/sql.ts", "import { Repository, Entity } from './repo';\n\nexport class SqlRepository implements Repository {\n  find(id: string): Entity {\n    return load(id);\n  }\n}\n"),
+        ("src/cache.ts", "import { Repository } from './repo';\n\nexport interface CachedRepository extends Repository {}\n\nexport class MemoryRepository implements CachedRepository {\n  find(id) { return null; }\n}\n"),
+        ("src/service.ts", "import { Repository } from './repo';\n\nexport class Logger {}\n\nexport function listUsers(repo: Repository): void {\n  repo.find('1');\n}\n"),
+    ];
+    let parser = ParserManager::new();
+    let results: Vec<ParseResult> = files
+        .iter()
+        .map(|(path, source)| parser.parse(source.as_bytes(), Path::new(path)).unwrap())
+        .collect();
+    let graph = build_symbol_graph(&results);
+
+    // Direct and transitive (through the `CachedRepository` interface) implementors.
+    let implementors: Vec<&str> = graph.implementors("Repository").iter().map(|n| n.name.as_str()).collect();
+    assert_eq!(implementors, vec!["MemoryRepository", "SqlRepository"]);
+    let subtypes: Vec<&str> = graph.subtypes("Repository").iter().map(|n| n.name.as_str()).collect();
+    assert_eq!(subtypes, vec!["CachedRepository", "MemoryRepository", "SqlRepository"]);
+    assert!(graph.implementors("Logger").is_empty());
+
+    // Type users: implements, imports and the parameter annotation in listUsers.
+    let users = graph.type_users("Repository");
+    assert!(users.iter().any(|(n, r)| n.name == "listUsers" && r.kind == ReferenceKind::TypeAnnotation));
+    assert!(users.iter().any(|(n, r)| n.kind == SymbolKind::File && n.file == "src/service.ts" && r.kind == ReferenceKind::Import));
+    let sql = users.iter().find(|(n, _)| n.name == "SqlRepository").unwrap();
+    assert_eq!(sql.1.kind, ReferenceKind::Implements);
+    assert!((sql.1.confidence - 0.85).abs() < 1e-6, "imported name: {}", sql.1.confidence);
+    assert!(graph.type_users("Entity").iter().any(|(n, _)| n.name == "SqlRepository.find"));
+}
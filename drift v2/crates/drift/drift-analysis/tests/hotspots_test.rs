@@ -0,0 +1,61 @@
+//! Hotspot analysis tests — churn-weighted risk ranking.
+
+use std::collections::HashMap;
+
+use drift_analysis::advanced::hotspots::rank;
+use drift_core::types::identifiers::FunctionId;
+use drift_core::types::interning::FunctionInterner;
+
+fn ids(interner: &FunctionInterner, names: &[&str]) -> Vec<FunctionId> {
+    names.iter().map(|n| FunctionId::new(interner.intern(n))).collect()
+}
+
+#[test]
+fn high_churn_high_complexity_outranks_others() {
+    let interner = FunctionInterner::new();
+    let f = ids(&interner, &["hot", "complex_only", "churn_only", "cold"]);
+
+    let complexity = HashMap::from([(f[0], 25), (f[1], 30), (f[2], 2), (f[3], 1)]);
+    let blast_radius = HashMap::from([(f[0], 5), (f[1], 5), (f[2], 5), (f[3], 5)]);
+    let churn = HashMap::from([(f[0], 40), (f[1], 1), (f[2], 50), (f[3], 0)]);
+
+    let ranked = rank(&complexity, &blast_radius, &churn);
+
+    assert_eq!(ranked.len(), 4);
+    assert_eq!(ranked[0].function_id, f[0], "hot function should rank first");
+    assert_eq!(ranked[0].churn, 40);
+    assert_eq!(ranked[0].complexity, 25);
+    assert!(ranked[0].score > ranked[1].score);
+    assert_eq!(ranked[3].function_id, f[3], "cold function should rank last");
+}
+
+#[test]
+fn blast_radius_breaks_ties_between_equal_hotspots() {
+    let interner = FunctionInterner::new();
+    let f = ids(&interner, &["widely_called", "leaf"]);
+
+    let complexity = HashMap::from([(f[0], 10), (f[1], 10)]);
+    let blast_radius = HashMap::from([(f[0], 100), (f[1], 1)]);
+    let churn = HashMap::from([(f[0], 10), (f[1], 10)]);
+
+    let ranked = rank(&complexity, &blast_radius, &churn);
+    assert_eq!(ranked[0].function_id, f[0]);
+}
+
+#[test]
+fn missing_churn_counts_as_zero() {
+    let interner = FunctionInterner::new();
+    let f = ids(&interner, &["untracked"]);
+
+    let complexity = HashMap::from([(f[0], 50)]);
+    let ranked = rank(&complexity, &HashMap::new(), &HashMap::new());
+
+    assert_eq!(ranked.len(), 1);
+    assert_eq!(ranked[0].churn, 0);
+    assert_eq!(ranked[0].score, 0.0);
+}
+
+#[test]
+fn empty_inputs_produce_no_hotspots() {
+    assert!(rank(&HashMap::new(), &HashMap::new(), &HashMap::new()).is_empty());
+}
@@ -338,6 +338,8 @@ fn t1_int_06_napi_type_conversion_fidelity() {
                 file_size: 1000 + i as u64,
                 language: Some(Language::TypeScript),
                 scan_duration_us: 42,
+                root: None,
+                is_test_file: false,
             },
         );
         added.push(path);
@@ -363,8 +365,14 @@ fn t1_int_06_napi_type_conversion_fidelity() {
             files_skipped_ignored: 0,
             files_skipped_binary: 0,
             languages_found,
+            files_skipped_sampled: 0,
+            sample_fraction: None,
+            files_outside_focus: 0,
+            files_resumed: 0,
+            files_fresh: 0,
         },
         entries,
+        context_files: vec![],
     };
 
     // Serialize to JSON (simulating what NAPI would do)
@@ -0,0 +1,138 @@
+//! Symbol interning tests — T1-SYM-01 through T1-SYM-02.
+//!
+//! Lives in its own test binary: T1-SYM-01 counts heap allocations through a
+//! global allocator, so the tests here hold `SERIAL` to keep each other out.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use drift_analysis::call_graph::CallGraphBuilder;
+use drift_analysis::parsers::interned::SymbolTable;
+use drift_analysis::parsers::manager::ParserManager;
+use drift_analysis::parsers::types::ParseResult;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static DEALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        DEALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+static SERIAL: Mutex<()> = Mutex::new(());
+
+/// Heap blocks still live after `f` returns, i.e. held by its result.
+fn blocks_retained_by<T>(f: impl FnOnce() -> T) -> (T, isize) {
+    let live = || ALLOCATIONS.load(Ordering::Relaxed) as isize - DEALLOCATIONS.load(Ordering::Relaxed) as isize;
+    let before = live();
+    let value = f();
+    (value, live() - before)
+}
+
+/// Ten files of services calling the same helper and repository methods,
+/// each handler making the same four calls `repeat` times.
+fn fixture(repeat: usize) -> Vec<ParseResult> {
+    let parser = ParserManager::new();
+    (0..10)
+        .map(|i| {
+            // Distinct content per file: the parse cache is keyed by content.
+            let mut source = format!(
+                "import {{ repo, log }} from './repo';\n\nexport const SERVICE = {i};\n\nfunction check(id: string) {{\n    return id;\n}}\n\n"
+            );
+            for f in 0..20 {
+                source.push_str(&format!("export async function handler{f}(id: string) {{\n"));
+                for _ in 0..repeat {
+                    source.push_str("    check(id);\n    log.info(id);\n    const user = await repo.findUser(id);\n    await repo.save(user);\n");
+                }
+                source.push_str("    return id;\n}\n\n");
+            }
+            source.push_str("class Service {\n    run() {\n        return repo.save(null);\n    }\n}\n");
+            parser
+                .parse(source.as_bytes(), Path::new(&format!("src/services/service_{i}.ts")))
+                .unwrap()
+        })
+        .collect()
+}
+
+fn call_count(results: &[ParseResult]) -> usize {
+    results.iter().map(|pr| pr.call_sites.len()).sum()
+}
+
+// ---- T1-SYM-01: The call graph's kept call sites hold no strings of their own ----
+
+#[test]
+fn t1_sym_01_call_graph_retains_no_allocations_per_call() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let small = fixture(1);
+    let large = fixture(4);
+    let builder = CallGraphBuilder::new();
+    // Warm up the rayon pool and other lazily initialized state.
+    drop(builder.build(&small).unwrap());
+
+    let (small_graph, small_blocks) = blocks_retained_by(|| builder.build(&small).unwrap().0);
+    let (large_graph, large_blocks) = blocks_retained_by(|| builder.build(&large).unwrap().0);
+
+    // Same functions and names, four times the calls.
+    let extra_calls = call_count(&large) - call_count(&small);
+    assert!(extra_calls >= 2000, "fixture adds {extra_calls} call sites");
+    assert_eq!(small_graph.function_count(), large_graph.function_count());
+    assert!(large_graph.edge_count() > small_graph.edge_count());
+
+    // Owned call sites kept a caller key, callee, file and receiver per call;
+    // interned ones keep ids into strings shared by all calls.
+    let extra_blocks = large_blocks - small_blocks;
+    assert!(
+        extra_blocks * 100 < extra_calls as isize,
+        "{extra_blocks} more live blocks for {extra_calls} more calls ({small_blocks} vs {large_blocks})"
+    );
+}
+
+// ---- T1-SYM-02: Interned call sites resolve back to their strings ----
+
+#[test]
+fn t1_sym_02_resolution_round_trips() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let results = fixture(1);
+    let symbols = SymbolTable::new();
+
+    for pr in &results {
+        let file = symbols.file_id(&pr.file);
+        for original in &pr.call_sites {
+            let interned = symbols.intern_call_site_in(file, original);
+            let resolved = interned.resolve(&symbols);
+            assert_eq!(resolved.callee_name, original.callee_name);
+            assert_eq!(resolved.receiver, original.receiver);
+            assert_eq!(resolved.file, original.file);
+            assert_eq!((resolved.line, resolved.column), (original.line, original.column));
+            assert_eq!((resolved.argument_count, resolved.is_await), (original.argument_count, original.is_await));
+        }
+    }
+
+    // One id per distinct string, whatever the number of uses.
+    let save = symbols.lookup_function("save").unwrap();
+    assert_eq!(symbols.function(save), "save");
+    assert!(results
+        .iter()
+        .flat_map(|pr| &pr.call_sites)
+        .filter(|c| c.callee_name == "save")
+        .all(|c| symbols.intern_call_site(c).callee == save));
+
+    // Paths are normalized on the way in.
+    let file = symbols.lookup_file("src\\services\\service_3.ts").unwrap();
+    assert_eq!(symbols.file(file), "src/services/service_3.ts");
+    assert!(symbols.lookup_file("src/services/service_10.ts").is_none());
+}
@@ -0,0 +1,193 @@
+//! Phase 5 size and complexity metrics tests (T5-LOC-01 through T5-LOC-03,
+//! T5-CPX-01 through T5-CPX-04).
+
+use drift_analysis::detectors::test_support::parse_snippet;
+use drift_analysis::scanner::language_detect::Language;
+use drift_analysis::structural::complexity::cognitive_complexity;
+use drift_analysis::structural::metrics::loc::{classify_lines, LineKind};
+use drift_analysis::structural::metrics::{loc_metrics, LineCounts};
+
+fn counts(source: &str, language: Language) -> LineCounts {
+    loc_metrics(&parse_snippet(source, language), source.as_bytes()).counts
+}
+
+/// T5-LOC-01: Block comments, markers inside strings and trailing comments (TS).
+#[test]
+fn t5_loc_01_c_like_comments_and_strings() {
+    let source = r#"/**
+ * Adds numbers.
+ */
+export function add(a: number, b: number): number {
+  const url = "http://example.com"; // trailing comment
+
+  /* one-line block */
+  const t = `multi
+// not a comment
+line`;
+  return a + b; /* spans
+  two lines */
+}
+"#;
+    use LineKind::*;
+    assert_eq!(
+        classify_lines(source, Language::TypeScript),
+        vec![Comment, Comment, Comment, Source, Source, Blank, Comment, Source, Source, Source, Source, Comment, Source],
+    );
+    let metrics = loc_metrics(&parse_snippet(source, Language::TypeScript), source.as_bytes());
+    assert_eq!(metrics.counts, LineCounts { loc: 13, sloc: 7, comment: 5, blank: 1 });
+
+    let add = metrics.functions.iter().find(|f| f.name == "add").expect("add() extracted");
+    assert_eq!(add.counts.loc, add.end_line - add.line + 1);
+    assert_eq!(add.counts.comment, 2);
+    assert_eq!(add.counts.blank, 1);
+}
+
+/// T5-LOC-02: Python docstrings and `#`, Ruby `=begin`/`=end`.
+#[test]
+fn t5_loc_02_python_and_ruby_comments() {
+    let python = r#"def greet(name):
+    """Say hello.
+
+    Multi-line docstring.
+    """
+    # a comment
+    msg = """not a docstring
+# still a string
+"""
+    return msg  # trailing
+"#;
+    assert_eq!(counts(python, Language::Python), LineCounts { loc: 10, sloc: 5, comment: 4, blank: 1 });
+
+    let ruby = "=begin\nblock comment\n=end\ndef hi\n  # comment\n  puts 'x # not a comment'\nend\n";
+    assert_eq!(counts(ruby, Language::Ruby), LineCounts { loc: 7, sloc: 3, comment: 4, blank: 0 });
+}
+
+/// T5-LOC-03: Nested block comments (Rust), PHP `#` vs attributes, comment ratio.
+#[test]
+fn t5_loc_03_nested_blocks_and_ratio() {
+    let rust = "/* outer /* inner */\n still comment */\nfn main() {\n    let s = \"/* not a comment\";\n    let c = '\"';\n}\n";
+    use LineKind::*;
+    assert_eq!(classify_lines(rust, Language::Rust), vec![Comment, Comment, Source, Source, Source, Source]);
+
+    let php = "<?php\n# comment\n#[Attribute]\nclass A {}\n";
+    assert_eq!(classify_lines(php, Language::Php), vec![Source, Comment, Source, Source]);
+
+    let c = counts("// one\nint x;\n", Language::C);
+    assert!((c.comment_ratio() - 0.5).abs() < 1e-9);
+    assert_eq!(LineCounts::default().comment_ratio(), 0.0);
+}
+
+fn cognitive(source: &str, language: Language, name: &str) -> u32 {
+    let result = parse_snippet(source, language);
+    let func = result.functions.iter().find(|f| f.name == name).expect("function extracted");
+    func.cognitive_complexity
+}
+
+/// T5-CPX-01: Nesting increments stack for loops and ifs; early returns are free.
+#[test]
+fn t5_cpx_01_triple_nested_loop() {
+    let source = r#"function findTriple(grid: number[][][], target: number): boolean {
+  for (const plane of grid) {
+    for (const row of plane) {
+      for (const cell of row) {
+        if (cell === target) return true;
+      }
+    }
+  }
+  return false;
+}
+"#;
+    // for +1, for +2, for +3, if +4
+    assert_eq!(cognitive(source, Language::TypeScript, "findTriple"), 10);
+    assert_eq!(cognitive("function id(x) { return x; }\n", Language::JavaScript, "id"), 0);
+}
+
+/// T5-CPX-02: `elif`/`else`, boolean sequences, recursion and `except` (Python).
+#[test]
+fn t5_cpx_02_branches_booleans_and_recursion() {
+    let source = r#"def walk(node, depth):
+    if node is None or depth > 10:
+        return 0
+    elif node.leaf:
+        return 1
+    else:
+        total = 0
+        for child in node.children:
+            try:
+                total += walk(child, depth + 1)
+            except ValueError:
+                pass
+        return total
+"#;
+    // if +1, `or` +1, elif +1, else +1, for +2, walk() +1, except +3
+    assert_eq!(cognitive(source, Language::Python, "walk"), 10);
+
+    let mixed = "function ok(a, b, c, d) {\n  return a && b && c || d;\n}\n";
+    assert_eq!(cognitive(mixed, Language::JavaScript, "ok"), 2);
+}
+
+/// T5-CPX-03: Nested functions are scored on their own; long chains don't overflow.
+#[test]
+fn t5_cpx_03_nested_functions_and_deep_chains() {
+    let source = r#"function outer(items) {
+  function inner(x) {
+    if (x) return 1;
+    return 0;
+  }
+  const pick = (x) => {
+    if (x > 1) { if (x > 2) return 2; }
+    return 0;
+  };
+  return items.map(function (x) { if (x) return inner(x); return pick(x); });
+}
+"#;
+    // outer: the anonymous callback nests its if: +2
+    assert_eq!(cognitive(source, Language::JavaScript, "outer"), 2);
+    assert_eq!(cognitive(source, Language::JavaScript, "inner"), 1);
+    assert_eq!(cognitive(source, Language::JavaScript, "pick"), 3);
+
+    // A 20k-term chain nests 20k levels deep
+    let terms: Vec<String> = (0..20_000).map(|i| format!("v{i}")).collect();
+    let chain = format!("function all() {{\n  return {};\n}}\n", terms.join(" && "));
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(&tree_sitter_javascript::LANGUAGE.into()).unwrap();
+    let tree = parser.parse(&chain, None).unwrap();
+    let all = tree.root_node().named_child(0).unwrap();
+    assert_eq!(cognitive_complexity(all, chain.as_bytes()), 1);
+}
+
+/// T5-CPX-04: `else if` inside a loop is a flat +1 (JS, Rust).
+#[test]
+fn t5_cpx_04_else_if_inside_loop() {
+    let js = r#"function classify(xs) {
+  for (const x of xs) {
+    if (x > 0) {
+      pos++;
+    } else if (x < 0) {
+      neg++;
+    } else {
+      zero++;
+    }
+  }
+}
+"#;
+    // for +1, if +2, else if +1, else +1
+    assert_eq!(cognitive(js, Language::JavaScript, "classify"), 5);
+
+    let rust = r#"fn classify(xs: &[i32]) -> i32 {
+    let mut n = 0;
+    for x in xs {
+        if *x > 0 {
+            n += 1;
+        } else if *x < 0 {
+            n -= 1;
+        } else if *x == 0 {
+            n += 0;
+        }
+    }
+    n
+}
+"#;
+    // for +1, if +2, else if +1, else if +1
+    assert_eq!(cognitive(rust, Language::Rust, "classify"), 5);
+}
@@ -237,6 +237,7 @@ fn make_parse_result(file: &str, functions: Vec<(&str, u32, u32, bool)>, calls:
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            cognitive_complexity: 0,
         }).collect(),
         classes: Vec::new(),
         imports: imports.iter().map(|src| ImportInfo {
@@ -266,6 +267,7 @@ fn make_parse_result(file: &str, functions: Vec<(&str, u32, u32, bool)>, calls:
         error_count: 0,
         error_ranges: Vec::new(),
         has_errors: false,
+        truncated_at_depth: None,
     }
 }
 
@@ -332,6 +334,7 @@ fn stress_call_graph_100_files_1000_functions() {
                     doc_comment: None,
                     body_hash: 0,
                     signature_hash: 0,
+                    cognitive_complexity: 0,
                 });
             }
             pr
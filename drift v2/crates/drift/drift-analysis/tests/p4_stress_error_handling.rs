@@ -34,6 +34,7 @@ fn func(name: &str, line: u32, end_line: u32) -> FunctionInfo {
         visibility: Visibility::Public, is_exported: true, is_async: false,
         is_generator: false, is_abstract: false, range: Range::default(),
         decorators: Vec::new(), doc_comment: None, body_hash: 0, signature_hash: 0,
+        cognitive_complexity: 0,
     }
 }
 
@@ -32,6 +32,7 @@ fn func(name: &str, line: u32, end_line: u32) -> FunctionInfo {
         visibility: Visibility::Public, is_exported: true, is_async: false,
         is_generator: false, is_abstract: false, range: Range::default(),
         decorators: Vec::new(), doc_comment: None, body_hash: 0, signature_hash: 0,
+        cognitive_complexity: 0,
     }
 }
 
@@ -58,6 +59,7 @@ fn full_pr(file: &str, functions: Vec<FunctionInfo>, calls: Vec<CallSite>) -> Pa
         numeric_literals: Vec::new(), error_handling: Vec::new(), doc_comments: Vec::new(),
         namespace: None, parse_time_us: 0, error_count: 0, error_ranges: Vec::new(),
         has_errors: false,
+        truncated_at_depth: None,
     }
 }
 
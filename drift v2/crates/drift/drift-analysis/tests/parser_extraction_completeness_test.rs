@@ -5,7 +5,7 @@
 use std::path::Path;
 
 use drift_analysis::parsers::manager::ParserManager;
-use drift_analysis::parsers::types::ParseResult;
+use drift_analysis::parsers::types::{ErrorHandlingKind, ParseResult};
 use drift_analysis::scanner::language_detect::Language;
 
 fn parse_fixture(file: &str) -> ParseResult {
@@ -228,14 +228,31 @@ fn read_file(path: &str) -> Result<String, io::Error> {
     let content = fs::read_to_string(path)?;
     Ok(content)
 }
+
+fn copy_first_line(from: &str, to: &str) -> Result<usize, io::Error> {
+    let line = read_file(from)?.lines().next().unwrap_or_default().to_string();
+    fs::write(to, &line)?;
+    Ok(line.len())
+}
 "#;
     let parser = ParserManager::new();
     let bytes = source.as_bytes().to_vec();
     let pr = parser.parse(&bytes, Path::new("test.rs")).unwrap();
-
-    // The ? operator may be parsed differently by tree-sitter
-    // At minimum, we shouldn't crash
     assert_eq!(pr.language, Language::Rust);
+
+    let try_ops: Vec<_> = pr
+        .error_handling
+        .iter()
+        .filter(|eh| eh.kind == ErrorHandlingKind::TryOperator)
+        .collect();
+    assert_eq!(try_ops.len(), 3, "each `?` should be one TryOperator: {:?}", pr.error_handling);
+    assert!(try_ops.iter().all(|eh| !eh.has_body));
+    let scopes: Vec<_> = try_ops.iter().map(|eh| eh.function_scope.as_deref()).collect();
+    assert_eq!(scopes, [Some("read_file"), Some("copy_first_line"), Some("copy_first_line")]);
+    assert!(
+        !pr.error_handling.iter().any(|eh| eh.kind == ErrorHandlingKind::TryCatch),
+        "`?` must not be reported as try/catch"
+    );
 }
 
 // ---- DP-CTX: String/Numeric context classification ----
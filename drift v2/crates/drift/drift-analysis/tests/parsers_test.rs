@@ -1,16 +1,19 @@
-//! Parser tests — T1-PRS-01 through T1-PRS-15.
+//! Parser tests — T1-PRS-01 through T1-PRS-36.
 //!
 //! Tests cover: all 10 language parsers, parse cache, error tolerance,
 //! body/signature hashing, macro correctness, edge cases, thread safety,
 //! and Unicode source code.
 
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 use std::thread;
 
+use drift_core::config::{ExportRule, VisibilityLevel, VisibilityPolicy};
 use drift_analysis::parsers::cache::ParseCache;
+use drift_analysis::parsers::languages::ParseOptions;
 use drift_analysis::parsers::manager::ParserManager;
-use drift_analysis::parsers::types::ParseResult;
+use drift_analysis::parsers::types::{ClassKind, ErrorHandlingKind, FunctionInfo, ParseResult, Visibility};
 use drift_analysis::scanner::language_detect::Language;
 
 /// Workspace root for test fixtures (relative to crate root).
@@ -437,6 +440,7 @@ fn t1_prs_14_cache_persistence_round_trip() {
         error_count: 0,
         error_ranges: vec![],
         has_errors: false,
+        truncated_at_depth: None,
     };
 
     let json = serde_json::to_string(&original).unwrap();
@@ -466,3 +470,749 @@ fn t1_prs_15_unicode_source_code() {
     let result2 = manager.parse(ts_source.as_bytes(), path2);
     assert!(result2.is_ok(), "TS parser should handle Unicode identifiers");
 }
+
+// ---- T1-PRS-16: Grammar ABI mismatch is distinguished from a missing grammar ----
+
+#[test]
+fn t1_prs_16_grammar_abi_mismatch() {
+    use drift_analysis::parsers::languages::grammar_error;
+    use drift_core::errors::ParseError;
+
+    // Simulate a grammar built against an ABI far older than the linked core supports.
+    let err = grammar_error(Language::Python, 1);
+    match err {
+        ParseError::GrammarAbiMismatch { language, grammar_abi, supported_range } => {
+            assert_eq!(language, "Python");
+            assert_eq!(grammar_abi, 1);
+            assert_eq!(*supported_range.start(), tree_sitter::MIN_COMPATIBLE_LANGUAGE_VERSION as u32);
+            assert_eq!(*supported_range.end(), tree_sitter::LANGUAGE_VERSION as u32);
+        }
+        other => panic!("expected GrammarAbiMismatch, got {other:?}"),
+    }
+
+    // A grammar newer than the core is also a mismatch.
+    let err = grammar_error(Language::Go, tree_sitter::LANGUAGE_VERSION + 1);
+    assert!(matches!(err, ParseError::GrammarAbiMismatch { .. }));
+    assert!(err.to_string().contains("ABI"), "got: {err}");
+
+    // An in-range ABI is not an ABI problem.
+    let abi = Language::Rust.ts_language().abi_version();
+    assert!(matches!(grammar_error(Language::Rust, abi), ParseError::GrammarNotFound { .. }));
+}
+
+// ---- T1-PRS-17: ParseResult::diff reports exactly the edited symbols ----
+
+#[test]
+fn t1_prs_17_parse_result_diff() {
+    let manager = ParserManager::new();
+    let old_src = r#"
+import { a } from './a';
+import { b } from './b';
+
+export function unchanged(x: number): number {
+    return x + 1;
+}
+
+export function edited(x: number): number {
+    return x * 2;
+}
+
+export function dropped() {}
+"#;
+    // `edited` gets a new body; `unchanged` moves down a line.
+    let new_src = r#"
+import { a } from './a';
+import { b, c } from './b';
+
+
+export function unchanged(x: number): number {
+    return x + 1;
+}
+
+export function edited(x: number): number {
+    return x * 3;
+}
+
+export class Fresh {}
+"#;
+    let old = manager.parse(old_src.as_bytes(), Path::new("diff.ts")).unwrap();
+    let new = manager.parse(new_src.as_bytes(), Path::new("diff.ts")).unwrap();
+
+    let diff = new.diff(&old);
+    assert_eq!(diff.functions.modified, vec!["edited".to_string()]);
+    assert_eq!(diff.functions.removed, vec!["dropped".to_string()]);
+    assert!(diff.functions.added.is_empty());
+    assert!(diff.signature_changed.is_empty(), "body-only edit keeps the signature");
+    assert_eq!(diff.classes.added, vec!["Fresh".to_string()]);
+    assert_eq!(diff.imports.modified, vec!["./b".to_string()]);
+
+    assert!(new.diff(&new).is_empty());
+}
+
+// ---- T1-PRS-18: summarize aggregates counts across languages ----
+
+#[test]
+fn t1_prs_18_repo_summary() {
+    use drift_analysis::parsers::summary::summarize;
+
+    let manager = ParserManager::new();
+    let ts = r#"import { db } from './db';
+import { log } from './log';
+
+export async function load(id: string) {
+    try {
+        return await db.get(id);
+    } catch (e) {
+        log(e);
+        return null;
+    }
+}
+
+function helper() {
+    return 1;
+}
+
+export class Store {}
+"#;
+    let py = r#"import json
+
+def read(path):
+    with open(path) as f:
+        return json.load(f)
+
+async def refresh():
+    try:
+        pass
+    except Exception:
+        raise
+"#;
+    let results = vec![
+        manager.parse(ts.as_bytes(), Path::new("src/store.ts")).unwrap(),
+        manager.parse(ts.as_bytes(), Path::new("src/copy.ts")).unwrap(),
+        manager.parse(py.as_bytes(), Path::new("app/io.py")).unwrap(),
+    ];
+    let summary = summarize(&results);
+
+    assert_eq!(summary.files, 3);
+    assert_eq!(summary.files_by_language.get("TypeScript"), Some(&2));
+    assert_eq!(summary.files_by_language.get("Python"), Some(&1));
+    assert_eq!(summary.functions, 6);
+    assert_eq!(summary.classes, 2);
+    assert_eq!(summary.imports, 5);
+    // (8 + 3) lines per TS file, 3 + 5 in Python.
+    assert!((summary.avg_function_length - 5.0).abs() < 1e-9);
+    // `load` and `Store` in each TS file, out of 6 functions + 2 classes.
+    assert!((summary.exported_ratio - 0.5).abs() < 1e-9);
+    assert!((summary.async_ratio - 0.5).abs() < 1e-9);
+    assert!(summary.error_handling_density > 0.0);
+
+    assert_eq!(summarize(&[]), Default::default());
+}
+
+// ---- T1-PRS-19: Hitting the AST depth limit marks the result truncated ----
+
+#[test]
+fn t1_prs_19_depth_limit_reports_truncation() {
+    let source = format!("const table = {}deep(){};\n", "[".repeat(80), "]".repeat(80));
+    let path = Path::new("src/generated.js");
+
+    let truncated = ParserManager::new().parse(source.as_bytes(), path).unwrap();
+    let truncation = truncated.truncated_at_depth.expect("default depth limit should be hit");
+    assert_eq!(truncation.max_depth, 50);
+    assert_eq!(truncation.position.line, 0);
+    assert!(truncation.position.column > 14, "{truncation:?}");
+    assert_eq!(truncation.skipped_subtrees, 1);
+    assert!(truncated.hit_depth_limit());
+    assert!(!truncated.call_sites.iter().any(|c| c.callee_name == "deep"));
+
+    let twice = format!("{source}{source}");
+    let truncated = ParserManager::new().parse(twice.as_bytes(), path).unwrap();
+    let truncation = truncated.truncated_at_depth.unwrap();
+    assert_eq!((truncation.position.line, truncation.skipped_subtrees), (0, 2));
+
+    let full = ParserManager::new().with_max_depth(400).parse(source.as_bytes(), path).unwrap();
+    assert_eq!(full.truncated_at_depth, None);
+    assert!(!full.hit_depth_limit());
+    assert!(full.call_sites.iter().any(|c| c.callee_name == "deep"));
+
+    let shallow = ParserManager::new().parse(b"const x = [[1]];\n", path).unwrap();
+    assert_eq!(shallow.truncated_at_depth, None);
+    assert!(!shallow.hit_depth_limit());
+
+    // ParseOptions override the limit for one call; the default stays at 50.
+    assert_eq!(ParseOptions::default().max_depth, 50);
+    let manager = ParserManager::new();
+    let options = ParseOptions { max_depth: 400 };
+    let deep = manager
+        .parse_with_language_options(source.as_bytes(), path, Language::JavaScript, &options)
+        .unwrap();
+    assert!(!deep.hit_depth_limit());
+    assert!(deep.call_sites.iter().any(|c| c.callee_name == "deep"));
+    let default = manager.parse_with_language(source.as_bytes(), path, Language::JavaScript).unwrap();
+    assert!(default.hit_depth_limit());
+}
+
+// ---- T1-PRS-20: Swift declarations, conformances and calls ----
+
+#[test]
+fn t1_prs_20_swift_extraction() {
+    let source = r#"import Foundation
+import UIKit.UIView
+
+protocol Store: AnyObject {
+    func load(id: String) -> User?
+}
+
+open class UserService: NSObject, Store {
+    private let store: Store
+
+    public init(store: Store) {
+        self.store = store
+        super.init()
+    }
+
+    fileprivate func load(id: String) async throws -> User? {
+        let user = try await api.fetch(id, retries: 3)
+        return user
+    }
+}
+
+struct Point: Equatable, Codable { let x: Int }
+
+func helper(_ value: Int) -> Int {
+    defer { cleanup() }
+    return value * 2
+}
+"#;
+    let manager = ParserManager::new();
+    assert_eq!(manager.detect_language(Path::new("UserService.swift")), Some(Language::Swift));
+    let result = manager.parse(source.as_bytes(), Path::new("Sources/UserService.swift")).unwrap();
+    assert_eq!(result.language, Language::Swift);
+    assert_eq!(result.error_count, 0);
+
+    let imports: Vec<&str> = result.imports.iter().map(|i| i.source.as_str()).collect();
+    assert_eq!(imports, ["Foundation", "UIKit.UIView"]);
+
+    let store = result.classes.iter().find(|c| c.name == "Store").unwrap();
+    assert_eq!(store.class_kind, ClassKind::Interface);
+    let service = result.classes.iter().find(|c| c.name == "UserService").unwrap();
+    assert_eq!(service.class_kind, ClassKind::Class);
+    assert!(service.is_exported, "open classes are exported");
+    // The first supertype is the superclass unless it is a protocol.
+    assert_eq!(service.extends.as_deref(), Some("NSObject"));
+    assert_eq!(service.implements.as_slice(), ["Store"]);
+    let methods: Vec<&str> = service.methods.iter().filter_map(|m| m.qualified_name.as_deref()).collect();
+    assert_eq!(methods, ["UserService.init", "UserService.load"]);
+    assert_eq!(service.properties[0].type_annotation.as_deref(), Some("Store"));
+    let point = result.classes.iter().find(|c| c.name == "Point").unwrap();
+    assert_eq!(point.class_kind, ClassKind::Struct);
+    assert_eq!(point.extends, None);
+    assert_eq!(point.implements.as_slice(), ["Equatable", "Codable"]);
+
+    let init = result.functions.iter().find(|f| f.name == "init").unwrap();
+    assert_eq!((init.visibility, init.is_exported), (Visibility::Public, true));
+    let load = result.functions.iter().find(|f| f.name == "load").unwrap();
+    assert_eq!(load.visibility, Visibility::Private);
+    assert!(load.is_async);
+    assert_eq!(load.return_type.as_deref(), Some("User?"));
+    assert_eq!(load.parameters[0].name, "id");
+    let helper = result.functions.iter().find(|f| f.name == "helper").unwrap();
+    assert_eq!(helper.parameters[0].name, "value");
+    assert_eq!(helper.parameters[0].type_annotation.as_deref(), Some("Int"));
+
+    let fetch = result.call_sites.iter().find(|c| c.callee_name == "fetch").unwrap();
+    assert_eq!(fetch.receiver.as_deref(), Some("api"));
+    assert_eq!((fetch.argument_count, fetch.is_await), (2, true));
+    assert!(result.call_sites.iter().any(|c| c.callee_name == "cleanup"));
+    assert!(!result.call_sites.iter().any(|c| c.callee_name == "defer"));
+    assert!(result.error_handling.iter().any(|e| e.kind == ErrorHandlingKind::Defer));
+}
+
+// ---- T1-PRS-21: C++ namespaces, out-of-line members and templates ----
+
+#[test]
+fn t1_prs_21_cpp_qualified_members() {
+    let source = r#"namespace app {
+namespace users {
+
+template <typename T, int N = 4>
+class Cache : public Base<T>, private Noncopyable {
+public:
+    Cache() {}
+    T get(const std::string& key) { return store.find(key); }
+private:
+    int size;
+};
+
+struct Point { int x; };
+
+class UserService {
+public:
+    User* find(int id);
+};
+
+User* UserService::find(int id) {
+    auto user = repo->load(id);
+    return Registry::lookup(user, id);
+}
+
+template <typename U>
+U convert(U value) { return value; }
+
+}
+}
+"#;
+    let result = ParserManager::new().parse(source.as_bytes(), Path::new("src/users.cpp")).unwrap();
+    assert_eq!(result.language, Language::Cpp);
+    assert_eq!(result.error_count, 0);
+    assert_eq!(result.namespace.as_deref(), Some("app::users"));
+
+    let cache = result.classes.iter().find(|c| c.name == "Cache").unwrap();
+    assert_eq!(cache.namespace.as_deref(), Some("app::users"));
+    assert_eq!(cache.extends.as_deref(), Some("Base<T>"));
+    assert_eq!(cache.implements.as_slice(), ["Noncopyable"]);
+    let generics: Vec<&str> = cache.generic_params.iter().map(|g| g.name.as_str()).collect();
+    assert_eq!(generics, ["T", "N"]);
+    let get = cache.methods.iter().find(|m| m.name == "get").unwrap();
+    assert_eq!(get.qualified_name.as_deref(), Some("Cache.get"));
+    assert_eq!(get.visibility, Visibility::Public);
+    assert_eq!(get.parameters[0].name, "key");
+    assert_eq!(cache.properties[0].name, "size");
+
+    let point = result.classes.iter().find(|c| c.name == "Point").unwrap();
+    assert_eq!(point.class_kind, ClassKind::Struct);
+
+    // The out-of-line definition keeps its class and is attached to it.
+    let find = result.functions.iter().find(|f| f.name == "find").unwrap();
+    assert_eq!(find.qualified_name.as_deref(), Some("UserService.find"));
+    assert_eq!(find.parameters[0].name, "id");
+    let service = result.classes.iter().find(|c| c.name == "UserService").unwrap();
+    assert!(service.methods.iter().any(|m| m.name == "find"));
+
+    let convert = result.functions.iter().find(|f| f.name == "convert").unwrap();
+    assert_eq!(convert.qualified_name, None);
+    assert_eq!(convert.generic_params[0].name, "U");
+
+    let load = result.call_sites.iter().find(|c| c.callee_name == "load").unwrap();
+    assert_eq!(load.receiver.as_deref(), Some("repo"));
+    let lookup = result.call_sites.iter().find(|c| c.callee_name == "lookup").unwrap();
+    assert_eq!(lookup.receiver.as_deref(), Some("Registry"));
+    assert_eq!(lookup.argument_count, 2);
+}
+
+// ---- T1-PRS-22: TypeScript interface heritage and type aliases ----
+
+#[test]
+fn t1_prs_22_ts_interfaces_and_type_aliases() {
+    let source = r#"export interface Repository<T> extends Reader<T>, Writer {
+  find(id: string): T;
+}
+
+export type Result<T, E = Error> = { ok: T } | { err: E };
+type UserId = string;
+"#;
+    let result = ParserManager::new().parse(source.as_bytes(), Path::new("src/repo.ts")).unwrap();
+    assert_eq!(result.error_count, 0);
+
+    let repo = result.classes.iter().find(|c| c.name == "Repository").unwrap();
+    assert_eq!(repo.class_kind, ClassKind::Interface);
+    assert_eq!(repo.extends.as_deref(), Some("Reader<T>, Writer"));
+    assert_eq!(repo.generic_params[0].name, "T");
+
+    let alias = result.classes.iter().find(|c| c.name == "Result").unwrap();
+    assert_eq!(alias.class_kind, ClassKind::TypeAlias);
+    assert!(alias.is_exported);
+    assert_eq!(alias.alias_target.as_deref(), Some("{ ok: T } | { err: E }"));
+    let generics: Vec<&str> = alias.generic_params.iter().map(|g| g.name.as_str()).collect();
+    assert_eq!(generics, ["T", "E"]);
+
+    let id = result.classes.iter().find(|c| c.name == "UserId").unwrap();
+    assert_eq!(id.alias_target.as_deref(), Some("string"));
+    assert!(!id.is_exported);
+    assert_eq!(repo.alias_target, None);
+}
+
+// ---- T1-PRS-23: PHP 8 attributes become decorators with arguments ----
+
+#[test]
+fn t1_prs_23_php_attributes() {
+    let source = r#"<?php
+namespace App\Controller;
+
+use Symfony\Component\Routing\Attribute\Route;
+use Symfony\Component\Security\Http\Attribute\IsGranted;
+
+#[Route('/users')]
+class UserController
+{
+    #[Route('/{id}', name: 'user_show', methods: ['GET'])]
+    #[IsGranted("ROLE_ADMIN")]
+    public function show(int $id): Response
+    {
+        return $this->render('user/show.html.twig');
+    }
+
+    public function plain(): void {}
+}
+"#;
+    let manager = ParserManager::new();
+    let result = manager.parse(source.as_bytes(), Path::new("src/Controller/UserController.php")).unwrap();
+    assert_eq!(result.language, Language::Php);
+
+    let controller = result.classes.iter().find(|c| c.name == "UserController").unwrap();
+    let class_attrs: Vec<&str> = controller.decorators.iter().map(|d| d.name.as_str()).collect();
+    assert_eq!(class_attrs, ["Route"]);
+    assert_eq!(controller.decorators[0].arguments[0].value, "/users");
+
+    let show = controller.methods.iter().find(|m| m.name == "show").unwrap();
+    let names: Vec<&str> = show.decorators.iter().map(|d| d.name.as_str()).collect();
+    assert_eq!(names, ["Route", "IsGranted"]);
+    let route: Vec<(Option<&str>, &str)> = show.decorators[0]
+        .arguments
+        .iter()
+        .map(|a| (a.key.as_deref(), a.value.as_str()))
+        .collect();
+    assert_eq!(route, [(None, "/{id}"), (Some("name"), "user_show"), (Some("methods"), "['GET']")]);
+    assert_eq!(show.decorators[1].arguments[0].value, "ROLE_ADMIN");
+    assert!(show.decorators[1].raw_text.contains("IsGranted"));
+
+    let plain = controller.methods.iter().find(|m| m.name == "plain").unwrap();
+    assert!(plain.decorators.is_empty());
+    assert_eq!(result.decorators.iter().filter(|d| d.name == "Route").count(), 2);
+}
+
+// ---- T1-PRS-24: Decorator arguments are extracted ----
+
+#[test]
+fn t1_prs_24_decorator_arguments() {
+    let manager = ParserManager::new();
+    let args = |dec: &drift_analysis::parsers::types::DecoratorInfo| -> Vec<(Option<String>, String)> {
+        dec.arguments.iter().map(|a| (a.key.clone(), a.value.clone())).collect()
+    };
+    let kv = |key: Option<&str>, value: &str| (key.map(str::to_string), value.to_string());
+
+    // Java annotation element-value pairs
+    let java = r#"@Entity
+@Table(name="users")
+public class User {
+    @Column(nullable = false, length = 64)
+    private String email;
+}
+"#;
+    let result = manager.parse(java.as_bytes(), Path::new("User.java")).unwrap();
+    let table = result.decorators.iter().find(|d| d.name == "Table").unwrap();
+    assert_eq!(args(table), [kv(Some("name"), "users")]);
+    let entity = result.decorators.iter().find(|d| d.name == "Entity").unwrap();
+    assert!(entity.arguments.is_empty());
+    let column = result.decorators.iter().find(|d| d.name == "Column").unwrap();
+    assert_eq!(args(column), [kv(Some("nullable"), "false"), kv(Some("length"), "64")]);
+
+    // Python positional and keyword arguments
+    let python = r#"@app.route("/path", methods=["POST"])
+def create():
+    pass
+"#;
+    let result = manager.parse(python.as_bytes(), Path::new("app.py")).unwrap();
+    let route = result.decorators.iter().find(|d| d.raw_text.starts_with("@app.route")).unwrap();
+    assert_eq!(args(route), [kv(None, "/path"), kv(Some("methods"), "[\"POST\"]")]);
+
+    // TypeScript decorator factories, with and without an options object
+    let ts = r#"@Entity({ name: 'users', schema: "public" })
+export class User {
+    @Column("varchar")
+    email: string;
+}
+"#;
+    let result = manager.parse(ts.as_bytes(), Path::new("user.ts")).unwrap();
+    let entity = result.decorators.iter().find(|d| d.raw_text.starts_with("@Entity")).unwrap();
+    assert_eq!(args(entity), [kv(Some("name"), "users"), kv(Some("schema"), "public")]);
+    let column = result.decorators.iter().find(|d| d.raw_text.starts_with("@Column")).unwrap();
+    assert_eq!(args(column), [kv(None, "varchar")]);
+}
+
+// ---- T1-PRS-25..36: Configured visibility policies ----
+
+fn parse_with_policy(file: &str, source: &str, language: &str, policy: VisibilityPolicy) -> ParseResult {
+    let policies = HashMap::from([(language.to_string(), policy)]);
+    ParserManager::new()
+        .with_visibility_policies(&policies)
+        .parse(source.as_bytes(), Path::new(file))
+        .unwrap()
+}
+
+fn function<'a>(pr: &'a ParseResult, name: &str) -> &'a FunctionInfo {
+    pr.functions.iter().find(|f| f.name == name).unwrap_or_else(|| panic!("no function {name}"))
+}
+
+fn class_exported(pr: &ParseResult, name: &str) -> bool {
+    pr.classes.iter().find(|c| c.name == name).unwrap_or_else(|| panic!("no class {name}")).is_exported
+}
+
+fn modifiers(entries: &[(&str, VisibilityLevel)]) -> HashMap<String, VisibilityLevel> {
+    entries.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+}
+
+// ---- T1-PRS-25: The default policy keeps extraction as it is ----
+
+#[test]
+fn t1_prs_25_default_visibility_policy_is_a_no_op() {
+    let sources = [
+        ("a.ts", "export function a() {}\nfunction b() {}\nexport class C { private x() {} m() {} }\n"),
+        ("a.py", "def run():\n    pass\ndef _hidden():\n    pass\n"),
+        ("a.kt", "internal fun a() {}\nfun b() {}\nprivate fun c() {}\n"),
+        ("a.cs", "public class A { public void M() {} internal void N() {} void O() {} }\n"),
+    ];
+    let policies: HashMap<String, VisibilityPolicy> = ["typescript", "python", "kotlin", "c#"]
+        .into_iter()
+        .map(|l| (l.to_string(), VisibilityPolicy::default()))
+        .collect();
+    let configured = ParserManager::new().with_visibility_policies(&policies);
+    let plain = ParserManager::new();
+
+    let flags = |pr: &ParseResult| -> Vec<(String, Visibility, bool)> {
+        pr.functions.iter().map(|f| (f.name.clone(), f.visibility, f.is_exported)).collect()
+    };
+    for (file, source) in sources {
+        let expected = plain.parse(source.as_bytes(), Path::new(file)).unwrap();
+        let actual = configured.parse(source.as_bytes(), Path::new(file)).unwrap();
+        assert_eq!(flags(&actual), flags(&expected), "{file}");
+        let classes = |pr: &ParseResult| pr.classes.iter().map(|c| c.is_exported).collect::<Vec<_>>();
+        assert_eq!(classes(&actual), classes(&expected), "{file}");
+    }
+}
+
+// ---- T1-PRS-26: Python exports follow __all__ ----
+
+#[test]
+fn t1_prs_26_python_dunder_all_exports() {
+    let policy = VisibilityPolicy { exports: ExportRule::DunderAll, ..Default::default() };
+    let source = r#"__all__ = [
+    "load",
+    "Model",
+]
+
+def load():
+    def inner():
+        pass
+    return inner
+
+def helper():
+    pass
+
+class Model:
+    def save(self):
+        pass
+
+class Cache:
+    pass
+"#;
+    let pr = parse_with_policy("pkg/models.py", source, "python", policy.clone());
+    assert!(function(&pr, "load").is_exported);
+    assert!(!function(&pr, "helper").is_exported, "public name missing from __all__");
+    assert!(!function(&pr, "inner").is_exported, "nested function");
+    assert!(!function(&pr, "save").is_exported, "method");
+    assert!(class_exported(&pr, "Model"));
+    assert!(!class_exported(&pr, "Cache"));
+
+    // Without __all__, module-level names without a leading underscore
+    let pr = parse_with_policy("pkg/util.py", "def run():\n    pass\n\ndef _hidden():\n    pass\n", "python", policy);
+    assert!(function(&pr, "run").is_exported);
+    assert!(!function(&pr, "_hidden").is_exported);
+}
+
+// ---- T1-PRS-27: Kotlin `internal` mapped to Protected ----
+
+#[test]
+fn t1_prs_27_kotlin_internal_is_protected() {
+    let source = "internal fun a() {}\nfun b() {}\nprivate fun c() {}\ninternal class K {}\nclass L {}\n";
+    let default = ParserManager::new().parse(source.as_bytes(), Path::new("a.kt")).unwrap();
+    assert_eq!(function(&default, "a").visibility, Visibility::Public);
+
+    let policy = VisibilityPolicy {
+        modifiers: modifiers(&[("internal", VisibilityLevel::Protected)]),
+        exports: ExportRule::Visibility,
+        ..Default::default()
+    };
+    let pr = parse_with_policy("a.kt", source, "Kotlin", policy);
+    let flags = |name: &str| (function(&pr, name).visibility, function(&pr, name).is_exported);
+    assert_eq!(flags("a"), (Visibility::Protected, false));
+    assert_eq!(flags("b"), (Visibility::Public, true));
+    assert_eq!(flags("c"), (Visibility::Private, false));
+    assert!(!class_exported(&pr, "K"));
+    assert!(class_exported(&pr, "L"));
+}
+
+// ---- T1-PRS-28: TypeScript public class members count as exported ----
+
+#[test]
+fn t1_prs_28_typescript_public_members_exported() {
+    let source = "export class Store {\n  private cache() {}\n  protected load() {}\n  get() {}\n}\n";
+    let default = ParserManager::new().parse(source.as_bytes(), Path::new("store.ts")).unwrap();
+    assert!(!function(&default, "get").is_exported);
+
+    let policy = VisibilityPolicy { exports: ExportRule::Visibility, ..Default::default() };
+    let pr = parse_with_policy("store.ts", source, "typescript", policy);
+    assert!(function(&pr, "get").is_exported);
+    assert!(!function(&pr, "cache").is_exported);
+    assert!(!function(&pr, "load").is_exported);
+    assert!(class_exported(&pr, "Store"));
+}
+
+// ---- T1-PRS-29: Go capitalization also exports types ----
+
+#[test]
+fn t1_prs_29_go_capitalized_exports() {
+    let source = "package store\n\ntype Store struct{}\ntype entry struct{}\n\nfunc Open() {}\nfunc open() {}\n";
+    let policy = VisibilityPolicy { exports: ExportRule::Capitalized, ..Default::default() };
+    let pr = parse_with_policy("store.go", source, "go", policy);
+    assert!(class_exported(&pr, "Store"));
+    assert!(!class_exported(&pr, "entry"));
+    assert!(function(&pr, "Open").is_exported);
+    assert!(!function(&pr, "open").is_exported);
+}
+
+// ---- T1-PRS-30: Rust crate-visible items are not exported ----
+
+#[test]
+fn t1_prs_30_rust_restricted_visibility() {
+    let source = "pub fn a() {}\nfn b() {}\npub(crate) fn c() {}\npub struct S;\nstruct P;\n";
+    let policy = VisibilityPolicy {
+        modifiers: modifiers(&[("pub(crate)", VisibilityLevel::Protected)]),
+        default_visibility: Some(VisibilityLevel::Private),
+        exports: ExportRule::Visibility,
+    };
+    let pr = parse_with_policy("lib.rs", source, "rust", policy);
+    let flags = |name: &str| (function(&pr, name).visibility, function(&pr, name).is_exported);
+    assert_eq!(flags("a"), (Visibility::Public, true));
+    assert_eq!(flags("b"), (Visibility::Private, false));
+    assert_eq!(flags("c"), (Visibility::Protected, false));
+    assert!(class_exported(&pr, "S"));
+    assert!(!class_exported(&pr, "P"));
+}
+
+// ---- T1-PRS-31: Java package-private and C# default/internal members ----
+
+#[test]
+fn t1_prs_31_java_and_csharp_default_visibility() {
+    let java = "public class A {\n  public void a() {}\n  void b() {}\n  private void c() {}\n}\nclass B {}\n";
+    let policy = VisibilityPolicy {
+        default_visibility: Some(VisibilityLevel::Protected),
+        exports: ExportRule::Visibility,
+        ..Default::default()
+    };
+    let pr = parse_with_policy("A.java", java, "java", policy);
+    let flags = |name: &str| (function(&pr, name).visibility, function(&pr, name).is_exported);
+    assert_eq!(flags("a"), (Visibility::Public, true));
+    assert_eq!(flags("b"), (Visibility::Protected, false), "package-private");
+    assert_eq!(flags("c"), (Visibility::Private, false));
+    assert!(class_exported(&pr, "A"));
+    assert!(!class_exported(&pr, "B"));
+
+    let csharp = "public class A {\n  public void M() {}\n  internal void N() {}\n  void O() {}\n}\ninternal class B {}\n";
+    let default = ParserManager::new().parse(csharp.as_bytes(), Path::new("A.cs")).unwrap();
+    assert!(function(&default, "O").is_exported);
+
+    let policy = VisibilityPolicy {
+        modifiers: modifiers(&[("internal", VisibilityLevel::Protected)]),
+        default_visibility: Some(VisibilityLevel::Private),
+        exports: ExportRule::Visibility,
+    };
+    let pr = parse_with_policy("A.cs", csharp, "csharp", policy);
+    let flags = |name: &str| (function(&pr, name).visibility, function(&pr, name).is_exported);
+    assert_eq!(flags("M"), (Visibility::Public, true));
+    assert_eq!(flags("N"), (Visibility::Protected, false));
+    assert_eq!(flags("O"), (Visibility::Private, false));
+    assert!(class_exported(&pr, "A"));
+    assert!(!class_exported(&pr, "B"));
+}
+
+// ---- T1-PRS-32: JavaScript `#private` members are never exported ----
+
+#[test]
+fn t1_prs_32_javascript_private_names() {
+    let source = "export class Store {\n  #cache() {}\n  get() {}\n  static make() {}\n}\n";
+    let policy = VisibilityPolicy { exports: ExportRule::Visibility, ..Default::default() };
+    let pr = parse_with_policy("store.js", source, "javascript", policy);
+    let flags = |name: &str| (function(&pr, name).visibility, function(&pr, name).is_exported);
+    assert_eq!(flags("#cache"), (Visibility::Private, false));
+    assert_eq!(flags("get"), (Visibility::Public, true));
+    assert_eq!(flags("make"), (Visibility::Public, true));
+    assert!(class_exported(&pr, "Store"));
+}
+
+// ---- T1-PRS-33: Ruby inline `private def` / `protected def` ----
+
+#[test]
+fn t1_prs_33_ruby_inline_access_modifiers() {
+    let source = "class Store\n  def get\n  end\n\n  private def cache\n  end\n\n  protected def load\n  end\nend\n";
+    let default = ParserManager::new().parse(source.as_bytes(), Path::new("store.rb")).unwrap();
+    assert_eq!(function(&default, "cache").visibility, Visibility::Public);
+
+    let policy = VisibilityPolicy {
+        modifiers: modifiers(&[("private", VisibilityLevel::Private), ("protected", VisibilityLevel::Protected)]),
+        exports: ExportRule::Visibility,
+        ..Default::default()
+    };
+    let pr = parse_with_policy("store.rb", source, "ruby", policy);
+    let flags = |name: &str| (function(&pr, name).visibility, function(&pr, name).is_exported);
+    assert_eq!(flags("get"), (Visibility::Public, true));
+    assert_eq!(flags("cache"), (Visibility::Private, false));
+    assert_eq!(flags("load"), (Visibility::Protected, false));
+}
+
+// ---- T1-PRS-34: PHP methods without a modifier are public ----
+
+#[test]
+fn t1_prs_34_php_implicit_public_methods() {
+    let source = "<?php\nclass Store {\n  public function get() {}\n  function put() {}\n  private function cache() {}\n  protected function load() {}\n}\n";
+    let default = ParserManager::new().parse(source.as_bytes(), Path::new("Store.php")).unwrap();
+    assert!(!function(&default, "put").is_exported);
+
+    let policy = VisibilityPolicy { exports: ExportRule::Visibility, ..Default::default() };
+    let pr = parse_with_policy("Store.php", source, "php", policy);
+    let flags = |name: &str| (function(&pr, name).visibility, function(&pr, name).is_exported);
+    assert_eq!(flags("get"), (Visibility::Public, true));
+    assert_eq!(flags("put"), (Visibility::Public, true));
+    assert_eq!(flags("cache"), (Visibility::Private, false));
+    assert_eq!(flags("load"), (Visibility::Protected, false));
+}
+
+// ---- T1-PRS-35: Swift implicit `internal` is module-only ----
+
+#[test]
+fn t1_prs_35_swift_internal_default() {
+    let source = "public func a() {}\nfunc b() {}\nprivate func c() {}\nfileprivate func d() {}\ninternal func e() {}\nopen class S {}\nclass T {}\n";
+    let policy = VisibilityPolicy {
+        modifiers: modifiers(&[("internal", VisibilityLevel::Protected)]),
+        default_visibility: Some(VisibilityLevel::Protected),
+        exports: ExportRule::Visibility,
+    };
+    let pr = parse_with_policy("api.swift", source, "swift", policy);
+    let flags = |name: &str| (function(&pr, name).visibility, function(&pr, name).is_exported);
+    assert_eq!(flags("a"), (Visibility::Public, true));
+    assert_eq!(flags("b"), (Visibility::Protected, false));
+    assert_eq!(flags("c"), (Visibility::Private, false));
+    assert_eq!(flags("d"), (Visibility::Private, false));
+    assert_eq!(flags("e"), (Visibility::Protected, false));
+    assert!(class_exported(&pr, "S"));
+    assert!(!class_exported(&pr, "T"));
+}
+
+// ---- T1-PRS-36: C++ `static` free functions have internal linkage ----
+
+#[test]
+fn t1_prs_36_cpp_static_linkage() {
+    let source = "int api(int x) { return x; }\nstatic int helper(int x) { return x; }\nclass Store {\npublic:\n  int get() { return 1; }\nprivate:\n  int cache() { return 2; }\n};\n";
+    let policy = VisibilityPolicy {
+        modifiers: modifiers(&[("static", VisibilityLevel::Private)]),
+        exports: ExportRule::Visibility,
+        ..Default::default()
+    };
+    let pr = parse_with_policy("store.cpp", source, "cpp", policy);
+    let flags = |name: &str| (function(&pr, name).visibility, function(&pr, name).is_exported);
+    assert_eq!(flags("api"), (Visibility::Public, true));
+    assert_eq!(flags("helper"), (Visibility::Private, false));
+    // Access sections are read by extraction and kept
+    assert_eq!(flags("get"), (Visibility::Public, true));
+    assert_eq!(flags("cache"), (Visibility::Private, false));
+}
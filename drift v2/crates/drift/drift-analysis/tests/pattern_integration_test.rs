@@ -283,6 +283,7 @@ fn t3_int_04_performance_10k() {
                 confidence_values: vec![0.85; locations as usize],
                 is_dirty: false,
                 location_hash: 0,
+                over_limit: false,
             }
         })
         .collect();
@@ -453,6 +454,7 @@ fn t3_int_09_memory_pressure() {
                 confidence_values: vec![0.9],
                 is_dirty: false,
                 location_hash: 0,
+                over_limit: false,
             }
         })
         .collect();
@@ -26,11 +26,13 @@ fn make_cwe_owasp_violation() -> Violation {
             strategy: QuickFixStrategy::UseParameterizedQuery,
             description: "Use parameterized query instead of string concatenation".to_string(),
             replacement: None,
+            range: None,
         }),
         cwe_id: Some(89),
         owasp_category: Some("A03:2021-Injection".to_string()),
         suppressed: false,
         is_new: true,
+        fingerprint: None,
     }
 }
 
@@ -51,11 +53,13 @@ fn make_warning_violation() -> Violation {
             strategy: QuickFixStrategy::Rename,
             description: "Rename to camelCase".to_string(),
             replacement: Some("myFunction".to_string()),
+            range: None,
         }),
         cwe_id: None,
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        fingerprint: None,
     }
 }
 
@@ -77,6 +81,7 @@ fn make_info_violation() -> Violation {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        fingerprint: None,
     }
 }
 
@@ -98,6 +103,7 @@ fn make_hint_violation() -> Violation {
         owasp_category: None,
         suppressed: false,
         is_new: true,
+        fingerprint: None,
     }
 }
 
@@ -121,6 +127,8 @@ fn make_mixed_gate_results() -> Vec<GateResult> {
             execution_time_ms: 42,
             details: serde_json::Value::Null,
             error: None,
+            explanation: None,
+            advisories: Vec::new(),
         },
         GateResult::pass(
             GateId::TestCoverage,
@@ -329,6 +337,7 @@ fn t10_04_console_report_readability() {
                     strategy: QuickFixStrategy::WrapInTryCatch,
                     description: "Add error handling".to_string(),
                     replacement: None,
+                    range: None,
                 })
             } else {
                 None
@@ -337,6 +346,7 @@ fn t10_04_console_report_readability() {
             owasp_category: None,
             suppressed: false,
             is_new: i % 2 == 0,
+            fingerprint: None,
         })
         .collect();
 
@@ -356,11 +366,13 @@ fn t10_04_console_report_readability() {
             strategy: QuickFixStrategy::WrapInTryCatch,
             description: "Sanitize user input".to_string(),
             replacement: None,
+            range: None,
         }),
         cwe_id: None,
         owasp_category: None,
         suppressed: false,
         is_new: true,
+        fingerprint: None,
     });
 
     let results = vec![GateResult::fail(
@@ -550,6 +562,7 @@ fn t10_07_gitlab_code_quality_fingerprints() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            fingerprint: None,
         },
         Violation {
             id: "v2".to_string(),
@@ -567,6 +580,7 @@ fn t10_07_gitlab_code_quality_fingerprints() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            fingerprint: None,
         },
         Violation {
             id: "v3".to_string(),
@@ -584,6 +598,7 @@ fn t10_07_gitlab_code_quality_fingerprints() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            fingerprint: None,
         },
         // One with CWE for Security category
         Violation {
@@ -602,6 +617,7 @@ fn t10_07_gitlab_code_quality_fingerprints() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            fingerprint: None,
         },
     ];
 
@@ -730,19 +746,19 @@ fn t10_08_html_report_generation() {
     );
 }
 
-// ─── T10-09: All 8 Formats via Reporter Factory ───────────────────────
+// ─── T10-09: All 10 Formats via Reporter Factory ───────────────────────
 
-/// T10-09: Call create_reporter(format) for each of the 8 formats.
+/// T10-09: Call create_reporter(format) for each of the 10 formats.
 /// Each must return non-empty string and not error. Reporter name must match format string.
 #[test]
-fn t10_09_all_8_formats_via_factory() {
+fn t10_09_all_9_formats_via_factory() {
     let results = make_mixed_gate_results();
     let all_formats = reporters::available_formats();
 
-    assert_eq!(all_formats.len(), 8, "Must have exactly 8 reporter formats");
+    assert_eq!(all_formats.len(), 10, "Must have exactly 10 reporter formats");
 
     let expected_formats = [
-        "sarif", "json", "console", "github", "gitlab", "junit", "html", "sonarqube",
+        "sarif", "json", "console", "github", "gitlab", "junit", "html", "sonarqube", "vulnreport", "ndjson",
     ];
     for fmt in &expected_formats {
         assert!(
@@ -785,7 +801,7 @@ fn t10_09_all_8_formats_via_factory() {
 
         // For JSON-based formats, verify valid JSON
         match *format {
-            "sarif" | "json" | "github" | "gitlab" | "sonarqube" => {
+            "sarif" | "json" | "github" | "gitlab" | "sonarqube" | "vulnreport" => {
                 let parsed: Result<serde_json::Value, _> = serde_json::from_str(&text);
                 assert!(
                     parsed.is_ok(),
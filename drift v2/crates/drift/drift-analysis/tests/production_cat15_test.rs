@@ -198,7 +198,7 @@ fn t15_02_all_10_language_parsers_return_valid_output() {
 fn t15_03_fallback_grammar_coverage() {
     let manager = ParserManager::new();
 
-    // C/C++ use C# grammar as fallback
+    // C++ has a dedicated grammar; C uses C# grammar as fallback
     let c_source = "int main() { return 0; }";
     let c_result = manager.parse(c_source.as_bytes(), Path::new("test.c"));
     assert!(c_result.is_ok(), "C file must not panic, got: {:?}", c_result.err());
@@ -211,7 +211,7 @@ fn t15_03_fallback_grammar_coverage() {
     let cpp_pr = cpp_result.unwrap();
     assert_eq!(cpp_pr.language, Language::Cpp, "language must be C++");
 
-    // Swift/Scala use Java grammar as fallback
+    // Swift has a dedicated grammar; Scala uses Java grammar as fallback
     let swift_source = "func hello() { print(\"hello\") }";
     let swift_result = manager.parse(swift_source.as_bytes(), Path::new("test.swift"));
     assert!(
@@ -70,6 +70,7 @@ fn make_function(name: &str, line: u32) -> FunctionInfo {
         doc_comment: None,
         body_hash: 0,
         signature_hash: 0,
+        cognitive_complexity: 0,
     }
 }
 
@@ -114,6 +115,7 @@ fn make_class(name: &str, line: u32) -> ClassInfo {
             },
         },
         decorators: Vec::new(),
+        alias_target: None,
     }
 }
 
@@ -61,6 +61,7 @@ fn make_function(name: &str, file: &str, line: u32, end_line: u32) -> FunctionIn
         doc_comment: None,
         body_hash: 0,
         signature_hash: 0,
+        cognitive_complexity: 0,
     }
 }
 
@@ -178,6 +179,7 @@ fn t18_01_call_graph_all_6_resolution_strategies() {
         properties: Vec::new(),
         range: default_range(),
         decorators: Vec::new(),
+        alias_target: None,
     }];
 
     let builder = CallGraphBuilder::new();
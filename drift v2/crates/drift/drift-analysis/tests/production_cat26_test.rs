@@ -172,6 +172,9 @@ fn build_gate_input() -> GateInput {
         predecessor_results: HashMap::new(),
         baseline_violations: std::collections::HashSet::new(),
         feedback_stats: None,
+        min_confidence: None,
+        sample_fraction: None,
+        source_lines: Default::default(),
     }
 }
 
@@ -195,11 +198,13 @@ fn build_gate_results_with_violations() -> Vec<drift_analysis::enforcement::gate
                 strategy: drift_analysis::enforcement::rules::QuickFixStrategy::Rename,
                 description: "Move to environment variable".to_string(),
                 replacement: Some("process.env.SECRET".to_string()),
+                range: None,
             }),
             cwe_id: Some(798),
             owasp_category: Some("A07:2021".to_string()),
             suppressed: false,
             is_new: true,
+            fingerprint: None,
         },
         Violation {
             id: "singleton-outlier-src/module_3.ts-13".to_string(),
@@ -217,6 +222,7 @@ fn build_gate_results_with_violations() -> Vec<drift_analysis::enforcement::gate
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            fingerprint: None,
         },
     ];
 
@@ -1,5 +1,8 @@
-//! T4-RCH-01 through T4-RCH-10: Reachability analysis tests.
+//! T4-RCH-01 through T4-RCH-11: Reachability analysis tests.
 
+use std::path::Path;
+
+use drift_analysis::call_graph::builder::CallGraphBuilder;
 use drift_analysis::call_graph::types::{CallEdge, CallGraph, FunctionNode, Resolution};
 use drift_analysis::graph::reachability::bfs::*;
 use drift_analysis::graph::reachability::cache::ReachabilityCache;
@@ -7,6 +10,7 @@ use drift_analysis::graph::reachability::cross_service::*;
 use drift_analysis::graph::reachability::field_flow::*;
 use drift_analysis::graph::reachability::sensitivity::classify_sensitivity;
 use drift_analysis::graph::reachability::types::*;
+use drift_analysis::parsers::manager::ParserManager;
 
 fn make_node(file: &str, name: &str, exported: bool) -> FunctionNode {
     FunctionNode {
@@ -322,3 +326,47 @@ fn test_disconnected_node_reachability() {
     let result = reachability_forward(&g, a, None);
     assert!(result.reachable.is_empty()); // Only itself, which is excluded
 }
+
+// T4-RCH-11: Shortest call path between two functions
+#[test]
+fn test_shortest_path_through_chain() {
+    let chain_ts = r#"export function step1() { return step2(); }
+export function step2() { return step3(); }
+export function step3() { return step4(); }
+export function step4() { return step5(); }
+export function step5() { return "done"; }
+"#;
+    let pr = ParserManager::new().parse(chain_ts.as_bytes(), Path::new("src/chain.ts")).unwrap();
+    let (g, _) = CallGraphBuilder::new().build(&[pr]).unwrap();
+
+    let path = shortest_path_by_name(&g, "step1", "step5").expect("step5 is reachable from step1");
+    let names: Vec<&str> = path.iter().map(|&n| g.graph[n].name.as_str()).collect();
+    assert_eq!(names, ["step1", "step2", "step3", "step4", "step5"]);
+
+    let step1 = g.get_node("src/chain.ts::step1").unwrap();
+    let step5 = g.get_node("src/chain.ts::step5").unwrap();
+    assert_eq!(shortest_path(&g, step1, step5), Some(path));
+    assert_eq!(shortest_path(&g, step1, step1), Some(vec![step1]));
+    assert_eq!(shortest_path(&g, step5, step1), None);
+    assert_eq!(shortest_path_by_name(&g, "step1", "missing"), None);
+}
+
+#[test]
+fn test_shortest_path_recursion_terminates() {
+    // A → B → A (mutual recursion), A → A (self-recursion), C unreachable
+    let mut g = CallGraph::new();
+    let a = g.add_function(make_node("a.ts", "funcA", true));
+    let b = g.add_function(make_node("b.ts", "funcB", false));
+    let c = g.add_function(make_node("c.ts", "funcC", false));
+    let edge = || CallEdge { resolution: Resolution::SameFile, confidence: 0.95, call_site_line: 5 };
+    g.add_edge(a, b, edge());
+    g.add_edge(b, a, edge());
+    g.add_edge(a, a, edge());
+
+    assert_eq!(shortest_path(&g, a, c), None);
+    assert_eq!(shortest_path(&g, b, a), Some(vec![b, a]));
+    // The diamond's two routes are the same length; either is a valid answer
+    let d = build_diamond_graph();
+    let path = shortest_path_by_name(&d, "a.ts::funcA", "d.ts::funcD").unwrap();
+    assert_eq!(path.len(), 3);
+}
@@ -1,5 +1,5 @@
 #![allow(clippy::len_zero)]
-//! Phase 8 reporter tests — T8-RPT-01 through T8-RPT-07.
+//! Phase 8 reporter tests — T8-RPT-01 through T8-RPT-09.
 //!
 //! Tests all 8 reporter formats: SARIF, JSON, console, GitHub Code Quality,
 //! GitLab Code Quality, JUnit XML, HTML, SonarQube.
@@ -26,11 +26,13 @@ fn test_violations() -> Vec<Violation> {
                 strategy: QuickFixStrategy::WrapInTryCatch,
                 description: "Wrap in try-catch block".to_string(),
                 replacement: None,
+                range: None,
             }),
             cwe_id: Some(755),
             owasp_category: Some("A09:2021".to_string()),
             suppressed: false,
             is_new: true,
+            fingerprint: None,
         },
         Violation {
             id: "security-boundary-src/db.ts-10".to_string(),
@@ -48,6 +50,7 @@ fn test_violations() -> Vec<Violation> {
             owasp_category: Some("A03:2021".to_string()),
             suppressed: false,
             is_new: false,
+            fingerprint: None,
         },
         Violation {
             id: "info-hint-src/utils.ts-5".to_string(),
@@ -64,11 +67,13 @@ fn test_violations() -> Vec<Violation> {
                 strategy: QuickFixStrategy::Rename,
                 description: "Rename to camelCase".to_string(),
                 replacement: Some("myFunction".to_string()),
+                range: None,
             }),
             cwe_id: None,
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            fingerprint: None,
         },
     ]
 }
@@ -269,6 +274,7 @@ fn t8_rpt_06_unicode_handling() {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        fingerprint: None,
     }];
 
     let results = vec![GateResult::fail(
@@ -337,6 +343,7 @@ fn t8_rpt_07_large_violation_set() {
             },
             suppressed: false,
             is_new: i % 2 == 0,
+            fingerprint: None,
         })
         .collect();
 
@@ -395,7 +402,7 @@ fn test_create_reporter_factory() {
 #[test]
 fn test_available_formats() {
     let formats = available_formats();
-    assert_eq!(formats.len(), 8);
+    assert_eq!(formats.len(), 10);
     assert!(formats.contains(&"sarif"));
     assert!(formats.contains(&"json"));
     assert!(formats.contains(&"console"));
@@ -404,6 +411,8 @@ fn test_available_formats() {
     assert!(formats.contains(&"junit"));
     assert!(formats.contains(&"html"));
     assert!(formats.contains(&"sonarqube"));
+    assert!(formats.contains(&"vulnreport"));
+    assert!(formats.contains(&"ndjson"));
 }
 
 // Test GitLab fingerprint stability
@@ -439,6 +448,7 @@ fn test_suppressed_violations_excluded() {
             owasp_category: None,
             suppressed: true,
             is_new: false,
+            fingerprint: None,
         },
     ];
 
@@ -488,6 +498,7 @@ fn test_sonarqube_issue_types() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            fingerprint: None,
         },
         Violation {
             id: "bug".to_string(),
@@ -505,6 +516,7 @@ fn test_sonarqube_issue_types() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            fingerprint: None,
         },
         Violation {
             id: "smell".to_string(),
@@ -522,6 +534,7 @@ fn test_sonarqube_issue_types() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            fingerprint: None,
         },
     ];
 
@@ -541,3 +554,94 @@ fn test_sonarqube_issue_types() {
     assert_eq!(issues[1]["type"], "BUG");
     assert_eq!(issues[2]["type"], "CODE_SMELL");
 }
+
+// T8-RPT-08: Register a custom reporter and generate output through it
+#[test]
+fn test_custom_reporter_registry() {
+    struct DashboardReporter;
+    impl Reporter for DashboardReporter {
+        fn name(&self) -> &'static str {
+            "dashboard"
+        }
+        fn generate(&self, results: &[GateResult]) -> Result<String, String> {
+            let violations: usize = results.iter().map(|r| r.violations.len()).sum();
+            Ok(format!("gates={} violations={violations}", results.len()))
+        }
+    }
+
+    let mut registry = ReporterRegistry::with_builtins();
+    assert_eq!(registry.formats(), registry::BUILTIN_FORMATS.to_vec());
+    registry.register("dashboard", Box::new(DashboardReporter));
+    assert_eq!(registry.formats().last(), Some(&"dashboard"));
+    assert_eq!(registry.formats().len(), 11);
+
+    let results = test_gate_results();
+    let violations: usize = results.iter().map(|r| r.violations.len()).sum();
+    let expected = format!("gates={} violations={violations}", results.len());
+    assert_eq!(registry.generate("dashboard", &results).unwrap(), expected);
+    let created = registry.create("dashboard").unwrap();
+    assert_eq!(created.name(), "dashboard");
+    let mut streamed = Vec::new();
+    created.generate_streaming(&results, &mut streamed).unwrap();
+    assert_eq!(String::from_utf8(streamed).unwrap(), expected);
+
+    // Built-ins still render, and re-registering a name replaces it in place.
+    assert!(registry.generate("json", &results).is_ok());
+    registry.register("json", Box::new(DashboardReporter));
+    assert_eq!(registry.generate("json", &results).unwrap(), expected);
+    assert_eq!(registry.formats().len(), 11);
+    assert!(registry.generate("nonexistent", &results).is_err());
+    assert!(ReporterRegistry::new().formats().is_empty());
+
+    // The process-wide registry takes runtime names behind create_reporter.
+    let name = String::from("dashboard");
+    register_reporter(name.clone(), Box::new(DashboardReporter));
+    assert!(registered_formats().contains(&name));
+    assert_eq!(create_reporter(&name).unwrap().generate(&results).unwrap(), expected);
+    assert_eq!(available_formats(), registry::BUILTIN_FORMATS);
+}
+
+// T8-RPT-09: Diff report separates new and fixed findings by fingerprint
+#[test]
+fn test_diff_report_new_and_fixed() {
+    let fingerprinted = |fingerprints: &[&str], line_shift: u32| -> Vec<GateResult> {
+        let violations = test_violations()
+            .into_iter()
+            .zip(fingerprints)
+            .map(|(v, fp)| Violation {
+                line: v.line + line_shift,
+                fingerprint: Some(fp.to_string()),
+                id: format!("{}-{fp}", v.id),
+                ..v
+            })
+            .collect();
+        vec![GateResult::fail(GateId::PatternCompliance, 65.0, "violations".to_string(), violations)]
+    };
+    // `b` and `c` survive (moved down 3 lines), `a` is fixed, `d` is new.
+    let previous = fingerprinted(&["a", "b", "c"], 0);
+    let current = fingerprinted(&["d", "b", "c"], 3);
+
+    let diff = diff_results(&previous, &current);
+    let ids = |found: &[(GateId, Violation)]| found.iter().map(|(_, v)| v.fingerprint.clone().unwrap()).collect::<Vec<_>>();
+    assert_eq!(ids(&diff.new), vec!["d"]);
+    assert_eq!(ids(&diff.fixed), vec!["a"]);
+    assert_eq!(diff.unchanged, 2);
+    assert_eq!(diff.summary(), "1 new, 1 fixed, 2 unchanged findings");
+
+    let json: serde_json::Value = serde_json::from_str(&diff_report(&previous, &current, "json").unwrap()).unwrap();
+    assert_eq!(json["total_violations"], 2);
+    let violations = json["gates"][0]["violations"].as_array().unwrap();
+    assert_eq!(violations[0]["is_new"], true);
+    assert_eq!(violations[1]["is_new"], false);
+    assert!(violations[1]["message"].as_str().unwrap().starts_with("Fixed: "));
+    assert_eq!(json["gates"][0]["summary"], "1 new, 1 fixed");
+
+    let console = diff_report(&previous, &current, "console").unwrap();
+    assert!(console.starts_with("1 new, 1 fixed, 2 unchanged findings\n"));
+
+    // No change renders no gates.
+    assert!(diff_results(&current, &current).new.is_empty());
+    let unchanged: serde_json::Value = serde_json::from_str(&diff_report(&current, &current, "json").unwrap()).unwrap();
+    assert_eq!(unchanged["gate_count"], 0);
+    assert!(diff_report(&previous, &current, "nonexistent").is_err());
+}
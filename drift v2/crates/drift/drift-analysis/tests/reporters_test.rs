@@ -1,11 +1,12 @@
 //! Phase 6 tests: Reporters — Schema Validation & Format Correctness
-//! T6-RPT-01 through T6-RPT-07
+//! T6-RPT-01 through T6-RPT-09
 
 use drift_analysis::enforcement::gates::*;
 use drift_analysis::enforcement::reporters::*;
 use drift_analysis::enforcement::reporters::sarif::SarifReporter;
 use drift_analysis::enforcement::reporters::json::JsonReporter;
 use drift_analysis::enforcement::reporters::console::ConsoleReporter;
+use drift_analysis::enforcement::reporters::vulnreport::VulnReportReporter;
 use drift_analysis::enforcement::rules::*;
 
 fn make_test_results() -> Vec<GateResult> {
@@ -32,11 +33,13 @@ fn make_test_results() -> Vec<GateResult> {
                         strategy: QuickFixStrategy::WrapInTryCatch,
                         description: "Use parameterized query".to_string(),
                         replacement: None,
+                        range: None,
                     }),
                     cwe_id: Some(89),
                     owasp_category: Some("A03:2021-Injection".to_string()),
                     suppressed: false,
                     is_new: true,
+                    fingerprint: None,
                 },
                 Violation {
                     id: "v2".to_string(),
@@ -54,12 +57,15 @@ fn make_test_results() -> Vec<GateResult> {
                     owasp_category: None,
                     suppressed: false,
                     is_new: false,
+                    fingerprint: None,
                 },
             ],
             warnings: vec![],
             execution_time_ms: 15,
             details: serde_json::Value::Null,
             error: None,
+            explanation: None,
+            advisories: Vec::new(),
         },
         GateResult::pass(
             GateId::TestCoverage,
@@ -168,6 +174,7 @@ fn test_sarif_10k_violations_performance() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            fingerprint: None,
         })
         .collect();
 
@@ -182,6 +189,8 @@ fn test_sarif_10k_violations_performance() {
         execution_time_ms: 0,
         details: serde_json::Value::Null,
         error: None,
+        explanation: None,
+        advisories: Vec::new(),
     }];
 
     let reporter = SarifReporter::new();
@@ -270,3 +279,98 @@ fn test_sarif_cwe_owasp_references() {
     let relationships = sql_rule.unwrap()["relationships"].as_array().unwrap();
     assert!(relationships.iter().any(|r| r["target"]["id"].as_str().unwrap().contains("CWE-89")));
 }
+
+/// T6-RPT-08: Vulnerability report is flat and keeps CWE/OWASP from the violation.
+#[test]
+fn test_vulnreport_shape_and_taxonomy() {
+    let reporter = VulnReportReporter::new();
+    assert_eq!(reporter.name(), "vulnreport");
+    let mut results = make_test_results();
+    results[0].violations[1].suppressed = true;
+    let output = reporter.generate(&results).unwrap();
+    let report: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    assert!(report["schema_version"].is_string());
+    assert_eq!(report["tool"]["name"], "drift");
+    assert_eq!(report["summary"]["total"], 1, "suppressed violations are excluded");
+
+    let vulns = report["vulnerabilities"].as_array().unwrap();
+    assert_eq!(vulns.len(), 1);
+    let sql = &vulns[0];
+    assert_eq!(sql["id"], "v1");
+    assert_eq!(sql["rule_id"], "security/sql-injection");
+    assert_eq!(sql["summary"], "SQL injection vulnerability");
+    assert!(sql["details"].as_str().unwrap().contains("CWE-89"));
+    assert_eq!(sql["cwe"]["id"], "CWE-89");
+    assert_eq!(sql["owasp"], "A03:2021-Injection");
+
+    // Error severity + injection CWE lands in the top band.
+    assert_eq!(sql["severity"]["band"], "CRITICAL");
+    assert_eq!(sql["severity"]["cvss_range"][0], 9.0);
+    assert_eq!(report["summary"]["critical"], 1);
+
+    let location = &sql["location"];
+    assert_eq!(location["file"], "src/app.ts");
+    assert_eq!(location["start_line"], 10);
+    assert_eq!(location["start_column"], 5);
+    assert_eq!(location["end_column"], 30);
+    assert_eq!(sql["database_specific"]["gate"], "pattern-compliance");
+    assert_eq!(sql["database_specific"]["is_new"], true);
+
+    // Every field is a scalar or a flat object: no nested finding arrays.
+    for value in sql.as_object().unwrap().values() {
+        assert!(!value.is_array(), "vulnerability records must be flat");
+    }
+}
+
+/// T6-RPT-09: Streaming SARIF for 10K results parses as the same SARIF
+/// document `generate` produces.
+#[test]
+fn test_sarif_streaming_10k_results() {
+    let mut results = make_test_results();
+    results[0].violations.extend((0..10_000).map(|i| Violation {
+        id: format!("s{i}"),
+        file: format!("src/file{}.ts", i / 100),
+        line: (i % 1000) as u32 + 1,
+        column: Some(1),
+        end_line: None,
+        end_column: None,
+        severity: Severity::Warning,
+        pattern_id: "stream-test".to_string(),
+        rule_id: format!("stream/rule-{}", i % 10),
+        message: format!("Streamed violation {i} with \"quotes\""),
+        quick_fix: None,
+        cwe_id: Some(79),
+        owasp_category: None,
+        suppressed: i % 1000 == 0,
+        is_new: false,
+        fingerprint: None,
+    }));
+
+    let reporter = SarifReporter::new();
+    let mut buffer: Vec<u8> = Vec::new();
+    reporter.generate_streaming(&results, &mut buffer).unwrap();
+    let streamed: serde_json::Value = serde_json::from_slice(&buffer).expect("streamed SARIF must be valid JSON");
+
+    assert_eq!(streamed["version"], "2.1.0");
+    assert!(streamed["$schema"].as_str().unwrap().contains("sarif-schema-2.1.0"));
+    let run = &streamed["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "drift");
+    let expected = results.iter().flat_map(|r| &r.violations).filter(|v| !v.suppressed).count();
+    assert_eq!(run["results"].as_array().unwrap().len(), expected);
+    assert_eq!(run["results"][0]["ruleId"], results[0].violations[0].rule_id.as_str());
+
+    let generated: serde_json::Value = serde_json::from_str(&reporter.generate(&results).unwrap()).unwrap();
+    assert_eq!(streamed, generated, "streaming and buffered output describe the same document");
+}
+
+/// T6-RPT-09: Reporters without a streaming implementation write their
+/// buffered output.
+#[test]
+fn test_default_streaming_matches_generate() {
+    let results = make_test_results();
+    let reporter = JsonReporter;
+    let mut buffer: Vec<u8> = Vec::new();
+    reporter.generate_streaming(&results, &mut buffer).unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), reporter.generate(&results).unwrap());
+}
@@ -1,5 +1,5 @@
 //! Phase 6 tests: Rules Engine — Violation Mapping & Suppression
-//! T6-RUL-01 through T6-RUL-06
+//! T6-RUL-01 through T6-RUL-07
 
 use drift_analysis::enforcement::rules::*;
 use std::collections::HashMap;
@@ -276,3 +276,48 @@ fn test_severity_assignment_by_cwe() {
     let violations3 = evaluator.evaluate(&input3);
     assert!(violations3.iter().all(|v| v.severity == Severity::Info));
 }
+
+/// T6-RUL-07: SQL injection from an interpolated literal gets a concrete
+/// parameterized rewrite in the language's placeholder style.
+#[test]
+fn test_parameterized_query_quick_fix() {
+    let generator = QuickFixGenerator::new();
+    let pattern = make_pattern("sql-injection", "security", 0.9, vec![89]);
+    let outlier = |file: &str| OutlierLocation {
+        file: file.to_string(),
+        line: 7,
+        column: None,
+        end_line: None,
+        end_column: None,
+        deviation_score: 2.0,
+        message: "SQL built from user input".to_string(),
+    };
+    let fix = |file: &str, line: &str| generator.suggest_for_line(&pattern, &outlier(file), Some(line)).unwrap();
+
+    // Call argument: parameters are passed right after the literal
+    let js = fix("src/db.js", "    db.query(`SELECT * FROM users WHERE name = '${name}' AND age > ${age}`);");
+    assert_eq!(js.replacement.as_deref(), Some("'SELECT * FROM users WHERE name = ? AND age > ?', [name, age]"));
+    assert_eq!(
+        js.range,
+        Some(FixRange { start_line: 7, start_column: 13, end_line: 7, end_column: 74 })
+    );
+
+    let go = fix("db.go", r#"    rows, err := db.Query(fmt.Sprintf("SELECT * FROM users WHERE id = %d", id))"#);
+    assert_eq!(go.replacement.as_deref(), Some(r#""SELECT * FROM users WHERE id = $1", id"#));
+
+    let rust = fix("src/db.rs", r#"    let rows = sqlx::query(&format!("DELETE FROM sessions WHERE user_id = {} AND token = '{token}'", id)).execute(&pool).await?;"#);
+    assert_eq!(rust.replacement.as_deref(), Some(r#""DELETE FROM sessions WHERE user_id = $1 AND token = $2""#));
+    assert!(rust.description.contains(".bind(id).bind(token)"), "{}", rust.description);
+
+    // No interpolated SQL on the line: generic template, no range
+    let generic = fix("src/db.ts", "    const rows = await db.query(sql);");
+    assert!(generic.range.is_none());
+    assert_eq!(generic.strategy, QuickFixStrategy::UseParameterizedQuery);
+
+    // Not SQL injection: the security template is left alone
+    let xss = make_pattern("xss", "security", 0.9, vec![79]);
+    let other = generator
+        .suggest_for_line(&xss, &outlier("src/db.ts"), Some("el.innerHTML = `SELECT ${x} FROM t`;"))
+        .unwrap();
+    assert!(other.range.is_none());
+}
@@ -1,4 +1,4 @@
-//! Scanner tests — T1-SCN-01 through T1-SCN-21.
+//! Scanner tests — T1-SCN-01 through T1-SCN-28.
 //!
 //! Tests cover: baseline correctness, incremental detection, .driftignore,
 //! cancellation, language detection, symlinks, permissions, edge cases,
@@ -693,6 +693,101 @@ fn t1_scn_21_incremental_performance() {
     );
 }
 
+// ---- T1-SCN-22: Multi-root scan tags files with their originating root ----
+
+#[test]
+fn t1_scn_22_multi_root_scan() {
+    let services = TempDir::new().unwrap();
+    let libs = TempDir::new().unwrap();
+    fs::write(services.path().join("api.ts"), "export const api = 1;").unwrap();
+    fs::write(services.path().join("worker.py"), "def work(): pass").unwrap();
+    fs::write(libs.path().join("util.go"), "package util").unwrap();
+
+    let roots = vec![services.path().to_path_buf(), libs.path().to_path_buf()];
+    let scanner = Scanner::new(test_config());
+    let diff = scanner
+        .scan_roots(&roots, &FxHashMap::default(), &NoOpHandler)
+        .unwrap();
+
+    assert_eq!(diff.added.len(), 3);
+    let root_of = |path: PathBuf| diff.entries[&path].root.clone();
+    assert_eq!(root_of(services.path().join("api.ts")), Some(roots[0].clone()));
+    assert_eq!(root_of(services.path().join("worker.py")), Some(roots[0].clone()));
+    assert_eq!(root_of(libs.path().join("util.go")), Some(roots[1].clone()));
+}
+
+#[test]
+fn t1_scn_22_multi_root_dedups_nested_roots() {
+    let outer = TempDir::new().unwrap();
+    let inner = outer.path().join("libs");
+    fs::create_dir(&inner).unwrap();
+    fs::write(outer.path().join("main.ts"), "export {};").unwrap();
+    fs::write(inner.join("lib.ts"), "export {};").unwrap();
+
+    let roots = vec![outer.path().to_path_buf(), inner.clone()];
+    let scanner = Scanner::new(test_config());
+    let diff = scanner
+        .scan_roots(&roots, &FxHashMap::default(), &NoOpHandler)
+        .unwrap();
+
+    assert_eq!(diff.added.len(), 2, "overlapping files are scanned once");
+    assert_eq!(diff.entries[&inner.join("lib.ts")].root, Some(inner.clone()));
+    assert_eq!(
+        diff.entries[&outer.path().join("main.ts")].root,
+        Some(outer.path().to_path_buf())
+    );
+
+    // Single-root scans leave the root untagged.
+    let single = scanner.scan(outer.path(), &FxHashMap::default(), &NoOpHandler).unwrap();
+    assert!(single.entries.values().all(|e| e.root.is_none()));
+}
+
+// ---- T1-SCN-23: Deterministic seeded sampling ----
+
+#[test]
+fn t1_scn_23_seeded_sample_is_reproducible() {
+    use drift_core::config::SampleConfig;
+
+    let dir = create_test_fixture(200);
+    let sampled = |seed: u64| {
+        let config = ScanConfig {
+            sample: Some(SampleConfig { fraction: 0.25, seed }),
+            ..test_config()
+        };
+        Scanner::new(config)
+            .scan(dir.path(), &FxHashMap::default(), &NoOpHandler)
+            .unwrap()
+    };
+
+    let first = sampled(7);
+    let again = sampled(7);
+    let other = sampled(8);
+
+    assert_eq!(first.added, again.added, "same seed must select the same files");
+    assert_ne!(first.added, other.added, "a different seed should select a different set");
+    assert!(
+        (20..=80).contains(&first.added.len()),
+        "about a quarter of 200 files, got {}",
+        first.added.len()
+    );
+    assert_eq!(first.stats.sample_fraction, Some(0.25));
+    assert_eq!(first.stats.files_skipped_sampled, 200 - first.added.len());
+
+    // Unsampled cached files are out of scope, not removed.
+    let full = Scanner::new(test_config())
+        .scan(dir.path(), &FxHashMap::default(), &NoOpHandler)
+        .unwrap();
+    assert_eq!(full.stats.sample_fraction, None);
+    let rescan = Scanner::new(ScanConfig {
+        sample: Some(SampleConfig { fraction: 0.25, seed: 7 }),
+        ..test_config()
+    })
+    .scan(dir.path(), &build_cached_metadata(&full), &NoOpHandler)
+    .unwrap();
+    assert!(rescan.removed.is_empty());
+    assert_eq!(rescan.unchanged, first.added);
+}
+
 // ---- Helper: build cached metadata from a ScanDiff ----
 
 fn build_cached_metadata(diff: &ScanDiff) -> FxHashMap<PathBuf, CachedFileMetadata> {
@@ -712,3 +807,340 @@ fn build_cached_metadata(diff: &ScanDiff) -> FxHashMap<PathBuf, CachedFileMetada
     }
     cached
 }
+
+// ---- T1-SCN-24: Focus paths scope analysis, imports still resolve ----
+
+#[test]
+fn t1_scn_24_focus_paths_resolve_out_of_focus_imports() {
+    use drift_analysis::call_graph::CallGraphBuilder;
+    use drift_analysis::detectors::registry::create_default_registry;
+    use drift_analysis::engine::visitor::DetectionContext;
+    use drift_analysis::parsers::manager::ParserManager;
+    use drift_analysis::scanner::focus::resolve_context_files;
+
+    let dir = TempDir::new().unwrap();
+    let write = |rel: &str, content: &str| {
+        let path = dir.path().join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    };
+    write(
+        "services/payments/charge.ts",
+        "import { query } from '../common/db';\n\nexport function charge(req) {\n  eval(req.body.code);\n  return query(req.body.id);\n}\n",
+    );
+    write("services/common/db.ts", "export function query(id) {\n  eval(id);\n  return id;\n}\n");
+    write("services/other/unused.ts", "export function unused(x) {\n  eval(x);\n}\n");
+
+    let config = ScanConfig {
+        include_paths: vec!["services/payments".to_string()],
+        ..test_config()
+    };
+    let diff = Scanner::new(config)
+        .scan(dir.path(), &FxHashMap::default(), &NoOpHandler)
+        .unwrap();
+    assert_eq!(diff.added, vec![dir.path().join("services/payments/charge.ts")]);
+    assert_eq!(diff.stats.files_outside_focus, 2);
+    assert_eq!(diff.context_files.len(), 2);
+
+    let parser = ParserManager::new();
+    let parse = |path: &PathBuf| {
+        let rel = path.strip_prefix(dir.path()).unwrap();
+        let source = fs::read(path).unwrap();
+        (parser.parse(&source, rel).unwrap(), source)
+    };
+    let focus: Vec<_> = diff.added.iter().map(parse).collect();
+    let focus_results: Vec<_> = focus.iter().map(|(pr, _)| pr.clone()).collect();
+
+    // Only the imported context file is pulled in for resolution.
+    let pulled_in = resolve_context_files(&focus_results, &diff.context_files, dir.path());
+    assert_eq!(pulled_in, vec![dir.path().join("services/common/db.ts")]);
+
+    // Findings come only from focus files.
+    let registry = create_default_registry();
+    let findings: Vec<_> = focus
+        .iter()
+        .flat_map(|(pr, source)| registry.run_all(&DetectionContext::from_parse_result(pr, source)))
+        .collect();
+    assert!(!findings.is_empty());
+    assert!(findings.iter().all(|m| m.file.starts_with("services/payments/")), "{findings:?}");
+
+    // The cross-directory call still resolves through the context file.
+    let mut all_results = focus_results.clone();
+    all_results.extend(pulled_in.iter().map(|p| parse(p).0));
+    let (graph, _) = CallGraphBuilder::new().build(&all_results).unwrap();
+    let caller = graph.get_node("services/payments/charge.ts::charge").expect("caller node");
+    let callee = graph.get_node("services/common/db.ts::query").expect("callee node");
+    assert!(graph.graph.find_edge(caller, callee).is_some());
+}
+
+// ---- T1-SCN-25: Interrupted scan resumes from its storage checkpoint ----
+
+#[test]
+fn t1_scn_25_interrupted_scan_resumes_from_checkpoint() {
+    use drift_analysis::scanner::cancellation::ScanCancellation;
+    use drift_analysis::scanner::checkpoint::{CheckpointSink, CheckpointedFile};
+    use drift_analysis::scanner::types::ScanEntry;
+    use drift_storage::queries::scan_checkpoint::{load_checkpoint, save_checkpoint, CheckpointRecord};
+
+    /// Writes checkpoints to drift.db, optionally interrupting the scan after the first one.
+    struct DbSink<'a> {
+        conn: &'a Mutex<rusqlite::Connection>,
+        root: String,
+        interrupt: Option<ScanCancellation>,
+        saved: Mutex<Vec<PathBuf>>,
+    }
+    impl CheckpointSink for DbSink<'_> {
+        fn checkpoint(&self, entries: &[ScanEntry]) {
+            let records: Vec<CheckpointRecord> = entries
+                .iter()
+                .map(|e| CheckpointRecord {
+                    path: e.path.to_string_lossy().to_string(),
+                    content_hash: e.content_hash.to_le_bytes().to_vec(),
+                    mtime_secs: e.mtime_secs,
+                    mtime_nanos: e.mtime_nanos as i64,
+                })
+                .collect();
+            save_checkpoint(&self.conn.lock().unwrap(), &self.root, &records).unwrap();
+            self.saved.lock().unwrap().extend(entries.iter().map(|e| e.path.clone()));
+            if let Some(interrupt) = &self.interrupt {
+                interrupt.cancel();
+            }
+        }
+    }
+
+    let dir = create_test_fixture(10);
+    let root = dir.path().to_string_lossy().to_string();
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    drift_storage::migrations::run_migrations(&conn).unwrap();
+    let conn = Mutex::new(conn);
+    let config = ScanConfig {
+        checkpoint_interval: Some(4),
+        ..test_config()
+    };
+
+    // First run: interrupted right after the first checkpoint.
+    let scanner = Scanner::new(config.clone());
+    let sink = DbSink {
+        conn: &conn,
+        root: root.clone(),
+        interrupt: Some(scanner.cancellation().clone()),
+        saved: Mutex::new(Vec::new()),
+    };
+    scanner
+        .scan_resumable(dir.path(), &FxHashMap::default(), &FxHashMap::default(), &sink, &NoOpHandler)
+        .unwrap();
+    let first_batch = sink.saved.into_inner().unwrap();
+    assert_eq!(first_batch.len(), 4, "one checkpoint of 4 files before the interrupt");
+
+    // Restart from the stored checkpoint.
+    let records = load_checkpoint(&conn.lock().unwrap(), &root).unwrap();
+    assert_eq!(records.len(), 4);
+    let checkpoint: FxHashMap<PathBuf, CheckpointedFile> = records
+        .iter()
+        .map(|r| {
+            let file = CheckpointedFile {
+                content_hash: u64::from_le_bytes(r.content_hash.as_slice().try_into().unwrap()),
+                mtime_secs: r.mtime_secs,
+                mtime_nanos: r.mtime_nanos as u32,
+            };
+            (PathBuf::from(&r.path), file)
+        })
+        .collect();
+    let sink = DbSink {
+        conn: &conn,
+        root: root.clone(),
+        interrupt: None,
+        saved: Mutex::new(Vec::new()),
+    };
+    let diff = Scanner::new(config)
+        .scan_resumable(dir.path(), &FxHashMap::default(), &checkpoint, &sink, &NoOpHandler)
+        .unwrap();
+
+    assert_eq!(diff.stats.files_resumed, 4);
+    assert_eq!(diff.stats.files_fresh, 6);
+    assert_eq!(diff.added.len(), 10, "resumed files are still part of the diff");
+    let second_batch = sink.saved.into_inner().unwrap();
+    assert_eq!(second_batch.len(), 6);
+    assert!(second_batch.iter().all(|p| !first_batch.contains(p)), "resumed files are not processed again");
+    for path in &first_batch {
+        let content = fs::read(path).unwrap();
+        assert_eq!(diff.entries[path].content_hash, hash_content(&content), "hash carried over from the checkpoint");
+    }
+    assert_eq!(load_checkpoint(&conn.lock().unwrap(), &root).unwrap().len(), 10);
+}
+
+// ---- T1-SCN-26: Streaming scan yields changes before the summary ----
+
+#[test]
+fn t1_scn_26_streaming_scan_events() {
+    use drift_analysis::scanner::ScanEvent;
+
+    let dir = create_test_fixture(30);
+    let scanner = Scanner::new(test_config());
+    let handler = RecordingHandler::default();
+
+    let events: Vec<ScanEvent> = scanner.scan_streaming(dir.path(), &FxHashMap::default(), &handler).collect();
+    assert_eq!(events.len(), 31);
+    assert!(events[..30].iter().all(|e| matches!(e, ScanEvent::Added(_))));
+    let ScanEvent::Complete(stats) = &events[30] else {
+        panic!("stream must end with Complete, got {:?}", events[30]);
+    };
+    assert_eq!(stats.total_files, 30);
+    assert_eq!(stats.languages_found.values().sum::<usize>(), 30);
+    assert_eq!(handler.started.lock().unwrap().len(), 1);
+    assert_eq!(handler.complete.lock().unwrap()[0].added, 30);
+
+    // Second scan against the cache: one modified, one removed, the rest unchanged.
+    let cached = build_cached_metadata(&scanner.scan(dir.path(), &FxHashMap::default(), &NoOpHandler).unwrap());
+    fs::write(dir.path().join("file_0.ts"), "// changed\n").unwrap();
+    fs::remove_file(dir.path().join("file_1.js")).unwrap();
+    let events: Vec<ScanEvent> = scanner.scan_streaming(dir.path(), &cached, &NoOpHandler).collect();
+    assert_eq!(events.len(), 3, "{events:?}");
+    assert!(matches!(&events[0], ScanEvent::Modified(e) if e.path.ends_with("file_0.ts")));
+    assert!(matches!(&events[1], ScanEvent::Removed(p) if p.ends_with("file_1.js")));
+    let ScanEvent::Complete(stats) = &events[2] else {
+        panic!("stream must end with Complete");
+    };
+    assert_eq!(stats.total_files, 29);
+    assert!((stats.cache_hit_rate - 28.0 / 29.0).abs() < 1e-9);
+
+    // Cancelling mid-stream stops file events and skips removals.
+    let empty = FxHashMap::default();
+    let mut stream = scanner.scan_streaming(dir.path(), &empty, &NoOpHandler);
+    assert!(matches!(stream.next(), Some(ScanEvent::Added(_))));
+    scanner.cancellation().cancel();
+    let rest: Vec<ScanEvent> = stream.collect();
+    assert_eq!(rest.len(), 1);
+    assert!(matches!(&rest[0], ScanEvent::Complete(stats) if stats.total_files == 1));
+}
+
+// ---- T1-SCN-27: .gitignore files and extra_ignore prune the walk ----
+
+#[test]
+fn t1_scn_27_gitignore_and_extra_ignore() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    for path in [
+        "src/app.ts",
+        "generated/client.ts",
+        "pkg/index.ts",
+        "pkg/schema.gen.ts",
+        "fixtures/huge.ts",
+        "fixtures/schema.ts",
+        "deps/left/mod.js",
+        "deps/right/nested/mod.js",
+    ] {
+        fs::create_dir_all(root.join(path).parent().unwrap()).unwrap();
+        fs::write(root.join(path), "export const x = 1;\n").unwrap();
+    }
+    // Not a git repository: .gitignore files still apply, nested ones too.
+    fs::write(root.join(".gitignore"), "generated/\n").unwrap();
+    fs::write(root.join("pkg/.gitignore"), "*.gen.ts\n").unwrap();
+
+    let discovered = |extra_ignore: &[&str]| -> Vec<String> {
+        let config = ScanConfig {
+            extra_ignore: extra_ignore.iter().map(|g| g.to_string()).collect(),
+            ..test_config()
+        };
+        let diff = Scanner::new(config).scan(root, &FxHashMap::default(), &NoOpHandler).unwrap();
+        let mut added: Vec<String> = diff
+            .added
+            .iter()
+            .filter(|p| p.extension().is_some_and(|e| e == "ts" || e == "js"))
+            .map(|p| p.strip_prefix(root).unwrap().to_string_lossy().replace('\\', "/"))
+            .collect();
+        added.sort();
+        added
+    };
+
+    assert_eq!(
+        discovered(&[]),
+        [
+            "deps/left/mod.js",
+            "deps/right/nested/mod.js",
+            "fixtures/huge.ts",
+            "fixtures/schema.ts",
+            "pkg/index.ts",
+            "src/app.ts",
+        ]
+    );
+    // The last matching pattern wins: `!` re-includes one fixture, and a
+    // directory pattern drops everything beneath it.
+    assert_eq!(
+        discovered(&["fixtures/**", "!fixtures/schema.ts", "deps/"]),
+        ["fixtures/schema.ts", "pkg/index.ts", "src/app.ts"]
+    );
+    assert_eq!(
+        discovered(&["*.js", "!deps/left/*"]),
+        ["deps/left/mod.js", "fixtures/huge.ts", "fixtures/schema.ts", "pkg/index.ts", "src/app.ts"]
+    );
+}
+
+// ---- T1-SCN-28: Diff-aware scan covers only files changed in a git range ----
+
+/// Stage everything under `root` (including deletions) and commit it.
+fn git_commit_all(repo: &git2::Repository, message: &str) {
+    let mut index = repo.index().unwrap();
+    index.add_all(["*"], git2::IndexAddOption::DEFAULT, None).unwrap();
+    index.update_all(["*"], None).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = git2::Signature::now("drift", "drift@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents).unwrap();
+}
+
+#[test]
+fn t1_scn_28_scan_git_range() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    let repo = git2::Repository::init(root).unwrap();
+    let write = |path: &str, content: &str| {
+        fs::create_dir_all(root.join(path).parent().unwrap()).unwrap();
+        fs::write(root.join(path), content).unwrap();
+    };
+    write("src/app.ts", "export const x = 1;\n");
+    write("src/util.py", "def f():\n    return 1\n");
+    write("src/lib.rs", "pub fn g() {}\n");
+    git_commit_all(&repo, "initial");
+
+    write("src/app.ts", "export const x = 2;\n");
+    fs::remove_file(root.join("src/util.py")).unwrap();
+    write("src/server.go", "package main\n");
+    write("node_modules/dep/index.js", "module.exports = 1;\n");
+    git_commit_all(&repo, "change");
+
+    let handler = RecordingHandler::default();
+    let diff = Scanner::new(test_config())
+        .scan_git_range(root, "HEAD~1", "HEAD", &handler)
+        .unwrap();
+    let relative = |paths: &[PathBuf]| -> Vec<String> {
+        paths
+            .iter()
+            .map(|p| p.strip_prefix(root).unwrap().to_string_lossy().replace('\\', "/"))
+            .collect()
+    };
+    // node_modules is committed but still ignored, as in a full scan.
+    assert_eq!(relative(&diff.added), vec!["src/server.go"]);
+    assert_eq!(relative(&diff.modified), vec!["src/app.ts"]);
+    assert_eq!(relative(&diff.removed), vec!["src/util.py"]);
+    assert!(diff.unchanged.is_empty());
+
+    // Changed files carry full metadata; untouched ones are not scanned.
+    assert_eq!(diff.entries.len(), 2);
+    let app = &diff.entries[&root.join("src/app.ts")];
+    assert_eq!(app.language, Some(Language::TypeScript));
+    assert_eq!(app.content_hash, hash_content(b"export const x = 2;\n"));
+    assert_eq!(app.file_size, 20);
+    assert!(!diff.entries.contains_key(&root.join("src/lib.rs")));
+    assert_eq!(diff.stats.total_files, 2);
+    let complete = handler.complete.lock().unwrap();
+    assert_eq!((complete[0].added, complete[0].modified, complete[0].removed), (1, 1, 1));
+
+    // An unknown ref is reported, not treated as an empty diff.
+    let err = Scanner::new(test_config())
+        .scan_git_range(root, "no-such-branch", "HEAD", &NoOpHandler)
+        .unwrap_err();
+    assert!(err.to_string().contains("no-such-branch"), "{err}");
+}
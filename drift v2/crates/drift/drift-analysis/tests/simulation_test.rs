@@ -1,4 +1,4 @@
-//! Phase 7 Simulation Engine tests — T7-SIM-01 through T7-SIM-07.
+//! Phase 7 Simulation Engine tests — T7-SIM-01 through T7-SIM-08.
 
 use drift_analysis::advanced::simulation::*;
 
@@ -195,3 +195,52 @@ fn test_confidence_interval_validation() {
     assert!(!ConfidenceInterval { p10: 10.0, p50: 5.0, p90: 1.0 }.is_valid());
     assert!(!ConfidenceInterval { p10: -1.0, p50: 5.0, p90: 10.0 }.is_valid());
 }
+
+// T7-SIM-08: Custom scorers are used for ranking and effort calibration.
+#[test]
+fn t7_sim_08_custom_scorer_injected() {
+    /// Favors whichever approach has the lowest estimated effort, and reads
+    /// every task as maximally hard.
+    struct HistoricalScorer;
+    impl Scorer for HistoricalScorer {
+        fn score(&self, _task: &SimulationTask, approach: &SimulationApproach) -> f64 {
+            (approach.estimated_effort_hours / 100.0).clamp(0.0, 1.0)
+        }
+        fn name(&self) -> &'static str { "historical" }
+        fn weight(&self) -> f64 { 1.0 }
+    }
+    struct Pessimist;
+    impl Scorer for Pessimist {
+        fn score(&self, _task: &SimulationTask, _approach: &SimulationApproach) -> f64 { 1.0 }
+        fn name(&self) -> &'static str { "pessimist" }
+        fn weight(&self) -> f64 { 1.0 }
+    }
+
+    let task = make_task(TaskCategory::FixBug);
+    let recommender = StrategyRecommender::new()
+        .with_scorers(vec![Box::new(HistoricalScorer)])
+        .with_seed(42);
+    assert_eq!(recommender.scorer_names(), vec!["historical"]);
+    let result = recommender.recommend(&task);
+    let best = &result.approaches[result.recommended_approach_index];
+    assert!(result
+        .approaches
+        .iter()
+        .all(|a| best.estimated_effort_hours <= a.estimated_effort_hours));
+
+    // The built-in formulas are available by name.
+    assert_eq!(DEFAULT_SCORER_NAMES.len(), 4);
+    assert!(DEFAULT_SCORER_NAMES.iter().all(|n| scorer_by_name(n).is_some()));
+    assert!(scorer_by_name("historical").is_none());
+
+    let ctx = make_context(15.0, 25, 0.7);
+    let baseline = MonteCarloSimulator::new(1000).with_seed(7).simulate(TaskCategory::FixBug, &ctx);
+    let calibrated = MonteCarloSimulator::new(1000)
+        .with_seed(7)
+        .with_scorer(Box::new(Pessimist));
+    assert_eq!(calibrated.scorer_name(), Some("pessimist"));
+    let pessimistic = calibrated.simulate(TaskCategory::FixBug, &ctx);
+    // A score of 1.0 raises the base effort by half; the sampling is otherwise identical.
+    let ratio = pessimistic.p50 / baseline.p50;
+    assert!((ratio - 1.5).abs() < 1e-9, "p50 ratio {ratio}");
+}
@@ -165,6 +165,8 @@ fn make_finding(category: CryptoCategory, file: &str) -> CryptoFinding {
         owasp: "A02:2025".into(),
         remediation: "test".into(),
         language: "python".into(),
+        suggested_replacement: None,
+        range: None,
     }
 }
 
@@ -268,6 +270,8 @@ fn stress_health_massive_findings_floor_at_zero() {
             owasp: "A02:2025".into(),
             remediation: "test".into(),
             language: "python".into(),
+            suggested_replacement: None,
+            range: None,
         })
         .collect();
     let h = calculate_crypto_health(&findings);
@@ -293,6 +297,8 @@ fn stress_health_zero_confidence_no_penalty() {
         owasp: "A02:2025".into(),
         remediation: "test".into(),
         language: "python".into(),
+        suggested_replacement: None,
+        range: None,
     }];
     let h = calculate_crypto_health(&findings);
     assert!(
@@ -310,18 +316,21 @@ fn stress_health_mixed_severities() {
             category: CryptoCategory::HardcodedKey, // sev 9 → critical
             description: "".into(), code: "".into(), confidence: 0.5,
             cwe_id: 321, owasp: "".into(), remediation: "".into(), language: "python".into(),
+            suggested_replacement: None, range: None,
         },
         CryptoFinding {
             file: "b.py".into(), line: 1,
             category: CryptoCategory::WeakHash, // sev 8 → high
             description: "".into(), code: "".into(), confidence: 0.5,
             cwe_id: 328, owasp: "".into(), remediation: "".into(), language: "python".into(),
+            suggested_replacement: None, range: None,
         },
         CryptoFinding {
             file: "c.py".into(), line: 1,
             category: CryptoCategory::MissingEncryption, // sev 6 → medium
             description: "".into(), code: "".into(), confidence: 0.5,
             cwe_id: 311, owasp: "".into(), remediation: "".into(), language: "python".into(),
+            suggested_replacement: None, range: None,
         },
     ];
     let h = calculate_crypto_health(&findings);
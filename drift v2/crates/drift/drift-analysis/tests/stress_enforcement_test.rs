@@ -72,6 +72,8 @@ fn stress_unicode_everywhere() {
         execution_time_ms: 0,
         details: serde_json::Value::Null,
         error: None,
+        explanation: None,
+        advisories: Vec::new(),
     };
     let sarif = SarifReporter::new().generate(&[gate_result]).unwrap();
     let parsed: serde_json::Value = serde_json::from_str(&sarif).unwrap();
@@ -89,6 +91,8 @@ fn stress_unicode_everywhere() {
         execution_time_ms: 0,
         details: serde_json::Value::Null,
         error: None,
+        explanation: None,
+        advisories: Vec::new(),
     };
     let json_out = JsonReporter.generate(&[gate_result2]).unwrap();
     let _: serde_json::Value = serde_json::from_str(&json_out).unwrap();
@@ -438,6 +442,8 @@ fn stress_rules_to_gates_to_reporters_data_flow() {
         execution_time_ms: 10,
         details: serde_json::Value::Null,
         error: None,
+        explanation: None,
+        advisories: Vec::new(),
     };
 
     // Step 3: SARIF reporter must preserve CWE and OWASP references
@@ -684,11 +690,13 @@ fn stress_serde_round_trip_all_types() {
             strategy: QuickFixStrategy::WrapInTryCatch,
             description: "Use parameterized query".to_string(),
             replacement: Some("db.query($1, [input])".to_string()),
+            range: None,
         }),
         cwe_id: Some(89),
         owasp_category: Some("A03:2021".to_string()),
         suppressed: false,
         is_new: true,
+        fingerprint: None,
     };
     let json = serde_json::to_string(&v).unwrap();
     let v2: Violation = serde_json::from_str(&json).unwrap();
@@ -829,6 +837,7 @@ fn stress_suppressed_violations_excluded_from_sarif() {
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                fingerprint: None,
             },
             Violation {
                 id: "v-suppressed".to_string(),
@@ -846,12 +855,15 @@ fn stress_suppressed_violations_excluded_from_sarif() {
                 owasp_category: None,
                 suppressed: true,
                 is_new: false,
+                fingerprint: None,
             },
         ],
         warnings: vec![],
         execution_time_ms: 0,
         details: serde_json::Value::Null,
         error: None,
+        explanation: None,
+        advisories: Vec::new(),
     };
 
     let sarif_out = SarifReporter::new().generate(&[gate_result]).unwrap();
@@ -68,6 +68,7 @@ fn make_pattern(id: &str, locations: u32, files: u32) -> AggregatedPattern {
         confidence_values: vec![0.9; locations as usize],
         is_dirty: false,
         location_hash: 0,
+        over_limit: false,
     }
 }
 
@@ -88,7 +88,7 @@ fn language_from_extension_none_and_unknown() {
 
 #[test]
 fn language_ts_language_new_variants_dont_panic() {
-    // The new languages use fallback grammars (C# for C/C++, Java for Swift/Scala).
+    // The new languages: Swift and C++ have their own grammars, the rest use fallbacks (C# for C, Java for Scala).
     // This test ensures ts_language() doesn't panic at runtime.
     let new_langs = [Language::Cpp, Language::C, Language::Swift, Language::Scala];
     for lang in &new_langs {
@@ -1,5 +1,5 @@
 #![allow(clippy::field_reassign_with_default)]
-//! T4-TST-01 through T4-TST-04: Test topology tests.
+//! T4-TST-01 through T4-TST-05: Test topology tests.
 
 use drift_analysis::call_graph::types::{CallEdge, CallGraph, FunctionNode, Resolution};
 use drift_analysis::graph::test_topology::*;
@@ -51,6 +51,7 @@ fn make_parse_result(file: &str, functions: Vec<FunctionInfo>, call_sites: Vec<C
         error_count: 0,
         error_ranges: Vec::new(),
         has_errors: false,
+        truncated_at_depth: None,
     }
 }
 
@@ -75,6 +76,7 @@ fn make_function(name: &str, line: u32, end_line: u32) -> FunctionInfo {
         doc_comment: None,
         body_hash: 0,
         signature_hash: 0,
+        cognitive_complexity: 0,
     }
 }
 
@@ -508,3 +510,30 @@ fn test_reachability_types() {
     assert_eq!(ReachabilityEngine::SqliteCte.name(), "sqlite_cte");
     assert_eq!(format!("{}", ReachabilityEngine::Petgraph), "petgraph");
 }
+
+// T4-TST-05: Impacted tests from changed functions
+// Changing a leaf returns exactly the tests whose call chains reach it
+#[test]
+fn test_impacted_tests_for_changed_leaf() {
+    let mut g = CallGraph::new();
+
+    let leaf = g.add_function(make_node("src/utils.ts", "format", false));
+    let mid = g.add_function(make_node("src/report.ts", "render", true));
+    let other = g.add_function(make_node("src/db.ts", "query", true));
+
+    let test_direct = g.add_function(make_node("tests/utils.test.ts", "test_format", false));
+    let test_indirect = g.add_function(make_node("tests/report.test.ts", "test_render", false));
+    let test_unrelated = g.add_function(make_node("tests/db.test.ts", "test_query", false));
+
+    g.add_edge(mid, leaf, make_edge());
+    g.add_edge(test_direct, leaf, make_edge());
+    g.add_edge(test_indirect, mid, make_edge());
+    g.add_edge(test_unrelated, other, make_edge());
+
+    let tests = [test_direct, test_indirect, test_unrelated];
+    assert_eq!(impacted_tests(&g, &[leaf], &tests), vec![test_direct, test_indirect]);
+    assert_eq!(impacted_tests(&g, &[other], &tests), vec![test_unrelated]);
+    // A changed test selects itself; nothing changed selects nothing.
+    assert_eq!(impacted_tests(&g, &[test_unrelated], &tests), vec![test_unrelated]);
+    assert!(impacted_tests(&g, &[], &tests).is_empty());
+}
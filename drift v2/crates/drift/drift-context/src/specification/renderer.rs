@@ -105,7 +105,7 @@ impl SpecificationRenderer {
 
         // Sort by caller count descending
         let mut funcs = module.public_functions.clone();
-        funcs.sort_by(|a, b| b.callers.len().cmp(&a.callers.len()));
+        funcs.sort_by_key(|b| std::cmp::Reverse(b.callers.len()));
 
         out.push_str("| Function | Signature | Callers |\n");
         out.push_str("|----------|-----------|--------|\n");
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::AnalysisProfile;
+
 /// Configuration for the analysis subsystem.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(default)]
@@ -25,6 +27,52 @@ pub struct AnalysisConfig {
     pub gast_languages: Vec<String>,
     /// Enable incremental analysis. Default: true.
     pub incremental: Option<bool>,
+    /// Named preset applied on load (`fast`, `balanced`, `deep`). Default: balanced.
+    pub profile: Option<AnalysisProfile>,
+    /// Follow taint across function calls via the call graph. Default: true.
+    pub interprocedural_taint: Option<bool>,
+    /// Detector categories that skip test files (e.g. `["security"]`), so
+    /// fixture secrets are not reported while complexity and other
+    /// categories still cover test code. Default: none.
+    #[serde(default)]
+    pub exclude_tests_from: Vec<String>,
+    /// Options for individual detectors (`[analysis.detectors]`).
+    #[serde(default)]
+    pub detectors: DetectorOptions,
+}
+
+/// Options for individual detectors.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct DetectorOptions {
+    /// Logger methods whose concatenated messages are flagged
+    /// (`LOG-CONCAT-004`). Default: `trace`, `debug`, `info`, `warn`, `error`.
+    pub logger_methods: Vec<String>,
+    /// Entropy bar for `SEC-SECRET-ENTROPY`, in bits per base64 character;
+    /// hex literals are held to the same bar scaled to their alphabet.
+    /// Default: 4.0.
+    pub entropy_threshold: Option<f64>,
+    /// Parameter count above which a function is reported
+    /// (`STRUCT-LONG-PARAMS`). Default: 6.
+    pub max_parameters: Option<usize>,
+    /// Leave constructors out of `STRUCT-LONG-PARAMS`, where dependency
+    /// injection lists every collaborator. Default: false.
+    pub exclude_constructors: Option<bool>,
+    /// Fetch-all calls checked for pagination in request handlers
+    /// (`PERF-UNBOUNDED-004`), added to the built-in ORM set.
+    pub fetch_all_sinks: Vec<String>,
+    /// Outbound client calls that must pass a timeout (`PERF-TIMEOUT-005`),
+    /// added to the built-in HTTP, RPC and database clients.
+    pub outbound_calls: Vec<String>,
+    /// Body parser setup calls that must set a size limit
+    /// (`PERF-BODY-LIMIT-007`), added to the built-in Express/Koa parsers.
+    pub body_parsers: Vec<String>,
+    /// Cookie-setting calls checked for `HttpOnly`, `Secure` and `SameSite`
+    /// (`SEC-COOKIE-001`), added to the built-in setters.
+    pub cookie_setters: Vec<String>,
+    /// Hosts (and their subdomains) whose `http://` URLs are not reported
+    /// (`SEC-HTTP-001`), e.g. an internal service mesh domain.
+    pub cleartext_allowed_hosts: Vec<String>,
 }
 
 impl AnalysisConfig {
@@ -42,4 +90,14 @@ impl AnalysisConfig {
     pub fn effective_min_files(&self) -> u32 {
         self.min_files.unwrap_or(2)
     }
+
+    /// Returns the selected profile, defaulting to balanced.
+    pub fn effective_profile(&self) -> AnalysisProfile {
+        self.profile.unwrap_or_default()
+    }
+
+    /// Returns whether interprocedural taint runs, defaulting to true.
+    pub fn effective_interprocedural_taint(&self) -> bool {
+        self.interprocedural_taint.unwrap_or(true)
+    }
 }
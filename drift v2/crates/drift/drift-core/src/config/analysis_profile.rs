@@ -0,0 +1,69 @@
+//! Named analysis profiles: presets over the analysis and scan knobs.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::{DriftConfig, SampleConfig};
+
+/// Share of files a `fast` scan samples when no sample is configured.
+pub const FAST_SAMPLE_FRACTION: f64 = 0.25;
+
+/// A preset trading analysis depth for speed.
+///
+/// Applying a profile fills in every knob it covers that is still unset, so
+/// explicit settings in `drift.toml`, the environment or the CLI win.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnalysisProfile {
+    /// Intraprocedural taint only, over a sample of the files.
+    Fast,
+    /// The compiled defaults.
+    #[default]
+    Balanced,
+    /// Interprocedural taint over every file.
+    Deep,
+}
+
+impl AnalysisProfile {
+    /// Set the sub-configs this profile covers, leaving explicit values alone.
+    pub fn apply(self, config: &mut DriftConfig) {
+        let analysis = &mut config.analysis;
+        analysis.profile = Some(self);
+        analysis.interprocedural_taint.get_or_insert(self != Self::Fast);
+        if self == Self::Fast && config.scan.sample.is_none() {
+            config.scan.sample = Some(SampleConfig {
+                fraction: FAST_SAMPLE_FRACTION,
+                seed: 0,
+            });
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Fast => "fast",
+            Self::Balanced => "balanced",
+            Self::Deep => "deep",
+        }
+    }
+}
+
+impl fmt::Display for AnalysisProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for AnalysisProfile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "fast" => Ok(Self::Fast),
+            "balanced" => Ok(Self::Balanced),
+            "deep" => Ok(Self::Deep),
+            other => Err(format!("unknown analysis profile '{other}' (expected fast, balanced or deep)")),
+        }
+    }
+}
@@ -5,7 +5,7 @@ use std::path::Path;
 use serde::{Deserialize, Serialize};
 
 use super::{
-    AnalysisConfig, BackupConfig, GateConfig, LicenseConfig, McpConfig, ScanConfig,
+    AnalysisConfig, AnalysisProfile, BackupConfig, GateConfig, LicenseConfig, McpConfig, ScanConfig,
     TelemetryConfig,
 };
 use crate::errors::ConfigError;
@@ -87,6 +87,10 @@ impl DriftConfig {
             Self::apply_cli_overrides(&mut config, cli);
         }
 
+        // The profile fills in whatever the layers above left unset.
+        let profile = config.analysis.effective_profile();
+        profile.apply(&mut config);
+
         // Validate the final config
         Self::validate(&config)?;
 
@@ -191,6 +195,14 @@ impl DriftConfig {
         if other.scan.parallelism.is_some() {
             base.scan.parallelism = other.scan.parallelism;
         }
+        if other.scan.sample.is_some() {
+            base.scan.sample = other.scan.sample;
+        }
+        // Per language, so a project can override one language's policy
+        // without restating the others from the user config
+        for (language, policy) in &other.scan.visibility {
+            base.scan.visibility.insert(language.clone(), policy.clone());
+        }
 
         // Analysis
         if other.analysis.min_occurrences.is_some() {
@@ -218,6 +230,39 @@ impl DriftConfig {
         if other.analysis.incremental.is_some() {
             base.analysis.incremental = other.analysis.incremental;
         }
+        if other.analysis.profile.is_some() {
+            base.analysis.profile = other.analysis.profile;
+        }
+        if other.analysis.interprocedural_taint.is_some() {
+            base.analysis.interprocedural_taint = other.analysis.interprocedural_taint;
+        }
+        if !other.analysis.detectors.logger_methods.is_empty() {
+            base.analysis.detectors.logger_methods = other.analysis.detectors.logger_methods.clone();
+        }
+        if other.analysis.detectors.entropy_threshold.is_some() {
+            base.analysis.detectors.entropy_threshold = other.analysis.detectors.entropy_threshold;
+        }
+        if other.analysis.detectors.max_parameters.is_some() {
+            base.analysis.detectors.max_parameters = other.analysis.detectors.max_parameters;
+        }
+        if other.analysis.detectors.exclude_constructors.is_some() {
+            base.analysis.detectors.exclude_constructors = other.analysis.detectors.exclude_constructors;
+        }
+        if !other.analysis.detectors.fetch_all_sinks.is_empty() {
+            base.analysis.detectors.fetch_all_sinks = other.analysis.detectors.fetch_all_sinks.clone();
+        }
+        if !other.analysis.detectors.outbound_calls.is_empty() {
+            base.analysis.detectors.outbound_calls = other.analysis.detectors.outbound_calls.clone();
+        }
+        if !other.analysis.detectors.body_parsers.is_empty() {
+            base.analysis.detectors.body_parsers = other.analysis.detectors.body_parsers.clone();
+        }
+        if !other.analysis.detectors.cookie_setters.is_empty() {
+            base.analysis.detectors.cookie_setters = other.analysis.detectors.cookie_setters.clone();
+        }
+        if !other.analysis.detectors.cleartext_allowed_hosts.is_empty() {
+            base.analysis.detectors.cleartext_allowed_hosts = other.analysis.detectors.cleartext_allowed_hosts.clone();
+        }
 
         // Quality gates
         if other.quality_gates.fail_on.is_some() {
@@ -325,6 +370,11 @@ impl DriftConfig {
                 config.analysis.dominance_threshold = Some(v);
             }
         }
+        if let Ok(val) = std::env::var("DRIFT_ANALYSIS_PROFILE") {
+            if let Ok(v) = val.parse::<AnalysisProfile>() {
+                config.analysis.profile = Some(v);
+            }
+        }
         if let Ok(val) = std::env::var("DRIFT_GATE_FAIL_ON") {
             config.quality_gates.fail_on = Some(val);
         }
@@ -2,6 +2,7 @@
 //! TOML-based, 4-layer resolution: CLI > env > project > user > defaults.
 
 pub mod analysis_config;
+pub mod analysis_profile;
 pub mod backup_config;
 pub mod drift_config;
 pub mod gate_config;
@@ -10,11 +11,12 @@ pub mod mcp_config;
 pub mod scan_config;
 pub mod telemetry_config;
 
-pub use analysis_config::AnalysisConfig;
+pub use analysis_config::{AnalysisConfig, DetectorOptions};
+pub use analysis_profile::AnalysisProfile;
 pub use backup_config::BackupConfig;
 pub use drift_config::DriftConfig;
 pub use gate_config::GateConfig;
 pub use license_config::LicenseConfig;
 pub use mcp_config::McpConfig;
-pub use scan_config::ScanConfig;
+pub use scan_config::{ExportRule, SampleConfig, ScanConfig, VisibilityLevel, VisibilityPolicy};
 pub use telemetry_config::TelemetryConfig;
@@ -1,5 +1,7 @@
 //! Scanner configuration.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Configuration for the file scanner subsystem.
@@ -14,9 +16,22 @@ pub struct ScanConfig {
     /// Patterns use gitignore syntax (e.g., "src/**", "lib/**").
     #[serde(default)]
     pub include: Vec<String>,
-    /// Additional ignore patterns beyond .gitignore/.driftignore.
+    /// Focus globs (e.g., "services/payments/**") — if non-empty, only matching
+    /// files are scanned and analyzed. Other files are still discovered and
+    /// listed as context, so imports leaving the focus can be resolved read-only.
+    #[serde(default)]
+    pub include_paths: Vec<String>,
+    /// Additional ignore patterns beyond .gitignore/.driftignore, in gitignore
+    /// syntax. The last matching pattern wins and `!pattern` re-includes a
+    /// path an earlier pattern excluded (e.g. `["fixtures/**",
+    /// "!fixtures/schema.ts"]`). Matching directories are not descended into.
     #[serde(default)]
     pub extra_ignore: Vec<String>,
+    /// Globs marking test files, on top of the built-in `*.test.*`, `*_test.*`,
+    /// `tests/**` and similar patterns. Test files are still scanned; this only
+    /// sets `ScanEntry::is_test_file`.
+    #[serde(default)]
+    pub test_patterns: Vec<String>,
     /// Follow symbolic links. Default: false.
     pub follow_symlinks: Option<bool>,
     /// Compute content hashes. Default: true.
@@ -33,6 +48,85 @@ pub struct ScanConfig {
     pub incremental: Option<bool>,
     /// Parallelism level for scanning.
     pub parallelism: Option<usize>,
+    /// Scan a reproducible subset of files instead of the whole tree.
+    pub sample: Option<SampleConfig>,
+    /// Deepest AST level structural extraction descends to. Files nested
+    /// deeper are marked truncated on their parse result. Default: 50.
+    pub max_ast_depth: Option<usize>,
+    /// Files processed between checkpoints of a resumable scan. Default: 5000.
+    pub checkpoint_interval: Option<usize>,
+    /// Per-language overrides of how declarations map to a visibility and
+    /// an export flag, keyed by language name (`python`, `kotlin`, `c#`,
+    /// ...; case-insensitive). Languages not listed keep the built-in
+    /// conventions.
+    #[serde(default)]
+    pub visibility: HashMap<String, VisibilityPolicy>,
+}
+
+/// Deterministic file sampling for repos too large to analyze in full.
+///
+/// A file is selected when the seeded hash of its root-relative path falls
+/// below `fraction`, so the same seed picks the same files on every run and
+/// every machine.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SampleConfig {
+    /// Share of files to keep, in `(0.0, 1.0]`.
+    pub fraction: f64,
+    /// Seed mixed into the path hash. Default: 0.
+    #[serde(default)]
+    pub seed: u64,
+}
+
+/// How one language's declarations map to a visibility and an export flag.
+/// Every field defaults to the built-in reading, so an empty policy changes
+/// nothing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VisibilityPolicy {
+    /// Visibility a modifier keyword stands for, overriding the built-in
+    /// reading of that keyword (e.g. `internal = "protected"` for Kotlin).
+    pub modifiers: HashMap<String, VisibilityLevel>,
+    /// Visibility of declarations without a visibility keyword.
+    pub default_visibility: Option<VisibilityLevel>,
+    /// How `is_exported` is decided.
+    pub exports: ExportRule,
+}
+
+/// Configured visibility of a declaration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VisibilityLevel {
+    Public,
+    Protected,
+    Private,
+}
+
+/// Which declarations count as exported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportRule {
+    /// The language's own conventions (`export`, `pub`, a public modifier,
+    /// Go capitalization, ...).
+    #[default]
+    Conventions,
+    /// Declarations whose visibility is public.
+    Visibility,
+    /// Module-level declarations named in the module's `__all__`, or every
+    /// module-level name without a leading `_` when there is no `__all__`
+    /// (Python). Nested functions and methods are not exported.
+    DunderAll,
+    /// Names starting with an uppercase letter (Go).
+    Capitalized,
+}
+
+impl SampleConfig {
+    /// Returns the fraction clamped to `[0.0, 1.0]`.
+    pub fn effective_fraction(&self) -> f64 {
+        if self.fraction.is_nan() {
+            return 1.0;
+        }
+        self.fraction.clamp(0.0, 1.0)
+    }
 }
 
 impl ScanConfig {
@@ -50,4 +144,19 @@ impl ScanConfig {
     pub fn effective_incremental(&self) -> bool {
         self.incremental.unwrap_or(true)
     }
+
+    /// Returns the effective AST recursion limit, defaulting to 50.
+    pub fn effective_max_ast_depth(&self) -> usize {
+        self.max_ast_depth.unwrap_or(50)
+    }
+
+    /// Returns the effective checkpoint interval, defaulting to 5000 files.
+    pub fn effective_checkpoint_interval(&self) -> usize {
+        self.checkpoint_interval.unwrap_or(5000).max(1)
+    }
+
+    /// Returns the sampling config if it actually drops files (fraction < 1).
+    pub fn effective_sample(&self) -> Option<SampleConfig> {
+        self.sample.filter(|s| s.effective_fraction() < 1.0)
+    }
 }
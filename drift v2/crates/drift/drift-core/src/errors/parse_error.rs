@@ -1,5 +1,6 @@
 //! Parser errors.
 
+use std::ops::RangeInclusive;
 use std::path::PathBuf;
 
 use super::error_code::{self, DriftErrorCode};
@@ -10,6 +11,13 @@ pub enum ParseError {
     #[error("Grammar not found for language: {language}")]
     GrammarNotFound { language: String },
 
+    #[error("Grammar ABI mismatch for {language}: grammar ABI {grammar_abi}, supported {supported_range:?}")]
+    GrammarAbiMismatch {
+        language: String,
+        grammar_abi: u32,
+        supported_range: RangeInclusive<u32>,
+    },
+
     #[error("Tree-sitter error parsing {path}: {message}")]
     TreeSitterError { path: PathBuf, message: String },
 
@@ -24,6 +24,9 @@ pub enum ScanError {
 
     #[error("Unsupported encoding in {path}: {encoding}")]
     UnsupportedEncoding { path: PathBuf, encoding: String },
+
+    #[error("Git error: {message}")]
+    Git { message: String },
 }
 
 impl DriftErrorCode for ScanError {
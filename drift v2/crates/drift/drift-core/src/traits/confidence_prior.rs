@@ -0,0 +1,21 @@
+//! PriorSource trait — D1 compliant.
+//!
+//! Supplies per-pattern Beta pseudo-counts to `ConfidenceScorer`. The
+//! no-op default returns no prior, so standalone scoring is unchanged. The
+//! bridge derives priors from grounding verdicts: patterns Cortex has
+//! validated start with extra alpha, invalidated ones with extra beta.
+
+/// Source of prior `(alpha, beta)` pseudo-counts per pattern.
+pub trait PriorSource: Send + Sync {
+    /// Pseudo-counts added to the pattern's posterior, or `None` when
+    /// nothing is known about it.
+    fn prior(&self, pattern_id: &str) -> Option<(f64, f64)> {
+        let _ = pattern_id;
+        None
+    }
+}
+
+/// No-op implementation for standalone mode.
+pub struct NoOpPriorSource;
+
+impl PriorSource for NoOpPriorSource {}
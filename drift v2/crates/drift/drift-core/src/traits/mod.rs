@@ -1,11 +1,13 @@
 //! Shared traits used across Drift crates.
 
 pub mod cancellation;
+pub mod confidence_prior;
 pub mod decomposition;
 pub mod storage;
 pub mod weight_provider;
 
 pub use cancellation::CancellationToken;
+pub use confidence_prior::{NoOpPriorSource, PriorSource};
 pub use decomposition::{DecompositionPriorProvider, NoOpPriorProvider};
 pub use storage::{
     IDriftAdvanced, IDriftAnalysis, IDriftBatchWriter, IDriftEnforcement, IDriftFiles,
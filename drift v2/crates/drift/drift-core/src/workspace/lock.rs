@@ -1,20 +1,62 @@
 //! Workspace locking via fd-lock for concurrent access safety.
 //! Shared read locks allow concurrent MCP queries.
 //! Exclusive write locks prevent concurrent mutations.
+//!
+//! Writers also record a lease (pid + hostname + timestamp) next to the lock,
+//! removed again when the write guard is dropped. A lease left behind names a
+//! writer that was killed. If that writer's lock is still held exclusively
+//! (through a leaked descriptor), the pid is gone from this host and the lease
+//! is older than the grace period, the lock file is replaced, so a killed
+//! process cannot leave the workspace locked. Readers take shared locks and
+//! record no lease, so a lock held only by readers is never reclaimed.
 
 use std::fs::File;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use fd_lock::RwLock;
+use serde::{Deserialize, Serialize};
 
 use super::errors::WorkspaceError;
 
+/// Default age a dead holder's lease must reach before its lock is reclaimed.
+const DEFAULT_STALE_GRACE: Duration = Duration::from_secs(30);
+
+/// The holder of the workspace write lock, stored in `.drift/workspace.lease`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockLease {
+    pub pid: u32,
+    pub hostname: String,
+    /// Acquisition time, seconds since the Unix epoch.
+    pub acquired_at: u64,
+}
+
+impl LockLease {
+    /// A lease for the current process, acquired now.
+    pub fn current() -> Self {
+        Self {
+            pid: std::process::id(),
+            hostname: hostname(),
+            acquired_at: now_secs(),
+        }
+    }
+
+    /// Whether the holder is known to be gone: same host, pid not running,
+    /// and the lease older than `grace`. Leases from other hosts are never stale.
+    pub fn is_stale(&self, grace: Duration) -> bool {
+        let age = now_secs().saturating_sub(self.acquired_at);
+        self.hostname == hostname() && age >= grace.as_secs() && !pid_alive(self.pid)
+    }
+}
+
 /// Cross-platform workspace lock using advisory file locks.
 /// Shared read locks allow concurrent MCP queries.
 /// Exclusive write locks prevent concurrent mutations.
 pub struct WorkspaceLock {
     lock_file: RwLock<File>,
     lock_path: PathBuf,
+    lease_path: PathBuf,
+    stale_grace: Duration,
 }
 
 impl WorkspaceLock {
@@ -25,13 +67,22 @@ impl WorkspaceLock {
         Ok(Self {
             lock_file: RwLock::new(file),
             lock_path,
+            lease_path: drift_path.join("workspace.lease"),
+            stale_grace: DEFAULT_STALE_GRACE,
         })
     }
 
+    /// Set how old a dead holder's lease must be before reclaiming. Default: 30s.
+    pub fn with_stale_grace(mut self, grace: Duration) -> Self {
+        self.stale_grace = grace;
+        self
+    }
+
     /// Acquire shared read lock (non-blocking).
     /// Used by: MCP tool queries, CLI read commands, backup creation.
     /// Multiple readers can hold this simultaneously.
     pub fn read(&mut self) -> Result<fd_lock::RwLockReadGuard<'_, File>, WorkspaceError> {
+        self.reclaim_stale()?;
         self.lock_file.try_read().map_err(|_| WorkspaceError::Locked {
             operation: "read".to_string(),
             message: "A write operation is in progress. Try again shortly.".to_string(),
@@ -40,16 +91,113 @@ impl WorkspaceLock {
 
     /// Acquire exclusive write lock (non-blocking).
     /// Used by: drift scan, drift migrate, drift reset.
-    /// Fails immediately if any other lock is held.
-    pub fn write(&mut self) -> Result<fd_lock::RwLockWriteGuard<'_, File>, WorkspaceError> {
-        self.lock_file.try_write().map_err(|_| WorkspaceError::Locked {
+    /// Fails immediately if any other lock is held by a live process.
+    pub fn write(&mut self) -> Result<WorkspaceWriteGuard<'_>, WorkspaceError> {
+        self.reclaim_stale()?;
+        let guard = self.lock_file.try_write().map_err(|_| WorkspaceError::Locked {
             operation: "write".to_string(),
             message: "Another operation is in progress. Wait for it to complete.".to_string(),
-        })
+        })?;
+        let guard = WorkspaceWriteGuard {
+            _guard: guard,
+            lease_path: self.lease_path.clone(),
+        };
+        let lease = serde_json::to_string(&LockLease::current()).unwrap_or_default();
+        std::fs::write(&self.lease_path, lease)?;
+        Ok(guard)
+    }
+
+    /// The current write lease, if one has been recorded.
+    pub fn lease(&self) -> Option<LockLease> {
+        let text = std::fs::read_to_string(&self.lease_path).ok()?;
+        serde_json::from_str(&text).ok()
     }
 
     /// Get the lock file path.
     pub fn path(&self) -> &Path {
         &self.lock_path
     }
+
+    /// Replace the lock file if a stale lease holder is what blocks it. The
+    /// old file may still be locked by a leaked descriptor, so a fresh file
+    /// is created.
+    fn reclaim_stale(&mut self) -> Result<(), WorkspaceError> {
+        let Some(lease) = self.lease() else {
+            return Ok(());
+        };
+        if !lease.is_stale(self.stale_grace) {
+            return Ok(());
+        }
+        // Only a writer holds the lock exclusively; if a shared lock is
+        // available, the lease's writer is not holding it and any blockers
+        // are live readers.
+        if self.lock_file.try_read().is_ok() {
+            return Ok(());
+        }
+        // A new writer may have taken the lock since the lease was read.
+        if self.lease().as_ref() != Some(&lease) {
+            return Ok(());
+        }
+        tracing::warn!(
+            pid = lease.pid,
+            hostname = %lease.hostname,
+            acquired_at = lease.acquired_at,
+            "reclaiming workspace lock from a process that no longer exists"
+        );
+        std::fs::remove_file(&self.lock_path)?;
+        let _ = std::fs::remove_file(&self.lease_path);
+        self.lock_file = RwLock::new(File::create(&self.lock_path)?);
+        Ok(())
+    }
+}
+
+/// Exclusive workspace lock. Removes the lease when dropped, before the lock
+/// itself is released.
+pub struct WorkspaceWriteGuard<'a> {
+    _guard: fd_lock::RwLockWriteGuard<'a, File>,
+    lease_path: PathBuf,
+}
+
+impl Drop for WorkspaceWriteGuard<'_> {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.lease_path);
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn hostname() -> String {
+    std::fs::read_to_string("/etc/hostname")
+        .ok()
+        .or_else(|| std::env::var("COMPUTERNAME").ok())
+        .or_else(|| std::env::var("HOSTNAME").ok())
+        .map(|h| h.trim().to_string())
+        .filter(|h| !h.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Whether a process with `pid` is running. Unknown means alive.
+#[cfg(unix)]
+fn pid_alive(pid: u32) -> bool {
+    if Path::new("/proc/self").exists() {
+        return Path::new(&format!("/proc/{pid}")).exists();
+    }
+    match std::process::Command::new("kill").args(["-0", &pid.to_string()]).output() {
+        Ok(out) => out.status.success() || String::from_utf8_lossy(&out.stderr).contains("not permitted"),
+        Err(_) => true,
+    }
+}
+
+/// Whether a process with `pid` is running. Unknown means alive.
+#[cfg(not(unix))]
+fn pid_alive(pid: u32) -> bool {
+    let filter = format!("PID eq {pid}");
+    match std::process::Command::new("tasklist").args(["/FI", &filter, "/NH"]).output() {
+        Ok(out) => String::from_utf8_lossy(&out.stdout).contains(&pid.to_string()),
+        Err(_) => true,
+    }
 }
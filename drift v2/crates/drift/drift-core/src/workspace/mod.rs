@@ -46,7 +46,7 @@ pub use errors::{WorkspaceError, WorkspaceResult};
 pub use gc::{garbage_collect, GCOptions, GCReport};
 pub use init::{is_initialized, open_workspace, workspace_init, InitOptions, WorkspaceInfo};
 pub use integrity::{auto_recover, verify_workspace, IntegrityReport};
-pub use lock::WorkspaceLock;
+pub use lock::{LockLease, WorkspaceLock, WorkspaceWriteGuard};
 pub use migration::{get_schema_version, initialize_workspace_db};
 pub use monorepo::{detect_workspace, WorkspaceLayout};
 pub use project::{
@@ -3,6 +3,7 @@
 use std::sync::Mutex;
 
 use drift_core::config::drift_config::{CliOverrides, DriftConfig};
+use drift_core::config::{AnalysisProfile, DetectorOptions, ExportRule, VisibilityLevel};
 use drift_core::errors::ConfigError;
 
 /// Global mutex to serialize tests that modify environment variables.
@@ -20,6 +21,7 @@ fn clear_drift_env_vars() {
         "DRIFT_SCAN_THREADS",
         "DRIFT_ANALYSIS_MIN_OCCURRENCES",
         "DRIFT_ANALYSIS_DOMINANCE_THRESHOLD",
+        "DRIFT_ANALYSIS_PROFILE",
         "DRIFT_GATE_FAIL_ON",
         "DRIFT_GATE_MIN_SCORE",
         "DRIFT_MCP_MAX_RESPONSE_TOKENS",
@@ -288,3 +290,114 @@ fn test_read_only_user_config() {
     let config = DriftConfig::load(dir.path(), None).unwrap();
     assert_eq!(config.scan.effective_max_file_size(), 1_048_576);
 }
+
+/// T0-CFG-11: Analysis profiles preset taint depth and sampling
+#[test]
+fn test_analysis_profiles() {
+    let _lock = ENV_MUTEX.lock().unwrap();
+    clear_drift_env_vars();
+
+    let mut fast = DriftConfig::default();
+    AnalysisProfile::Fast.apply(&mut fast);
+    assert!(!fast.analysis.effective_interprocedural_taint());
+    assert!(fast.scan.effective_sample().is_some(), "fast samples files");
+
+    let mut deep = DriftConfig::default();
+    AnalysisProfile::Deep.apply(&mut deep);
+    assert!(deep.analysis.effective_interprocedural_taint());
+    assert!(deep.scan.effective_sample().is_none());
+
+    // Selected in drift.toml; explicit knobs win over the preset.
+    let dir = tempdir();
+    std::fs::write(
+        dir.path().join("drift.toml"),
+        "[analysis]\nprofile = \"fast\"\n\n[scan.sample]\nfraction = 0.5\n",
+    )
+    .unwrap();
+    let config = DriftConfig::load(dir.path(), None).unwrap();
+    assert_eq!(config.analysis.effective_profile(), AnalysisProfile::Fast);
+    assert!(!config.analysis.effective_interprocedural_taint());
+    assert_eq!(config.scan.effective_sample().map(|s| s.fraction), Some(0.5));
+
+    // The environment selects a profile too.
+    std::env::set_var("DRIFT_ANALYSIS_PROFILE", "deep");
+    let config = DriftConfig::load(tempdir().path(), None).unwrap();
+    clear_drift_env_vars();
+    assert_eq!(config.analysis.effective_profile(), AnalysisProfile::Deep);
+    assert!(config.analysis.effective_interprocedural_taint());
+    assert!("turbo".parse::<AnalysisProfile>().is_err());
+}
+
+/// T0-CFG-12: Per-language visibility policies load from drift.toml
+#[test]
+fn test_visibility_policies() {
+    let _lock = ENV_MUTEX.lock().unwrap();
+    clear_drift_env_vars();
+
+    let dir = tempdir();
+    std::fs::write(
+        dir.path().join("drift.toml"),
+        r#"
+[scan.visibility.python]
+exports = "dunder_all"
+
+[scan.visibility.kotlin]
+exports = "visibility"
+default_visibility = "public"
+modifiers = { internal = "protected" }
+"#,
+    )
+    .unwrap();
+    let config = DriftConfig::load(dir.path(), None).unwrap();
+
+    let python = &config.scan.visibility["python"];
+    assert_eq!(python.exports, ExportRule::DunderAll);
+    assert!(python.modifiers.is_empty());
+    assert_eq!(python.default_visibility, None);
+
+    let kotlin = &config.scan.visibility["kotlin"];
+    assert_eq!(kotlin.exports, ExportRule::Visibility);
+    assert_eq!(kotlin.default_visibility, Some(VisibilityLevel::Public));
+    assert_eq!(kotlin.modifiers.get("internal"), Some(&VisibilityLevel::Protected));
+
+    // Unlisted languages keep the built-in conventions
+    assert!(!config.scan.visibility.contains_key("go"));
+    assert!(DriftConfig::default().scan.visibility.is_empty());
+}
+
+/// T0-CFG-13: Detector options load from drift.toml
+#[test]
+fn test_detector_options() {
+    let _lock = ENV_MUTEX.lock().unwrap();
+    clear_drift_env_vars();
+
+    let dir = tempdir();
+    std::fs::write(
+        dir.path().join("drift.toml"),
+        r#"
+[analysis.detectors]
+logger_methods = ["info", "severe"]
+entropy_threshold = 4.5
+max_parameters = 8
+exclude_constructors = true
+fetch_all_sinks = ["scan"]
+outbound_calls = ["ky.get"]
+body_parsers = ["fastifyMultipart"]
+cookie_setters = ["h.state"]
+cleartext_allowed_hosts = ["mesh.corp"]
+"#,
+    )
+    .unwrap();
+    let config = DriftConfig::load(dir.path(), None).unwrap();
+    assert_eq!(config.analysis.detectors.logger_methods, vec!["info", "severe"]);
+    assert_eq!(config.analysis.detectors.entropy_threshold, Some(4.5));
+    assert_eq!(config.analysis.detectors.max_parameters, Some(8));
+    assert_eq!(config.analysis.detectors.exclude_constructors, Some(true));
+    assert_eq!(config.analysis.detectors.fetch_all_sinks, vec!["scan"]);
+    assert_eq!(config.analysis.detectors.outbound_calls, vec!["ky.get"]);
+    assert_eq!(config.analysis.detectors.body_parsers, vec!["fastifyMultipart"]);
+    assert_eq!(config.analysis.detectors.cookie_setters, vec!["h.state"]);
+    assert_eq!(config.analysis.detectors.cleartext_allowed_hosts, vec!["mesh.corp"]);
+
+    assert_eq!(DriftConfig::default().analysis.detectors, DetectorOptions::default());
+}
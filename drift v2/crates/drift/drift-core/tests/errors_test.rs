@@ -122,6 +122,11 @@ fn test_display_human_readable() {
         Box::new(ParseError::GrammarNotFound {
             language: "brainfuck".into(),
         }),
+        Box::new(ParseError::GrammarAbiMismatch {
+            language: "Python".into(),
+            grammar_abi: 12,
+            supported_range: 13..=15,
+        }),
         Box::new(StorageError::DbBusy),
         Box::new(StorageError::DiskFull),
         Box::new(StorageError::MigrationFailed {
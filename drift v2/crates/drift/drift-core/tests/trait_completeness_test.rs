@@ -119,7 +119,7 @@ fn ct0_g18_trait_surface_area_meets_minimum() {
     assert!(idrift_advanced_methods.len() >= 9, "IDriftAdvanced should have ≥9 methods, got {}", idrift_advanced_methods.len());
 
     // IDriftBatchWriter: 5 methods
-    let idrift_batch_methods = vec![
+    let idrift_batch_methods = [
         "send_raw", "flush", "flush_sync", "stats", "shutdown",
     ];
     assert!(idrift_batch_methods.len() >= 5, "IDriftBatchWriter should have ≥5 methods, got {}", idrift_batch_methods.len());
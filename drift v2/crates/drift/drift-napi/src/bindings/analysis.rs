@@ -158,7 +158,9 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
     }
 
     // Step 2: Parse each file and run detection
-    let parser_manager = drift_analysis::parsers::ParserManager::new();
+    let parser_manager = drift_analysis::parsers::ParserManager::new()
+        .with_max_depth(rt.config.scan.effective_max_ast_depth())
+        .with_visibility_policies(&rt.config.scan.visibility);
     let detection_engine = drift_analysis::engine::DetectionEngine::new(
         drift_analysis::engine::VisitorRegistry::new(),
     );
@@ -238,6 +240,14 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
             Ok(pair) => pair,
             Err(_) => continue,
         };
+        if let Some(truncation) = parse_result.truncated_at_depth {
+            drift_log!(
+                "[drift-analyze] warning: {} nests deeper than {} AST levels (line {}); extraction is incomplete",
+                parse_result.file,
+                truncation.max_depth,
+                truncation.position.line + 1,
+            );
+        }
 
         // Run the 4-phase analysis pipeline
         let mut resolution_index = drift_analysis::engine::ResolutionIndex::new();
@@ -549,11 +559,17 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
     }
 
     let phase_timer = std::time::Instant::now();
-    // Step 4: Run pattern intelligence pipeline (with feedback store for closed-loop)
+    // Step 4: Run pattern intelligence pipeline (with feedback store for closed-loop
+    // and grounding verdicts from the bridge as priors)
     if !all_matches.is_empty() {
         let feedback_store = crate::feedback_store::DbFeedbackStore::new(rt.clone());
+        let prior_source = cortex_drift_bridge::grounding::BridgePriorSource::new(
+            rt.bridge_storage()
+                .map(|s| std::sync::Arc::clone(s) as std::sync::Arc<dyn cortex_drift_bridge::traits::IBridgeStorage>),
+        );
         let mut pattern_pipeline = drift_analysis::patterns::pipeline::PatternIntelligencePipeline::new()
-            .with_feedback_store(Box::new(feedback_store));
+            .with_feedback_store(Box::new(feedback_store))
+            .with_prior_source(Box::new(prior_source));
 
         let total_files = files.len() as u64;
         let now = std::time::SystemTime::now()
@@ -864,10 +880,23 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
 
         // 5e: Secrets detection → secrets table (pre-compiled + parallel)
         let secret_detector = drift_analysis::structural::constants::secrets::CompiledSecretDetector::new();
-        let secret_rows: Vec<drift_storage::batch::commands::SecretInsertRow> = prod_parse_results.par_iter()
+        // Config/text files (.env, YAML, Dockerfile, ...) are not parsed, so read them here.
+        let config_files: Vec<(String, String)> = files.iter()
+            .filter(|f| drift_analysis::structural::constants::secrets::is_config_text_file(&f.path))
+            .filter(|f| !is_test_or_fixture_file(&f.path) && !file_contents.contains_key(&f.path))
+            .filter_map(|f| {
+                let path = project_root.map_or_else(|| std::path::PathBuf::from(&f.path), |r| r.join(&f.path));
+                std::fs::read_to_string(path).ok().map(|c| (f.path.clone(), c))
+            })
+            .collect();
+        let source_secrets = prod_parse_results.par_iter()
             .filter_map(|pr| file_contents.get(&pr.file).map(|c| (pr, c)))
-            .flat_map(|(_pr, content)| {
-                let secrets = secret_detector.detect(content, &_pr.file);
+            .map(|(pr, content)| secret_detector.detect(content, &pr.file));
+        let config_secrets = config_files.par_iter()
+            .map(|(path, content)| secret_detector.scan_text_file(path, content));
+        let secret_rows: Vec<drift_storage::batch::commands::SecretInsertRow> = source_secrets
+            .chain(config_secrets)
+            .flat_map(|secrets| {
                 secrets.into_iter().map(|s| {
                     drift_storage::batch::commands::SecretInsertRow {
                         pattern_name: s.pattern_name,
@@ -927,6 +956,14 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
         if !constraint_rows.is_empty() {
             // Populate the invariant detector from parse results
             let mut inv_detector = drift_analysis::structural::constraints::detector::InvariantDetector::new();
+            // Monorepo packages back `pkg:` constraint targets
+            if let Some(root) = project_root {
+                if let Ok(drift_core::workspace::monorepo::WorkspaceLayout::Monorepo { packages, .. }) =
+                    drift_core::workspace::monorepo::detect_workspace(root)
+                {
+                    inv_detector.set_packages(&packages);
+                }
+            }
             for pr in &prod_parse_results {
                 let funcs: Vec<drift_analysis::structural::constraints::detector::FunctionInfo> = pr.functions.iter().map(|f| {
                     drift_analysis::structural::constraints::detector::FunctionInfo {
@@ -1284,11 +1321,13 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                 let intra_flows = drift_analysis::graph::taint::analyze_intraprocedural(pr, &taint_registry);
                 all_taint_flows.extend(intra_flows);
             }
-            // Phase 2: interprocedural (cross-function via call graph)
-            if let Ok(inter_flows) = drift_analysis::graph::taint::analyze_interprocedural(
-                call_graph, &prod_pr_owned, &taint_registry, None,
-            ) {
-                all_taint_flows.extend(inter_flows);
+            // Phase 2: interprocedural (cross-function via call graph), off in the fast profile
+            if rt.config.analysis.effective_interprocedural_taint() {
+                if let Ok(inter_flows) = drift_analysis::graph::taint::analyze_interprocedural(
+                    call_graph, &prod_pr_owned, &taint_registry, None,
+                ) {
+                    all_taint_flows.extend(inter_flows);
+                }
             }
 
             let taint_rows: Vec<drift_storage::batch::commands::TaintFlowInsertRow> = all_taint_flows
@@ -1558,6 +1597,19 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
             }
 
             if !violation_rows.is_empty() {
+                // Tag the findings with the scan they were observed in, for history queries.
+                let observed: Vec<(String, String)> = violation_rows
+                    .iter()
+                    .map(|v| (v.id.clone(), v.file.clone()))
+                    .collect();
+                rt.storage.with_writer(|conn| {
+                    match drift_storage::queries::scan_history::latest_scan_id(conn)? {
+                        Some(scan_id) => drift_storage::queries::scan_history::record_scan_violations(
+                            conn, scan_id, &observed,
+                        ),
+                        None => Ok(()),
+                    }
+                }).map_err(storage_err)?;
                 rt.storage.send_batch(
                     drift_storage::batch::commands::BatchCommand::InsertViolations(violation_rows),
                 ).map_err(storage_err)?;
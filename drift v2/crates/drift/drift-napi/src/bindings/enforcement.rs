@@ -360,7 +360,7 @@ pub fn drift_violations(_root: String) -> napi::Result<Vec<JsViolation>> {
 
 /// Generate a report in the specified format from stored violations and gate results.
 ///
-/// Supported formats: "sarif", "json", "html", "junit", "sonarqube", "console", "github", "gitlab"
+/// Supported formats: "sarif", "json", "html", "junit", "sonarqube", "console", "github", "gitlab", "vulnreport", "ndjson"
 #[napi]
 pub fn drift_report(format: String) -> napi::Result<String> {
     let rt = runtime::get()?;
@@ -379,7 +379,7 @@ pub fn drift_report(format: String) -> napi::Result<String> {
     // Create reporter and generate output
     let reporter = drift_analysis::enforcement::reporters::create_reporter(&format)
         .ok_or_else(|| napi::Error::from_reason(format!(
-            "[{}] Unknown report format: '{}'. Supported: sarif, json, html, junit, sonarqube, console, github, gitlab",
+            "[{}] Unknown report format: '{}'. Supported: sarif, json, html, junit, sonarqube, console, github, gitlab, vulnreport, ndjson",
             error_codes::INVALID_ARGUMENT, format
         )))?;
 
@@ -433,8 +433,10 @@ fn storage_to_gate_results(
                     strategy,
                     description: v.quick_fix_description.clone().unwrap_or_default(),
                     replacement: None,
+                    range: None,
                 })
             }),
+            fingerprint: None,
         }
     }).collect();
 
@@ -455,6 +457,8 @@ fn storage_to_gate_results(
             execution_time_ms: 0,
             details: serde_json::Value::Null,
             error: None,
+            explanation: None,
+            advisories: Vec::new(),
         }];
     }
 
@@ -494,6 +498,8 @@ fn storage_to_gate_results(
             execution_time_ms: g.execution_time_ms,
             details,
             error: g.error.clone(),
+            explanation: None,
+            advisories: Vec::new(),
         }
     }).collect()
 }
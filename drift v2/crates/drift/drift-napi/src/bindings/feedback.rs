@@ -2,6 +2,7 @@
 
 #[allow(unused_imports)]
 use napi::bindgen_prelude::*;
+use drift_analysis::enforcement::feedback::{DismissalReason, FeedbackAction, FeedbackRecord};
 use napi_derive::napi;
 use serde::{Deserialize, Serialize};
 
@@ -32,25 +33,8 @@ pub struct JsFeedbackResult {
 pub fn drift_dismiss_violation(input: JsFeedbackInput) -> napi::Result<JsFeedbackResult> {
     let rt = runtime::get()?;
 
-    let pattern_id = resolve_pattern_id(&rt, &input.violation_id);
-
-    let detector_id = resolve_detector_id(&rt, &input.violation_id);
-    let created_at = unix_timestamp_now();
-
-    let feedback = drift_storage::queries::enforcement::FeedbackRow {
-        violation_id: input.violation_id.clone(),
-        pattern_id,
-        detector_id,
-        action: "dismiss".to_string(),
-        dismissal_reason: input.reason.clone(),
-        reason: input.reason,
-        author: None,
-        created_at,
-    };
-
-    rt.storage.with_writer(|conn| {
-        drift_storage::queries::enforcement::insert_feedback(conn, &feedback)
-    }).map_err(storage_err)?;
+    let dismissal_reason = input.reason.as_deref().and_then(DismissalReason::parse);
+    record_feedback(&rt, &input.violation_id, FeedbackAction::Dismiss, dismissal_reason, input.reason)?;
 
     Ok(JsFeedbackResult {
         success: true,
@@ -63,25 +47,7 @@ pub fn drift_dismiss_violation(input: JsFeedbackInput) -> napi::Result<JsFeedbac
 pub fn drift_fix_violation(violation_id: String) -> napi::Result<JsFeedbackResult> {
     let rt = runtime::get()?;
 
-    let pattern_id = resolve_pattern_id(&rt, &violation_id);
-
-    let detector_id = resolve_detector_id(&rt, &violation_id);
-    let created_at = unix_timestamp_now();
-
-    let feedback = drift_storage::queries::enforcement::FeedbackRow {
-        violation_id: violation_id.clone(),
-        pattern_id,
-        detector_id,
-        action: "fix".to_string(),
-        dismissal_reason: None,
-        reason: None,
-        author: None,
-        created_at,
-    };
-
-    rt.storage.with_writer(|conn| {
-        drift_storage::queries::enforcement::insert_feedback(conn, &feedback)
-    }).map_err(storage_err)?;
+    record_feedback(&rt, &violation_id, FeedbackAction::Fix, None, None)?;
 
     Ok(JsFeedbackResult {
         success: true,
@@ -97,25 +63,8 @@ pub fn drift_suppress_violation(
 ) -> napi::Result<JsFeedbackResult> {
     let rt = runtime::get()?;
 
-    let pattern_id = resolve_pattern_id(&rt, &violation_id);
-
-    let detector_id = resolve_detector_id(&rt, &violation_id);
-    let created_at = unix_timestamp_now();
-
-    let feedback = drift_storage::queries::enforcement::FeedbackRow {
-        violation_id: violation_id.clone(),
-        pattern_id,
-        detector_id,
-        action: "suppress".to_string(),
-        dismissal_reason: Some(reason.clone()),
-        reason: Some(reason.clone()),
-        author: None,
-        created_at,
-    };
-
-    rt.storage.with_writer(|conn| {
-        drift_storage::queries::enforcement::insert_feedback(conn, &feedback)
-    }).map_err(storage_err)?;
+    let dismissal_reason = DismissalReason::parse(&reason);
+    record_feedback(&rt, &violation_id, FeedbackAction::Suppress, dismissal_reason, Some(reason.clone()))?;
 
     Ok(JsFeedbackResult {
         success: true,
@@ -123,6 +72,36 @@ pub fn drift_suppress_violation(
     })
 }
 
+/// Record an action through the runtime's `FeedbackTracker`, which writes it
+/// to the `feedback` table, then rebuild the `detector_health` view.
+fn record_feedback(
+    rt: &crate::runtime::DriftRuntime,
+    violation_id: &str,
+    action: FeedbackAction,
+    dismissal_reason: Option<DismissalReason>,
+    reason: Option<String>,
+) -> napi::Result<()> {
+    let record = FeedbackRecord {
+        violation_id: violation_id.to_string(),
+        pattern_id: resolve_pattern_id(rt, violation_id),
+        detector_id: resolve_detector_id(rt, violation_id),
+        action,
+        dismissal_reason,
+        reason,
+        author: None,
+        timestamp: unix_timestamp_now(),
+    };
+    rt.feedback
+        .lock()
+        .map_err(|e| napi::Error::from_reason(format!("[{}] {e}", error_codes::LOCK_POISONED)))?
+        .record(&record);
+
+    rt.storage
+        .with_writer(drift_storage::materialized::detector_health::refresh_detector_health)
+        .map_err(storage_err)?;
+    Ok(())
+}
+
 /// Resolve pattern_id from the violations table for a given violation_id.
 /// Returns empty string if the violation is not found (graceful degradation).
 fn resolve_pattern_id(rt: &crate::runtime::DriftRuntime, violation_id: &str) -> String {
@@ -16,9 +16,10 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use drift_analysis::scanner::Scanner;
+use drift_analysis::scanner::checkpoint::{CheckpointSink, CheckpointedFile};
 use drift_analysis::scanner::language_detect::Language;
-use drift_analysis::scanner::types::{CachedFileMetadata, ScanDiff};
-use drift_core::config::ScanConfig;
+use drift_analysis::scanner::types::{CachedFileMetadata, ScanDiff, ScanEntry};
+use drift_core::config::{SampleConfig, ScanConfig};
 use drift_core::events::handler::DriftEventHandler;
 use drift_core::events::types::ScanProgressEvent;
 use drift_core::types::collections::FxHashMap;
@@ -61,12 +62,15 @@ impl Task for ScanTask {
         }
 
         let cached = load_cached_metadata(&rt)?;
+        let root = self.root.to_string_lossy().to_string();
+        let checkpoint = load_checkpoint(&rt, &root)?;
+        let sink = StorageCheckpointSink { rt: &rt, root: &root };
 
         let diff = scanner
-            .scan(&self.root, &cached, &NoOpHandler)
+            .scan_resumable(&self.root, &cached, &checkpoint, &sink, &NoOpHandler)
             .map_err(error_codes::scan_error)?;
 
-        persist_scan_diff(&rt, &diff, &self.root.to_string_lossy())?;
+        persist_scan_diff(&rt, &diff, &root)?;
 
         Ok(ScanSummary::from(&diff))
     }
@@ -115,12 +119,15 @@ impl Task for ScanWithProgressTask {
         let progress_handler = NapiProgressHandler::new(self.on_progress.clone());
 
         let cached = load_cached_metadata(&rt)?;
+        let root = self.root.to_string_lossy().to_string();
+        let checkpoint = load_checkpoint(&rt, &root)?;
+        let sink = StorageCheckpointSink { rt: &rt, root: &root };
 
         let diff = scanner
-            .scan(&self.root, &cached, &progress_handler)
+            .scan_resumable(&self.root, &cached, &checkpoint, &sink, &progress_handler)
             .map_err(error_codes::scan_error)?;
 
-        persist_scan_diff(&rt, &diff, &self.root.to_string_lossy())?;
+        persist_scan_diff(&rt, &diff, &root)?;
 
         Ok(ScanSummary::from(&diff))
     }
@@ -246,6 +253,65 @@ fn load_cached_metadata(
     Ok(cached)
 }
 
+/// Load the checkpoint left by an interrupted scan of `root`.
+fn load_checkpoint(
+    rt: &crate::runtime::DriftRuntime,
+    root: &str,
+) -> napi::Result<FxHashMap<PathBuf, CheckpointedFile>> {
+    let records = rt.storage.with_reader(|conn| {
+        drift_storage::queries::scan_checkpoint::load_checkpoint(conn, root)
+    }).map_err(|e| {
+        napi::Error::from_reason(format!(
+            "[{}] Failed to load scan checkpoint: {e}",
+            error_codes::STORAGE_ERROR
+        ))
+    })?;
+
+    let mut checkpoint = FxHashMap::default();
+    for record in records {
+        let Ok(hash) = <[u8; 8]>::try_from(record.content_hash.as_slice()) else {
+            continue;
+        };
+        checkpoint.insert(
+            PathBuf::from(&record.path),
+            CheckpointedFile {
+                content_hash: u64::from_le_bytes(hash),
+                mtime_secs: record.mtime_secs,
+                mtime_nanos: record.mtime_nanos as u32,
+            },
+        );
+    }
+    Ok(checkpoint)
+}
+
+/// Writes scan checkpoints straight to drift.db, bypassing the batch writer
+/// so progress survives a crash.
+struct StorageCheckpointSink<'a> {
+    rt: &'a crate::runtime::DriftRuntime,
+    root: &'a str,
+}
+
+impl CheckpointSink for StorageCheckpointSink<'_> {
+    fn checkpoint(&self, entries: &[ScanEntry]) {
+        let records: Vec<_> = entries
+            .iter()
+            .map(|entry| drift_storage::queries::scan_checkpoint::CheckpointRecord {
+                path: entry.path.to_string_lossy().to_string(),
+                content_hash: entry.content_hash.to_le_bytes().to_vec(),
+                mtime_secs: entry.mtime_secs,
+                mtime_nanos: entry.mtime_nanos as i64,
+            })
+            .collect();
+        let saved = self.rt.storage.with_writer(|conn| {
+            drift_storage::queries::scan_checkpoint::save_checkpoint(conn, self.root, &records)
+        });
+        // Non-fatal — a missed checkpoint only means more work on resume.
+        if let Err(e) = saved {
+            tracing::warn!(root = self.root, error = %e, "failed to save scan checkpoint");
+        }
+    }
+}
+
 /// Convert a language display name back to a Language enum.
 fn language_from_name(name: &str) -> Option<Language> {
     match name {
@@ -269,14 +335,16 @@ fn language_from_name(name: &str) -> Option<Language> {
 
 // ---- Storage persistence ----
 
-/// Persist scan results to drift.db via the batch writer.
-/// Converts ScanEntry records to file_metadata rows and handles deletions.
-fn persist_scan_diff(
-    rt: &crate::runtime::DriftRuntime,
-    diff: &ScanDiff,
-    root_path: &str,
-) -> napi::Result<()> {
-    // Convert entries to batch rows
+/// Build the batch commands that persist a scan diff's file rows.
+///
+/// Upserts file_metadata for every entry, records each file's scan root
+/// (the entry's own root for multi-root scans, `root_path` otherwise), and
+/// deletes removed files.
+pub fn scan_diff_batches(diff: &ScanDiff, root_path: &str) -> Vec<BatchCommand> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
     let rows: Vec<BatchFileMetadataRow> = diff
         .entries
         .values()
@@ -287,40 +355,52 @@ fn persist_scan_diff(
             content_hash: entry.content_hash.to_le_bytes().to_vec(),
             mtime_secs: entry.mtime_secs,
             mtime_nanos: entry.mtime_nanos as i64,
-            last_scanned_at: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_secs() as i64,
+            last_scanned_at: now,
             scan_duration_us: Some(entry.scan_duration_us as i64),
         })
         .collect();
+    let roots: Vec<(String, String)> = diff
+        .entries
+        .values()
+        .map(|entry| {
+            let root = entry
+                .root
+                .as_ref()
+                .map_or_else(|| root_path.to_string(), |r| r.to_string_lossy().to_string());
+            (entry.path.to_string_lossy().to_string(), root)
+        })
+        .collect();
 
+    let mut batches = Vec::new();
     if !rows.is_empty() {
-        rt.storage
-            .send_batch(BatchCommand::UpsertFileMetadata(rows))
-            .map_err(|e| {
-                napi::Error::from_reason(format!(
-                    "[{}] Failed to persist file metadata: {e}",
-                    error_codes::STORAGE_ERROR
-                ))
-            })?;
+        batches.push(BatchCommand::UpsertFileMetadata(rows));
+        batches.push(BatchCommand::UpsertFileRoots(roots));
     }
-
-    // Delete removed files
     if !diff.removed.is_empty() {
         let paths: Vec<String> = diff
             .removed
             .iter()
             .map(|p| p.to_string_lossy().to_string())
             .collect();
-        rt.storage
-            .send_batch(BatchCommand::DeleteFileMetadata(paths))
-            .map_err(|e| {
-                napi::Error::from_reason(format!(
-                    "[{}] Failed to delete removed files: {e}",
-                    error_codes::STORAGE_ERROR
-                ))
-            })?;
+        batches.push(BatchCommand::DeleteFileMetadata(paths));
+    }
+    batches
+}
+
+/// Persist scan results to drift.db via the batch writer.
+/// Converts ScanEntry records to file_metadata rows and handles deletions.
+fn persist_scan_diff(
+    rt: &crate::runtime::DriftRuntime,
+    diff: &ScanDiff,
+    root_path: &str,
+) -> napi::Result<()> {
+    for batch in scan_diff_batches(diff, root_path) {
+        rt.storage.send_batch(batch).map_err(|e| {
+            napi::Error::from_reason(format!(
+                "[{}] Failed to persist scan results: {e}",
+                error_codes::STORAGE_ERROR
+            ))
+        })?;
     }
 
     // PH7-01: Record completed scan in scan_history.
@@ -341,13 +421,24 @@ fn persist_scan_diff(
         let duration_ms = diff.stats.discovery_ms as i64
             + diff.stats.hashing_ms as i64
             + diff.stats.diff_ms as i64;
+        let changes: Vec<(String, &str)> = [
+            (&diff.added, "added"),
+            (&diff.modified, "modified"),
+            (&diff.removed, "removed"),
+        ]
+        .into_iter()
+        .flat_map(|(paths, change)| {
+            paths.iter().map(move |p| (p.to_string_lossy().to_string(), change))
+        })
+        .collect();
 
         rt.storage.with_writer(|conn| {
             let scan_id = drift_storage::queries::scan_history::insert_scan_start(conn, now, &root)?;
             drift_storage::queries::scan_history::update_scan_complete(
                 conn, scan_id, now, total, added, modified, removed, unchanged,
                 duration_ms, "completed", None,
-            )
+            )?;
+            drift_storage::queries::scan_history::record_file_changes(conn, scan_id, &changes)
         }).map_err(|e| {
             napi::Error::from_reason(format!(
                 "[{}] Failed to record scan history: {e}",
@@ -366,6 +457,16 @@ fn persist_scan_diff(
         ))
     })?;
 
+    // file_metadata now holds the whole scan; the checkpoint is obsolete.
+    rt.storage.with_writer(|conn| {
+        drift_storage::queries::scan_checkpoint::clear_checkpoint(conn, root_path)
+    }).map_err(|e| {
+        napi::Error::from_reason(format!(
+            "[{}] Failed to clear scan checkpoint: {e}",
+            error_codes::STORAGE_ERROR
+        ))
+    })?;
+
     Ok(())
 }
 
@@ -384,12 +485,21 @@ fn build_scan_config(base: &ScanConfig, opts: &ScanOptions) -> ScanConfig {
     if let Some(ref include) = opts.include {
         config.include.extend(include.iter().cloned());
     }
+    if let Some(ref focus) = opts.include_paths {
+        config.include_paths.extend(focus.iter().cloned());
+    }
     if let Some(ref extra) = opts.extra_ignore {
         config.extra_ignore.extend(extra.iter().cloned());
     }
     if let Some(follow) = opts.follow_symlinks {
         config.follow_symlinks = Some(follow);
     }
+    if let Some(fraction) = opts.sample_fraction {
+        config.sample = Some(SampleConfig {
+            fraction,
+            seed: opts.sample_seed.unwrap_or(0) as u64,
+        });
+    }
 
     config
 }
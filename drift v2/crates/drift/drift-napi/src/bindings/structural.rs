@@ -783,8 +783,8 @@ pub fn drift_owasp_analysis(_root: String) -> napi::Result<JsOwaspResult> {
             line: *sink_line,
             description: format!(
                 "Unsanitized data flow from {}:{} to {}:{} (sink: {})",
-                src_file.split('/').last().unwrap_or(src_file), src_line,
-                sink_file.split('/').last().unwrap_or(sink_file), sink_line,
+                src_file.split('/').next_back().unwrap_or(src_file), src_line,
+                sink_file.split('/').next_back().unwrap_or(sink_file), sink_line,
                 sink_type,
             ),
             severity,
@@ -26,6 +26,12 @@ pub struct ScanOptions {
     pub extra_ignore: Option<Vec<String>>,
     /// Follow symbolic links.
     pub follow_symlinks: Option<bool>,
+    /// Scan only this share of files, chosen deterministically from `sample_seed`.
+    pub sample_fraction: Option<f64>,
+    /// Seed for `sample_fraction`. Default: 0.
+    pub sample_seed: Option<i64>,
+    /// Focus globs — analyze only matching files, resolving imports outside read-only.
+    pub include_paths: Option<Vec<String>>,
 }
 
 /// Lightweight scan summary returned to TypeScript.
@@ -42,6 +48,10 @@ pub struct ScanSummary {
     pub duration_ms: u32,
     pub status: String,
     pub languages: HashMap<String, u32>,
+    /// Files taken from an interrupted scan's checkpoint.
+    pub files_resumed: u32,
+    /// Files processed from scratch.
+    pub files_fresh: u32,
 }
 
 impl From<&ScanDiff> for ScanSummary {
@@ -67,6 +77,8 @@ impl From<&ScanDiff> for ScanSummary {
                 "partial".to_string()
             },
             languages,
+            files_resumed: diff.stats.files_resumed as u32,
+            files_fresh: diff.stats.files_fresh as u32,
         }
     }
 }
@@ -84,6 +96,12 @@ pub struct ScanStatsJs {
     pub files_skipped_large: u32,
     pub files_skipped_ignored: u32,
     pub files_skipped_binary: u32,
+    pub files_skipped_sampled: u32,
+    /// Sampled fraction, or `None` for a full scan.
+    pub sample_fraction: Option<f64>,
+    pub files_outside_focus: u32,
+    pub files_resumed: u32,
+    pub files_fresh: u32,
 }
 
 impl From<&ScanStats> for ScanStatsJs {
@@ -98,6 +116,11 @@ impl From<&ScanStats> for ScanStatsJs {
             files_skipped_large: stats.files_skipped_large as u32,
             files_skipped_ignored: stats.files_skipped_ignored as u32,
             files_skipped_binary: stats.files_skipped_binary as u32,
+            files_skipped_sampled: stats.files_skipped_sampled as u32,
+            sample_fraction: stats.sample_fraction,
+            files_outside_focus: stats.files_outside_focus as u32,
+            files_resumed: stats.files_resumed as u32,
+            files_fresh: stats.files_fresh as u32,
         }
     }
 }
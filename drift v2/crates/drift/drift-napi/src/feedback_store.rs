@@ -4,10 +4,17 @@
 //! This closes the feedback loop: user actions (dismiss/fix/suppress/escalate)
 //! written via NAPI → feedback table → DbFeedbackStore → ConfidenceScorer
 //! adjusts (alpha, beta) → confidence changes on next analysis run.
+//!
+//! `DbFeedbackSink` is the write side: plugged into `FeedbackTracker`, it
+//! stores every recorded action so per-detector FP rates can be rebuilt
+//! (`materialized::detector_health`) after a restart.
 
 use std::sync::Arc;
 
+use drift_analysis::enforcement::feedback::{FeedbackRecord, FeedbackSink};
 use drift_analysis::patterns::confidence::scorer::FeedbackStore;
+use drift_storage::queries::enforcement::FeedbackRow;
+use drift_storage::DriftStorageEngine;
 
 use crate::runtime::DriftRuntime;
 
@@ -36,3 +43,35 @@ impl FeedbackStore for DbFeedbackStore {
             .unwrap_or_default()
     }
 }
+
+/// Database-backed feedback sink that appends to the `feedback` table.
+pub struct DbFeedbackSink {
+    storage: Arc<DriftStorageEngine>,
+}
+
+impl DbFeedbackSink {
+    pub fn new(storage: Arc<DriftStorageEngine>) -> Self {
+        Self { storage }
+    }
+}
+
+impl FeedbackSink for DbFeedbackSink {
+    fn persist(&self, record: &FeedbackRecord) {
+        let row = FeedbackRow {
+            violation_id: record.violation_id.clone(),
+            pattern_id: record.pattern_id.clone(),
+            detector_id: record.detector_id.clone(),
+            action: record.action.as_str().to_string(),
+            dismissal_reason: record.dismissal_reason.map(|r| r.as_str().to_string()),
+            reason: record.reason.clone(),
+            author: record.author.clone(),
+            created_at: record.timestamp,
+        };
+        let result = self.storage.with_writer(|conn| {
+            drift_storage::queries::enforcement::insert_feedback(conn, &row)
+        });
+        if let Err(e) = result {
+            tracing::warn!(detector_id = %record.detector_id, error = %e, "failed to persist feedback");
+        }
+    }
+}
@@ -9,6 +9,7 @@
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex, OnceLock};
 
+use drift_analysis::enforcement::feedback::FeedbackTracker;
 use drift_core::config::DriftConfig;
 use drift_core::events::dispatcher::EventDispatcher;
 use drift_storage::DriftStorageEngine;
@@ -19,6 +20,7 @@ use cortex_drift_bridge::storage::engine::BridgeStorageEngine;
 use cortex_drift_bridge::traits::{CortexMemoryWriter, IBridgeStorage};
 
 use crate::conversions::error_codes;
+use crate::feedback_store::DbFeedbackSink;
 
 // ─── CortexStorageWriter (P0-3) ──────────────────────────────────────────
 
@@ -55,7 +57,7 @@ static RUNTIME: OnceLock<Arc<DriftRuntime>> = OnceLock::new();
 /// Scanner and parsers are stateless or use `thread_local!` storage,
 /// so no additional Mutex wrappers are needed here.
 pub struct DriftRuntime {
-    pub storage: DriftStorageEngine,
+    pub storage: Arc<DriftStorageEngine>,
     pub config: DriftConfig,
     pub dispatcher: EventDispatcher,
     pub project_root: Option<PathBuf>,
@@ -69,6 +71,9 @@ pub struct DriftRuntime {
     pub drift_db_for_bridge: Option<Mutex<rusqlite::Connection>>,
     // ─── Bridge event pipeline (Phase B) ─────────────────────────────────
     pub bridge_deduplicator: Mutex<EventDeduplicator>,
+    /// Per-detector FP tracking; every recorded action is written through
+    /// to drift.db's `feedback` table.
+    pub feedback: Mutex<FeedbackTracker>,
 }
 
 /// Options for initializing the runtime.
@@ -105,7 +110,7 @@ impl DriftRuntime {
     /// Create a new runtime with the given options.
     fn new(opts: RuntimeOptions) -> Result<Self, napi::Error> {
         // Resolve configuration
-        let config = match &opts.config_toml {
+        let mut config = match &opts.config_toml {
             Some(toml_str) => DriftConfig::from_toml(toml_str).map_err(|e| {
                 napi::Error::from_reason(format!("[{}] {e}", error_codes::CONFIG_ERROR))
             })?,
@@ -118,6 +123,8 @@ impl DriftRuntime {
                 }
             }
         };
+        let profile = config.analysis.effective_profile();
+        profile.apply(&mut config);
 
         // Resolve database path
         let db_path = match &opts.db_path {
@@ -152,12 +159,13 @@ impl DriftRuntime {
         };
 
         // Open the unified storage engine (DatabaseManager + BatchWriter)
-        let storage = DriftStorageEngine::open(&db_path).map_err(|e| {
+        let storage = Arc::new(DriftStorageEngine::open(&db_path).map_err(|e| {
             napi::Error::from_reason(format!(
                 "[{}] {e}",
                 error_codes::STORAGE_ERROR
             ))
-        })?;
+        })?);
+        let feedback = FeedbackTracker::new().with_sink(Box::new(DbFeedbackSink::new(Arc::clone(&storage))));
 
         let mut dispatcher = EventDispatcher::new();
 
@@ -263,6 +271,7 @@ impl DriftRuntime {
             bridge_initialized,
             drift_db_for_bridge,
             bridge_deduplicator: Mutex::new(EventDeduplicator::new()),
+            feedback: Mutex::new(feedback),
         })
     }
 
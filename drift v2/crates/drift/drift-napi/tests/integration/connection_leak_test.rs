@@ -53,7 +53,7 @@ fn ct0_g09_bridge_no_leaks_after_1000_ops() {
         let engine = BridgeStorageEngine::open(&db_path).unwrap();
         let storage: &dyn IBridgeStorage = &engine;
 
-        for i in 0..500 {
+        for _ in 0..500 {
             let _ = storage.count_memories();
             let _ = storage.get_events(1);
         }
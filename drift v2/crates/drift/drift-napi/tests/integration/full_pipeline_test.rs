@@ -4,7 +4,6 @@
 //! DriftStorageEngine (drift.db) + BridgeStorageEngine (bridge.db).
 //! At no point does any code touch a raw `&Connection`.
 
-use std::sync::Arc;
 use tempfile::tempdir;
 
 use drift_core::traits::storage::{
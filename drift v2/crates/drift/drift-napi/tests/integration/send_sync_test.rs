@@ -6,7 +6,7 @@ use tempfile::tempdir;
 
 use drift_core::traits::storage::{
     IDriftFiles, IDriftAnalysis, IDriftStructural, IDriftEnforcement,
-    IDriftAdvanced, IDriftBatchWriter, IDriftReader,
+    IDriftAdvanced, IDriftReader,
 };
 use drift_storage::engine::DriftStorageEngine;
 
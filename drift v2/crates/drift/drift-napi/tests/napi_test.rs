@@ -1,4 +1,4 @@
-//! NAPI bridge tests — T1-NAPI-01 through T1-NAPI-08.
+//! NAPI bridge tests — T1-NAPI-01 through T1-NAPI-10.
 //!
 //! Since drift-napi is a cdylib, these tests exercise the Rust internals
 //! that power the NAPI boundary: runtime initialization, type conversions,
@@ -60,7 +60,7 @@ fn t1_napi_01_initialize_creates_db_with_pragmas() {
             .map_err(|e| drift_core::errors::StorageError::SqliteError {
                 message: e.to_string(),
             })?;
-        assert_eq!(version, 9, "schema version should match latest migration");
+        assert_eq!(version, 14, "schema version should match latest migration");
         Ok(())
     })
     .unwrap();
@@ -93,6 +93,11 @@ fn t1_napi_02_scan_summary_type_conversion() {
         files_skipped_ignored: 5,
         files_skipped_binary: 3,
         languages_found,
+        files_skipped_sampled: 0,
+        sample_fraction: None,
+        files_outside_focus: 0,
+        files_resumed: 0,
+        files_fresh: 0,
     };
 
     let diff = ScanDiff {
@@ -103,6 +108,7 @@ fn t1_napi_02_scan_summary_type_conversion() {
         errors: vec![],
         stats,
         entries: FxHashMap::default(),
+        context_files: vec![],
     };
 
     let summary = ScanSummary::from(&diff);
@@ -310,6 +316,9 @@ fn t1_napi_08_scan_options_config_merging() {
         include: None,
         extra_ignore: Some(vec!["*.log".to_string(), "dist/".to_string()]),
         follow_symlinks: Some(false),
+        sample_fraction: None,
+        sample_seed: None,
+        include_paths: None,
     };
 
     assert_eq!(opts.force_full, Some(true));
@@ -331,6 +340,11 @@ fn t1_napi_08_scan_options_config_merging() {
         files_skipped_ignored: 15,
         files_skipped_binary: 7,
         languages_found: FxMap::default(),
+        files_skipped_sampled: 0,
+        sample_fraction: None,
+        files_outside_focus: 0,
+        files_resumed: 0,
+        files_fresh: 0,
     };
 
     let js_stats = ScanStatsJs::from(&stats);
@@ -351,3 +365,144 @@ fn t1_napi_08_scan_options_config_merging() {
     assert_eq!(config.scan.effective_threads(), 0);
     assert!(config.scan.effective_incremental());
 }
+
+// ---- T1-NAPI-09: Scan persistence records each file's scan root ----
+
+#[test]
+fn t1_napi_09_scan_persistence_records_file_roots() {
+    use drift_analysis::scanner::Scanner;
+    use drift_core::config::ScanConfig;
+    use drift_core::events::handler::DriftEventHandler;
+    use drift_core::types::collections::FxHashMap;
+    use drift_napi::bindings::scanner::scan_diff_batches;
+    use drift_storage::engine::DriftStorageEngine;
+    use drift_storage::queries::files;
+
+    struct NoOpHandler;
+    impl DriftEventHandler for NoOpHandler {}
+
+    let services = TempDir::new().unwrap();
+    let libs = TempDir::new().unwrap();
+    std::fs::write(services.path().join("api.ts"), "export const api = 1;").unwrap();
+    std::fs::write(libs.path().join("util.go"), "package util").unwrap();
+    let roots = vec![services.path().to_path_buf(), libs.path().to_path_buf()];
+
+    let diff = Scanner::new(ScanConfig::default())
+        .scan_roots(&roots, &FxHashMap::default(), &NoOpHandler)
+        .unwrap();
+
+    let dir = TempDir::new().unwrap();
+    let engine = DriftStorageEngine::open(&dir.path().join("drift.db")).unwrap();
+    for batch in scan_diff_batches(&diff, "unused") {
+        engine.send_batch(batch).unwrap();
+    }
+    engine.flush_batch_sync().unwrap();
+
+    let services_root = services.path().to_string_lossy().to_string();
+    let libs_root = libs.path().to_string_lossy().to_string();
+    let (under_services, under_libs, listed) = engine
+        .with_reader(|conn| {
+            Ok((
+                files::load_file_metadata_by_root(conn, &services_root)?,
+                files::load_file_metadata_by_root(conn, &libs_root)?,
+                files::list_roots(conn)?,
+            ))
+        })
+        .unwrap();
+
+    let api = services.path().join("api.ts").to_string_lossy().to_string();
+    let util = libs.path().join("util.go").to_string_lossy().to_string();
+    assert_eq!(under_services.iter().map(|r| &r.path).collect::<Vec<_>>(), vec![&api]);
+    assert_eq!(under_libs.iter().map(|r| &r.path).collect::<Vec<_>>(), vec![&util]);
+    let mut expected = vec![services_root, libs_root];
+    expected.sort();
+    assert_eq!(listed, expected);
+
+    // Single-root scans attribute every file to the scanned root.
+    let diff = Scanner::new(ScanConfig::default())
+        .scan(libs.path(), &FxHashMap::default(), &NoOpHandler)
+        .unwrap();
+    for batch in scan_diff_batches(&diff, "/workspace/libs") {
+        engine.send_batch(batch).unwrap();
+    }
+    engine.flush_batch_sync().unwrap();
+    let root = engine
+        .with_reader(|conn| files::get_file_root(conn, &util))
+        .unwrap();
+    assert_eq!(root.as_deref(), Some("/workspace/libs"));
+}
+
+// ---- T1-NAPI-10: DbFeedbackSink persists tracker feedback for detector_health ----
+
+#[test]
+fn t1_napi_10_feedback_sink_rebuilds_detector_health() {
+    use std::sync::Arc;
+
+    use drift_analysis::enforcement::feedback::{
+        DismissalReason, FeedbackAction, FeedbackRecord, FeedbackTracker,
+    };
+    use drift_napi::feedback_store::DbFeedbackSink;
+    use drift_storage::engine::DriftStorageEngine;
+    use drift_storage::materialized::detector_health::refresh_detector_health;
+    use drift_storage::queries::enforcement::{detector_fp_rate, query_feedback_by_pattern};
+
+    let record = |detector_id: &str, action, dismissal_reason, timestamp| FeedbackRecord {
+        violation_id: format!("v-{detector_id}-{timestamp}"),
+        pattern_id: format!("pat-{detector_id}"),
+        detector_id: detector_id.to_string(),
+        action,
+        dismissal_reason,
+        reason: None,
+        author: Some("dev".to_string()),
+        timestamp,
+    };
+
+    let dir = TempDir::new().unwrap();
+    let storage = Arc::new(DriftStorageEngine::open(&dir.path().join("drift.db")).unwrap());
+    let mut tracker = FeedbackTracker::new().with_sink(Box::new(DbFeedbackSink::new(Arc::clone(&storage))));
+    for i in 0..6 {
+        tracker.record(&record("det-a", FeedbackAction::Fix, None, i));
+    }
+    for (i, reason) in [
+        DismissalReason::FalsePositive,
+        DismissalReason::NotApplicable,
+        DismissalReason::WontFix,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        tracker.record(&record("det-a", FeedbackAction::Dismiss, Some(reason), 6 + i as u64));
+    }
+    tracker.record(&record("det-a", FeedbackAction::Suppress, None, 9));
+    tracker.record(&record("det-b", FeedbackAction::Fix, None, 10));
+    tracker.record(&record("det-c", FeedbackAction::Escalate, None, 11));
+    let expected = tracker.fp_rate("det-a");
+    drop(tracker);
+
+    storage
+        .with_writer(|conn| {
+            let stored = query_feedback_by_pattern(conn, "pat-det-a")?;
+            assert_eq!(stored.len(), 10);
+            let dismissal = stored.iter().find(|f| f.violation_id == "v-det-a-6").unwrap();
+            assert_eq!(dismissal.action, "dismiss");
+            assert_eq!(dismissal.dismissal_reason.as_deref(), Some("false_positive"));
+            assert_eq!(dismissal.author.as_deref(), Some("dev"));
+
+            let rows = refresh_detector_health(conn)?;
+            let ids: Vec<&str> = rows.iter().map(|r| r.detector_id.as_str()).collect();
+            assert_eq!(ids, vec!["det-a", "det-b", "det-c"]);
+            assert_eq!((rows[0].total, rows[0].false_positives), (10, 2));
+
+            // 2 FPs / (6 fixes + 3 dismissals), same as in memory
+            let fp_rate = detector_fp_rate(conn, "det-a")?.unwrap();
+            assert!((fp_rate - 2.0 / 9.0).abs() < 1e-9);
+            assert!((fp_rate - expected).abs() < 1e-9);
+            assert_eq!(detector_fp_rate(conn, "det-b")?, Some(0.0));
+            assert_eq!(detector_fp_rate(conn, "unknown")?, None);
+
+            // Rebuilding replaces rather than appends
+            assert_eq!(refresh_detector_health(conn)?.len(), 3);
+            Ok(())
+        })
+        .unwrap();
+}
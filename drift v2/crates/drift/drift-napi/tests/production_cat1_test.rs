@@ -110,6 +110,7 @@ fn t1_02_buffer_transfer_stress() {
             doc_comment: Some(format!("Function {i} documentation")),
             body_hash: i as u64,
             signature_hash: i as u64 * 31,
+            cognitive_complexity: 0,
         });
     }
 
@@ -606,6 +607,8 @@ fn t1_11_stub_fallback_safe_defaults() {
         duration_ms: 0,
         status: "stub".to_string(),
         languages: std::collections::HashMap::new(),
+        files_resumed: 0,
+        files_fresh: 0,
     };
     assert_eq!(empty_summary.files_total, 0);
     assert!(empty_summary.languages.is_empty());
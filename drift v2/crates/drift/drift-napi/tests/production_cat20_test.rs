@@ -244,6 +244,8 @@ fn t20_04_infrastructure_types() {
             ("TypeScript".to_string(), 80),
             ("JavaScript".to_string(), 20),
         ]),
+        files_resumed: 0,
+        files_fresh: 0,
     };
     assert_eq!(summary.files_total, summary.files_added + summary.files_modified + summary.files_removed + summary.files_unchanged);
 
@@ -254,6 +256,9 @@ fn t20_04_infrastructure_types() {
         include: None,
         extra_ignore: Some(vec!["*.generated.ts".to_string()]),
         follow_symlinks: Some(false),
+        sample_fraction: None,
+        sample_seed: None,
+        include_paths: None,
     };
     assert!(opts.force_full.unwrap());
     assert_eq!(opts.max_file_size.unwrap(), 2_000_000);
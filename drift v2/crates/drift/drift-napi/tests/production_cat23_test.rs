@@ -397,6 +397,8 @@ function internalHelper(): number {
         execution_time_ms: 0,
         details: serde_json::Value::Null,
         error: None,
+        explanation: None,
+        advisories: Vec::new(),
     }];
 
     let reporter = create_reporter("sarif").expect("SARIF reporter must exist");
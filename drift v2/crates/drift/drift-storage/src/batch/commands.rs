@@ -11,6 +11,8 @@ pub enum BatchCommand {
     InsertFunctions(Vec<FunctionRow>),
     /// Delete file metadata for removed files.
     DeleteFileMetadata(Vec<String>),
+    /// Record the originating scan root for files, as `(path, root)` pairs.
+    UpsertFileRoots(Vec<(String, String)>),
     /// Flush any pending writes immediately (fire-and-forget).
     Flush,
     /// Flush and signal completion via the provided sender (synchronous).
@@ -32,6 +32,7 @@ pub struct WriteStats {
     pub parse_cache_rows: usize,
     pub function_rows: usize,
     pub deleted_files: usize,
+    pub file_root_rows: usize,
     pub call_edge_rows: usize,
     pub detection_rows: usize,
     pub boundary_rows: usize,
@@ -212,6 +213,10 @@ fn flush_buffer(
                 delete_file_metadata(&tx, paths)?;
                 batch_stats.deleted_files += paths.len();
             }
+            BatchCommand::UpsertFileRoots(roots) => {
+                crate::queries::files::upsert_file_roots(&tx, roots)?;
+                batch_stats.file_root_rows += roots.len();
+            }
             BatchCommand::InsertCallEdges(rows) => {
                 insert_call_edges(&tx, rows)?;
                 batch_stats.call_edge_rows += rows.len();
@@ -342,6 +347,7 @@ fn flush_buffer(
     stats.parse_cache_rows += batch_stats.parse_cache_rows;
     stats.function_rows += batch_stats.function_rows;
     stats.deleted_files += batch_stats.deleted_files;
+    stats.file_root_rows += batch_stats.file_root_rows;
     stats.call_edge_rows += batch_stats.call_edge_rows;
     stats.detection_rows += batch_stats.detection_rows;
     stats.boundary_rows += batch_stats.boundary_rows;
@@ -485,11 +491,21 @@ fn delete_file_metadata(
             message: e.to_string(),
         })?;
 
+    let mut roots_stmt = conn
+        .prepare_cached("DELETE FROM file_roots WHERE path = ?1")
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+
     for path in paths {
         stmt.execute(rusqlite::params![path])
             .map_err(|e| StorageError::SqliteError {
                 message: e.to_string(),
             })?;
+        roots_stmt.execute(rusqlite::params![path])
+            .map_err(|e| StorageError::SqliteError {
+                message: e.to_string(),
+            })?;
     }
     Ok(())
 }
@@ -0,0 +1,66 @@
+//! Materialized detector health view — false-positive rates per detector.
+//!
+//! Mirrors `FeedbackTracker` in drift-analysis: `total` counts every
+//! feedback action, `false_positives` the dismissals marked `false_positive`
+//! or `not_applicable`, and `fp_rate` divides them by the fixes and
+//! dismissals. `last_updated` is the time of the detector's latest feedback.
+
+use rusqlite::Connection;
+use drift_core::errors::StorageError;
+
+/// Rebuild `detector_health` from the `feedback` table.
+pub fn refresh_detector_health(conn: &Connection) -> Result<Vec<DetectorHealthRow>, StorageError> {
+    conn.execute_batch(
+        "DELETE FROM detector_health;
+         INSERT INTO detector_health (detector_id, total, false_positives, fp_rate, last_updated)
+         SELECT detector_id,
+                COUNT(*),
+                SUM(fp),
+                COALESCE(CAST(SUM(fp) AS REAL) / NULLIF(SUM(action IN ('fix', 'dismiss')), 0), 0.0),
+                MAX(created_at)
+         FROM (
+             SELECT detector_id, action, created_at,
+                    action = 'dismiss' AND COALESCE(dismissal_reason IN ('false_positive', 'not_applicable'), 0) AS fp
+             FROM feedback
+         )
+         GROUP BY detector_id;",
+    )
+    .map_err(|e| StorageError::SqliteError {
+        message: e.to_string(),
+    })?;
+
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT detector_id, total, false_positives, fp_rate, last_updated
+             FROM detector_health ORDER BY detector_id",
+        )
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(DetectorHealthRow {
+                detector_id: row.get(0)?,
+                total: row.get(1)?,
+                false_positives: row.get(2)?,
+                fp_rate: row.get(3)?,
+                last_updated: row.get(4)?,
+            })
+        })
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })
+}
+
+#[derive(Debug, Clone)]
+pub struct DetectorHealthRow {
+    pub detector_id: String,
+    pub total: u64,
+    pub false_positives: u64,
+    pub fp_rate: f64,
+    pub last_updated: u64,
+}
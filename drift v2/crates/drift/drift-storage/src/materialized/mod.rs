@@ -3,3 +3,4 @@
 pub mod status;
 pub mod security;
 pub mod trends;
+pub mod detector_health;
@@ -9,6 +9,11 @@ pub mod v006_enforcement;
 pub mod v007_advanced;
 pub mod v008_enforcement_fixes;
 pub mod v009_pattern_status;
+pub mod v010_file_roots;
+pub mod v011_scan_tagging;
+pub mod v012_scan_checkpoint;
+pub mod v013_function_keyset;
+pub mod v014_detector_health;
 
 use drift_core::errors::StorageError;
 use rusqlite::Connection;
@@ -32,6 +37,11 @@ pub fn run_migrations(conn: &Connection) -> Result<(), StorageError> {
         (v007_advanced::MIGRATION_SQL, 7),
         (v008_enforcement_fixes::MIGRATION_SQL, 8),
         (v009_pattern_status::MIGRATION_SQL, 9),
+        (v010_file_roots::MIGRATION_SQL, 10),
+        (v011_scan_tagging::MIGRATION_SQL, 11),
+        (v012_scan_checkpoint::MIGRATION_SQL, 12),
+        (v013_function_keyset::MIGRATION_SQL, 13),
+        (v014_detector_health::MIGRATION_SQL, 14),
     ];
 
     for (sql, version) in migrations {
@@ -0,0 +1,15 @@
+//! V010 migration: Multi-root workspace support.
+//!
+//! Adds file_roots to record which scan root each file belongs to, so one
+//! drift.db can hold several unrelated roots and queries can filter by root.
+
+pub const MIGRATION_SQL: &str = r#"
+-- Originating scan root per file (multi-root scans).
+-- Kept separate from file_metadata so scanner upserts do not clear it.
+CREATE TABLE IF NOT EXISTS file_roots (
+    path TEXT PRIMARY KEY,
+    root TEXT NOT NULL
+) STRICT;
+
+CREATE INDEX IF NOT EXISTS idx_file_roots_root ON file_roots(root);
+"#;
@@ -0,0 +1,31 @@
+//! V011 migration: Per-scan tagging of file changes and findings.
+//!
+//! scan_history records only totals. These tables record which files each
+//! scan saw change and which violations it observed, so history queries can
+//! ask what changed, and what was new, since a given scan.
+
+pub const MIGRATION_SQL: &str = r#"
+-- Files added/modified/removed by each scan.
+CREATE TABLE IF NOT EXISTS scan_file_changes (
+    scan_id INTEGER NOT NULL,
+    path TEXT NOT NULL,
+    change TEXT NOT NULL,
+    recorded_at INTEGER NOT NULL DEFAULT (unixepoch()),
+    PRIMARY KEY (scan_id, path)
+) STRICT;
+
+CREATE INDEX IF NOT EXISTS idx_scan_file_changes_path ON scan_file_changes(path);
+
+-- Violations observed by each scan. Kept separate from violations, whose
+-- rows are replaced on every run.
+CREATE TABLE IF NOT EXISTS scan_violations (
+    scan_id INTEGER NOT NULL,
+    violation_id TEXT NOT NULL,
+    file TEXT NOT NULL,
+    recorded_at INTEGER NOT NULL DEFAULT (unixepoch()),
+    PRIMARY KEY (scan_id, violation_id)
+) STRICT;
+
+CREATE INDEX IF NOT EXISTS idx_scan_violations_file ON scan_violations(file);
+CREATE INDEX IF NOT EXISTS idx_scan_violations_violation ON scan_violations(violation_id);
+"#;
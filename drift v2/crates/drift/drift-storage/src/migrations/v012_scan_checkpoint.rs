@@ -0,0 +1,19 @@
+//! V012 migration: Resumable scan checkpoints.
+//!
+//! file_metadata is written once a scan completes. For long scans that may
+//! be interrupted, the scanner checkpoints every file it has processed here,
+//! so a restarted scan of the same root can skip them.
+
+pub const MIGRATION_SQL: &str = r#"
+-- Files processed by an unfinished scan, per scan root.
+-- Cleared when the scan completes and file_metadata is persisted.
+CREATE TABLE IF NOT EXISTS scan_checkpoint (
+    root TEXT NOT NULL,
+    path TEXT NOT NULL,
+    content_hash BLOB NOT NULL,
+    mtime_secs INTEGER NOT NULL,
+    mtime_nanos INTEGER NOT NULL,
+    checkpointed_at INTEGER NOT NULL DEFAULT (unixepoch()),
+    PRIMARY KEY (root, path)
+) STRICT;
+"#;
@@ -0,0 +1,9 @@
+//! V013 migration: Keyset index for paging functions by file.
+//!
+//! `queries::functions::functions_by_prefix` pages in `(file, line, id)`
+//! order. `id` is the rowid, which every index carries, so an index on
+//! `(file, line)` serves both the prefix range and the keyset comparison.
+
+pub const MIGRATION_SQL: &str = r#"
+CREATE INDEX IF NOT EXISTS idx_functions_file_line ON functions(file, line);
+"#;
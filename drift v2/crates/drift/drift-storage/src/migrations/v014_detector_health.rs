@@ -0,0 +1,15 @@
+//! V014 migration: Detector health materialized view.
+//!
+//! Per-detector false-positive rates derived from the `feedback` table, so
+//! they survive process restarts. Rebuilt by
+//! `materialized::detector_health::refresh_detector_health`.
+
+pub const MIGRATION_SQL: &str = r#"
+CREATE TABLE IF NOT EXISTS detector_health (
+    detector_id TEXT PRIMARY KEY,
+    total INTEGER NOT NULL,
+    false_positives INTEGER NOT NULL,
+    fp_rate REAL NOT NULL,
+    last_updated INTEGER NOT NULL
+) STRICT;
+"#;
@@ -1,7 +1,7 @@
 //! Queries for enforcement tables: violations, gate_results, audit_snapshots,
 //! health_trends, feedback.
 
-use rusqlite::{params, Connection};
+use rusqlite::{params, Connection, OptionalExtension};
 
 use drift_core::errors::StorageError;
 
@@ -406,6 +406,21 @@ pub fn query_feedback_by_pattern(
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })
 }
 
+/// Historical false-positive rate of a detector, from the `detector_health`
+/// view as of its last refresh. `None` if the detector has no feedback.
+pub fn detector_fp_rate(
+    conn: &Connection,
+    detector_id: &str,
+) -> Result<Option<f64>, StorageError> {
+    conn.query_row(
+        "SELECT fp_rate FROM detector_health WHERE detector_id = ?1",
+        params![detector_id],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
 /// Compute (alpha_delta, beta_delta) adjustments for a pattern from its feedback rows.
 ///
 /// Maps action strings to Bayesian parameter deltas using the same logic as
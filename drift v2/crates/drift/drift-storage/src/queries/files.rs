@@ -151,3 +151,115 @@ pub fn count_files(conn: &Connection) -> Result<i64, StorageError> {
             message: e.to_string(),
         })
 }
+
+/// Record the originating scan root for each file (multi-root scans).
+pub fn upsert_file_roots(
+    conn: &Connection,
+    roots: &[(String, String)],
+) -> Result<(), StorageError> {
+    let mut stmt = conn
+        .prepare_cached("INSERT OR REPLACE INTO file_roots (path, root) VALUES (?1, ?2)")
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+
+    for (path, root) in roots {
+        stmt.execute(params![path, root])
+            .map_err(|e| StorageError::SqliteError {
+                message: e.to_string(),
+            })?;
+    }
+    Ok(())
+}
+
+/// Get the recorded scan root for a file.
+pub fn get_file_root(conn: &Connection, path: &str) -> Result<Option<String>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached("SELECT root FROM file_roots WHERE path = ?1")
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+
+    let mut rows = stmt
+        .query_map(params![path], |row| row.get(0))
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+
+    match rows.next() {
+        Some(Ok(root)) => Ok(Some(root)),
+        Some(Err(e)) => Err(StorageError::SqliteError {
+            message: e.to_string(),
+        }),
+        None => Ok(None),
+    }
+}
+
+/// List all distinct scan roots, sorted.
+pub fn list_roots(conn: &Connection) -> Result<Vec<String>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached("SELECT DISTINCT root FROM file_roots ORDER BY root")
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+
+    let rows = stmt
+        .query_map([], |row| row.get(0))
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+
+    rows.collect::<Result<Vec<String>, _>>()
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })
+}
+
+/// Load file metadata for files discovered under a specific scan root.
+pub fn load_file_metadata_by_root(
+    conn: &Connection,
+    root: &str,
+) -> Result<Vec<FileMetadataRecord>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT m.path, m.language, m.file_size, m.content_hash, m.mtime_secs, m.mtime_nanos,
+                    m.last_scanned_at, m.scan_duration_us, m.pattern_count, m.function_count,
+                    m.error_count, m.error
+             FROM file_metadata m
+             JOIN file_roots r ON r.path = m.path
+             WHERE r.root = ?1
+             ORDER BY m.path",
+        )
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+
+    let rows = stmt
+        .query_map(params![root], |row| {
+            Ok(FileMetadataRecord {
+                path: row.get(0)?,
+                language: row.get(1)?,
+                file_size: row.get(2)?,
+                content_hash: row.get(3)?,
+                mtime_secs: row.get(4)?,
+                mtime_nanos: row.get(5)?,
+                last_scanned_at: row.get(6)?,
+                scan_duration_us: row.get(7)?,
+                pattern_count: row.get(8)?,
+                function_count: row.get(9)?,
+                error_count: row.get(10)?,
+                error: row.get(11)?,
+            })
+        })
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+
+    let mut result = Vec::new();
+    for row in rows {
+        result.push(row.map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?);
+    }
+    Ok(result)
+}
@@ -3,6 +3,8 @@
 use drift_core::errors::StorageError;
 use rusqlite::{params, Connection};
 
+use crate::pagination::{PaginatedResult, PaginationCursor};
+
 /// A function record from the database.
 #[derive(Debug, Clone)]
 pub struct FunctionRecord {
@@ -68,6 +70,96 @@ pub fn get_functions_by_file(
     Ok(result)
 }
 
+/// Page through functions whose file path starts with `prefix`, in
+/// `(file, line)` order, resuming after the encoded cursor `after`.
+///
+/// Keyset pagination: rows inserted before the cursor while paging do not
+/// shift later pages, and each page is an index range scan on
+/// `idx_functions_file_line` regardless of its position. The cursor's sort
+/// value is the last row's `file:line`; its id breaks ties between functions
+/// declared on the same line.
+pub fn functions_by_prefix(
+    conn: &Connection,
+    prefix: &str,
+    after: Option<&str>,
+    limit: usize,
+) -> Result<PaginatedResult<FunctionRecord>, StorageError> {
+    // Upper bound of the prefix range: U+10FFFF sorts after every other
+    // code point under BINARY collation.
+    let upper = format!("{prefix}\u{10FFFF}");
+    let (file, line, id) = match after {
+        Some(encoded) => decode_function_cursor(encoded).ok_or_else(|| StorageError::SqliteError {
+            message: format!("invalid pagination cursor: {encoded}"),
+        })?,
+        None => (String::new(), i64::MIN, i64::MIN),
+    };
+
+    let total: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM functions WHERE file >= ?1 AND file < ?2",
+            params![prefix, upper],
+            |row| row.get(0),
+        )
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+
+    // Fetch one extra row to learn whether another page follows.
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT id, file, name, qualified_name, language, line, end_line,
+                    parameter_count, return_type, is_exported, is_async,
+                    body_hash, signature_hash
+             FROM functions
+             WHERE file >= ?1 AND file < ?2 AND (file, line, id) > (?3, ?4, ?5)
+             ORDER BY file, line, id
+             LIMIT ?6",
+        )
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+
+    let rows = stmt
+        .query_map(params![prefix, upper, file, line, id, limit as i64 + 1], map_function_row)
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+
+    let mut items = Vec::new();
+    for row in rows {
+        items.push(row.map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?);
+    }
+
+    let has_more = items.len() > limit;
+    items.truncate(limit);
+    let next_cursor = if has_more {
+        items.last().map(|last| {
+            PaginationCursor {
+                last_sort_value: format!("{}:{}", last.file, last.line),
+                last_id: last.id.to_string(),
+            }
+            .encode()
+        })
+    } else {
+        None
+    };
+    Ok(PaginatedResult {
+        items,
+        total: total as u64,
+        has_more,
+        next_cursor,
+    })
+}
+
+/// Split an encoded [`functions_by_prefix`] cursor into `(file, line, id)`.
+fn decode_function_cursor(encoded: &str) -> Option<(String, i64, i64)> {
+    let cursor = PaginationCursor::decode(encoded)?;
+    let (file, line) = cursor.last_sort_value.rsplit_once(':')?;
+    Some((file.to_string(), line.parse().ok()?, cursor.last_id.parse().ok()?))
+}
+
 /// Get a function by qualified name.
 pub fn get_function_by_qualified_name(
     conn: &Connection,
@@ -146,3 +238,21 @@ pub fn count_entry_points(conn: &Connection) -> Result<i64, StorageError> {
         message: e.to_string(),
     })
 }
+
+fn map_function_row(row: &rusqlite::Row) -> rusqlite::Result<FunctionRecord> {
+    Ok(FunctionRecord {
+        id: row.get(0)?,
+        file: row.get(1)?,
+        name: row.get(2)?,
+        qualified_name: row.get(3)?,
+        language: row.get(4)?,
+        line: row.get(5)?,
+        end_line: row.get(6)?,
+        parameter_count: row.get(7)?,
+        return_type: row.get(8)?,
+        is_exported: row.get(9)?,
+        is_async: row.get(10)?,
+        body_hash: row.get(11)?,
+        signature_hash: row.get(12)?,
+    })
+}
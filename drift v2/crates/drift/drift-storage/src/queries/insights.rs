@@ -0,0 +1,89 @@
+//! Reporting queries that combine scan history with findings.
+
+use drift_core::errors::StorageError;
+use rusqlite::{params, Connection};
+
+/// A file where new findings appeared since a given scan.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HotspotRow {
+    pub file: String,
+    /// Violations first observed after `since_scan`.
+    pub new_findings: i64,
+    /// Scans after `since_scan` that saw the file added or modified.
+    pub change_count: i64,
+    /// Violations in the latest tagged scan minus those in `since_scan`
+    /// (positive: the file's health got worse).
+    pub findings_delta: i64,
+    /// `new_findings * (1 + change_count)`: new findings in frequently
+    /// changed files rank first.
+    pub score: i64,
+}
+
+/// Files with new findings or changes since `since_scan`, hottest first.
+///
+/// Reads the per-scan tags in `scan_violations` and `scan_file_changes`. A
+/// violation is new if no scan up to and including `since_scan` observed it.
+pub fn recent_hotspots(
+    conn: &Connection,
+    since_scan: i64,
+    limit: usize,
+) -> Result<Vec<HotspotRow>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "WITH new_findings AS (
+                 SELECT file, COUNT(DISTINCT violation_id) AS n
+                 FROM scan_violations
+                 WHERE scan_id > ?1
+                   AND violation_id NOT IN
+                       (SELECT violation_id FROM scan_violations WHERE scan_id <= ?1)
+                 GROUP BY file
+             ),
+             changes AS (
+                 SELECT path AS file, COUNT(*) AS n
+                 FROM scan_file_changes
+                 WHERE scan_id > ?1 AND change != 'removed'
+                 GROUP BY path
+             ),
+             latest AS (
+                 SELECT file, COUNT(*) AS n FROM scan_violations
+                 WHERE scan_id = (SELECT MAX(scan_id) FROM scan_violations WHERE scan_id > ?1)
+                 GROUP BY file
+             ),
+             baseline AS (
+                 SELECT file, COUNT(*) AS n FROM scan_violations
+                 WHERE scan_id = (SELECT MAX(scan_id) FROM scan_violations WHERE scan_id <= ?1)
+                 GROUP BY file
+             ),
+             touched AS (
+                 SELECT file FROM new_findings UNION SELECT file FROM changes
+             )
+             SELECT t.file,
+                    COALESCE(nf.n, 0) AS new_findings,
+                    COALESCE(c.n, 0) AS change_count,
+                    COALESCE(l.n, 0) - COALESCE(b.n, 0) AS findings_delta,
+                    COALESCE(nf.n, 0) * (1 + COALESCE(c.n, 0)) AS score
+             FROM touched t
+             LEFT JOIN new_findings nf ON nf.file = t.file
+             LEFT JOIN changes c ON c.file = t.file
+             LEFT JOIN latest l ON l.file = t.file
+             LEFT JOIN baseline b ON b.file = t.file
+             ORDER BY score DESC, change_count DESC, t.file ASC
+             LIMIT ?2",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    let rows = stmt
+        .query_map(params![since_scan, limit as i64], |row| {
+            Ok(HotspotRow {
+                file: row.get(0)?,
+                new_findings: row.get(1)?,
+                change_count: row.get(2)?,
+                findings_delta: row.get(3)?,
+                score: row.get(4)?,
+            })
+        })
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
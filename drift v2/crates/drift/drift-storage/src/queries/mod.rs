@@ -13,6 +13,8 @@ pub mod structural;
 pub mod enforcement;
 pub mod advanced;
 pub mod scan_history;
+pub mod scan_checkpoint;
+pub mod insights;
 pub mod data_access;
 pub mod constants;
 pub mod env_variables;
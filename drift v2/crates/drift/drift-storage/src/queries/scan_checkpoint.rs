@@ -0,0 +1,67 @@
+//! scan_checkpoint queries — progress of an interrupted scan.
+
+use drift_core::errors::StorageError;
+use rusqlite::{params, Connection};
+
+/// A file processed by an unfinished scan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckpointRecord {
+    pub path: String,
+    pub content_hash: Vec<u8>,
+    pub mtime_secs: i64,
+    pub mtime_nanos: i64,
+}
+
+/// Add processed files to the checkpoint of `root` in one transaction.
+pub fn save_checkpoint(
+    conn: &Connection,
+    root: &str,
+    records: &[CheckpointRecord],
+) -> Result<(), StorageError> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    {
+        let mut stmt = tx
+            .prepare_cached(
+                "INSERT OR REPLACE INTO scan_checkpoint (root, path, content_hash, mtime_secs, mtime_nanos)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+            )
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        for r in records {
+            stmt.execute(params![root, r.path, r.content_hash, r.mtime_secs, r.mtime_nanos])
+                .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        }
+    }
+    tx.commit()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+/// Load the checkpoint of `root` (empty when the last scan completed).
+pub fn load_checkpoint(conn: &Connection, root: &str) -> Result<Vec<CheckpointRecord>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT path, content_hash, mtime_secs, mtime_nanos
+             FROM scan_checkpoint WHERE root = ?1 ORDER BY path",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    let rows = stmt
+        .query_map(params![root], |row| {
+            Ok(CheckpointRecord {
+                path: row.get(0)?,
+                content_hash: row.get(1)?,
+                mtime_secs: row.get(2)?,
+                mtime_nanos: row.get(3)?,
+            })
+        })
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+/// Drop the checkpoint of `root` once its scan has completed.
+pub fn clear_checkpoint(conn: &Connection, root: &str) -> Result<(), StorageError> {
+    conn.execute("DELETE FROM scan_checkpoint WHERE root = ?1", params![root])
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    Ok(())
+}
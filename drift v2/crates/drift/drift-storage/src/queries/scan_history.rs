@@ -102,3 +102,46 @@ pub fn count(conn: &Connection) -> Result<i64, StorageError> {
     conn.query_row("SELECT COUNT(*) FROM scan_history", [], |row| row.get(0))
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })
 }
+
+/// The most recent scan's id, if any scan has been recorded.
+pub fn latest_scan_id(conn: &Connection) -> Result<Option<i64>, StorageError> {
+    conn.query_row("SELECT MAX(id) FROM scan_history", [], |row| row.get(0))
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+/// Record the files a scan saw change, as `(path, change)` with change one of
+/// `added`, `modified` or `removed`.
+pub fn record_file_changes(
+    conn: &Connection,
+    scan_id: i64,
+    changes: &[(String, &str)],
+) -> Result<(), StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "INSERT OR REPLACE INTO scan_file_changes (scan_id, path, change) VALUES (?1, ?2, ?3)",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    for (path, change) in changes {
+        stmt.execute(params![scan_id, path, change])
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    }
+    Ok(())
+}
+
+/// Record the violations observed by a scan, as `(violation_id, file)`.
+pub fn record_scan_violations(
+    conn: &Connection,
+    scan_id: i64,
+    violations: &[(String, String)],
+) -> Result<(), StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "INSERT OR REPLACE INTO scan_violations (scan_id, violation_id, file) VALUES (?1, ?2, ?3)",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    for (violation_id, file) in violations {
+        stmt.execute(params![scan_id, violation_id, file])
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    }
+    Ok(())
+}
@@ -104,6 +104,7 @@ fn apply_retention_inner(
     cleanup_orphans_by_file(conn, "wrappers", "file", report)?;
     cleanup_orphans_by_file(conn, "crypto_findings", "file", report)?;
     cleanup_orphans_by_file(conn, "owasp_findings", "file", report)?;
+    cleanup_orphans_by_file(conn, "file_roots", "path", report)?;
 
     // ─── Short retention (30 days) ──────────────────────────────────
 
@@ -118,10 +119,14 @@ fn apply_retention_inner(
     cleanup_by_time(conn, "secrets", "created_at", short_cutoff, report)?;
     cleanup_by_time(conn, "degradation_alerts", "created_at", short_cutoff, report)?;
     cleanup_by_time(conn, "policy_results", "run_at", short_cutoff, report)?;
+    // Checkpoints of scans that were never resumed.
+    cleanup_by_time(conn, "scan_checkpoint", "checkpointed_at", short_cutoff, report)?;
 
     // ─── Medium retention (90 days) ─────────────────────────────────
 
     cleanup_by_time(conn, "scan_history", "started_at", medium_cutoff, report)?;
+    cleanup_by_time(conn, "scan_file_changes", "recorded_at", medium_cutoff, report)?;
+    cleanup_by_time(conn, "scan_violations", "recorded_at", medium_cutoff, report)?;
     cleanup_by_time(conn, "audit_snapshots", "created_at", medium_cutoff, report)?;
     cleanup_by_time(conn, "health_trends", "recorded_at", medium_cutoff, report)?;
     cleanup_by_time(conn, "feedback", "created_at", medium_cutoff, report)?;
@@ -234,7 +239,11 @@ mod tests {
              CREATE TABLE boundaries (id INTEGER PRIMARY KEY, file TEXT, created_at INTEGER DEFAULT 0);
              CREATE TABLE constants (id INTEGER PRIMARY KEY, file TEXT, created_at INTEGER DEFAULT 0);
              CREATE TABLE env_variables (id INTEGER PRIMARY KEY, file TEXT, created_at INTEGER DEFAULT 0);
-             CREATE TABLE wrappers (id INTEGER PRIMARY KEY, file TEXT, created_at INTEGER DEFAULT 0);",
+             CREATE TABLE wrappers (id INTEGER PRIMARY KEY, file TEXT, created_at INTEGER DEFAULT 0);
+             CREATE TABLE file_roots (path TEXT PRIMARY KEY, root TEXT);
+             CREATE TABLE scan_file_changes (scan_id INTEGER, path TEXT, recorded_at INTEGER DEFAULT 0);
+             CREATE TABLE scan_violations (scan_id INTEGER, violation_id TEXT, recorded_at INTEGER DEFAULT 0);
+             CREATE TABLE scan_checkpoint (root TEXT, path TEXT, checkpointed_at INTEGER DEFAULT 0);",
         )
         .unwrap();
         conn
@@ -333,19 +333,21 @@ fn t13_03_self_bounding_reachability_cache() {
 // Tiers:
 //   - Reference: file_metadata (the root reference table)
 //   - Current (orphan cleanup): detections, functions, boundaries, constants,
-//     secrets, env_variables, wrappers, crypto_findings, owasp_findings
+//     secrets, env_variables, wrappers, crypto_findings, owasp_findings,
+//     file_roots
 //   - Short (30d): detections, outliers, violations, gate_results, error_gaps,
 //     taint_flows, crypto_findings, owasp_findings, secrets, degradation_alerts,
-//     policy_results
-//   - Medium (90d): scan_history, audit_snapshots, health_trends, feedback,
-//     constraint_verifications, contract_mismatches, dna_mutations,
-//     coupling_cycles, decomposition_decisions
+//     policy_results, scan_checkpoint
+//   - Medium (90d): scan_history, scan_file_changes, scan_violations,
+//     audit_snapshots, health_trends, feedback, constraint_verifications,
+//     contract_mismatches, dna_mutations, coupling_cycles,
+//     decomposition_decisions
 //   - Long (365d): parse_cache, context_cache, simulations, decisions,
 //     migration_corrections, migration_modules, migration_projects
 //   - Self-bounding (PK/UPSERT, no time-based cleanup needed):
 //     call_edges, data_access, pattern_confidence, conventions,
 //     reachability_cache, impact_scores, test_coverage, test_quality,
-//     coupling_metrics, constraints, contracts, dna_genes
+//     coupling_metrics, constraints, contracts, dna_genes, detector_health
 // ═══════════════════════════════════════════════════════════════════════════
 
 #[test]
@@ -370,6 +372,7 @@ fn t13_04_tier_assignment_coverage() {
         "wrappers",
         "crypto_findings",
         "owasp_findings",
+        "file_roots",
     ]
     .into_iter()
     .collect();
@@ -387,6 +390,7 @@ fn t13_04_tier_assignment_coverage() {
         "secrets",
         "degradation_alerts",
         "policy_results",
+        "scan_checkpoint",
     ]
     .into_iter()
     .collect();
@@ -394,6 +398,8 @@ fn t13_04_tier_assignment_coverage() {
     // Medium tier (90d): time-based cleanup
     let medium_tier: HashSet<&str> = [
         "scan_history",
+        "scan_file_changes",
+        "scan_violations",
         "audit_snapshots",
         "health_trends",
         "feedback",
@@ -434,6 +440,7 @@ fn t13_04_tier_assignment_coverage() {
         "contracts",
         "dna_genes",
         "pattern_status",
+        "detector_health",
     ]
     .into_iter()
     .collect();
@@ -476,8 +483,8 @@ fn t13_04_tier_assignment_coverage() {
     // ── Verify expected table count ──
     assert_eq!(
         all_tables.len(),
-        46,
-        "Expected 46 tables after all migrations, got {}. Tables: {:?}",
+        51,
+        "Expected 51 tables after all migrations, got {}. Tables: {:?}",
         all_tables.len(),
         all_tables
     );
@@ -46,7 +46,7 @@ fn t8_01_fresh_db_all_45_tables_created() {
 
     let tables = get_table_names(&conn);
 
-    // All 46 expected tables from v001–v009 (+ v006 PART2)
+    // All 51 expected tables from v001–v014 (+ v006 PART2)
     let expected_tables = [
         // v001
         "file_metadata",
@@ -103,12 +103,21 @@ fn t8_01_fresh_db_all_45_tables_created() {
         "migration_corrections",
         // v009
         "pattern_status",
+        // v010
+        "file_roots",
+        // v011
+        "scan_file_changes",
+        "scan_violations",
+        // v012
+        "scan_checkpoint",
+        // v014
+        "detector_health",
     ];
 
     assert_eq!(
         expected_tables.len(),
-        46,
-        "sanity: expected_tables array must have 46 entries"
+        51,
+        "sanity: expected_tables array must have 51 entries"
     );
 
     for table_name in &expected_tables {
@@ -121,26 +130,28 @@ fn t8_01_fresh_db_all_45_tables_created() {
     // Verify total table count matches
     assert_eq!(
         tables.len(),
-        46,
-        "expected 46 tables, got {}: {:?}",
+        51,
+        "expected 51 tables, got {}: {:?}",
         tables.len(),
         tables
     );
 
     // Verify total column count across all tables matches DD-15 audit
-    // v001-v007: 398 columns + v008 scan_root: 1 column + v009 pattern_status: 7 columns = 406
+    // v001-v007: 398 columns + v008 scan_root: 1 column + v009 pattern_status: 7 columns
+    // + v010 file_roots: 2 columns + v011 scan tagging: 8 columns
+    // + v012 scan_checkpoint: 6 columns + v014 detector_health: 5 columns = 427
     let total_columns: usize = expected_tables
         .iter()
         .map(|t| get_column_count(&conn, t))
         .sum();
     assert_eq!(
-        total_columns, 406,
-        "total column count across 46 tables must be 406 (DD-15 audit + v008 + v009)"
+        total_columns, 427,
+        "total column count across 51 tables must be 427 (DD-15 audit + v008 + v009 + v010 + v011 + v012 + v014)"
     );
 
     // Verify schema version
     let version = migrations::current_version(&conn).unwrap();
-    assert_eq!(version, 9);
+    assert_eq!(version, 14);
 }
 
 // ---- T8-02: Idempotent Re-Open ----
@@ -160,10 +171,10 @@ fn t8_02_idempotent_reopen() {
         let db = DatabaseManager::open(&db_path).unwrap();
         db.with_writer(|conn| {
             let version = migrations::current_version(conn).unwrap();
-            assert_eq!(version, 9, "version must remain 9 after re-open");
+            assert_eq!(version, 14, "version must remain 14 after re-open");
 
             let tables = get_table_names(conn);
-            assert_eq!(tables.len(), 46, "all 46 tables must still exist after re-open");
+            assert_eq!(tables.len(), 51, "all 51 tables must still exist after re-open");
             Ok(())
         })
         .unwrap();
@@ -174,7 +185,7 @@ fn t8_02_idempotent_reopen() {
         let db = DatabaseManager::open(&db_path).unwrap();
         db.with_writer(|conn| {
             let version = migrations::current_version(conn).unwrap();
-            assert_eq!(version, 9);
+            assert_eq!(version, 14);
             Ok(())
         })
         .unwrap();
@@ -3,7 +3,7 @@
 use drift_storage::connection::pragmas::apply_pragmas;
 use drift_storage::migrations;
 use drift_storage::pagination::keyset::PaginationCursor;
-use drift_storage::queries::{functions, parse_cache};
+use drift_storage::queries::{files, functions, insights, parse_cache, scan_history};
 use rusqlite::Connection;
 
 fn test_connection() -> Connection {
@@ -174,6 +174,173 @@ fn t1_str_queries_functions_crud() {
     assert_eq!(functions::count_functions(&conn).unwrap(), 0);
 }
 
+// ---- T1-STR-04 (functions): Keyset pagination by file prefix ----
+
+#[test]
+fn t1_str_queries_functions_by_prefix_pagination() {
+    let conn = test_connection();
+
+    // 150 functions under src/, two per line, plus some outside the prefix
+    for i in 0..150 {
+        conn.execute(
+            "INSERT INTO functions (file, name, language, line, end_line) VALUES (?1, ?2, 'TypeScript', ?3, ?3)",
+            rusqlite::params![format!("src/mod_{}.ts", i % 4), format!("fn_{i}"), (i / 8) as i64],
+        )
+        .unwrap();
+    }
+    for i in 0..20 {
+        conn.execute(
+            "INSERT INTO functions (file, name, language, line, end_line) VALUES (?1, ?2, 'TypeScript', ?3, ?3)",
+            rusqlite::params![if i % 2 == 0 { "lib/util.ts" } else { "srcgen/out.ts" }, format!("other_{i}"), i],
+        )
+        .unwrap();
+    }
+
+    let mut seen = Vec::new();
+    let mut pages = 0;
+    let mut cursor = None;
+    loop {
+        let page = functions::functions_by_prefix(&conn, "src/", cursor.as_deref(), 50).unwrap();
+        pages += 1;
+        assert_eq!(page.total, 150);
+        seen.extend(page.items.iter().map(|f| (f.file.clone(), f.line, f.name.clone())));
+        if page.next_cursor.is_none() {
+            assert!(!page.has_more);
+            assert_eq!(page.items.len(), 50);
+            break;
+        }
+        cursor = page.next_cursor;
+    }
+
+    assert_eq!(pages, 3, "150 functions in pages of 50");
+    assert_eq!(seen.len(), 150);
+    assert!(seen.iter().all(|(file, _, _)| file.starts_with("src/")));
+    let mut sorted = seen.clone();
+    sorted.sort_by(|a, b| (&a.0, a.1).cmp(&(&b.0, b.1)));
+    let order: Vec<_> = seen.iter().map(|(f, l, _)| (f, l)).collect();
+    let expected: Vec<_> = sorted.iter().map(|(f, l, _)| (f, l)).collect();
+    assert_eq!(order, expected, "pages come back in (file, line) order");
+    let mut names: Vec<&str> = seen.iter().map(|(_, _, n)| n.as_str()).collect();
+    names.sort();
+    names.dedup();
+    assert_eq!(names.len(), 150, "no duplicates or gaps across pages");
+
+    // A function inserted behind the cursor does not shift later pages
+    let first = functions::functions_by_prefix(&conn, "src/", None, 50).unwrap();
+    conn.execute(
+        "INSERT INTO functions (file, name, language, line, end_line) VALUES ('src/aaa.ts', 'late', 'TypeScript', 1, 1)",
+        [],
+    )
+    .unwrap();
+    let second = functions::functions_by_prefix(&conn, "src/", first.next_cursor.as_deref(), 50).unwrap();
+    let resumed: Vec<&str> = second.items.iter().map(|f| f.name.as_str()).collect();
+    let expected: Vec<&str> = seen[50..100].iter().map(|(_, _, n)| n.as_str()).collect();
+    assert_eq!(resumed, expected);
+
+    assert!(functions::functions_by_prefix(&conn, "src/", Some("not a cursor"), 50).is_err());
+}
+
+// ---- Multi-root workspaces: file_roots ----
+
+#[test]
+fn t1_str_queries_file_roots_filter() {
+    let conn = test_connection();
+
+    for path in ["services/api.ts", "services/worker.ts", "libs/util.ts"] {
+        conn.execute(
+            "INSERT INTO file_metadata (path, language, file_size, content_hash, mtime_secs, mtime_nanos, last_scanned_at)
+             VALUES (?1, 'TypeScript', 10, ?2, 0, 0, 0)",
+            rusqlite::params![path, vec![0u8; 8]],
+        )
+        .unwrap();
+    }
+    files::upsert_file_roots(
+        &conn,
+        &[
+            ("services/api.ts".to_string(), "/work/services".to_string()),
+            ("services/worker.ts".to_string(), "/work/services".to_string()),
+            ("libs/util.ts".to_string(), "/opt/libs".to_string()),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(files::list_roots(&conn).unwrap(), vec!["/opt/libs", "/work/services"]);
+    assert_eq!(
+        files::get_file_root(&conn, "libs/util.ts").unwrap().as_deref(),
+        Some("/opt/libs")
+    );
+
+    let services: Vec<String> = files::load_file_metadata_by_root(&conn, "/work/services")
+        .unwrap()
+        .into_iter()
+        .map(|r| r.path)
+        .collect();
+    assert_eq!(services, vec!["services/api.ts", "services/worker.ts"]);
+    assert!(files::load_file_metadata_by_root(&conn, "/missing").unwrap().is_empty());
+}
+
+#[test]
+fn t1_str_queries_recent_hotspots() {
+    let conn = test_connection();
+    let owned = |pairs: &[(&str, &str)]| -> Vec<(String, String)> {
+        pairs.iter().map(|(a, b)| (a.to_string(), b.to_string())).collect()
+    };
+
+    // Scan 1: the baseline.
+    let first = scan_history::insert_scan_start(&conn, 100, "/repo").unwrap();
+    scan_history::record_file_changes(
+        &conn,
+        first,
+        &[("src/api.ts".to_string(), "added"), ("src/util.ts".to_string(), "added")],
+    )
+    .unwrap();
+    scan_history::record_scan_violations(&conn, first, &owned(&[("v-old", "src/util.ts")])).unwrap();
+
+    // Scans 2 and 3: api.ts changes twice and gains findings; util.ts gains
+    // one finding without changing; docs.ts changes with no findings.
+    let second = scan_history::insert_scan_start(&conn, 200, "/repo").unwrap();
+    scan_history::record_file_changes(
+        &conn,
+        second,
+        &[("src/api.ts".to_string(), "modified"), ("src/docs.ts".to_string(), "modified")],
+    )
+    .unwrap();
+    scan_history::record_scan_violations(
+        &conn,
+        second,
+        &owned(&[("v-old", "src/util.ts"), ("v-api-1", "src/api.ts"), ("v-util", "src/util.ts")]),
+    )
+    .unwrap();
+    let third = scan_history::insert_scan_start(&conn, 300, "/repo").unwrap();
+    scan_history::record_file_changes(&conn, third, &[("src/api.ts".to_string(), "modified")]).unwrap();
+    scan_history::record_scan_violations(
+        &conn,
+        third,
+        &owned(&[
+            ("v-old", "src/util.ts"),
+            ("v-api-1", "src/api.ts"),
+            ("v-api-2", "src/api.ts"),
+            ("v-util", "src/util.ts"),
+        ]),
+    )
+    .unwrap();
+    assert_eq!(scan_history::latest_scan_id(&conn).unwrap(), Some(third));
+
+    let hotspots = insights::recent_hotspots(&conn, first, 10).unwrap();
+    let files: Vec<&str> = hotspots.iter().map(|h| h.file.as_str()).collect();
+    assert_eq!(files, vec!["src/api.ts", "src/util.ts", "src/docs.ts"]);
+
+    let api = &hotspots[0];
+    assert_eq!((api.new_findings, api.change_count, api.findings_delta), (2, 2, 2));
+    assert_eq!(api.score, 6);
+    // The pre-existing finding is not new.
+    assert_eq!((hotspots[1].new_findings, hotspots[1].findings_delta), (1, 1));
+    assert_eq!((hotspots[2].new_findings, hotspots[2].score), (0, 0));
+
+    assert_eq!(insights::recent_hotspots(&conn, first, 1).unwrap().len(), 1);
+    assert!(insights::recent_hotspots(&conn, third, 10).unwrap().is_empty());
+}
+
 // ---- Helpers ----
 
 /// Simple keyset pagination over file_metadata ordered by path.
@@ -142,7 +142,7 @@ describe('CI Agent', () => {
           // Simulate slow scan — but our timeout is very short
           const start = Date.now();
           while (Date.now() - start < 10) { /* busy wait */ }
-          return { filesTotal: 0, filesAdded: 0, filesModified: 0, filesRemoved: 0, filesUnchanged: 0, errorsCount: 0, durationMs: 10, status: 'ok', languages: {} };
+          return { filesTotal: 0, filesAdded: 0, filesModified: 0, filesRemoved: 0, filesUnchanged: 0, errorsCount: 0, durationMs: 10, status: 'ok', languages: {}, filesResumed: 0, filesFresh: 0 };
         },
       }),
     );
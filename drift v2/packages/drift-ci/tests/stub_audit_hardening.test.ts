@@ -151,6 +151,8 @@ describe('PH6-02: changedFiles forwarded to driftScan', () => {
             durationMs: 20,
             status: 'ok',
             languages: {},
+            filesResumed: 0,
+            filesFresh: 0,
           };
         },
       }),
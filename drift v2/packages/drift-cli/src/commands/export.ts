@@ -7,7 +7,7 @@ import { loadNapi } from '../napi.js';
 import { formatOutput, type OutputFormat } from '../output/index.js';
 import * as fs from 'node:fs';
 
-const REPORT_FORMATS = ['sarif', 'json', 'html', 'junit', 'sonarqube', 'console', 'github', 'gitlab'] as const;
+const REPORT_FORMATS = ['sarif', 'json', 'html', 'junit', 'sonarqube', 'console', 'github', 'gitlab', 'vulnreport', 'ndjson'] as const;
 
 export function registerExportCommand(program: Command): void {
   program
@@ -1,12 +1,12 @@
 /**
- * drift report — generate reports from stored violations in 8 formats.
+ * drift report — generate reports from stored violations in 10 formats.
  */
 
 import type { Command } from 'commander';
 import { loadNapi } from '../napi.js';
 
 const VALID_FORMATS = [
-  'sarif', 'json', 'html', 'junit', 'sonarqube', 'console', 'github', 'gitlab',
+  'sarif', 'json', 'html', 'junit', 'sonarqube', 'console', 'github', 'gitlab', 'vulnreport', 'ndjson',
 ] as const;
 
 export function registerReportCommand(program: Command): void {
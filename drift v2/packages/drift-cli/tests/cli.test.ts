@@ -84,7 +84,7 @@ describe('CLI', () => {
   it('T8-CLI-04: scan on empty directory returns 0 files', async () => {
     const napi = createMockNapi({
       async driftScan() {
-        return { filesTotal: 0, filesAdded: 0, filesModified: 0, filesRemoved: 0, filesUnchanged: 0, errorsCount: 0, durationMs: 5, status: 'ok', languages: {} };
+        return { filesTotal: 0, filesAdded: 0, filesModified: 0, filesRemoved: 0, filesUnchanged: 0, errorsCount: 0, durationMs: 5, status: 'ok', languages: {}, filesResumed: 0, filesFresh: 0 };
       },
     });
     setNapi(napi);
@@ -134,7 +134,7 @@ describe('CLI E2E Pipeline', () => {
   // T9-CLI-05: Report generates string output
   it('T9-CLI-05: driftReport returns string for all formats', () => {
     const napi = loadNapi();
-    const formats = ['sarif', 'json', 'html', 'junit', 'sonarqube', 'console', 'github', 'gitlab'];
+    const formats = ['sarif', 'json', 'html', 'junit', 'sonarqube', 'console', 'github', 'gitlab', 'vulnreport', 'ndjson'];
     for (const format of formats) {
       const result = napi.driftReport(format);
       expect(typeof result).toBe('string');
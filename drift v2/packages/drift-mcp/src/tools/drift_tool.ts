@@ -417,10 +417,10 @@ export function buildToolCatalog(): Map<string, InternalTool> {
     },
   });
 
-  // PH-TOOL-26: drift_report — generate reports in 8 formats
+  // PH-TOOL-26: drift_report — generate reports in 10 formats
   register(catalog, {
     name: 'drift_report',
-    description: 'Generate report from stored violations. Formats: sarif, json, html, junit, sonarqube, console, github, gitlab.',
+    description: 'Generate report from stored violations. Formats: sarif, json, html, junit, sonarqube, console, github, gitlab, vulnreport, ndjson.',
     category: 'generation',
     estimatedTokens: '~500-5000',
     handler: async (p) => loadNapi().driftReport(p.format as string ?? 'json'),
@@ -75,9 +75,9 @@ describe('MCP E2E Pipeline', () => {
   });
 
   // T9-MCP-05: Report tool generates string output for all formats
-  it('T9-MCP-05: driftReport works for all 8 formats', () => {
+  it('T9-MCP-05: driftReport works for all 10 formats', () => {
     const napi = loadNapi();
-    const formats = ['sarif', 'json', 'html', 'junit', 'sonarqube', 'console', 'github', 'gitlab'];
+    const formats = ['sarif', 'json', 'html', 'junit', 'sonarqube', 'console', 'github', 'gitlab', 'vulnreport', 'ndjson'];
     for (const fmt of formats) {
       const output = napi.driftReport(fmt);
       expect(typeof output).toBe('string');
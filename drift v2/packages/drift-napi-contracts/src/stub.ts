@@ -95,6 +95,8 @@ export function createStubNapi(): DriftNapi {
         durationMs: 0,
         status: 'complete',
         languages: {},
+        filesResumed: 0,
+        filesFresh: 0,
       };
     },
 
@@ -113,6 +115,8 @@ export function createStubNapi(): DriftNapi {
         durationMs: 0,
         status: 'complete',
         languages: {},
+        filesResumed: 0,
+        filesFresh: 0,
       };
     },
 
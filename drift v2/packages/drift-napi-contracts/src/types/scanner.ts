@@ -19,6 +19,12 @@ export interface ScanOptions {
   followSymlinks?: boolean;
   /** Restrict scan to these files only (for incremental CI analysis). */
   changedFiles?: string[];
+  /** Scan only this share of files (0–1), chosen deterministically from sampleSeed. */
+  sampleFraction?: number;
+  /** Seed for sampleFraction. Default: 0. */
+  sampleSeed?: number;
+  /** Focus globs: analyze only matching files, resolving imports outside them read-only. */
+  includePaths?: string[];
 }
 
 /**
@@ -36,6 +42,10 @@ export interface ScanSummary {
   durationMs: number;
   status: string;
   languages: Record<string, number>;
+  /** Files taken from an interrupted scan's checkpoint. */
+  filesResumed: number;
+  /** Files processed from scratch. */
+  filesFresh: number;
 }
 
 /**
@@ -52,4 +62,10 @@ export interface ScanStatsJs {
   filesSkippedLarge: number;
   filesSkippedIgnored: number;
   filesSkippedBinary: number;
+  filesSkippedSampled: number;
+  /** Sampled fraction, or absent for a full scan. */
+  sampleFraction?: number | null;
+  filesOutsideFocus: number;
+  filesResumed: number;
+  filesFresh: number;
 }